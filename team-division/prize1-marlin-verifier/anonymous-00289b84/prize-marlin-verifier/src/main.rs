@@ -50,14 +50,13 @@ fn verify_group(
             <FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>> as FiatShamirRng<_, _>>::parameters()
         };
     }
-    let fs_parameters = &FS_PARAMS;
+    let fs_parameters: &Arc<PoseidonParameters<Fp384<FqParameters>, 6, 1>> = &FS_PARAMS;
     let prepared_vk = circuit_vk.prepare();
-    let mut results = vec![];
-    group_proofs.iter().for_each(|((proof, inputs), _faulty)| {
-        let verified = MarlinInst::verify_batch_with_prepared_vk(fs_parameters, &prepared_vk, &inputs, &proof).unwrap();
-        results.push(verified);
-    });
-    results
+    let instances: Vec<_> = group_proofs
+        .iter()
+        .map(|((proof, inputs), _faulty)| (&prepared_vk, &inputs[..], proof))
+        .collect();
+    MarlinInst::verify_many(Some(fs_parameters), &instances, &mut thread_rng()).unwrap()
 }
 
 //********************************************************************
@@ -15,7 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::crypto_hash::Blake2Xs;
-use snarkvm_curves::AffineCurve;
+use snarkvm_curves::{templates::short_weierstrass_jacobian::Affine, AffineCurve, ProjectiveCurve, SWUParameters};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use sha2::{Digest, Sha256};
 
 /// Runs hash-to-curve and returns the generator, message, and counter on success.
 #[inline]
@@ -54,11 +58,85 @@ pub fn try_hash_to_curve<G: AffineCurve>(input: &str) -> Option<G> {
     })
 }
 
+/// Hashes `message` to a point of the prime order subgroup of a short Weierstrass curve, as in `hash_to_curve`
+/// of the IETF hash-to-curve draft, with `expand_message_xmd` over SHA-256, the simplified SWU map
+/// to an isogenous curve, and the domain separation tag `dst`.
+pub fn hash_to_curve_swu<P: SWUParameters>(message: &[u8], dst: &[u8]) -> Affine<P> {
+    let elements = hash_to_field::<P::BaseField>(message, dst, 2);
+
+    let q0 = Affine::<P>::map_to_curve(elements[0]);
+    let q1 = Affine::<P>::map_to_curve(elements[1]);
+    let point = (q0.to_projective() + q1.to_projective()).to_affine().mul_by_cofactor();
+    debug_assert!(point.is_on_curve());
+    debug_assert!(point.is_in_correct_subgroup_assuming_on_curve());
+    point
+}
+
+/// Hashes `message` to `count` field elements, as in `hash_to_field` of the IETF hash-to-curve draft,
+/// with `expand_message_xmd` over SHA-256, and the domain separation tag `dst`.
+pub fn hash_to_field<F: Field>(message: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    // The number of coordinates of an element over the base prime field, which `ToBytes` writes in order.
+    let coordinate_size = F::BasePrimeField::zero().to_bytes_le().expect("Failed to serialize a field element").len();
+    let degree = F::zero().to_bytes_le().expect("Failed to serialize a field element").len() / coordinate_size;
+    // L = ceil((ceil(log2(p)) + k) / 8), for the security parameter k = 128.
+    let length = (F::BasePrimeField::size_in_bits() + 128 + 7) / 8;
+
+    let uniform_bytes = expand_message_xmd(message, dst, count * degree * length);
+    uniform_bytes
+        .chunks(degree * length)
+        .map(|element| {
+            let mut bytes = Vec::with_capacity(degree * coordinate_size);
+            for coordinate in element.chunks(length) {
+                F::BasePrimeField::from_bytes_be_mod_order(coordinate)
+                    .write_le(&mut bytes)
+                    .expect("Failed to serialize a field element");
+            }
+            F::read_le(&bytes[..]).expect("Failed to deserialize a field element")
+        })
+        .collect()
+}
+
+/// Expands `message` to `length` pseudorandom bytes, as in `expand_message_xmd` of the IETF hash-to-curve draft,
+/// with SHA-256, and the domain separation tag `dst`.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], length: usize) -> Vec<u8> {
+    // The output and input block sizes of SHA-256.
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = (length + B_IN_BYTES - 1) / B_IN_BYTES;
+    assert!(ell <= 255 && length <= u16::MAX as usize, "Cannot expand a message to {length} bytes");
+
+    // Domain separation tags longer than 255 bytes are hashed first.
+    let dst = match dst.len() > 255 {
+        true => Sha256::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize().to_vec(),
+        false => dst.to_vec(),
+    };
+    let dst_prime = [&dst[..], &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(message)
+        .chain_update((length as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+    let mut uniform_bytes = b_i.to_vec();
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        b_i = Sha256::new().chain_update(xor).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(length);
+    uniform_bytes
+}
+
 #[cfg(test)]
 mod bls12_377 {
-    use crate::crypto_hash::hash_to_curve::{hash_to_curve, try_hash_to_curve};
+    use crate::crypto_hash::hash_to_curve::{expand_message_xmd, hash_to_curve, hash_to_curve_swu, try_hash_to_curve};
     use snarkvm_curves::{
-        bls12_377::{G1Affine, G2Affine},
+        bls12_377::{g1::Bls12_377G1Parameters, g2::Bls12_377G2Parameters, G1Affine, G2Affine},
         AffineCurve,
     };
     use snarkvm_fields::PrimeField;
@@ -241,4 +319,68 @@ mod bls12_377 {
             ])
         );
     }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The test vectors of expand_message_xmd with SHA-256 in the IETF hash-to-curve draft.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"", dst, 0x20)),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"abc", dst, 0x20)),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"", dst, 0x80)),
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+        );
+    }
+
+    #[test]
+    fn hash_swu_bls12_377_g1() {
+        let dst = b"SNARKVM-V01-CS02-with-BLS12377G1_XMD:SHA-256_SSWU_RO_";
+        for (message, x, y) in [
+            (
+                &b""[..],
+                "27440364614670509350852502689989690586311424048797306496206019259138862118222885565929115357773390390498795405437",
+                "242607107543478550197750262037564193704185922566545821114789674842179034973594696543030667843447612463969427833830",
+            ),
+            (
+                &b"abc"[..],
+                "234870211350460132931718053294748815718746793228544229793457542777087527043013440065824635528687551664788425921662",
+                "173593085278097601161543763220326441943619474254252342400563727411795361030361633544287024747210984853843320029193",
+            ),
+        ] {
+            let g1 = hash_to_curve_swu::<Bls12_377G1Parameters>(message, dst);
+            assert!(g1.is_on_curve());
+            assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(g1.x.to_string(), x);
+            assert_eq!(g1.y.to_string(), y);
+        }
+    }
+
+    #[test]
+    fn hash_swu_bls12_377_g2() {
+        let dst = b"SNARKVM-V01-CS02-with-BLS12377G2_XMD:SHA-256_SSWU_RO_";
+        for (message, x, y) in [
+            (
+                &b""[..],
+                "Fp2(246061205049540215215447591176225476672428147352576008952162781526356864080380664621709079633608524326212812075402 + 107955209573498812073711980353619241431474672240609251485693815083673440153342501885649459934877027527091915085192 * u)",
+                "Fp2(75437088098973053156182809254877420337212188345333370207717234014258386496889706328317531081435774893669286798505 + 43999269021723558276119676947726419646157815670083052580432938629848824131763743459322135916608773865691061513583 * u)",
+            ),
+            (
+                &b"abc"[..],
+                "Fp2(39965712675081476001702734405228995793568418602476953747410351038520702507995947675843741082253292180371535556914 + 120220544669394879732670271907663873370482750474444561005801565977345150006349658809029418617492056632230691164996 * u)",
+                "Fp2(129656344621982916676274833569220873150414295726076390023775528696127648300566328508499779448161165857136243289709 + 157630131389860663559293591897861023015733400650655586952402210931375483114784462243315971423497259966168337581251 * u)",
+            ),
+        ] {
+            let g2 = hash_to_curve_swu::<Bls12_377G2Parameters>(message, dst);
+            assert!(g2.is_on_curve());
+            assert!(g2.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(g2.x.to_string(), x);
+            assert_eq!(g2.y.to_string(), y);
+        }
+    }
 }
@@ -20,5 +20,11 @@ pub use crh::*;
 pub mod merkle;
 pub use merkle::*;
 
+pub mod msm;
+pub use msm::*;
+
 pub mod snark;
 pub use snark::*;
+
+pub mod verification;
+pub use verification::*;
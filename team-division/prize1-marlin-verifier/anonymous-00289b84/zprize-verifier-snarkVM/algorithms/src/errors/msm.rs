@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[derive(Debug, Error)]
+pub enum MsmError {
+    #[error("every MSM engine failed: {:?}", _0)]
+    AllEnginesFailed(Vec<MsmError>),
+
+    #[error("the {} MSM engine failed: {}", _0, _1)]
+    EngineFailure(&'static str, String),
+
+    #[error("cannot precompute a base at infinity")]
+    BaseAtInfinity,
+
+    #[error("no MSM engine supports {} bases on this curve", _0)]
+    NoSupportedEngine(usize),
+
+    #[error("the tables of {} precomputed bases exceed the addressable size", _0)]
+    TooManyBases(usize),
+
+    #[error("the {} MSM engine does not support {} bases on this curve", _0, _1)]
+    Unsupported(&'static str, usize),
+
+    #[error("unsupported window size of {} bits", _0)]
+    UnsupportedWindowSize(usize),
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::SNARKError;
+
+/// The reason a proof was rejected by a SNARK verifier.
+#[derive(Debug, Error)]
+pub enum VerificationError {
+    #[error("Proof has an incorrect hiding mode (expected hiding: {expected}, found hiding: {found})")]
+    IncorrectZKMode { expected: bool, found: bool },
+
+    #[error("Malformed proof: {}", _0)]
+    MalformedProof(String),
+
+    #[error("Proof is for a batch of {proof} instances, but {inputs} public inputs were provided")]
+    BatchSizeMismatch { proof: usize, inputs: usize },
+
+    #[error("Proof covers {proof} circuits, but {keys} verifying keys were provided")]
+    CircuitCountMismatch { proof: usize, keys: usize },
+
+    #[error("Public input {index} has {found} field elements, but the circuit supports at most {expected}")]
+    PublicInputLengthMismatch { index: usize, expected: usize, found: usize },
+
+    /// The AHP equations are only checked through the evaluations opened by the polynomial
+    /// commitment, so a proof that fails them is also reported as a failed pairing check.
+    #[error("Polynomial commitment pairing check failed")]
+    InvalidPairingCheck,
+
+    #[error("{}", _0)]
+    SNARKError(#[from] SNARKError),
+}
+
+impl VerificationError {
+    /// Returns `true` if the proof was rejected, and `false` if verification could not be carried out.
+    pub fn is_rejection(&self) -> bool {
+        !matches!(self, VerificationError::SNARKError(_))
+    }
+}
@@ -77,12 +77,14 @@ const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2. Fields that additionally define a small subgroup base `b` also
+/// support domains of size `2^a * b^c`, see [`EvaluationDomain::new_mixed_radix`].
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// The two-adicity of `self.size`. This is `log_2(self.size)` only for power-of-two domains;
+    /// a mixed-radix domain has size `2^log_size_of_group * b^c`, see [`Self::small_subgroup_adicity`].
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -124,11 +126,26 @@ impl<F: FftField> EvaluationDomain<F> {
             return None;
         }
 
+        Self::from_size(size)
+    }
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, whose size is the smallest one of the
+    /// form `2^a * b^c`, where `b` is the small subgroup base of the field.
+    /// For fields without a small subgroup, this is equivalent to [`Self::new`].
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_mixed_radix_domain(num_coeffs)?;
+        Self::from_size(size as u64)
+    }
+
+    /// Construct the domain of the given `size`, which must divide the order
+    /// of the largest subgroup supported by the field.
+    fn from_size(size: u64) -> Option<Self> {
         // Compute the generator for the multiplicative subgroup.
-        // It should be the 2^(log_size_of_group) root of unity.
+        // It should be the `size`-th root of unity.
         let group_gen = F::get_root_of_unity(size as usize)?;
 
-        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        // Check that it is indeed the `size`-th root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
@@ -136,7 +153,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
         Some(EvaluationDomain {
             size,
-            log_size_of_group,
+            log_size_of_group: size.trailing_zeros(),
             size_as_field_element,
             size_inv,
             group_gen,
@@ -152,6 +169,33 @@ impl<F: FftField> EvaluationDomain<F> {
         if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
     }
 
+    /// Return the size of a mixed-radix domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, i.e. the smallest size of the form `2^a * b^c`.
+    pub fn compute_size_of_mixed_radix_domain(num_coeffs: usize) -> Option<usize> {
+        let (base, max_base_adicity) =
+            match (F::FftParameters::SMALL_SUBGROUP_BASE, F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY) {
+                (Some(base), Some(adicity)) => (base as usize, adicity),
+                _ => return Self::compute_size_of_domain(num_coeffs),
+            };
+
+        let mut size = None;
+        let mut base_power = 1usize;
+        for _ in 0..=max_base_adicity {
+            // The smallest power of two `2^a` such that `2^a * base_power >= num_coeffs`.
+            let candidate = ((num_coeffs + base_power - 1) / base_power).next_power_of_two().checked_mul(base_power);
+            if let Some(candidate) = candidate {
+                if candidate.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                    size = Some(size.map_or(candidate, |size: usize| size.min(candidate)));
+                }
+            }
+            base_power = match base_power.checked_mul(base) {
+                Some(base_power) => base_power,
+                None => break,
+            };
+        }
+        size
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -370,20 +414,59 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Returns `true` if the size of `self` is not a power of two.
+    pub fn is_mixed_radix(&self) -> bool {
+        !self.size.is_power_of_two()
+    }
+
+    /// Returns the exponent `c` of the small subgroup base `b` in the size `2^a * b^c` of `self`,
+    /// which is zero for power-of-two domains.
+    pub fn small_subgroup_adicity(&self) -> u32 {
+        match F::FftParameters::SMALL_SUBGROUP_BASE {
+            Some(base) if self.is_mixed_radix() => {
+                let base = base as u64;
+                let mut odd_part = self.size >> self.log_size_of_group;
+                let mut adicity = 0;
+                while odd_part % base == 0 {
+                    odd_part /= base;
+                    adicity += 1;
+                }
+                adicity
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns `log_2(self.size)`, or `None` if `self` is a mixed-radix domain.
+    pub fn log_size(&self) -> Option<u32> {
+        if self.is_mixed_radix() { None } else { Some(self.log_size_of_group) }
+    }
+
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
+        if self.is_mixed_radix() {
+            return Self::mixed_radix_fft_in_place(x_s, false);
+        }
         let pc = self.precompute_fft();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
     }
 
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        let pc = self.precompute_ifft();
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
+        if self.is_mixed_radix() {
+            Self::mixed_radix_fft_in_place(x_s, true);
+        } else {
+            let pc = self.precompute_ifft();
+            self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
+        }
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
     }
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        let pc = self.precompute_ifft();
-        self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
+        if self.is_mixed_radix() {
+            Self::mixed_radix_fft_in_place(x_s, true);
+        } else {
+            let pc = self.precompute_ifft();
+            self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
+        }
         let coset_shift = self.generator_inv;
         Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
     }
@@ -441,6 +524,9 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &FFTPrecomputation<F>,
     ) {
         use FFTOrder::*;
+        if self.is_mixed_radix() {
+            return Self::mixed_radix_fft_in_place_with_order(x_s, ord, false);
+        }
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
         let log_len = log2(x_s.len());
@@ -466,6 +552,9 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         use FFTOrder::*;
+        if self.is_mixed_radix() {
+            return Self::mixed_radix_fft_in_place_with_order(x_s, ord, true);
+        }
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
         let log_len = log2(x_s.len());
@@ -481,6 +570,87 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Computes the (I)FFT of `x_s` over the domain of size `x_s.len() = 2^a * b^c`, with the input
+    /// and output in the order given by `ord`. Out of order means permuted by [`Self::mixed_radix_derange`].
+    fn mixed_radix_fft_in_place_with_order<T: DomainCoeff<F>>(x_s: &mut [T], ord: FFTOrder, inverse: bool) {
+        if ord == FFTOrder::OI {
+            Self::mixed_radix_derange(x_s, true);
+        }
+        Self::mixed_radix_fft_in_place(x_s, inverse);
+        if ord == FFTOrder::IO {
+            Self::mixed_radix_derange(x_s, false);
+        }
+    }
+
+    /// Moves the element at each index `i` of `x_s` to the index whose digits are those of `i` reversed,
+    /// where `i` is written with `c` digits of base `b` followed by `a` bits, least significant first,
+    /// for `x_s.len() = 2^a * b^c`. If `inverse` is set, the elements are moved back instead.
+    /// On power-of-two lengths, this is the bit-reversal permutation of the radix-2 FFT.
+    fn mixed_radix_derange<T: Copy + Send + Sync>(x_s: &mut [T], inverse: bool) {
+        let base = F::FftParameters::SMALL_SUBGROUP_BASE.map_or(2, |base| base as usize);
+        let mut radices = Vec::new();
+        let mut remainder = x_s.len();
+        while remainder % base == 0 && remainder > 1 {
+            radices.push(base);
+            remainder /= base;
+        }
+        while remainder > 1 {
+            radices.push(2);
+            remainder /= 2;
+        }
+        let reverse = |mut i: usize| {
+            radices.iter().fold(0, |reversed, radix| {
+                let digit = i % radix;
+                i /= radix;
+                reversed * radix + digit
+            })
+        };
+
+        let input = x_s.to_vec();
+        match inverse {
+            true => cfg_iter_mut!(x_s).enumerate().for_each(|(i, x)| *x = input[reverse(i)]),
+            false => input.into_iter().enumerate().for_each(|(i, x)| x_s[reverse(i)] = x),
+        }
+    }
+
+    /// Computes the (I)FFT of `x_s` in order, over the domain of size `x_s.len() = 2^a * b^c`.
+    /// The factors of `b` are split off by decimation in time, down to radix-2 FFTs.
+    /// As for the radix-2 IFFT, the results of the IFFT are left to be divided by `|x_s|`.
+    fn mixed_radix_fft_in_place<T: DomainCoeff<F>>(x_s: &mut [T], inverse: bool) {
+        let n = x_s.len();
+        if n.is_power_of_two() {
+            let domain = Self::new(n).unwrap();
+            match inverse {
+                true => domain.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &domain.precompute_ifft()),
+                false => domain.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &domain.precompute_fft()),
+            }
+            return;
+        }
+
+        let base = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap() as usize;
+        let domain = Self::from_size(n as u64).unwrap();
+        let root = if inverse { domain.group_gen_inv } else { domain.group_gen };
+
+        // Split `x_s` into the `base` subsequences `x_s[base * k + j]`, and transform each of them
+        // over the subdomain of size `n / base`, whose generator is `root^base`.
+        let mut subsequences =
+            (0..base).map(|j| x_s.iter().skip(j).step_by(base).copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+        cfg_iter_mut!(subsequences).for_each(|y| Self::mixed_radix_fft_in_place(y, inverse));
+
+        // Recombine, using X[i] = sum_j root^(j * i) * Y_j[i mod (n / base)].
+        let mut powers = vec![F::one(); n];
+        Self::distribute_powers(&mut powers, root);
+        let m = n / base;
+        cfg_iter_mut!(x_s).enumerate().for_each(|(i, x)| {
+            *x = subsequences[0][i % m];
+            for (j, y) in subsequences.iter().enumerate().skip(1) {
+                let mut term = y[i % m];
+                term *= powers[(j * i) % n];
+                *x += term;
+            }
+        });
+    }
+
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
     /// e.g. for the domain [1, g, g^2, ..., g^{n - 1}], it computes
     // [1, g, g^2, ..., g^{(n/2) - 1}]
@@ -511,6 +681,8 @@ impl<F: FftField> EvaluationDomain<F> {
             // allocate the return array and start the recursion
             let mut powers = vec![F::zero(); 1 << (log_size - 1)];
             Self::roots_of_unity_recursive(&mut powers, &log_powers);
+            // Mixed-radix domains are not a power of two, so drop the excess roots.
+            powers.truncate((self.size as usize) / 2);
             powers
         }
     }
@@ -797,7 +969,7 @@ impl<F: FftField> FFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
             let roots = self.roots.iter().step_by(size_ratio).copied().collect();
             Some(Cow::Owned(Self { roots, domain: *domain }))
@@ -821,7 +993,7 @@ impl<F: FftField> IFFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
             let inverse_roots = self.inverse_roots.iter().step_by(size_ratio).copied().collect();
             Some(Cow::Owned(Self { inverse_roots, domain: *domain }))
@@ -1032,4 +1204,122 @@ mod tests {
             assert_eq!(pc, fft_pc.to_ifft_precomputation())
         }
     }
+
+    /// Tests that mixed-radix domains pick the smallest size of the form `2^a * 3^b`.
+    #[test]
+    fn test_mixed_radix_domain_size() {
+        for (num_coeffs, expected) in
+            [(0, 1), (1, 1), (2, 2), (3, 3), (4, 4), (5, 6), (6, 6), (7, 8), (9, 12), (13, 16), (17, 24), (1025, 1536)]
+        {
+            assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(num_coeffs), Some(expected));
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            assert_eq!(domain.size(), expected);
+            assert_eq!(domain.group_gen.pow([expected as u64]), Fr::one());
+            assert_eq!(domain.elements().collect::<std::collections::HashSet<_>>().len(), expected);
+        }
+        for (size, two_adicity, three_adicity) in [(6, 1, 1), (12, 2, 1), (24, 3, 1), (1536, 9, 1), (3, 0, 1)] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(size).unwrap();
+            assert_eq!(domain.log_size_of_group, two_adicity);
+            assert_eq!(domain.small_subgroup_adicity(), three_adicity);
+            assert_eq!(domain.log_size(), None);
+        }
+        // Power-of-two sizes are unaffected.
+        for log_size in 0..10 {
+            let size = 1 << log_size;
+            assert_eq!(EvaluationDomain::<Fr>::new_mixed_radix(size), EvaluationDomain::<Fr>::new(size));
+            assert_eq!(EvaluationDomain::<Fr>::new(size).unwrap().log_size(), Some(log_size));
+            assert_eq!(EvaluationDomain::<Fr>::new(size).unwrap().small_subgroup_adicity(), 0);
+        }
+    }
+
+    /// Tests that the vanishing polynomial of a mixed-radix domain vanishes on the domain.
+    #[test]
+    fn mixed_radix_vanishing_polynomial_vanishes_on_domain() {
+        for coeffs in [3, 5, 9, 17, 33, 100] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(coeffs).unwrap();
+            let z = domain.vanishing_polynomial();
+            for point in domain.elements() {
+                assert!(z.evaluate(point).is_zero())
+            }
+        }
+    }
+
+    /// Tests that the FFTs over mixed-radix domains output the correct result.
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let rng = &mut thread_rng();
+        for domain_size in [3, 6, 12, 48, 96, 384] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(domain_size).unwrap();
+            assert_eq!(domain.size(), domain_size);
+
+            let random_polynomial = DensePolynomial::<Fr>::rand(domain_size - 1, rng);
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+            }
+
+            let random_polynomial_from_subgroup =
+                DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations));
+            let random_polynomial_from_coset =
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations));
+            assert_eq!(random_polynomial, random_polynomial_from_subgroup, "domain size = {}", domain_size);
+            assert_eq!(random_polynomial, random_polynomial_from_coset, "domain size = {}", domain_size);
+        }
+    }
+
+    /// Tests that the FFTs over mixed-radix domains honour each `FFTOrder`.
+    #[test]
+    fn test_mixed_radix_fft_orders() {
+        use super::{derange, FFTOrder};
+
+        let out_of_order = |mut x_s: Vec<Fr>| {
+            EvaluationDomain::<Fr>::mixed_radix_derange(&mut x_s, false);
+            x_s
+        };
+
+        let rng = &mut thread_rng();
+        for domain_size in [6, 12, 48, 96, 384] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(domain_size).unwrap();
+            let (fft_pc, ifft_pc) = (domain.precompute_fft(), domain.precompute_ifft());
+            let coeffs = DensePolynomial::<Fr>::rand(domain_size - 1, rng).coeffs;
+            let evaluations = domain.fft(&coeffs);
+            let scaled_coeffs = coeffs.iter().map(|c| *c * Fr::from(domain_size as u64)).collect::<Vec<_>>();
+
+            for (ord, input, expected) in [
+                (FFTOrder::II, coeffs.clone(), evaluations.clone()),
+                (FFTOrder::IO, coeffs.clone(), out_of_order(evaluations.clone())),
+                (FFTOrder::OI, out_of_order(coeffs.clone()), evaluations.clone()),
+            ] {
+                let mut x_s = input;
+                domain.fft_helper_in_place_with_pc(&mut x_s, ord, &fft_pc);
+                assert_eq!(x_s, expected, "domain size = {}", domain_size);
+            }
+            for (ord, input, expected) in [
+                (FFTOrder::II, evaluations.clone(), scaled_coeffs.clone()),
+                (FFTOrder::IO, evaluations.clone(), out_of_order(scaled_coeffs.clone())),
+                (FFTOrder::OI, out_of_order(evaluations.clone()), scaled_coeffs.clone()),
+            ] {
+                let mut x_s = input;
+                domain.ifft_helper_in_place_with_pc(&mut x_s, ord, &ifft_pc);
+                assert_eq!(x_s, expected, "domain size = {}", domain_size);
+            }
+
+            // An out-of-order FFT is undone by an out-of-order IFFT.
+            let mut x_s = coeffs.clone();
+            domain.out_order_fft_in_place_with_pc(&mut x_s, &fft_pc);
+            assert_ne!(x_s, evaluations);
+            domain.out_order_ifft_in_place_with_pc(&mut x_s, &ifft_pc);
+            assert_eq!(x_s, coeffs);
+        }
+
+        // On power-of-two lengths, the permutation is the bit reversal of the radix-2 FFT.
+        let x_s = (0..64).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let mut expected = x_s.clone();
+        derange(&mut expected);
+        assert_eq!(out_of_order(x_s), expected);
+    }
 }
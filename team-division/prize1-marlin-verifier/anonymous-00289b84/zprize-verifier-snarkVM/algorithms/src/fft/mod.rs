@@ -23,6 +23,11 @@ pub use domain::EvaluationDomain;
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod out_of_core;
+#[cfg(not(target_family = "wasm"))]
+pub use out_of_core::FileStorage;
+pub use out_of_core::{FftStorage, OutOfCoreFft};
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
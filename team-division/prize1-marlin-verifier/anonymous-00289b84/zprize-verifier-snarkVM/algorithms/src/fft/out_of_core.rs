@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An FFT over vectors that do not fit in memory.
+//!
+//! The vectors live in an [`FftStorage`], which is either an in-memory `Vec<F>` or a
+//! [`FileStorage`] mapping a file that holds the little-endian encoding of each element, back to back.
+//! A transform of size `n = n1 * n2` is computed with the four-step decomposition:
+//! `n2` FFTs of size `n1` over the columns of the `n1 x n2` matrix view of the input,
+//! a multiplication by twiddle factors, and `n1` FFTs of size `n2` over its rows,
+//! whose results are written out transposed. Columns and rows are processed in blocks,
+//! so that at most `memory_limit` elements are held in memory at once.
+
+use crate::{cfg_chunks_mut, cfg_iter, cfg_iter_mut, fft::EvaluationDomain};
+use snarkvm_fields::{FftField, Field};
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_utilities::{mmap::MappedFileMut, FromBytes, ToBytes};
+
+use anyhow::{bail, ensure, Result};
+#[cfg(not(target_family = "wasm"))]
+use std::{fs::OpenOptions, marker::PhantomData, path::Path};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A random-access vector of field elements.
+pub trait FftStorage<F: Field> {
+    /// Returns the number of elements in the storage.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the storage holds no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads the elements starting at index `start` into `out`.
+    fn read_range(&mut self, start: usize, out: &mut [F]) -> Result<()>;
+
+    /// Writes `values` to the elements starting at index `start`.
+    fn write_range(&mut self, start: usize, values: &[F]) -> Result<()>;
+
+    /// Returns the elements, if the storage holds them in memory.
+    fn in_memory(&mut self) -> Option<&mut [F]> {
+        None
+    }
+}
+
+impl<F: Field> FftStorage<F> for Vec<F> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn read_range(&mut self, start: usize, out: &mut [F]) -> Result<()> {
+        ensure!(start + out.len() <= self.as_slice().len(), "Read out of bounds of the FFT storage");
+        out.copy_from_slice(&self[start..start + out.len()]);
+        Ok(())
+    }
+
+    fn write_range(&mut self, start: usize, values: &[F]) -> Result<()> {
+        ensure!(start + values.len() <= self.as_slice().len(), "Write out of bounds of the FFT storage");
+        self[start..start + values.len()].copy_from_slice(values);
+        Ok(())
+    }
+
+    fn in_memory(&mut self) -> Option<&mut [F]> {
+        Some(self)
+    }
+}
+
+/// A vector of field elements stored in a memory-mapped file, as the concatenation of their little-endian encodings.
+#[cfg(not(target_family = "wasm"))]
+pub struct FileStorage<F: Field> {
+    mmap: MappedFileMut,
+    len: usize,
+    element_size: usize,
+    _field: PhantomData<F>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<F: Field> FileStorage<F> {
+    /// Creates the file at `path`, holding `len` zero elements.
+    pub fn create<P: AsRef<Path>>(path: P, len: usize) -> Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+        let element_size = Self::element_size()?;
+        file.set_len((len * element_size) as u64)?;
+        Ok(Self { mmap: MappedFileMut::map(&file)?, len, element_size, _field: PhantomData })
+    }
+
+    /// Opens the existing file at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let element_size = Self::element_size()?;
+        let num_bytes = file.metadata()?.len() as usize;
+        if num_bytes % element_size != 0 {
+            bail!("The file size ({num_bytes} bytes) is not a multiple of the element size ({element_size} bytes)")
+        }
+        let mmap = MappedFileMut::map(&file)?;
+        Ok(Self { mmap, len: num_bytes / element_size, element_size, _field: PhantomData })
+    }
+
+    /// Creates the file at `path`, holding the given `values`.
+    pub fn from_slice<P: AsRef<Path>>(path: P, values: &[F]) -> Result<Self> {
+        let mut storage = Self::create(path, values.len())?;
+        storage.write_range(0, values)?;
+        Ok(storage)
+    }
+
+    /// Reads all elements into memory.
+    pub fn to_vec(&mut self) -> Result<Vec<F>> {
+        let mut values = vec![F::zero(); self.len];
+        self.read_range(0, &mut values)?;
+        Ok(values)
+    }
+
+    /// Writes the elements back to the file, and waits for the writes to complete.
+    pub fn flush(&self) -> Result<()> {
+        Ok(self.mmap.flush()?)
+    }
+
+    /// Returns the number of bytes in the encoding of one element.
+    fn element_size() -> Result<usize> {
+        Ok(F::zero().to_bytes_le()?.len())
+    }
+
+    /// Returns the bytes of the `len` elements starting at index `start`.
+    fn bytes_mut(&mut self, start: usize, len: usize) -> Result<&mut [u8]> {
+        ensure!(start + len <= self.len, "Access out of bounds of the file storage");
+        Ok(&mut self.mmap[start * self.element_size..(start + len) * self.element_size])
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<F: Field> FftStorage<F> for FileStorage<F> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn read_range(&mut self, start: usize, out: &mut [F]) -> Result<()> {
+        let element_size = self.element_size;
+        let bytes = self.bytes_mut(start, out.len())?;
+        cfg_iter_mut!(out).zip(cfg_chunks_mut!(bytes, element_size)).try_for_each(|(value, bytes)| {
+            *value = F::read_le(&bytes[..])?;
+            Ok(())
+        })
+    }
+
+    fn write_range(&mut self, start: usize, values: &[F]) -> Result<()> {
+        let element_size = self.element_size;
+        let bytes = self.bytes_mut(start, values.len())?;
+        cfg_iter!(values).zip(cfg_chunks_mut!(bytes, element_size)).try_for_each(|(value, bytes)| {
+            value.write_le(bytes)?;
+            Ok(())
+        })
+    }
+}
+
+/// Computes (I)FFTs over a power-of-two domain on vectors held in an [`FftStorage`].
+#[derive(Copy, Clone, Debug)]
+pub struct OutOfCoreFft<F: FftField> {
+    domain: EvaluationDomain<F>,
+    /// The domain of the column FFTs, of size `n1`.
+    column_domain: EvaluationDomain<F>,
+    /// The domain of the row FFTs, of size `n2`.
+    row_domain: EvaluationDomain<F>,
+    /// The number of elements to hold in memory at once.
+    memory_limit: usize,
+}
+
+impl<F: FftField> OutOfCoreFft<F> {
+    /// Initializes the out-of-core FFT over `domain`, holding about `memory_limit` elements in memory at once.
+    /// A single row or column of the decomposition, about `sqrt(domain.size())` elements, is always held in memory.
+    pub fn new(domain: EvaluationDomain<F>, memory_limit: usize) -> Result<Self> {
+        let size = domain.size();
+        ensure!(size.is_power_of_two(), "The out-of-core FFT requires a power-of-two domain, found {size}");
+
+        let log_size = domain.log_size_of_group;
+        let column_size = 1 << (log_size / 2);
+        let row_size = size / column_size;
+        match (EvaluationDomain::new(column_size), EvaluationDomain::new(row_size)) {
+            (Some(column_domain), Some(row_domain)) => {
+                Ok(Self { domain, column_domain, row_domain, memory_limit: memory_limit.max(1) })
+            }
+            _ => bail!("Failed to construct the subdomains of the out-of-core FFT"),
+        }
+    }
+
+    /// Returns the domain of the transform.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Computes the FFT of `input`, writing the evaluations to `output`.
+    /// Both storages must hold `self.domain().size()` elements, and `input` is overwritten.
+    pub fn fft<S: FftStorage<F>, T: FftStorage<F>>(&self, input: &mut S, output: &mut T) -> Result<()> {
+        self.four_step(input, output, false)
+    }
+
+    /// Computes the IFFT of `input`, writing the coefficients to `output`.
+    /// Both storages must hold `self.domain().size()` elements, and `input` is overwritten.
+    pub fn ifft<S: FftStorage<F>, T: FftStorage<F>>(&self, input: &mut S, output: &mut T) -> Result<()> {
+        self.four_step(input, output, true)
+    }
+
+    fn four_step<S: FftStorage<F>, T: FftStorage<F>>(
+        &self,
+        input: &mut S,
+        output: &mut T,
+        inverse: bool,
+    ) -> Result<()> {
+        let size = self.domain.size();
+        ensure!(input.len() == size, "The FFT input has {} elements, expected {size}", input.len());
+        ensure!(output.len() == size, "The FFT output has {} elements, expected {size}", output.len());
+
+        // Vectors that fit in memory and are held there are transformed directly.
+        if size <= self.memory_limit {
+            if let (Some(input), Some(output)) = (input.in_memory(), output.in_memory()) {
+                output.copy_from_slice(input);
+                match inverse {
+                    true => self.domain.in_order_ifft_in_place(output),
+                    false => self.domain.in_order_fft_in_place(output),
+                }
+                return Ok(());
+            }
+        }
+
+        let (n1, n2) = (self.column_domain.size(), self.row_domain.size());
+        let root = if inverse { self.domain.group_gen_inv } else { self.domain.group_gen };
+
+        // Step 1 and 2: transform the columns, and multiply the entry (k1, j2) by root^(j2 * k1).
+        let column_fft_pc = self.column_domain.precompute_fft();
+        let column_ifft_pc = self.column_domain.precompute_ifft();
+        let block_width = (self.memory_limit / n1).clamp(1, n2);
+        let mut columns = vec![vec![F::zero(); n1]; block_width];
+        let mut row_segment = vec![F::zero(); block_width];
+        for start in (0..n2).step_by(block_width) {
+            let width = block_width.min(n2 - start);
+            let (columns, row_segment) = (&mut columns[..width], &mut row_segment[..width]);
+            for k1 in 0..n1 {
+                input.read_range(k1 * n2 + start, row_segment)?;
+                columns.iter_mut().zip(row_segment.iter()).for_each(|(column, value)| column[k1] = *value);
+            }
+
+            cfg_iter_mut!(columns).enumerate().for_each(|(j, column)| {
+                match inverse {
+                    true => self.column_domain.in_order_ifft_in_place_with_pc(column, &column_ifft_pc),
+                    false => self.column_domain.in_order_fft_in_place_with_pc(column, &column_fft_pc),
+                }
+                let twiddle = root.pow([(start + j) as u64]);
+                let mut power = F::one();
+                for value in column.iter_mut() {
+                    *value *= power;
+                    power *= twiddle;
+                }
+            });
+
+            for k1 in 0..n1 {
+                row_segment.iter_mut().zip(columns.iter()).for_each(|(value, column)| *value = column[k1]);
+                input.write_range(k1 * n2 + start, row_segment)?;
+            }
+        }
+        drop(columns);
+
+        // Step 3 and 4: transform the rows, and write the entry (k1, k2) to index k1 + n1 * k2.
+        let row_fft_pc = self.row_domain.precompute_fft();
+        let row_ifft_pc = self.row_domain.precompute_ifft();
+        let block_height = (self.memory_limit / n2).clamp(1, n1);
+        let mut rows = vec![F::zero(); block_height * n2];
+        let mut column_segment = vec![F::zero(); block_height];
+        for start in (0..n1).step_by(block_height) {
+            let height = block_height.min(n1 - start);
+            let (rows, column_segment) = (&mut rows[..height * n2], &mut column_segment[..height]);
+            input.read_range(start * n2, rows)?;
+
+            cfg_chunks_mut!(rows, n2).for_each(|row| match inverse {
+                true => self.row_domain.in_order_ifft_in_place_with_pc(row, &row_ifft_pc),
+                false => self.row_domain.in_order_fft_in_place_with_pc(row, &row_fft_pc),
+            });
+
+            for k2 in 0..n2 {
+                column_segment.iter_mut().enumerate().for_each(|(k1, value)| *value = rows[k1 * n2 + k2]);
+                output.write_range(k2 * n1 + start, column_segment)?;
+            }
+        }
+        Ok(())
+    }
+}
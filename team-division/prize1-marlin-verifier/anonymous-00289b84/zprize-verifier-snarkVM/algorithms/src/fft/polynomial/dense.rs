@@ -16,7 +16,7 @@
 
 //! A polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{EvaluationDomain, Evaluations, FftStorage, OutOfCoreFft, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{cfg_iter_mut, serialize::*};
 
@@ -173,6 +173,34 @@ impl<F: PrimeField> DensePolynomial<F> {
         let poly: Polynomial<'_, F> = self.into();
         Polynomial::<F>::evaluate_over_domain(poly, domain)
     }
+
+    /// Evaluate `self` over the domain of `fft` with the out-of-core FFT, writing the evaluations to `output`.
+    /// The coefficients are reduced modulo the vanishing polynomial into `scratch`, which is overwritten.
+    pub fn evaluate_over_domain_with_storage<S: FftStorage<F>, T: FftStorage<F>>(
+        &self,
+        fft: &OutOfCoreFft<F>,
+        scratch: &mut S,
+        output: &mut T,
+    ) -> anyhow::Result<()> {
+        let size = fft.domain().size();
+        anyhow::ensure!(scratch.len() == size, "The scratch storage has {} elements, expected {size}", scratch.len());
+
+        let mut chunks = self.coeffs.chunks(size);
+        let first = chunks.next().unwrap_or(&[]);
+        scratch.write_range(0, first)?;
+        let zeros = vec![F::zero(); size - first.len()];
+        scratch.write_range(first.len(), &zeros)?;
+
+        // Since X^size = 1 over the domain, fold the higher coefficients onto the first `size` ones.
+        let mut buffer = Vec::with_capacity(size);
+        for chunk in chunks {
+            buffer.resize(chunk.len(), F::zero());
+            scratch.read_range(0, &mut buffer)?;
+            buffer.iter_mut().zip(chunk).for_each(|(b, c)| *b += c);
+            scratch.write_range(0, &buffer)?;
+        }
+        fft.fft(scratch, output)
+    }
 }
 
 impl<F: Field> From<super::SparsePolynomial<F>> for DensePolynomial<F> {
@@ -14,11 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, FftStorage, FileStorage, OutOfCoreFft};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
-use snarkvm_utilities::{rand::Uniform, test_rng};
+use snarkvm_utilities::{rand::Uniform, test_rng, ToBytes};
 
 #[test]
 fn vanishing_polynomial_evaluation() {
@@ -332,10 +332,72 @@ fn evaluate_over_domain() {
         let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
         for degree in [domain_size - 2, domain_size - 1, domain_size + 10] {
             let p = DensePolynomial::rand(degree, rng);
-            assert_eq!(
-                p.evaluate_over_domain_by_ref(domain).evaluations,
-                domain.elements().map(|e| p.evaluate(e)).collect::<Vec<_>>()
-            );
+            let expected = domain.elements().map(|e| p.evaluate(e)).collect::<Vec<_>>();
+            assert_eq!(p.evaluate_over_domain_by_ref(domain).evaluations, expected);
+            assert_eq!(p.evaluate_over_domain(domain).evaluations, expected);
         }
     }
 }
+
+#[test]
+fn out_of_core_fft() {
+    let rng = &mut test_rng();
+    for log_size in 0..9 {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+        let coeffs = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let evaluations = domain.fft(&coeffs);
+        // Vary the memory limit between a single row or column and the whole vector.
+        for memory_limit in [1, 5, domain.size() / 2, domain.size()] {
+            let fft = OutOfCoreFft::new(domain, memory_limit).unwrap();
+
+            let mut output = vec![Fr::zero(); domain.size()];
+            fft.fft(&mut coeffs.clone(), &mut output).unwrap();
+            assert_eq!(output, evaluations, "size = {}, memory limit = {}", domain.size(), memory_limit);
+
+            let mut output = vec![Fr::zero(); domain.size()];
+            fft.ifft(&mut evaluations.clone(), &mut output).unwrap();
+            assert_eq!(output, coeffs, "size = {}, memory limit = {}", domain.size(), memory_limit);
+        }
+    }
+}
+
+#[test]
+fn out_of_core_fft_with_file_storage() {
+    let rng = &mut test_rng();
+    let domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+    let fft = OutOfCoreFft::new(domain, 100).unwrap();
+    let directory = std::env::temp_dir().join(format!("out_of_core_fft_{}", rng.gen::<u32>()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    for degree in [domain.size() - 1, domain.size() + 10] {
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let mut scratch = FileStorage::create(directory.join("scratch"), domain.size()).unwrap();
+        let mut output = FileStorage::create(directory.join("output"), domain.size()).unwrap();
+        p.evaluate_over_domain_with_storage(&fft, &mut scratch, &mut output).unwrap();
+        assert_eq!(output.to_vec().unwrap(), p.evaluate_over_domain_by_ref(domain).evaluations);
+
+        // The output can be reopened, and mixed with in-memory storage.
+        let mut output = FileStorage::<Fr>::open(directory.join("output")).unwrap();
+        assert_eq!(output.len(), domain.size());
+        let mut coeffs = vec![Fr::zero(); domain.size()];
+        fft.ifft(&mut output, &mut coeffs).unwrap();
+        let mut expected = p.coeffs().to_vec();
+        for (i, c) in p.coeffs().iter().enumerate().skip(domain.size()) {
+            expected[i % domain.size()] += c;
+        }
+        expected.truncate(domain.size());
+        assert_eq!(coeffs, expected);
+    }
+
+    // The storage maps the file, so its writes reach the file.
+    let values = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let storage = FileStorage::from_slice(directory.join("values"), &values).unwrap();
+    storage.flush().unwrap();
+    let expected = values.iter().flat_map(|value| value.to_bytes_le().unwrap()).collect::<Vec<_>>();
+    assert_eq!(std::fs::read(directory.join("values")).unwrap(), expected);
+
+    // Mixed-radix domains and mismatched storage sizes are rejected.
+    assert!(OutOfCoreFft::new(EvaluationDomain::<Fr>::new_mixed_radix(3).unwrap(), 100).is_err());
+    assert!(fft.fft(&mut vec![Fr::zero(); 3], &mut vec![Fr::zero(); domain.size()]).is_err());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
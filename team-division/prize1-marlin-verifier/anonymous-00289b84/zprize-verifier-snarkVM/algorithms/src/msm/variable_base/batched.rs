@@ -16,7 +16,7 @@
 
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_into_iter, BigInteger};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, BigInteger};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -364,14 +364,45 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    msm_with_num_bits(bases, scalars, <G::ScalarField as PrimeField>::size_in_bits())
+}
+
+/// Computes the MSM with the GLV endomorphism of the curve. Every scalar is split into two halves
+/// of at most 128 bits, so that twice as many bases are summed over half as many windows.
+/// Returns `None` if the curve has no GLV endomorphism, or if a scalar is not reduced.
+pub fn msm_glv<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Option<G::Projective> {
+    let to_bigint = |(is_negative, scalar): (bool, u128), base: G| {
+        let mut bigint = <G::ScalarField as PrimeField>::BigInteger::default();
+        bigint.as_mut()[0] = scalar as u64;
+        bigint.as_mut()[1] = (scalar >> 64) as u64;
+        (if is_negative { -base } else { base }, bigint)
+    };
+    let halves = cfg_iter!(bases)
+        .zip(scalars)
+        .map(|(base, scalar)| {
+            let [k1, k2] = G::glv_decomposition(&G::ScalarField::from_repr(*scalar)?)?;
+            Some([to_bigint(k1, *base), to_bigint(k2, base.glv_endomorphism()?)])
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (glv_bases, glv_scalars): (Vec<_>, Vec<_>) = halves.into_iter().flatten().unzip();
+    Some(msm_with_num_bits(&glv_bases, &glv_scalars, 128))
+}
+
+/// Computes the MSM over the lowest `num_bits` bits of the scalars.
+fn msm_with_num_bits<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    num_bits: usize,
+) -> G::Projective {
     // Determine the bucket size `c` (chosen empirically).
     let c = match scalars.len() < 32 {
         true => 1,
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
-
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
     // in parallel process each such window.
@@ -390,3 +421,152 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         total
     }) + lowest.0
 }
+
+#[inline]
+fn batched_multi_window<G: AffineCurve>(
+    bases: &[G],
+    scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    negations: &[Vec<bool>],
+    w_start: usize,
+    c: usize,
+) -> (Vec<G::Projective>, usize) {
+    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets per scalar vector and sign.
+    let window_size = if (w_start % c) != 0 { w_start % c } else { c };
+    let num_buckets = (1 << window_size) - 1;
+    let num_signs = if negations.is_empty() { 1 } else { 2 };
+
+    // The digits of every scalar vector are sorted into one set of buckets,
+    // so that all vectors share the batched additions.
+    let mut bucket_positions = Vec::with_capacity(scalar_sets.iter().map(|scalars| scalars.len()).sum());
+    for (k, scalars) in scalar_sets.iter().enumerate() {
+        for (scalar_index, &scalar) in scalars.iter().take(bases.len()).enumerate() {
+            let mut scalar = scalar;
+
+            // We right-shift by w_start, thus getting rid of the lower bits.
+            scalar.divn(w_start as u32);
+
+            // We mod the remaining bits by the window size.
+            let digit = (scalar.as_ref()[0] % (1 << c)) as usize;
+
+            if digit != 0 {
+                let sign = negations.get(k).map_or(0, |negations| negations[scalar_index] as usize);
+                let bucket_index = (k * num_signs + sign) * num_buckets + digit - 1;
+                bucket_positions
+                    .push(BucketPosition { bucket_index: bucket_index as u32, scalar_index: scalar_index as u32 });
+            }
+        }
+    }
+
+    let buckets = batch_add(num_buckets * num_signs * scalar_sets.len(), bases, &mut bucket_positions);
+
+    let sums = buckets
+        .chunks(num_buckets)
+        .map(|buckets| {
+            let mut res = G::Projective::zero();
+            let mut running_sum = G::Projective::zero();
+            for b in buckets.iter().rev() {
+                running_sum.add_assign_mixed(b);
+                res += &running_sum;
+            }
+            res
+        })
+        .collect::<Vec<_>>();
+    let res = match num_signs {
+        1 => sums,
+        _ => sums.chunks(2).map(|sums| sums[0] - sums[1]).collect(),
+    };
+
+    (res, window_size)
+}
+
+/// Computes the MSM of each of `scalar_sets` over the same `bases`. Every window decomposes all
+/// of the scalar vectors at once and sorts their digits into a single set of buckets.
+pub fn multi_msm<G: AffineCurve>(
+    bases: &[G],
+    scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+) -> Vec<G::Projective> {
+    multi_msm_with_num_bits(bases, scalar_sets, &[], <G::ScalarField as PrimeField>::size_in_bits())
+}
+
+/// Computes the MSM of each of `scalar_sets` over the same `bases` with the GLV endomorphism of the curve.
+/// The bases and their endomorphisms are shared by every scalar vector, while the signs of the
+/// scalar halves select separate buckets. Returns `None` if the curve has no GLV endomorphism,
+/// or if a scalar is not reduced.
+pub fn multi_msm_glv<G: AffineCurve>(
+    bases: &[G],
+    scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+) -> Option<Vec<G::Projective>> {
+    // Check for the endomorphism even if there are no bases.
+    G::glv_decomposition(&G::ScalarField::zero())?;
+    let glv_bases = cfg_iter!(bases)
+        .map(|base| Some([*base, base.glv_endomorphism()?]))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let to_bigint = |scalar: u128| {
+        let mut bigint = <G::ScalarField as PrimeField>::BigInteger::default();
+        bigint.as_mut()[0] = scalar as u64;
+        bigint.as_mut()[1] = (scalar >> 64) as u64;
+        bigint
+    };
+    let mut glv_scalar_sets = Vec::with_capacity(scalar_sets.len());
+    let mut negations = Vec::with_capacity(scalar_sets.len());
+    for scalars in scalar_sets {
+        let halves = cfg_iter!(scalars)
+            .take(bases.len())
+            .map(|scalar| G::glv_decomposition(&G::ScalarField::from_repr(*scalar)?))
+            .collect::<Option<Vec<_>>>()?;
+        let (signs, scalars): (Vec<_>, Vec<_>) =
+            halves.into_iter().flatten().map(|(is_negative, scalar)| (is_negative, to_bigint(scalar))).unzip();
+        negations.push(signs);
+        glv_scalar_sets.push(scalars);
+    }
+    let glv_scalar_sets = glv_scalar_sets.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Some(multi_msm_with_num_bits(&glv_bases, &glv_scalar_sets, &negations, 128))
+}
+
+/// Computes the MSM of each of `scalar_sets` over the lowest `num_bits` bits of the scalars.
+/// If `negations` is non-empty, then `negations[k][i]` indicates that `scalar_sets[k][i]` multiplies `-bases[i]`.
+fn multi_msm_with_num_bits<G: AffineCurve>(
+    bases: &[G],
+    scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    negations: &[Vec<bool>],
+    num_bits: usize,
+) -> Vec<G::Projective> {
+    let max_scalars = scalar_sets.iter().map(|scalars| scalars.len().min(bases.len())).max().unwrap_or(0);
+    if max_scalars == 0 {
+        return vec![G::Projective::zero(); scalar_sets.len()];
+    }
+
+    // Determine the bucket size `c` (chosen empirically).
+    let c = match max_scalars < 32 {
+        true => 1,
+        false => crate::msm::ln_without_floats(max_scalars) + 2,
+    };
+
+    // Each window is of size `c`.
+    // We divide up the bits 0..num_bits into windows of size `c`, and
+    // in parallel process each such window.
+    let window_sums: Vec<_> = cfg_into_iter!(0..num_bits)
+        .step_by(c)
+        .map(|w_start| batched_multi_window(bases, scalar_sets, negations, w_start, c))
+        .collect();
+
+    // We store the sums for the lowest window.
+    let (lowest, window_sums) = window_sums.split_first().unwrap();
+
+    // We're traversing windows from high to low.
+    let mut totals = vec![G::Projective::zero(); scalar_sets.len()];
+    for (sums, window_size) in window_sums.iter().rev() {
+        for (total, sum_i) in totals.iter_mut().zip(sums) {
+            *total += sum_i;
+            for _ in 0..*window_size {
+                total.double_in_place();
+            }
+        }
+    }
+    totals.iter_mut().zip(&lowest.0).for_each(|(total, lowest)| *total += lowest);
+    totals
+}
@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{batched, standard};
+use crate::errors::MsmError;
+use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_fields::PrimeField;
+
+use core::any::{Any, TypeId};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// The registries installed for each curve, keyed by the type of its affine points.
+static REGISTRIES: Lazy<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>> = Lazy::new(Default::default);
+
+/// What an MSM engine reports it can compute on a given curve.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MsmCapabilities {
+    /// Whether the engine can compute MSMs on the curve.
+    pub supports_curve: bool,
+    /// The smallest number of bases the engine accepts.
+    pub min_bases: usize,
+    /// Whether the engine only accepts the bases it was prepared with.
+    pub fixed_bases: bool,
+    /// Whether the engine runs on an external accelerator.
+    pub accelerated: bool,
+}
+
+impl MsmCapabilities {
+    /// Returns the capabilities of an engine that runs on the CPU for any bases of the curve.
+    pub const fn any_bases() -> Self {
+        Self { supports_curve: true, min_bases: 0, fixed_bases: false, accelerated: false }
+    }
+}
+
+/// An algorithm for variable base multi-scalar multiplication on the curve with affine points `G`.
+pub trait MsmEngine<G: AffineCurve>: Send + Sync {
+    /// Returns the name of the engine.
+    fn name(&self) -> &'static str;
+
+    /// Returns what the engine can compute on this curve.
+    fn capabilities(&self) -> MsmCapabilities;
+
+    /// Returns `true` if the engine can compute an MSM over the given bases.
+    fn supports(&self, bases: &[G]) -> bool {
+        let capabilities = self.capabilities();
+        capabilities.supports_curve && bases.len() >= capabilities.min_bases
+    }
+
+    /// Returns the sum of `scalars[i] * bases[i]`, ignoring any excess bases or scalars.
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError>;
+
+    /// Returns the MSM of each of `scalar_sets` over the same `bases`.
+    /// By default, this computes one MSM per scalar vector.
+    fn multi_msm(
+        &self,
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>, MsmError> {
+        scalar_sets.iter().map(|scalars| self.msm(bases, scalars)).collect()
+    }
+}
+
+/// Pippenger's algorithm, with bucket sums in projective coordinates.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StandardEngine;
+
+impl<G: AffineCurve> MsmEngine<G> for StandardEngine {
+    fn name(&self) -> &'static str {
+        "standard"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        MsmCapabilities::any_bases()
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        Ok(standard::msm(bases, scalars))
+    }
+}
+
+/// Pippenger's algorithm, with bucket sums computed by batched additions in affine coordinates.
+/// Every base is expected to be a finite point.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct BatchedEngine;
+
+impl<G: AffineCurve> MsmEngine<G> for BatchedEngine {
+    fn name(&self) -> &'static str {
+        "batched"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        MsmCapabilities::any_bases()
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        Ok(batched::msm(bases, scalars))
+    }
+
+    fn multi_msm(
+        &self,
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>, MsmError> {
+        Ok(batched::multi_msm(bases, scalar_sets))
+    }
+}
+
+/// Batched affine additions on the scalars split in half by the GLV endomorphism of the curve,
+/// which halves the number of windows. Every base is expected to be a finite point.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GlvEngine;
+
+impl<G: AffineCurve> MsmEngine<G> for GlvEngine {
+    fn name(&self) -> &'static str {
+        "glv"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        MsmCapabilities {
+            supports_curve: G::prime_subgroup_generator().glv_endomorphism().is_some(),
+            ..MsmCapabilities::any_bases()
+        }
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        batched::msm_glv(bases, scalars).ok_or(MsmError::Unsupported("glv", bases.len()))
+    }
+
+    fn multi_msm(
+        &self,
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>, MsmError> {
+        batched::multi_msm_glv(bases, scalar_sets).ok_or(MsmError::Unsupported("glv", bases.len()))
+    }
+}
+
+/// The CUDA kernels, which support BLS12-377 G1 only.
+/// Once a call fails, the engine stops reporting support for any curve.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[derive(Debug, Default)]
+pub struct CudaEngine {
+    has_failed: AtomicBool,
+}
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+impl<G: AffineCurve> MsmEngine<G> for CudaEngine {
+    fn name(&self) -> &'static str {
+        "cuda"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        let is_g1 = TypeId::of::<G>() == TypeId::of::<G1Affine>();
+        MsmCapabilities {
+            supports_curve: is_g1 && !self.has_failed.load(Ordering::SeqCst),
+            min_bases: 0,
+            fixed_bases: false,
+            accelerated: true,
+        }
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        if !<Self as MsmEngine<G>>::supports(self, bases) {
+            return Err(MsmError::Unsupported("cuda", bases.len()));
+        }
+        super::cuda::msm_cuda(bases, scalars).map_err(|error| {
+            self.has_failed.store(true, Ordering::SeqCst);
+            MsmError::EngineFailure("cuda", format!("{:?}", error))
+        })
+    }
+}
+
+/// An ordered list of MSM engines. Each MSM runs on the first engine that supports its bases,
+/// and falls back to the next supporting engine if it fails; if they all fail, their errors are returned together.
+pub struct MsmRegistry<G: AffineCurve> {
+    engines: Vec<Arc<dyn MsmEngine<G>>>,
+}
+
+impl<G: AffineCurve> MsmRegistry<G> {
+    /// Returns a registry with no engines.
+    pub fn new() -> Self {
+        Self { engines: Vec::new() }
+    }
+
+    /// Appends `engine`, to be tried after the engines already in the registry.
+    pub fn with_engine(mut self, engine: impl MsmEngine<G> + 'static) -> Self {
+        self.engines.push(Arc::new(engine));
+        self
+    }
+
+    /// Inserts `engine`, to be tried before the engines already in the registry.
+    pub fn with_first_engine(mut self, engine: impl MsmEngine<G> + 'static) -> Self {
+        self.engines.insert(0, Arc::new(engine));
+        self
+    }
+
+    /// Returns the engines, in the order they are tried.
+    pub fn engines(&self) -> &[Arc<dyn MsmEngine<G>>] {
+        &self.engines
+    }
+
+    /// Returns the engine that an MSM over `bases` is first tried on.
+    pub fn select(&self, bases: &[G]) -> Option<&dyn MsmEngine<G>> {
+        self.engines.iter().map(|engine| &**engine).find(|engine| engine.supports(bases))
+    }
+
+    /// Installs this registry for every MSM on this curve that is not handed an engine,
+    /// including those in `KZG10::commit` and in the Marlin prover.
+    pub fn install(self) {
+        REGISTRIES.write().insert(TypeId::of::<G>(), Arc::new(Arc::new(self)));
+    }
+
+    /// Returns the installed registry for this curve, or installs and returns the default one.
+    pub fn current() -> Arc<Self> {
+        let registry = REGISTRIES.read().get(&TypeId::of::<G>()).cloned();
+        let registry = match registry {
+            Some(registry) => registry,
+            None => REGISTRIES
+                .write()
+                .entry(TypeId::of::<G>())
+                .or_insert_with(|| Arc::new(Arc::new(Self::default())))
+                .clone(),
+        };
+        registry.downcast_ref::<Arc<Self>>().expect("The MSM registry was installed for another curve").clone()
+    }
+}
+
+impl<G: AffineCurve> Default for MsmRegistry<G> {
+    /// Returns the registry that tries the CUDA kernels if enabled, then batched affine additions
+    /// with and without the GLV endomorphism on BLS12-377 G1, then projective additions. The batched
+    /// engines are left out for the other curves, whose callers may pass bases at infinity; they can
+    /// still be handed to them directly.
+    fn default() -> Self {
+        let registry = Self::new();
+        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+        let registry = registry.with_engine(CudaEngine::default());
+        let registry = match TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            true => registry.with_engine(GlvEngine).with_engine(BatchedEngine),
+            false => registry,
+        };
+        registry.with_engine(StandardEngine)
+    }
+}
+
+impl<G: AffineCurve> Clone for MsmRegistry<G> {
+    fn clone(&self) -> Self {
+        Self { engines: self.engines.clone() }
+    }
+}
+
+impl<G: AffineCurve> MsmEngine<G> for MsmRegistry<G> {
+    fn name(&self) -> &'static str {
+        "registry"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        let mut capabilities = MsmCapabilities { supports_curve: false, min_bases: usize::MAX, ..Default::default() };
+        for engine in self.engines.iter().map(|engine| engine.capabilities()).filter(|c| c.supports_curve) {
+            capabilities.supports_curve = true;
+            capabilities.min_bases = capabilities.min_bases.min(engine.min_bases);
+            capabilities.fixed_bases |= engine.fixed_bases;
+            capabilities.accelerated |= engine.accelerated;
+        }
+        capabilities
+    }
+
+    fn supports(&self, bases: &[G]) -> bool {
+        self.select(bases).is_some()
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        let mut errors = Vec::new();
+        for engine in self.engines.iter().filter(|engine| engine.supports(bases)) {
+            match engine.msm(bases, scalars) {
+                Ok(result) => return Ok(result),
+                Err(error) => errors.push(error),
+            }
+        }
+        match errors.is_empty() {
+            true => Err(MsmError::NoSupportedEngine(bases.len())),
+            false => Err(MsmError::AllEnginesFailed(errors)),
+        }
+    }
+
+    fn multi_msm(
+        &self,
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>, MsmError> {
+        let mut errors = Vec::new();
+        for engine in self.engines.iter().filter(|engine| engine.supports(bases)) {
+            match engine.multi_msm(bases, scalar_sets) {
+                Ok(result) => return Ok(result),
+                Err(error) => errors.push(error),
+            }
+        }
+        match errors.is_empty() {
+            true => Err(MsmError::NoSupportedEngine(bases.len())),
+            false => Err(MsmError::AllEnginesFailed(errors)),
+        }
+    }
+}
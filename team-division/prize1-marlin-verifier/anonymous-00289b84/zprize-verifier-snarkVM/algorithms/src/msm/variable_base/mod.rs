@@ -20,42 +20,47 @@ mod standard;
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
 
+pub mod engine;
+pub use engine::*;
+
+pub mod precomputed;
+pub use precomputed::*;
+
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use crate::errors::MsmError;
+use snarkvm_curves::traits::AffineCurve;
 use snarkvm_fields::PrimeField;
 
-use core::any::TypeId;
-
-#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-use core::sync::atomic::{AtomicBool, Ordering};
-
-#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-static HAS_CUDA_FAILED: AtomicBool = AtomicBool::new(false);
-
 pub struct VariableBase;
 
 impl VariableBase {
+    /// Computes the MSM on the installed registry of engines for this curve.
+    /// If every engine in the registry fails, it falls back to Pippenger's algorithm.
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-        // For BLS12-377, we perform variable base MSM using a batched addition technique.
-        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
-            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
-                match cuda::msm_cuda(bases, scalars) {
-                    Ok(x) => return x,
-                    Err(_e) => {
-                        HAS_CUDA_FAILED.store(true, Ordering::SeqCst);
-                        eprintln!("CUDA failed, moving to the next MSM method");
-                    }
-                }
-            }
-            batched::msm(bases, scalars)
-        }
-        // For all other curves, we perform variable base MSM using Pippenger's algorithm.
-        else {
-            standard::msm(bases, scalars)
-        }
+        MsmRegistry::<G>::current().msm(bases, scalars).unwrap_or_else(|_| standard::msm(bases, scalars))
+    }
+
+    /// Computes the MSM of each of `scalar_sets` over the same `bases` on the installed registry of engines
+    /// for this curve. The batched engines decompose the scalar vectors and sort their digits into buckets together.
+    /// If every engine in the registry fails, it falls back to one run of Pippenger's algorithm per scalar vector.
+    pub fn multi_msm<G: AffineCurve>(
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Vec<G::Projective> {
+        MsmRegistry::<G>::current()
+            .multi_msm(bases, scalar_sets)
+            .unwrap_or_else(|_| scalar_sets.iter().map(|scalars| standard::msm(bases, scalars)).collect())
+    }
+
+    /// Computes the MSM on the given engine.
+    pub fn msm_with<G: AffineCurve>(
+        engine: &dyn MsmEngine<G>,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        engine.msm(bases, scalars)
     }
 
     #[cfg(test)]
@@ -81,10 +86,14 @@ impl VariableBase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use snarkvm_curves::bls12_377::{Fr, G1Affine};
-    use snarkvm_fields::PrimeField;
-    use snarkvm_utilities::rand::test_rng;
+    use snarkvm_curves::{
+        bls12_377::{Fr, G1Affine, G2Affine},
+        edwards_bls12::EdwardsAffine,
+    };
+    use snarkvm_fields::{PrimeField, Zero};
+    use snarkvm_utilities::{rand::test_rng, CanonicalDeserialize, CanonicalSerialize};
 
+    use core::any::TypeId;
     use rand_xorshift::XorShiftRng;
 
     fn create_scalar_bases<G: AffineCurve<ScalarField = F>, F: PrimeField>(
@@ -110,6 +119,156 @@ mod tests {
 
         let candidate = batched::msm(bases.as_slice(), scalars.as_slice());
         assert_eq!(naive_a, candidate);
+
+        let candidate = batched::msm_glv(bases.as_slice(), scalars.as_slice()).unwrap();
+        assert_eq!(naive_a, candidate);
+    }
+
+    /// An engine that fails on every call.
+    struct FailingEngine;
+
+    impl<G: AffineCurve> MsmEngine<G> for FailingEngine {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        fn capabilities(&self) -> MsmCapabilities {
+            MsmCapabilities::any_bases()
+        }
+
+        fn msm(
+            &self,
+            _bases: &[G],
+            _scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        ) -> Result<G::Projective, MsmError> {
+            Err(MsmError::EngineFailure("failing", "always fails".to_string()))
+        }
+    }
+
+    fn check_engines<G: AffineCurve>(rng: &mut XorShiftRng) {
+        let (bases, scalars) = create_scalar_bases::<G, G::ScalarField>(rng, 1000);
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice());
+
+        for engine in [&StandardEngine as &dyn MsmEngine<G>, &BatchedEngine, &MsmRegistry::<G>::default()] {
+            assert_eq!(naive, VariableBase::msm_with(engine, bases.as_slice(), scalars.as_slice()).unwrap());
+        }
+        // Only the BLS12-377 curves have a GLV endomorphism.
+        let candidate = VariableBase::msm_with(&GlvEngine, bases.as_slice(), scalars.as_slice());
+        match TypeId::of::<G>() == TypeId::of::<EdwardsAffine>() {
+            true => assert!(candidate.is_err()),
+            false => assert_eq!(naive, candidate.unwrap()),
+        }
+        assert_eq!(naive, VariableBase::msm(bases.as_slice(), scalars.as_slice()));
+    }
+
+    fn check_multi_msm<G: AffineCurve>(rng: &mut XorShiftRng) {
+        let (bases, scalars) = create_scalar_bases::<G, G::ScalarField>(rng, 600);
+        let (_, other_scalars) = create_scalar_bases::<G, G::ScalarField>(rng, 700);
+        let scalar_sets = [&scalars[..], &scalars[..37], &[], &other_scalars[..]];
+        // The excess scalars of the last vector are ignored.
+        let naive = scalar_sets
+            .iter()
+            .map(|scalars| scalars.len().min(bases.len()))
+            .zip(&scalar_sets)
+            .map(|(len, scalars)| VariableBase::msm_naive(&bases[..len], &scalars[..len]))
+            .collect::<Vec<_>>();
+
+        for engine in [&StandardEngine as &dyn MsmEngine<G>, &BatchedEngine, &MsmRegistry::<G>::default()] {
+            assert_eq!(naive, engine.multi_msm(&bases, &scalar_sets).unwrap());
+        }
+        let candidate = GlvEngine.multi_msm(&bases, &scalar_sets);
+        match TypeId::of::<G>() == TypeId::of::<EdwardsAffine>() {
+            true => assert!(candidate.is_err()),
+            false => assert_eq!(naive, candidate.unwrap()),
+        }
+        assert_eq!(naive, VariableBase::multi_msm(&bases, &scalar_sets));
+        assert!(VariableBase::multi_msm::<G>(&bases, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_multi_msm() {
+        let mut rng = test_rng();
+        check_multi_msm::<G1Affine>(&mut rng);
+        check_multi_msm::<G2Affine>(&mut rng);
+        check_multi_msm::<EdwardsAffine>(&mut rng);
+    }
+
+    #[test]
+    fn test_msm_engines() {
+        let mut rng = test_rng();
+        check_engines::<G1Affine>(&mut rng);
+        check_engines::<G2Affine>(&mut rng);
+        check_engines::<EdwardsAffine>(&mut rng);
+    }
+
+    #[test]
+    fn test_msm_registry() {
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 100);
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice());
+
+        // The engines are tried in order, falling back on failure.
+        let registry = MsmRegistry::new().with_engine(FailingEngine).with_engine(StandardEngine);
+        assert_eq!(registry.select(&bases).unwrap().name(), "failing");
+        assert_eq!(naive, registry.msm(bases.as_slice(), scalars.as_slice()).unwrap());
+
+        // Every failure is returned once no engine is left.
+        let registry = MsmRegistry::<G1Affine>::new().with_engine(FailingEngine).with_engine(FailingEngine);
+        match registry.msm(&bases, &scalars) {
+            Err(MsmError::AllEnginesFailed(errors)) => {
+                assert_eq!(errors.len(), 2);
+                assert!(errors.iter().all(|error| matches!(error, MsmError::EngineFailure("failing", _))));
+            }
+            result => panic!("Expected every engine to fail, found {:?}", result.map(|_| ())),
+        }
+        let registry = MsmRegistry::<G1Affine>::new();
+        assert!(matches!(registry.msm(&bases, &scalars), Err(MsmError::NoSupportedEngine(100))));
+
+        // The batched engine is only a default for BLS12-377 G1.
+        let g1_registry = MsmRegistry::<G1Affine>::default();
+        let g1_names = g1_registry.engines().iter().map(|engine| engine.name()).collect::<Vec<_>>();
+        assert!(g1_names.ends_with(&["glv", "batched", "standard"]));
+        let g2_registry = MsmRegistry::<G2Affine>::default();
+        let g2_names = g2_registry.engines().iter().map(|engine| engine.name()).collect::<Vec<_>>();
+        assert!(g2_names.ends_with(&["standard"]) && !g2_names.contains(&"batched") && !g2_names.contains(&"glv"));
+    }
+
+    #[test]
+    fn test_precomputed_bases() {
+        let mut rng = test_rng();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 300);
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice());
+        let naive_offset = VariableBase::msm_naive(&bases[50..250], &scalars[50..250]);
+
+        for window_bits in [4, 8, 13] {
+            let precomputed = PrecomputedBases::new(&bases, window_bits).unwrap();
+            assert_eq!(precomputed.len(), bases.len());
+            assert_eq!(naive, precomputed.msm(&bases, &scalars).unwrap());
+            assert_eq!(Some(50), precomputed.offset_of(&bases[50..250]));
+            assert_eq!(naive_offset, precomputed.msm(&bases[50..250], &scalars[50..250]).unwrap());
+
+            let registry = MsmRegistry::default().with_first_engine(precomputed);
+            assert_eq!(naive, registry.msm(&bases, &scalars).unwrap());
+        }
+
+        // Bases that were not precomputed are rejected.
+        let precomputed = PrecomputedBases::new(&bases[..100], 8).unwrap();
+        let (other_bases, _) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 100);
+        assert!(!precomputed.supports(&other_bases));
+        assert!(precomputed.msm(&other_bases, &scalars[..100]).is_err());
+        assert!(!precomputed.supports(&bases[50..150]));
+        assert!(matches!(PrecomputedBases::new(&bases, 0), Err(MsmError::UnsupportedWindowSize(0))));
+        let result = PrecomputedBases::new(&bases, MAX_WINDOW_BITS + 1);
+        assert!(matches!(result, Err(MsmError::UnsupportedWindowSize(bits)) if bits == MAX_WINDOW_BITS + 1));
+        let result = PrecomputedBases::new(&[bases[0], G1Affine::zero()], 8);
+        assert!(matches!(result, Err(MsmError::BaseAtInfinity)));
+
+        // The tables can be cached and reloaded.
+        let mut bytes = Vec::new();
+        precomputed.serialize_compressed(&mut bytes).unwrap();
+        let precomputed = PrecomputedBases::<G1Affine>::deserialize_compressed(&bytes[..]).unwrap();
+        let naive = VariableBase::msm_naive(&bases[..100], &scalars[..100]);
+        assert_eq!(naive, precomputed.msm(&bases[..100], &scalars[..100]).unwrap());
     }
 
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
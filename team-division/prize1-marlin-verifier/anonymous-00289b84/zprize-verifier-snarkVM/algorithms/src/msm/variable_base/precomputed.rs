@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    batched::{batch_add, BucketPosition},
+    MsmCapabilities,
+    MsmEngine,
+};
+use crate::errors::MsmError;
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    cfg_chunks,
+    cfg_iter,
+    io::{Read, Write},
+    serialize::*,
+    BigInteger,
+};
+
+use std::collections::HashMap;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The largest supported window size, in bits.
+pub const MAX_WINDOW_BITS: usize = 24;
+
+/// A fixed list of bases, together with the multiples `bases[i] * 2^{j * c}` for every window `j`
+/// of `c` bits in a scalar. An MSM over these bases is then a sum of the scalar digits times the
+/// precomputed multiples, and needs a single set of buckets and no doublings.
+///
+/// The window size `c` trades memory for speed: the tables hold `ceil(num_bits / c)` points per base,
+/// while each MSM sums `2^c - 1` buckets.
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The size of a window, in bits.
+    window_bits: usize,
+    /// The number of windows in a scalar.
+    num_windows: usize,
+    /// The multiples of each base, with `bases[i] * 2^{j * c}` at index `i * num_windows + j`.
+    tables: Vec<G>,
+    /// The index of the first occurrence of each base.
+    index: HashMap<G, usize>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Precomputes the multiples of `bases` for windows of `window_bits` bits.
+    /// Every base is expected to be a finite point.
+    pub fn new(bases: &[G], window_bits: usize) -> Result<Self, MsmError> {
+        if window_bits == 0 || window_bits > MAX_WINDOW_BITS {
+            return Err(MsmError::UnsupportedWindowSize(window_bits));
+        }
+        if bases.iter().any(|base| base.is_zero()) {
+            return Err(MsmError::BaseAtInfinity);
+        }
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + window_bits - 1) / window_bits;
+        // The multiples are addressed by `u32` indices in the buckets.
+        if u32::try_from(bases.len() * num_windows).is_err() {
+            return Err(MsmError::TooManyBases(bases.len()));
+        }
+
+        let multiples = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut multiple = base.to_projective();
+                (0..num_windows)
+                    .map(|_| {
+                        let current = multiple;
+                        (0..window_bits).for_each(|_| multiple.double_in_place());
+                        current
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let tables = G::Projective::batch_normalization_into_affine(multiples);
+        Ok(Self::from_tables(window_bits, num_windows, tables))
+    }
+
+    /// Returns the precomputed bases for the given tables.
+    fn from_tables(window_bits: usize, num_windows: usize, tables: Vec<G>) -> Self {
+        let mut index = HashMap::with_capacity(tables.len() / num_windows);
+        for (i, base) in tables.iter().step_by(num_windows).enumerate() {
+            index.entry(*base).or_insert(i);
+        }
+        Self { window_bits, num_windows, tables, index }
+    }
+
+    /// Returns the number of bases.
+    pub fn len(&self) -> usize {
+        self.tables.len() / self.num_windows
+    }
+
+    /// Returns `true` if there are no bases.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Returns the size of a window, in bits.
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// Returns the bases.
+    pub fn bases(&self) -> impl Iterator<Item = &G> {
+        self.tables.iter().step_by(self.num_windows)
+    }
+
+    /// Returns the position of `bases` in the precomputed bases, if they form a contiguous run of them.
+    pub fn offset_of(&self, bases: &[G]) -> Option<usize> {
+        let offset = match bases.first() {
+            Some(first) => *self.index.get(first)?,
+            None => 0,
+        };
+        let matches = offset + bases.len() <= self.len() && self.bases().skip(offset).zip(bases).all(|(a, b)| a == b);
+        match matches {
+            true => Some(offset),
+            false => None,
+        }
+    }
+
+    /// Returns the sum of `scalars[i] * bases[offset + i]`.
+    fn msm_at(
+        &self,
+        offset: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        let scalars = &scalars[..scalars.len().min(self.len() - offset)];
+        if scalars.is_empty() {
+            return Ok(G::Projective::zero());
+        }
+
+        // Split the scalars so that each chunk fills its buckets a few times over.
+        #[cfg(feature = "parallel")]
+        let num_threads = snarkvm_utilities::max_available_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_threads = 1;
+        let num_buckets = (1 << self.window_bits) - 1;
+        let num_chunks = num_threads.min(scalars.len() * self.num_windows / num_buckets).max(1);
+        let chunk_size = (scalars.len() + num_chunks - 1) / num_chunks;
+
+        cfg_chunks!(scalars, chunk_size)
+            .enumerate()
+            .map(|(chunk, scalars)| {
+                let start = offset + chunk * chunk_size;
+                let mut bucket_positions = Vec::with_capacity(scalars.len() * self.num_windows);
+                for (i, scalar) in scalars.iter().enumerate() {
+                    let mut scalar = *scalar;
+                    for j in 0..self.num_windows {
+                        let digit = scalar.as_ref()[0] as usize & num_buckets;
+                        scalar.divn(self.window_bits as u32);
+                        if digit != 0 {
+                            let scalar_index = u32::try_from((start + i) * self.num_windows + j)
+                                .map_err(|_| MsmError::TooManyBases(self.len()))?;
+                            bucket_positions.push(BucketPosition { bucket_index: (digit - 1) as u32, scalar_index });
+                        }
+                    }
+                }
+                if bucket_positions.is_empty() {
+                    return Ok(G::Projective::zero());
+                }
+
+                let buckets = batch_add(num_buckets, &self.tables, &mut bucket_positions);
+                let mut res = G::Projective::zero();
+                let mut running_sum = G::Projective::zero();
+                for b in buckets.into_iter().rev() {
+                    running_sum.add_assign_mixed(&b);
+                    res += &running_sum;
+                }
+                Ok(res)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|sums| sums.into_iter().sum())
+    }
+}
+
+impl<G: AffineCurve> MsmEngine<G> for PrecomputedBases<G> {
+    fn name(&self) -> &'static str {
+        "precomputed"
+    }
+
+    fn capabilities(&self) -> MsmCapabilities {
+        MsmCapabilities { fixed_bases: true, ..MsmCapabilities::any_bases() }
+    }
+
+    fn supports(&self, bases: &[G]) -> bool {
+        self.offset_of(bases).is_some()
+    }
+
+    fn msm(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Result<G::Projective, MsmError> {
+        let offset = self.offset_of(bases).ok_or(MsmError::Unsupported("precomputed", bases.len()))?;
+        self.msm_at(offset, &scalars[..scalars.len().min(bases.len())])
+    }
+
+    fn multi_msm(
+        &self,
+        bases: &[G],
+        scalar_sets: &[&[<G::ScalarField as PrimeField>::BigInteger]],
+    ) -> Result<Vec<G::Projective>, MsmError> {
+        let offset = self.offset_of(bases).ok_or(MsmError::Unsupported("precomputed", bases.len()))?;
+        scalar_sets.iter().map(|scalars| self.msm_at(offset, &scalars[..scalars.len().min(bases.len())])).collect()
+    }
+}
+
+impl<G: AffineCurve> CanonicalSerialize for PrecomputedBases<G> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, mode: Compress) -> Result<(), SerializationError> {
+        self.window_bits.serialize_with_mode(&mut writer, mode)?;
+        self.tables.serialize_with_mode(&mut writer, mode)
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        self.window_bits.serialized_size(mode) + self.tables.serialized_size(mode)
+    }
+}
+
+impl<G: AffineCurve> Valid for PrecomputedBases<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.tables.check()
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for PrecomputedBases<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window_bits: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if window_bits == 0 || window_bits > MAX_WINDOW_BITS {
+            return Err(SerializationError::InvalidData);
+        }
+        let num_windows = (<G::ScalarField as PrimeField>::size_in_bits() + window_bits - 1) / window_bits;
+        let tables: Vec<G> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if tables.len() % num_windows != 0
+            || u32::try_from(tables.len()).is_err()
+            || tables.iter().any(|base| base.is_zero())
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self::from_tables(window_bits, num_windows, tables))
+    }
+}
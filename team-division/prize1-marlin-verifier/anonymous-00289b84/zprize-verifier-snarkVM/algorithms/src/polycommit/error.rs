@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::FiatShamirError;
+use crate::{errors::MsmError, snark::marlin::FiatShamirError};
 
 /// The error type for `PolynomialCommitment`.
 #[derive(Debug)]
@@ -95,6 +95,31 @@ pub enum PCError {
         label: String,
     },
 
+    /// The number of evaluation proofs does not match the number of query points.
+    IncorrectNumberOfProofs {
+        /// The number of evaluation proofs provided.
+        num_proofs: usize,
+        /// The number of distinct points in the query set.
+        num_points: usize,
+    },
+
+    /// The keys were trimmed from universal parameters of different maximum degrees.
+    MismatchedMaxDegree {
+        /// The maximum degree of the first key.
+        expected: usize,
+        /// The maximum degree of the offending key.
+        found: usize,
+    },
+
+    /// The parameters could not be read, or did not match their checksums.
+    CorruptedParameters(String),
+
+    /// A powers-of-tau contribution failed verification.
+    InvalidContribution(String),
+
+    /// The MSM engine failed to compute a commitment.
+    MsmError(MsmError),
+
     Terminated,
 }
 
@@ -106,6 +131,12 @@ impl From<FiatShamirError> for PCError {
     }
 }
 
+impl From<MsmError> for PCError {
+    fn from(other: MsmError) -> Self {
+        Self::MsmError(other)
+    }
+}
+
 impl core::fmt::Display for PCError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -155,6 +186,19 @@ impl core::fmt::Display for PCError {
                  supported degree ({:?})",
                 degree_bound, label, poly_degree, supported_degree
             ),
+            PCError::IncorrectNumberOfProofs { num_proofs, num_points } => write!(
+                f,
+                "found {:?} evaluation proofs, but the query set contains {:?} points",
+                num_proofs, num_points
+            ),
+            PCError::MismatchedMaxDegree { expected, found } => write!(
+                f,
+                "the keys were derived from parameters of maximum degree {:?} and {:?}",
+                expected, found
+            ),
+            PCError::CorruptedParameters(reason) => write!(f, "the parameters are corrupted: {}", reason),
+            PCError::InvalidContribution(reason) => write!(f, "invalid powers-of-tau contribution: {}", reason),
+            PCError::MsmError(e) => write!(f, "{e}"),
             PCError::Terminated => write!(f, "terminated"),
         }
     }
@@ -0,0 +1,453 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party powers-of-tau ceremony for the universal parameters of [`KZG10`](super::KZG10).
+//!
+//! Each participant multiplies the secret `\beta` by a fresh `\tau` and the secret `\gamma` by a
+//! fresh `\delta`, and publishes a [`ContributionProof`] showing knowledge of both. As long as a
+//! single participant discards their contribution, nobody knows the trapdoor of the final
+//! [`UniversalParams`].
+
+use super::{KZG10DegreeBoundsConfig, UniversalParams};
+use crate::{crypto_hash::sha256::sha256, msm::VariableBase, polycommit::PCError};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_utilities::{
+    cfg_iter,
+    error,
+    io::{self, Read, Write},
+    rand::Uniform,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    FromBytes,
+    ToBytes,
+};
+
+use core::ops::Mul;
+use parking_lot::RwLock;
+use rand::{CryptoRng, Rng};
+use std::{collections::BTreeMap, sync::Arc};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The domain separator for the challenges of the proofs of knowledge.
+const POK_DOMAIN: &[u8] = b"snarkVM.KZG10.PowersOfTau";
+
+/// The state of a powers-of-tau ceremony, after zero or more contributions.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `max_degree + 1`.
+    pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
+    /// \beta times the generator of G2.
+    pub beta_h: E::G2Affine,
+    /// Group elements of the form `{ \beta^{-(max_degree - i)} H }`, where `i` is a supported degree bound.
+    pub inverse_neg_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+}
+
+/// A Schnorr proof of knowledge of the discrete logarithm of a G1 element, with respect to the generator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KnowledgeProof<E: PairingEngine> {
+    /// The commitment `r G` to the prover's nonce.
+    pub commitment: E::G1Affine,
+    /// The response `r + c x`, for challenge `c` and secret `x`.
+    pub response: E::Fr,
+}
+
+/// The public record of a single contribution to a [`PowersOfTau`] ceremony.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ContributionProof<E: PairingEngine> {
+    /// `\tau G`, where `\tau` is the multiplier applied to `\beta`.
+    pub tau_g: E::G1Affine,
+    /// `\delta G`, where `\delta` is the multiplier applied to `\gamma`.
+    pub delta_g: E::G1Affine,
+    /// `\delta H`.
+    pub delta_h: E::G2Affine,
+    /// A proof of knowledge of `\tau`.
+    pub tau_proof: KnowledgeProof<E>,
+    /// A proof of knowledge of `\delta`.
+    pub delta_proof: KnowledgeProof<E>,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Returns the starting point of a ceremony for polynomials of degree up to `max_degree`,
+    /// in which `\beta` and `\gamma` are both one.
+    pub fn new(max_degree: usize, supported_degree_bounds_config: &KZG10DegreeBoundsConfig) -> Result<Self, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        let inverse_neg_powers_of_beta_h = supported_degree_bounds_config
+            .get_list::<E::Fr>(max_degree)
+            .into_iter()
+            .map(|bound| if bound <= max_degree { Ok((bound, h)) } else { Err(PCError::UnsupportedDegreeBound(bound)) })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            powers_of_beta_g: vec![g; max_degree + 1],
+            powers_of_beta_times_gamma_g: vec![g; max_degree + 2],
+            beta_h: h,
+            inverse_neg_powers_of_beta_h,
+        })
+    }
+
+    /// Returns the maximum degree of the polynomials supported by these powers.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_beta_g.len() - 1
+    }
+
+    /// Returns the hash that binds a contribution's proofs of knowledge to this state.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut bytes = Vec::new();
+        self.serialize_compressed(&mut bytes).expect("serialization into a vector cannot fail");
+        sha256(&bytes)
+    }
+
+    /// Applies a fresh contribution to the ceremony, returning the new state and the proof
+    /// that must be published alongside it. The contribution's secrets are dropped on return.
+    pub fn contribute<R: Rng + CryptoRng>(&self, rng: &mut R) -> (Self, ContributionProof<E>) {
+        let contribute_time = start_timer!(|| format!("PowersOfTau::Contribute with degree {}", self.max_degree()));
+        let tau = sample_nonzero::<E::Fr, _>(rng);
+        let delta = sample_nonzero::<E::Fr, _>(rng);
+
+        // Compute `tau^i`, for `i` up to the length of the longer vector.
+        let powers_of_tau = {
+            let mut powers_of_tau = vec![E::Fr::one()];
+            let mut cur = tau;
+            for _ in 0..self.powers_of_beta_times_gamma_g.len() {
+                powers_of_tau.push(cur);
+                cur *= &tau;
+            }
+            powers_of_tau
+        };
+
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(
+            cfg_iter!(self.powers_of_beta_g).enumerate().map(|(i, g)| g.mul(powers_of_tau[i])).collect(),
+        );
+        let powers_of_beta_times_gamma_g = E::G1Projective::batch_normalization_into_affine(
+            cfg_iter!(self.powers_of_beta_times_gamma_g)
+                .enumerate()
+                .map(|(i, g)| g.mul(powers_of_tau[i] * delta))
+                .collect(),
+        );
+        let beta_h = self.beta_h.mul(tau).to_affine();
+
+        let max_degree = self.max_degree();
+        let inverse_neg_powers_of_beta_h = self
+            .inverse_neg_powers_of_beta_h
+            .iter()
+            .map(|(bound, h)| (*bound, h.mul(powers_of_tau[max_degree - *bound].inverse().unwrap()).to_affine()))
+            .collect();
+
+        let digest = self.digest();
+        let g = E::G1Affine::prime_subgroup_generator();
+        let tau_g = g.mul(tau).to_affine();
+        let delta_g = g.mul(delta).to_affine();
+        let proof = ContributionProof {
+            tau_g,
+            delta_g,
+            delta_h: E::G2Affine::prime_subgroup_generator().mul(delta).to_affine(),
+            tau_proof: KnowledgeProof::prove(tau, &tau_g, &digest, rng),
+            delta_proof: KnowledgeProof::prove(delta, &delta_g, &digest, rng),
+        };
+        end_timer!(contribute_time);
+
+        (Self { powers_of_beta_g, powers_of_beta_times_gamma_g, beta_h, inverse_neg_powers_of_beta_h }, proof)
+    }
+
+    /// Checks that `next` is the result of applying the contribution attested by `proof` to `self`.
+    /// The group elements are assumed to lie in the prime order subgroup, which is checked when
+    /// they are deserialized. The batched checks are only sound if the contributor cannot predict
+    /// `rng`, so it must be a cryptographic RNG.
+    pub fn verify_contribution<R: Rng + CryptoRng>(
+        &self,
+        next: &Self,
+        proof: &ContributionProof<E>,
+        rng: &mut R,
+    ) -> Result<(), PCError> {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let invalid = |reason: &str| Err(PCError::InvalidContribution(reason.to_string()));
+
+        if next.powers_of_beta_g.len() != self.powers_of_beta_g.len()
+            || next.powers_of_beta_times_gamma_g.len() != self.powers_of_beta_times_gamma_g.len()
+            || !next.inverse_neg_powers_of_beta_h.keys().eq(self.inverse_neg_powers_of_beta_h.keys())
+        {
+            return invalid("the shape of the powers changed");
+        }
+        if proof.tau_g.is_zero() || proof.delta_g.is_zero() {
+            return invalid("the contributed secrets are zero");
+        }
+
+        // Check the proofs of knowledge of `tau` and `delta`.
+        let digest = self.digest();
+        if !proof.tau_proof.verify(&proof.tau_g, &digest) || !proof.delta_proof.verify(&proof.delta_g, &digest) {
+            return invalid("the proof of knowledge does not verify");
+        }
+
+        // Check that `beta` was multiplied by `tau`, and `gamma` by `delta`.
+        if !same_ratio::<E>((g, proof.tau_g), (self.beta_h, next.beta_h)) {
+            return invalid("`beta_h` is not the previous `beta_h` times `tau`");
+        }
+        if !same_ratio::<E>((g, proof.delta_g), (h, proof.delta_h)) {
+            return invalid("`delta_g` and `delta_h` are inconsistent");
+        }
+        if !same_ratio::<E>(
+            (self.powers_of_beta_times_gamma_g[0], next.powers_of_beta_times_gamma_g[0]),
+            (h, proof.delta_h),
+        ) {
+            return invalid("`gamma_g` is not the previous `gamma_g` times `delta`");
+        }
+
+        // Check that the powers are consecutive powers of the new `beta`.
+        if next.powers_of_beta_g[0] != g {
+            return invalid("the zeroth power of `beta_g` is not the generator");
+        }
+        if !same_ratio::<E>((g, next.powers_of_beta_g[1]), (h, next.beta_h)) {
+            return invalid("`beta_g` and `beta_h` are inconsistent");
+        }
+        if !is_geometric::<E, _>(&next.powers_of_beta_g, next.beta_h, rng) {
+            return invalid("`powers_of_beta_g` is not a geometric sequence");
+        }
+        if !is_geometric::<E, _>(&next.powers_of_beta_times_gamma_g, next.beta_h, rng) {
+            return invalid("`powers_of_beta_times_gamma_g` is not a geometric sequence");
+        }
+
+        // Check that `inverse_neg_powers_of_beta_h[i] * beta^{max_degree - i}` is the generator.
+        let max_degree = next.max_degree();
+        for (bound, inverse_power) in &next.inverse_neg_powers_of_beta_h {
+            if !same_ratio::<E>((g, next.powers_of_beta_g[max_degree - bound]), (*inverse_power, h)) {
+                return invalid("`inverse_neg_powers_of_beta_h` is inconsistent with `powers_of_beta_g`");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a whole transcript, starting from [`PowersOfTau::new`]. Only two states are held
+    /// at a time, so `contributions` may read them lazily from disk. Returns the final state.
+    pub fn verify_transcript<R: Rng + CryptoRng>(
+        max_degree: usize,
+        supported_degree_bounds_config: &KZG10DegreeBoundsConfig,
+        contributions: impl IntoIterator<Item = Result<(Self, ContributionProof<E>), PCError>>,
+        rng: &mut R,
+    ) -> Result<Self, PCError> {
+        let mut current = Self::new(max_degree, supported_degree_bounds_config)?;
+        let mut num_contributions = 0;
+        for contribution in contributions {
+            let (next, proof) = contribution?;
+            current.verify_contribution(&next, &proof, rng).map_err(|error| match error {
+                PCError::InvalidContribution(reason) => {
+                    PCError::InvalidContribution(format!("contribution {num_contributions}: {reason}"))
+                }
+                error => error,
+            })?;
+            current = next;
+            num_contributions += 1;
+        }
+        if num_contributions == 0 {
+            return Err(PCError::InvalidContribution("the transcript has no contributions".to_string()));
+        }
+        Ok(current)
+    }
+
+    /// Converts the final state of a verified ceremony into universal parameters.
+    pub fn into_universal_params(self) -> UniversalParams<E> {
+        let h = E::G2Affine::prime_subgroup_generator();
+        let powers: PowersOfG<E> =
+            (self.powers_of_beta_g, self.powers_of_beta_times_gamma_g.into_iter().enumerate().collect()).into();
+        let supported_degree_bounds = self.inverse_neg_powers_of_beta_h.keys().copied().collect();
+
+        UniversalParams {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h: self.beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_beta_h: self.inverse_neg_powers_of_beta_h,
+            prepared_h: h.prepare(),
+            prepared_beta_h: self.beta_h.prepare(),
+        }
+    }
+}
+
+impl<E: PairingEngine> KnowledgeProof<E> {
+    fn prove<R: Rng + CryptoRng>(secret: E::Fr, public: &E::G1Affine, digest: &[u8; 32], rng: &mut R) -> Self {
+        let nonce = sample_nonzero::<E::Fr, _>(rng);
+        let commitment = E::G1Affine::prime_subgroup_generator().mul(nonce).to_affine();
+        let challenge = Self::challenge(public, &commitment, digest);
+        Self { commitment, response: nonce + challenge * secret }
+    }
+
+    fn verify(&self, public: &E::G1Affine, digest: &[u8; 32]) -> bool {
+        let challenge = Self::challenge(public, &self.commitment, digest);
+        let lhs = E::G1Affine::prime_subgroup_generator().mul(self.response);
+        let rhs = self.commitment.to_projective() + public.mul(challenge);
+        lhs.to_affine() == rhs.to_affine()
+    }
+
+    fn challenge(public: &E::G1Affine, commitment: &E::G1Affine, digest: &[u8; 32]) -> E::Fr {
+        let mut bytes = POK_DOMAIN.to_vec();
+        bytes.extend_from_slice(digest);
+        public.serialize_compressed(&mut bytes).expect("serialization into a vector cannot fail");
+        commitment.serialize_compressed(&mut bytes).expect("serialization into a vector cannot fail");
+        E::Fr::from_bytes_le_mod_order(&sha256(&bytes))
+    }
+}
+
+/// Returns `true` if `e(a.0, b.1) == e(a.1, b.0)`, that is, if the two pairs share the same discrete log ratio.
+fn same_ratio<E: PairingEngine>(a: (E::G1Affine, E::G1Affine), b: (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(a.0, b.1) == E::pairing(a.1, b.0)
+}
+
+/// Returns `true` if each element of `powers` is the previous one times the discrete log of `beta_h`.
+/// The check compares random linear combinations of `powers[..n-1]` and `powers[1..]`.
+fn is_geometric<E: PairingEngine, R: Rng + CryptoRng>(
+    powers: &[E::G1Affine],
+    beta_h: E::G2Affine,
+    rng: &mut R,
+) -> bool {
+    let scalars = (0..powers.len() - 1).map(|_| E::Fr::rand(rng).to_repr()).collect::<Vec<_>>();
+    let lhs = VariableBase::msm(&powers[..powers.len() - 1], &scalars);
+    let rhs = VariableBase::msm(&powers[1..], &scalars);
+    same_ratio::<E>((lhs.to_affine(), rhs.to_affine()), (E::G2Affine::prime_subgroup_generator(), beta_h))
+}
+
+fn sample_nonzero<F: PrimeField, R: Rng>(rng: &mut R) -> F {
+    loop {
+        let value = F::rand(rng);
+        if !value.is_zero() {
+            return value;
+        }
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PowersOfTau<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize PowersOfTau"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PowersOfTau<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize PowersOfTau"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for ContributionProof<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize ContributionProof"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for ContributionProof<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize ContributionProof"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::DensePolynomial, polycommit::kzg10::KZG10};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{rand::test_rng, test_crypto_rng};
+
+    use core::sync::atomic::AtomicBool;
+
+    type Tau = PowersOfTau<Bls12_377>;
+
+    fn run_ceremony(max_degree: usize, num_contributions: usize) -> Vec<(Tau, ContributionProof<Bls12_377>)> {
+        let rng = &mut test_crypto_rng();
+        let mut current = Tau::new(max_degree, &KZG10DegreeBoundsConfig::MARLIN).unwrap();
+        let mut transcript = Vec::new();
+        for _ in 0..num_contributions {
+            let (next, proof) = current.contribute(rng);
+            transcript.push((next.clone(), proof));
+            current = next;
+        }
+        transcript
+    }
+
+    #[test]
+    fn test_transcript_verifies() {
+        let transcript = run_ceremony(16, 3);
+        let last = transcript.last().unwrap().0.clone();
+        let verified = Tau::verify_transcript(
+            16,
+            &KZG10DegreeBoundsConfig::MARLIN,
+            transcript.into_iter().map(Ok),
+            &mut test_crypto_rng(),
+        )
+        .unwrap();
+        assert_eq!(verified, last);
+
+        let bytes = verified.to_bytes_le().unwrap();
+        assert_eq!(Tau::read_le(&bytes[..]).unwrap(), verified);
+    }
+
+    #[test]
+    fn test_tampered_contribution_is_rejected() {
+        let rng = &mut test_crypto_rng();
+        let start = Tau::new(8, &KZG10DegreeBoundsConfig::MARLIN).unwrap();
+        let (next, proof) = start.contribute(rng);
+        start.verify_contribution(&next, &proof, rng).unwrap();
+
+        let mut tampered = next.clone();
+        tampered.powers_of_beta_g[3] = tampered.powers_of_beta_g[4];
+        assert!(start.verify_contribution(&tampered, &proof, rng).is_err());
+
+        let mut tampered = next.clone();
+        tampered.powers_of_beta_times_gamma_g[9] = tampered.powers_of_beta_times_gamma_g[0];
+        assert!(start.verify_contribution(&tampered, &proof, rng).is_err());
+
+        let mut tampered = next.clone();
+        let bound = *tampered.inverse_neg_powers_of_beta_h.keys().next().unwrap();
+        tampered.inverse_neg_powers_of_beta_h.insert(bound, tampered.beta_h);
+        assert!(start.verify_contribution(&tampered, &proof, rng).is_err());
+
+        // A proof of knowledge must not transfer to a different previous state.
+        let (other, other_proof) = next.contribute(rng);
+        let forged = ContributionProof { tau_proof: proof.tau_proof, ..other_proof };
+        assert!(next.verify_contribution(&other, &forged, rng).is_err());
+
+        // Skipping the only contribution leaves the trapdoor known.
+        assert!(Tau::verify_transcript(8, &KZG10DegreeBoundsConfig::MARLIN, [], rng).is_err());
+    }
+
+    #[test]
+    fn test_ceremony_params_commit_and_open() {
+        let rng = &mut test_rng();
+        let degree = 16;
+        let (last, _) = run_ceremony(degree, 2).pop().unwrap();
+        let pp = last.into_universal_params();
+        assert_eq!(pp.supported_degree_bounds, KZG10DegreeBoundsConfig::MARLIN.get_list::<Fr>(degree));
+
+        let (ck, vk) = KZG10::trim(&pp, degree);
+        let p = DensePolynomial::rand(degree, rng);
+        let (comm, rand) = KZG10::commit(&ck, &(&p).into(), Some(1), &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG10::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+    }
+}
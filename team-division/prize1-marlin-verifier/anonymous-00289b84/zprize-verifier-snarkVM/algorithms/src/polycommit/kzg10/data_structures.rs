@@ -20,6 +20,8 @@ use crate::{
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_parameters::testnet3::MappedUniversalSRS;
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{
     borrow::Cow,
@@ -244,6 +246,22 @@ impl<E: PairingEngine> UniversalParams<E> {
     pub fn increase_degree(&self, degree: usize) -> Result<()> {
         self.download_up_to(degree)
     }
+
+    /// Writes `self` to `path` in the format read by `MappedUniversalSRS`, with a checksum
+    /// for every `chunk_size` powers of G.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped(&self, path: &std::path::Path, chunk_size: usize) -> Result<()> {
+        MappedUniversalSRS::<E>::write(
+            path,
+            self.max_degree() + 1,
+            chunk_size,
+            |lower, upper| Ok(self.powers_of_beta_g(lower, upper)),
+            &self.get_powers_times_gamma_g(),
+            self.h,
+            self.beta_h,
+            &self.inverse_neg_powers_of_beta_h,
+        )
+    }
 }
 
 /// `Powers` is used to commit to and create evaluation proofs for a given polynomial.
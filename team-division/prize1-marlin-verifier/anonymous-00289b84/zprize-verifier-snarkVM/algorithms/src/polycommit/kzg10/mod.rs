@@ -23,11 +23,13 @@
 
 use crate::{
     fft::{DensePolynomial, Polynomial},
-    msm::{FixedBase, VariableBase},
+    msm::{FixedBase, MsmEngine, MsmRegistry},
     polycommit::PCError,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_parameters::testnet3::MappedUniversalSRS;
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{cfg_iter, rand::Uniform, BitIteratorBE};
 
@@ -39,11 +41,14 @@ use core::{
 use itertools::Itertools;
 use parking_lot::RwLock;
 use rand_core::RngCore;
-use std::{collections::BTreeMap, sync::Arc};
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod ceremony;
+pub use ceremony::*;
+
 mod data_structures;
 pub use data_structures::*;
 
@@ -212,6 +217,36 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(pp)
     }
 
+    /// Returns the powers for polynomials of degree up to `supported_degree` from memory-mapped
+    /// parameters. Only the powers of G not yet requested are decoded, into a cache held by `pp`
+    /// that the returned powers borrow from.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn mapped_powers(
+        pp: &mut MappedUniversalSRS<E>,
+        supported_degree: usize,
+        supported_hiding_bound: usize,
+    ) -> Result<Powers<'_, E>, PCError> {
+        if supported_degree > pp.max_degree() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+        let powers_of_gamma_g = pp.get_powers_times_gamma_g();
+        let powers_of_beta_times_gamma_g = (0..=supported_hiding_bound + 1)
+            .map(|i| {
+                powers_of_gamma_g.get(&i).copied().ok_or(PCError::HidingBoundToolarge {
+                    hiding_poly_degree: supported_hiding_bound,
+                    num_powers: powers_of_gamma_g.len(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let powers_of_beta_g =
+            pp.prefix(supported_degree + 1).map_err(|e| PCError::CorruptedParameters(e.to_string()))?;
+
+        Ok(Powers {
+            powers_of_beta_g: Cow::Borrowed(powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+        })
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit(
         powers: &Powers<E>,
@@ -219,6 +254,19 @@ impl<E: PairingEngine> KZG10<E> {
         hiding_bound: Option<usize>,
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::commit_with_engine(&*engine, powers, polynomial, hiding_bound, terminator, rng)
+    }
+
+    /// Outputs a commitment to `polynomial`, computing its MSMs on `engine`.
+    pub fn commit_with_engine(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        powers: &Powers<E>,
+        polynomial: &Polynomial<'_, E::Fr>,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
     ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
 
@@ -233,7 +281,7 @@ impl<E: PairingEngine> KZG10<E> {
                 let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(&powers.powers_of_beta_g[num_leading_zeros..], &plain_coeffs);
+                let commitment = engine.msm(&powers.powers_of_beta_g[num_leading_zeros..], &plain_coeffs)?;
                 end_timer!(msm_time);
 
                 if terminator.load(Ordering::Relaxed) {
@@ -265,8 +313,7 @@ impl<E: PairingEngine> KZG10<E> {
 
         let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
         let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
-        let random_commitment =
-            VariableBase::msm(&powers.powers_of_beta_times_gamma_g, random_ints.as_slice()).to_affine();
+        let random_commitment = engine.msm(&powers.powers_of_beta_times_gamma_g, random_ints.as_slice())?.to_affine();
         end_timer!(msm_time);
 
         if terminator.load(Ordering::Relaxed) {
@@ -286,6 +333,19 @@ impl<E: PairingEngine> KZG10<E> {
         hiding_bound: Option<usize>,
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::commit_lagrange_with_engine(&*engine, lagrange_basis, evaluations, hiding_bound, terminator, rng)
+    }
+
+    /// Outputs a commitment to the polynomial with the given `evaluations`, computing its MSMs on `engine`.
+    pub fn commit_lagrange_with_engine(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        lagrange_basis: &LagrangeBasis<E>,
+        evaluations: &[E::Fr],
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
     ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
         Self::check_degree_is_too_large(evaluations.len() - 1, lagrange_basis.size())?;
         assert_eq!(evaluations.len().next_power_of_two(), lagrange_basis.size());
@@ -298,7 +358,7 @@ impl<E: PairingEngine> KZG10<E> {
 
         let evaluations = evaluations.iter().map(|e| e.to_repr()).collect::<Vec<_>>();
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBase::msm(&lagrange_basis.lagrange_basis_at_beta_g, &evaluations);
+        let mut commitment = engine.msm(&lagrange_basis.lagrange_basis_at_beta_g, &evaluations)?;
         end_timer!(msm_time);
 
         if terminator.load(Ordering::Relaxed) {
@@ -322,7 +382,7 @@ impl<E: PairingEngine> KZG10<E> {
         let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
         let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
         let random_commitment =
-            VariableBase::msm(&lagrange_basis.powers_of_beta_times_gamma_g, random_ints.as_slice()).to_affine();
+            engine.msm(&lagrange_basis.powers_of_beta_times_gamma_g, random_ints.as_slice())?.to_affine();
         end_timer!(msm_time);
 
         if terminator.load(Ordering::Relaxed) {
@@ -367,6 +427,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     pub(crate) fn open_with_witness_polynomial(
+        engine: &dyn MsmEngine<E::G1Affine>,
         powers: &Powers<E>,
         point: E::Fr,
         randomness: &Randomness<E>,
@@ -377,7 +438,7 @@ impl<E: PairingEngine> KZG10<E> {
         let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(witness_polynomial);
 
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBase::msm(&powers.powers_of_beta_g[num_leading_zeros..], &witness_coeffs);
+        let mut w = engine.msm(&powers.powers_of_beta_g[num_leading_zeros..], &witness_coeffs)?;
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -388,7 +449,7 @@ impl<E: PairingEngine> KZG10<E> {
 
             let random_witness_coeffs = convert_to_bigints(&hiding_witness_polynomial.coeffs);
             let witness_comm_time = start_timer!(|| "Computing commitment to random witness polynomial");
-            w += &VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_witness_coeffs);
+            w += &engine.msm(&powers.powers_of_beta_times_gamma_g, &random_witness_coeffs)?;
             end_timer!(witness_comm_time);
             Some(blinding_evaluation)
         } else {
@@ -404,6 +465,19 @@ impl<E: PairingEngine> KZG10<E> {
         polynomial: &DensePolynomial<E::Fr>,
         point: E::Fr,
         rand: &Randomness<E>,
+    ) -> Result<Proof<E>, PCError> {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::open_with_engine(&*engine, powers, polynomial, point, rand)
+    }
+
+    /// On input a polynomial `p` and a point `point`, outputs a proof for the same,
+    /// computing its MSMs on `engine`.
+    pub(crate) fn open_with_engine(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        point: E::Fr,
+        rand: &Randomness<E>,
     ) -> Result<Proof<E>, PCError> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
         let open_time = start_timer!(|| format!("Opening polynomial of degree {}", polynomial.degree()));
@@ -412,8 +486,14 @@ impl<E: PairingEngine> KZG10<E> {
         let (witness_poly, hiding_witness_poly) = Self::compute_witness_polynomial(polynomial, point, rand)?;
         end_timer!(witness_time);
 
-        let proof =
-            Self::open_with_witness_polynomial(powers, point, rand, &witness_poly, hiding_witness_poly.as_ref());
+        let proof = Self::open_with_witness_polynomial(
+            engine,
+            powers,
+            point,
+            rand,
+            &witness_poly,
+            hiding_witness_poly.as_ref(),
+        );
 
         end_timer!(open_time);
         proof
@@ -561,7 +641,7 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
-fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
+pub(crate) fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_repr()).collect::<Vec<_>>();
     end_timer!(to_bigint_time);
@@ -17,13 +17,14 @@
 use crate::{
     crypto_hash::sha256::sha256,
     fft::EvaluationDomain,
-    polycommit::kzg10,
+    msm::PrecomputedBases,
+    polycommit::{kzg10, PCError},
     snark::marlin::{FiatShamirError, FiatShamirRng},
     Prepare,
 };
 use hashbrown::HashMap;
-use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use std::{
@@ -359,6 +360,20 @@ impl<E: PairingEngine> CommitterKey<E> {
             domain,
         })
     }
+
+    /// Precomputes the multiples of the powers of G, of their Lagrange bases, and of the hiding
+    /// powers, for windows of `window_bits` bits. The result serves commitments against this key
+    /// as an MSM engine, and can be serialized to be cached next to the proving key.
+    pub fn precompute_bases(&self, window_bits: usize) -> Result<PrecomputedBases<E::G1Affine>, PCError> {
+        let bases = self
+            .powers_of_beta_g
+            .iter()
+            .chain(self.lagrange_bases_at_beta_g.values().flatten())
+            .chain(&self.powers_of_beta_times_gamma_g)
+            .copied()
+            .collect::<Vec<_>>();
+        Ok(PrecomputedBases::new(&bases, window_bits)?)
+    }
 }
 
 impl<E: PairingEngine> CommitterKey<E> {
@@ -369,6 +384,49 @@ impl<E: PairingEngine> CommitterKey<E> {
     pub fn supported_degree(&self) -> usize {
         self.powers_of_beta_g.len() - 1
     }
+
+    /// Combines keys trimmed from the same universal parameters into one key that supports
+    /// every degree, Lagrange basis size, and degree bound supported by any of them.
+    pub fn union<'a>(keys: impl IntoIterator<Item = &'a Self>) -> Result<Self, PCError>
+    where
+        E: 'a,
+    {
+        let mut keys = keys.into_iter();
+        let mut union = keys.next().cloned().unwrap_or_default();
+        for key in keys {
+            if key.max_degree != union.max_degree {
+                return Err(PCError::MismatchedMaxDegree { expected: union.max_degree, found: key.max_degree });
+            }
+            if key.powers_of_beta_g.len() > union.powers_of_beta_g.len() {
+                union.powers_of_beta_g = key.powers_of_beta_g.clone();
+            }
+            if key.powers_of_beta_times_gamma_g.len() > union.powers_of_beta_times_gamma_g.len() {
+                union.powers_of_beta_times_gamma_g = key.powers_of_beta_times_gamma_g.clone();
+            }
+            for (size, basis) in &key.lagrange_bases_at_beta_g {
+                union.lagrange_bases_at_beta_g.entry(*size).or_insert_with(|| basis.clone());
+            }
+            // The shifted powers of every key end at `max_degree`, so the longest of them covers all bounds.
+            if let Some(shifted_powers) = &key.shifted_powers_of_beta_g {
+                if union.shifted_powers_of_beta_g.as_ref().map_or(true, |powers| powers.len() < shifted_powers.len()) {
+                    union.shifted_powers_of_beta_g = Some(shifted_powers.clone());
+                }
+            }
+            if let Some(shifted_powers) = &key.shifted_powers_of_beta_times_gamma_g {
+                let union_powers = union.shifted_powers_of_beta_times_gamma_g.get_or_insert_with(BTreeMap::new);
+                for (bound, powers) in shifted_powers {
+                    union_powers.entry(*bound).or_insert_with(|| powers.clone());
+                }
+            }
+            if let Some(bounds) = &key.enforced_degree_bounds {
+                let union_bounds = union.enforced_degree_bounds.get_or_insert_with(Vec::new);
+                union_bounds.extend(bounds);
+                union_bounds.sort_unstable();
+                union_bounds.dedup();
+            }
+        }
+        Ok(union)
+    }
 }
 
 /// `VerifierKey` is used to check evaluation proofs for a given commitment.
@@ -499,6 +557,11 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Returns the evaluation proofs, one per query point.
+    pub fn proofs(&self) -> &[kzg10::Proof<E>] {
+        &self.0
+    }
 }
 
 impl<E: PairingEngine> BatchProof<E> {
@@ -799,3 +862,78 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The terms of the pairing equation that `batch_check` reduces to, before the
+/// final product of pairings is computed. Keeping these around allows the checks
+/// of many independent proofs to be folded into a single product of pairings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, grouped by their degree bound.
+    pub combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined evaluation proofs, paired with `\beta h`.
+    pub combined_witness: E::G1Projective,
+    /// The combined evaluations and shifted evaluation proofs, paired with `h`.
+    pub combined_adjusted_witness: E::G1Projective,
+}
+
+/// An accumulator for the pairing checks of [`SonicKZG10`](super::SonicKZG10).
+///
+/// An accumulator is the deferred form of a [`PairingCheck`]: it is satisfied if and only if
+/// `e(left, h) * \prod_i e(shifted_i, shift_power_i) = e(right, \beta h)`. Accumulators from
+/// many proofs can be merged with random linear combinations, so that a single product of
+/// pairings ("deciding" the accumulator) verifies all of them at once.
+///
+/// Commitments with a degree bound are paired with a shift power of `h` rather than with `h`
+/// itself, and are therefore kept apart, grouped by their degree bound. Accumulators can only
+/// be merged and decided under verifier keys trimmed from the same universal parameters.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KZGAccumulator<E: PairingEngine> {
+    /// The element paired with `h`.
+    pub left: E::G1Affine,
+    /// The element paired with `\beta h`.
+    pub right: E::G1Affine,
+    /// The combined commitments with a degree bound, each paired with the corresponding shift power of `h`.
+    pub shifted: BTreeMap<usize, E::G1Affine>,
+}
+
+impl<E: PairingEngine> KZGAccumulator<E> {
+    /// Returns the empty accumulator, which is trivially satisfied.
+    pub fn empty() -> Self {
+        Self { left: E::G1Affine::zero(), right: E::G1Affine::zero(), shifted: BTreeMap::new() }
+    }
+
+    /// Returns `true` if `self` is the empty accumulator.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_zero() && self.right.is_zero() && self.shifted.values().all(|s| s.is_zero())
+    }
+}
+
+impl<E: PairingEngine> From<PairingCheck<E>> for KZGAccumulator<E> {
+    fn from(pairing_check: PairingCheck<E>) -> Self {
+        let PairingCheck { mut combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
+        let left = combined_comms.remove(&None).unwrap_or_else(E::G1Projective::zero) - combined_adjusted_witness;
+
+        let (degree_bounds, shifted): (Vec<_>, Vec<_>) =
+            combined_comms.into_iter().filter_map(|(degree_bound, comm)| Some((degree_bound?, comm))).unzip();
+        let mut elements = shifted;
+        elements.push(left);
+        elements.push(combined_witness);
+        let mut elements = E::G1Projective::batch_normalization_into_affine(elements);
+
+        let right = elements.pop().unwrap();
+        let left = elements.pop().unwrap();
+        Self { left, right, shifted: degree_bounds.into_iter().zip(elements).collect() }
+    }
+}
+
+impl<E: PairingEngine> FromBytes for KZGAccumulator<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for KZGAccumulator<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
@@ -15,14 +15,15 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fft::DensePolynomial,
+    fft::{DensePolynomial, Polynomial},
     polycommit::{kzg10, optional_rng::OptionalRng, PCError},
-    snark::marlin::{params::OptimizationType, FiatShamirRng}, msm::VariableBase,
+    snark::marlin::{params::OptimizationType, FiatShamirRng}, msm::{MsmEngine, MsmRegistry, VariableBase},
 };
 use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero, PrimeField};
+use snarkvm_utilities::{BitIteratorBE, Uniform};
 
 use core::{
     convert::TryInto,
@@ -30,7 +31,10 @@ use core::{
     ops::Mul,
     sync::atomic::{AtomicBool, Ordering},
 };
+use rand::{CryptoRng, Rng};
 use rand_core::{RngCore, SeedableRng};
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_parameters::testnet3::MappedUniversalSRS;
 use std::collections::{BTreeMap, BTreeSet};
 
 mod data_structures;
@@ -54,6 +58,17 @@ pub struct SonicKZG10<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> {
     _engine: PhantomData<(E, S)>,
 }
 
+/// The bases of the committer key that the MSMs of a commitment run over.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum CommitmentBases {
+    /// The powers of G, shifted for the given degree bound.
+    Powers(Option<usize>),
+    /// The Lagrange basis for the domain of the given size.
+    Lagrange(usize),
+    /// The powers of gamma G, shifted for the given degree bound.
+    HidingPowers(Option<usize>),
+}
+
 impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
     pub fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<UniversalParams<E>, PCError> {
         kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true, rng).map_err(Into::into)
@@ -65,13 +80,44 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         supported_lagrange_sizes: impl IntoIterator<Item = usize>,
         supported_hiding_bound: usize,
         enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<E>, VerifierKey<E>), PCError> {
+        let mut pp = pp;
+        Self::trim_from(
+            &mut pp,
+            supported_degree,
+            supported_lagrange_sizes,
+            supported_hiding_bound,
+            enforced_degree_bounds,
+        )
+    }
+
+    /// Specializes memory-mapped public parameters. Only the powers of G that the committer key
+    /// needs are checked and decoded.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn trim_mapped(
+        pp: &mut MappedUniversalSRS<E>,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<E>, VerifierKey<E>), PCError> {
+        Self::trim_from(pp, supported_degree, supported_lagrange_sizes, supported_hiding_bound, enforced_degree_bounds)
+    }
+
+    fn trim_from<P: TrimSource<E>>(
+        pp: &mut P,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
     ) -> Result<(CommitterKey<E>, VerifierKey<E>), PCError> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let mut max_degree = pp.max_degree();
         if supported_degree > max_degree {
-            pp.download_up_to(supported_degree).map_err(|_| PCError::TrimmingDegreeTooLarge)?;
+            pp.download_up_to(supported_degree)?;
             max_degree = pp.max_degree();
         }
+        let powers_of_beta_times_gamma_g = pp.powers_of_beta_times_gamma_g();
 
         let enforced_degree_bounds = enforced_degree_bounds.map(|bounds| {
             let mut v = bounds.to_vec();
@@ -98,17 +144,15 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
                     max_degree - lowest_shift_degree + 1
                 ));
 
-                let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, pp.max_degree() + 1).to_vec();
+                let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, max_degree + 1)?;
                 let mut shifted_powers_of_beta_times_gamma_g = BTreeMap::new();
-                // Also add degree 0.
-                let _max_gamma_g = pp.get_powers_times_gamma_g().keys().last().unwrap();
                 for degree_bound in enforced_degree_bounds {
                     let shift_degree = max_degree - degree_bound;
                     let mut powers_for_degree_bound = Vec::with_capacity((max_degree + 2).saturating_sub(shift_degree));
                     for i in 0..=supported_hiding_bound + 1 {
                         // We have an additional degree in `powers_of_beta_times_gamma_g` beyond `powers_of_beta_g`.
                         if shift_degree + i < max_degree + 2 {
-                            powers_for_degree_bound.push(pp.get_powers_times_gamma_g()[&(shift_degree + i)]);
+                            powers_for_degree_bound.push(powers_of_beta_times_gamma_g[&(shift_degree + i)]);
                         }
                     }
                     shifted_powers_of_beta_times_gamma_g.insert(*degree_bound, powers_for_degree_bound);
@@ -122,9 +166,10 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             (None, None)
         };
 
-        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1).to_vec();
+        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?;
+        let gamma_g = powers_of_beta_times_gamma_g[&0];
         let powers_of_beta_times_gamma_g =
-            (0..=supported_hiding_bound + 1).map(|i| pp.get_powers_times_gamma_g()[&i]).collect();
+            (0..=supported_hiding_bound + 1).map(|i| powers_of_beta_times_gamma_g[&i]).collect();
 
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for size in supported_lagrange_sizes {
@@ -132,11 +177,14 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             if !size.is_power_of_two() {
                 return Err(PCError::LagrangeBasisSizeIsNotPowerOfTwo);
             }
-            if size > pp.max_degree() + 1 {
+            if size > max_degree + 1 {
                 return Err(PCError::LagrangeBasisSizeIsTooLarge);
             }
             let domain = crate::fft::EvaluationDomain::new(size).unwrap();
-            let lagrange_basis_at_beta_g = pp.lagrange_basis(domain);
+            let powers = pp.powers_of_beta_g(0, domain.size())?;
+            let lagrange_basis_at_beta_g = E::G1Projective::batch_normalization_into_affine(
+                domain.ifft(&powers.iter().map(|e| e.to_projective()).collect::<Vec<_>>()),
+            );
             assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
             lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
             end_timer!(lagrange_time);
@@ -152,18 +200,17 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             max_degree,
         };
 
-        let g = pp.power_of_beta_g(0);
-        let h = pp.h;
-        let beta_h = pp.beta_h;
-        let gamma_g = pp.get_powers_times_gamma_g()[&0];
-        let prepared_h = pp.prepared_h.clone();
-        let prepared_beta_h = pp.prepared_beta_h.clone();
+        let g = ck.powers_of_beta_g[0];
+        let h = pp.h();
+        let beta_h = pp.beta_h();
+        let prepared_h = pp.prepared_h();
+        let prepared_beta_h = pp.prepared_beta_h();
 
-        let degree_bounds_and_neg_powers_of_h = if pp.inverse_neg_powers_of_beta_h.is_empty() {
+        let degree_bounds_and_neg_powers_of_h = if pp.inverse_neg_powers_of_beta_h().is_empty() {
             None
         } else {
             Some(
-                pp.inverse_neg_powers_of_beta_h
+                pp.inverse_neg_powers_of_beta_h()
                     .iter()
                     .map(|(d, affine)| (*d, *affine))
                     .collect::<Vec<(usize, E::G2Affine)>>(),
@@ -212,19 +259,35 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
 
     /// Outputs a commitment to `polynomial`.
     #[allow(clippy::type_complexity)]
-    #[allow(clippy::format_push_string)]
     pub fn commit_with_terminator<'a>(
         ck: &CommitterKey<E>,
         polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, E::Fr>>,
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::commit_with_engine(&*engine, ck, polynomials, terminator, rng)
+    }
+
+    /// Outputs a commitment to `polynomial`, computing its MSMs on `engine`.
+    /// The polynomials are prepared in parallel, and the MSMs of all polynomials over the same bases of `ck`
+    /// run together in one [`MsmEngine::multi_msm`].
+    /// The engine may be a registry that tries [`PrecomputedBases`](crate::msm::PrecomputedBases) for `ck` first.
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::format_push_string)]
+    pub fn commit_with_engine<'a>(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        ck: &CommitterKey<E>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, E::Fr>>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
     ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
         let rng = &mut OptionalRng(rng);
         let commit_time = start_timer!(|| "Committing to polynomials");
-        let mut labeled_comms: Vec<LabeledCommitment<Commitment<E>>> = Vec::new();
-        let mut randomness: Vec<Randomness<E>> = Vec::new();
 
-        let mut pool = snarkvm_utilities::ExecutionPool::<Result<_, _>>::new();
+        // Prepare the scalars of every MSM of each polynomial in parallel.
+        let mut labels = Vec::new();
+        let mut pool = snarkvm_utilities::ExecutionPool::<Result<_, PCError>>::new();
         for p in polynomials {
             if terminator.load(Ordering::Relaxed) {
                 return Err(PCError::Terminated);
@@ -244,67 +307,127 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             let degree_bound = p.degree_bound();
             let hiding_bound = p.hiding_bound();
             let label = p.label().to_string();
+            add_to_trace!(|| "PC::Commit", || format!(
+                "Polynomial {} of degree {}, degree bound {:?}, and hiding bound {:?}",
+                label,
+                p.degree(),
+                degree_bound,
+                hiding_bound,
+            ));
+            labels.push((label, degree_bound));
 
             pool.add_job(move || {
                 let mut rng = seed.map(rand::rngs::StdRng::from_seed);
-                add_to_trace!(|| "PC::Commit", || format!(
-                    "Polynomial {} of degree {}, degree bound {:?}, and hiding bound {:?}",
-                    label,
-                    p.degree(),
-                    degree_bound,
-                    hiding_bound,
-                ));
-
-                #[allow(clippy::or_fun_call)]
-                let (comm, rand) = p
-                    .sum()
-                    .map(move |p| {
-                        let rng_ref = rng.as_mut().map(|s| s as _);
-                        match p {
-                            PolynomialWithBasis::Lagrange { evaluations } => {
-                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
-                                let lagrange_basis = ck
-                                    .lagrange_basis(domain)
-                                    .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
-                                assert!(domain.size().is_power_of_two());
-                                assert!(lagrange_basis.size().is_power_of_two());
-                                kzg10::KZG10::commit_lagrange(
-                                    &lagrange_basis,
-                                    &evaluations.evaluations,
-                                    hiding_bound,
-                                    terminator,
-                                    rng_ref,
-                                )
-                            }
-                            PolynomialWithBasis::Monomial { polynomial, degree_bound } => {
-                                let powers = if let Some(degree_bound) = degree_bound {
-                                    ck.shifted_powers_of_beta_g(degree_bound).unwrap()
-                                } else {
-                                    ck.powers()
-                                };
-
-                                kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, terminator, rng_ref)
+                let mut comm = E::G1Projective::zero();
+                let mut rand = Randomness::empty();
+                let mut msms = Vec::new();
+                for term in p.sum() {
+                    let (hiding_key, hiding_bases) = match term {
+                        PolynomialWithBasis::Lagrange { evaluations } => {
+                            let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
+                            let lagrange_basis = ck
+                                .lagrange_basis(domain)
+                                .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
+                            kzg10::KZG10::<E>::check_degree_is_too_large(
+                                evaluations.evaluations.len() - 1,
+                                lagrange_basis.size(),
+                            )?;
+                            let scalars = kzg10::convert_to_bigints(&evaluations.evaluations);
+                            let key = CommitmentBases::Lagrange(domain.size());
+                            msms.push((key, lagrange_basis.lagrange_basis_at_beta_g, scalars));
+                            (CommitmentBases::HidingPowers(None), lagrange_basis.powers_of_beta_times_gamma_g)
+                        }
+                        PolynomialWithBasis::Monomial { polynomial, degree_bound } => {
+                            let powers = if let Some(degree_bound) = degree_bound {
+                                ck.shifted_powers_of_beta_g(degree_bound).unwrap()
+                            } else {
+                                ck.powers()
+                            };
+                            kzg10::KZG10::<E>::check_degree_is_too_large(polynomial.degree(), powers.size())?;
+                            match polynomial.as_ref() {
+                                Polynomial::Dense(polynomial) => {
+                                    let scalars = kzg10::convert_to_bigints(&polynomial.coeffs);
+                                    let key = CommitmentBases::Powers(degree_bound);
+                                    msms.push((key, powers.powers_of_beta_g, scalars));
+                                }
+                                Polynomial::Sparse(polynomial) => {
+                                    comm += polynomial
+                                        .coeffs()
+                                        .map(|(i, coeff)| {
+                                            powers.powers_of_beta_g[*i]
+                                                .mul_bits(BitIteratorBE::new_without_leading_zeros(coeff.to_repr()))
+                                        })
+                                        .sum::<E::G1Projective>();
+                                }
                             }
+                            (CommitmentBases::HidingPowers(degree_bound), powers.powers_of_beta_times_gamma_g)
                         }
-                    })
-                    .collect::<Result<Vec<_>, _>>()?
-                    .into_iter()
-                    .fold((E::G1Projective::zero(), Randomness::empty()), |mut a, b| {
-                        a.0.add_assign_mixed(&b.0.0);
-                        a.1 += (E::Fr::one(), &b.1);
-                        a
-                    });
-                let comm = kzg10::Commitment(comm.to_affine());
-
-                Ok((LabeledCommitment::new(label.to_string(), comm, degree_bound), rand))
+                    };
+
+                    if let Some(hiding_degree) = hiding_bound {
+                        let rng = rng.as_mut().ok_or(PCError::MissingRng)?;
+                        let term_rand = Randomness::<E>::rand(hiding_degree, false, rng);
+                        kzg10::KZG10::<E>::check_hiding_bound(
+                            term_rand.blinding_polynomial.degree(),
+                            hiding_bases.len(),
+                        )?;
+                        let scalars = kzg10::convert_to_bigints(&term_rand.blinding_polynomial.coeffs);
+                        msms.push((hiding_key, hiding_bases, scalars));
+                        rand += (E::Fr::one(), &term_rand);
+                    }
+                }
+                Ok((comm, rand, msms))
             });
         }
-        let results: Vec<Result<_, PCError>> = pool.execute_all();
-        for result in results {
-            let (comm, rand) = result?;
-            labeled_comms.push(comm);
+
+        // Collect the scalars of every MSM, grouped by the bases that they run over.
+        let mut bases = BTreeMap::new();
+        let mut scalar_sets = BTreeMap::<_, Vec<_>>::new();
+        let mut comms = Vec::with_capacity(labels.len());
+        let mut randomness = Vec::with_capacity(labels.len());
+        for (i, result) in pool.execute_all().into_iter().enumerate() {
+            let (comm, rand, msms) = result?;
+            for (key, key_bases, scalars) in msms {
+                bases.entry(key).or_insert(key_bases);
+                scalar_sets.entry(key).or_default().push((i, scalars));
+            }
+            comms.push(comm);
             randomness.push(rand);
         }
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PCError::Terminated);
+        }
+
+        // Compute the MSMs over each set of bases together, and the sets of bases in parallel.
+        let mut pool = snarkvm_utilities::ExecutionPool::<Result<_, PCError>>::with_capacity(scalar_sets.len());
+        for (key, scalar_sets) in &scalar_sets {
+            let bases = &bases[key];
+            pool.add_job(move || {
+                let (indices, scalar_sets): (Vec<_>, Vec<_>) =
+                    scalar_sets.iter().map(|(i, scalars)| (*i, scalars.as_slice())).unzip();
+                let msm_time = start_timer!(|| format!("Multi-MSM of {} vectors over {:?}", scalar_sets.len(), key));
+                let results = engine.multi_msm(bases, &scalar_sets)?;
+                end_timer!(msm_time);
+                Ok((indices, results))
+            });
+        }
+        for result in pool.execute_all() {
+            let (indices, results) = result?;
+            for (i, result) in indices.into_iter().zip_eq(results) {
+                comms[i] += result;
+            }
+        }
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PCError::Terminated);
+        }
+
+        let labeled_comms = labels
+            .into_iter()
+            .zip_eq(comms)
+            .map(|((label, degree_bound), comm)| {
+                LabeledCommitment::new(label, kzg10::Commitment(comm.to_affine()), degree_bound)
+            })
+            .collect();
 
         end_timer!(commit_time);
         Ok((labeled_comms, randomness))
@@ -345,6 +468,24 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         rands: impl IntoIterator<Item = &'a Randomness<E>>,
         fs_rng: &mut S,
     ) -> Result<BatchProof<E>, PCError>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::batch_open_with_engine(&*engine, ck, labeled_polynomials, commitments, query_set, rands, fs_rng)
+    }
+
+    /// Same as [`Self::batch_open`], but computes the MSMs of the proofs on `engine`.
+    pub fn batch_open_with_engine<'a>(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        ck: &CommitterKey<E>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchProof<E>, PCError>
     where
         Randomness<E>: 'a,
         Commitment<E>: 'a,
@@ -387,7 +528,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
 
             pool.add_job(move || {
                 let proof_time = start_timer!(|| "Creating proof");
-                let proof = kzg10::KZG10::open(&ck.powers(), &polynomial, query, &rand);
+                let proof = kzg10::KZG10::open_with_engine(engine, &ck.powers(), &polynomial, query, &rand);
                 end_timer!(proof_time);
                 proof
             });
@@ -407,6 +548,24 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         fs_rng: &mut S,
         fs_parameters: Option<&S::Parameters>,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::batch_check_elems(vk, commitments, query_set, values, proof, fs_rng, fs_parameters)?;
+        Self::check_elems(vk, pairing_check)
+    }
+
+    /// Performs the same work as `batch_check`, but stops short of the final product of pairings
+    /// and instead returns the terms of the pairing equation.
+    pub fn batch_check_elems<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+        fs_parameters: Option<&S::Parameters>,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -418,7 +577,12 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             labels.1.insert(label);
         }
 
-        assert_eq!(proof.0.len(), query_to_labels_map.len());
+        if proof.0.len() != query_to_labels_map.len() {
+            return Err(PCError::IncorrectNumberOfProofs {
+                num_proofs: proof.0.len(),
+                num_points: query_to_labels_map.len(),
+            });
+        }
 
         let mut randomizer = E::Fr::one();
 
@@ -470,7 +634,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             randomizer = batch_kzg_check_fs_rng.squeeze_short_nonnative_field_element()?;
         }
 
-        Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
+        Ok(PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
     pub fn open_combinations<'a>(
@@ -482,6 +646,35 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         rands: impl IntoIterator<Item = &'a Randomness<E>>,
         fs_rng: &mut S,
     ) -> Result<BatchLCProof<E>, PCError>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::open_combinations_with_engine(
+            &*engine,
+            ck,
+            linear_combinations,
+            polynomials,
+            commitments,
+            query_set,
+            rands,
+            fs_rng,
+        )
+    }
+
+    /// Same as [`Self::open_combinations`], but computes the MSMs of the proofs on `engine`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn open_combinations_with_engine<'a>(
+        engine: &dyn MsmEngine<E::G1Affine>,
+        ck: &CommitterKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<E>, PCError>
     where
         Randomness<E>: 'a,
         Commitment<E>: 'a,
@@ -539,7 +732,8 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
             .collect::<Vec<_>>();
 
-        let proof = Self::batch_open(
+        let proof = Self::batch_open_with_engine(
+            engine,
             ck,
             lc_polynomials.iter(),
             lc_commitments.iter(),
@@ -563,6 +757,34 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         fs_rng: &mut S,
         fs_parameters: Option<&S::Parameters>,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::check_combinations_elems(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+            fs_parameters,
+        )?;
+        Self::check_elems(vk, pairing_check)
+    }
+
+    /// Performs the same work as `check_combinations`, but stops short of the final product of pairings
+    /// and instead returns the terms of the pairing equation, so that they may be checked with `check_many`.
+    pub fn check_combinations_elems<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+        fs_parameters: Option<&S::Parameters>,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -627,7 +849,84 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng, fs_parameters)
+        Self::batch_check_elems(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng, fs_parameters)
+    }
+
+    /// Checks many pairing equations, possibly under different verifier keys, with a single
+    /// product of pairings. Each equation is scaled by a random challenge sampled from `rng`,
+    /// so that the combined check passes (with overwhelming probability) only if every
+    /// individual check passes.
+    pub fn check_many<'a, R: Rng + CryptoRng>(
+        checks: impl IntoIterator<Item = (&'a VerifierKey<E>, PairingCheck<E>)>,
+        rng: &mut R,
+    ) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| "Checking many pairing equations");
+        let mut terms: Vec<(E::G1Projective, E::G2Affine, <E::G2Affine as PairingCurve>::Prepared)> = Vec::new();
+        for (i, (vk, pairing_check)) in checks.into_iter().enumerate() {
+            // The first equation does not need to be randomized.
+            let randomizer = if i == 0 { None } else { Some(E::Fr::rand(rng)) };
+            for (g1, g2, prepared_g2) in Self::pairing_terms(vk, pairing_check)? {
+                let g1 = if let Some(randomizer) = randomizer { g1 * randomizer } else { g1 };
+                // Terms that share a G2 element (e.g. `h` and `\beta h` from the same SRS) are merged,
+                // which saves one Miller loop per shared element.
+                match terms.iter_mut().find(|(_, other, _)| *other == g2) {
+                    Some((acc, _, _)) => *acc += g1,
+                    None => terms.push((g1, g2, prepared_g2)),
+                }
+            }
+        }
+        let is_one = Self::product_of_pairings_is_one(terms);
+        end_timer!(check_time);
+        Ok(is_one)
+    }
+
+    /// Merges `accumulators` into a single accumulator, by taking a random linear combination
+    /// of them with challenges sampled from `rng`. The result is satisfied (with overwhelming
+    /// probability) only if every one of `accumulators` is satisfied.
+    ///
+    /// The challenges must be unpredictable to whoever produced the accumulators.
+    pub fn merge_accumulators<R: Rng + CryptoRng>(
+        accumulators: impl IntoIterator<Item = KZGAccumulator<E>>,
+        rng: &mut R,
+    ) -> KZGAccumulator<E> {
+        let merge_time = start_timer!(|| "Merging accumulators");
+        let mut left = E::G1Projective::zero();
+        let mut right = E::G1Projective::zero();
+        let mut shifted = BTreeMap::<usize, E::G1Projective>::new();
+        for (i, accumulator) in accumulators.into_iter().enumerate() {
+            // The first accumulator does not need to be randomized.
+            let randomizer = if i == 0 { E::Fr::one() } else { E::Fr::rand(rng) };
+            left += accumulator.left.mul(randomizer);
+            right += accumulator.right.mul(randomizer);
+            for (degree_bound, comm) in accumulator.shifted {
+                *shifted.entry(degree_bound).or_insert_with(E::G1Projective::zero) += comm.mul(randomizer);
+            }
+        }
+
+        let accumulator = KZGAccumulator::from(PairingCheck {
+            combined_comms: shifted.into_iter().map(|(degree_bound, comm)| (Some(degree_bound), comm)).collect(),
+            combined_witness: right,
+            combined_adjusted_witness: -left,
+        });
+        end_timer!(merge_time);
+        accumulator
+    }
+
+    /// Decides `accumulator`, by computing the product of pairings it has deferred.
+    pub fn decide(vk: &VerifierKey<E>, accumulator: &KZGAccumulator<E>) -> Result<bool, PCError> {
+        let decide_time = start_timer!(|| "Deciding accumulator");
+        let mut terms = Vec::with_capacity(accumulator.shifted.len() + 2);
+        terms.push((accumulator.left.to_projective(), vk.vk.h, vk.vk.prepared_h.clone()));
+        terms.push((-accumulator.right.to_projective(), vk.vk.beta_h, vk.vk.prepared_beta_h.clone()));
+        for (&degree_bound, comm) in &accumulator.shifted {
+            let shift_power = vk.get_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+            let prepared_shift_power =
+                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+            terms.push((comm.to_projective(), shift_power, prepared_shift_power));
+        }
+        let is_one = Self::product_of_pairings_is_one(terms);
+        end_timer!(decide_time);
+        Ok(is_one)
     }
 }
 
@@ -740,33 +1039,49 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         end_timer!(acc_time);
     }
 
+    /// Returns the terms of the product of pairings that `pairing_check` reduces to,
+    /// as triples of the G1 element, the G2 element, and the prepared G2 element.
     #[allow(clippy::type_complexity)]
-    fn check_elems(
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
+    fn pairing_terms(
         vk: &VerifierKey<E>,
-    ) -> Result<bool, PCError> {
-        let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+        pairing_check: PairingCheck<E>,
+    ) -> Result<Vec<(E::G1Projective, E::G2Affine, <E::G2Affine as PairingCurve>::Prepared)>, PCError> {
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
+        let mut terms = Vec::with_capacity(combined_comms.len() + 2);
 
         for (degree_bound, comm) in combined_comms.into_iter() {
-            let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
+            let (shift_power, prepared_shift_power) = if let Some(degree_bound) = degree_bound {
+                let shift_power =
+                    vk.get_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+                let prepared_shift_power =
+                    vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+                (shift_power, prepared_shift_power)
             } else {
-                vk.vk.prepared_h.clone()
+                (vk.vk.h, vk.vk.prepared_h.clone())
             };
 
-            g1_projective_elems.push(comm);
-            g2_prepared_elems.push(shift_power);
+            terms.push((comm, shift_power, prepared_shift_power));
         }
 
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+        terms.push((-combined_adjusted_witness, vk.vk.h, vk.vk.prepared_h.clone()));
+        terms.push((-combined_witness, vk.vk.beta_h, vk.vk.prepared_beta_h.clone()));
 
-        g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        Ok(terms)
+    }
+
+    pub(crate) fn check_elems(vk: &VerifierKey<E>, pairing_check: PairingCheck<E>) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| "Checking elems");
+        let is_one = Self::product_of_pairings_is_one(Self::pairing_terms(vk, pairing_check)?);
+        end_timer!(check_time);
+        Ok(is_one)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn product_of_pairings_is_one(
+        terms: Vec<(E::G1Projective, E::G2Affine, <E::G2Affine as PairingCurve>::Prepared)>,
+    ) -> bool {
+        let (g1_projective_elems, g2_prepared_elems): (Vec<_>, Vec<_>) =
+            terms.into_iter().map(|(g1, _, prepared_g2)| (g1, prepared_g2)).unzip();
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
@@ -774,9 +1089,112 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
 
         let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
-        let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
-        end_timer!(check_time);
-        Ok(is_one)
+        E::product_of_pairings(g1_g2_prepared).is_one()
+    }
+}
+
+/// The parameters that `SonicKZG10::trim` reads from, held either in memory or in a mapped file.
+trait TrimSource<E: PairingEngine> {
+    fn max_degree(&self) -> usize;
+
+    /// Ensures that powers of G up to `degree` are available.
+    fn download_up_to(&mut self, degree: usize) -> Result<(), PCError>;
+
+    fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>, PCError>;
+
+    fn powers_of_beta_times_gamma_g(&self) -> BTreeMap<usize, E::G1Affine>;
+
+    fn h(&self) -> E::G2Affine;
+
+    fn beta_h(&self) -> E::G2Affine;
+
+    fn prepared_h(&self) -> <E::G2Affine as PairingCurve>::Prepared;
+
+    fn prepared_beta_h(&self) -> <E::G2Affine as PairingCurve>::Prepared;
+
+    fn inverse_neg_powers_of_beta_h(&self) -> &BTreeMap<usize, E::G2Affine>;
+}
+
+impl<E: PairingEngine> TrimSource<E> for &UniversalParams<E> {
+    fn max_degree(&self) -> usize {
+        UniversalParams::max_degree(self)
+    }
+
+    fn download_up_to(&mut self, degree: usize) -> Result<(), PCError> {
+        UniversalParams::download_up_to(self, degree).map_err(|_| PCError::TrimmingDegreeTooLarge)
+    }
+
+    fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>, PCError> {
+        Ok(UniversalParams::powers_of_beta_g(self, lower, upper))
+    }
+
+    fn powers_of_beta_times_gamma_g(&self) -> BTreeMap<usize, E::G1Affine> {
+        self.get_powers_times_gamma_g()
+    }
+
+    fn h(&self) -> E::G2Affine {
+        self.h
+    }
+
+    fn beta_h(&self) -> E::G2Affine {
+        self.beta_h
+    }
+
+    fn prepared_h(&self) -> <E::G2Affine as PairingCurve>::Prepared {
+        self.prepared_h.clone()
+    }
+
+    fn prepared_beta_h(&self) -> <E::G2Affine as PairingCurve>::Prepared {
+        self.prepared_beta_h.clone()
+    }
+
+    fn inverse_neg_powers_of_beta_h(&self) -> &BTreeMap<usize, E::G2Affine> {
+        &self.inverse_neg_powers_of_beta_h
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<E: PairingEngine> TrimSource<E> for MappedUniversalSRS<E> {
+    fn max_degree(&self) -> usize {
+        MappedUniversalSRS::max_degree(self)
+    }
+
+    fn download_up_to(&mut self, _degree: usize) -> Result<(), PCError> {
+        // A mapped file holds every power it will ever have.
+        Err(PCError::TrimmingDegreeTooLarge)
+    }
+
+    fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>, PCError> {
+        // Serve prefixes from the decoded prefix, so that each power is only decoded once.
+        let powers = match lower {
+            0 => self.prefix(upper).map(|powers| powers.to_vec()),
+            _ => MappedUniversalSRS::powers_of_beta_g(self, lower, upper),
+        };
+        powers.map_err(|e| PCError::CorruptedParameters(e.to_string()))
+    }
+
+    fn powers_of_beta_times_gamma_g(&self) -> BTreeMap<usize, E::G1Affine> {
+        self.get_powers_times_gamma_g().clone()
+    }
+
+    fn h(&self) -> E::G2Affine {
+        MappedUniversalSRS::h(self)
+    }
+
+    fn beta_h(&self) -> E::G2Affine {
+        MappedUniversalSRS::beta_h(self)
+    }
+
+    fn prepared_h(&self) -> <E::G2Affine as PairingCurve>::Prepared {
+        MappedUniversalSRS::h(self).prepare()
+    }
+
+    fn prepared_beta_h(&self) -> <E::G2Affine as PairingCurve>::Prepared {
+        MappedUniversalSRS::beta_h(self).prepare()
+    }
+
+    fn inverse_neg_powers_of_beta_h(&self) -> &BTreeMap<usize, E::G2Affine> {
+        MappedUniversalSRS::inverse_neg_powers_of_beta_h(self)
     }
 }
 
@@ -784,16 +1202,21 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
+    use super::{Commitment, CommitterKey, LabeledPolynomialWithBasis, Randomness, SonicKZG10};
     use crate::{
         crypto_hash::PoseidonSponge,
-        polycommit::test_templates::*,
+        fft::{DensePolynomial, EvaluationDomain, Evaluations, Polynomial},
+        msm::{MsmRegistry, PrecomputedBases},
+        polycommit::{kzg10::KZG10, test_templates::*},
         snark::marlin::FiatShamirAlgebraicSpongeRng,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_utilities::{rand::test_rng, FromBytes, ToBytes};
+    use snarkvm_parameters::testnet3::MappedUniversalSRS;
+    use snarkvm_utilities::{rand::test_rng, CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes, Uniform};
 
-    use rand::distributions::Distribution;
+    use core::sync::atomic::AtomicBool;
+    use rand::{distributions::Distribution, Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
 
     type Sponge = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
     type PC_Bls12_377 = SonicKZG10<Bls12_377, Sponge>;
@@ -817,6 +1240,144 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_trim_mapped() {
+        let rng = &mut test_rng();
+        let max_degree = 64;
+        let supported_degree = 33;
+        let pp = PC_Bls12_377::setup(max_degree, rng).unwrap();
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("mapped_srs_{}", rand::Rng::gen::<u32>(rng)));
+        pp.write_mapped(&path, 10).unwrap();
+        let mut mapped = MappedUniversalSRS::open(&path).unwrap();
+
+        let bounds = [30, 32];
+        let (ck, vk) = PC_Bls12_377::trim(&pp, supported_degree, [32], 1, Some(&bounds)).unwrap();
+        let (mapped_ck, mapped_vk) =
+            PC_Bls12_377::trim_mapped(&mut mapped, supported_degree, [32], 1, Some(&bounds)).unwrap();
+        assert_eq!(ck.to_bytes_le().unwrap(), mapped_ck.to_bytes_le().unwrap());
+        assert_eq!(vk.to_bytes_le().unwrap(), mapped_vk.to_bytes_le().unwrap());
+
+        let powers = KZG10::mapped_powers(&mut mapped, supported_degree, 1).unwrap();
+        assert_eq!(&powers.powers_of_beta_g[..], &ck.powers_of_beta_g[..]);
+        assert_eq!(&powers.powers_of_beta_times_gamma_g[..], &ck.powers_of_beta_times_gamma_g[..]);
+
+        // Corrupting a power is caught by its chunk's checksum, but only once that chunk is read.
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[24 + 60 * ck.powers_of_beta_g[0].uncompressed_size()] ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+        let mut mapped = MappedUniversalSRS::<Bls12_377>::open(&path).unwrap();
+        assert!(KZG10::mapped_powers(&mut mapped, supported_degree, 1).is_ok());
+        assert!(PC_Bls12_377::trim_mapped(&mut mapped, supported_degree, [32], 1, Some(&bounds)).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Commits to each of `monomials` and then `lagrange` on its own with [`KZG10`], drawing the hiding
+    /// randomness of every polynomial from its own seed, as [`SonicKZG10::commit`] does.
+    #[allow(clippy::type_complexity)]
+    fn commit_with_kzg10(
+        ck: &CommitterKey<Bls12_377>,
+        monomials: &[Polynomial<Fr>],
+        lagrange: &[Evaluations<Fr>],
+        hiding_bound: Option<usize>,
+        rng: &mut XorShiftRng,
+    ) -> (Vec<Commitment<Bls12_377>>, Vec<Randomness<Bls12_377>>) {
+        let mut seeded_rng = || {
+            let mut seed = [0u8; 32];
+            rng.fill(&mut seed);
+            rand::rngs::StdRng::from_seed(seed)
+        };
+        let terminator = AtomicBool::new(false);
+        let mut results = Vec::new();
+        for p in monomials {
+            let rng = &mut seeded_rng();
+            results.push(KZG10::commit(&ck.powers(), p, hiding_bound, &terminator, Some(rng)).unwrap());
+        }
+        for e in lagrange {
+            let rng = &mut seeded_rng();
+            let lagrange_basis = ck.lagrange_basis(e.domain()).unwrap();
+            let result = KZG10::commit_lagrange(&lagrange_basis, &e.evaluations, hiding_bound, &terminator, Some(rng));
+            results.push(result.unwrap());
+        }
+        results.into_iter().unzip()
+    }
+
+    /// Returns the labeled polynomials of `monomials` followed by `lagrange`.
+    fn labeled<'a>(
+        monomials: &'a [Polynomial<Fr>],
+        lagrange: &'a [Evaluations<Fr>],
+        hiding_bound: Option<usize>,
+    ) -> impl Iterator<Item = LabeledPolynomialWithBasis<'a, Fr>> {
+        monomials
+            .iter()
+            .map(move |p| LabeledPolynomialWithBasis::new_monomial_basis("test".into(), p, None, hiding_bound))
+            .chain(
+                lagrange
+                    .iter()
+                    .map(move |e| LabeledPolynomialWithBasis::new_lagrange_basis_ref("test".into(), e, hiding_bound)),
+            )
+    }
+
+    #[test]
+    fn test_commit_with_precomputed_bases() {
+        let rng = &mut test_rng();
+        let pp = PC_Bls12_377::setup(64, rng).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, [16], 1, None).unwrap();
+
+        let monomials = [Polynomial::from(DensePolynomial::<Fr>::rand(20, rng))];
+        let domain = EvaluationDomain::new(16).unwrap();
+        let lagrange = [Evaluations::from_vec_and_domain((0..16).map(|_| Fr::rand(rng)).collect(), domain)];
+
+        for window_bits in [6, 10] {
+            let precomputed = ck.precompute_bases(window_bits).unwrap();
+            let mut bytes = Vec::new();
+            precomputed.serialize_compressed(&mut bytes).unwrap();
+            let precomputed = PrecomputedBases::deserialize_compressed(&bytes[..]).unwrap();
+            let engine = MsmRegistry::default().with_first_engine(precomputed);
+
+            // The hiding randomness is drawn identically on both paths.
+            let seed: u64 = rng.gen();
+            let hiding_rng = &mut XorShiftRng::seed_from_u64(seed);
+            let expected = commit_with_kzg10(&ck, &monomials, &lagrange, Some(1), hiding_rng);
+            let hiding_rng = &mut XorShiftRng::seed_from_u64(seed);
+            let terminator = AtomicBool::new(false);
+            let polynomials = labeled(&monomials, &lagrange, Some(1));
+            let (commitments, randomness) =
+                PC_Bls12_377::commit_with_engine(&engine, &ck, polynomials, &terminator, Some(hiding_rng)).unwrap();
+            assert_eq!(expected.0, commitments.iter().map(|c| *c.commitment()).collect::<Vec<_>>());
+            assert_eq!(expected.1, randomness);
+        }
+    }
+
+    #[test]
+    fn test_commit_matches_kzg10() {
+        let rng = &mut test_rng();
+        let pp = PC_Bls12_377::setup(64, rng).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 32, [16, 32], 2, None).unwrap();
+
+        let monomials = (0..3).map(|i| Polynomial::from(DensePolynomial::rand(10 * i + 2, rng))).collect::<Vec<_>>();
+        let lagrange = [16, 32, 16]
+            .into_iter()
+            .map(|size| {
+                let domain = EvaluationDomain::new(size).unwrap();
+                Evaluations::from_vec_and_domain((0..size).map(|_| Fr::rand(rng)).collect(), domain)
+            })
+            .collect::<Vec<_>>();
+
+        for hiding_bound in [None, Some(1), Some(2)] {
+            let seed: u64 = rng.gen();
+            let hiding_rng = &mut XorShiftRng::seed_from_u64(seed);
+            let expected = commit_with_kzg10(&ck, &monomials, &lagrange, hiding_bound, hiding_rng);
+            let hiding_rng = &mut XorShiftRng::seed_from_u64(seed);
+            let polynomials = labeled(&monomials, &lagrange, hiding_bound);
+            let (commitments, randomness) = PC_Bls12_377::commit(&ck, polynomials, Some(hiding_rng)).unwrap();
+            assert_eq!(expected.0, commitments.iter().map(|c| *c.commitment()).collect::<Vec<_>>());
+            assert_eq!(expected.1, randomness);
+        }
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
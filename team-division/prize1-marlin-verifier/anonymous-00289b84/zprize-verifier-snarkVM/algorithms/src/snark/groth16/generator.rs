@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{r1cs_to_qap::R1CStoQAP, ProvingKey, VerifyingKey};
+use crate::{fft::EvaluationDomain, msm::FixedBase};
+use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, rand::Uniform};
+
+use rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Generates a random common reference string for a circuit.
+pub fn generate_random_parameters<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, R: Rng>(
+    circuit: &C,
+    rng: &mut R,
+) -> Result<ProvingKey<E>, SynthesisError> {
+    let alpha = E::Fr::rand(rng);
+    let beta = E::Fr::rand(rng);
+    let gamma = E::Fr::rand(rng);
+    let delta = E::Fr::rand(rng);
+
+    let g1_generator = E::G1Projective::rand(rng);
+    let g2_generator = E::G2Projective::rand(rng);
+
+    generate_parameters::<E, C, R>(circuit, alpha, beta, gamma, delta, g1_generator, g2_generator, rng)
+}
+
+/// Stores the constraints of a circuit during parameter generation.
+pub struct KeypairAssembly<E: PairingEngine> {
+    pub num_public_variables: usize,
+    pub num_private_variables: usize,
+    pub at: Vec<Vec<(E::Fr, Index)>>,
+    pub bt: Vec<Vec<(E::Fr, Index)>>,
+    pub ct: Vec<Vec<(E::Fr, Index)>>,
+}
+
+impl<E: PairingEngine> KeypairAssembly<E> {
+    /// Initializes an empty assembly, with the constant `1` allocated as the first public variable.
+    pub fn new() -> Self {
+        Self { num_public_variables: 1, num_private_variables: 0, at: vec![], bt: vec![], ct: vec![] }
+    }
+
+    #[inline]
+    fn make_row(l: &LinearCombination<E::Fr>) -> Vec<(E::Fr, Index)> {
+        l.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
+    }
+}
+
+impl<E: PairingEngine> Default for KeypairAssembly<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> ConstraintSystem<E::Fr> for KeypairAssembly<E> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        // There is no assignment, so we don't invoke the
+        // function for obtaining one.
+
+        let index = self.num_private_variables;
+        self.num_private_variables += 1;
+
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    #[inline]
+    fn alloc_input<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        // There is no assignment, so we don't invoke the
+        // function for obtaining one.
+
+        let index = self.num_public_variables;
+        self.num_public_variables += 1;
+
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LB: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LC: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+    {
+        self.at.push(Self::make_row(&a(LinearCombination::zero())));
+        self.bt.push(Self::make_row(&b(LinearCombination::zero())));
+        self.ct.push(Self::make_row(&c(LinearCombination::zero())));
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.at.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        true
+    }
+}
+
+/// Create parameters for a circuit, given some toxic waste.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_parameters<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, R: Rng>(
+    circuit: &C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    delta: E::Fr,
+    g1_generator: E::G1Projective,
+    g2_generator: E::G2Projective,
+    rng: &mut R,
+) -> Result<ProvingKey<E>, SynthesisError> {
+    let mut assembly = KeypairAssembly::<E>::new();
+
+    // Synthesize the circuit.
+    let synthesis_time = start_timer!(|| "Constraint synthesis");
+    circuit.generate_constraints(&mut assembly)?;
+    end_timer!(synthesis_time);
+
+    // Input constraints to ensure full density of IC query.
+    // x * 0 = 0
+    for i in 0..assembly.num_public_variables {
+        assembly.enforce(|| "", |lc| lc + Variable::new_unchecked(Index::Public(i)), |lc| lc, |lc| lc);
+    }
+
+    // Reduce the R1CS instance to a QAP, evaluated at a random point.
+    let reduction_time = start_timer!(|| "R1CS to QAP instance map with evaluation");
+    let t = EvaluationDomain::<E::Fr>::new(assembly.num_constraints())
+        .ok_or(SynthesisError::PolynomialDegreeTooLarge)?
+        .sample_element_outside_domain(rng);
+    let (a, b, c, zt, qap_num_variables, m_raw) = R1CStoQAP::instance_map_with_evaluation::<E>(&assembly, &t)?;
+    end_timer!(reduction_time);
+
+    // Compute the query densities.
+    let non_zero_a = cfg_iter!(a).filter(|coeff| !coeff.is_zero()).count();
+    let non_zero_b = cfg_iter!(b).filter(|coeff| !coeff.is_zero()).count();
+    let scalar_bits = E::Fr::size_in_bits();
+
+    let gamma_inverse = gamma.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+    let delta_inverse = delta.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    let num_public_variables = assembly.num_public_variables;
+    let gamma_abc = cfg_iter!(a[..num_public_variables])
+        .zip(&b[..num_public_variables])
+        .zip(&c[..num_public_variables])
+        .map(|((a, b), c)| (beta * a + (alpha * b) + c) * gamma_inverse)
+        .collect::<Vec<_>>();
+
+    let l = cfg_iter!(a[num_public_variables..])
+        .zip(&b[num_public_variables..])
+        .zip(&c[num_public_variables..])
+        .map(|((a, b), c)| (beta * a + (alpha * b) + c) * delta_inverse)
+        .collect::<Vec<_>>();
+
+    // Compute B window table.
+    let g2_time = start_timer!(|| "Compute G2 table");
+    let g2_window = FixedBase::get_mul_window_size(non_zero_b);
+    let g2_table = FixedBase::get_window_table::<E::G2Projective>(scalar_bits, g2_window, g2_generator);
+    end_timer!(g2_time);
+
+    // Compute the B-query in G2.
+    let b_g2_time = start_timer!(|| format!("Calculate B G2 of size {}", b.len()));
+    let b_g2_query = FixedBase::msm::<E::G2Projective>(scalar_bits, g2_window, &g2_table, &b);
+    drop(g2_table);
+    end_timer!(b_g2_time);
+
+    // Compute G window table.
+    let g1_window_time = start_timer!(|| "Compute G1 window table");
+    let num_scalars = non_zero_a + non_zero_b + qap_num_variables + m_raw + 1;
+    let g1_window = FixedBase::get_mul_window_size(num_scalars);
+    let g1_table = FixedBase::get_window_table::<E::G1Projective>(scalar_bits, g1_window, g1_generator);
+    end_timer!(g1_window_time);
+
+    // Generate the R1CS proving key.
+    let proving_key_time = start_timer!(|| "Generate the R1CS proving key");
+
+    // Compute the A-query.
+    let a_time = start_timer!(|| "Calculate A");
+    let a_query = FixedBase::msm::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &a);
+    drop(a);
+    end_timer!(a_time);
+
+    // Compute the B-query in G1.
+    let b_g1_time = start_timer!(|| "Calculate B G1");
+    let b_g1_query = FixedBase::msm::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &b);
+    drop(b);
+    end_timer!(b_g1_time);
+
+    // Compute the H-query.
+    let h_time = start_timer!(|| "Calculate H");
+    let h_scalars = cfg_into_iter!(0..m_raw - 1).map(|i| zt * delta_inverse * t.pow(&[i as u64])).collect::<Vec<_>>();
+    let h_query = FixedBase::msm::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &h_scalars);
+    end_timer!(h_time);
+
+    // Compute the L-query.
+    let l_time = start_timer!(|| "Calculate L");
+    let l_query = FixedBase::msm::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &l);
+    drop(l);
+    end_timer!(l_time);
+
+    end_timer!(proving_key_time);
+
+    // Generate the R1CS verifying key.
+    let verifying_key_time = start_timer!(|| "Generate the R1CS verifying key");
+    let gamma_abc_g1 = FixedBase::msm::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &gamma_abc);
+    drop(g1_table);
+    end_timer!(verifying_key_time);
+
+    let alpha_g1 = g1_generator * alpha;
+    let beta_g1 = g1_generator * beta;
+    let beta_g2 = g2_generator * beta;
+    let gamma_g2 = g2_generator * gamma;
+    let delta_g1 = g1_generator * delta;
+    let delta_g2 = g2_generator * delta;
+
+    let vk = VerifyingKey::<E> {
+        alpha_g1: alpha_g1.to_affine(),
+        beta_g2: beta_g2.to_affine(),
+        gamma_g2: gamma_g2.to_affine(),
+        delta_g2: delta_g2.to_affine(),
+        gamma_abc_g1: E::G1Projective::batch_normalization_into_affine(gamma_abc_g1),
+    };
+
+    let batch_normalization_time = start_timer!(|| "Convert proving key elements to affine");
+    let proving_key = ProvingKey {
+        vk,
+        beta_g1: beta_g1.to_affine(),
+        delta_g1: delta_g1.to_affine(),
+        a_query: E::G1Projective::batch_normalization_into_affine(a_query),
+        b_g1_query: E::G1Projective::batch_normalization_into_affine(b_g1_query),
+        b_g2_query: E::G2Projective::batch_normalization_into_affine(b_g2_query),
+        h_query: E::G1Projective::batch_normalization_into_affine(h_query),
+        l_query: E::G1Projective::batch_normalization_into_affine(l_query),
+    };
+    end_timer!(batch_normalization_time);
+
+    Ok(proving_key)
+}
@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An implementation of the [Groth16] zkSNARK.
+//!
+//! Unlike Marlin, Groth16 requires a circuit-specific setup, so it is only
+//! suitable for circuits whose shape is fixed ahead of time.
+//!
+//! [Groth16]: https://eprint.iacr.org/2016/260.pdf
+#![forbid(unsafe_code)]
+
+use crate::Prepare;
+use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+    ToMinimalBits,
+};
+
+/// Generates the proving and verifying keys for a circuit.
+mod generator;
+pub use generator::*;
+
+/// Produces proofs for an assignment to a circuit.
+mod prover;
+pub use prover::*;
+
+/// Reduces R1CS instances and witnesses to quadratic arithmetic programs.
+mod r1cs_to_qap;
+
+/// Implements the `SNARK` trait for Groth16.
+mod snark;
+pub use snark::*;
+
+/// Checks proofs against a prepared verifying key.
+mod verifier;
+pub use verifier::*;
+
+#[cfg(test)]
+mod tests;
+
+/// A proof in the Groth16 SNARK.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine> {
+    pub a: E::G1Affine,
+    pub b: E::G2Affine,
+    pub c: E::G1Affine,
+}
+
+impl<E: PairingEngine> ToBytes for Proof<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize Proof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize Proof"))
+    }
+}
+
+/// The proofs for a batch of assignments to the same circuit, in the order they were proven.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<E: PairingEngine> {
+    pub proofs: Vec<Proof<E>>,
+}
+
+impl<E: PairingEngine> BatchProof<E> {
+    /// Returns the number of instances proven by this batch proof.
+    pub fn batch_size(&self) -> usize {
+        self.proofs.len()
+    }
+}
+
+impl<E: PairingEngine> ToBytes for BatchProof<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize BatchProof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for BatchProof<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize BatchProof"))
+    }
+}
+
+/// A verifying key in the Groth16 SNARK.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerifyingKey<E: PairingEngine> {
+    pub alpha_g1: E::G1Affine,
+    pub beta_g2: E::G2Affine,
+    pub gamma_g2: E::G2Affine,
+    pub delta_g2: E::G2Affine,
+    /// The `[(beta * u_i(x) + alpha * v_i(x) + w_i(x)) / gamma] * G` for every public variable `i`,
+    /// including the constant `1` at index `0`.
+    pub gamma_abc_g1: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> Prepare for VerifyingKey<E> {
+    type Prepared = PreparedVerifyingKey<E>;
+
+    /// Prepare the verifying key.
+    fn prepare(&self) -> Self::Prepared {
+        prepare_verifying_key(self.clone())
+    }
+}
+
+impl<E: PairingEngine> From<ProvingKey<E>> for VerifyingKey<E> {
+    fn from(other: ProvingKey<E>) -> Self {
+        other.vk
+    }
+}
+
+impl<'a, E: PairingEngine> From<&'a ProvingKey<E>> for VerifyingKey<E> {
+    fn from(other: &'a ProvingKey<E>) -> Self {
+        other.vk.clone()
+    }
+}
+
+impl<E: PairingEngine> From<PreparedVerifyingKey<E>> for VerifyingKey<E> {
+    fn from(other: PreparedVerifyingKey<E>) -> Self {
+        other.vk
+    }
+}
+
+impl<E: PairingEngine> ToMinimalBits for VerifyingKey<E> {
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        [
+            self.alpha_g1.to_minimal_bits(),
+            self.beta_g2.to_minimal_bits(),
+            self.gamma_g2.to_minimal_bits(),
+            self.delta_g2.to_minimal_bits(),
+            self.gamma_abc_g1.to_minimal_bits(),
+        ]
+        .concat()
+    }
+}
+
+impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifyingKey<E> {
+    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
+        let mut res = Vec::new();
+        res.append(&mut self.alpha_g1.to_field_elements()?);
+        res.append(&mut self.beta_g2.to_field_elements()?);
+        res.append(&mut self.gamma_g2.to_field_elements()?);
+        res.append(&mut self.delta_g2.to_field_elements()?);
+        for gamma_abc in self.gamma_abc_g1.iter() {
+            res.append(&mut gamma_abc.to_field_elements()?);
+        }
+        Ok(res)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize VerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize VerifyingKey"))
+    }
+}
+
+/// A verifying key, prepared (preprocessed) for use in pairings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedVerifyingKey<E: PairingEngine> {
+    /// The pairing `e(alpha * G, beta * H)`, computed once at preparation time.
+    pub alpha_g1_beta_g2: E::Fqk,
+    /// The negation of `gamma * H`, prepared.
+    pub gamma_g2_neg_pc: <E::G2Affine as PairingCurve>::Prepared,
+    /// The negation of `delta * H`, prepared.
+    pub delta_g2_neg_pc: <E::G2Affine as PairingCurve>::Prepared,
+    /// The unprepared verifying key.
+    pub vk: VerifyingKey<E>,
+}
+
+impl<E: PairingEngine> PreparedVerifyingKey<E> {
+    /// Returns the number of public inputs (excluding the constant `1`) this key accepts.
+    pub fn num_public_inputs(&self) -> usize {
+        self.vk.gamma_abc_g1.len().saturating_sub(1)
+    }
+}
+
+impl<E: PairingEngine> From<VerifyingKey<E>> for PreparedVerifyingKey<E> {
+    fn from(other: VerifyingKey<E>) -> Self {
+        prepare_verifying_key(other)
+    }
+}
+
+/// A proving key in the Groth16 SNARK.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProvingKey<E: PairingEngine> {
+    pub vk: VerifyingKey<E>,
+    pub beta_g1: E::G1Affine,
+    pub delta_g1: E::G1Affine,
+    /// The `[(beta * u_i(x) + alpha * v_i(x) + w_i(x)) / delta] * G` for every private variable `i`.
+    pub l_query: Vec<E::G1Affine>,
+    /// The `[x^i * t(x) / delta] * G` for `i` in `0..domain_size - 1`.
+    pub h_query: Vec<E::G1Affine>,
+    pub a_query: Vec<E::G1Affine>,
+    pub b_g1_query: Vec<E::G1Affine>,
+    pub b_g2_query: Vec<E::G2Affine>,
+}
+
+impl<E: PairingEngine> ToBytes for ProvingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize ProvingKey"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for ProvingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize ProvingKey"))
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{r1cs_to_qap::R1CStoQAP, Proof, ProvingKey};
+use crate::msm::VariableBase;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+use snarkvm_utilities::rand::Uniform;
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use rand::Rng;
+
+/// Stores the constraints of a circuit, along with its assignment, during proving.
+pub struct ProvingAssignment<E: PairingEngine> {
+    // Constraints
+    pub(crate) at: Vec<Vec<(E::Fr, Index)>>,
+    pub(crate) bt: Vec<Vec<(E::Fr, Index)>>,
+    pub(crate) ct: Vec<Vec<(E::Fr, Index)>>,
+
+    // Assignments of variables
+    pub(crate) public_variables: Vec<E::Fr>,
+    pub(crate) private_variables: Vec<E::Fr>,
+}
+
+impl<E: PairingEngine> ProvingAssignment<E> {
+    /// Initializes an empty assignment, with the constant `1` assigned to the first public variable.
+    pub fn new() -> Self {
+        Self { at: vec![], bt: vec![], ct: vec![], public_variables: vec![E::Fr::one()], private_variables: vec![] }
+    }
+
+    #[inline]
+    fn make_row(l: &LinearCombination<E::Fr>) -> Vec<(E::Fr, Index)> {
+        l.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
+    }
+}
+
+impl<E: PairingEngine> Default for ProvingAssignment<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> ConstraintSystem<E::Fr> for ProvingAssignment<E> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.private_variables.len();
+        self.private_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    #[inline]
+    fn alloc_input<F, A, AR>(&mut self, _: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.public_variables.len();
+        self.public_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LB: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+        LC: FnOnce(LinearCombination<E::Fr>) -> LinearCombination<E::Fr>,
+    {
+        self.at.push(Self::make_row(&a(LinearCombination::zero())));
+        self.bt.push(Self::make_row(&b(LinearCombination::zero())));
+        self.ct.push(Self::make_row(&c(LinearCombination::zero())));
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.at.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        false
+    }
+}
+
+/// Creates a zero-knowledge proof for `circuit`, sampling the blinding factors from `rng`.
+pub fn create_random_proof<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, R: Rng>(
+    circuit: &C,
+    proving_key: &ProvingKey<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError> {
+    let r = E::Fr::rand(rng);
+    let s = E::Fr::rand(rng);
+
+    create_proof::<E, C>(circuit, proving_key, r, s)
+}
+
+/// Creates a proof for `circuit` that is *not* zero-knowledge.
+/// This method should be used *only* for testing purposes.
+pub fn create_proof_no_zk<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>>(
+    circuit: &C,
+    proving_key: &ProvingKey<E>,
+) -> Result<Proof<E>, SynthesisError> {
+    create_proof::<E, C>(circuit, proving_key, E::Fr::zero(), E::Fr::zero())
+}
+
+/// Creates a proof for `circuit` with the blinding factors `r` and `s`.
+pub fn create_proof<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError> {
+    let prover_time = start_timer!(|| "Groth16::Prover");
+    let mut prover = ProvingAssignment::<E>::new();
+
+    // Synthesize the circuit.
+    let synthesis_time = start_timer!(|| "Constraint synthesis");
+    circuit.generate_constraints(&mut prover)?;
+    end_timer!(synthesis_time);
+
+    // Input constraints to ensure full density of IC query.
+    // x * 0 = 0
+    for i in 0..prover.public_variables.len() {
+        prover.enforce(|| "", |lc| lc + Variable::new_unchecked(Index::Public(i)), |lc| lc, |lc| lc);
+    }
+
+    // The assignment must match the shape of the circuit the proving key was generated for.
+    let num_variables = prover.public_variables.len() + prover.private_variables.len();
+    if params.a_query.len() != num_variables || params.l_query.len() != prover.private_variables.len() {
+        return Err(anyhow!("The circuit does not match the shape of the proving key").into());
+    }
+
+    let witness_map_time = start_timer!(|| "R1CS to QAP witness map");
+    let h = R1CStoQAP::witness_map::<E>(&prover)?;
+    end_timer!(witness_map_time);
+
+    let input_assignment = prover.public_variables[1..].iter().map(|v| v.to_repr()).collect::<Vec<_>>();
+    let aux_assignment = prover.private_variables.iter().map(|v| v.to_repr()).collect::<Vec<_>>();
+    let assignment = [&input_assignment[..], &aux_assignment[..]].concat();
+    drop(input_assignment);
+
+    // The quotient polynomial has degree at most `domain_size - 2`.
+    let h_assignment = h.iter().take(params.h_query.len()).map(|v| v.to_repr()).collect::<Vec<_>>();
+
+    // Compute A
+    let a_acc_time = start_timer!(|| "Compute A");
+    let r_g1 = params.delta_g1 * r;
+    let g_a = calculate_coeff(r_g1, &params.a_query, params.vk.alpha_g1, &assignment);
+    end_timer!(a_acc_time);
+
+    // Compute B in G1 if needed
+    let g1_b = if !r.is_zero() {
+        let b_g1_acc_time = start_timer!(|| "Compute B in G1");
+        let s_g1 = params.delta_g1 * s;
+        let g1_b = calculate_coeff(s_g1, &params.b_g1_query, params.beta_g1, &assignment);
+        end_timer!(b_g1_acc_time);
+        g1_b
+    } else {
+        E::G1Projective::zero()
+    };
+
+    // Compute B in G2
+    let b_g2_acc_time = start_timer!(|| "Compute B in G2");
+    let s_g2 = params.vk.delta_g2 * s;
+    let g2_b = calculate_coeff(s_g2, &params.b_g2_query, params.vk.beta_g2, &assignment);
+    end_timer!(b_g2_acc_time);
+
+    // Compute C
+    let c_acc_time = start_timer!(|| "Compute C");
+    let h_acc = msm(&params.h_query, &h_assignment);
+    let l_aux_acc = msm(&params.l_query, &aux_assignment);
+
+    let s_g_a = g_a * s;
+    let r_g1_b = g1_b * r;
+    let r_s_delta_g1 = params.delta_g1 * (r * s);
+
+    let mut g_c = s_g_a;
+    g_c += r_g1_b;
+    g_c -= r_s_delta_g1;
+    g_c += l_aux_acc;
+    g_c += h_acc;
+    end_timer!(c_acc_time);
+
+    end_timer!(prover_time);
+
+    Ok(Proof { a: g_a.to_affine(), b: g2_b.to_affine(), c: g_c.to_affine() })
+}
+
+/// Returns `initial + query[0] + vk_param + <query[1..], assignment>`.
+fn calculate_coeff<G: AffineCurve>(
+    initial: G::Projective,
+    query: &[G],
+    vk_param: G,
+    assignment: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    let el = query[0];
+    let acc = msm(&query[1..], assignment);
+
+    let mut res = initial;
+    res.add_assign_mixed(&el);
+    res += acc;
+    res.add_assign_mixed(&vk_param);
+
+    res
+}
+
+/// Computes a variable-base MSM, skipping the bases that are the point at infinity.
+///
+/// The query vectors of a proving key are sparse for most circuits, and the batched
+/// addition in `VariableBase::msm` expects every base to be a finite point.
+fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    let (bases, scalars): (Vec<_>, Vec<_>) =
+        bases.iter().zip_eq(scalars).filter(|(base, _)| !base.is_zero()).map(|(base, scalar)| (*base, *scalar)).unzip();
+    match bases.is_empty() {
+        true => G::Projective::zero(),
+        false => VariableBase::msm(&bases, &scalars),
+    }
+}
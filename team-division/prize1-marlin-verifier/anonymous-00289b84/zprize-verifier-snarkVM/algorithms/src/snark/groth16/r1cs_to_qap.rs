@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{KeypairAssembly, ProvingAssignment};
+use crate::fft::EvaluationDomain;
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{Field, Zero};
+use snarkvm_r1cs::{ConstraintSystem, Index, SynthesisError};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Evaluates the linear combination `terms` at `assignment`, where the private variables
+/// are stored after the `num_public_variables` public variables.
+#[inline]
+fn evaluate_constraint<F: Field>(terms: &[(F, Index)], assignment: &[F], num_public_variables: usize) -> F {
+    terms.iter().fold(F::zero(), |sum, (coeff, index)| {
+        let value = match index {
+            Index::Public(i) => assignment[*i],
+            Index::Private(i) => assignment[num_public_variables + i],
+        };
+        sum + (value * coeff)
+    })
+}
+
+/// Reduces an R1CS instance to a quadratic arithmetic program (QAP).
+pub(crate) struct R1CStoQAP;
+
+impl R1CStoQAP {
+    /// Evaluates the QAP polynomials of every variable at `t`.
+    ///
+    /// Returns the evaluations of the `A`, `B` and `C` polynomials, the evaluation of the vanishing
+    /// polynomial at `t`, the number of QAP variables (excluding the constant `1`), and the domain size.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn instance_map_with_evaluation<E: PairingEngine>(
+        assembly: &KeypairAssembly<E>,
+        t: &E::Fr,
+    ) -> Result<(Vec<E::Fr>, Vec<E::Fr>, Vec<E::Fr>, E::Fr, usize, usize), SynthesisError> {
+        let domain = EvaluationDomain::<E::Fr>::new(assembly.num_constraints())
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+
+        let zt = domain.evaluate_vanishing_polynomial(*t);
+
+        // Evaluate all Lagrange polynomials.
+        let coefficients_time = start_timer!(|| "Evaluate Lagrange coefficients");
+        let u = domain.evaluate_all_lagrange_coefficients(*t);
+        end_timer!(coefficients_time);
+
+        let num_public_variables = assembly.num_public_variables;
+        let qap_num_variables = (num_public_variables - 1) + assembly.num_private_variables;
+
+        let mut a = vec![E::Fr::zero(); qap_num_variables + 1];
+        let mut b = vec![E::Fr::zero(); qap_num_variables + 1];
+        let mut c = vec![E::Fr::zero(); qap_num_variables + 1];
+
+        let position = |index: &Index| match index {
+            Index::Public(i) => *i,
+            Index::Private(i) => num_public_variables + i,
+        };
+
+        for (i, x) in u.iter().enumerate().take(assembly.num_constraints()) {
+            for (coeff, index) in assembly.at[i].iter() {
+                a[position(index)] += &(*x * coeff);
+            }
+            for (coeff, index) in assembly.bt[i].iter() {
+                b[position(index)] += &(*x * coeff);
+            }
+            for (coeff, index) in assembly.ct[i].iter() {
+                c[position(index)] += &(*x * coeff);
+            }
+        }
+
+        Ok((a, b, c, zt, qap_num_variables, domain_size))
+    }
+
+    /// Computes the coefficients of the quotient polynomial `h(x) = (a(x) * b(x) - c(x)) / t(x)`
+    /// for the witness held by `prover`.
+    pub(crate) fn witness_map<E: PairingEngine>(prover: &ProvingAssignment<E>) -> Result<Vec<E::Fr>, SynthesisError> {
+        let num_public_variables = prover.public_variables.len();
+        let num_constraints = prover.num_constraints();
+
+        let full_assignment = [&prover.public_variables[..], &prover.private_variables[..]].concat();
+
+        let domain = EvaluationDomain::<E::Fr>::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+
+        let mut a = vec![E::Fr::zero(); domain_size];
+        let mut b = vec![E::Fr::zero(); domain_size];
+        cfg_iter_mut!(a[..num_constraints])
+            .zip(cfg_iter_mut!(b[..num_constraints]))
+            .zip(cfg_iter!(prover.at))
+            .zip(cfg_iter!(prover.bt))
+            .for_each(|(((a, b), at_i), bt_i)| {
+                *a = evaluate_constraint(at_i, &full_assignment, num_public_variables);
+                *b = evaluate_constraint(bt_i, &full_assignment, num_public_variables);
+            });
+
+        domain.ifft_in_place(&mut a);
+        domain.ifft_in_place(&mut b);
+
+        domain.coset_fft_in_place(&mut a);
+        domain.coset_fft_in_place(&mut b);
+
+        let mut ab = domain.mul_polynomials_in_evaluation_domain(&a, &b);
+        drop(a);
+        drop(b);
+
+        let mut c = vec![E::Fr::zero(); domain_size];
+        cfg_iter_mut!(c[..num_constraints]).zip(cfg_iter!(prover.ct)).for_each(|(c, ct_i)| {
+            *c = evaluate_constraint(ct_i, &full_assignment, num_public_variables);
+        });
+
+        domain.ifft_in_place(&mut c);
+        domain.coset_fft_in_place(&mut c);
+
+        cfg_iter_mut!(ab).zip(c).for_each(|(ab_i, c_i)| *ab_i -= &c_i);
+
+        domain.divide_by_vanishing_poly_on_coset_in_place(&mut ab);
+        domain.coset_ifft_in_place(&mut ab);
+
+        Ok(ab)
+    }
+}
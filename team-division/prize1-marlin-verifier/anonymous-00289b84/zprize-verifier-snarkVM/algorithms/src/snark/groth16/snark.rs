@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{create_random_proof, generate_random_parameters, verify_proof, BatchProof, ProvingKey, VerifyingKey};
+use crate::{Prepare, SNARKError, VerificationError, SNARK, SRS};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::ToConstraintField;
+use snarkvm_r1cs::ConstraintSynthesizer;
+
+use rand::{CryptoRng, Rng};
+use std::borrow::Borrow;
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// The Groth16 proof system.
+#[derive(Clone, Debug)]
+pub struct Groth16<E: PairingEngine, Input: ToConstraintField<E::Fr> + ?Sized>(#[doc(hidden)] PhantomData<(E, Input)>);
+
+impl<E: PairingEngine, Input: ToConstraintField<E::Fr> + ?Sized> Groth16<E, Input> {
+    fn terminate(terminator: &AtomicBool) -> Result<(), SNARKError> {
+        if terminator.load(Ordering::Relaxed) { Err(SNARKError::Terminated) } else { Ok(()) }
+    }
+}
+
+impl<E: PairingEngine, Input: ToConstraintField<E::Fr> + ?Sized> SNARK for Groth16<E, Input> {
+    type BaseField = E::Fq;
+    type FSParameters = ();
+    type Proof = BatchProof<E>;
+    type ProvingKey = ProvingKey<E>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = ();
+    type UniversalSetupParameters = ();
+    type VerifierInput = Input;
+    type VerifyingKey = VerifyingKey<E>;
+
+    /// Groth16 has no universal setup; use `SRS::CircuitSpecific` in [`Self::setup`] instead.
+    fn universal_setup<R: Rng + CryptoRng>(
+        _: &Self::UniversalSetupConfig,
+        _: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        Err(SNARKError::ExpectedCircuitSpecificSRS)
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        let setup_time = start_timer!(|| "Groth16::Setup");
+        let proving_key = match srs {
+            SRS::CircuitSpecific(rng) => generate_random_parameters(circuit, rng)?,
+            SRS::Universal(_) => return Err(SNARKError::ExpectedCircuitSpecificSRS),
+        };
+        let verifying_key = proving_key.vk.clone();
+        end_timer!(setup_time);
+
+        Ok((proving_key, verifying_key))
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        proving_key: &Self::ProvingKey,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        let prover_time = start_timer!(|| format!("Groth16::ProveBatch of size {}", circuits.len()));
+        if circuits.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let mut proofs = Vec::with_capacity(circuits.len());
+        for circuit in circuits {
+            Self::terminate(terminator)?;
+            proofs.push(create_random_proof(circuit, proving_key, rng)?);
+        }
+        Self::terminate(terminator)?;
+        end_timer!(prover_time);
+
+        Ok(BatchProof { proofs })
+    }
+
+    fn verify_detailed<B: Borrow<Self::VerifierInput>>(
+        _: Option<&Self::FSParameters>,
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<(), VerificationError> {
+        let verifier_time = start_timer!(|| "Groth16::Verify");
+        if public_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch.into());
+        }
+        if proof.batch_size() != public_inputs.len() {
+            return Err(VerificationError::BatchSizeMismatch {
+                proof: proof.batch_size(),
+                inputs: public_inputs.len(),
+            });
+        }
+
+        let max_num_inputs = prepared_verifying_key.num_public_inputs();
+        for (index, (public_input, proof)) in public_inputs.iter().zip(&proof.proofs).enumerate() {
+            let public_input = public_input.borrow().to_field_elements().map_err(SNARKError::from)?;
+            if public_input.len() > max_num_inputs {
+                return Err(VerificationError::PublicInputLengthMismatch {
+                    index,
+                    expected: max_num_inputs,
+                    found: public_input.len(),
+                });
+            }
+            verify_proof(prepared_verifying_key, proof, &public_input)?;
+        }
+        end_timer!(verifier_time);
+
+        Ok(())
+    }
+}
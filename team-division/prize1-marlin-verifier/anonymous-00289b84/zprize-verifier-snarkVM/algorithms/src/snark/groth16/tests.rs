@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{snark::marlin::tests::Circuit, Prepare, SNARKError, VerificationError, SNARK, SRS};
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_utilities::{
+    rand::{test_crypto_rng, Uniform},
+    FromBytes,
+    ToBytes,
+};
+
+use rand::Rng;
+
+type TestSNARK = Groth16<Bls12_377, [Fr]>;
+
+fn sample_circuit<R: Rng>(rng: &mut R) -> (Circuit<Fr>, [Fr; 2]) {
+    let a = Fr::rand(rng);
+    let b = Fr::rand(rng);
+    let c = a * b;
+    let d = c * b;
+    (Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 }, [c, d])
+}
+
+#[test]
+fn prove_and_verify() {
+    let mut rng = test_crypto_rng();
+
+    for _ in 0..10 {
+        let (circuit, inputs) = sample_circuit(&mut rng);
+        let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+
+        let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&vk, inputs, &proof).unwrap());
+        assert!(!TestSNARK::verify(&vk, [inputs[1], inputs[0]], &proof).unwrap());
+    }
+}
+
+#[test]
+fn prove_batch_and_verify() {
+    let mut rng = test_crypto_rng();
+
+    let (circuit, _) = sample_circuit(&mut rng);
+    let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+
+    for batch_size in (0..4).map(|i| 2usize.pow(i)) {
+        let (circuits, inputs): (Vec<_>, Vec<_>) = (0..batch_size).map(|_| sample_circuit(&mut rng)).unzip();
+
+        let proof = TestSNARK::prove_batch(&pk, &circuits, &mut rng).unwrap();
+        assert_eq!(proof.batch_size(), batch_size);
+        assert!(TestSNARK::verify_batch(None, &vk, &inputs, &proof).unwrap());
+
+        let mut wrong_inputs = inputs.clone();
+        wrong_inputs[batch_size - 1] = [Fr::rand(&mut rng), Fr::rand(&mut rng)];
+        assert!(!TestSNARK::verify_batch(None, &vk, &wrong_inputs, &proof).unwrap());
+    }
+
+    let empty: &[Circuit<Fr>] = &[];
+    assert!(matches!(TestSNARK::prove_batch(&pk, empty, &mut rng), Err(SNARKError::EmptyBatch)));
+}
+
+#[test]
+fn verify_detailed_reports_rejection_reason() {
+    let mut rng = test_crypto_rng();
+
+    let (circuit, inputs) = sample_circuit(&mut rng);
+    let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+    let prepared_vk = vk.prepare();
+    let proof = TestSNARK::prove_batch(&pk, &[circuit, circuit], &mut rng).unwrap();
+
+    assert!(TestSNARK::verify_detailed(None, &prepared_vk, &[inputs, inputs], &proof).is_ok());
+
+    let result = TestSNARK::verify_detailed(None, &prepared_vk, &[inputs, [inputs[0], inputs[0]]], &proof);
+    assert!(matches!(result, Err(VerificationError::InvalidPairingCheck)));
+
+    let result = TestSNARK::verify_detailed(None, &prepared_vk, &[inputs], &proof);
+    assert!(matches!(result, Err(VerificationError::BatchSizeMismatch { proof: 2, inputs: 1 })));
+
+    let too_long = vec![inputs.to_vec(), vec![inputs[0]; 3]];
+    let result = TestSNARK::verify_detailed(None, &prepared_vk, &too_long, &proof);
+    assert!(matches!(result, Err(VerificationError::PublicInputLengthMismatch { index: 1, expected: 2, found: 3 })));
+}
+
+#[test]
+fn universal_srs_is_rejected() {
+    let mut rng = test_crypto_rng();
+
+    assert!(matches!(TestSNARK::universal_setup(&(), &mut rng), Err(SNARKError::ExpectedCircuitSpecificSRS)));
+
+    let (circuit, _) = sample_circuit(&mut rng);
+    let result = TestSNARK::setup::<_, rand_chacha::ChaChaRng>(&circuit, &mut SRS::Universal(&()));
+    assert!(matches!(result, Err(SNARKError::ExpectedCircuitSpecificSRS)));
+}
+
+#[test]
+fn serialization_roundtrip() {
+    let mut rng = test_crypto_rng();
+
+    let (circuit, inputs) = sample_circuit(&mut rng);
+    let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+    let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+
+    let pk_bytes = pk.to_bytes_le().unwrap();
+    assert_eq!(pk, ProvingKey::<Bls12_377>::read_le(&pk_bytes[..]).unwrap());
+
+    let vk_bytes = vk.to_bytes_le().unwrap();
+    let candidate_vk = VerifyingKey::<Bls12_377>::read_le(&vk_bytes[..]).unwrap();
+    assert_eq!(vk, candidate_vk);
+
+    let proof_bytes = proof.to_bytes_le().unwrap();
+    let candidate_proof = BatchProof::<Bls12_377>::read_le(&proof_bytes[..]).unwrap();
+    assert_eq!(proof, candidate_proof);
+
+    assert!(TestSNARK::verify(&candidate_vk, inputs, &candidate_proof).unwrap());
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{PreparedVerifyingKey, Proof, VerifyingKey};
+use crate::{SNARKError, VerificationError};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+
+/// Prepares the verifying key for use in pairings.
+pub fn prepare_verifying_key<E: PairingEngine>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
+    PreparedVerifyingKey {
+        alpha_g1_beta_g2: E::pairing(vk.alpha_g1, vk.beta_g2),
+        gamma_g2_neg_pc: (-vk.gamma_g2).prepare(),
+        delta_g2_neg_pc: (-vk.delta_g2).prepare(),
+        vk,
+    }
+}
+
+/// Verifies `proof` against the public inputs `public_inputs`, which exclude the constant `1`,
+/// and returns the reason for rejecting it, if any.
+///
+/// As with Marlin, missing trailing public inputs are treated as zero.
+pub fn verify_proof<E: PairingEngine>(
+    pvk: &PreparedVerifyingKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[E::Fr],
+) -> Result<(), VerificationError> {
+    if public_inputs.len() > pvk.num_public_inputs() {
+        return Err(VerificationError::PublicInputLengthMismatch {
+            index: 0,
+            expected: pvk.num_public_inputs(),
+            found: public_inputs.len(),
+        });
+    }
+
+    let (constant_base, input_bases) = pvk
+        .vk
+        .gamma_abc_g1
+        .split_first()
+        .ok_or_else(|| SNARKError::Message("The verifying key is missing its public input bases".to_string()))?;
+
+    let mut g_ic = constant_base.to_projective();
+    for (input, base) in public_inputs.iter().zip(input_bases) {
+        g_ic += *base * *input;
+    }
+
+    let qap = E::miller_loop(
+        [
+            (&proof.a.prepare(), &proof.b.prepare()),
+            (&g_ic.to_affine().prepare(), &pvk.gamma_g2_neg_pc),
+            (&proof.c.prepare(), &pvk.delta_g2_neg_pc),
+        ]
+        .into_iter(),
+    );
+
+    match E::final_exponentiation(&qap) {
+        Some(test) if test == pvk.alpha_g1_beta_g2 => Ok(()),
+        _ => Err(VerificationError::InvalidPairingCheck),
+    }
+}
@@ -40,10 +40,17 @@ pub struct AHPForR1CS<F: Field, MM: MarlinMode> {
     mode: PhantomData<MM>,
 }
 
-pub(crate) fn witness_label(poly: &str, i: usize) -> String {
+/// Returns the label of the witness polynomial `poly` of the `i`-th instance in a batch.
+pub fn witness_label(poly: &str, i: usize) -> String {
     format!("{poly}_{:0>8}", i)
 }
 
+/// Returns the label of the circuit-specific polynomial `poly` of the `j`-th circuit in a proof.
+/// The first circuit keeps the plain label, so that proofs for a single circuit are unchanged.
+pub fn circuit_label(poly: &str, j: usize) -> String {
+    if j == 0 { poly.to_string() } else { format!("{poly}_circuit_{j}") }
+}
+
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// The linear combinations that are statically known to evaluate to zero.
     #[rustfmt::skip]
@@ -142,49 +149,24 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     /// Construct the linear combinations that are checked by the AHP.
-    /// Public input should be unformatted.
+    /// Public input should be unformatted, and is given per circuit.
     #[allow(non_snake_case)]
     pub fn construct_linear_combinations<E: EvaluationsProvider<F>>(
-        public_inputs: &[Vec<F>],
+        public_inputs: &[Vec<Vec<F>>],
         evals: &E,
         prover_third_message: &prover::ThirdMessage<F>,
         state: &verifier::State<F, MM>,
     ) -> Result<BTreeMap<String, LinearCombination<F>>, AHPError> {
         assert!(!public_inputs.is_empty());
-        let constraint_domain = state.constraint_domain;
-
-        let non_zero_a_domain = state.non_zero_a_domain;
-        let non_zero_b_domain = state.non_zero_b_domain;
-        let non_zero_c_domain = state.non_zero_c_domain;
-
-        let largest_non_zero_domain =
-            Self::max_non_zero_domain_helper(state.non_zero_a_domain, state.non_zero_b_domain, state.non_zero_c_domain);
-
-        let public_inputs = public_inputs
-            .iter()
-            .map(|p| {
-                let public_input = prover::ConstraintSystem::format_public_input(p);
-                Self::formatted_public_input_is_admissible(&public_input).map(|_| public_input)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let input_domain = EvaluationDomain::new(public_inputs[0].len()).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let max_constraint_domain = state.max_constraint_domain;
+        let largest_non_zero_domain = state.max_non_zero_domain;
 
         let first_round_msg = state.first_round_message.as_ref().unwrap();
         let alpha = first_round_msg.alpha;
         let eta_a = F::one();
         let eta_b = first_round_msg.eta_b;
         let eta_c = first_round_msg.eta_c;
-        let batch_combiners = &first_round_msg.batch_combiners;
-        let prover::ThirdMessage { sum_a, sum_b, sum_c } = prover_third_message;
-
-        #[rustfmt::skip]
-        let t_at_beta =
-            eta_a * state.non_zero_a_domain.size_as_field_element * sum_a +
-            eta_b * state.non_zero_b_domain.size_as_field_element * sum_b +
-            eta_c * state.non_zero_c_domain.size_as_field_element * sum_c;
-        let r_b = state.third_round_message.as_ref().unwrap().r_b;
-        let r_c = state.third_round_message.as_ref().unwrap().r_c;
+        let third_round_msg = state.third_round_message.as_ref().unwrap();
 
         let beta = state.second_round_message.unwrap().beta;
         let gamma = state.gamma.unwrap();
@@ -192,88 +174,126 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mut linear_combinations = BTreeMap::new();
 
         // Lincheck sumcheck:
-        let z_b_s = (0..state.batch_size)
-            .map(|i| {
-                let z_b_i = witness_label("z_b", i);
-                LinearCombination::new(z_b_i.clone(), [(F::one(), z_b_i)])
-            })
-            .collect::<Vec<_>>();
         let g_1 = LinearCombination::new("g_1", [(F::one(), "g_1")]);
-
-        let r_alpha_at_beta = constraint_domain.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
-        let v_H_at_alpha = constraint_domain.evaluate_vanishing_polynomial(alpha);
-        let v_H_at_beta = constraint_domain.evaluate_vanishing_polynomial(beta);
-        let v_X_at_beta = input_domain.evaluate_vanishing_polynomial(beta);
-
-        let z_b_s_at_beta = z_b_s.iter().map(|z_b| evals.get_lc_eval(z_b, beta)).collect::<Result<Vec<_>, _>>()?;
-        let batch_z_b_at_beta: F =
-            z_b_s_at_beta.iter().zip_eq(batch_combiners).map(|(z_b_at_beta, combiner)| *z_b_at_beta * combiner).sum();
         let g_1_at_beta = evals.get_lc_eval(&g_1, beta)?;
+        let v_H_max_at_beta = max_constraint_domain.evaluate_vanishing_polynomial(beta);
+        let mut lincheck_sumcheck = LinearCombination::empty("lincheck_sumcheck");
+        if MM::ZK {
+            lincheck_sumcheck.add(F::one(), "mask_poly");
+        }
 
-        let lag_at_beta = input_domain.evaluate_all_lagrange_coefficients(beta);
-        let combined_x_at_beta = batch_combiners
-            .iter()
-            .zip_eq(&public_inputs)
-            .map(|(c, x)| x.iter().zip_eq(&lag_at_beta).map(|(x, l)| *x * l).sum::<F>() * c)
-            .sum::<F>();
-
-        #[rustfmt::skip]
-        let lincheck_sumcheck = {
-            let mut lincheck_sumcheck = LinearCombination::empty("lincheck_sumcheck");
-            if MM::ZK {
-                lincheck_sumcheck.add(F::one(), "mask_poly");
-            }
-            for (i, (z_b_i_at_beta, combiner)) in z_b_s_at_beta.iter().zip_eq(batch_combiners).enumerate() {
+        //  Matrix sumcheck:
+        let mut matrix_sumcheck = LinearCombination::empty("matrix_sumcheck");
+
+        // The instances of all circuits are numbered consecutively, in the order of the circuits.
+        let mut batch_combiners = first_round_msg.batch_combiners.as_slice();
+        let mut instance_offset = 0;
+        for (circuit_index, (circuit_state, public_inputs)) in
+            state.circuit_specific_states.iter().zip_eq(public_inputs).enumerate()
+        {
+            let constraint_domain = circuit_state.constraint_domain;
+            let batch_size = circuit_state.batch_size;
+            assert_eq!(public_inputs.len(), batch_size);
+            let (combiners, remaining_combiners) = batch_combiners.split_at(batch_size);
+            batch_combiners = remaining_combiners;
+
+            let public_inputs = public_inputs
+                .iter()
+                .map(|p| {
+                    let public_input = prover::ConstraintSystem::format_public_input(p);
+                    Self::formatted_public_input_is_admissible(&public_input).map(|_| public_input)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let input_domain =
+                EvaluationDomain::new(public_inputs[0].len()).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+
+            let prover::MatrixSums { sum_a, sum_b, sum_c } = &prover_third_message.sums[circuit_index];
+
+            #[rustfmt::skip]
+            let t_at_beta =
+                eta_a * circuit_state.non_zero_a_domain.size_as_field_element * sum_a +
+                eta_b * circuit_state.non_zero_b_domain.size_as_field_element * sum_b +
+                eta_c * circuit_state.non_zero_c_domain.size_as_field_element * sum_c;
+
+            let z_b_s = (instance_offset..instance_offset + batch_size)
+                .map(|i| {
+                    let z_b_i = witness_label("z_b", i);
+                    LinearCombination::new(z_b_i.clone(), [(F::one(), z_b_i)])
+                })
+                .collect::<Vec<_>>();
+
+            let r_alpha_at_beta = constraint_domain.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
+            let v_H_at_alpha = constraint_domain.evaluate_vanishing_polynomial(alpha);
+            let v_H_at_beta = constraint_domain.evaluate_vanishing_polynomial(beta);
+            let v_X_at_beta = input_domain.evaluate_vanishing_polynomial(beta);
+            // The lincheck of this circuit is lifted from its own constraint domain to the largest one.
+            let selector_at_beta = max_constraint_domain.evaluate_selector_polynomial(constraint_domain, beta);
+
+            let z_b_s_at_beta = z_b_s.iter().map(|z_b| evals.get_lc_eval(z_b, beta)).collect::<Result<Vec<_>, _>>()?;
+            let batch_z_b_at_beta: F =
+                z_b_s_at_beta.iter().zip_eq(combiners).map(|(z_b_at_beta, combiner)| *z_b_at_beta * combiner).sum();
+
+            let lag_at_beta = input_domain.evaluate_all_lagrange_coefficients(beta);
+            let combined_x_at_beta = combiners
+                .iter()
+                .zip_eq(&public_inputs)
+                .map(|(c, x)| x.iter().zip_eq(&lag_at_beta).map(|(x, l)| *x * l).sum::<F>() * c)
+                .sum::<F>();
+
+            for (i, (z_b_i_at_beta, combiner)) in z_b_s_at_beta.iter().zip_eq(combiners).enumerate() {
+                let z_a_coeff = selector_at_beta * r_alpha_at_beta * combiner * (eta_a + eta_c * z_b_i_at_beta);
+                let w_coeff = -selector_at_beta * t_at_beta * v_X_at_beta * combiner;
                 lincheck_sumcheck
-                    .add(r_alpha_at_beta * combiner * (eta_a + eta_c * z_b_i_at_beta), witness_label("z_a", i))
-                    .add(-t_at_beta * v_X_at_beta * combiner, witness_label("w", i));
+                    .add(z_a_coeff, witness_label("z_a", instance_offset + i))
+                    .add(w_coeff, witness_label("w", instance_offset + i));
             }
             lincheck_sumcheck
-                .add(r_alpha_at_beta * eta_b * batch_z_b_at_beta, LCTerm::One)
-                .add(-t_at_beta * combined_x_at_beta, LCTerm::One)
-                .add(-v_H_at_beta, "h_1")
-                .add(-beta * g_1_at_beta, LCTerm::One);
-            lincheck_sumcheck
-        };
-        debug_assert!(evals.get_lc_eval(&lincheck_sumcheck, beta)?.is_zero());
+                .add(selector_at_beta * r_alpha_at_beta * eta_b * batch_z_b_at_beta, LCTerm::One)
+                .add(-selector_at_beta * t_at_beta * combined_x_at_beta, LCTerm::One);
+
+            for z_b in z_b_s {
+                linear_combinations.insert(z_b.label.clone(), z_b);
+            }
 
-        for z_b in z_b_s {
-            linear_combinations.insert(z_b.label.clone(), z_b);
+            let matrices = [
+                ("a", circuit_state.non_zero_a_domain, *sum_a, third_round_msg.r_a[circuit_index]),
+                ("b", circuit_state.non_zero_b_domain, *sum_b, third_round_msg.r_b[circuit_index]),
+                ("c", circuit_state.non_zero_c_domain, *sum_c, third_round_msg.r_c[circuit_index]),
+            ];
+            for (matrix, non_zero_domain, sum, randomizer) in matrices {
+                let g_label = circuit_label(&format!("g_{matrix}"), circuit_index);
+                let g = LinearCombination::new(g_label.clone(), [(F::one(), g_label)]);
+                let g_at_gamma = evals.get_lc_eval(&g, gamma)?;
+                let selector = largest_non_zero_domain.evaluate_selector_polynomial(non_zero_domain, gamma);
+                let lhs = Self::construct_lhs(
+                    matrix,
+                    circuit_index,
+                    alpha,
+                    beta,
+                    gamma,
+                    v_H_at_alpha * v_H_at_beta,
+                    g_at_gamma,
+                    sum,
+                    selector,
+                );
+                matrix_sumcheck += (randomizer, &lhs);
+                linear_combinations.insert(g.label.clone(), g);
+            }
+
+            instance_offset += batch_size;
         }
-        linear_combinations.insert("g_1".into(), g_1);
-        linear_combinations.insert("lincheck_sumcheck".into(), lincheck_sumcheck);
 
-        //  Matrix sumcheck:
-        let mut matrix_sumcheck = LinearCombination::empty("matrix_sumcheck");
+        lincheck_sumcheck.add(-v_H_max_at_beta, "h_1").add(-beta * g_1_at_beta, LCTerm::One);
+        debug_assert!(evals.get_lc_eval(&lincheck_sumcheck, beta)?.is_zero());
 
-        let g_a = LinearCombination::new("g_a", [(F::one(), "g_a")]);
-        let g_a_at_gamma = evals.get_lc_eval(&g_a, gamma)?;
-        let selector_a = largest_non_zero_domain.evaluate_selector_polynomial(non_zero_a_domain, gamma);
-        let lhs_a =
-            Self::construct_lhs("a", alpha, beta, gamma, v_H_at_alpha * v_H_at_beta, g_a_at_gamma, *sum_a, selector_a);
-        matrix_sumcheck += &lhs_a;
-
-        let g_b = LinearCombination::new("g_b", [(F::one(), "g_b")]);
-        let g_b_at_gamma = evals.get_lc_eval(&g_b, gamma)?;
-        let selector_b = largest_non_zero_domain.evaluate_selector_polynomial(non_zero_b_domain, gamma);
-        let lhs_b =
-            Self::construct_lhs("b", alpha, beta, gamma, v_H_at_alpha * v_H_at_beta, g_b_at_gamma, *sum_b, selector_b);
-        matrix_sumcheck += (r_b, &lhs_b);
-
-        let g_c = LinearCombination::new("g_c", [(F::one(), "g_c")]);
-        let g_c_at_gamma = evals.get_lc_eval(&g_c, gamma)?;
-        let selector_c = largest_non_zero_domain.evaluate_selector_polynomial(non_zero_c_domain, gamma);
-        let lhs_c =
-            Self::construct_lhs("c", alpha, beta, gamma, v_H_at_alpha * v_H_at_beta, g_c_at_gamma, *sum_c, selector_c);
-        matrix_sumcheck += (r_c, &lhs_c);
+        linear_combinations.insert("g_1".into(), g_1);
+        linear_combinations.insert("lincheck_sumcheck".into(), lincheck_sumcheck);
 
         matrix_sumcheck -=
             &LinearCombination::new("h_2", [(largest_non_zero_domain.evaluate_vanishing_polynomial(gamma), "h_2")]);
         debug_assert!(evals.get_lc_eval(&matrix_sumcheck, gamma)?.is_zero());
 
-        linear_combinations.insert("g_a".into(), g_a);
-        linear_combinations.insert("g_b".into(), g_b);
-        linear_combinations.insert("g_c".into(), g_c);
         linear_combinations.insert("matrix_sumcheck".into(), matrix_sumcheck);
 
         Ok(linear_combinations)
@@ -282,6 +302,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     #[allow(clippy::too_many_arguments)]
     fn construct_lhs(
         label: &str,
+        circuit_index: usize,
         alpha: F,
         beta: F,
         gamma: F,
@@ -290,15 +311,15 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         sum: F,
         selector_at_gamma: F,
     ) -> LinearCombination<F> {
-        let a =
-            LinearCombination::new("a_poly_".to_string() + label, [(v_h_at_alpha_beta, "val_".to_string() + label)]);
+        let index_label = |poly: &str| circuit_label(&(poly.to_string() + label), circuit_index);
+        let a = LinearCombination::new("a_poly_".to_string() + label, [(v_h_at_alpha_beta, index_label("val_"))]);
         let alpha_beta = alpha * beta;
 
         let mut b = LinearCombination::new("denom_".to_string() + label, [
             (alpha_beta, LCTerm::One),
-            (-alpha, ("row_".to_string() + label).into()),
-            (-beta, ("col_".to_string() + label).into()),
-            (F::one(), ("row_col_".to_string() + label).into()),
+            (-alpha, index_label("row_").into()),
+            (-beta, index_label("col_").into()),
+            (F::one(), index_label("row_col_").into()),
         ]);
         b *= gamma * g_at_gamma + sum;
 
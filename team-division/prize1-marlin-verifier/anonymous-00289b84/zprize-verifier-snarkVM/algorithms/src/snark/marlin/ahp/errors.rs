@@ -27,6 +27,8 @@ pub enum AHPError {
     InvalidPublicInputLength,
     /// During verification, a required evaluation is missing
     MissingEval(String),
+    /// No circuits were provided to the prover or verifier.
+    NoCircuits,
     /// Currently we only support square constraint matrices.
     NonSquareMatrix,
     /// During synthesis, our polynomials ended up being too high of degree
@@ -97,6 +97,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             f: PhantomData,
         };
 
+        // The prover, the verifier and the Lagrange bases of the SRS assume power-of-two domains,
+        // which nest into each other, so the index does not use `EvaluationDomain::new_mixed_radix` yet.
+        // Supporting mixed-radix domains end to end is left to a follow-up change.
         let constraint_domain =
             EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let input_domain =
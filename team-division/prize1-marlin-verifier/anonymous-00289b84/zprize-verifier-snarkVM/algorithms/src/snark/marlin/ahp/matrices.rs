@@ -218,8 +218,7 @@ mod tests {
         matrix[row].iter().find_map(|(f, i)| (i == &col).then(|| *f)).unwrap_or_else(F::zero)
     }
 
-    #[test]
-    fn check_arithmetization() {
+    fn check_arithmetization_with(interpolation_domain: fn(usize) -> Option<EvaluationDomain<F>>) {
         let a = vec![
             vec![(F::one(), 1), (F::one(), 2)],
             vec![(F::one(), 3)],
@@ -261,7 +260,7 @@ mod tests {
 
         for (matrix, label) in [(a, "a"), (b, "b"), (c, "c")] {
             let num_non_zero = num_non_zero(&matrix);
-            let interpolation_domain = EvaluationDomain::new(num_non_zero).unwrap();
+            let interpolation_domain = interpolation_domain(num_non_zero).unwrap();
             let arith = arithmetize_matrix(&matrix, label, interpolation_domain, constraint_domain, input_domain);
 
             for (k_index, k) in interpolation_domain.elements().enumerate() {
@@ -284,4 +283,16 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn check_arithmetization() {
+        check_arithmetization_with(EvaluationDomain::new);
+    }
+
+    #[test]
+    fn check_mixed_radix_arithmetization() {
+        // The matrix `a` has 19 non-zero entries, which fit in a domain of size 24 instead of 32.
+        assert_eq!(EvaluationDomain::<F>::compute_size_of_mixed_radix_domain(19), Some(24));
+        check_arithmetization_with(EvaluationDomain::new_mixed_radix);
+    }
 }
@@ -115,6 +115,24 @@ impl<F: PrimeField> SecondOracles<F> {
 /// The third set of prover oracles.
 #[derive(Debug)]
 pub struct ThirdOracles<F: PrimeField> {
+    /// The oracles of each circuit, in the order in which the circuits are proven.
+    pub matrix_oracles: Vec<MatrixOracles<F>>,
+}
+
+impl<F: PrimeField> ThirdOracles<F> {
+    /// Iterate over the polynomials output by the prover in the third round.
+    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        self.matrix_oracles.iter().flat_map(|oracles| oracles.iter())
+    }
+
+    pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
+        self.matrix_oracles.iter().all(|oracles| oracles.matches_info(info))
+    }
+}
+
+/// The third round oracles of a single circuit.
+#[derive(Debug)]
+pub struct MatrixOracles<F: PrimeField> {
     /// The polynomial `g_a` resulting from the second sumcheck.
     pub g_a: LabeledPolynomial<F>,
     /// The polynomial `g_b` resulting from the second sumcheck.
@@ -123,8 +141,8 @@ pub struct ThirdOracles<F: PrimeField> {
     pub g_c: LabeledPolynomial<F>,
 }
 
-impl<F: PrimeField> ThirdOracles<F> {
-    /// Iterate over the polynomials output by the prover in the third round.
+impl<F: PrimeField> MatrixOracles<F> {
+    /// Iterate over the polynomials of this circuit.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         [&self.g_a, &self.g_b, &self.g_c].into_iter()
     }
@@ -69,28 +69,41 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         rng: &mut R,
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
-        let constraint_domain = state.constraint_domain;
         let batch_size = state.batch_size;
 
-        let z_a = state.z_a.take().unwrap();
-        let z_b = state.z_b.take().unwrap();
-        let private_variables = core::mem::take(&mut state.private_variables);
-        assert_eq!(z_a.len(), batch_size);
-        assert_eq!(z_b.len(), batch_size);
-        assert_eq!(private_variables.len(), batch_size);
+        let witnesses = state
+            .circuit_specific_states
+            .iter_mut()
+            .map(|circuit_state| {
+                let z_a = circuit_state.z_a.take().unwrap();
+                let z_b = circuit_state.z_b.take().unwrap();
+                let private_variables = core::mem::take(&mut circuit_state.private_variables);
+                assert_eq!(z_a.len(), circuit_state.batch_size);
+                assert_eq!(z_b.len(), circuit_state.batch_size);
+                assert_eq!(private_variables.len(), circuit_state.batch_size);
+                (z_a, z_b, private_variables)
+            })
+            .collect::<Vec<_>>();
         let mut r_b_s = Vec::with_capacity(batch_size);
 
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(3 * batch_size);
-        let state_ref = &state;
-        for (i, (z_a, z_b, private_variables, x_poly)) in
-            itertools::izip!(z_a, z_b, private_variables, &state.x_poly).enumerate()
-        {
-            job_pool.add_job(move || Self::calculate_w(witness_label("w", i), private_variables, x_poly, state_ref));
-            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_a", i), z_a, false, state_ref, None));
-            let r_b = F::rand(rng);
-            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_b", i), z_b, true, state_ref, Some(r_b)));
-            if MM::ZK {
-                r_b_s.push(r_b);
+        // The instances of all circuits are numbered consecutively, in the order of the circuits.
+        let mut i = 0;
+        for (circuit_state, (z_a, z_b, private_variables)) in state.circuit_specific_states.iter().zip_eq(witnesses) {
+            for (z_a, z_b, private_variables, x_poly) in
+                itertools::izip!(z_a, z_b, private_variables, &circuit_state.x_poly)
+            {
+                job_pool.add_job(move || {
+                    Self::calculate_w(witness_label("w", i), private_variables, x_poly, circuit_state)
+                });
+                job_pool.add_job(move || Self::calculate_z_m(witness_label("z_a", i), z_a, false, circuit_state, None));
+                let r_b = F::rand(rng);
+                job_pool
+                    .add_job(move || Self::calculate_z_m(witness_label("z_b", i), z_b, true, circuit_state, Some(r_b)));
+                if MM::ZK {
+                    r_b_s.push(r_b);
+                }
+                i += 1;
             }
         }
 
@@ -108,7 +121,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .collect::<Vec<_>>();
         assert_eq!(batches.len(), batch_size);
 
-        let mask_poly = Self::calculate_mask_poly(constraint_domain, rng);
+        let mask_poly = Self::calculate_mask_poly(state.max_constraint_domain, rng);
 
         let oracles = prover::FirstOracles { batches, mask_poly };
         assert!(oracles.matches_info(&Self::first_round_polynomial_info(batch_size)));
@@ -154,7 +167,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         label: String,
         private_variables: Vec<F>,
         x_poly: &DensePolynomial<F>,
-        state: &prover::State<'a, F, MM>,
+        state: &prover::CircuitSpecificState<'a, F, MM>,
     ) -> PoolResult<'a, F> {
         let constraint_domain = state.constraint_domain;
         let input_domain = state.input_domain;
@@ -191,7 +204,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         label: impl ToString,
         evaluations: Vec<F>,
         will_be_evaluated: bool,
-        state: &prover::State<'a, F, MM>,
+        state: &prover::CircuitSpecificState<'a, F, MM>,
         r: Option<F>,
     ) -> PoolResult<'a, F> {
         let constraint_domain = state.constraint_domain;
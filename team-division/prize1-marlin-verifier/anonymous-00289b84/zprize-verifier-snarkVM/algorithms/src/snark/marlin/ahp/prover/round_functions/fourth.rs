@@ -17,6 +17,7 @@
 use std::collections::BTreeMap;
 
 use crate::{
+    fft::DensePolynomial,
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{verifier, AHPError, AHPForR1CS},
@@ -25,8 +26,9 @@ use crate::{
     },
 };
 
+use itertools::Itertools;
 use rand_core::RngCore;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{One, PrimeField, Zero};
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the fourth round message and the next state.
@@ -35,14 +37,23 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         state: prover::State<'a, F, MM>,
         _r: &mut R,
     ) -> Result<prover::FourthOracles<F>, AHPError> {
-        let verifier::ThirdMessage { r_b, r_c, .. } = verifier_message;
-        let [mut lhs_a, mut lhs_b, mut lhs_c] = state.lhs_polynomials.unwrap();
-        lhs_b *= *r_b;
-        lhs_c *= *r_c;
-
-        lhs_a += &lhs_b;
-        lhs_a += &lhs_c;
-        let h_2 = LabeledPolynomial::new("h_2".into(), lhs_a, None, None);
+        let verifier::ThirdMessage { r_a, r_b, r_c } = verifier_message;
+        let mut h_2 = DensePolynomial::zero();
+        for ((circuit_state, r_a), (r_b, r_c)) in
+            state.circuit_specific_states.into_iter().zip_eq(r_a).zip_eq(r_b.iter().zip_eq(r_c))
+        {
+            let [mut lhs_a, mut lhs_b, mut lhs_c] = circuit_state.lhs_polynomials.unwrap();
+            if !r_a.is_one() {
+                lhs_a *= *r_a;
+            }
+            lhs_b *= *r_b;
+            lhs_c *= *r_c;
+
+            h_2 += &lhs_a;
+            h_2 += &lhs_b;
+            h_2 += &lhs_c;
+        }
+        let h_2 = LabeledPolynomial::new("h_2".into(), h_2, None, None);
         let oracles = prover::FourthOracles { h_2 };
         assert!(oracles.matches_info(&Self::fourth_round_polynomial_info()));
         Ok(oracles)
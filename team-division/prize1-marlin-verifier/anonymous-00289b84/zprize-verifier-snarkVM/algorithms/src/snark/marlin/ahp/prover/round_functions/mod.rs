@@ -37,12 +37,26 @@ mod third;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Initialize the AHP prover.
+    /// `circuits` pairs each indexed circuit with the instances of it that are proven.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>>(
-        index: &'a Circuit<F, MM>,
-        circuits: &[C],
+        circuits: &[(&'a Circuit<F, MM>, &[C])],
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
+        let circuit_specific_states = circuits
+            .iter()
+            .map(|&(index, instances)| Self::init_circuit_specific_state(index, instances))
+            .collect::<Result<Vec<_>, _>>()?;
+        let state = prover::State::initialize(circuit_specific_states)?;
+        end_timer!(init_time);
+
+        Ok(state)
+    }
+
+    fn init_circuit_specific_state<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F, MM>,
+        circuits: &[C],
+    ) -> Result<prover::CircuitSpecificState<'a, F, MM>, AHPError> {
         // Perform matrix multiplications.
         let (padded_public_variables, private_variables, z_a, z_b) = cfg_iter!(circuits)
             .map(|circuit| {
@@ -101,14 +115,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                     .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
                     .collect();
                 end_timer!(eval_z_b_time);
-                end_timer!(init_time);
                 Ok((padded_public_variables, private_variables, z_a, z_b))
             })
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
             .multiunzip();
 
-        let mut state = prover::State::initialize(padded_public_variables, private_variables, index)?;
+        let mut state = prover::CircuitSpecificState::initialize(padded_public_variables, private_variables, index)?;
         state.z_a = Some(z_a);
         state.z_b = Some(z_b);
 
@@ -54,8 +54,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     /// Output the degree bounds of oracles in the first round.
-    pub fn second_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
+    pub fn second_round_polynomial_info(infos: &[CircuitInfo<F>]) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        let constraint_domain_size = infos
+            .iter()
+            .map(|info| EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap())
+            .max()
+            .unwrap();
         [
             PolynomialInfo::new("g_1".into(), Some(constraint_domain_size - 2), Self::zk_bound()),
             PolynomialInfo::new("h_1".into(), None, None),
@@ -73,49 +77,79 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     ) -> (prover::SecondOracles<F>, prover::State<'a, F, MM>) {
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
 
-        let constraint_domain = state.constraint_domain;
+        let max_constraint_domain = state.max_constraint_domain;
         let zk_bound = Self::zk_bound();
 
         let verifier::FirstMessage { alpha, eta_b, eta_c, batch_combiners } = verifier_message;
 
-        let (summed_z_m, t) = Self::calculate_summed_z_m_and_t(&state, *alpha, *eta_b, *eta_c, batch_combiners);
-
-        let z_time = start_timer!(|| "Compute z poly");
-        let z = cfg_iter!(state.first_round_oracles.as_ref().unwrap().batches)
-            .zip_eq(batch_combiners)
-            .zip(&state.x_poly)
-            .map(|((b, &coeff), x_poly)| {
-                let mut z = b.w_poly.polynomial().as_dense().unwrap().mul_by_vanishing_poly(state.input_domain);
-                // Zip safety: `x_poly` is smaller than `z_poly`.
-                z.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
-                cfg_iter_mut!(z.coeffs).for_each(|z| *z *= &coeff);
-                z
-            })
-            .sum::<DensePolynomial<F>>();
-        assert!(z.degree() <= constraint_domain.size());
+        // The instances of all circuits are numbered consecutively, in the order of the circuits.
+        let mut batch_combiners = batch_combiners.as_slice();
+        let mut entries = state.first_round_oracles.as_ref().unwrap().batches.as_slice();
+        let mut lifted_lhs_s = Vec::with_capacity(state.circuit_specific_states.len());
+        for circuit_state in &state.circuit_specific_states {
+            let (combiners, remaining_combiners) = batch_combiners.split_at(circuit_state.batch_size);
+            let (circuit_entries, remaining_entries) = entries.split_at(circuit_state.batch_size);
+            batch_combiners = remaining_combiners;
+            entries = remaining_entries;
+
+            let constraint_domain = circuit_state.constraint_domain;
+            let (summed_z_m, t) =
+                Self::calculate_summed_z_m_and_t(circuit_state, circuit_entries, *alpha, *eta_b, *eta_c, combiners);
+
+            let z_time = start_timer!(|| "Compute z poly");
+            let z = cfg_iter!(circuit_entries)
+                .zip_eq(combiners)
+                .zip(&circuit_state.x_poly)
+                .map(|((b, &coeff), x_poly)| {
+                    let mut z =
+                        b.w_poly.polynomial().as_dense().unwrap().mul_by_vanishing_poly(circuit_state.input_domain);
+                    // Zip safety: `x_poly` is smaller than `z_poly`.
+                    z.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
+                    cfg_iter_mut!(z.coeffs).for_each(|z| *z *= &coeff);
+                    z
+                })
+                .sum::<DensePolynomial<F>>();
+            assert!(z.degree() <= constraint_domain.size());
+
+            end_timer!(z_time);
 
-        end_timer!(z_time);
+            let lhs = Self::calculate_lhs(circuit_state, t, summed_z_m, z, *alpha);
 
-        let sumcheck_lhs = Self::calculate_lhs(&state, t, summed_z_m, z, *alpha);
+            debug_assert!(
+                lhs.evaluate_over_domain_by_ref(constraint_domain).evaluations.into_iter().sum::<F>().is_zero()
+            );
+            lifted_lhs_s.push(Self::lift_to_domain(lhs, constraint_domain, max_constraint_domain));
+        }
+
+        let mask_poly = state.first_round_oracles.as_ref().unwrap().mask_poly.as_ref();
+        assert_eq!(MM::ZK, mask_poly.is_some());
+        let mut sumcheck_lhs = lifted_lhs_s.into_iter().sum::<DensePolynomial<F>>();
+        sumcheck_lhs += &mask_poly.map_or(SparsePolynomial::zero(), |p| p.polynomial().as_sparse().unwrap().clone());
 
         debug_assert!(
-            sumcheck_lhs.evaluate_over_domain_by_ref(constraint_domain).evaluations.into_iter().sum::<F>().is_zero()
+            sumcheck_lhs
+                .evaluate_over_domain_by_ref(max_constraint_domain)
+                .evaluations
+                .into_iter()
+                .sum::<F>()
+                .is_zero()
         );
 
         let sumcheck_time = start_timer!(|| "Compute sumcheck h and g polys");
-        let (h_1, x_g_1) = sumcheck_lhs.divide_by_vanishing_poly(constraint_domain).unwrap();
+        let (h_1, x_g_1) = sumcheck_lhs.divide_by_vanishing_poly(max_constraint_domain).unwrap();
         let g_1 = DensePolynomial::from_coefficients_slice(&x_g_1.coeffs[1..]);
         drop(x_g_1);
         end_timer!(sumcheck_time);
 
-        assert!(g_1.degree() <= constraint_domain.size() - 2);
-        assert!(h_1.degree() <= 2 * constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
+        assert!(g_1.degree() <= max_constraint_domain.size() - 2);
+        assert!(h_1.degree() <= 2 * max_constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
 
         let oracles = prover::SecondOracles {
-            g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(constraint_domain.size() - 2), zk_bound),
+            g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(max_constraint_domain.size() - 2), zk_bound),
             h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
         };
-        assert!(oracles.matches_info(&Self::second_round_polynomial_info(&state.index.index_info)));
+        let infos = state.circuit_specific_states.iter().map(|s| s.index.index_info).collect::<Vec<_>>();
+        assert!(oracles.matches_info(&Self::second_round_polynomial_info(&infos)));
 
         state.verifier_first_message = Some(verifier_message.clone());
         end_timer!(round_time);
@@ -123,8 +157,27 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         (oracles, state)
     }
 
+    /// Multiplies `lhs`, which sums to zero over `domain`, by the selector polynomial of `domain` in `max_domain`,
+    /// i.e. by `(|H| / |H_max|) * sum_{k < |H_max| / |H|} X^{k |H|}`, so that the result sums to zero over `max_domain`.
+    fn lift_to_domain(
+        lhs: DensePolynomial<F>,
+        domain: EvaluationDomain<F>,
+        max_domain: EvaluationDomain<F>,
+    ) -> DensePolynomial<F> {
+        if domain.size() == max_domain.size() {
+            return lhs;
+        }
+        let ratio = max_domain.size() / domain.size();
+        let scale = domain.size_as_field_element / max_domain.size_as_field_element;
+        let mut coeffs = vec![F::zero(); lhs.coeffs.len() + (ratio - 1) * domain.size()];
+        for k in 0..ratio {
+            cfg_iter_mut!(coeffs[k * domain.size()..]).zip(&lhs.coeffs).for_each(|(c, l)| *c += scale * l);
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
     fn calculate_lhs(
-        state: &prover::State<F, MM>,
+        state: &prover::CircuitSpecificState<F, MM>,
         t: DensePolynomial<F>,
         summed_z_m: DensePolynomial<F>,
         z: DensePolynomial<F>,
@@ -133,9 +186,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let constraint_domain = state.constraint_domain;
         let q_1_time = start_timer!(|| "Compute LHS of sumcheck");
 
-        let mask_poly = state.first_round_oracles.as_ref().unwrap().mask_poly.as_ref();
-        assert_eq!(MM::ZK, mask_poly.is_some());
-
         let mul_domain_size = (constraint_domain.size() + summed_z_m.coeffs.len()).max(t.coeffs.len() + z.len());
         let mul_domain =
             EvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
@@ -150,19 +200,18 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             fft::Evaluations::from_vec_and_domain(r_alpha_x_evals, mul_domain)
         };
         multiplier.add_evaluation(r_alpha_x_evals, "r_alpha_x");
-        let mut lhs = multiplier
+        let lhs = multiplier
             .element_wise_arithmetic_4_over_domain(mul_domain, ["r_alpha_x", "summed_z_m", "z", "t"], |a, b, c, d| {
                 a * b - c * d
             })
             .unwrap();
-
-        lhs += &mask_poly.map_or(SparsePolynomial::zero(), |p| p.polynomial().as_sparse().unwrap().clone());
         end_timer!(q_1_time);
         lhs
     }
 
     fn calculate_summed_z_m_and_t(
-        state: &prover::State<F, MM>,
+        state: &prover::CircuitSpecificState<F, MM>,
+        entries: &[prover::SingleEntry<F>],
         alpha: F,
         eta_b: F,
         eta_c: F,
@@ -173,11 +222,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let fft_precomputation = &state.index.fft_precomputation;
         let ifft_precomputation = &state.index.ifft_precomputation;
-        let first_msg = state.first_round_oracles.as_ref().unwrap();
         let mut job_pool = ExecutionPool::with_capacity(2 * state.batch_size);
         let eta_b_over_eta_c = eta_b * eta_c.inverse().unwrap();
         job_pool.add_job(|| {
-            cfg_iter!(first_msg.batches)
+            cfg_iter!(entries)
                 .zip_eq(batch_combiners)
                 .map(|(entry, combiner)| {
                     let z_a = entry.z_a_poly.polynomial().as_dense().unwrap();
@@ -27,7 +27,7 @@ use crate::{
     },
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
-        ahp::{indexer::CircuitInfo, verifier, AHPError, AHPForR1CS},
+        ahp::{circuit_label, indexer::CircuitInfo, verifier, AHPError, AHPForR1CS},
         matrices::MatrixArithmetization,
         prover,
         MarlinMode,
@@ -50,19 +50,23 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     }
 
     /// Output the degree bounds of oracles in the first round.
-    pub fn third_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
-        let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
-        let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
-
-        [
-            PolynomialInfo::new("g_a".into(), Some(non_zero_a_size - 2), None),
-            PolynomialInfo::new("g_b".into(), Some(non_zero_b_size - 2), None),
-            PolynomialInfo::new("g_c".into(), Some(non_zero_c_size - 2), None),
-        ]
-        .into_iter()
-        .map(|info| (info.label().into(), info))
-        .collect()
+    pub fn third_round_polynomial_info(infos: &[CircuitInfo<F>]) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        infos
+            .iter()
+            .enumerate()
+            .flat_map(|(j, info)| {
+                let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
+                let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
+                let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
+
+                [
+                    PolynomialInfo::new(circuit_label("g_a", j), Some(non_zero_a_size - 2), None),
+                    PolynomialInfo::new(circuit_label("g_b", j), Some(non_zero_b_size - 2), None),
+                    PolynomialInfo::new(circuit_label("g_c", j), Some(non_zero_c_size - 2), None),
+                ]
+            })
+            .map(|info| (info.label().into(), info))
+            .collect()
     }
 
     /// Output the third round message and the next state.
@@ -77,66 +81,76 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .verifier_first_message
             .as_ref()
             .expect("prover::State should include verifier_first_msg when prover_third_round is called");
+        let alpha = *alpha;
 
         let beta = verifier_message.beta;
 
-        let v_H_at_alpha = state.constraint_domain.evaluate_vanishing_polynomial(*alpha);
-        let v_H_at_beta = state.constraint_domain.evaluate_vanishing_polynomial(beta);
-
-        let v_H_alpha_v_H_beta = v_H_at_alpha * v_H_at_beta;
-
-        let largest_non_zero_domain_size = Self::max_non_zero_domain(&state.index.index_info).size_as_field_element;
-        let mut pool = ExecutionPool::with_capacity(3);
-        pool.add_job(|| {
-            Self::matrix_sumcheck_helper(
-                "a",
-                state.non_zero_a_domain,
-                &state.index.a_arith,
-                *alpha,
-                beta,
-                v_H_alpha_v_H_beta,
-                largest_non_zero_domain_size,
-                state.fft_precomputation(),
-                state.ifft_precomputation(),
-            )
-        });
-
-        pool.add_job(|| {
-            Self::matrix_sumcheck_helper(
-                "b",
-                state.non_zero_b_domain,
-                &state.index.b_arith,
-                *alpha,
-                beta,
-                v_H_alpha_v_H_beta,
-                largest_non_zero_domain_size,
-                state.fft_precomputation(),
-                state.ifft_precomputation(),
-            )
-        });
-
-        pool.add_job(|| {
-            Self::matrix_sumcheck_helper(
-                "c",
-                state.non_zero_c_domain,
-                &state.index.c_arith,
-                *alpha,
-                beta,
-                v_H_alpha_v_H_beta,
-                largest_non_zero_domain_size,
-                state.fft_precomputation(),
-                state.ifft_precomputation(),
-            )
-        });
-
-        let [(sum_a, lhs_a, g_a), (sum_b, lhs_b, g_b), (sum_c, lhs_c, g_c)]: [_; 3] =
-            pool.execute_all().try_into().unwrap();
-
-        let msg = prover::ThirdMessage { sum_a, sum_b, sum_c };
-        let oracles = prover::ThirdOracles { g_a, g_b, g_c };
-        state.lhs_polynomials = Some([lhs_a, lhs_b, lhs_c]);
-        state.sums = Some([sum_a, sum_b, sum_c]);
-        assert!(oracles.matches_info(&Self::third_round_polynomial_info(&state.index.index_info)));
+        let largest_non_zero_domain_size = state.max_non_zero_domain.size_as_field_element;
+        let mut sums = Vec::with_capacity(state.circuit_specific_states.len());
+        let mut matrix_oracles = Vec::with_capacity(state.circuit_specific_states.len());
+        for (j, circuit_state) in state.circuit_specific_states.iter_mut().enumerate() {
+            let v_H_at_alpha = circuit_state.constraint_domain.evaluate_vanishing_polynomial(alpha);
+            let v_H_at_beta = circuit_state.constraint_domain.evaluate_vanishing_polynomial(beta);
+
+            let v_H_alpha_v_H_beta = v_H_at_alpha * v_H_at_beta;
+
+            let circuit_state_ref = &*circuit_state;
+            let mut pool = ExecutionPool::with_capacity(3);
+            pool.add_job(|| {
+                Self::matrix_sumcheck_helper(
+                    circuit_label("g_a", j),
+                    circuit_state_ref.non_zero_a_domain,
+                    &circuit_state_ref.index.a_arith,
+                    alpha,
+                    beta,
+                    v_H_alpha_v_H_beta,
+                    largest_non_zero_domain_size,
+                    circuit_state_ref.fft_precomputation(),
+                    circuit_state_ref.ifft_precomputation(),
+                )
+            });
+
+            pool.add_job(|| {
+                Self::matrix_sumcheck_helper(
+                    circuit_label("g_b", j),
+                    circuit_state_ref.non_zero_b_domain,
+                    &circuit_state_ref.index.b_arith,
+                    alpha,
+                    beta,
+                    v_H_alpha_v_H_beta,
+                    largest_non_zero_domain_size,
+                    circuit_state_ref.fft_precomputation(),
+                    circuit_state_ref.ifft_precomputation(),
+                )
+            });
+
+            pool.add_job(|| {
+                Self::matrix_sumcheck_helper(
+                    circuit_label("g_c", j),
+                    circuit_state_ref.non_zero_c_domain,
+                    &circuit_state_ref.index.c_arith,
+                    alpha,
+                    beta,
+                    v_H_alpha_v_H_beta,
+                    largest_non_zero_domain_size,
+                    circuit_state_ref.fft_precomputation(),
+                    circuit_state_ref.ifft_precomputation(),
+                )
+            });
+
+            let [(sum_a, lhs_a, g_a), (sum_b, lhs_b, g_b), (sum_c, lhs_c, g_c)]: [_; 3] =
+                pool.execute_all().try_into().unwrap();
+
+            sums.push(prover::MatrixSums { sum_a, sum_b, sum_c });
+            matrix_oracles.push(prover::MatrixOracles { g_a, g_b, g_c });
+            circuit_state.lhs_polynomials = Some([lhs_a, lhs_b, lhs_c]);
+            circuit_state.sums = Some([sum_a, sum_b, sum_c]);
+        }
+
+        let msg = prover::ThirdMessage { sums };
+        let oracles = prover::ThirdOracles { matrix_oracles };
+        let infos = state.circuit_specific_states.iter().map(|s| s.index.index_info).collect::<Vec<_>>();
+        assert!(oracles.matches_info(&Self::third_round_polynomial_info(&infos)));
 
         end_timer!(round_time);
 
@@ -145,7 +159,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     #[allow(clippy::too_many_arguments)]
     fn matrix_sumcheck_helper(
-        label: &str,
+        label: String,
         non_zero_domain: EvaluationDomain<F>,
         arithmetization: &MatrixArithmetization<F>,
         alpha: F,
@@ -226,7 +240,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let multiplier = non_zero_domain.size_as_field_element / largest_non_zero_domain_size;
         cfg_iter_mut!(h.coeffs).for_each(|c| *c *= multiplier);
 
-        let g = LabeledPolynomial::new("g_".to_string() + label, g, Some(non_zero_domain.size() - 2), None);
+        let g = LabeledPolynomial::new(label, g, Some(non_zero_domain.size() - 2), None);
 
         assert!(h.degree() <= non_zero_domain.size() - 2);
         assert!(g.degree() <= non_zero_domain.size() - 2);
@@ -32,8 +32,8 @@ use crate::{
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::SynthesisError;
 
-/// State for the AHP prover.
-pub struct State<'a, F: PrimeField, MM: MarlinMode> {
+/// The part of the state of the AHP prover that is specific to one circuit.
+pub struct CircuitSpecificState<'a, F: PrimeField, MM: MarlinMode> {
     pub(super) index: &'a Circuit<F, MM>,
 
     /// A domain that is sized for the public input.
@@ -49,22 +49,22 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     /// A domain that is sized for the number of non-zero elements in C.
     pub(super) non_zero_c_domain: EvaluationDomain<F>,
 
-    /// The number of instances being proved in this batch.
-    pub(in crate::snark) batch_size: usize,
+    /// The number of instances of this circuit being proved.
+    pub(super) batch_size: usize,
 
-    /// The list of public inputs for each instance in the batch.
+    /// The list of public inputs for each instance of this circuit.
     /// The length of this list must be equal to the batch size.
     pub(super) padded_public_variables: Vec<Vec<F>>,
 
-    /// The list of private variables for each instance in the batch.
+    /// The list of private variables for each instance of this circuit.
     /// The length of this list must be equal to the batch size.
     pub(super) private_variables: Vec<Vec<F>>,
 
-    /// The list of Az vectors for each instance in the batch.
+    /// The list of Az vectors for each instance of this circuit.
     /// The length of this list must be equal to the batch size.
     pub(super) z_a: Option<Vec<Vec<F>>>,
 
-    /// The list of Bz vectors for each instance in the batch.
+    /// The list of Bz vectors for each instance of this circuit.
     /// The length of this list must be equal to the batch size.
     pub(super) z_b: Option<Vec<Vec<F>>>,
 
@@ -72,24 +72,13 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     /// The length of this list must be equal to the batch size.
     pub(super) x_poly: Vec<DensePolynomial<F>>,
 
-    /// The first round oracles sent by the prover.
-    /// The length of this list must be equal to the batch size.
-    pub(in crate::snark) first_round_oracles: Option<Arc<super::FirstOracles<'a, F>>>,
-
-    /// Randomizers for z_b.
-    /// The length of this list must be equal to the batch size.
-    pub(super) mz_poly_randomizer: Option<Vec<F>>,
-
-    /// The challenges sent by the verifier in the first round
-    pub(super) verifier_first_message: Option<verifier::FirstMessage<F>>,
-
     /// Polynomials involved in the holographic sumcheck.
     pub(super) lhs_polynomials: Option<[DensePolynomial<F>; 3]>,
     /// Polynomials involved in the holographic sumcheck.
     pub(super) sums: Option<[F; 3]>,
 }
 
-impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
+impl<'a, F: PrimeField, MM: MarlinMode> CircuitSpecificState<'a, F, MM> {
     pub fn initialize(
         padded_public_input: Vec<Vec<F>>,
         private_variables: Vec<Vec<F>>,
@@ -131,11 +120,68 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
             private_variables,
             z_a: None,
             z_b: None,
+            lhs_polynomials: None,
+            sums: None,
+        })
+    }
+
+    pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
+        &self.index.fft_precomputation
+    }
+
+    pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
+        &self.index.ifft_precomputation
+    }
+}
+
+/// State for the AHP prover.
+pub struct State<'a, F: PrimeField, MM: MarlinMode> {
+    /// The state of each circuit, in the order in which the circuits are proven.
+    pub(super) circuit_specific_states: Vec<CircuitSpecificState<'a, F, MM>>,
+
+    /// The largest constraint domain among all circuits.
+    pub(super) max_constraint_domain: EvaluationDomain<F>,
+
+    /// The largest non-zero domain among all matrices of all circuits.
+    pub(super) max_non_zero_domain: EvaluationDomain<F>,
+
+    /// The number of instances being proved in this batch, across all circuits.
+    pub(in crate::snark) batch_size: usize,
+
+    /// The first round oracles sent by the prover.
+    /// The length of this list must be equal to the batch size.
+    pub(in crate::snark) first_round_oracles: Option<Arc<super::FirstOracles<'a, F>>>,
+
+    /// Randomizers for z_b.
+    /// The length of this list must be equal to the batch size.
+    pub(super) mz_poly_randomizer: Option<Vec<F>>,
+
+    /// The challenges sent by the verifier in the first round
+    pub(super) verifier_first_message: Option<verifier::FirstMessage<F>>,
+}
+
+impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
+    pub fn initialize(circuit_specific_states: Vec<CircuitSpecificState<'a, F, MM>>) -> Result<Self, AHPError> {
+        let max_constraint_domain = circuit_specific_states
+            .iter()
+            .map(|state| state.constraint_domain)
+            .max_by_key(|domain| domain.size())
+            .ok_or(AHPError::NoCircuits)?;
+        let max_non_zero_domain = circuit_specific_states
+            .iter()
+            .flat_map(|state| [state.non_zero_a_domain, state.non_zero_b_domain, state.non_zero_c_domain])
+            .max_by_key(|domain| domain.size())
+            .ok_or(AHPError::NoCircuits)?;
+        let batch_size = circuit_specific_states.iter().map(|state| state.batch_size).sum();
+
+        Ok(Self {
+            circuit_specific_states,
+            max_constraint_domain,
+            max_non_zero_domain,
+            batch_size,
             first_round_oracles: None,
             mz_poly_randomizer: None,
             verifier_first_message: None,
-            lhs_polynomials: None,
-            sums: None,
         })
     }
 
@@ -144,21 +190,23 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         self.batch_size
     }
 
-    /// Get the public inputs for the entire batch.
-    pub fn public_inputs(&self) -> Vec<Vec<F>> {
-        self.padded_public_variables.iter().map(|v| super::ConstraintSystem::unformat_public_input(v)).collect()
+    /// Get the batch size of each circuit.
+    pub fn batch_sizes(&self) -> Vec<usize> {
+        self.circuit_specific_states.iter().map(|state| state.batch_size).collect()
     }
 
-    /// Get the padded public inputs for the entire batch.
-    pub fn padded_public_inputs(&self) -> Vec<Vec<F>> {
-        self.padded_public_variables.clone()
-    }
-
-    pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
-        &self.index.fft_precomputation
+    /// Get the public inputs for the entire batch, per circuit.
+    pub fn public_inputs(&self) -> Vec<Vec<Vec<F>>> {
+        self.circuit_specific_states
+            .iter()
+            .map(|state| {
+                state.padded_public_variables.iter().map(|v| super::ConstraintSystem::unformat_public_input(v)).collect()
+            })
+            .collect()
     }
 
-    pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
-        &self.index.ifft_precomputation
+    /// Get the padded public inputs for the entire batch, per circuit.
+    pub fn padded_public_inputs(&self) -> Vec<Vec<Vec<F>>> {
+        self.circuit_specific_states.iter().map(|state| state.padded_public_variables.clone()).collect()
     }
 }
@@ -16,7 +16,7 @@
 
 use snarkvm_fields::PrimeField;
 
-use crate::snark::marlin::{witness_label, MarlinMode};
+use crate::snark::marlin::{circuit_label, witness_label, MarlinMode};
 
 /// First message of the verifier.
 #[derive(Clone, Debug)]
@@ -39,18 +39,21 @@ pub struct SecondMessage<F> {
 }
 
 /// Third message of the verifier.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct ThirdMessage<F> {
-    /// Randomizer for the h-polynomial for `B`.
-    pub r_b: F,
-    /// Randomizer for the h-polynomial for `C`.
-    pub r_c: F,
+    /// Randomizers for the h-polynomial for `A` of each circuit. The first one is always one.
+    pub r_a: Vec<F>,
+    /// Randomizers for the h-polynomial for `B` of each circuit.
+    pub r_b: Vec<F>,
+    /// Randomizers for the h-polynomial for `C` of each circuit.
+    pub r_c: Vec<F>,
 }
 
 /// Query set of the verifier.
 #[derive(Clone, Debug)]
 pub struct QuerySet<F> {
     pub batch_size: usize,
+    pub num_circuits: usize,
     pub g_1_query: (String, F),
     pub z_b_query: (String, F),
     pub lincheck_sumcheck_query: (String, F),
@@ -74,7 +77,8 @@ impl<F: PrimeField> QuerySet<F> {
         // We also use an optimization: instead of explicitly calculating z_c, we
         // use the "virtual oracle" z_a * z_b
         Self {
-            batch_size: state.batch_size,
+            batch_size: state.batch_size(),
+            num_circuits: state.circuit_specific_states.len(),
             g_1_query: ("beta".into(), beta),
             z_b_query: ("beta".into(), beta),
             lincheck_sumcheck_query: ("beta".into(), beta),
@@ -96,9 +100,11 @@ impl<F: PrimeField> QuerySet<F> {
         query_set.insert(("g_1".into(), self.g_1_query.clone()));
         query_set.insert(("lincheck_sumcheck".into(), self.lincheck_sumcheck_query.clone()));
 
-        query_set.insert(("g_a".into(), self.g_a_query.clone()));
-        query_set.insert(("g_b".into(), self.g_b_query.clone()));
-        query_set.insert(("g_c".into(), self.g_c_query.clone()));
+        for j in 0..self.num_circuits {
+            query_set.insert((circuit_label("g_a", j), self.g_a_query.clone()));
+            query_set.insert((circuit_label("g_b", j), self.g_b_query.clone()));
+            query_set.insert((circuit_label("g_c", j), self.g_c_query.clone()));
+        }
         query_set.insert(("matrix_sumcheck".into(), self.matrix_sumcheck_query.clone()));
         query_set
     }
@@ -25,14 +25,25 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 
-/// State of the AHP verifier.
+/// Domains and batch size of one of the circuits being verified.
 #[derive(Debug)]
-pub struct State<F: PrimeField, MM: MarlinMode> {
+pub struct CircuitSpecificState<F: PrimeField> {
     pub(in crate::snark::marlin) batch_size: usize,
     pub(crate) constraint_domain: EvaluationDomain<F>,
     pub(crate) non_zero_a_domain: EvaluationDomain<F>,
     pub(crate) non_zero_b_domain: EvaluationDomain<F>,
     pub(crate) non_zero_c_domain: EvaluationDomain<F>,
+}
+
+/// State of the AHP verifier.
+#[derive(Debug)]
+pub struct State<F: PrimeField, MM: MarlinMode> {
+    /// The state of each circuit, in the order in which the circuits are proven.
+    pub(crate) circuit_specific_states: Vec<CircuitSpecificState<F>>,
+    /// The largest constraint domain among all circuits.
+    pub(crate) max_constraint_domain: EvaluationDomain<F>,
+    /// The largest non-zero domain among all matrices of all circuits.
+    pub(crate) max_non_zero_domain: EvaluationDomain<F>,
 
     pub(crate) first_round_message: Option<FirstMessage<F>>,
     pub(crate) second_round_message: Option<SecondMessage<F>>,
@@ -41,3 +52,10 @@ pub struct State<F: PrimeField, MM: MarlinMode> {
     pub(crate) gamma: Option<F>,
     pub(crate) mode: PhantomData<MM>,
 }
+
+impl<F: PrimeField, MM: MarlinMode> State<F, MM> {
+    /// Returns the total number of instances, across all circuits.
+    pub(in crate::snark::marlin) fn batch_size(&self) -> usize {
+        self.circuit_specific_states.iter().map(|state| state.batch_size).sum()
+    }
+}
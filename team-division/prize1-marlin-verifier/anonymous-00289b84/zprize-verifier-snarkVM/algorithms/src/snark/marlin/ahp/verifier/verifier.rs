@@ -21,7 +21,7 @@ use crate::{
     snark::marlin::{
         ahp::{
             indexer::CircuitInfo,
-            verifier::{FirstMessage, QuerySet, SecondMessage, State, ThirdMessage},
+            verifier::{CircuitSpecificState, FirstMessage, QuerySet, SecondMessage, State, ThirdMessage},
             AHPError,
             AHPForR1CS,
         },
@@ -34,42 +34,62 @@ use snarkvm_fields::PrimeField;
 
 impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
     /// Output the first message and next round state.
+    /// `circuits` holds the information and batch size of each circuit, in the order in which they are proven.
     pub fn verifier_first_round<BaseField: PrimeField, R: FiatShamirRng<TargetField, BaseField>>(
-        index_info: CircuitInfo<TargetField>,
-        batch_size: usize,
+        circuits: &[(CircuitInfo<TargetField>, usize)],
         fs_rng: &mut R,
     ) -> Result<(FirstMessage<TargetField>, State<TargetField, MM>), AHPError> {
-        // Check that the R1CS is a square matrix.
-        if index_info.num_constraints != index_info.num_variables {
-            return Err(AHPError::NonSquareMatrix);
+        let mut circuit_specific_states = Vec::with_capacity(circuits.len());
+        for (index_info, batch_size) in circuits {
+            // Check that the R1CS is a square matrix.
+            if index_info.num_constraints != index_info.num_variables {
+                return Err(AHPError::NonSquareMatrix);
+            }
+
+            let constraint_domain =
+                EvaluationDomain::new(index_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+
+            let non_zero_a_domain =
+                EvaluationDomain::new(index_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+            let non_zero_b_domain =
+                EvaluationDomain::new(index_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+            let non_zero_c_domain =
+                EvaluationDomain::new(index_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+
+            circuit_specific_states.push(CircuitSpecificState {
+                batch_size: *batch_size,
+                constraint_domain,
+                non_zero_a_domain,
+                non_zero_b_domain,
+                non_zero_c_domain,
+            });
         }
 
-        let constraint_domain =
-            EvaluationDomain::new(index_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_a_domain =
-            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_b_domain =
-            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let max_constraint_domain = circuit_specific_states
+            .iter()
+            .map(|state| state.constraint_domain)
+            .max_by_key(|domain| domain.size())
+            .ok_or(AHPError::NoCircuits)?;
+        let max_non_zero_domain = circuit_specific_states
+            .iter()
+            .flat_map(|state| [state.non_zero_a_domain, state.non_zero_b_domain, state.non_zero_c_domain])
+            .max_by_key(|domain| domain.size())
+            .ok_or(AHPError::NoCircuits)?;
+        let batch_size: usize = circuits.iter().map(|(_, batch_size)| batch_size).sum();
 
         let elems = fs_rng.squeeze_nonnative_field_elements(3 + batch_size - 1, OptimizationType::Weight)?;
         let (first, rest) = elems.split_at(3);
         let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().unwrap();
         let mut batch_combiners = vec![TargetField::one()];
         batch_combiners.extend_from_slice(rest);
-        assert!(!constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
+        assert!(!max_constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
 
         let message = FirstMessage { alpha, eta_b, eta_c, batch_combiners };
 
         let new_state = State {
-            batch_size,
-            constraint_domain,
-            non_zero_a_domain,
-            non_zero_b_domain,
-            non_zero_c_domain,
+            circuit_specific_states,
+            max_constraint_domain,
+            max_non_zero_domain,
             first_round_message: Some(message.clone()),
             second_round_message: None,
             third_round_message: None,
@@ -87,7 +107,7 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
     ) -> Result<(SecondMessage<TargetField>, State<TargetField, MM>), AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?;
         let beta = elems[0];
-        assert!(!state.constraint_domain.evaluate_vanishing_polynomial(beta).is_zero());
+        assert!(!state.max_constraint_domain.evaluate_vanishing_polynomial(beta).is_zero());
 
         let message = SecondMessage { beta };
         state.second_round_message = Some(message);
@@ -100,12 +120,20 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         mut state: State<TargetField, MM>,
         fs_rng: &mut R,
     ) -> Result<(ThirdMessage<TargetField>, State<TargetField, MM>), AHPError> {
-        let elems = fs_rng.squeeze_nonnative_field_elements(2, OptimizationType::Weight)?;
-        let r_b = elems[0];
-        let r_c = elems[1];
-        let message = ThirdMessage { r_b, r_c };
+        let num_circuits = state.circuit_specific_states.len();
+        let elems = fs_rng.squeeze_nonnative_field_elements(3 * num_circuits - 1, OptimizationType::Weight)?;
+        // The randomizer for `A` of the first circuit is fixed to one.
+        let mut r_a = vec![TargetField::one()];
+        let mut r_b = vec![elems[0]];
+        let mut r_c = vec![elems[1]];
+        for randomizers in elems[2..].chunks_exact(3) {
+            r_a.push(randomizers[0]);
+            r_b.push(randomizers[1]);
+            r_c.push(randomizers[2]);
+        }
+        let message = ThirdMessage { r_a, r_b, r_c };
 
-        state.third_round_message = Some(message);
+        state.third_round_message = Some(message.clone());
         Ok((message, state))
     }
 
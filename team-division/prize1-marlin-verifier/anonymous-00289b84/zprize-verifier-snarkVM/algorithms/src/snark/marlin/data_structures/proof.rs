@@ -35,12 +35,8 @@ pub struct Commitments<E: PairingEngine> {
     pub g_1: sonic_pc::Commitment<E>,
     /// Commitment to the `h_1` polynomial.
     pub h_1: sonic_pc::Commitment<E>,
-    /// Commitment to the `g_a` polynomial.
-    pub g_a: sonic_pc::Commitment<E>,
-    /// Commitment to the `g_b` polynomial.
-    pub g_b: sonic_pc::Commitment<E>,
-    /// Commitment to the `g_c` polynomial.
-    pub g_c: sonic_pc::Commitment<E>,
+    /// Commitments to the `g_a`, `g_b`, and `g_c` polynomials of each circuit.
+    pub matrix_commitments: Vec<MatrixCommitments<E>>,
     /// Commitment to the `h_2` polynomial.
     pub h_2: sonic_pc::Commitment<E>,
 }
@@ -57,9 +53,9 @@ impl<E: PairingEngine> Commitments<E> {
         CanonicalSerialize::serialize_with_mode(&self.mask_poly, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.g_1, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.h_1, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_a, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_b, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_c, &mut writer, compress)?;
+        for comm in &self.matrix_commitments {
+            comm.serialize_with_mode(&mut writer, compress)?;
+        }
         CanonicalSerialize::serialize_with_mode(&self.h_2, &mut writer, compress)?;
         Ok(())
     }
@@ -71,15 +67,14 @@ impl<E: PairingEngine> Commitments<E> {
         size += CanonicalSerialize::serialized_size(&self.mask_poly, compress);
         size += CanonicalSerialize::serialized_size(&self.g_1, compress);
         size += CanonicalSerialize::serialized_size(&self.h_1, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_a, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_b, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_c, compress);
+        size += self.matrix_commitments.iter().map(|comm| comm.serialized_size(compress)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.h_2, compress);
         size
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        num_circuits: usize,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -88,18 +83,35 @@ impl<E: PairingEngine> Commitments<E> {
         for _ in 0..batch_size {
             witness_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
+        let mask_poly = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let h_1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let mut matrix_commitments = Vec::with_capacity(num_circuits);
+        for _ in 0..num_circuits {
+            matrix_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
         Ok(Commitments {
             witness_commitments,
-            mask_poly: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_1: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            h_1: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_a: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_b: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_c: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            mask_poly,
+            g_1,
+            h_1,
+            matrix_commitments,
             h_2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
         })
     }
 }
+
+/// Commitments to the `g_a`, `g_b`, and `g_c` polynomials of a single circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MatrixCommitments<E: PairingEngine> {
+    /// Commitment to the `g_a` polynomial.
+    pub g_a: sonic_pc::Commitment<E>,
+    /// Commitment to the `g_b` polynomial.
+    pub g_b: sonic_pc::Commitment<E>,
+    /// Commitment to the `g_c` polynomial.
+    pub g_c: sonic_pc::Commitment<E>,
+}
+
 /// Commitments to the `w`, `z_a`, and `z_b` polynomials.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct WitnessCommitments<E: PairingEngine> {
@@ -117,12 +129,8 @@ pub struct Evaluations<F: PrimeField> {
     pub z_b_evals: Vec<F>,
     /// Evaluation of `g_1` at `beta`.
     pub g_1_eval: F,
-    /// Evaluation of `g_a` at `beta`.
-    pub g_a_eval: F,
-    /// Evaluation of `g_b` at `gamma`.
-    pub g_b_eval: F,
-    /// Evaluation of `g_c` at `gamma`.
-    pub g_c_eval: F,
+    /// Evaluations of the `g_a`, `g_b`, and `g_c` polynomials of each circuit at `gamma`.
+    pub matrix_evals: Vec<MatrixEvaluations<F>>,
 }
 
 impl<F: PrimeField> Evaluations<F> {
@@ -135,9 +143,9 @@ impl<F: PrimeField> Evaluations<F> {
             CanonicalSerialize::serialize_with_mode(z_b_eval, &mut writer, compress)?;
         }
         CanonicalSerialize::serialize_with_mode(&self.g_1_eval, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_a_eval, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_b_eval, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.g_c_eval, &mut writer, compress)?;
+        for evals in &self.matrix_evals {
+            evals.serialize_with_mode(&mut writer, compress)?;
+        }
         Ok(())
     }
 
@@ -145,14 +153,13 @@ impl<F: PrimeField> Evaluations<F> {
         let mut size = 0;
         size += self.z_b_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.g_1_eval, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_a_eval, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_b_eval, compress);
-        size += CanonicalSerialize::serialized_size(&self.g_c_eval, compress);
+        size += self.matrix_evals.iter().map(|evals| evals.serialized_size(compress)).sum::<usize>();
         size
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        num_circuits: usize,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -161,34 +168,46 @@ impl<F: PrimeField> Evaluations<F> {
         for _ in 0..batch_size {
             z_b_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
-        Ok(Evaluations {
-            z_b_evals,
-            g_1_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_a_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_b_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_c_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-        })
+        let g_1_eval = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let mut matrix_evals = Vec::with_capacity(num_circuits);
+        for _ in 0..num_circuits {
+            matrix_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        Ok(Evaluations { z_b_evals, g_1_eval, matrix_evals })
     }
 }
 
 impl<F: PrimeField> Evaluations<F> {
-    pub(crate) fn from_map(map: &std::collections::BTreeMap<String, F>, batch_size: usize) -> Self {
-        let z_b_evals = map.iter().filter_map(|(k, v)| k.starts_with("z_b_").then(|| *v)).collect::<Vec<_>>();
-        assert_eq!(z_b_evals.len(), batch_size);
-        Self { z_b_evals, g_1_eval: map["g_1"], g_a_eval: map["g_a"], g_b_eval: map["g_b"], g_c_eval: map["g_c"] }
+    pub(crate) fn from_map(map: &std::collections::BTreeMap<String, F>, batch_sizes: &[usize]) -> Self {
+        let batch_size = batch_sizes.iter().sum::<usize>();
+        let z_b_evals = (0..batch_size).map(|i| map[&format!("z_b_{i}")]).collect();
+        let matrix_evals = (0..batch_sizes.len())
+            .map(|j| MatrixEvaluations {
+                g_a_eval: map[&ahp::circuit_label("g_a", j)],
+                g_b_eval: map[&ahp::circuit_label("g_b", j)],
+                g_c_eval: map[&ahp::circuit_label("g_c", j)],
+            })
+            .collect();
+        Self { z_b_evals, g_1_eval: map["g_1"], matrix_evals }
     }
 
     pub(crate) fn get(&self, label: &str) -> Option<F> {
         if let Some(index) = label.strip_prefix("z_b_") {
-            self.z_b_evals.get(index.parse::<usize>().unwrap()).copied()
-        } else {
-            match label {
-                "g_1" => Some(self.g_1_eval),
-                "g_a" => Some(self.g_a_eval),
-                "g_b" => Some(self.g_b_eval),
-                "g_c" => Some(self.g_c_eval),
-                _ => None,
-            }
+            return self.z_b_evals.get(index.parse::<usize>().ok()?).copied();
+        }
+        if label == "g_1" {
+            return Some(self.g_1_eval);
+        }
+        let (poly, circuit_index) = match label.split_once("_circuit_") {
+            Some((poly, index)) => (poly, index.parse::<usize>().ok()?),
+            None => (label, 0),
+        };
+        let evals = self.matrix_evals.get(circuit_index)?;
+        match poly {
+            "g_a" => Some(evals.g_a_eval),
+            "g_b" => Some(evals.g_b_eval),
+            "g_c" => Some(evals.g_c_eval),
+            _ => None,
         }
     }
 }
@@ -197,25 +216,37 @@ impl<F: PrimeField> Valid for Evaluations<F> {
     fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
         self.z_b_evals.check()?;
         self.g_1_eval.check()?;
-        self.g_a_eval.check()?;
-        self.g_b_eval.check()?;
-        self.g_c_eval.check()
+        self.matrix_evals.check()
     }
 }
 
 impl<F: PrimeField> Evaluations<F> {
     pub fn to_field_elements(&self) -> Vec<F> {
         let mut result = self.z_b_evals.clone();
-        result.extend([self.g_1_eval, self.g_a_eval, self.g_b_eval, self.g_c_eval]);
+        result.push(self.g_1_eval);
+        for evals in &self.matrix_evals {
+            result.extend([evals.g_a_eval, evals.g_b_eval, evals.g_c_eval]);
+        }
         result
     }
 }
 
+/// Evaluations of the `g_a`, `g_b`, and `g_c` polynomials of a single circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MatrixEvaluations<F: PrimeField> {
+    /// Evaluation of `g_a` at `gamma`.
+    pub g_a_eval: F,
+    /// Evaluation of `g_b` at `gamma`.
+    pub g_b_eval: F,
+    /// Evaluation of `g_c` at `gamma`.
+    pub g_c_eval: F,
+}
+
 /// A zkSNARK proof.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Proof<E: PairingEngine> {
-    /// The number of instances being proven in this proof.
-    batch_size: usize,
+    /// The number of instances of each circuit being proven in this proof.
+    batch_sizes: Vec<usize>,
 
     /// Commitments to prover polynomials.
     pub commitments: Commitments<E>,
@@ -233,32 +264,93 @@ pub struct Proof<E: PairingEngine> {
 impl<E: PairingEngine> Proof<E> {
     /// Construct a new proof.
     pub fn new(
-        batch_size: usize,
+        batch_sizes: Vec<usize>,
         commitments: Commitments<E>,
         evaluations: Evaluations<E::Fr>,
         msg: ahp::prover::ThirdMessage<E::Fr>,
         pc_proof: sonic_pc::BatchLCProof<E>,
     ) -> Self {
-        Self { batch_size, commitments, evaluations, msg, pc_proof }
+        Self { batch_sizes, commitments, evaluations, msg, pc_proof }
+    }
+
+    /// Returns the number of instances being proven in this proof.
+    pub fn batch_size(&self) -> usize {
+        self.batch_sizes.iter().sum()
+    }
+
+    /// Returns the number of instances of each circuit being proven in this proof.
+    pub fn batch_sizes(&self) -> &[usize] {
+        &self.batch_sizes
+    }
+
+    /// Returns the number of circuits being proven in this proof.
+    pub fn num_circuits(&self) -> usize {
+        self.batch_sizes.len()
+    }
+}
+
+/// Marks the header of a proof of several circuits. A proof of a single circuit starts with its
+/// batch size alone, and its sums are not length-prefixed, so that it keeps the encoding it had
+/// before proofs could cover several circuits.
+const MULTI_CIRCUIT_FLAG: u64 = 1 << 63;
+
+impl<E: PairingEngine> Proof<E> {
+    fn serialize_batch_sizes<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        if let [batch_size] = self.batch_sizes[..] {
+            return CanonicalSerialize::serialize_with_mode(&(batch_size as u64), &mut writer, compress);
+        }
+        let header = MULTI_CIRCUIT_FLAG | self.batch_sizes.len() as u64;
+        CanonicalSerialize::serialize_with_mode(&header, &mut writer, compress)?;
+        for batch_size in &self.batch_sizes {
+            CanonicalSerialize::serialize_with_mode(&(*batch_size as u64), &mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_batch_sizes<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Vec<usize>, SerializationError> {
+        let header: u64 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if header & MULTI_CIRCUIT_FLAG == 0 {
+            return Ok(vec![usize::try_from(header).map_err(|_| SerializationError::InvalidData)?]);
+        }
+        let num_circuits = header & !MULTI_CIRCUIT_FLAG;
+        // A single circuit is always encoded without the flag.
+        if num_circuits < 2 {
+            return Err(SerializationError::InvalidData);
+        }
+        let mut batch_sizes = Vec::new();
+        for _ in 0..num_circuits {
+            let batch_size: u64 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+            batch_sizes.push(usize::try_from(batch_size).map_err(|_| SerializationError::InvalidData)?);
+        }
+        Ok(batch_sizes)
     }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
-        CanonicalSerialize::serialize_with_mode(&self.batch_size, &mut writer, compress)?;
+        self.serialize_batch_sizes(&mut writer, compress)?;
         Commitments::serialize_with_mode(&self.commitments, &mut writer, compress)?;
         Evaluations::serialize_with_mode(&self.evaluations, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.msg, &mut writer, compress)?;
+        for sums in &self.msg.sums {
+            CanonicalSerialize::serialize_with_mode(sums, &mut writer, compress)?;
+        }
         CanonicalSerialize::serialize_with_mode(&self.pc_proof, &mut writer, compress)?;
         Ok(())
     }
 
     fn serialized_size(&self, mode: Compress) -> usize {
         let mut size = 0;
-        size += CanonicalSerialize::serialized_size(&self.batch_size, mode);
+        size += match self.batch_sizes.len() {
+            1 => 0u64.serialized_size(mode),
+            n => (n + 1) * 0u64.serialized_size(mode),
+        };
         size += Commitments::serialized_size(&self.commitments, mode);
         size += Evaluations::serialized_size(&self.evaluations, mode);
-        size += CanonicalSerialize::serialized_size(&self.msg, mode);
+        size += self.msg.sums.iter().map(|sums| sums.serialized_size(mode)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.pc_proof, mode);
         size
     }
@@ -266,7 +358,7 @@ impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
 
 impl<E: PairingEngine> Valid for Proof<E> {
     fn check(&self) -> Result<(), SerializationError> {
-        self.batch_size.check()?;
+        self.batch_sizes.check()?;
         self.commitments.check()?;
         self.evaluations.check()?;
         self.msg.check()?;
@@ -280,12 +372,22 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let batch_size = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let batch_sizes = Self::deserialize_batch_sizes(&mut reader, compress, validate)?;
+        let batch_size = batch_sizes.iter().sum();
+        let num_circuits = batch_sizes.len();
+        let commitments =
+            Commitments::deserialize_with_mode(batch_size, num_circuits, &mut reader, compress, validate)?;
+        let evaluations =
+            Evaluations::deserialize_with_mode(batch_size, num_circuits, &mut reader, compress, validate)?;
+        let mut sums = Vec::with_capacity(num_circuits);
+        for _ in 0..num_circuits {
+            sums.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
         Ok(Proof {
-            batch_size,
-            commitments: Commitments::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            evaluations: Evaluations::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            msg: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            batch_sizes,
+            commitments,
+            evaluations,
+            msg: ahp::prover::ThirdMessage { sums },
             pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
         })
     }
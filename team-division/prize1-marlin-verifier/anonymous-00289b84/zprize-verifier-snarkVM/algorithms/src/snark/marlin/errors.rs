@@ -15,8 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    polycommit::PCError,
     snark::marlin::{ahp::AHPError, fiat_shamir::FiatShamirError},
     SNARKError,
+    VerificationError,
 };
 
 use core::fmt::Debug;
@@ -88,3 +90,28 @@ impl From<crate::polycommit::PCError> for SNARKError {
         .into()
     }
 }
+
+impl From<AHPError> for VerificationError {
+    fn from(err: AHPError) -> Self {
+        match err {
+            AHPError::MissingEval(label) => {
+                VerificationError::MalformedProof(format!("missing evaluation for {label}"))
+            }
+            AHPError::InvalidPublicInputLength => {
+                VerificationError::MalformedProof("the formatted public input length is not a power of two".into())
+            }
+            err => VerificationError::SNARKError(err.into()),
+        }
+    }
+}
+
+impl From<PCError> for VerificationError {
+    fn from(err: PCError) -> Self {
+        match err {
+            PCError::IncorrectNumberOfProofs { .. }
+            | PCError::MissingPolynomial { .. }
+            | PCError::MissingEvaluation { .. } => VerificationError::MalformedProof(err.to_string()),
+            err => VerificationError::SNARKError(err.into()),
+        }
+    }
+}
@@ -16,9 +16,24 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    msm::{MsmEngine, MsmRegistry},
+    polycommit::{
+        sonic_pc::{
+            Commitment,
+            CommitterKey,
+            Evaluations,
+            KZGAccumulator,
+            LabeledCommitment,
+            PairingCheck,
+            PolynomialInfo,
+            Randomness,
+            SonicKZG10,
+            VerifierKey,
+        },
+        PCError,
+    },
     snark::marlin::{
-        ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+        ahp::{circuit_label, AHPError, AHPForR1CS, EvaluationsProvider},
         fiat_shamir::traits::FiatShamirRng,
         params::OptimizationType,
         proof,
@@ -28,11 +43,13 @@ use crate::{
         CircuitVerifyingKey,
         MarlinError,
         MarlinMode,
+        PreparedCircuitVerifyingKey,
         Proof,
         UniversalSRS,
     },
     Prepare,
     SNARKError,
+    VerificationError,
     SNARK,
     SRS,
 };
@@ -44,7 +61,10 @@ use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSynthesizer;
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
-use std::{borrow::Borrow, sync::Arc};
+use std::{
+    borrow::{Borrow, Cow},
+    sync::Arc,
+};
 
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
@@ -148,15 +168,15 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
     }
 
-    fn init_sponge(
-        batch_size: usize,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
-        inputs: &[Vec<E::Fr>],
-    ) -> FS {
+    /// `circuits` pairs the batch size of each circuit with the commitments to its index.
+    /// `inputs` holds the padded public inputs of all instances, in the order of the circuits.
+    fn init_sponge(circuits: &[(usize, &[Commitment<E>])], inputs: &[Vec<E::Fr>]) -> FS {
         let mut sponge = FS::new();
         sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_bytes(&batch_size.to_le_bytes());
-        sponge.absorb_native_field_elements(circuit_commitments);
+        for (batch_size, circuit_commitments) in circuits {
+            sponge.absorb_bytes(&batch_size.to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+        }
         for input in inputs {
             sponge.absorb_nonnative_field_elements(input.iter().copied(), OptimizationType::Weight);
         }
@@ -165,14 +185,15 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
 
     fn init_sponge_with_parameters(
         fs_parameters: &FS::Parameters,
-        batch_size: usize,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
+        circuits: &[(usize, &[Commitment<E>])],
         inputs: &[Vec<E::Fr>],
     ) -> FS {
         let mut sponge = FS::new_with_parameters(fs_parameters);
         sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_bytes(&batch_size.to_le_bytes());
-        sponge.absorb_native_field_elements(circuit_commitments);
+        for (batch_size, circuit_commitments) in circuits {
+            sponge.absorb_bytes(&batch_size.to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+        }
         for input in inputs {
             sponge.absorb_nonnative_field_elements(input.iter().copied(), OptimizationType::Weight);
         }
@@ -199,75 +220,65 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
 
     fn absorb_with_msg(commitments: &[Commitment<E>], msg: &prover::ThirdMessage<E::Fr>, sponge: &mut FS) {
         Self::absorb(commitments, sponge);
-        sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c], OptimizationType::Weight);
+        let sums = msg.sums.iter().flat_map(|sums| [sums.sum_a, sums.sum_b, sums.sum_c]);
+        sponge.absorb_nonnative_field_elements(sums, OptimizationType::Weight);
     }
-}
-
-impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: FiatShamirRng<E::Fr, E::Fq>,
-    MM: MarlinMode,
-    Input: ToConstraintField<E::Fr> + ?Sized,
-{
-    type BaseField = E::Fq;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = Input;
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-    type FSParameters = FS::Parameters;
-
-    fn universal_setup<R: Rng + CryptoRng>(
-        max_degree: &Self::UniversalSetupConfig,
-        rng: &mut R,
-    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
 
-        let srs = SonicKZG10::<E, FS>::setup(*max_degree, rng).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
+    /// Proves instances of several circuits in a single proof.
+    ///
+    /// `keys_to_constraints` pairs the proving key of each circuit with the instances of it
+    /// that are proven. The keys must be derived from the same universal SRS. The order of the
+    /// circuits is bound into the proof, and the verifier must provide the keys in the same order.
+    pub fn prove_multi_circuit<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        Self::prove_multi_circuit_with_terminator(keys_to_constraints, &AtomicBool::new(false), zk_rng)
     }
 
-    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        circuit: &C,
-        srs: &mut SRS<R, Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
+    /// Same as [`Self::prove_multi_circuit`], but stops early once `terminator` is set.
+    pub fn prove_multi_circuit_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        let engine = MsmRegistry::<E::G1Affine>::current();
+        Self::prove_multi_circuit_with_engine(keys_to_constraints, &*engine, terminator, zk_rng)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        circuit_proving_key: &CircuitProvingKey<E, MM>,
-        circuits: &[C],
+    /// Same as [`Self::prove_multi_circuit_with_terminator`], but computes every commitment and
+    /// opening MSM on `engine` instead of the installed [`MsmRegistry`].
+    pub fn prove_multi_circuit_with_engine<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
+        engine: &dyn MsmEngine<E::G1Affine>,
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+    ) -> Result<Proof<E>, SNARKError> {
         let prover_time = start_timer!(|| "Marlin::Prover");
-        let batch_size = circuits.len();
-        if batch_size == 0 {
+        if keys_to_constraints.is_empty() || keys_to_constraints.iter().any(|(_, circuits)| circuits.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
 
         Self::terminate(terminator)?;
 
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+        let circuits = keys_to_constraints.iter().map(|(pk, circuits)| (&pk.circuit, *circuits)).collect::<Vec<_>>();
+        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuits)?;
         let public_input = prover_state.public_inputs();
-        let padded_public_input = prover_state.padded_public_inputs();
-        assert_eq!(prover_state.batch_size, batch_size);
+        let padded_public_input = prover_state.padded_public_inputs().concat();
+        let batch_sizes = prover_state.batch_sizes();
+
+        // The committer key must support the polynomials of every circuit.
+        let committer_key = match keys_to_constraints {
+            [(pk, _)] => Cow::Borrowed(&pk.committer_key),
+            _ => Cow::Owned(CommitterKey::union(keys_to_constraints.iter().map(|(pk, _)| &pk.committer_key))?),
+        };
 
-        let mut sponge = Self::init_sponge(
-            batch_size,
-            &circuit_proving_key.circuit_verifying_key.circuit_commitments,
-            &padded_public_input,
-        );
+        let circuit_commitments = keys_to_constraints
+            .iter()
+            .zip_eq(&batch_sizes)
+            .map(|((pk, _), batch_size)| (*batch_size, pk.circuit_verifying_key.circuit_commitments.as_slice()))
+            .collect::<Vec<_>>();
+        let mut sponge = Self::init_sponge(&circuit_commitments, &padded_public_input);
 
         // --------------------------------------------------------------------
         // First round
@@ -279,9 +290,11 @@ where
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit(
-                &circuit_proving_key.committer_key,
+            SonicKZG10::<E, FS>::commit_with_engine(
+                engine,
+                &committer_key,
                 first_round_oracles.iter_for_commit(),
+                terminator,
                 Some(zk_rng),
             )?
         };
@@ -290,11 +303,13 @@ where
         Self::absorb_labeled(&first_commitments, &mut sponge);
         Self::terminate(terminator)?;
 
-        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
-            circuit_proving_key.circuit_verifying_key.circuit_info,
-            batch_size,
-            &mut sponge,
-        )?;
+        let circuit_infos = keys_to_constraints
+            .iter()
+            .zip_eq(&batch_sizes)
+            .map(|((pk, _), batch_size)| (pk.circuit_verifying_key.circuit_info, *batch_size))
+            .collect::<Vec<_>>();
+        let (verifier_first_message, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_first_round(&circuit_infos, &mut sponge)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -306,8 +321,9 @@ where
         Self::terminate(terminator)?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
+        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_engine(
+            engine,
+            &committer_key,
             second_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
@@ -331,8 +347,9 @@ where
         Self::terminate(terminator)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
+        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_engine(
+            engine,
+            &committer_key,
             third_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
@@ -355,8 +372,9 @@ where
         Self::terminate(terminator)?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
+        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_engine(
+            engine,
+            &committer_key,
             fourth_oracles.iter().map(Into::into),
             terminator,
             Some(zk_rng),
@@ -370,13 +388,29 @@ where
 
         Self::terminate(terminator)?;
 
+        // The index polynomials of every circuit but the first are relabeled, so that all labels are unique.
+        let relabeled_index_polynomials: Vec<_> = keys_to_constraints
+            .iter()
+            .enumerate()
+            .skip(1)
+            .flat_map(|(j, (pk, _))| {
+                pk.circuit.iter().map(move |p| {
+                    let mut p = p.clone();
+                    p.info = PolynomialInfo::new(circuit_label(p.label(), j), p.degree_bound(), p.hiding_bound());
+                    p
+                })
+            })
+            .collect();
+
         // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = circuit_proving_key
+        let polynomials: Vec<_> = keys_to_constraints[0]
+            .0
             .circuit
             .iter() // 12 items
+            .chain(relabeled_index_polynomials.iter()) // 12 * (num_circuits - 1) items
             .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
             .chain(second_oracles.iter())// 2 items
-            .chain(third_oracles.iter())// 3 items
+            .chain(third_oracles.iter())// 3 * num_circuits items
             .chain(fourth_oracles.iter())// 1 item
             .collect();
 
@@ -392,6 +426,14 @@ where
                 z_b: *c[2].commitment(),
             })
             .collect();
+        let matrix_commitments = third_commitments
+            .chunks_exact(3)
+            .map(|c| proof::MatrixCommitments {
+                g_a: *c[0].commitment(),
+                g_b: *c[1].commitment(),
+                g_c: *c[2].commitment(),
+            })
+            .collect();
         #[rustfmt::skip]
         let commitments = proof::Commitments {
             witness_commitments,
@@ -400,20 +442,24 @@ where
             g_1: *second_commitments[0].commitment(),
             h_1: *second_commitments[1].commitment(),
 
-
-            g_a: *third_commitments[0].commitment(),
-            g_b: *third_commitments[1].commitment(),
-            g_c: *third_commitments[2].commitment(),
+            matrix_commitments,
 
             h_2: *fourth_commitments[0].commitment(),
         };
 
-        let labeled_commitments: Vec<_> = circuit_proving_key
-            .circuit_verifying_key
+        let labeled_commitments: Vec<_> = keys_to_constraints
             .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .enumerate()
+            .flat_map(|(j, (pk, _))| {
+                pk.circuit_verifying_key
+                    .iter()
+                    .cloned()
+                    .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().into_values())
+                    .map(move |(c, info)| {
+                        let info = PolynomialInfo::new(circuit_label(info.label(), j), None, None);
+                        LabeledCommitment::new_with_info(&info, c)
+                    })
+            })
             .chain(first_commitments.into_iter())
             .chain(second_commitments.into_iter())
             .chain(third_commitments.into_iter())
@@ -421,10 +467,9 @@ where
             .collect();
 
         // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
-            .circuit_commitment_randomness
-            .clone()
-            .into_iter()
+        let commitment_randomnesses: Vec<Randomness<E>> = keys_to_constraints
+            .iter()
+            .flat_map(|(pk, _)| pk.circuit_commitment_randomness.clone())
             .chain(first_commitment_randomnesses)
             .chain(second_commitment_randomnesses)
             .chain(third_commitment_randomnesses)
@@ -457,15 +502,16 @@ where
             }
         }
 
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
+        let evaluations = proof::Evaluations::from_map(&evaluations, &batch_sizes);
         end_timer!(eval_time);
 
         Self::terminate(terminator)?;
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements(), OptimizationType::Weight);
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            &circuit_proving_key.committer_key,
+        let pc_proof = SonicKZG10::<E, FS>::open_combinations_with_engine(
+            engine,
+            &committer_key,
             lc_s.values(),
             polynomials,
             &labeled_commitments,
@@ -476,25 +522,140 @@ where
 
         Self::terminate(terminator)?;
 
-        let proof = Proof::<E>::new(batch_size, commitments, evaluations, prover_third_message, pc_proof);
+        let proof = Proof::<E>::new(batch_sizes, commitments, evaluations, prover_third_message, pc_proof);
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
         end_timer!(prover_time);
 
         Ok(proof)
     }
 
-    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
-	fs_parameters: Option<&Self::FSParameters>,
-        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+    /// Verifies many independent proofs, possibly for different circuits, with a single
+    /// product of pairings. The AHP verifier is run for each proof, and the resulting
+    /// polynomial commitment checks are folded into one randomized pairing check.
+    ///
+    /// Returns one result per entry of `instances`. If the combined check rejects,
+    /// each proof is checked on its own to determine which of them are invalid.
+    /// Returns an error if verification of any proof could not be carried out.
+    pub fn verify_many<B: Borrow<Input>, R: Rng + CryptoRng>(
+        fs_parameters: Option<&FS::Parameters>,
+        instances: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B], &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<Vec<bool>, SNARKError> {
+        let verifier_time = start_timer!(|| format!("Marlin::VerifyMany with {} proofs", instances.len()));
+
+        let mut results = vec![false; instances.len()];
+        let mut pairing_checks = Vec::with_capacity(instances.len());
+        for (i, (prepared_verifying_key, public_inputs, proof)) in instances.iter().enumerate() {
+            match Self::prepare_pairing_check(fs_parameters, &[(*prepared_verifying_key, *public_inputs)], proof) {
+                Ok(pairing_check) => pairing_checks.push((i, pairing_check)),
+                Err(VerificationError::SNARKError(error)) => return Err(error),
+                // The proof was rejected before reaching the pairing check, and is left as `false`.
+                Err(_) => continue,
+            }
+        }
+
+        let all_are_correct = SonicKZG10::<E, FS>::check_many(
+            pairing_checks.iter().map(|(i, check)| (&instances[*i].0.orig_vk.verifier_key, check.clone())),
+            rng,
+        )?;
+        if all_are_correct {
+            pairing_checks.iter().for_each(|(i, _)| results[*i] = true);
+        } else {
+            for (i, pairing_check) in pairing_checks {
+                results[i] = SonicKZG10::<E, FS>::check_elems(&instances[i].0.orig_vk.verifier_key, pairing_check)?;
+            }
+        }
+
+        end_timer!(verifier_time, || format!(" Combined check for all proofs: {}", all_are_correct));
+        Ok(results)
+    }
+
+    /// Runs the AHP verifier on `proof`, and turns its polynomial commitment checks into an
+    /// accumulator instead of evaluating them. Accumulators of many proofs (under verifying keys
+    /// derived from the same universal SRS) can be merged with [`SonicKZG10::merge_accumulators`],
+    /// and verified at once with [`Self::decide`].
+    ///
+    /// Returns an error if the proof is rejected before reaching the pairing check.
+    pub fn accumulate<B: Borrow<Input>>(
+        fs_parameters: Option<&FS::Parameters>,
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E, MM>,
         public_inputs: &[B],
-        proof: &Self::Proof,
+        proof: &Proof<E>,
+    ) -> Result<KZGAccumulator<E>, VerificationError> {
+        let accumulate_time = start_timer!(|| "Marlin::Accumulate");
+        let pairing_check =
+            Self::prepare_pairing_check(fs_parameters, &[(prepared_verifying_key, public_inputs)], proof);
+        end_timer!(accumulate_time);
+        Ok(pairing_check?.into())
+    }
+
+    /// Decides `accumulator`, i.e. checks the pairing equations of all the proofs it accumulates.
+    /// Any verifying key derived from the same universal SRS as those proofs can be used.
+    pub fn decide(
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E, MM>,
+        accumulator: &KZGAccumulator<E>,
     ) -> Result<bool, SNARKError> {
-        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
-            return Err(SNARKError::EmptyBatch);
+        Ok(SonicKZG10::<E, FS>::decide(&prepared_verifying_key.orig_vk.verifier_key, accumulator)?)
+    }
+
+    /// Verifies a proof produced by [`Self::prove_multi_circuit`].
+    ///
+    /// `keys_to_inputs` pairs the verifying key of each circuit with the public inputs of its
+    /// instances, in the same order as the proving keys were given to the prover.
+    pub fn verify_multi_circuit<B: Borrow<Input>>(
+        fs_parameters: Option<&FS::Parameters>,
+        keys_to_inputs: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B])],
+        proof: &Proof<E>,
+    ) -> Result<(), VerificationError> {
+        let verifier_time =
+            start_timer!(|| format!("Marlin::VerifyMultiCircuit with {} circuits", keys_to_inputs.len()));
+        let pairing_check = Self::prepare_pairing_check(fs_parameters, keys_to_inputs, proof)?;
+        let verifier_key = Self::verifier_key(keys_to_inputs)?;
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_elems(verifier_key, pairing_check)?;
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+
+        match evaluations_are_correct {
+            true => Ok(()),
+            false => Err(VerificationError::InvalidPairingCheck),
+        }
+    }
+
+    /// Returns the verifier key that supports the largest degree among those of `keys_to_inputs`.
+    /// The keys must be trimmed from the same universal SRS.
+    fn verifier_key<'a, B>(
+        keys_to_inputs: &[(&'a PreparedCircuitVerifyingKey<E, MM>, &[B])],
+    ) -> Result<&'a VerifierKey<E>, VerificationError> {
+        let verifier_key = keys_to_inputs
+            .iter()
+            .map(|(key, _)| &key.orig_vk.verifier_key)
+            .max_by_key(|key| key.supported_degree())
+            .ok_or(SNARKError::EmptyBatch)?;
+        for (key, _) in keys_to_inputs {
+            if key.orig_vk.verifier_key.max_degree() != verifier_key.max_degree() {
+                return Err(PCError::MismatchedMaxDegree {
+                    expected: verifier_key.max_degree(),
+                    found: key.orig_vk.verifier_key.max_degree(),
+                }
+                .into());
+            }
         }
-        let verifier_time = start_timer!(|| "Marlin::Verify");
+        Ok(verifier_key)
+    }
 
+    /// Runs the AHP verifier on `proof`, and reduces the polynomial commitment checks to a
+    /// pairing equation that has not been evaluated yet.
+    fn prepare_pairing_check<B: Borrow<Input>>(
+        fs_parameters: Option<&FS::Parameters>,
+        keys_to_inputs: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B])],
+        proof: &Proof<E>,
+    ) -> Result<PairingCheck<E>, VerificationError> {
+        if keys_to_inputs.is_empty() || keys_to_inputs.iter().any(|(_, public_inputs)| public_inputs.is_empty()) {
+            return Err(SNARKError::EmptyBatch.into());
+        }
+        let verifier_key = Self::verifier_key(keys_to_inputs)?;
         let comms = &proof.commitments;
         let proof_has_correct_zk_mode = if MM::ZK {
             proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
@@ -502,14 +663,46 @@ where
             !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
         };
         if !proof_has_correct_zk_mode {
-            eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
-            );
-            return Ok(false);
+            return Err(VerificationError::IncorrectZKMode { expected: MM::ZK, found: proof.pc_proof.is_hiding() });
         }
 
-        let batch_size = public_inputs.len();
+        if proof.num_circuits() != keys_to_inputs.len() {
+            return Err(VerificationError::CircuitCountMismatch {
+                proof: proof.num_circuits(),
+                keys: keys_to_inputs.len(),
+            });
+        }
+        for (batch_size, (_, public_inputs)) in proof.batch_sizes().iter().zip_eq(keys_to_inputs) {
+            if *batch_size != public_inputs.len() {
+                return Err(VerificationError::BatchSizeMismatch { proof: *batch_size, inputs: public_inputs.len() });
+            }
+        }
+        if comms.witness_commitments.len() != proof.batch_size() {
+            return Err(VerificationError::MalformedProof(format!(
+                "expected {} witness commitments, found {}",
+                proof.batch_size(),
+                comms.witness_commitments.len()
+            )));
+        }
+        if proof.evaluations.z_b_evals.len() != proof.batch_size() {
+            return Err(VerificationError::MalformedProof(format!(
+                "expected {} evaluations of z_b, found {}",
+                proof.batch_size(),
+                proof.evaluations.z_b_evals.len()
+            )));
+        }
+        if comms.matrix_commitments.len() != proof.num_circuits()
+            || proof.evaluations.matrix_evals.len() != proof.num_circuits()
+            || proof.msg.sums.len() != proof.num_circuits()
+        {
+            return Err(VerificationError::MalformedProof(format!(
+                "expected the matrix sumcheck messages of {} circuits",
+                proof.num_circuits()
+            )));
+        }
+
+        let batch_size = proof.batch_size();
+        let circuit_infos = keys_to_inputs.iter().map(|(key, _)| key.orig_vk.circuit_info).collect::<Vec<_>>();
 
         let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
         let mut first_commitments = comms
@@ -531,56 +724,75 @@ where
             ));
         }
 
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+        let second_round_info = AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_infos);
         let second_commitments = [
             LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
             LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
         ];
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
+        let third_round_info = AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_infos);
+        let third_commitments = comms
+            .matrix_commitments
+            .iter()
+            .enumerate()
+            .flat_map(|(j, c)| {
+                [
+                    LabeledCommitment::new_with_info(&third_round_info[&circuit_label("g_a", j)], c.g_a),
+                    LabeledCommitment::new_with_info(&third_round_info[&circuit_label("g_b", j)], c.g_b),
+                    LabeledCommitment::new_with_info(&third_round_info[&circuit_label("g_c", j)], c.g_c),
+                ]
+            })
+            .collect::<Vec<_>>();
         let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
         let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
 
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
-                .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
-                })
-                .unzip()
-        };
+        let mut padded_public_inputs = Vec::with_capacity(batch_size);
+        let mut unformatted_public_inputs = Vec::with_capacity(keys_to_inputs.len());
+        let mut index = 0;
+        for (key, public_inputs) in keys_to_inputs {
+            let circuit_info = &key.orig_vk.circuit_info;
+            let input_domain = EvaluationDomain::<E::Fr>::new(circuit_info.num_public_inputs).unwrap();
+
+            let mut circuit_public_inputs = Vec::with_capacity(public_inputs.len());
+            for input in public_inputs.iter() {
+                let input = input.borrow().to_field_elements().map_err(SNARKError::from)?;
+                // The first public variable is always the constant one.
+                let max_num_inputs = circuit_info.num_public_inputs - 1;
+                if input.len() > max_num_inputs {
+                    return Err(VerificationError::PublicInputLengthMismatch {
+                        index,
+                        expected: max_num_inputs,
+                        found: input.len(),
+                    });
+                }
+                let mut new_input = vec![E::Fr::one()];
+                new_input.extend_from_slice(&input);
+                new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                if cfg!(debug_assertions) {
+                    println!("Number of padded public variables: {}", new_input.len());
+                }
+                circuit_public_inputs.push(prover::ConstraintSystem::unformat_public_input(&new_input));
+                padded_public_inputs.push(new_input);
+                index += 1;
+            }
+            unformatted_public_inputs.push(circuit_public_inputs);
+        }
 
+        let circuit_commitments = keys_to_inputs
+            .iter()
+            .map(|(key, public_inputs)| (public_inputs.len(), key.orig_vk.circuit_commitments.as_slice()))
+            .collect::<Vec<_>>();
         let mut sponge = match fs_parameters {
             Some(fs_parameters) => {
-                Self::init_sponge_with_parameters(fs_parameters, batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs)
-            },
-            None => {
-                Self::init_sponge(batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs)
+                Self::init_sponge_with_parameters(fs_parameters, &circuit_commitments, &padded_public_inputs)
             }
+            None => Self::init_sponge(&circuit_commitments, &padded_public_inputs),
         };
 
         // --------------------------------------------------------------------
         // First round
         Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        let circuits = circuit_infos.iter().copied().zip_eq(proof.batch_sizes().iter().copied()).collect::<Vec<_>>();
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(&circuits, &mut sponge)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -606,11 +818,17 @@ where
         // correct degree.
 
         // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
+        let commitments: Vec<_> = keys_to_inputs
             .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .enumerate()
+            .flat_map(|(j, (key, _))| {
+                key.orig_vk.iter().cloned().zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().into_values()).map(
+                    move |(c, info)| {
+                        let info = PolynomialInfo::new(circuit_label(info.label(), j), None, None);
+                        LabeledCommitment::new_with_info(&info, c)
+                    },
+                )
+            })
             .chain(first_commitments)
             .chain(second_commitments)
             .chain(third_commitments)
@@ -633,14 +851,14 @@ where
         }
 
         let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
+            &unformatted_public_inputs,
             &evaluations,
             &proof.msg,
             &verifier_state,
         )?;
 
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
+        let pairing_check = SonicKZG10::<E, FS>::check_combinations_elems(
+            verifier_key,
             lc_s.values(),
             &commitments,
             &query_set.to_set(),
@@ -649,16 +867,66 @@ where
             &mut sponge,
             fs_parameters,
         )?;
+        Ok(pairing_check)
+    }
+}
+
+impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: FiatShamirRng<E::Fr, E::Fq>,
+    MM: MarlinMode,
+    Input: ToConstraintField<E::Fr> + ?Sized,
+{
+    type BaseField = E::Fq;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<E>;
+    type VerifierInput = Input;
+    type VerifyingKey = CircuitVerifyingKey<E, MM>;
+    type FSParameters = FS::Parameters;
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::<E, FS>::Check failed");
+    fn universal_setup<R: Rng + CryptoRng>(
+        max_degree: &Self::UniversalSetupConfig,
+        rng: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
+
+        let srs = SonicKZG10::<E, FS>::setup(*max_degree, rng).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
         }
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        .map_err(SNARKError::from)
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_multi_circuit_with_terminator(&[(circuit_proving_key, circuits)], terminator, zk_rng)
+    }
+
+    fn verify_detailed<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: Option<&Self::FSParameters>,
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<(), VerificationError> {
+        Self::verify_multi_circuit(fs_parameters, &[(prepared_verifying_key, public_inputs)], proof)
     }
 }
 
@@ -74,18 +74,35 @@ mod marlin {
         MarlinHidingMode,
         MarlinNonHidingMode,
         MarlinSNARK,
+        UniversalSRS,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::rand::{test_crypto_rng, Uniform};
 
     use blake2::Blake2s256;
     use core::ops::MulAssign;
+    use rand::{CryptoRng, Rng};
 
     type MarlinSonicInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
 
     type MarlinSonicPoswInst =
         MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinNonHidingMode, [Fr]>;
 
+    /// Samples a universal SRS which supports the circuits sampled by `sample_circuit`.
+    fn test_universal_srs<R: Rng + CryptoRng>(rng: &mut R) -> UniversalSRS<Bls12_377> {
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        MarlinSonicInst::universal_setup(&max_degree, rng).unwrap()
+    }
+
+    /// Samples a circuit with the given number of constraints and variables, and returns it with its public inputs.
+    fn sample_circuit<R: Rng>(num_constraints: usize, num_variables: usize, rng: &mut R) -> (Circuit<Fr>, [Fr; 2]) {
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * b;
+        let d = c * b;
+        (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, [c, d])
+    }
+
     macro_rules! impl_marlin_test {
         ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
             struct $test_struct {}
@@ -291,6 +308,317 @@ mod marlin {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn verify_many_across_circuits() {
+        use crate::Prepare;
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let mut prepared_vks = vec![];
+        let mut instances = vec![];
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let (circ, inputs) = sample_circuit(num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+            let proof = MarlinSonicInst::prove_batch(&index_pk, &[circ, circ], rng).unwrap();
+
+            prepared_vks.push(index_vk.prepare());
+            instances.push((vec![inputs, inputs], proof));
+        }
+
+        let batch: Vec<_> =
+            prepared_vks.iter().zip(&instances).map(|(pvk, (inputs, proof))| (pvk, &inputs[..], proof)).collect();
+        let results = MarlinSonicInst::verify_many(None, &batch, rng).unwrap();
+        assert_eq!(results, vec![true, true, true]);
+
+        // Tamper with the public inputs of the second proof; only it should be rejected.
+        instances[1].0[1] = [Fr::rand(rng), Fr::rand(rng)];
+        let batch: Vec<_> =
+            prepared_vks.iter().zip(&instances).map(|(pvk, (inputs, proof))| (pvk, &inputs[..], proof)).collect();
+        let results = MarlinSonicInst::verify_many(None, &batch, rng).unwrap();
+        assert_eq!(results, vec![true, false, true]);
+
+        // A malformed proof is rejected before the pairing check, without aborting the batch.
+        instances[1].1.commitments.witness_commitments.pop();
+        let batch: Vec<_> =
+            prepared_vks.iter().zip(&instances).map(|(pvk, (inputs, proof))| (pvk, &inputs[..], proof)).collect();
+        let results = MarlinSonicInst::verify_many(None, &batch, rng).unwrap();
+        assert_eq!(results, vec![true, false, true]);
+
+        // An instance without public inputs can not be verified, and aborts the batch.
+        let mut batch = batch;
+        batch[2].1 = &[][..];
+        assert!(MarlinSonicInst::verify_many(None, &batch, rng).is_err());
+    }
+
+    #[test]
+    fn accumulate_and_decide_across_circuits() {
+        use crate::{
+            polycommit::sonic_pc::{KZGAccumulator, SonicKZG10},
+            Prepare,
+        };
+        use snarkvm_utilities::{FromBytes, ToBytes};
+
+        type FS = FiatShamirChaChaRng<Fr, Fq, Blake2s256>;
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let mut prepared_vks = vec![];
+        let mut accumulators = vec![];
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let (circ, inputs) = sample_circuit(num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+            let prepared_vk = index_vk.prepare();
+            let proof = MarlinSonicInst::prove_batch(&index_pk, &[circ, circ], rng).unwrap();
+
+            let accumulator = MarlinSonicInst::accumulate(None, &prepared_vk, &[inputs, inputs], &proof).unwrap();
+            assert!(MarlinSonicInst::decide(&prepared_vk, &accumulator).unwrap());
+
+            // An accumulator for the wrong public inputs is not rejected until it is decided.
+            let wrong_inputs = [Fr::rand(rng), Fr::rand(rng)];
+            let invalid = MarlinSonicInst::accumulate(None, &prepared_vk, &[inputs, wrong_inputs], &proof).unwrap();
+            assert!(!MarlinSonicInst::decide(&prepared_vk, &invalid).unwrap());
+
+            prepared_vks.push(prepared_vk);
+            accumulators.push((accumulator, invalid));
+        }
+
+        let valid = accumulators.iter().map(|(valid, _)| valid.clone());
+        let merged = SonicKZG10::<Bls12_377, FS>::merge_accumulators(valid.clone(), rng);
+        assert!(MarlinSonicInst::decide(&prepared_vks[0], &merged).unwrap());
+
+        // Accumulators survive a roundtrip through their byte representation.
+        let bytes = merged.to_bytes_le().unwrap();
+        let recovered = KZGAccumulator::<Bls12_377>::read_le(&bytes[..]).unwrap();
+        assert_eq!(merged, recovered);
+
+        // Merging in the accumulator of a single invalid proof makes the result invalid.
+        let with_invalid = valid.chain([accumulators[1].1.clone()]);
+        let merged = SonicKZG10::<Bls12_377, FS>::merge_accumulators(with_invalid, rng);
+        assert!(!MarlinSonicInst::decide(&prepared_vks[2], &merged).unwrap());
+
+        // Merging with the empty accumulator does not change the result.
+        let empty = KZGAccumulator::<Bls12_377>::empty();
+        assert!(empty.is_empty());
+        assert!(MarlinSonicInst::decide(&prepared_vks[1], &empty).unwrap());
+        let merged = SonicKZG10::<Bls12_377, FS>::merge_accumulators([empty, recovered.clone()], rng);
+        assert!(MarlinSonicInst::decide(&prepared_vks[1], &merged).unwrap());
+    }
+
+    #[test]
+    fn verify_detailed_reports_rejection_reason() {
+        use crate::{Prepare, VerificationError};
+        use snarkvm_fields::One;
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let (circ, [c, d]) = sample_circuit(25, 25, rng);
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let prepared_vk = index_vk.prepare();
+        let proof = MarlinSonicInst::prove_batch(&index_pk, &[circ, circ], rng).unwrap();
+
+        assert!(MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &proof).is_ok());
+
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [d, c]], &proof);
+        assert!(matches!(result, Err(VerificationError::InvalidPairingCheck)));
+
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d]], &proof);
+        assert!(matches!(result, Err(VerificationError::BatchSizeMismatch { proof: 2, inputs: 1 })));
+
+        let too_long = vec![vec![c; 8], vec![c, d]];
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &too_long, &proof);
+        assert!(matches!(result, Err(VerificationError::PublicInputLengthMismatch { index: 0, .. })));
+
+        // A tampered evaluation breaks the lincheck equation, which is only caught by the pairing check.
+        let mut tampered = proof.clone();
+        tampered.evaluations.z_b_evals[0] += Fr::one();
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &tampered);
+        assert!(matches!(result, Err(VerificationError::InvalidPairingCheck)));
+
+        let mut tampered = proof.clone();
+        tampered.evaluations.matrix_evals[0].g_a_eval += Fr::one();
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &tampered);
+        assert!(matches!(result, Err(VerificationError::InvalidPairingCheck)));
+
+        let mut truncated = proof.clone();
+        truncated.commitments.witness_commitments.pop();
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &truncated);
+        assert!(matches!(result, Err(VerificationError::MalformedProof(_))));
+
+        let mut truncated = proof.clone();
+        truncated.evaluations.z_b_evals.pop();
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &truncated);
+        assert!(matches!(result, Err(VerificationError::MalformedProof(_))));
+
+        // A non-hiding proof is rejected by a hiding verifier, and vice versa.
+        let (posw_pk, posw_vk) = MarlinSonicPoswInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let posw_prepared_vk = posw_vk.prepare();
+        let posw_proof = MarlinSonicPoswInst::prove_batch(&posw_pk, &[circ, circ], rng).unwrap();
+        assert!(MarlinSonicPoswInst::verify_detailed(None, &posw_prepared_vk, &[[c, d], [c, d]], &posw_proof).is_ok());
+
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vk, &[[c, d], [c, d]], &posw_proof);
+        assert!(matches!(result, Err(VerificationError::IncorrectZKMode { expected: true, found: false })));
+
+        let result = MarlinSonicPoswInst::verify_detailed(None, &posw_prepared_vk, &[[c, d], [c, d]], &proof);
+        assert!(matches!(result, Err(VerificationError::IncorrectZKMode { expected: false, found: true })));
+    }
+
+    #[test]
+    fn prove_and_verify_multiple_circuits() {
+        use crate::{snark::marlin::Proof, Prepare, VerificationError};
+        use snarkvm_utilities::{FromBytes, ToBytes};
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let mut keys = vec![];
+        let mut circuits = vec![];
+        let mut inputs = vec![];
+        for (num_constraints, num_variables, batch_size) in [(25, 25, 2), (100, 25, 1), (25, 100, 3)] {
+            let (circuit_batch, input_batch): (Vec<_>, Vec<_>) =
+                (0..batch_size).map(|_| sample_circuit(num_constraints, num_variables, rng)).unzip();
+            keys.push(MarlinSonicInst::circuit_setup(&universal_srs, &circuit_batch[0]).unwrap());
+            circuits.push(circuit_batch);
+            inputs.push(input_batch);
+        }
+        let prepared_vks = keys.iter().map(|(_, vk)| vk.prepare()).collect::<Vec<_>>();
+
+        let keys_to_constraints =
+            keys.iter().zip(&circuits).map(|((pk, _), circuits)| (pk, &circuits[..])).collect::<Vec<_>>();
+        let proof = MarlinSonicInst::prove_multi_circuit(&keys_to_constraints, rng).unwrap();
+        assert_eq!(proof.num_circuits(), 3);
+        assert_eq!(proof.batch_sizes(), &[2, 1, 3]);
+
+        let keys_to_inputs =
+            prepared_vks.iter().zip(&inputs).map(|(pvk, inputs)| (pvk, &inputs[..])).collect::<Vec<_>>();
+        assert!(MarlinSonicInst::verify_multi_circuit(None, &keys_to_inputs, &proof).is_ok());
+
+        // The proof survives a roundtrip through its byte representation.
+        let proof_bytes = proof.to_bytes_le().unwrap();
+        let recovered = Proof::<Bls12_377>::read_le(&proof_bytes[..]).unwrap();
+        assert!(MarlinSonicInst::verify_multi_circuit(None, &keys_to_inputs, &recovered).is_ok());
+
+        // The keys must be given in the order in which the circuits were proven.
+        let mut swapped = keys_to_inputs.clone();
+        swapped.swap(0, 2);
+        assert!(MarlinSonicInst::verify_multi_circuit(None, &swapped, &proof).is_err());
+
+        let mut tampered = inputs.clone();
+        tampered[1][0] = [Fr::rand(rng), Fr::rand(rng)];
+        let tampered = prepared_vks.iter().zip(&tampered).map(|(pvk, inputs)| (pvk, &inputs[..])).collect::<Vec<_>>();
+        let result = MarlinSonicInst::verify_multi_circuit(None, &tampered, &proof);
+        assert!(matches!(result, Err(VerificationError::InvalidPairingCheck)));
+
+        let result = MarlinSonicInst::verify_multi_circuit(None, &keys_to_inputs[..2], &proof);
+        assert!(matches!(result, Err(VerificationError::CircuitCountMismatch { proof: 3, keys: 2 })));
+
+        // A proof of several circuits is not accepted as a proof of one of them.
+        let result = MarlinSonicInst::verify_detailed(None, &prepared_vks[0], &inputs[0], &proof);
+        assert!(matches!(result, Err(VerificationError::CircuitCountMismatch { proof: 3, keys: 1 })));
+    }
+
+    #[test]
+    fn prove_with_msm_engine() {
+        use crate::msm::{MsmCapabilities, MsmEngine, MsmError, StandardEngine};
+        use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+        use snarkvm_fields::PrimeField;
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        // Counts the MSMs that the prover hands to the engine.
+        #[derive(Default)]
+        struct CountingEngine(AtomicUsize);
+
+        impl MsmEngine<G1Affine> for CountingEngine {
+            fn name(&self) -> &'static str {
+                "counting"
+            }
+
+            fn capabilities(&self) -> MsmCapabilities {
+                MsmCapabilities::any_bases()
+            }
+
+            fn msm(
+                &self,
+                bases: &[G1Affine],
+                scalars: &[<<G1Affine as AffineCurve>::ScalarField as PrimeField>::BigInteger],
+            ) -> Result<<G1Affine as AffineCurve>::Projective, MsmError> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                StandardEngine.msm(bases, scalars)
+            }
+        }
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let (circ, inputs) = sample_circuit(100, 25, rng);
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+
+        let engine = CountingEngine::default();
+        let keys_to_constraints = [(&index_pk, &[circ][..])];
+        let proof = MarlinSonicInst::prove_multi_circuit_with_engine(
+            &keys_to_constraints,
+            &engine,
+            &AtomicBool::new(false),
+            rng,
+        )
+        .unwrap();
+        assert!(engine.0.load(Ordering::SeqCst) > 0);
+        assert!(MarlinSonicInst::verify(&index_vk, inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn single_circuit_proof_keeps_its_encoding() {
+        use crate::snark::marlin::Proof;
+        use snarkvm_utilities::{CanonicalSerialize, Compress, FromBytes, ToBytes};
+
+        let rng = &mut test_crypto_rng();
+
+        let universal_srs = test_universal_srs(rng);
+
+        let (circ, _) = sample_circuit(25, 25, rng);
+        let (index_pk, _) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let proof = MarlinSonicInst::prove_batch(&index_pk, &[circ, circ], rng).unwrap();
+
+        // A proof of a single circuit is laid out as before proofs could cover several circuits:
+        // its batch size, followed by the commitments, evaluations, sums and opening proof.
+        let mut expected = 2u64.to_le_bytes().to_vec();
+        let (comms, evals) = (&proof.commitments, &proof.evaluations);
+        for c in &comms.witness_commitments {
+            c.serialize_compressed(&mut expected).unwrap();
+        }
+        comms.mask_poly.serialize_compressed(&mut expected).unwrap();
+        for c in [&comms.g_1, &comms.h_1] {
+            c.serialize_compressed(&mut expected).unwrap();
+        }
+        comms.matrix_commitments[0].serialize_compressed(&mut expected).unwrap();
+        comms.h_2.serialize_compressed(&mut expected).unwrap();
+        for eval in evals.z_b_evals.iter().chain([&evals.g_1_eval]) {
+            eval.serialize_compressed(&mut expected).unwrap();
+        }
+        evals.matrix_evals[0].serialize_compressed(&mut expected).unwrap();
+        proof.msg.sums[0].serialize_compressed(&mut expected).unwrap();
+        proof.pc_proof.serialize_compressed(&mut expected).unwrap();
+
+        let proof_bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(proof_bytes, expected);
+        assert_eq!(proof_bytes.len(), proof.serialized_size(Compress::Yes));
+
+        let recovered = Proof::<Bls12_377>::read_le(&proof_bytes[..]).unwrap();
+        assert_eq!(recovered, proof);
+
+        // The header of a proof of a single circuit can not claim several circuits.
+        let mut malformed = proof_bytes;
+        malformed[..8].copy_from_slice(&((1u64 << 63) | 1).to_le_bytes());
+        assert!(Proof::<Bls12_377>::read_le(&malformed[..]).is_err());
+    }
 }
 
 mod marlin_recursion {
@@ -466,3 +794,38 @@ mod marlin_recursion {
     //     println!("Called verifier");
     // }
 }
+
+mod marlin_bw6_761 {
+    use super::*;
+    use crate::snark::marlin::{fiat_shamir::FiatShamirChaChaRng, AHPForR1CS, MarlinHidingMode, MarlinSNARK};
+    use snarkvm_curves::bw6_761::{BW6_761, Fq, Fr};
+    use snarkvm_utilities::rand::{test_crypto_rng, Uniform};
+
+    use blake2::Blake2s256;
+    use core::ops::MulAssign;
+
+    type MarlinInst = MarlinSNARK<BW6_761, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
+
+    #[test]
+    fn prove_and_verify_over_bw6_761() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+
+        assert!(MarlinInst::verify(&index_vk, [c, d], &proof).unwrap());
+        assert!(!MarlinInst::verify(&index_vk, [a, a], &proof).unwrap());
+    }
+}
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SNARKError, AlgebraicSponge, snark::marlin::FiatShamirRng};
+use crate::{
+    errors::{SNARKError, VerificationError},
+    snark::marlin::FiatShamirRng,
+    AlgebraicSponge,
+};
 use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
 
 use rand::{CryptoRng, Rng};
@@ -103,12 +107,26 @@ pub trait SNARK {
         Self::prove_batch_with_terminator(proving_key, std::slice::from_ref(input_and_witness), terminator, rng)
     }
 
+    /// Verifies `proof` against a batch of public inputs, and returns the reason for rejecting it, if any.
+    fn verify_detailed<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: Option<&Self::FSParameters>,
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        input: &[B],
+        proof: &Self::Proof,
+    ) -> Result<(), VerificationError>;
+
     fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
         fs_parameters: Option<&Self::FSParameters>,
         prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
         input: &[B],
         proof: &Self::Proof,
-    ) -> Result<bool, SNARKError>;
+    ) -> Result<bool, SNARKError> {
+        match Self::verify_detailed(fs_parameters, prepared_verifying_key, input, proof) {
+            Ok(()) => Ok(true),
+            Err(VerificationError::SNARKError(error)) => Err(error),
+            Err(_) => Ok(false),
+        }
+    }
 
     fn verify_batch_with_prepared_vk<B: Borrow<Self::VerifierInput>>(
         fs_parameters: &Self::FSParameters,
@@ -14,15 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{ops::Mul, str::FromStr};
-
-use snarkvm_fields::{field, Field, One, Zero};
-use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+use snarkvm_fields::{field, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    BitIteratorBE,
+};
 
 use crate::{
-    bls12_377::{Fq, Fr},
+    bls12_377::{glv_decompose, Fq, Fr, GLV_LAMBDA, GLV_OMEGA},
     templates::bls12::Bls12Parameters,
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    traits::{GLVParameters, ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
     ProjectiveCurve,
 };
 
@@ -59,6 +61,9 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
         Fr,
         BigInteger256([2013239619100046060, 4201184776506987597, 2526766393982337036, 1114629510922847535,])
     );
+    /// GLV = the endomorphism (x, y) -> (GLV_OMEGA * x, y), with eigenvalue GLV_LAMBDA
+    const GLV: Option<GLVParameters<Fq, Fr>> =
+        Some(GLVParameters { omega: GLV_OMEGA, lambda: GLV_LAMBDA, decompose: glv_decompose });
 
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
@@ -67,16 +72,13 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
 
     fn is_in_correct_subgroup_assuming_on_curve(p: &super::G1Affine) -> bool {
         let phi = |mut p: super::G1Affine| {
-            let cube_root_of_unity = Fq::from_str(
-                "80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945",
-            )
-            .unwrap();
-            debug_assert!(cube_root_of_unity.pow(&[3]).is_one());
-            p.x *= cube_root_of_unity;
+            debug_assert!(GLV_OMEGA.pow(&[3]).is_one());
+            p.x *= GLV_OMEGA;
             p
         };
         let x_square = Fr::from(super::Bls12_377Parameters::X[0]).square();
-        (phi(*p).mul(x_square).add_mixed(p)).is_zero()
+        // The point may lie outside the prime order subgroup, so it is multiplied without the GLV endomorphism.
+        phi(*p).mul_bits(BitIteratorBE::new_without_leading_zeros(x_square.to_repr())).add_mixed(p).is_zero()
     }
 }
 
@@ -21,8 +21,8 @@ use snarkvm_utilities::{
 };
 
 use crate::{
-    bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    bls12_377::{g1::Bls12_377G1Parameters, glv_decompose, Fq, Fq2, Fr, GLV_LAMBDA, GLV_OMEGA_SQUARED},
+    traits::{GLVParameters, ModelParameters, ShortWeierstrassParameters},
     AffineCurve,
 };
 
@@ -78,6 +78,12 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
         Fr,
         BigInteger256([15499857013495546999, 4613531467548868169, 14546778081091178013, 549402535258503313,])
     );
+    /// GLV = the endomorphism (x, y) -> ([GLV_OMEGA_SQUARED, 0] * x, y), with eigenvalue GLV_LAMBDA
+    const GLV: Option<GLVParameters<Fq2, Fr>> = Some(GLVParameters {
+        omega: field!(Fq2, GLV_OMEGA_SQUARED, field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))),
+        lambda: GLV_LAMBDA,
+        decompose: glv_decompose,
+    });
 
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, PrimeField};
+use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+
+use crate::bls12_377::{Fq, Fr};
+
+/// GLV_LAMBDA = x^2 - 1, a primitive cube root of unity in Fr, and the eigenvalue of the
+/// GLV endomorphism on G1 and G2.
+/// = 91893752504881257701523279626832445440
+pub const GLV_LAMBDA: Fr =
+    field!(Fr, BigInteger256([12574070832645531618, 10005695704657941814, 1564543351912391449, 657300228442948690]));
+
+/// GLV_OMEGA = the primitive cube root of unity in Fq for which `(GLV_OMEGA * x, y) = GLV_LAMBDA * (x, y)` on G1.
+/// = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945
+pub const GLV_OMEGA: Fq = field!(
+    Fq,
+    BigInteger384([
+        15766275933608376691,
+        15635974902606112666,
+        1934946774703877852,
+        18129354943882397960,
+        15437979634065614942,
+        101285514078273488,
+    ])
+);
+
+/// GLV_OMEGA_SQUARED = GLV_OMEGA^2, for which `(GLV_OMEGA_SQUARED * x, y) = GLV_LAMBDA * (x, y)` on G2.
+/// = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+pub const GLV_OMEGA_SQUARED: Fq = field!(
+    Fq,
+    BigInteger384([
+        3203870859294639911,
+        276961138506029237,
+        9479726329337356593,
+        13645541738420943632,
+        7584832609311778094,
+        101110569012358506,
+    ])
+);
+
+/// floor(2^256 * x^2 / r), the little-endian limbs used to round in the scalar decomposition.
+const GLV_ROUNDING: [u64; 3] = [0x7f72ed32af90182c, 0xb3f7aa969fd37160, 0x3];
+
+/// Decomposes `scalar` into `[k1, k2]` with `scalar = k1 + GLV_LAMBDA * k2`.
+///
+/// The short basis of the lattice `{ (a, b) : a + b * GLV_LAMBDA = 0 mod r }` is `(x^2 - 1, -1)` and `(1, x^2)`,
+/// so `k2` is taken as an approximation of `scalar * x^2 / r`, and `|k1|, k2 < 2^128`.
+pub fn glv_decompose(scalar: &Fr) -> [(bool, u128); 2] {
+    // Compute the product of the scalar and the rounding constant, and keep its bits above 2^256.
+    let scalar_limbs = scalar.to_repr().0;
+    let mut product = [0u64; 7];
+    for (i, a) in scalar_limbs.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b) in GLV_ROUNDING.iter().enumerate() {
+            let sum = product[i + j] as u128 + (*a as u128) * (*b as u128) + carry;
+            product[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        product[i + GLV_ROUNDING.len()] = carry as u64;
+    }
+    debug_assert_eq!(product[6], 0);
+    let k2 = product[4] as u128 | (product[5] as u128) << 64;
+
+    let k1 = *scalar - GLV_LAMBDA * Fr::from(k2);
+    let k1 = match to_u128(&k1) {
+        Some(k1) => (false, k1),
+        None => (true, to_u128(&-k1).expect("The GLV decomposition of a scalar is short")),
+    };
+    [k1, (false, k2)]
+}
+
+/// Returns the given scalar as a `u128`, if it fits.
+fn to_u128(scalar: &Fr) -> Option<u128> {
+    let limbs = scalar.to_repr().0;
+    match limbs[2] == 0 && limbs[3] == 0 {
+        true => Some(limbs[0] as u128 | (limbs[1] as u128) << 64),
+        false => None,
+    }
+}
@@ -44,9 +44,15 @@ pub mod g2;
 #[doc(inline)]
 pub use g2::*;
 
+pub mod glv;
+#[doc(inline)]
+pub use glv::*;
+
 pub mod parameters;
 #[doc(inline)]
 pub use parameters::*;
 
+pub mod swu;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,3121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The parameters of the simplified SWU map of the IETF hash-to-curve draft for BLS12-377.
+//!
+//! Both groups of BLS12-377 lie on curves with `a = 0`, so the map targets an isogenous curve with `a' b' != 0`.
+//! The isogenies are normalized as in Vélu's formulas, and composed with an isomorphism onto the curve.
+//! For G1, the isogenous curve `y^2 = x^3 - 15 x + 22` is reached through the 2-isogeny whose kernel is
+//! generated by `(2, 0)`. For G2, the smallest degree of an isogeny from a curve with `a' != 0` is 23,
+//! as the Frobenius endomorphism of the curve is `a + b ω` with `ω^3 = 1`, and 23 is the smallest prime factor of `b`.
+
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger384;
+
+use crate::{
+    bls12_377::{g1::Bls12_377G1Parameters, g2::Bls12_377G2Parameters, Fq, Fq2},
+    traits::SWUParameters,
+};
+
+impl SWUParameters for Bls12_377G1Parameters {
+    /// ISOGENOUS_COEFF_A = -15
+    const ISOGENOUS_COEFF_A: Fq = field!(
+        Fq,
+        BigInteger384([
+            0x6f19c000000008ed,
+            0xb06f79a0700004a3,
+            0x426363ff84d7a8cd,
+            0x49135dff8b7f9477,
+            0x5cd256899b9aa488,
+            0x1e23a229f7ff8b
+        ])
+    );
+    /// ISOGENOUS_COEFF_B = 22
+    const ISOGENOUS_COEFF_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            0x9a76bffffffff2e9,
+            0x5a3e286faffff932,
+            0xdc25c143d08286d2,
+            0xe1cd141e77fcf991,
+            0x3167b6320cca6b5c,
+            0x63347edb6f8ed7
+        ])
+    );
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xf54900000000068c,
+            0xde669a6a40000366,
+            0xa166d075f4c36096,
+            0x1c2ae2ea447c0cfe,
+            0xca69a7c72feb6eef,
+            0xa582e39e2ac109
+        ])
+    );
+
+    /// x_num = (x'^2 - 2 x' - 3) / 4
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0xc2ee400000000073,
+                0x5a1ae5a19000003b,
+                0xe7551b70922b580a,
+                0xbda7e8296e17c94a,
+                0xc839570cc73e58c,
+                0x1442d7cad869977
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x3a1c0000000004d,
+                0xee6b0d8270000028,
+                0x4f34885af4caa806,
+                0xdc7b8e1749b6e20c,
+                0x9fc0bae0ac8306c6,
+                0x16787152646169d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x40b37fffffffffda,
+                0x945027e0dfffffec,
+                0x67df6cea629f4ffc,
+                0x1ed3a5eddb9f18c1,
+                0x933d256fe00f213a,
+                0x23599878bf7d26
+            ])
+        ),
+    ];
+    /// x_den = x' - 2
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x7f6cc00000000131,
+                0x748a1e3d3000009e,
+                0xdff7fadca50ec81b,
+                0x2385aa8423fc4d83,
+                0x2c51da416c283f6a,
+                0x936d8251c927b6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+    ];
+    /// y_num = (x'^2 - 4 x' + 7) / 8
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0xe2743fffffffff7b,
+                0x7188b930fffffba,
+                0xeb8dfd34592d97f4,
+                0x6be4c4c080acd6a4,
+                0x35603079034f44b,
+                0x7bb995a69e3607
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x3a1c0000000004d,
+                0xee6b0d8270000028,
+                0x4f34885af4caa806,
+                0xdc7b8e1749b6e20c,
+                0x9fc0bae0ac8306c6,
+                0x16787152646169d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2059bfffffffffed,
+                0x4a2813f06ffffff6,
+                0xb3efb675314fa7fe,
+                0xf69d2f6edcf8c60,
+                0x499e92b7f007909d,
+                0x11accc3c5fbe93
+            ])
+        ),
+    ];
+    /// y_den = (x' - 2)^2
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x862f3ffffffffd9f,
+                0x2df720c9cffffec3,
+                0x5f036c766febb7c9,
+                0xd31784eab8fc7887,
+                0x6d97513d9450ca66,
+                0x875f417432c17e
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xfed9800000000262,
+                0xe9143c7a6000013c,
+                0xbfeff5b94a1d9036,
+                0x470b550847f89b07,
+                0x58a3b482d8507ed4,
+                0x126db04a3924f6c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+    ];
+}
+
+impl SWUParameters for Bls12_377G2Parameters {
+    const ISOGENOUS_COEFF_A: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfaf7a23bf5b527f5,
+                0xaba484e70a8e9668,
+                0xf8b26f8d1f3a6978,
+                0x4710f5a1df5b1ae6,
+                0x9b22f7a437e656f,
+                0x138c26586a15a35
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x3b47a736d9710061,
+                0xbed0f94a5e606a37,
+                0xe8589393959a4a4e,
+                0x31eb4faad958b14,
+                0x31957654adfa26c7,
+                0x195da6f4ae4239a
+            ])
+        )
+    );
+    const ISOGENOUS_COEFF_B: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xeb4499d9f227930,
+                0x2afaad65bcdf0d4a,
+                0x3512fe7701e9e15,
+                0xc073d097a989cadd,
+                0x3cd7db35155ab949,
+                0x49a581c1accfbe
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xfa1951bbaf4ecba2,
+                0xd3785d7de370764,
+                0xb398c5b431106f9c,
+                0xec30f2d0bb6f7259,
+                0x64a47325bdcbebbe,
+                0x114f809745bf84c
+            ])
+        )
+    );
+    /// SWU_Z = u
+    const SWU_Z: Fq2 = field!(
+        Fq2,
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        )
+    );
+
+    /// x_num, of degree 23
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe14f1e95b3134f,
+                    0xb4fbde8359974382,
+                    0xc3d6fd091c4b3079,
+                    0x6cf5b590355171d1,
+                    0x3932b66b4c81177b,
+                    0x1a5ffaa4e647ad9
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3e1cbc0d14aa74b6,
+                    0xc7b1258db4fe2840,
+                    0x6047e8aa381d0951,
+                    0x6566014b55537c2a,
+                    0xb2a51eb4b9dd0e2a,
+                    0x119f682adf00bcb
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x67de3fd8b7094e14,
+                    0xda4759618e22c211,
+                    0xf091d217c5595b33,
+                    0x7b5aba4a256a8561,
+                    0x2b710d1a85c732ed,
+                    0x199209b7e76e04c
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x458a6913bbdd94c9,
+                    0x7b854db27dafd98b,
+                    0x4aae7539d0c03566,
+                    0x74a1a1b3dcc5f25d,
+                    0xc630109fcf36df3d,
+                    0xbe8bac2475274e
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x934c6fe7313da6f9,
+                    0xfcf90c8e90261828,
+                    0x30327fbd387cf1b9,
+                    0x6ef67d721301d0fe,
+                    0x354bbea0f038f7a1,
+                    0xc7def8c7022420
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa5c966932f7eeca0,
+                    0x931ae637711bfc86,
+                    0x6484716903a25620,
+                    0xccd18c4a60c7d01a,
+                    0xb45c20c1117c264b,
+                    0x2f6bfc74a4b510
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x934275fffd6fc828,
+                    0x65302c8ebd1c883b,
+                    0xc68c973bc91f9795,
+                    0xc86dc43a20dd231e,
+                    0x8f01ecde10d55991,
+                    0x183e4edfcd218
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x24067c3821e2e944,
+                    0xc5d592dbbed2118b,
+                    0xe3dff8abfde3e821,
+                    0x923d4b5bbe9e7c39,
+                    0xe5b1d551cff507dd,
+                    0x5163945afa28a0
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x10293f18237c4f04,
+                    0x50b6132e309f496d,
+                    0xaa625a1174e85f4e,
+                    0x3b5820374899e786,
+                    0xd8dc060baa065938,
+                    0xfdeaf5e148aa09
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xda788fd815f37d3d,
+                    0x34335ae3772e3ecc,
+                    0x412bdb4853f7b41e,
+                    0xaa5653dc00448af3,
+                    0x815e85bb836adfcb,
+                    0xe5692f4102ff5e
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x93bb2f1b48e148a6,
+                    0xd185c1e216e28beb,
+                    0x33ed0dec76b3c7b3,
+                    0xd379cfb5907c566d,
+                    0xd3726db717357c38,
+                    0x1532d644c18bbde
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x109fde10a3cf8b57,
+                    0xa57ec3ff83be333b,
+                    0xdbe16a7ae0423eaf,
+                    0x440c9d960273d4ce,
+                    0xa1660665ab77a600,
+                    0x10d1e0a9f593bf7
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa7445934be1a808f,
+                    0x87ea9154e86a3c5e,
+                    0x692746dcd7891913,
+                    0x80ceea68bef3aaa,
+                    0x84546f3ce0cd7fca,
+                    0x2429912360d285
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x819a9f2197021ef9,
+                    0xcd22d731906b45c,
+                    0x339044f318f28252,
+                    0x374f6f4b1bd4c393,
+                    0xeff5c000f7947337,
+                    0x17ed3bad918411a
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x26881053bcb506ed,
+                    0xe612c743807646a0,
+                    0x5f771132c838664d,
+                    0x18ffda14d8d994a9,
+                    0x62947692e0a1539e,
+                    0x86aea9dbed2788
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb918c1dd687fc55e,
+                    0xc52a7959d3b0a046,
+                    0xf944c687decf2150,
+                    0x21bff0df872bb9f6,
+                    0x87814c347d951664,
+                    0xc09cef03cb60a9
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x74d9f0a128c0a204,
+                    0x420c277a4dec5722,
+                    0xcebe2d0cf93199c2,
+                    0xafb5dc1cf6b7c70d,
+                    0x4727f4cd7b9c9a67,
+                    0xbf5693d42a50bb
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x969a3a8a6d0bfcb6,
+                    0x498dae349616cc16,
+                    0xb6f17037944517a3,
+                    0x86dea6391c1d41be,
+                    0xa4e01b901ef12ae0,
+                    0x165db12067f4048
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd7ad1858bd353f4c,
+                    0x74bfcd1bcba2cabf,
+                    0x61029c921f7d1829,
+                    0x72b26b4b5fcfa4d9,
+                    0x84b9c45ebdeac23e,
+                    0x11ae64e600f5ec7
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8460045f66f16b61,
+                    0xd3ed8a47890359f8,
+                    0xc9426e934c2712c0,
+                    0xa12daac356081f8b,
+                    0xed2f47998971c1b6,
+                    0xc57f68a834c8eb
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1134e3c697efd3c4,
+                    0xd55befb7fee3f82f,
+                    0x6caffd66ab913734,
+                    0x5f17d98285d3853e,
+                    0x420932c052655fec,
+                    0xd1f53cee9e9d54
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4853d535b490b1c3,
+                    0x689561cdd71a5525,
+                    0xb5f4b7297ee701d2,
+                    0x79e65717000eb4ea,
+                    0xc3b6a326dd5558db,
+                    0x157dac9097eb0
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x18d2b16616f306ce,
+                    0x73ea4e2ea649cd70,
+                    0x17ed386601d467c0,
+                    0x6699d54b593e7fcd,
+                    0x202b2e3b5a6de6f9,
+                    0x10659f5c82c13f9
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa03fdfc35ad0280,
+                    0x5ef583ddc6885c92,
+                    0x489ca8671535b4a7,
+                    0x268adb0acb7facd9,
+                    0x87aa67422ec3ad28,
+                    0xc40bbba03190b3
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf143f42f93144afe,
+                    0x8b6b9d5ba47a436b,
+                    0x8a7454e08c72db0e,
+                    0xb6c178ee05911e67,
+                    0x3c97954572276f5d,
+                    0x15a6351cd656879
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1e90a3d36704a33f,
+                    0x5339911b54962d7a,
+                    0xf6fadbc776397d91,
+                    0xcc121fc633eecba7,
+                    0x611daece01d00e9c,
+                    0x4c74c4a9c072c3
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf83dbb4fa28c65d7,
+                    0xe7693457594ae48d,
+                    0xf78c6e62a6c941d6,
+                    0xc7faa610aa5ddbc3,
+                    0xdca74204c0b08630,
+                    0x81680ecb781d42
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x25869c79b5d573af,
+                    0xede0f929266f355,
+                    0xa757c3dcdb5570fb,
+                    0xee5361104611a8d5,
+                    0xd3a63bf17380f98e,
+                    0x144f41e7b0956c0
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf64af525bce1fb88,
+                    0x6acf055a48103d88,
+                    0x94545b77b8f623b9,
+                    0x934a2e3df0c6d50e,
+                    0xb46daa0ff74b75fa,
+                    0xbba9783b4fb3b7
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5728f0ed3121e1d9,
+                    0x814701354c55c334,
+                    0x69aef746eea92c68,
+                    0xe495dbf0f9209429,
+                    0x28c3592ec9727aaa,
+                    0x457de905d97004
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x86f576d46ddbfb07,
+                    0x9e8898732054f24d,
+                    0x8e0d582afa04d4b7,
+                    0xdec302ff85fdd113,
+                    0x89db4b7269d1e339,
+                    0x15f6f3fdf187cc1
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x9da73bf3307c3bec,
+                    0x969e9af7ea4b43bc,
+                    0x377872ba45f989c5,
+                    0x86163fa3715dd0e8,
+                    0xc8be211983d56407,
+                    0xeb4a7ed57b9b43
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x7bde4c7a236d0751,
+                    0x87db74a455584ba8,
+                    0xc51bcc5f0c5f2171,
+                    0x84a7205078430d11,
+                    0xd24c94c8e1e52674,
+                    0x11294bac9e3da4e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x87c54bd8c48e9050,
+                    0x2563810b04f6bb4c,
+                    0x18543c6b99dda6bd,
+                    0xc9132bf0f25dcb43,
+                    0xc2ad02fd9bdb8d84,
+                    0x11d4edaa7398cff
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xbe49d6f50d38de5f,
+                    0xd8d2665aa25d2f66,
+                    0x23df58a1f5b578d6,
+                    0x541069a92579a016,
+                    0xf3b109a96508e122,
+                    0x9186d9c231db17
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd2d78132fe5713ea,
+                    0x2d57e449a0142708,
+                    0x7e775f24c2eb6145,
+                    0x3c46b8e6e211a3bd,
+                    0x63e07f117fd673b1,
+                    0x9aba25b771ec88
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x93fbc915e117eada,
+                    0x1644c8a2c83f80ac,
+                    0xeefc3651e0efa0ad,
+                    0x45e7ee5e51a521b5,
+                    0x2d649412de9354e6,
+                    0x17853c08242ce21
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x880e1b807ab83264,
+                    0x8bb5efeaaf0356d5,
+                    0xb86fb3a6b4d72410,
+                    0x32abc13ead41f2e3,
+                    0x5bdc5439881bb942,
+                    0x1aa35961226a925
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc3749e9baf321116,
+                    0x4f16dc8e303f22cd,
+                    0x5eb8785612391c1e,
+                    0x5425a1435bd38e42,
+                    0x4cdc8a9aceaedf6d,
+                    0x15ac0ce00bca652
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf3bfdcfdf5595546,
+                    0xeb540e9382589218,
+                    0xd159b56207ed7ec,
+                    0xf2b9e5254d5c5a9f,
+                    0x4d0d192a832d6e26,
+                    0xba44c083ddd0ca
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2beb45d699fe54a0,
+                    0xb9c3c2681c507ebb,
+                    0x53ba9774051d3882,
+                    0xaa5cd9ebacec69aa,
+                    0xda979d52febb9e8,
+                    0xc0b977d6b0e498
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb73ec68a788a38a4,
+                    0x46d86202124888d,
+                    0x6a72671af34c7e67,
+                    0xf32e5b56fe3fbb11,
+                    0xd5f28fb60f56631c,
+                    0x19ec346c646c20e
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x449d6620e7f38a03,
+                    0x3c3c991a39c14627,
+                    0xb1e361fcadc46d92,
+                    0x207d3d512185ea9b,
+                    0x432c8318d687f38f,
+                    0x9b836935fb3c6
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x579d46da5053faaa,
+                    0x47e20f651aa1ed82,
+                    0x28da169fdca3715d,
+                    0x570ca7ed1d6738c,
+                    0xf68a167c4db5c5f6,
+                    0x94b658982e60f7
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf9feff3f5c2b785d,
+                    0xe13804a97cf39d05,
+                    0xb8e34c22e12a6c34,
+                    0xedc4ff89521cbc2a,
+                    0x97759db8bebd756c,
+                    0x8bf62eb72f6fb5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x78839df21b008863,
+                    0xa478d8a95db07ba6,
+                    0xb7ae8ef572f04080,
+                    0x5a81bd2d8d42e1b0,
+                    0x7686a551f7bfacfa,
+                    0xd88c80c794a70c
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc67980efd2ae72f3,
+                    0x728bdefb8d7fdfbe,
+                    0x1dfe9d79652aafab,
+                    0x1e3ae21d94097b65,
+                    0x160a565568b4cb3e,
+                    0xcda076554c76d8
+                ])
+            ),
+            field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+        ),
+    ];
+    /// x_den = K(x')^2, where K is the kernel polynomial of the isogeny, of degree 11
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe2bd5a692f03fdc1,
+                    0x860a345c5b7327ea,
+                    0xa325d94391f118fe,
+                    0x907a803dde8384,
+                    0xcf9e23bf34b64950,
+                    0x465a05067296ad
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x69c6a010267212d,
+                    0x9c90eb5d41f5a7fb,
+                    0xd1789949a6270fb3,
+                    0x9464de31f023cae6,
+                    0x39038cf17eae7ec3,
+                    0x175c8aa927b2f4d
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x9ce2c7b900b6bb73,
+                    0x8c82d34006518323,
+                    0xf0bc95f9546ab5fd,
+                    0xc3572b3951864983,
+                    0x4bba7df7009dbe2e,
+                    0x1021da124005370
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa9bfdb4fd37fd796,
+                    0x1f84ef03572b5bc6,
+                    0x4dd3a89da5eee654,
+                    0xddb63fc2317789f4,
+                    0x503db0160b4f4d41,
+                    0x12b29e482a25991
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa820055d279a19a3,
+                    0x3c2a10544cbc04d3,
+                    0x392adaab0b22c201,
+                    0x556b3dfde4235017,
+                    0x7b66dfaa44e12faf,
+                    0x14c4439530f6dab
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x65ec7ddcf37e9b2f,
+                    0x7cc73cb8420b645d,
+                    0x1fec85ecb789bd07,
+                    0x6d2edd955f82d57e,
+                    0x7d7cea6aedb0c668,
+                    0x18a495605d99aac
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc68197343f0ff769,
+                    0x14822179562049e0,
+                    0x908d1eb70421d171,
+                    0xfa61147dd331ca37,
+                    0x10e921cde73d81e1,
+                    0x13a7387e6b2ef2a
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8da0d30e34fa576c,
+                    0xdd5a4edb98e4d23f,
+                    0xee7d1ff7887d0b33,
+                    0xa29fe9cf1293bf46,
+                    0x310302041a96c013,
+                    0xe0a82cbe5059fe
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x52c6fa8eedd47c99,
+                    0x3b866ee5f106dbec,
+                    0xd28add38e95b7e01,
+                    0xfe6a2d695b5f3c5a,
+                    0x14a1f4b3e83d6224,
+                    0xb4146206ab998b
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4a8b94e04f5feefb,
+                    0x49b8a64a9418fde6,
+                    0xf488c79f8a57aed,
+                    0xb43eb4574d0cd24f,
+                    0x88b254bfb81542bf,
+                    0x15c520b0da3503f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x74601757a17af11a,
+                    0x43b98fc50d914cfa,
+                    0xdd89ac4a352e1f89,
+                    0x43e4176ae25baf26,
+                    0xdffb6bf667281db1,
+                    0x14bb21bb6b3f8f5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xbb57316eca92792c,
+                    0x6cef8e2eae69178b,
+                    0x7d95d55cca3ab2b2,
+                    0x32a8ec137b692570,
+                    0xc84b1ce6ce6798ab,
+                    0xb9b82180e99b94
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd97baedf9ce4d8f7,
+                    0x9a489d85871ef975,
+                    0xa089f2c5ce801493,
+                    0xbe5361fb4f5bcd25,
+                    0x56a6a478063faa6c,
+                    0x8269fd4b051612
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf839f8a7ea750a83,
+                    0xeaf55a615c96d538,
+                    0x216c5d2991ea2966,
+                    0x2b615f0018d37b80,
+                    0x3405e15beec19212,
+                    0xb468c40c3264e4
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x40ba4da0b2545248,
+                    0x10eed7f479248fc0,
+                    0xf1470403a6ab7fcd,
+                    0x735b3921700fba4f,
+                    0xcb9d9c08d5765b93,
+                    0x351c30d55243bf
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x18ab09d94d3c3f78,
+                    0x4799f7917f22bf8d,
+                    0x652333eef98215b8,
+                    0xfd7ae1abad95184,
+                    0xfe566105c02131c6,
+                    0x36af50434acee0
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x32a3646b45b6ab7,
+                    0x856c0fcd7a1e7014,
+                    0x470eb5d2089aefd0,
+                    0xc0e0be376242a80,
+                    0x5858b26783935fec,
+                    0x46686dae63541e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4b31d5f615dcac60,
+                    0x5bed642424fe29ae,
+                    0xfe13ccf8f2d9e93b,
+                    0x77a1751f806860ce,
+                    0x550a4a121f59dbf9,
+                    0xb1c56f7edd176c
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1ae26fcf2064f503,
+                    0xd1a2933be1239d9b,
+                    0xc8337a3360814844,
+                    0x9423091c7f8bf1d1,
+                    0x180d99e5c4581552,
+                    0x109585f82d183b7
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8739b966f0061bda,
+                    0x2ef5293439915528,
+                    0x7e055b8911f89529,
+                    0x8276afad1c307cad,
+                    0x93d0c451d042856,
+                    0xc4c32de200db1b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xeac4e448b978a6ab,
+                    0x5dd4c6d8292e3712,
+                    0x669e86b66e1ac1de,
+                    0xf95a19759b48f1b,
+                    0xf615c834a7e4dbbd,
+                    0x18412efa4b9f3a5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd70b095d4a9327c3,
+                    0xea130134d6a4ccaa,
+                    0xa3c8aa0b6ba0486c,
+                    0x8959dccc88579dba,
+                    0xd95c551c7e141004,
+                    0x1521db8d40fef1a
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x841ac3096a196d98,
+                    0x3da8fded36e00b3f,
+                    0x5c9ecd6ba51782e0,
+                    0x7c8823b8a0b57bd6,
+                    0x850de438f1f44dd2,
+                    0x103680a1a089aac
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8d555d1182fb1cc3,
+                    0x4aa88e6e7aafe1b,
+                    0x7b6c0f6a8dc5d1b,
+                    0x4ac71d95c073cc26,
+                    0xc9efcafb2501adbc,
+                    0x11cfc589eae4069
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8c74a867e33f1e58,
+                    0x94c9860bf380b7a6,
+                    0xe3da3b8487718769,
+                    0x44587963c78dde4f,
+                    0x8a7956e913728fa,
+                    0xd8895a596a2daf
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd202620d41f32161,
+                    0x79a43e5e8947ab3f,
+                    0x55bec466ca0b29bd,
+                    0x43d26fd9fd2968d6,
+                    0x21fed0252d389312,
+                    0xf2e4446fb685b2
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x9e32235f8ab8e48d,
+                    0x81b15fad9f695e24,
+                    0x81d7a94dd64c53c2,
+                    0x30e1fdd50df8874d,
+                    0x5157c8e468974c96,
+                    0x2cc20b6b45bb99
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5a23a86ecba182ba,
+                    0x9c04a195317a5d97,
+                    0x13b5a7109968d50c,
+                    0xbfa7025bc6b151b2,
+                    0xdb22ac109e6ee39d,
+                    0x187810e10b4782b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd738d2c90340c3e1,
+                    0x90f47d9afb379379,
+                    0x124ac43a61194550,
+                    0xa8c01fdabd66f9ce,
+                    0x2c2482766b66c393,
+                    0x14a3a8c5bcdc942
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb3e7febafa4948b3,
+                    0x103b4fc2c152f496,
+                    0x51ef614e8c4b37af,
+                    0xfe2762f91b3cc43,
+                    0x7ac4db362dddcfb3,
+                    0x151cc42170ad14b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8fbee8406c73f8ea,
+                    0x69fbada6abeeb7cd,
+                    0x9e45ec1f5e403315,
+                    0xb2996638a7063105,
+                    0x8e9371d9b03d03b1,
+                    0x1733fbac4ad38bd
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdb4588e45fa886e0,
+                    0xa2de97084f53e619,
+                    0xd3fbeaadfba18b72,
+                    0xeb5b41a2fee9ce68,
+                    0x3a908800e1b6bbad,
+                    0x1433b190b98ea92
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf55e7589c38476a5,
+                    0x828f7fb79016b123,
+                    0x9939020f058fbaaf,
+                    0x86e12ec9d3c79411,
+                    0xe1977579c4c4703b,
+                    0x158397b08028cf8
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa945fdf5761f68b8,
+                    0xc4bf45814b44a897,
+                    0x885795fc0a8e602f,
+                    0xe42b0fd68b7aff82,
+                    0x9f5cba915f4e20bd,
+                    0xf5d5cb8ebc0f5d
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x343f25e0aa366c6b,
+                    0x3878f109b8630e26,
+                    0x20a936a8769b387a,
+                    0x6a383ae0d46e062a,
+                    0x67d0e5d43bb5fa8f,
+                    0x3893d260adf702
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x48dedcf272761674,
+                    0x5ebcdd8f3b1f741a,
+                    0x793eee2854041d80,
+                    0x6771ab61fa0150cc,
+                    0x453014b3ef90b1af,
+                    0x5c4dca993952c8
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x710e8e7cbebce77e,
+                    0x327e7aa5f4c32448,
+                    0x579309cb24cf242f,
+                    0x2b170ba17d6e3f8,
+                    0x68e9febe34bd0bde,
+                    0x105224e66ebf372
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1ece846dbbf63a6,
+                    0x97b59a85c713af29,
+                    0xe7e160075fb1b503,
+                    0x72fba27a8e51ca8e,
+                    0xf4b25703d0aa45c3,
+                    0xdc4fcab2c4cd31
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2f5393da4a01523f,
+                    0x7fb895cb785a9495,
+                    0x3fc974ad9333b705,
+                    0xbdd8e31e5cbdb8b9,
+                    0xb4883f6d0408edb8,
+                    0xd8a8dc7ef200af
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x771dd6dd995cf3d1,
+                    0x6d2c2ee1610ecc21,
+                    0x7508bdf3fa9f919a,
+                    0xf856f7ecdb00ba9,
+                    0x72524b6e0b5824f0,
+                    0x179cdae96db026f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc7b67b4fec4e70d5,
+                    0x5159b57132a3cd6b,
+                    0x7f945345a51f4c5f,
+                    0xfb54a2ec3628e269,
+                    0x4deea7a0edf0f28f,
+                    0x4ecbd7431c6ef6
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf5ccbfac229af821,
+                    0xab1771b86725bbab,
+                    0x80fe893dd7408e97,
+                    0x530d3d5525106a4e,
+                    0xc1c2814fbe75ac8e,
+                    0x19fc08a5367c633
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x499938fc0101fe9a,
+                    0x16598e29f5c18673,
+                    0xde4b9b51db46ffbc,
+                    0x12bd936167dd117c,
+                    0xf9bc685122634254,
+                    0xbef1b59cbaeccc
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x47ae88e834b60b8,
+                    0x8389ba6afb98b704,
+                    0xcf765c14c46a631e,
+                    0xf5ef99e283e3dd20,
+                    0x7013da2935c84628,
+                    0x232658b7b370f7
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a
+                ])
+            ),
+            field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+        ),
+    ];
+    /// y_num, of degree 33
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x688278fe072b1299,
+                    0xd2315c87df3822fa,
+                    0x74bd8e3262139b9d,
+                    0x3ac605ea7c0b0a03,
+                    0x6a98f684ededf7d7,
+                    0x567f1520904d4b
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd6c53097a85b3a32,
+                    0xa839695317d1c779,
+                    0x47abf7088c18cda4,
+                    0xb71cba4dfa0b3bc1,
+                    0x133ad511eb193d18,
+                    0xb1700581772c0b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x30df9996bfdf136c,
+                    0xe922c463a7f26cdd,
+                    0xdb9516e8471a1714,
+                    0x25d4986cd90e735a,
+                    0x1773e84b065aad3e,
+                    0x843201e5f32fe7
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc17f715c3c12341a,
+                    0xa40a9a888cbf868e,
+                    0x5dc5607085dab89e,
+                    0x6b944f551dc0ae39,
+                    0x45d3f4a0fa85d3f1,
+                    0x1746d7ab0bfcf69
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc604ec7f5b520978,
+                    0x546a42d926d1aca1,
+                    0x913daea70eea5736,
+                    0xe8ac2caebaed384d,
+                    0x80fc98facb49a65a,
+                    0x8b7d8ea571ee16
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x30ad54593e1a095,
+                    0xacabc9752694e21f,
+                    0x44322b9a63891a42,
+                    0xf7ef3c736b2c98b8,
+                    0xe28ba5d8aae9b520,
+                    0x84f1de21f39ca6
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x617be26c893b4ba8,
+                    0x122c736a79cf1f8f,
+                    0x9fa9574a578d9a18,
+                    0x4b0747d7d7b6e6fc,
+                    0xdbdd17091bcb54fa,
+                    0xc176d1f1eaf755
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x422bc4c45582a33b,
+                    0xf4128970ef4be64e,
+                    0x83bda04f2e7186a,
+                    0xb7c5339f8d40ffc3,
+                    0x5efb303c7dc7bf9a,
+                    0xc7f5320a8cc652
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf811cd948095fc5a,
+                    0xb5ba71b2dfb8c1a9,
+                    0x44391c1b262a50f2,
+                    0xfcb73ba19a25f35d,
+                    0x4096ae40628a60a1,
+                    0xd3de8de053faec
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x250dc036c8199b70,
+                    0x4a8edbe1afe0c950,
+                    0xa3eedd00ca732cd5,
+                    0x627f9324205f8e8b,
+                    0xdd224ca76bebe907,
+                    0x10e2cdf680edd71
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc2bfa2bf0b90f3df,
+                    0x62606568e58fda31,
+                    0xdd9161f1f6610ade,
+                    0x8f5b827e4bfea87,
+                    0x5e9fbd11b863cc7f,
+                    0xbbe9c4244dfd70
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xffcb272396691bc9,
+                    0x354bc069db059ff4,
+                    0x658d05f7929dc788,
+                    0xecf7e98b59336c3a,
+                    0xeb64734588a6b546,
+                    0x1096247a333801d
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x18547587b46249ce,
+                    0xb5f9b8523e824602,
+                    0x4504889696b8c5a,
+                    0xcdacfc2140132310,
+                    0xa1d60eb61ff070ad,
+                    0x166dd0e0b5b5468
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdda44b60613d2ddb,
+                    0xaff801f21171a606,
+                    0x18048f7d0a0e4e50,
+                    0xc9a948cdb184c985,
+                    0x1c5f33ea87e569fa,
+                    0x19cd7339695f21f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x21298584d15d82c8,
+                    0x96f14effd68abd4f,
+                    0x1ebbe7891fe48e92,
+                    0xcac35a9551f1b3fc,
+                    0x99ad3e859d1631c7,
+                    0xcd9ab367542ba5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x822bcb1c48b6594,
+                    0x12251ca046348f92,
+                    0xe7ad69bb3dacca2,
+                    0x16b3ff81c18b5f9e,
+                    0xbc9a1d7201a24b28,
+                    0x15804c52dbb2058
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x9792ecdaa0370103,
+                    0x2a65746390be1ca7,
+                    0xb555c5ac127eafd0,
+                    0x863e0b7929e18e3e,
+                    0x59df333058c4175c,
+                    0xc70c5e08dfc9b6
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x26e90df5a5ad059b,
+                    0x3f8d3209e512eaa3,
+                    0x77701adbb1425405,
+                    0xd5f4a5d09dee8f,
+                    0x98ee61757ce7aa26,
+                    0x1c9784f0138986
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd0b3f56377a00f07,
+                    0xa365c5e95ff860ce,
+                    0xf41a4169c91dadd2,
+                    0x175e3039162aba33,
+                    0x29cecc8c45a0fe2f,
+                    0x5f97accd3b542e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x17845a240714a2fa,
+                    0xc07bdc1b2165217d,
+                    0xc763c18e49b5ae3a,
+                    0xe4f6256dfc09bc3,
+                    0x59b6f509dfeb1a0a,
+                    0x5f406047cf36d7
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x67a52c36b633d810,
+                    0x21a8c45dc44c9b4e,
+                    0xa80fde421b4804d1,
+                    0x63adcaa36d2b1190,
+                    0x605df6997f21da46,
+                    0x177fc7e1735e05b
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc15acc4b5ac8735f,
+                    0x94569ca754602456,
+                    0xcb1d9e37fe49d431,
+                    0x3d81e0c647110def,
+                    0x9eaf63a880f3ae6c,
+                    0xd8b72c518a660b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdb6419ede3010bef,
+                    0xe06310f53bb7377f,
+                    0xf1f3d449278a23ca,
+                    0xe5e62679b6653602,
+                    0x8fa114194cb299da,
+                    0x12facd1835d413f
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb47d24ed34678420,
+                    0xab7fe95a808d8e31,
+                    0x4c5490410d803c66,
+                    0xa5cb8f9b5c65e716,
+                    0xdd58c54b4bc72ef3,
+                    0xff683f6f26a156
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaa93a6d1fe4b6fe9,
+                    0x79fb25375c511435,
+                    0x8e53aada87a34ddf,
+                    0x4c51807e4771dc86,
+                    0x24583f1f0d05346b,
+                    0x178f29970b6b5f5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2f5ec46d42b1acb0,
+                    0x17fa2f018148f557,
+                    0x88ac2b76ba9d9bfa,
+                    0xf45b0f4347b6f9fa,
+                    0xa2a9a9eda26b8957,
+                    0x3571c21ad68696
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe1af5fccd5654e66,
+                    0xfc94840b553e1c39,
+                    0x9bbc00de69fbcf5c,
+                    0xad5ab9a502609728,
+                    0xb0e0eaa93f323554,
+                    0x7c76ef4c695368
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x59f8a9e430b35d2e,
+                    0x8b526a6b21bd7f38,
+                    0xf7da5e80fea0cb88,
+                    0x2c06ba851c52539e,
+                    0x5474932de87d66e2,
+                    0x220130b9f346da
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1ab1d4bbf3923f9c,
+                    0x317d88f81eb2eb6e,
+                    0x36d8f459614b3108,
+                    0xf79e59b17c660672,
+                    0x78c2e2f260ece6ca,
+                    0x139975a2fd0a522
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xbe203aedaebc73b3,
+                    0x9df5cf458df4af1e,
+                    0xa9d92d9d843dbb0b,
+                    0x34e1b4e313f85b3c,
+                    0x31a898bf2f8ca8e,
+                    0x172c69fd1151e6f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf562023020e18fa5,
+                    0x5619813bd976a061,
+                    0xc291350baec1f31f,
+                    0x1b87ea51adb1c346,
+                    0x355939438b88f6dc,
+                    0x6865eff40b58ae
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5c5178dc6e03623b,
+                    0x2e2d4db3b5e43a15,
+                    0xaa91af4ac90853b4,
+                    0x1daf82bdc31acfe9,
+                    0xbb0ad78c3a05b630,
+                    0xb09a9bf330fb02
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd55ab270e5dfde59,
+                    0x912cb1c74d63f15a,
+                    0xd8eeaf785e5c8d6c,
+                    0x654905bf3c628e9c,
+                    0x9747ad2f2e7166d,
+                    0x2f001459ef98dc
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa4219667ac08e8d6,
+                    0x78c1d4b3c10aaba6,
+                    0x1552f27ba5dce20a,
+                    0x5b06e9c38275008b,
+                    0xd4cb1a3e882116f4,
+                    0x159e5726c6ffaf4
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x75c2ecc2c9c66c02,
+                    0x363b8fb8777f7e90,
+                    0x6ce718a9bbe70913,
+                    0xcb44c9e1671c8813,
+                    0x43a28fc928137163,
+                    0x17f5d3389f8abd1
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe99d3e38e30337be,
+                    0x20a1e47b8792c041,
+                    0x54807394f2e8089c,
+                    0x64632b361544ccfc,
+                    0xb1140c3b5efd3188,
+                    0x132eae6ef0cec84
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc5bc89a6eeffd851,
+                    0x94526135e1bc1974,
+                    0x440d8ea35a74f81f,
+                    0x1768e28f9e1626e5,
+                    0x8c1a417f476d5e5e,
+                    0x55364e04238ab5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe47bba357f433e41,
+                    0x5bc7af74ba6556da,
+                    0xea520860d6a15b66,
+                    0x7becc1d967902c7e,
+                    0xb4528bfa52f02add,
+                    0x1a135023738d3cc
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1ca660b32cf8944,
+                    0x23680e0336432d1a,
+                    0xc4671be725f6c348,
+                    0x6306431562ec74e0,
+                    0x754bc7bedf73ba44,
+                    0x51f75c04af76e8
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x7bc257fe7557d777,
+                    0xf3ab3dd1d765e15d,
+                    0x740fdc91d1fb2a1,
+                    0xa80f22f3fd8c6649,
+                    0x62006bbe8298c3e9,
+                    0x19cfb2ef3cee54b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xed7a317ca2c4ca80,
+                    0x2e431c71380b73a8,
+                    0x76c8caaa0e6d6ddd,
+                    0x6218249ba3a8dcf2,
+                    0xa036e19e64e9ea2d,
+                    0x5ac55928180fe8
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x654ff4b40ad1e55e,
+                    0x831c768b2224fb56,
+                    0x6a60b84a6e036f27,
+                    0x42c8bb6795697278,
+                    0xd7aab852b57fcf3d,
+                    0x107e34ba155b1a2
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe0e08e14a48abbe8,
+                    0x9a97b3fadacdca29,
+                    0x45b1445f9d2d455,
+                    0xc158e46fc6c206c,
+                    0xe7f554d64209e41b,
+                    0xeb6f93654dd40d
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x443a4b8cec4707e3,
+                    0x3f7ae4261bc71041,
+                    0x635e10cad41aad6e,
+                    0x725ceae95eeed950,
+                    0x624a8f7fed98c797,
+                    0x395fd5111e68d3
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x45765b251b06552b,
+                    0x31a60605f8b0ba2c,
+                    0x848825a947592754,
+                    0x424cbd510550afae,
+                    0x6586aeb58ed85af1,
+                    0xa1610cd244ec08
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xeb1158d45a00c35c,
+                    0x1848009f2c7babca,
+                    0x3307d933dc825ffb,
+                    0xe55c71f195c144a5,
+                    0x5d736109cca96a1c,
+                    0x17dbc18fff198b2
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x31a8d9184e2ab706,
+                    0x99cc64fc48f5d88e,
+                    0x8bb1bf45f6f989c0,
+                    0xc37deb4f22814c2a,
+                    0xd8930c338cd54557,
+                    0x121beebec5f8048
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1bb529e86cf58a0a,
+                    0x904247856d42335a,
+                    0xf12f1059e7b8afca,
+                    0xee4c60f4aec9648e,
+                    0x5400275a0c7e1b7e,
+                    0x3e904e6be35fac
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa0aaf9daacb610c8,
+                    0x25d07b3559365d8c,
+                    0x627722835080b5ee,
+                    0xc3ea9a30a7b687d6,
+                    0xf2b74a6db821e24,
+                    0x2ab66f590b5fbb
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe5c87da9aa6a0069,
+                    0x7c93dd3cf648fd1c,
+                    0x7bb192e44a72e708,
+                    0xc546da4edcc7d6cb,
+                    0x54f77fb0c5ff045f,
+                    0xd8f30cc9ce1462
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcfa93a4357495c75,
+                    0xe05f1684321c9f15,
+                    0x2cf62c78d33307b6,
+                    0xb977bab0f3543e75,
+                    0xd551c96a04c89f4c,
+                    0x5e9be9236e258d
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc7a14ad07827227e,
+                    0x89ec017c6a569331,
+                    0xf8c5fbe1c4f424ba,
+                    0x78f9cdc75e89579,
+                    0x75fe13ee28cf848a,
+                    0x6ae29ecc2fbb29
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x7cc620732ae660e3,
+                    0x4cbdee011cfbaa91,
+                    0xdf47b55c4118157c,
+                    0xb058fad422deb38d,
+                    0x9b58949c6c685930,
+                    0x115968d71ccdf75
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x723400a0cca80d11,
+                    0x85de746ab3963619,
+                    0x9ecf0700b2c3510a,
+                    0x337171cd05c12452,
+                    0x18322dbf579ef9,
+                    0x1a4541ec904a87
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2b72151ec072ebaa,
+                    0x64a43320f6eb4cce,
+                    0x1a86b533733881ad,
+                    0x740d75d4d8e416ac,
+                    0xe4a652b51b7a937a,
+                    0x10030d48cc54517
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf2f8723c900a299d,
+                    0x46fcbb74993aab3b,
+                    0x3aa7b22c104abd9b,
+                    0xd2df8f14134c3be,
+                    0x10b40ea819ede2f3,
+                    0x3eace519c5ca4b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2fb13100f811320d,
+                    0x480ddca38a9ee4ea,
+                    0xfb8ab389a5492e0c,
+                    0x7791274c901584b,
+                    0x769e4afdd1870849,
+                    0x12b99c1d6ad011d
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x15a094faa33e9f6b,
+                    0x48ad49a4598f540f,
+                    0xfff4cedaf5f0cd6c,
+                    0xd7a262a32ad59ad1,
+                    0x2e9bf914ce085e53,
+                    0x10622681ed311f6
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xacdd336930f22b52,
+                    0xfdbd16bbc9ed0cde,
+                    0x805f2d5b876bb9a4,
+                    0xc6c0d7d677ba6b84,
+                    0x5752c3d2cb1fa1c9,
+                    0x13e29aeb9269544
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa6073ef167dcbe48,
+                    0xf5c057d6f037f731,
+                    0x1d6834bc39d8ef3f,
+                    0xe70886ffe0c9787f,
+                    0x96c4bbb5a8ebee3b,
+                    0x13363430b091762
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe1b1f7d54d63362f,
+                    0x9ecf116cf4057039,
+                    0x99b258e59489bc25,
+                    0x644407241a352200,
+                    0x854775f6f6189a4e,
+                    0x4e9a25e0c5e911
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x7a51d1528eb70d58,
+                    0x9c83bc1eaa2df98d,
+                    0x836f1b1d88534d57,
+                    0x32854543e6319ef1,
+                    0x9670893c3dbf3e94,
+                    0x79f59c29038ece
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd99c6b82f22f8c19,
+                    0x76c996c2ddb3c7a6,
+                    0xff198e8c8bddb49f,
+                    0xdcfd97eb52c53f46,
+                    0x2b126d13397d3b38,
+                    0x8967a36d0a4a2e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xbc699b94378e8e01,
+                    0xa8f495a64647ecf0,
+                    0x70c843304a323da5,
+                    0x11219f13e786c2c2,
+                    0xd4e3f87db7f6d353,
+                    0x10f0da5af9fcd77
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x13c8241b77c8a736,
+                    0x715af0c1d3bdc71d,
+                    0x3aee6275453bf344,
+                    0x2c8dc23027ccd149,
+                    0x2da356b90adfd61a,
+                    0xe786dd28e3676a
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4bc8ccac8cc197b5,
+                    0x872b10f7843ee5ff,
+                    0x9de6be4eb2f18aea,
+                    0xeeb3a2aada37e227,
+                    0xa22af1631194c9f3,
+                    0x17f8e7651e4fe5b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xfd46d4d733d2a2fb,
+                    0x67e432dee24ccc42,
+                    0x7930f6a2ecf9d7a0,
+                    0x657b5be873fdeb1,
+                    0xa8dd05b036329d6e,
+                    0x480a552ca602c4
+                ])
+            ),
+            field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+        ),
+    ];
+    /// y_den = K(x')^3
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField] = &[
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcf23bc6c790f3305,
+                    0xcdf870c397d3da2c,
+                    0x3506ab497d1e67ab,
+                    0xd9fc481a441a4d0b,
+                    0x5ebf6204ee027843,
+                    0x6d2f7755d629c8
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x22aa82007bebcb34,
+                    0x7ecd1a4ddf3a092d,
+                    0xfee97cd8e9b297aa,
+                    0xc8311fc188d07578,
+                    0x69b96cf3ec3c2913,
+                    0x182a39ef37744e
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xcbe4f35d00180119,
+                    0xf64368d403ef76d2,
+                    0x68c5dbc4b1159914,
+                    0x3e734a71829f8a2e,
+                    0x4c461d41c9efe232,
+                    0x5f35c4ac8a2904
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1459bb591814bbb6,
+                    0x915b0b02623d5649,
+                    0x90fee6959967c412,
+                    0x3fa5548ed33fc790,
+                    0x658e1b6e1ea84aaa,
+                    0xecfff73b9d31f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa4627328d66112d3,
+                    0x44870bc5d8e73808,
+                    0xf1ee20f40ca8b89,
+                    0xc0395d4f8e520d8f,
+                    0x67aea255bffb5c77,
+                    0xbe76dbcdd8b92d
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4faaf56679bd5908,
+                    0x41379f21c4fe04e5,
+                    0xd9dd5ae399e2b1eb,
+                    0xfedaaf38980b55d8,
+                    0xe0f933132156de38,
+                    0x1367e04f6d929af
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x141bd2e2ce6f25e9,
+                    0x5fb118b78def85e0,
+                    0xcd8d470dc49924a0,
+                    0xf524bbc1ebe46814,
+                    0x3024b8927c9b4702,
+                    0x1799a7b8839b4e9
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xef9e40be7ce2c310,
+                    0x760eb04b24f6e310,
+                    0xf63ece8b95ed5ec2,
+                    0xeaf3a0c1b5d07551,
+                    0xdff4bd51e6ee3f04,
+                    0x1a0c6f9e515b254
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6d42162c328f835a,
+                    0x98b221e0ba1029a5,
+                    0xdde08b600728b04c,
+                    0xdc041ff1bdacd862,
+                    0xca0ffb874178a2bc,
+                    0x216ff2f9ae70f1
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe0aedc616dd73e03,
+                    0xb086ea664ec5b3bd,
+                    0xa653bd61c9b82714,
+                    0x8cce569a0b2fe95f,
+                    0x584d15305584b33b,
+                    0x15dac7c0b63cf82
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf8ef098af037b88b,
+                    0x8b15f3fdece36aaf,
+                    0x2efaa3466373b60,
+                    0xca8219995592d86a,
+                    0x2724c9d9de1234f,
+                    0x218eeb86a4f79c
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf78678d6812fe242,
+                    0xf860b74f848e8caa,
+                    0x2b51869852626fcb,
+                    0x567bd879554bf884,
+                    0x82083dc2a69b0870,
+                    0x671c4107dca3b9
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc590b12bcffd9d28,
+                    0x609f7d6490c2e038,
+                    0x786ec9cab20a07b,
+                    0xabc294fc38e8ff21,
+                    0x5d1e635ec8ea8bbe,
+                    0xc17af2f1247502
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc6fdfb66ea3623fe,
+                    0x72ac9e44478f44d5,
+                    0x14ba0324b6d29a44,
+                    0x9058930ede07c84e,
+                    0x49cadf4c00f86150,
+                    0xd6e6d1e3663ee3
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa18b8e2928f390c9,
+                    0x3b798a9ba7f7621b,
+                    0x8ceac98c26e457cc,
+                    0x7faa27b4a8dfedaf,
+                    0x41e9e20e80d55c5d,
+                    0x6c4125384df1fb
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc8f494bcd877021e,
+                    0x8d2ca7bacb3b6b7c,
+                    0xf15dafc801e87f01,
+                    0xc9dd69930b4957fd,
+                    0xc836c97ebeef72d2,
+                    0x1a5a4dad47fac0e
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf81adc5a0c708b6d,
+                    0x6b1717571724fd66,
+                    0xd8b355f8c89c73a1,
+                    0x4db94bf495b80a6c,
+                    0x4a751ca660b36472,
+                    0x153f025213dd4f4
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc98a603e6fd6fd55,
+                    0xee4b706c8e48b2e6,
+                    0xc88100471b6cc243,
+                    0x97351c54d048f5a4,
+                    0xbb5e250c9539e058,
+                    0x19b8db7829f38c6
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd46a17c486a8b9df,
+                    0x2bcf7f46540abc3e,
+                    0x2ca9a910f4e3d7a0,
+                    0x279852a4190468e7,
+                    0xbeb603eec89ed78d,
+                    0x8d76e12342a456
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x87cdb2b1f86595c4,
+                    0x9d9446453031d6b9,
+                    0x6ea77bc0a88947eb,
+                    0x6cc236207c19d62,
+                    0x1f242dfe99b5ce7c,
+                    0x151c61fc0f111e0
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6ebec0e980e3b884,
+                    0xd0c2b8c1a544ef5f,
+                    0x82fa0b8ac08ac197,
+                    0x7232128810b0c86a,
+                    0xcb34ceb99c7c5cfc,
+                    0x182fd57264fe9a6
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc9105b6ef88e85da,
+                    0x9d5dce7af0afeb3,
+                    0x2a2334e8b48f2267,
+                    0xf9af2d24f3344960,
+                    0x7ca55d34ba0d05c0,
+                    0xebbcb0f88d87e5
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4a1e984d0a12c846,
+                    0x2d15e24ac0d6645a,
+                    0x83092753169233cc,
+                    0xd43f93c09303048e,
+                    0x7225175573a6dfcf,
+                    0x354b057e60c7c
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb47f5cd7ff1b8e33,
+                    0xe49cbbedd8941a9a,
+                    0xef0d5721632367a0,
+                    0xd3accfc7f2562086,
+                    0x3333ee5c001a558a,
+                    0xe210507a4f0663
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x349ad472fa5f056e,
+                    0x7fa5fe6e1dbdd63e,
+                    0x2d7ea59c7172f74d,
+                    0xcbe54e44aa6dc5fb,
+                    0x4f80bbd47f000fb8,
+                    0x9ca50905cd40db
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xedca31767430cf4,
+                    0x50070ec1e8c026eb,
+                    0x6b459a9d82484fae,
+                    0x511e58014f7d4db3,
+                    0x5862cb04af5880b8,
+                    0x3bac741d22017a
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x92ac2d1f41fa9d8b,
+                    0x247d5a599c02821c,
+                    0xb9e7555e28611bb5,
+                    0xcbafc0c13bacf567,
+                    0xd914b2ffda4c00b1,
+                    0x1299e298e022123
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x15fc3551a616a9da,
+                    0xee5d92fbe110e406,
+                    0x90fef3071881b95d,
+                    0x4e7dd8acb89410d1,
+                    0x3c2898c14a164fbb,
+                    0xc2010b1283a712
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xab94be8af6632995,
+                    0x95af6e90cf7c2dec,
+                    0xa0325b5d55398c83,
+                    0x9ae2d6fa540bbe7,
+                    0x5f0b32891daff961,
+                    0x14516605f70502
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x4ab4a148b750450c,
+                    0xc8db472960103d01,
+                    0xd38916501fa2ed88,
+                    0xf48ad78876819ca7,
+                    0x2ffdbff5c3a0f522,
+                    0x48543345425ee5
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x41ed72fa487bf627,
+                    0x6e52708521f8c783,
+                    0xeb894ef2bc38573f,
+                    0x628d9051948a4702,
+                    0x66286d12531cd695,
+                    0x17fc1966d8c12e2
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb03c2a46e86f447,
+                    0xd7a372a21556732f,
+                    0xd06a5ac0150226c3,
+                    0xc0663843ddb69814,
+                    0x46a87484c7e14c35,
+                    0x127f7e25a6c1a44
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xda317e0458bf5dbc,
+                    0xc5552060ef649acd,
+                    0xe1936d9c2fe914c3,
+                    0x6236473e9e7abcd0,
+                    0x366611d2fc02318c,
+                    0xc5ae1639013a01
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x345e4d8172016d21,
+                    0xb97f472062af0134,
+                    0x6baf338f207a377e,
+                    0xe4a4d0554cef60e0,
+                    0xcebab3f2dd63f4d7,
+                    0xed2924b9d5394f
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf12becc95dde41c8,
+                    0x268ed9fc027addf1,
+                    0xd5931ac41b0ed63,
+                    0x48f42fe14bba80c2,
+                    0x4b8df9ab6a7c3c26,
+                    0x7082d755d4c860
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x88e6fa6e7890e951,
+                    0xe6770b7f045ef191,
+                    0x2824043c904978d2,
+                    0xe4cce9b377879ed3,
+                    0xf61f5ad7fae70f7c,
+                    0x13c8c310a8720f8
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd80b7f01901a5aeb,
+                    0x1d1fc358c6398baf,
+                    0x498a1ff383311dc7,
+                    0x9d0669f2a42f0077,
+                    0x677e4036618f1473,
+                    0x31d0e41cf99075
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb2b4eddbdf36ba4b,
+                    0xb65a0380e7b8ef5c,
+                    0x2454013c66833cf7,
+                    0x6e118ea8e5fd6516,
+                    0x977faef0ef428389,
+                    0x23fbee74616924
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x52debeeedaa11b7a,
+                    0x1c29c81157c7c448,
+                    0x6677da7552ae2c11,
+                    0x45761da5323c7103,
+                    0x17cbf8dd3d845a15,
+                    0x8d2e4b96bbb2e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1b8ac9bb0c6f529e,
+                    0x7cc53b932aa0113,
+                    0x71918c934c1de227,
+                    0x398330a5178f9759,
+                    0x8d01d1d762c6281d,
+                    0xc7e543e0d3adbc
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1f6ad3e417f622a6,
+                    0x764ef7075de42020,
+                    0x667864863dea6b44,
+                    0xe51751a1d575bc5e,
+                    0xafbe20dfa06e76e5,
+                    0x353ca04b483e60
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe6471da9f8fd3e97,
+                    0x1918f8b26cda1bf9,
+                    0x330ab9fc0843d867,
+                    0xe3c16222534260dc,
+                    0xcdebfc78babd8cff,
+                    0x178d0e71f0b9bbc
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5243ba98cdb8164a,
+                    0x29b81d4ef750ed8e,
+                    0x8138b996c2f2f618,
+                    0x8cce8ea985903fbd,
+                    0xbc3caf805531e41f,
+                    0x14032e842e8d356
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa5dbc1bf530586d6,
+                    0x94a8aa33b681b197,
+                    0x69575c0db77b5a3c,
+                    0xc005c2c16b175da1,
+                    0xe75be563e6ae3c08,
+                    0x29f9b17ad7e899
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6da4768f619dfc96,
+                    0x21aed5e8b0b8d8be,
+                    0x2271c13c298c6445,
+                    0xdb3bb90caadf87b6,
+                    0xee38ea9e08fbc72b,
+                    0x1231e11230f3ba5
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xb6aca2212cff9e7a,
+                    0x4dbe99b576946555,
+                    0x500d070e270b6e80,
+                    0xc96fafa5147f1ebc,
+                    0x44f98c99a787854,
+                    0x1582327cc4c8cdc
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2a7a0f47c1d40ebe,
+                    0xf3a9e99e89a30c52,
+                    0xdd7e807209042e9d,
+                    0xf48c55574b8bd523,
+                    0xa5ce61d1a17203a,
+                    0x97a38dc55b651e
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xc42b93805bb0a3f9,
+                    0xcf9713f4ccf058af,
+                    0x96fe5d1bac6852a1,
+                    0x1fc6f238a29196b9,
+                    0xb61d929139fda4fa,
+                    0x4bd71c0c05fc47
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xe67ff927a571dd9,
+                    0xe72236b985e9e966,
+                    0xa11ad388d2a26a2d,
+                    0x5e1ba828897f1c62,
+                    0xe94716782a48bfdc,
+                    0x17224ee05f25a0
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf2e40bba92a0489d,
+                    0x6887eb804237aeb9,
+                    0x97e7728640b41420,
+                    0xba0e89649735444b,
+                    0x5ffac47dd717fe52,
+                    0x15f0fb9d59ce6dd
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x55270a342f8c47c8,
+                    0x27b49d7e1ecaed5d,
+                    0x67bc07f14c6765d6,
+                    0x21ac40483c3a47ae,
+                    0xfcfbdd6608e0ab18,
+                    0x139636fc0d19aa9
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x13525ca5eb69e4ca,
+                    0x1fd7910ed79a7d4b,
+                    0xf9eb251c4368d46b,
+                    0x246080f6d7bc428e,
+                    0x1ee6fc51d8e40677,
+                    0x1990f7cd6f626a4
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x45b95b16a205bc95,
+                    0x7d04bd1c4270b3db,
+                    0x864c2acb2764254,
+                    0x6b2e90e51f0ceb4e,
+                    0x6f0b473a3bf9896,
+                    0x1e15521d4d940f
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x36fce7aa3649de6c,
+                    0xf63f268dc160b266,
+                    0x7b42a36e407ac149,
+                    0x62d5c0894bf53aa1,
+                    0x807d4f9d7139e145,
+                    0x1a7116e9f7eede5
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3c1a5264bf81e893,
+                    0x8c6360812f4ce35f,
+                    0x31660dcdb1b37979,
+                    0x10212a8572944b41,
+                    0xefbbd8460973edb1,
+                    0x9dd84ff3fc8457
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x8ee776a6839d7199,
+                    0x44aeb99ff7075c19,
+                    0x6fa2edae84635cbb,
+                    0xdaf9ea0bf1a25870,
+                    0xa3eb4e926d3bbe39,
+                    0x5aa99125f19051
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd44de23d2e31857d,
+                    0xee51658d11c10e93,
+                    0x53ff8f569a06c978,
+                    0xa8b6a235ad82843a,
+                    0x587171a9f9b43ca0,
+                    0xc1ac4341b716a4
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3318a56470928431,
+                    0x967c13b8deb1103c,
+                    0x94c2f34656c0731a,
+                    0x2ed66073cca78f7d,
+                    0x9c7e0bb0979534f2,
+                    0xaa5880add1baa8
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaada68d5abd84896,
+                    0xa3eb9ee8184b5e70,
+                    0x2cfa8cda2310bcf2,
+                    0x5302cf4dc599d74b,
+                    0xd09871dd8ceab7c3,
+                    0xb7be928132dad2
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3c9eeb5e8a88d829,
+                    0x50e93371e88a5988,
+                    0xaae217fd41d8019a,
+                    0x2e90bf787e74f663,
+                    0x42c084a953edbcc3,
+                    0x104bda1afcf53bd
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd4547880f13fdb5d,
+                    0x7e68f2de6c7e14df,
+                    0xaa2a916565f80c97,
+                    0x12339e20a3e3647a,
+                    0xf644188fe0afcc23,
+                    0x187bd8979c0f1ac
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0xa95c4b0561741a19,
+                    0x41ba1206fae9fd4d,
+                    0x83a428282738330b,
+                    0x2b9cb61797c57523,
+                    0x69cbdd42ad185dbe,
+                    0x13f663ecec108eb
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xf4cdb6906fbc70ff,
+                    0x329db3a223f8cdee,
+                    0x69e44cdda8a485d4,
+                    0x3bd47a7ce7b6bd3c,
+                    0xc7fc2ecc13772f57,
+                    0x58ad25b252e1e0
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x445496010a225a10,
+                    0xc9902f77fdb491a0,
+                    0x8a280ca2a03f0bbe,
+                    0x46e3591d81ff8054,
+                    0x9cc99b0869bff18d,
+                    0x17a0847bab0ac1b
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xee65d57a0182fde7,
+                    0x2186553ef0a249ac,
+                    0x4d7168fac8ea7f9a,
+                    0x1c1c5d121bcb9a3b,
+                    0x769a9c79b394e37e,
+                    0x11e6a906b186333
+                ])
+            ),
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6b85cd5c4f11114,
+                    0xc54e97a079651286,
+                    0x37318a1f269f94ad,
+                    0x70e766d3c5d5cbb1,
+                    0x281dc73dd0ac693d,
+                    0x34b985138d2973
+                ])
+            )
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a
+                ])
+            ),
+            field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+        ),
+    ];
+}
@@ -19,6 +19,7 @@ use crate::{
     bls12_377::{
         g1::Bls12_377G1Parameters,
         g2::Bls12_377G2Parameters,
+        glv_decompose,
         Bls12_377,
         Fq,
         Fq12,
@@ -28,16 +29,22 @@ use crate::{
         Fq6Parameters,
         FqParameters,
         Fr,
+        FrParameters,
         G1Affine,
         G1Projective,
         G2Affine,
         G2Projective,
+        GLV_LAMBDA,
+    },
+    templates::{
+        short_weierstrass_jacobian::tests::{sw_swu_test, sw_tests},
+        twisted_edwards_extended::tests::edwards_test,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
     traits::{
         tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
         tests_group::*,
         tests_projective::curve_tests,
+        tests_timing::assert_constant_time,
         AffineCurve,
         PairingEngine,
         ProjectiveCurve,
@@ -60,6 +67,7 @@ use snarkvm_fields::{
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384},
     rand::{test_rng, Uniform},
+    BitIteratorBE,
 };
 
 use rand::{thread_rng, Rng, SeedableRng};
@@ -408,6 +416,29 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+#[test]
+fn test_fr_root_of_unity() {
+    assert_eq!(FrParameters::TWO_ADICITY, 47);
+    assert_eq!(Fr::two_adic_root_of_unity().pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+
+    // The large subgroup has order 2^47 * 3, and extends the 2-adic roots of unity.
+    let large_root = Fr::large_subgroup_root_of_unity().unwrap();
+    assert_eq!(large_root.pow([3]), Fr::two_adic_root_of_unity());
+    assert_ne!(large_root.pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_eq!(large_root.pow([3 << FrParameters::TWO_ADICITY]), Fr::one());
+
+    for size in [1, 3, 6, 1 << 10, 3 << 10] {
+        let root = Fr::get_root_of_unity(size).unwrap();
+        assert_eq!(root.pow([size as u64]), Fr::one());
+        if size > 1 {
+            assert_ne!(root.pow([size as u64 / 2]), Fr::one());
+            assert_ne!(root.pow([size as u64 / 3]), Fr::one());
+        }
+    }
+    assert!(Fr::get_root_of_unity(9).is_none());
+    assert!(Fr::get_root_of_unity(5).is_none());
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
@@ -563,6 +594,11 @@ fn test_g1_projective_group() {
     projective_test(a, b);
 }
 
+#[test]
+fn test_g1_montgomery_ladder() {
+    ladder_test::<G1Projective>();
+}
+
 #[test]
 fn test_g1_generator() {
     let generator = G1Affine::prime_subgroup_generator();
@@ -570,6 +606,11 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_map_to_curve() {
+    sw_swu_test::<Bls12_377G1Parameters>();
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -583,6 +624,11 @@ fn test_g2_projective_group() {
     projective_test(a, b);
 }
 
+#[test]
+fn test_g2_montgomery_ladder() {
+    ladder_test::<G2Projective>();
+}
+
 #[test]
 fn test_g2_generator() {
     let generator = G2Affine::prime_subgroup_generator();
@@ -590,6 +636,42 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_map_to_curve() {
+    sw_swu_test::<Bls12_377G2Parameters>();
+}
+
+#[test]
+fn test_glv_decomposition() {
+    let mut rng = test_rng();
+    let scalars = (0..100).map(|_| Fr::rand(&mut rng)).chain([Fr::zero(), Fr::one(), -Fr::one(), GLV_LAMBDA]);
+    for scalar in scalars {
+        let [k1, k2] = glv_decompose(&scalar).map(|(is_negative, k)| match is_negative {
+            true => -Fr::from(k),
+            false => Fr::from(k),
+        });
+        assert_eq!(scalar, k1 + GLV_LAMBDA * k2);
+    }
+}
+
+#[test]
+fn test_glv_mul() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let scalar = Fr::rand(&mut rng);
+        let bits = || BitIteratorBE::new(scalar.to_repr());
+
+        let g1 = G1Affine::rand(&mut rng);
+        assert_eq!(g1.glv_endomorphism().unwrap(), g1.mul_bits(BitIteratorBE::new(GLV_LAMBDA.to_repr())));
+        assert_eq!(g1 * scalar, g1.mul_bits(bits()));
+
+        let g2 = G2Affine::rand(&mut rng);
+        assert_eq!(g2.glv_endomorphism().unwrap(), g2.mul_bits(BitIteratorBE::new(GLV_LAMBDA.to_repr())));
+        assert_eq!(g2 * scalar, g2.mul_bits(bits()));
+    }
+    assert!((G1Affine::zero() * Fr::rand(&mut rng)).is_zero());
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -614,3 +696,32 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[cfg(feature = "ct")]
+#[test]
+#[ignore]
+fn test_field_timing() {
+    let mut rng = test_rng();
+    assert_constant_time("Fr::inverse", &mut rng, Fr::zero(), Fr::rand, |x| x.inverse());
+    assert_constant_time("Fq::inverse", &mut rng, Fq::zero(), Fq::rand, |x| x.inverse());
+    assert_constant_time("Fr::sqrt", &mut rng, Fr::one(), |rng| Fr::rand(rng).square(), |x| x.sqrt());
+    assert_constant_time("Fq::sqrt", &mut rng, Fq::one(), |rng| Fq::rand(rng).square(), |x| x.sqrt());
+    assert_constant_time(
+        "Fq::sub",
+        &mut rng,
+        (Fq::zero(), Fq::zero()),
+        |rng| (Fq::rand(rng), Fq::rand(rng)),
+        |(a, b)| a - b,
+    );
+}
+
+#[cfg(feature = "ct")]
+#[test]
+#[ignore]
+fn test_scalar_multiplication_timing() {
+    let mut rng = test_rng();
+    let g1 = G1Affine::prime_subgroup_generator();
+    assert_constant_time("G1Affine::mul", &mut rng, Fr::zero(), Fr::rand, |scalar| g1 * scalar);
+    let g2 = G2Affine::prime_subgroup_generator();
+    assert_constant_time("G2Affine::mul", &mut rng, Fr::zero(), Fr::rand, |scalar| g2 * scalar);
+}
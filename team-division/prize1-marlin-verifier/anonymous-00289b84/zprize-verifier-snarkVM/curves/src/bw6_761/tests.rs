@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bw6_761::{
+        g1::BW6_761G1Parameters,
+        g2::BW6_761G2Parameters,
+        BW6_761,
+        Fq,
+        Fq3,
+        Fq6,
+        FqParameters,
+        Fr,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingEngine,
+    },
+};
+use snarkvm_fields::{FftField, FftParameters, Field, FieldParameters, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::rand::{test_rng, Uniform};
+
+use std::ops::MulAssign;
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bw6_761_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_bw6_761_fq3() {
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rand::random();
+        let b: Fq3 = rand::random();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq3>();
+}
+
+#[test]
+fn test_bw6_761_fq6() {
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rand::random();
+        let h: Fq6 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq6>();
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 761);
+    assert_eq!(FqParameters::CAPACITY, 760);
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(FqParameters::TWO_ADICITY, 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq6_mul_by_014() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let c0 = Fq::rand(&mut rng);
+        let c1 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_014(&c0, &c1, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, c1, Fq::zero()), Fq3::new(Fq::zero(), c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_034() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let c0 = Fq::rand(&mut rng);
+        let c3 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, Fq::zero(), Fq::zero()), Fq3::new(c3, c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<BW6_761G1Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let a: G1Projective = rand::random();
+    let b: G1Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<BW6_761G2Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let a: G2Projective = rand::random();
+    let b: G2Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = BW6_761::pairing(sa, b);
+    let ans2 = BW6_761::pairing(a, sb);
+    let ans3 = BW6_761::pairing(a, b).pow(s.to_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq6::one());
+    assert_ne!(ans2, Fq6::one());
+    assert_ne!(ans3, Fq6::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
+}
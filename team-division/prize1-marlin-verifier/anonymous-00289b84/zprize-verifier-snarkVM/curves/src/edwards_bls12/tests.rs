@@ -65,6 +65,11 @@ fn test_projective_group() {
     }
 }
 
+#[test]
+fn test_montgomery_ladder() {
+    ladder_test::<EdwardsProjective>();
+}
+
 #[test]
 fn test_affine_group() {
     for _i in 0..10 {
@@ -404,3 +409,14 @@ fn test_isomorphism() {
 
     assert_eq!(fr_element, fr_element_reconstructed);
 }
+
+#[cfg(feature = "ct")]
+#[test]
+#[ignore]
+fn test_scalar_multiplication_timing() {
+    use crate::traits::tests_timing::assert_constant_time;
+
+    let mut rng = snarkvm_utilities::rand::test_rng();
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert_constant_time("EdwardsAffine::mul", &mut rng, Fr::zero(), Fr::rand, |scalar| generator * scalar);
+}
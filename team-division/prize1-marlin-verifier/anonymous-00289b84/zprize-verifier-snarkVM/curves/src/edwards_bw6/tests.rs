@@ -59,6 +59,11 @@ fn test_projective_group() {
     }
 }
 
+#[test]
+fn test_montgomery_ladder() {
+    ladder_test::<EdwardsProjective>();
+}
+
 #[test]
 fn test_affine_group() {
     for _i in 0..10 {
@@ -27,6 +27,8 @@ extern crate thiserror;
 
 pub mod bls12_377;
 
+pub mod bw6_761;
+
 pub mod edwards_bls12;
 
 pub mod edwards_bw6;
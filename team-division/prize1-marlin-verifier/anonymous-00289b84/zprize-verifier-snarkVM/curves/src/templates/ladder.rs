@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::ProjectiveCurve;
+use snarkvm_fields::{Choice, ConditionallySelectable, PrimeField};
+use snarkvm_utilities::biginteger::BigInteger;
+
+/// Returns `base * scalar`, computed with a Montgomery ladder that performs the same sequence
+/// of group operations for every scalar.
+///
+/// The ladder computes `base * (scalar + 2^n)`, where `n` is the bit size of the scalar field,
+/// so that the number of steps is fixed and the ladder starts from `(base, 2 * base)`, instead of
+/// from the identity. The offset `base * 2^n` does not depend on `scalar`, and is subtracted at the end.
+/// On twisted Edwards curves, the unified addition law makes every step uniform. On short Weierstrass
+/// curves, the addition formulas branch on their exceptional cases, which the ladder only reaches
+/// if `base` is the identity, or for a negligible fraction of scalars.
+pub fn montgomery_ladder<G: ProjectiveCurve + ConditionallySelectable>(base: G, scalar: &G::ScalarField) -> G {
+    let scalar = scalar.to_repr();
+    let num_bits = G::ScalarField::size_in_bits();
+
+    let (mut r0, mut r1) = (base, base.double());
+    let mut offset = base;
+    for i in (0..num_bits).rev() {
+        // Swap the points if the bit is set, so that the same operations update the right point.
+        let bit = Choice::from_bool(scalar.get_bit(i));
+        G::conditional_swap(&mut r0, &mut r1, bit);
+        r1 += &r0;
+        r0.double_in_place();
+        G::conditional_swap(&mut r0, &mut r1, bit);
+        offset.double_in_place();
+    }
+    r0 - offset
+}
@@ -127,6 +127,10 @@ macro_rules! impl_sw_curve_serializer {
                 let point = if let Compress::Yes = compress {
                     let (x, flags) = P::BaseField::deserialize_with_flags::<_, SWFlags>(&mut reader)?;
                     if flags.is_infinity() {
+                        // The point at infinity is only encoded with a zero x-coordinate.
+                        if !x.is_zero() {
+                            return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                        }
                         Self::zero()
                     } else {
                         Affine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
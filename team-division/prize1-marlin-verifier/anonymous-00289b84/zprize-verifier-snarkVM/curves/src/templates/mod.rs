@@ -15,6 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod bls12;
+pub mod bw6;
+pub mod ladder;
 pub mod short_weierstrass_jacobian;
 pub mod to_field_vec;
 pub mod twisted_edwards_extended;
@@ -16,8 +16,8 @@
 
 use crate::{
     impl_sw_curve_serializer,
-    templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    templates::{ladder::montgomery_ladder, short_weierstrass_jacobian::Projective},
+    traits::{AffineCurve, GLVParameters, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
 use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
@@ -61,6 +61,32 @@ impl<P: Parameters> Affine<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
         Self { x, y, infinity }
     }
+
+    /// Multiplies this element by `scalar`, splitting the scalar into two halves of at most 128 bits
+    /// with the GLV endomorphism, and adding both halves in a single double-and-add loop.
+    /// This element must be in the prime order subgroup.
+    fn glv_mul(&self, scalar: &P::ScalarField, glv: &GLVParameters<P::BaseField, P::ScalarField>) -> Projective<P> {
+        if self.is_zero() {
+            return Projective::zero();
+        }
+        let [(k1_is_negative, k1), (k2_is_negative, k2)] = (glv.decompose)(scalar);
+        let endomorphism = Self::new(self.x * glv.omega, self.y, false);
+        let p1 = if k1_is_negative { -*self } else { *self };
+        let p2 = if k2_is_negative { -endomorphism } else { endomorphism };
+        let p1_plus_p2 = p1.to_projective().add_mixed(&p2).to_affine();
+
+        let mut output = Projective::zero();
+        for i in (0..128 - (k1 | k2).leading_zeros()).rev() {
+            output.double_in_place();
+            match ((k1 >> i) & 1 == 1, (k2 >> i) & 1 == 1) {
+                (true, true) => output.add_assign_mixed(&p1_plus_p2),
+                (true, false) => output.add_assign_mixed(&p1),
+                (false, true) => output.add_assign_mixed(&p2),
+                (false, false) => (),
+            }
+        }
+        output
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -157,6 +183,14 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         unimplemented!()
     }
 
+    fn glv_endomorphism(&self) -> Option<Self> {
+        P::GLV.map(|glv| Self::new(self.x * glv.omega, self.y, self.infinity))
+    }
+
+    fn glv_decomposition(scalar: &Self::ScalarField) -> Option<[(bool, u128); 2]> {
+        P::GLV.map(|glv| (glv.decompose)(scalar))
+    }
+
     fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> Projective<P> {
         let mut output = Projective::zero();
         for i in bits {
@@ -173,7 +207,7 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 
     fn mul_by_cofactor_inv(&self) -> Self {
-        (*self * P::COFACTOR_INV).into()
+        self.mul_bits(BitIteratorBE::new(P::COFACTOR_INV.to_repr())).into()
     }
 
     #[inline]
@@ -282,7 +316,14 @@ impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Projective<P>;
 
     fn mul(self, other: P::ScalarField) -> Self::Output {
-        self.mul_bits(BitIteratorBE::new(other.to_repr()))
+        if cfg!(feature = "ct") {
+            return montgomery_ladder(self.into_projective(), &other);
+        }
+
+        match P::GLV {
+            Some(glv) => self.glv_mul(&other, &glv),
+            None => self.mul_bits(BitIteratorBE::new(other.to_repr())),
+        }
     }
 }
 
@@ -20,5 +20,8 @@ pub use affine::*;
 pub mod projective;
 pub use projective::*;
 
+pub mod swu;
+pub use swu::*;
+
 #[cfg(test)]
 pub mod tests;
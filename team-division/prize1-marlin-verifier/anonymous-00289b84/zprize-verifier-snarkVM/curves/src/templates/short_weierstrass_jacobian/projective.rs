@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    templates::short_weierstrass_jacobian::Affine,
+    templates::{ladder::montgomery_ladder, short_weierstrass_jacobian::Affine},
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
+use snarkvm_fields::{impl_add_sub_from_field_ref, Choice, ConditionallySelectable, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
 
 use rand::{
@@ -51,6 +51,17 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Projective<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Zero for Projective<P> {
     // The point at infinity is always represented by Z = 0.
     #[inline]
@@ -509,6 +520,10 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
+        if cfg!(feature = "ct") {
+            return montgomery_ladder(self, &other);
+        }
+
         let mut res = Self::zero();
         for i in BitIteratorBE::new_without_leading_zeros(other.to_repr()) {
             res.double_in_place();
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, SWUParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger, ToBytes};
+
+impl<P: SWUParameters> Affine<P> {
+    /// Maps a base field element to a point of the curve, with `map_to_curve_simple_swu` to the
+    /// isogenous curve, followed by `iso_map`, as in the IETF hash-to-curve draft.
+    /// The point is not guaranteed to be in the prime order subgroup.
+    pub fn map_to_curve(u: P::BaseField) -> Self {
+        let (a, b, z) = (P::ISOGENOUS_COEFF_A, P::ISOGENOUS_COEFF_B, P::SWU_Z);
+        let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+        // x1 = -b / a * (1 + 1 / (z^2 u^4 + z u^2)), or b / (z a) if the denominator is zero.
+        let z_u2 = z * u.square();
+        let x1 = match (z_u2.square() + z_u2).inverse() {
+            Some(tv1) => -b / a * (P::BaseField::one() + tv1),
+            None => b / (z * a),
+        };
+        let x2 = z_u2 * x1;
+
+        // As g(x2) = z^3 u^6 g(x1), and z is not a square, one of g(x1) and g(x2) is a square.
+        let (x, y) = match g(x1).sqrt() {
+            Some(y1) => (x1, y1),
+            None => (x2, g(x2).sqrt().expect("g(x2) is a square if g(x1) is not")),
+        };
+        let y = if sgn0(&u) == sgn0(&y) { y } else { -y };
+
+        Self::isogeny_map(x, y)
+    }
+
+    /// Maps a point of the isogenous curve to the curve.
+    fn isogeny_map(x: P::BaseField, y: P::BaseField) -> Self {
+        let evaluate = |coefficients: &[P::BaseField]| {
+            coefficients.iter().rev().fold(P::BaseField::zero(), |result, coefficient| result * x + coefficient)
+        };
+
+        match (evaluate(P::ISOGENY_X_DENOMINATOR).inverse(), evaluate(P::ISOGENY_Y_DENOMINATOR).inverse()) {
+            (Some(x_den_inv), Some(y_den_inv)) => {
+                let point = Self::new(
+                    evaluate(P::ISOGENY_X_NUMERATOR) * x_den_inv,
+                    y * evaluate(P::ISOGENY_Y_NUMERATOR) * y_den_inv,
+                    false,
+                );
+                debug_assert!(point.is_on_curve());
+                point
+            }
+            // The denominators only vanish on the kernel of the isogeny, which is mapped to the identity.
+            _ => Self::zero(),
+        }
+    }
+}
+
+/// Returns `sgn0` of the IETF hash-to-curve draft, which is the parity of the first nonzero
+/// coordinate of `element` over the base prime field.
+pub fn sgn0<F: Field>(element: &F) -> bool {
+    // The coordinates are written in order, each as its canonical little-endian representation.
+    let coordinate_size = <F::BasePrimeField as PrimeField>::BigInteger::NUM_LIMBS * 8;
+    let bytes = element.to_bytes_le().expect("Failed to serialize a field element");
+    bytes
+        .chunks(coordinate_size)
+        .find(|coordinate| coordinate.iter().any(|byte| *byte != 0))
+        .map_or(false, |coordinate| coordinate[0] & 1 == 1)
+}
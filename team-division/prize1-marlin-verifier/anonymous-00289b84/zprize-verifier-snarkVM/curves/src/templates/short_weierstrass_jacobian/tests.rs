@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{Affine, Projective};
-use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
+use crate::{AffineCurve, ProjectiveCurve, SWUParameters, ShortWeierstrassParameters};
 use snarkvm_fields::Zero;
 use snarkvm_utilities::{
     io::Cursor,
@@ -32,6 +32,7 @@ pub const ITERATIONS: usize = 10;
 
 pub fn sw_tests<P: ShortWeierstrassParameters>() {
     sw_curve_serialization_test::<P>();
+    sw_decompression_validation_test::<P>();
     sw_from_random_bytes::<P>();
 }
 
@@ -126,6 +127,55 @@ pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
     }
 }
 
+pub fn sw_decompression_validation_test<P: ShortWeierstrassParameters>() {
+    let buf_size = Affine::<P>::zero().compressed_size();
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        // A point on the curve, outside of the prime order subgroup.
+        let a = loop {
+            if let Some(a) = Affine::<P>::from_x_coordinate(P::BaseField::rand(&mut rng), false) {
+                if !a.is_in_correct_subgroup_assuming_on_curve() {
+                    break a;
+                }
+            }
+        };
+        let mut serialized = vec![0; buf_size];
+        let mut cursor = Cursor::new(&mut serialized[..]);
+        a.serialize_compressed(&mut cursor).unwrap();
+
+        let mut cursor = Cursor::new(&serialized[..]);
+        Affine::<P>::deserialize_compressed(&mut cursor).unwrap_err();
+        let mut cursor = Cursor::new(&serialized[..]);
+        assert_eq!(a, Affine::<P>::deserialize_compressed_unchecked(&mut cursor).unwrap());
+    }
+
+    // The point at infinity has a single encoding, with a zero x-coordinate.
+    let mut serialized = vec![0; buf_size];
+    let mut cursor = Cursor::new(&mut serialized[..]);
+    Affine::<P>::zero().serialize_compressed(&mut cursor).unwrap();
+    serialized[0] |= 1;
+    let mut cursor = Cursor::new(&serialized[..]);
+    Affine::<P>::deserialize_compressed_unchecked(&mut cursor).unwrap_err();
+}
+
+pub fn sw_swu_test<P: SWUParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let u = P::BaseField::rand(&mut rng);
+        let a = Affine::<P>::map_to_curve(u);
+        assert!(a.is_on_curve());
+        // The sign of the y-coordinate follows the sign of u.
+        assert_eq!(Affine::<P>::map_to_curve(-u), -a);
+        assert!(a.mul_by_cofactor().is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    // The denominator of the map vanishes at zero.
+    assert!(Affine::<P>::map_to_curve(P::BaseField::zero()).is_on_curve());
+}
+
 pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>() {
     let buf_size = Affine::<P>::zero().compressed_size();
 
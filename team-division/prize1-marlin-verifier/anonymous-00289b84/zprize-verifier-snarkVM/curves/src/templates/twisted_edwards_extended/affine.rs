@@ -16,7 +16,7 @@
 
 use crate::{
     impl_edwards_curve_serializer,
-    templates::twisted_edwards_extended::Projective,
+    templates::{ladder::montgomery_ladder, twisted_edwards_extended::Projective},
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
 use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
@@ -266,6 +266,10 @@ impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Projective<P>;
 
     fn mul(self, other: P::ScalarField) -> Self::Output {
+        if cfg!(feature = "ct") {
+            return montgomery_ladder(self.into_projective(), &other);
+        }
+
         self.mul_bits(BitIteratorBE::new(other.to_repr()))
     }
 }
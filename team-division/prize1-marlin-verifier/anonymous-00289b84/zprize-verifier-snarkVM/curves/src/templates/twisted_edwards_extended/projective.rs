@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    templates::twisted_edwards_extended::Affine,
+    templates::{ladder::montgomery_ladder, twisted_edwards_extended::Affine},
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
+use snarkvm_fields::{impl_add_sub_from_field_ref, Choice, ConditionallySelectable, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
 
 use rand::{
@@ -53,6 +53,18 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Projective<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Zero for Projective<P> {
     fn zero() -> Self {
         Self::new(P::BaseField::zero(), P::BaseField::one(), P::BaseField::zero(), P::BaseField::one())
@@ -342,6 +354,10 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
+        if cfg!(feature = "ct") {
+            return montgomery_ladder(self, &other);
+        }
+
         let mut res = Self::zero();
 
         let mut found_one = false;
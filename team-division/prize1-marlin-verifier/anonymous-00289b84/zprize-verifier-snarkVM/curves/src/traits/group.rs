@@ -188,6 +188,18 @@ pub trait AffineCurve:
     /// an integer.
     fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> Self::Projective;
 
+    /// Returns the image of this element under the GLV endomorphism of the curve, if it has one.
+    #[must_use]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        None
+    }
+
+    /// Decomposes `scalar` into halves of at most 128 bits for the GLV endomorphism of the curve,
+    /// if it has one. See [`GLVParameters::decompose`].
+    fn glv_decomposition(_scalar: &Self::ScalarField) -> Option<[(bool, u128); 2]> {
+        None
+    }
+
     /// Multiply this element by the cofactor.
     #[must_use]
     fn mul_by_cofactor(&self) -> Self {
@@ -257,12 +269,26 @@ pub trait ModelParameters: Send + Sync + 'static + Sized {
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInteger>;
 }
 
+/// The parameters of a GLV endomorphism `φ(x, y) = (ω x, y)` of a short Weierstrass curve with `a = 0`.
+#[derive(Copy, Clone, Debug)]
+pub struct GLVParameters<F: Field, S: PrimeField> {
+    /// A primitive cube root of unity in the base field.
+    pub omega: F,
+    /// The eigenvalue of `φ` on the prime order subgroup, so that `φ(P) = λ P`.
+    pub lambda: S,
+    /// Decomposes a scalar `k` into halves `[k1, k2]` with `k = k1 + λ k2`, each given as
+    /// a sign, set if the half is negative, and a magnitude of at most 128 bits.
+    pub decompose: fn(&S) -> [(bool, u128); 2],
+}
+
 pub trait ShortWeierstrassParameters: ModelParameters {
     const COEFF_A: Self::BaseField;
     const COEFF_B: Self::BaseField;
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// The GLV endomorphism of the curve, if it has one.
+    const GLV: Option<GLVParameters<Self::BaseField, Self::ScalarField>> = None;
 
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
@@ -281,6 +307,23 @@ pub trait ShortWeierstrassParameters: ModelParameters {
     fn is_in_correct_subgroup_assuming_on_curve(p: &short_weierstrass_jacobian::Affine<Self>) -> bool;
 }
 
+/// The parameters of the simplified SWU map of the IETF hash-to-curve draft, for a short Weierstrass
+/// curve that is reached by an isogeny from a curve `y^2 = x^3 + a' x + b'` with `a' b' != 0`.
+pub trait SWUParameters: ShortWeierstrassParameters {
+    /// The coefficient `a'` of the isogenous curve.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+    /// The coefficient `b'` of the isogenous curve.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+    /// The non-square `Z` of the map, chosen with `find_z_sswu` of the draft.
+    const SWU_Z: Self::BaseField;
+    /// The isogeny `(x', y') -> (x_num(x') / x_den(x'), y' * y_num(x') / y_den(x'))`, given as
+    /// the coefficients of `x_num`, `x_den`, `y_num` and `y_den`, from the constant term up.
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField];
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField];
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField];
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField];
+}
+
 pub trait TwistedEdwardsParameters: Copy + Clone + Debug + Default + PartialEq + Eq + ModelParameters {
     const COEFF_A: Self::BaseField;
     const COEFF_D: Self::BaseField;
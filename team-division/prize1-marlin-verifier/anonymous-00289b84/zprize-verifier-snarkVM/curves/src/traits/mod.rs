@@ -28,3 +28,6 @@ pub mod tests_group;
 
 #[cfg(test)]
 pub mod tests_projective;
+
+#[cfg(test)]
+pub mod tests_timing;
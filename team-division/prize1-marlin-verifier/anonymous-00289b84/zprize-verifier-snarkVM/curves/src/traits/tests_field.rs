@@ -14,7 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use snarkvm_fields::{
+    traits::FftParameters,
+    Choice,
+    FftField,
+    Field,
+    LegendreSymbol,
+    PrimeField,
+    SquareRootField,
+};
 use snarkvm_utilities::{
     io::Cursor,
     rand::test_rng,
@@ -104,6 +112,28 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_selection_tests<F: Field, R: Rng>(rng: &mut R) {
+    assert!(F::zero().ct_eq(&F::zero()).unwrap_bool());
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(F::conditional_select(&a, &b, Choice::from_bool(false)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from_bool(true)), b);
+
+        let (mut c, mut d) = (a, b);
+        F::conditional_swap(&mut c, &mut d, Choice::from_bool(false));
+        assert_eq!((c, d), (a, b));
+        F::conditional_swap(&mut c, &mut d, Choice::from_bool(true));
+        assert_eq!((c, d), (b, a));
+
+        assert!(a.ct_eq(&a).unwrap_bool());
+        assert_eq!(a.ct_eq(&b).unwrap_bool(), a == b);
+        assert_eq!(a.ct_eq(&F::zero()).unwrap_bool(), a.is_zero());
+    }
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -309,6 +339,7 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_selection_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{test_rng, Uniform};
+use crate::{templates::ladder::montgomery_ladder, AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{ConditionallySelectable, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    rand::{test_rng, Uniform},
+};
 
 #[allow(clippy::eq_op)]
 pub fn affine_test<G: AffineCurve>(a: G) {
@@ -93,3 +96,20 @@ pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G) {
     assert_eq!(a_rand2.mul(fr_rand1), a.mul(fr_rand1 * fr_rand2), "(a * r2) * r1 != a * (r1 * r2)");
     assert_eq!(a_rand1.mul(fr_rand2), a.mul(fr_rand1 * fr_rand2), "(a * r1) * r2 != a * (r1 * r2)");
 }
+
+pub fn ladder_test<G: ProjectiveCurve + ConditionallySelectable>() {
+    let mut rng = test_rng();
+
+    let fr_one = G::ScalarField::one();
+    let mut scalars = vec![G::ScalarField::zero(), fr_one, fr_one.double(), -fr_one];
+    scalars.extend((0..10).map(|_| G::ScalarField::rand(&mut rng)));
+
+    for base in [G::prime_subgroup_generator(), G::rand(&mut rng)] {
+        for scalar in &scalars {
+            let expected = base.to_affine().mul_bits(BitIteratorBE::new(scalar.to_repr()));
+            assert_eq!(montgomery_ladder(base, scalar), expected);
+            assert_eq!(base * *scalar, expected);
+        }
+    }
+    assert!(montgomery_ladder(G::zero(), &G::ScalarField::rand(&mut rng)).is_zero());
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A timing leakage test, following "Dude, is my code constant time?" (Reparaz, Balasch and Verbauwhede).
+//!
+//! An operation is timed on two classes of inputs, a fixed input and random inputs, in a random order.
+//! Welch's t-test then checks whether the two timing distributions have the same mean, over all
+//! measurements, and over the measurements below a few percentiles, which discards the long tail of
+//! measurements interrupted by the operating system. A `|t|` above [`T_THRESHOLD`] is evidence of a leak.
+//!
+//! These tests only measure wall-clock time with `std::time::Instant`, so they run on plain Linux.
+//! They are slow and sensitive to the machine load, so they are ignored by default, and should be run with
+//! `cargo test --release --features ct -- --ignored timing`.
+
+use criterion::black_box;
+use rand::Rng;
+use std::time::Instant;
+
+/// The `|t|` above which the two classes are considered distinguishable.
+pub const T_THRESHOLD: f64 = 4.5;
+
+/// The number of measurements taken by [`timing_test`].
+pub const MEASUREMENTS: usize = 200_000;
+
+/// The percentiles below which the measurements are cropped, in addition to the uncropped measurements.
+const PERCENTILES: [f64; 4] = [0.5, 0.75, 0.9, 0.99];
+
+/// The running mean and variance of a sequence of measurements, using Welford's algorithm.
+#[derive(Copy, Clone, Default)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn push(&mut self, value: f64) {
+        self.count += 1.0;
+        let delta = value - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2.0 { 0.0 } else { self.m2 / (self.count - 1.0) }
+    }
+}
+
+/// Returns Welch's t statistic of the two classes.
+fn welch_t(fixed: &Moments, random: &Moments) -> f64 {
+    let denominator = (fixed.variance() / fixed.count + random.variance() / random.count).sqrt();
+    if denominator == 0.0 { 0.0 } else { (fixed.mean - random.mean) / denominator }
+}
+
+/// Times `operation` on `fixed`, and on inputs sampled with `sample`, and returns the largest `|t|`
+/// over the uncropped and cropped measurements.
+pub fn timing_test<T: Copy, O, R: Rng>(
+    rng: &mut R,
+    fixed: T,
+    mut sample: impl FnMut(&mut R) -> T,
+    operation: impl Fn(T) -> O,
+) -> f64 {
+    // Prepare the inputs beforehand, so that sampling does not pollute the measurements.
+    let inputs: Vec<(bool, T)> = (0..MEASUREMENTS)
+        .map(|_| match rng.gen::<bool>() {
+            true => (true, fixed),
+            false => (false, sample(rng)),
+        })
+        .collect();
+
+    // Warm up the caches and the branch predictors.
+    for (_, input) in inputs.iter().take(MEASUREMENTS / 100) {
+        black_box(operation(black_box(*input)));
+    }
+
+    let timings: Vec<(bool, f64)> = inputs
+        .iter()
+        .map(|(is_fixed, input)| {
+            let input = black_box(*input);
+            let start = Instant::now();
+            black_box(operation(input));
+            (*is_fixed, start.elapsed().as_nanos() as f64)
+        })
+        .collect();
+
+    let mut sorted: Vec<f64> = timings.iter().map(|(_, timing)| *timing).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let thresholds = PERCENTILES.iter().map(|p| sorted[((sorted.len() - 1) as f64 * p) as usize]).chain([f64::MAX]);
+
+    thresholds
+        .map(|threshold| {
+            let (mut fixed, mut random) = (Moments::default(), Moments::default());
+            for (is_fixed, timing) in timings.iter().filter(|(_, timing)| *timing <= threshold) {
+                match is_fixed {
+                    true => fixed.push(*timing),
+                    false => random.push(*timing),
+                }
+            }
+            welch_t(&fixed, &random).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Asserts that [`timing_test`] does not distinguish the two classes of inputs of `operation`.
+pub fn assert_constant_time<T: Copy, O, R: Rng>(
+    name: &str,
+    rng: &mut R,
+    fixed: T,
+    sample: impl FnMut(&mut R) -> T,
+    operation: impl Fn(T) -> O,
+) {
+    let t = timing_test(rng, fixed, sample, operation);
+    println!("{name}: max |t| = {t:.2}");
+    assert!(t < T_THRESHOLD, "{name} leaks timing information: max |t| = {t:.2} >= {T_THRESHOLD}");
+}
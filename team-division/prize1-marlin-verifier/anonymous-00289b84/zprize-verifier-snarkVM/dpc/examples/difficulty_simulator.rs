@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_dpc::{prelude::*, testnet2::Testnet2};
+
+use anyhow::{anyhow, Result};
+
+/// Runs the given scenario for the given number of blocks, with the given target block time and half life,
+/// and prints the block time statistics.
+pub fn simulate(scenario: &str, number_of_blocks: u32, block_time: i64, half_life: i64) -> Result<SimulationReport> {
+    let mut config = SimulationConfig::new::<Testnet2>(number_of_blocks);
+    config.target_block_time = block_time;
+    config.half_life = half_life;
+
+    // The hashrate at which the anchor difficulty target meets the target block time.
+    let hashrate = u64::MAX as f64 / config.anchor_difficulty_target as f64 / block_time as f64;
+    config.hashrate = match scenario {
+        "constant" => HashrateSchedule::Constant(hashrate),
+        "step" => HashrateSchedule::Step { before: hashrate, after: 10.0 * hashrate, height: number_of_blocks / 4 },
+        "oscillation" => HashrateSchedule::Oscillation { high: 3.0 * hashrate, low: hashrate, period: 200 },
+        "manipulation" => {
+            config.timestamps = TimestampStrategy::Manipulated { share: 0.3, offset: -3_600 };
+            HashrateSchedule::Constant(hashrate)
+        }
+        _ => return Err(anyhow!("Invalid scenario {}", scenario)),
+    };
+
+    let report = config.simulate::<Testnet2>()?;
+    println!("{}", serde_json::to_string_pretty(&report.statistics)?);
+    Ok(report)
+}
+
+pub fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 3 {
+        println!(
+            "Usage: {} <constant|step|oscillation|manipulation> <number_of_blocks> [block_time] [half_life] [vectors_file]",
+            args[0]
+        );
+        return;
+    }
+
+    let number_of_blocks = args[2].parse().unwrap();
+    let block_time = args.get(3).map_or(Testnet2::ALEO_BLOCK_TIME_IN_SECS, |arg| arg.parse().unwrap());
+    let half_life = args.get(4).map_or(Blocks::<Testnet2>::ASERT_HALF_LIFE_IN_SECS, |arg| arg.parse().unwrap());
+
+    let report = simulate(&args[1], number_of_blocks, block_time, half_life).unwrap();
+
+    // Export the difficulty targets of the simulation as reference vectors.
+    if let Some(vectors_file) = args.get(5) {
+        let vectors = serde_json::to_string_pretty(&report.to_retarget_vectors()).unwrap();
+        std::fs::write(vectors_file, vectors).unwrap();
+    }
+}
@@ -17,12 +17,24 @@
 use crate::prelude::*;
 use snarkvm_algorithms::merkle_tree::*;
 
+use snarkvm_utilities::{FromBytes, ToBytes};
+
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 use time::OffsetDateTime;
 
-#[derive(Clone, Debug)]
+/// The metadata key of the latest block height in storage.
+const LATEST_BLOCK_HEIGHT_KEY: &[u8] = b"latest_block_height";
+
+///
+/// The canonical chain of blocks, backed by a storage.
+///
+/// `Blocks` is the only writer of its storage, and it is not `Clone`, so the chain held in memory
+/// can not diverge from the chain in storage through a copy that writes to the same storage.
+///
+#[derive(Derivative)]
+#[derivative(Debug(bound = "N: Network"))]
 pub struct Blocks<N: Network> {
     /// The current block height.
     current_height: u32,
@@ -36,11 +48,24 @@ pub struct Blocks<N: Network> {
     headers: HashMap<u32, BlockHeader<N>>,
     /// The chain of block transactions.
     transactions: HashMap<u32, Transactions<N>>,
+    /// The storage that every block is committed to.
+    #[derivative(Debug = "ignore")]
+    storage: Arc<dyn Storage>,
 }
 
 impl<N: Network> Blocks<N> {
-    /// Initializes a new instance of `Blocks` with the genesis block.
+    /// Initializes a new instance of `Blocks` with the genesis block, in memory.
     pub fn new() -> Result<Self> {
+        Self::open(Arc::new(MemoryStorage::new()))
+    }
+
+    ///
+    /// Opens an instance of `Blocks` from the given storage.
+    ///
+    /// If the storage is empty, it is initialized with the genesis block. Otherwise, the chain is
+    /// restored from the blocks in storage, and checked to be linked from the genesis block onwards.
+    ///
+    pub fn open(storage: Arc<dyn Storage>) -> Result<Self> {
         let genesis_block = N::genesis_block();
         let height = genesis_block.height();
 
@@ -51,12 +76,59 @@ impl<N: Network> Blocks<N> {
             previous_hashes: Default::default(),
             headers: Default::default(),
             transactions: Default::default(),
+            storage,
+        };
+
+        let latest_block_height = match blocks.storage.get(DataMap::Metadata, LATEST_BLOCK_HEIGHT_KEY)? {
+            Some(bytes) => u32::from_bytes_le(&bytes)?,
+            None => {
+                let ledger_tree_index = blocks.ledger_tree.add(&genesis_block.hash())?;
+                blocks.previous_hashes.insert(height, genesis_block.previous_block_hash());
+                blocks.headers.insert(height, genesis_block.header().clone());
+                blocks.transactions.insert(height, genesis_block.transactions().clone());
+
+                blocks.storage.write_batch(Self::to_storage_batch(genesis_block, ledger_tree_index)?)?;
+                return Ok(blocks);
+            }
         };
 
-        blocks.ledger_tree.add(&genesis_block.hash())?;
-        blocks.previous_hashes.insert(height, genesis_block.previous_block_hash());
-        blocks.headers.insert(height, genesis_block.header().clone());
-        blocks.transactions.insert(height, genesis_block.transactions().clone());
+        // Restore the chain of blocks.
+        let mut block_hashes = Vec::with_capacity(latest_block_height as usize + 1);
+        for height in 0..=latest_block_height {
+            let block_hash: N::BlockHash = blocks.read_from_storage(DataMap::BlockHashes, height)?;
+            let previous_hash: N::BlockHash = blocks.read_from_storage(DataMap::PreviousBlockHashes, height)?;
+
+            // Ensure the stored chain starts from the genesis block of this network, and is linked.
+            match block_hashes.last() {
+                None if block_hash != genesis_block.hash() => {
+                    return Err(anyhow!("The storage does not start from the genesis block of this network"));
+                }
+                Some(expected_previous_hash) if *expected_previous_hash != previous_hash => {
+                    return Err(anyhow!("The stored block at height {} has an incorrect previous block hash", height));
+                }
+                _ => (),
+            }
+
+            let header = blocks.read_from_storage(DataMap::BlockHeaders, height)?;
+            let transactions = blocks.read_from_storage(DataMap::BlockTransactions, height)?;
+
+            blocks.previous_hashes.insert(height, previous_hash);
+            blocks.headers.insert(height, header);
+            blocks.transactions.insert(height, transactions);
+            block_hashes.push(block_hash);
+        }
+
+        // Restore the ledger tree from its stored leaves.
+        let leaves = (0..=latest_block_height)
+            .map(|index| blocks.read_from_storage(DataMap::LedgerTree, index))
+            .collect::<Result<Vec<N::BlockHash>>>()?;
+        if leaves != block_hashes {
+            return Err(anyhow!("The stored ledger tree does not match the stored chain of blocks"));
+        }
+        blocks.ledger_tree.add_all(&leaves)?;
+
+        blocks.current_height = latest_block_height;
+        blocks.current_hash = block_hashes[latest_block_height as usize];
 
         Ok(blocks)
     }
@@ -193,6 +265,75 @@ impl<N: Network> Blocks<N> {
 
     /// Adds the given block as the next block in the chain.
     pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+        // Add the block to the ledger. This code section executes atomically.
+        let mut blocks = self.stage();
+        let batch = blocks.stage_next(block)?;
+
+        // Commit the block to storage, which either persists in full or not at all.
+        self.storage.write_batch(batch)?;
+
+        *self = blocks;
+        Ok(())
+    }
+
+    /// Removes the latest block from the chain, and returns it. The genesis block cannot be removed.
+    pub fn remove_last(&mut self) -> Result<Block<N>> {
+        // Ensure the genesis block is not removed.
+        let height = self.current_height;
+        if height == 0 {
+            return Err(anyhow!("The genesis block cannot be removed from the ledger"));
+        }
+
+        let mut removed = self.remove_above(height - 1)?;
+        removed.pop().ok_or_else(|| anyhow!("Failed to remove the block at height {}", height))
+    }
+
+    ///
+    /// Removes every block above the given height from the chain, and returns them from the earliest to the latest.
+    ///
+    /// The blocks are removed from storage in a single batch, and the ledger tree is rebuilt once.
+    ///
+    pub fn remove_above(&mut self, height: u32) -> Result<Vec<Block<N>>> {
+        // Remove the blocks from the ledger. This code section executes atomically.
+        let mut blocks = self.stage();
+        let (removed, batch) = blocks.stage_removal(height)?;
+
+        // Remove the blocks from storage, which either persists in full or not at all.
+        self.storage.write_batch(batch)?;
+
+        *self = blocks;
+        Ok(removed)
+    }
+
+    ///
+    /// Replaces every block above the given height with the given blocks, and returns the removed blocks
+    /// from the earliest to the latest.
+    ///
+    /// The removals and the additions are written to storage in a single batch, so the chain in storage
+    /// is either replaced in full or left unchanged. If one of the given blocks can not be added,
+    /// the chain is left unchanged, and the index of that block is returned instead.
+    ///
+    pub fn replace_above(&mut self, height: u32, blocks: &[Block<N>]) -> Result<Result<Vec<Block<N>>, usize>> {
+        // Replace the blocks in the ledger. This code section executes atomically.
+        let mut staged = self.stage();
+        let (removed, mut batch) = staged.stage_removal(height)?;
+        for (index, block) in blocks.iter().enumerate() {
+            match staged.stage_next(block) {
+                Ok(addition) => batch.extend(addition),
+                Err(_) => return Ok(Err(index)),
+            }
+        }
+
+        // Replace the blocks in storage, which either persists in full or not at all.
+        self.storage.write_batch(batch)?;
+
+        *self = staged;
+        Ok(Ok(removed))
+    }
+
+    /// Adds the given block as the next block of `self`, in memory only,
+    /// and returns the storage batch that commits it.
+    fn stage_next(&mut self, block: &Block<N>) -> Result<StorageBatch> {
         // Ensure the block itself is valid.
         if !block.is_valid() {
             return Err(anyhow!("The given block is invalid"));
@@ -282,21 +423,73 @@ impl<N: Network> Blocks<N> {
             }
         }
 
-        // Add the block to the ledger. This code section executes atomically.
-        {
-            let mut blocks = self.clone();
+        // Add the block to the chain.
+        self.current_height = height;
+        self.current_hash = block_hash;
+        let ledger_tree_index = self.ledger_tree.add(&block.hash())?;
+        self.previous_hashes.insert(height, block.previous_block_hash());
+        self.headers.insert(height, block.header().clone());
+        self.transactions.insert(height, block.transactions().clone());
 
-            blocks.current_height = height;
-            blocks.current_hash = block_hash;
-            blocks.ledger_tree.add(&block.hash())?;
-            blocks.previous_hashes.insert(height, block.previous_block_hash());
-            blocks.headers.insert(height, block.header().clone());
-            blocks.transactions.insert(height, block.transactions().clone());
+        Self::to_storage_batch(block, ledger_tree_index)
+    }
+
+    /// Removes every block above the given height from `self`, in memory only,
+    /// and returns the removed blocks from the earliest to the latest, with the storage batch that removes them.
+    fn stage_removal(&mut self, height: u32) -> Result<(Vec<Block<N>>, StorageBatch)> {
+        if height > self.current_height {
+            return Err(anyhow!("Given block height {} is greater than current height", height));
+        }
 
-            *self = blocks;
+        let removed =
+            ((height + 1)..=self.current_height).map(|height| self.get_block(height)).collect::<Result<Vec<_>>>()?;
+        let mut batch = StorageBatch::new();
+        if removed.is_empty() {
+            return Ok((removed, batch));
         }
 
-        Ok(())
+        // Remove the blocks from the latest to the earliest, so the latest block height is stored last.
+        for block in removed.iter().rev() {
+            let ledger_tree_index = match self.ledger_tree.get_block_hash_index(&block.hash()) {
+                Some(index) => *index,
+                None => return Err(anyhow!("Missing ledger tree index for block {}", block.hash())),
+            };
+            self.previous_hashes.remove(&block.height());
+            self.headers.remove(&block.height());
+            self.transactions.remove(&block.height());
+            batch.extend(Self::to_storage_removal_batch(block, ledger_tree_index)?);
+        }
+        self.current_height = height;
+        self.current_hash = removed[0].previous_block_hash();
+
+        // Rebuild the ledger tree without the block hashes, as the tree only supports appending leaves.
+        let block_hashes = (0..=height).map(|height| self.get_block_hash(height)).collect::<Result<Vec<_>>>()?;
+        self.ledger_tree = LedgerTree::<N>::new()?;
+        self.ledger_tree.add_all(&block_hashes)?;
+
+        Ok((removed, batch))
+    }
+
+    /// Returns a copy of the chain in memory, to apply changes to before they are committed to storage.
+    /// The copy must replace `self` once the changes are committed, and is discarded otherwise.
+    fn stage(&self) -> Self {
+        Self {
+            current_height: self.current_height,
+            current_hash: self.current_hash,
+            ledger_tree: self.ledger_tree.clone(),
+            previous_hashes: self.previous_hashes.clone(),
+            headers: self.headers.clone(),
+            transactions: self.transactions.clone(),
+            storage: self.storage.clone(),
+        }
+    }
+
+    /// Returns the transition given the transition ID, from storage.
+    pub fn get_transition(&self, transition_id: &N::TransitionID) -> Result<Transition<N>> {
+        match self.storage.get(DataMap::Transitions, &transition_id.to_bytes_le()?)? {
+            Some(bytes) => Transition::from_bytes_le(&bytes),
+            None => Err(anyhow!("Missing transition {} in storage", transition_id)),
+        }
     }
 
     /// Returns the ledger tree.
@@ -399,6 +592,70 @@ impl<N: Network> Blocks<N> {
         LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
     }
 
+    /// Returns the storage batch that commits the given block, with its block hash at the given ledger tree index.
+    fn to_storage_batch(block: &Block<N>, ledger_tree_index: u32) -> Result<StorageBatch> {
+        let height = block.height().to_le_bytes().to_vec();
+
+        let mut batch = StorageBatch::new();
+        batch.insert(DataMap::BlockHashes, height.clone(), block.hash().to_bytes_le()?);
+        batch.insert(DataMap::PreviousBlockHashes, height.clone(), block.previous_block_hash().to_bytes_le()?);
+        batch.insert(DataMap::BlockHeaders, height.clone(), block.header().to_bytes_le()?);
+        batch.insert(DataMap::BlockTransactions, height.clone(), block.transactions().to_bytes_le()?);
+
+        for transition in block.transactions().iter().flat_map(Transaction::transitions) {
+            let transition_id = transition.transition_id().to_bytes_le()?;
+            for serial_number in transition.serial_numbers() {
+                batch.insert(DataMap::SerialNumbers, serial_number.to_bytes_le()?, transition_id.clone());
+            }
+            for commitment in transition.commitments() {
+                batch.insert(DataMap::Commitments, commitment.to_bytes_le()?, transition_id.clone());
+            }
+            batch.insert(DataMap::Transitions, transition_id, transition.to_bytes_le()?);
+        }
+
+        batch.insert(DataMap::LedgerTree, ledger_tree_index.to_le_bytes().to_vec(), block.hash().to_bytes_le()?);
+        batch.insert(DataMap::Metadata, LATEST_BLOCK_HEIGHT_KEY.to_vec(), height);
+
+        Ok(batch)
+    }
+
+    /// Returns the storage batch that removes the given block, with its block hash at the given ledger tree index.
+    fn to_storage_removal_batch(block: &Block<N>, ledger_tree_index: u32) -> Result<StorageBatch> {
+        let height = block.height().to_le_bytes().to_vec();
+
+        let mut batch = StorageBatch::new();
+        batch.remove(DataMap::BlockHashes, height.clone());
+        batch.remove(DataMap::PreviousBlockHashes, height.clone());
+        batch.remove(DataMap::BlockHeaders, height.clone());
+        batch.remove(DataMap::BlockTransactions, height);
+
+        for transition in block.transactions().iter().flat_map(Transaction::transitions) {
+            for serial_number in transition.serial_numbers() {
+                batch.remove(DataMap::SerialNumbers, serial_number.to_bytes_le()?);
+            }
+            for commitment in transition.commitments() {
+                batch.remove(DataMap::Commitments, commitment.to_bytes_le()?);
+            }
+            batch.remove(DataMap::Transitions, transition.transition_id().to_bytes_le()?);
+        }
+
+        batch.remove(DataMap::LedgerTree, ledger_tree_index.to_le_bytes().to_vec());
+        batch.insert(DataMap::Metadata, LATEST_BLOCK_HEIGHT_KEY.to_vec(), (block.height() - 1).to_le_bytes().to_vec());
+
+        Ok(batch)
+    }
+
+    /// Returns the value at the given height or index in the given map, from storage.
+    fn read_from_storage<T: FromBytes>(&self, map: DataMap, height: u32) -> Result<T> {
+        match self.storage.get(map, &height.to_le_bytes())? {
+            Some(bytes) => T::from_bytes_le(&bytes),
+            None => Err(anyhow!("Missing {:?} entry for height {} in storage", map, height)),
+        }
+    }
+
+    /// The half life of the ASERT difficulty retarget algorithm, in seconds.
+    pub const ASERT_HALF_LIFE_IN_SECS: i64 = 64_800; // 64,800 seconds = 18 hours
+
     /// Returns the expected difficulty target given the previous block and expected next block details.
     pub fn compute_difficulty_target(
         anchor_block_header: &BlockHeader<N>,
@@ -431,6 +688,27 @@ impl<N: Network> Blocks<N> {
         block_timestamp: i64,
         block_height: u32,
         target_block_time: i64,
+    ) -> u64 {
+        Self::asert_retarget_with_half_life(
+            anchor_timestamp,
+            anchor_difficulty_target,
+            anchor_block_height,
+            block_timestamp,
+            block_height,
+            target_block_time,
+            Self::ASERT_HALF_LIFE_IN_SECS,
+        )
+    }
+
+    /// ASERT difficulty retarget algorithm with the given half life `tau` (in seconds), as in `asert_retarget`.
+    pub fn asert_retarget_with_half_life(
+        anchor_timestamp: i64,
+        anchor_difficulty_target: u64,
+        anchor_block_height: u32,
+        block_timestamp: i64,
+        block_height: u32,
+        target_block_time: i64,
+        half_life: i64,
     ) -> u64 {
         // Compute the difference in block time elapsed, defined as:
         // (block_timestamp - anchor_timestamp) - target_block_time * number_of_blocks_elapsed.
@@ -451,35 +729,8 @@ impl<N: Network> Blocks<N> {
             block_time_elapsed - expected_block_time_elapsed
         };
 
-        // Constants used for fixed point arithmetic.
-        const RBITS: u32 = 16;
-        const RADIX: u128 = 1 << RBITS;
-
-        // The half life for the expected duration in doubling the difficulty target.
-        const TAU: u128 = 64_800; // 64,800 seconds = 18 hours
-
-        // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
-        let (integral, fractional) = {
-            // Calculate the exponent factor.
-            let exponent = (RADIX as i128).saturating_mul(drift as i128) / (TAU as i128);
-
-            // Decompose into the integral and fractional parts.
-            let integral = exponent >> RBITS;
-            let fractional = (exponent - (integral << RBITS)) as u128;
-            assert!(fractional < RADIX, "Ensure fractional part is within fixed point size");
-            assert_eq!(exponent, integral * (RADIX as i128) + fractional as i128);
-
-            (integral, fractional)
-        };
-
-        // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
-        // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
-        let fractional_multiplier = RADIX
-            + ((195_766_423_245_049_u128 * fractional
-                + 971_821_376_u128 * fractional.pow(2)
-                + 5_127_u128 * fractional.pow(3)
-                + 2_u128.pow(RBITS * 3 - 1))
-                >> (RBITS * 3));
+        // Compute the multiplier and the shifts that scale the target by 2^(drift / tau).
+        let (fractional_multiplier, shifts) = asert_exponent(drift, half_life);
 
         // Cast the anchor difficulty target from a u64 to a u128.
         // The difficulty target must allow for leading zeros to account for overflows;
@@ -488,7 +739,6 @@ impl<N: Network> Blocks<N> {
 
         // Calculate the new difficulty.
         // Shift the target to multiply by 2^(integer) / RADIX.
-        let shifts = integral - RBITS as i128;
         let mut candidate_difficulty_target = if shifts < 0 {
             match candidate_difficulty_target.checked_shr((-shifts) as u32) {
                 Some(target) => core::cmp::max(target, 1),
@@ -511,6 +761,43 @@ impl<N: Network> Blocks<N> {
     }
 }
 
+///
+/// Returns the fixed-point approximation of 2^(drift / half_life) used by ASERT, as a multiplier in [2^16, 2^17)
+/// and a number of shifts, such that the scaled target is `(target * multiplier) << shifts`,
+/// or `>> -shifts` if the shifts are negative.
+///
+pub(crate) fn asert_exponent(drift: i64, half_life: i64) -> (u128, i128) {
+    // Constants used for fixed point arithmetic.
+    const RBITS: u32 = 16;
+    const RADIX: u128 = 1 << RBITS;
+
+    // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
+    let (integral, fractional) = {
+        // Calculate the exponent factor.
+        let exponent = (RADIX as i128).saturating_mul(drift as i128) / (half_life as i128);
+
+        // Decompose into the integral and fractional parts.
+        let integral = exponent >> RBITS;
+        let fractional = (exponent - (integral << RBITS)) as u128;
+        assert!(fractional < RADIX, "Ensure fractional part is within fixed point size");
+        assert_eq!(exponent, integral * (RADIX as i128) + fractional as i128);
+
+        (integral, fractional)
+    };
+
+    // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
+    // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // Divide by RADIX through the shifts, to account for the fixed point multiplier.
+    (fractional_multiplier, integral - RBITS as i128)
+}
+
 #[cfg(test)]
 #[allow(clippy::comparison_chain)]
 mod tests {
@@ -0,0 +1,495 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::biginteger::{BigInteger, BigInteger256};
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// The hashrate of the miners over the course of a simulation, in hashes per second,
+/// where a hash meets a difficulty target `T` with probability `T / u64::MAX`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum HashrateSchedule {
+    /// A constant hashrate.
+    Constant(f64),
+    /// A hashrate that changes from `before` to `after` at the given block height.
+    Step { before: f64, after: f64, height: u32 },
+    /// A hashrate that alternates between `high` and `low` every half `period` blocks, as with switch mining.
+    Oscillation { high: f64, low: f64, period: u32 },
+}
+
+impl HashrateSchedule {
+    /// Returns the hashrate while mining the block at the given height.
+    pub fn hashrate(&self, height: u32) -> f64 {
+        match *self {
+            Self::Constant(hashrate) => hashrate,
+            Self::Step { before, after, height: step_height } => match height < step_height {
+                true => before,
+                false => after,
+            },
+            Self::Oscillation { high, low, period } => match height % core::cmp::max(period, 2) < period / 2 {
+                true => high,
+                false => low,
+            },
+        }
+    }
+}
+
+/// The timestamps reported by the miners in a simulation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TimestampStrategy {
+    /// Every block reports the time it was found.
+    Honest,
+    /// The given share of blocks reports the time it was found plus the given offset (in seconds),
+    /// as far as the consensus rules on block timestamps permit.
+    Manipulated { share: f64, offset: i64 },
+}
+
+/// The configuration of a difficulty retarget simulation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    /// The target block time, in seconds.
+    pub target_block_time: i64,
+    /// The half life of the ASERT difficulty retarget algorithm, in seconds.
+    pub half_life: i64,
+    /// The maximum number of seconds that a block timestamp may be ahead of the time it was found.
+    pub future_time_limit: i64,
+    /// The difficulty target of the anchor block, at height 0 and timestamp 0.
+    pub anchor_difficulty_target: u64,
+    /// The number of blocks to simulate after the anchor block.
+    pub number_of_blocks: u32,
+    /// The hashrate of the miners.
+    pub hashrate: HashrateSchedule,
+    /// The timestamps reported by the miners.
+    pub timestamps: TimestampStrategy,
+    /// The seed of the random number generator.
+    pub seed: u64,
+}
+
+impl SimulationConfig {
+    /// Initializes a configuration with the consensus parameters and genesis difficulty target of the given network,
+    /// and a constant hashrate at which the genesis difficulty target meets the target block time.
+    pub fn new<N: Network>(number_of_blocks: u32) -> Self {
+        let anchor_difficulty_target = N::genesis_block().difficulty_target();
+        let hashrate = u64::MAX as f64 / anchor_difficulty_target as f64 / N::ALEO_BLOCK_TIME_IN_SECS as f64;
+
+        Self {
+            target_block_time: N::ALEO_BLOCK_TIME_IN_SECS,
+            half_life: Blocks::<N>::ASERT_HALF_LIFE_IN_SECS,
+            future_time_limit: N::ALEO_FUTURE_TIME_LIMIT_IN_SECS,
+            anchor_difficulty_target,
+            number_of_blocks,
+            hashrate: HashrateSchedule::Constant(hashrate),
+            timestamps: TimestampStrategy::Honest,
+            seed: 0,
+        }
+    }
+
+    ///
+    /// Simulates mining the configured number of blocks, with the difficulty targets of `Blocks::<N>`.
+    ///
+    /// Miners refresh their block template every second, so each block is mined at the difficulty target
+    /// for the timestamp it would report in that second, and is found with the probability given by the hashrate.
+    ///
+    pub fn simulate<N: Network>(&self) -> Result<SimulationReport> {
+        if self.target_block_time <= 0 || self.half_life <= 0 || self.anchor_difficulty_target == 0 {
+            return Err(anyhow!("The simulation requires a positive target block time, half life and anchor target"));
+        }
+
+        let rng = &mut StdRng::seed_from_u64(self.seed);
+
+        let mut blocks = Vec::with_capacity(self.number_of_blocks as usize);
+        let mut previous_timestamp = 0i64;
+        let mut previous_time_found = 0f64;
+
+        for height in 1..=self.number_of_blocks {
+            let hashrate = self.hashrate.hashrate(height);
+            if hashrate <= 0.0 {
+                return Err(anyhow!("The hashrate at block height {} must be positive", height));
+            }
+
+            // Determine the timestamp offset of the miner that finds this block.
+            let offset = match self.timestamps {
+                TimestampStrategy::Manipulated { share, offset } if rng.gen_bool(share.clamp(0.0, 1.0)) => offset,
+                _ => 0,
+            };
+
+            // Mine until the work of the hashrate exceeds an exponentially distributed amount of expected solutions.
+            let mut remaining_solutions = -(1.0 - rng.gen::<f64>()).ln();
+            let mut second = previous_time_found.floor() as i64;
+            let mut start = previous_time_found;
+            let (timestamp, difficulty_target, time_found) = loop {
+                // The block timestamp must be after the previous timestamp, and within the future time limit.
+                let timestamp = (second + offset).min(second + self.future_time_limit).max(previous_timestamp + 1);
+                let difficulty_target = Blocks::<N>::asert_retarget_with_half_life(
+                    0,
+                    self.anchor_difficulty_target,
+                    0,
+                    timestamp,
+                    height,
+                    self.target_block_time,
+                    self.half_life,
+                );
+
+                let solutions_per_second = hashrate * difficulty_target as f64 / u64::MAX as f64;
+                let duration = (second + 1) as f64 - start;
+                match remaining_solutions <= solutions_per_second * duration {
+                    true => break (timestamp, difficulty_target, start + remaining_solutions / solutions_per_second),
+                    false => remaining_solutions -= solutions_per_second * duration,
+                }
+
+                second += 1;
+                start = second as f64;
+            };
+
+            blocks.push(SimulatedBlock {
+                height,
+                timestamp,
+                difficulty_target,
+                block_time: time_found - previous_time_found,
+            });
+
+            previous_timestamp = timestamp;
+            previous_time_found = time_found;
+        }
+
+        let statistics = BlockTimeStatistics::new(&blocks.iter().map(|block| block.block_time).collect::<Vec<_>>())?;
+        Ok(SimulationReport { config: self.clone(), blocks, statistics })
+    }
+}
+
+/// A block of a difficulty retarget simulation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedBlock {
+    /// The block height.
+    pub height: u32,
+    /// The block timestamp, as reported by the miner.
+    pub timestamp: i64,
+    /// The block difficulty target.
+    pub difficulty_target: u64,
+    /// The time it took to find the block after the previous block, in seconds.
+    pub block_time: f64,
+}
+
+/// The statistics of the block times of a simulation, in seconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BlockTimeStatistics {
+    pub mean: f64,
+    pub standard_deviation: f64,
+    pub median: f64,
+    pub minimum: f64,
+    pub maximum: f64,
+}
+
+impl BlockTimeStatistics {
+    /// Computes the statistics of the given block times.
+    pub fn new(block_times: &[f64]) -> Result<Self> {
+        if block_times.is_empty() {
+            return Err(anyhow!("The statistics require at least one block time"));
+        }
+
+        let mut sorted = block_times.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / count;
+        let variance = sorted.iter().map(|block_time| (block_time - mean).powi(2)).sum::<f64>() / count;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2.0,
+            _ => sorted[sorted.len() / 2],
+        };
+
+        Ok(Self {
+            mean,
+            standard_deviation: variance.sqrt(),
+            median,
+            minimum: sorted[0],
+            maximum: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// The result of a difficulty retarget simulation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    /// The configuration of the simulation.
+    pub config: SimulationConfig,
+    /// The simulated blocks, in order of block height.
+    pub blocks: Vec<SimulatedBlock>,
+    /// The statistics of the block times.
+    pub statistics: BlockTimeStatistics,
+}
+
+impl SimulationReport {
+    /// Returns the difficulty targets of the simulated blocks as retarget vectors.
+    pub fn to_retarget_vectors(&self) -> RetargetVectors {
+        RetargetVectors {
+            target_block_time: self.config.target_block_time,
+            half_life: self.config.half_life,
+            anchor_height: 0,
+            anchor_timestamp: 0,
+            anchor_difficulty_target: self.config.anchor_difficulty_target,
+            blocks: self
+                .blocks
+                .iter()
+                .map(|block| RetargetVector {
+                    height: block.height,
+                    timestamp: block.timestamp,
+                    difficulty_target: block.difficulty_target,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A block height and timestamp, with its expected difficulty target.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetargetVector {
+    pub height: u32,
+    pub timestamp: i64,
+    pub difficulty_target: u64,
+}
+
+/// Reference vectors for the ASERT difficulty retarget algorithm, from a fixed anchor block.
+/// They are exported as JSON, so that other implementations can be checked against them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetargetVectors {
+    pub target_block_time: i64,
+    pub half_life: i64,
+    pub anchor_height: u32,
+    pub anchor_timestamp: i64,
+    pub anchor_difficulty_target: u64,
+    pub blocks: Vec<RetargetVector>,
+}
+
+impl RetargetVectors {
+    /// Ensures that every vector matches the difficulty target computed by `Blocks::<N>`.
+    pub fn verify<N: Network>(&self) -> Result<()> {
+        for block in &self.blocks {
+            let difficulty_target = Blocks::<N>::asert_retarget_with_half_life(
+                self.anchor_timestamp,
+                self.anchor_difficulty_target,
+                self.anchor_height,
+                block.timestamp,
+                block.height,
+                self.target_block_time,
+                self.half_life,
+            );
+            if difficulty_target != block.difficulty_target {
+                return Err(anyhow!(
+                    "Mismatched difficulty target at height {}. Found {}, but expected {}",
+                    block.height,
+                    difficulty_target,
+                    block.difficulty_target
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The target block time of aserti3-2d, in seconds.
+const ASERTI3_2D_BLOCK_TIME_IN_SECS: i64 = 600;
+/// The half life of aserti3-2d, in seconds.
+const ASERTI3_2D_HALF_LIFE_IN_SECS: i64 = 2 * 24 * 60 * 60;
+/// The compact encoding of the maximum target of aserti3-2d.
+const ASERTI3_2D_MAXIMUM_BITS: u32 = 0x1d00ffff;
+
+///
+/// Returns the compact target of the block after the block at the given height and time,
+/// with the aserti3-2d algorithm of https://www.reference.cash/protocol/forks/2020-11-15-asert.
+///
+/// This uses the same fixed-point exponent as `Blocks::asert_retarget`, with 256-bit targets,
+/// so that it can be checked against the reference aserti3-2d vectors.
+///
+pub fn aserti3_2d_next_bits(
+    anchor_height: u32,
+    anchor_parent_time: i64,
+    anchor_bits: u32,
+    height: u32,
+    time: i64,
+) -> u32 {
+    // The anchor block is compared to its parent timestamp, so one more block has elapsed.
+    let number_of_blocks_elapsed = height as i64 - anchor_height as i64 + 1;
+    let drift = time - anchor_parent_time - ASERTI3_2D_BLOCK_TIME_IN_SECS * number_of_blocks_elapsed;
+    let (fractional_multiplier, shifts) = asert_exponent(drift, ASERTI3_2D_HALF_LIFE_IN_SECS);
+
+    // Multiply the anchor target by the fractional multiplier, which is less than 2^17.
+    let anchor_target = compact_to_target(anchor_bits);
+    let mut target = BigInteger256::from(0);
+    for i in (0..u128::BITS - fractional_multiplier.leading_zeros()).rev() {
+        target.mul2();
+        if (fractional_multiplier >> i) & 1 == 1 {
+            target.add_nocarry(&anchor_target);
+        }
+    }
+
+    // Shift the target, saturating at the maximum target.
+    let maximum_target = compact_to_target(ASERTI3_2D_MAXIMUM_BITS);
+    match shifts < 0 {
+        true => target.divn((-shifts).min(256) as u32),
+        false if target.num_bits() as i128 + shifts > 256 => return ASERTI3_2D_MAXIMUM_BITS,
+        false => target.muln(shifts as u32),
+    }
+
+    if target.is_zero() {
+        target_to_compact(BigInteger256::from(1))
+    } else if target > maximum_target {
+        ASERTI3_2D_MAXIMUM_BITS
+    } else {
+        target_to_compact(target)
+    }
+}
+
+/// Returns the target of the given compact encoding, for a valid compact encoding.
+fn compact_to_target(bits: u32) -> BigInteger256 {
+    let size = bits >> 24;
+    let word = (bits & 0x007fffff) as u64;
+    match size <= 3 {
+        true => BigInteger256::from(word >> (8 * (3 - size))),
+        false => {
+            let mut target = BigInteger256::from(word);
+            target.muln(8 * (size - 3));
+            target
+        }
+    }
+}
+
+/// Returns the compact encoding of the given target, rounding the target down.
+fn target_to_compact(target: BigInteger256) -> u32 {
+    let mut size = (target.num_bits() + 7) / 8;
+    let mut compact = match size <= 3 {
+        true => (target.0[0] << (8 * (3 - size))) as u32,
+        false => {
+            let mut target = target;
+            target.divn(8 * (size - 3));
+            target.0[0] as u32
+        }
+    };
+
+    // The most significant bit of the mantissa is a sign bit, so it must not be set.
+    if compact & 0x00800000 != 0 {
+        compact >>= 8;
+        size += 1;
+    }
+    compact | (size << 24)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    /// Parses vectors in the format of the aserti3-2d reference vectors, returning
+    /// the anchor height, anchor parent time, anchor bits, and (height, time, bits) of each block.
+    fn parse_aserti3_2d_vectors(vectors: &str) -> Vec<(u32, i64, u32, Vec<(u32, i64, u32)>)> {
+        let parse_bits = |bits: &str| u32::from_str_radix(bits.trim_start_matches("0x"), 16).unwrap();
+
+        let mut runs = Vec::new();
+        for run in vectors.split("\n\n") {
+            let header = |name: &str| {
+                run.lines().find_map(|line| line.strip_prefix(&format!("## {}: ", name))).unwrap().to_string()
+            };
+            let blocks = run
+                .lines()
+                .filter(|line| !line.starts_with('#') && !line.is_empty())
+                .map(|line| {
+                    let fields = line.split_whitespace().collect::<Vec<_>>();
+                    (fields[1].parse().unwrap(), fields[2].parse().unwrap(), parse_bits(fields[3]))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(header("iterations").parse::<usize>().unwrap(), blocks.len());
+
+            runs.push((
+                header("anchor height").parse().unwrap(),
+                header("anchor parent time").parse().unwrap(),
+                parse_bits(&header("anchor nBits")),
+                blocks,
+            ));
+        }
+        runs
+    }
+
+    #[test]
+    fn test_aserti3_2d_reference_vectors() {
+        let runs = parse_aserti3_2d_vectors(include_str!("./resources/aserti3-2d.txt"));
+        assert_eq!(7, runs.len());
+
+        for (anchor_height, anchor_parent_time, anchor_bits, blocks) in runs {
+            for (height, time, expected_bits) in blocks {
+                let bits = aserti3_2d_next_bits(anchor_height, anchor_parent_time, anchor_bits, height, time);
+                assert_eq!(expected_bits, bits, "Mismatched target at height {}", height);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        for bits in [0x1d00ffff, 0x1802aee8, 0x1a2b3c4d, 0x03010000, 0x0300ff63, 0x01010000] {
+            assert_eq!(bits, target_to_compact(compact_to_target(bits)));
+        }
+    }
+
+    #[test]
+    fn test_simulate_constant_hashrate() {
+        let mut config = SimulationConfig::new::<Testnet2>(2_000);
+        config.seed = 1;
+
+        let report = config.simulate::<Testnet2>().unwrap();
+        assert_eq!(2_000, report.blocks.len());
+
+        // The block times should be near the target block time.
+        let target_block_time = Testnet2::ALEO_BLOCK_TIME_IN_SECS as f64;
+        assert!((report.statistics.mean - target_block_time).abs() < 0.1 * target_block_time);
+
+        // The exported vectors should match the retarget algorithm, including after a JSON round trip.
+        let vectors = report.to_retarget_vectors();
+        let vectors: RetargetVectors = serde_json::from_str(&serde_json::to_string(&vectors).unwrap()).unwrap();
+        vectors.verify::<Testnet2>().unwrap();
+    }
+
+    #[test]
+    fn test_simulate_step_hashrate() {
+        let mut config = SimulationConfig::new::<Testnet2>(25_000);
+        let hashrate = config.hashrate.hashrate(0);
+        config.hashrate = HashrateSchedule::Step { before: hashrate, after: 4.0 * hashrate, height: 1_000 };
+
+        // After the hashrate quadruples, the difficulty target should settle at a quarter of the anchor target.
+        let report = config.simulate::<Testnet2>().unwrap();
+        let settled = &report.blocks[20_000..];
+        let mean_target =
+            settled.iter().map(|block| block.difficulty_target as f64).sum::<f64>() / settled.len() as f64;
+        let expected_target = config.anchor_difficulty_target as f64 / 4.0;
+        assert!((mean_target - expected_target).abs() < 0.15 * expected_target);
+    }
+
+    #[test]
+    fn test_simulate_timestamp_manipulation() {
+        let mut config = SimulationConfig::new::<Testnet2>(1_000);
+        config.timestamps = TimestampStrategy::Manipulated { share: 1.0, offset: 3_600 };
+
+        // Timestamps are capped by the future time limit, and strictly increasing.
+        let report = config.simulate::<Testnet2>().unwrap();
+        let mut previous_timestamp = 0;
+        for block in &report.blocks {
+            assert!(block.timestamp > previous_timestamp);
+            previous_timestamp = block.timestamp;
+        }
+        report.to_retarget_vectors().verify::<Testnet2>().unwrap();
+    }
+}
@@ -18,23 +18,61 @@ use crate::prelude::*;
 
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, Rng};
-use std::{collections::HashMap, sync::atomic::AtomicBool};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::AtomicBool,
+        mpsc::{channel, Receiver, Sender},
+        Arc,
+    },
+};
 use time::OffsetDateTime;
 
-#[derive(Clone, Debug)]
+/// The maximum number of orphan blocks held by the ledger.
+const MAXIMUM_ORPHAN_BLOCKS: usize = 1024;
+/// The maximum number of blocks by which an orphan block may be ahead of the canon chain.
+const MAXIMUM_ORPHAN_BLOCK_HEIGHT_AHEAD: u32 = 256;
+
+/// The ledger, which owns the canon chain and its storage, and is therefore not `Clone`.
+#[derive(Debug)]
 pub struct Ledger<N: Network> {
     /// The canonical chain of blocks.
     canon_blocks: Blocks<N>,
-    /// The set of unknown orphan blocks.
-    orphan_blocks: HashMap<u32, Block<N>>,
+    /// The tree of blocks that are not on the canon chain, keyed by block hash.
+    orphan_blocks: HashMap<N::BlockHash, Block<N>>,
     /// The pool of unconfirmed transactions.
     memory_pool: MemoryPool<N>,
+    /// The subscribers to ledger events.
+    subscribers: Vec<Sender<LedgerEvent<N>>>,
 }
 
 impl<N: Network> Ledger<N> {
-    /// Initializes a new instance of the ledger.
+    /// Initializes a new instance of the ledger, in memory.
     pub fn new() -> Result<Self> {
-        Ok(Self { canon_blocks: Blocks::new()?, orphan_blocks: Default::default(), memory_pool: MemoryPool::new() })
+        Self::from_storage(Arc::new(MemoryStorage::new()))
+    }
+
+    /// Opens the ledger in the given directory on disk, restoring any existing canon chain.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        Self::from_storage(Arc::new(DiskStorage::open(directory)?))
+    }
+
+    /// Opens the ledger from the given storage, restoring any existing canon chain.
+    pub fn from_storage(storage: Arc<dyn Storage>) -> Result<Self> {
+        Ok(Self {
+            canon_blocks: Blocks::open(storage)?,
+            orphan_blocks: Default::default(),
+            memory_pool: MemoryPool::new(),
+            subscribers: Default::default(),
+        })
+    }
+
+    /// Returns a receiver of the events emitted by the ledger from now on.
+    pub fn subscribe(&mut self) -> Receiver<LedgerEvent<N>> {
+        let (sender, receiver) = channel();
+        self.subscribers.push(sender);
+        receiver
     }
 
     /// Returns the latest block height.
@@ -92,26 +130,68 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Adds the given block, either to the tip of the canon chain, or as an orphan block.
+    pub fn add_block(&mut self, block: &Block<N>) -> Result<()> {
+        match block.previous_block_hash() == self.latest_block_hash() {
+            true => self.add_next_block(block),
+            false => self.add_orphan_block(block),
+        }
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Attempt to insert the block into canon.
         self.canon_blocks.add_next(block)?;
 
-        Ok(())
+        // On success, clear the memory pool of the block transactions.
+        self.memory_pool.remove_transactions(block.transactions());
+        self.orphan_blocks.remove(&block.hash());
+        self.emit(LedgerEvent::BlockAdded(block.height(), block.hash()));
+
+        // Switch to a heavier fork, if the orphan blocks now extend the canon chain.
+        self.update_canon_chain()
     }
 
-    /// Adds the given orphan block, if it is well-formed and does not already exist.
+    ///
+    /// Adds the given orphan block, if it does not already exist, and switches the canon chain
+    /// to the heaviest chain of known blocks.
+    ///
+    /// Note: Orphan blocks are only validated once they are added to the canon chain.
+    ///
     pub fn add_orphan_block(&mut self, block: &Block<N>) -> Result<()> {
         // Ensure the block does not exist in canon.
-        if self.canon_blocks.contains_block_hash(&block.hash()) {
+        let block_hash = block.hash();
+        if self.canon_blocks.contains_block_hash(&block_hash) {
             return Err(anyhow!("Orphan block already exists in canon chain"));
         }
 
+        // Ensure the block does not exist in the orphan blocks.
+        if self.orphan_blocks.contains_key(&block_hash) {
+            return Err(anyhow!("Orphan block already exists in the orphan blocks"));
+        }
+
+        // Ensure the block is not too deep to become canon, nor too far ahead of the canon chain.
+        let latest_block_height = self.latest_block_height();
+        if block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) <= latest_block_height {
+            return Err(anyhow!("Orphan block is too deep to become canon"));
+        }
+        if block.height() > latest_block_height.saturating_add(MAXIMUM_ORPHAN_BLOCK_HEIGHT_AHEAD) {
+            return Err(anyhow!("Orphan block is too far ahead of the canon chain"));
+        }
+
+        // Ensure the block would not be the first to be evicted from the full orphan blocks.
+        if self.orphan_blocks.len() >= MAXIMUM_ORPHAN_BLOCKS
+            && self.orphan_blocks.values().all(|orphan_block| orphan_block.height() <= block.height())
+        {
+            return Err(anyhow!("The orphan blocks are full"));
+        }
+
         // Insert the block into the orphan blocks.
-        self.orphan_blocks.insert(block.height(), block.clone());
+        self.orphan_blocks.insert(block_hash, block.clone());
 
-        Ok(())
+        // Switch to a heavier fork, if the block completes one.
+        self.update_canon_chain()
     }
 
     /// Adds the given unconfirmed transaction to the memory pool.
@@ -208,6 +288,162 @@ impl<N: Network> Ledger<N> {
     pub fn to_ledger_proof(&self, commitment: N::Commitment) -> Result<LedgerProof<N>> {
         self.canon_blocks.to_ledger_proof(commitment)
     }
+
+    ///
+    /// Switches the canon chain to the heaviest fork of orphan blocks, as long as it has
+    /// a higher cumulative weight than the canon chain. Forks that fail validation are discarded.
+    ///
+    fn update_canon_chain(&mut self) -> Result<()> {
+        loop {
+            // Find the heaviest fork that connects to the canon chain, starting from the heaviest orphan blocks.
+            let latest_cumulative_weight = self.latest_cumulative_weight()?;
+            let mut candidates = self
+                .orphan_blocks
+                .values()
+                .filter(|block| block.cumulative_weight() > latest_cumulative_weight)
+                .map(|block| (block.cumulative_weight(), block.hash()))
+                .collect::<Vec<_>>();
+            candidates.sort_unstable_by(|(weight_a, _), (weight_b, _)| weight_b.cmp(weight_a));
+
+            match candidates.iter().find_map(|(_, block_hash)| self.get_fork(block_hash)) {
+                Some(fork) => self.reorganize(&fork)?,
+                None => break,
+            }
+        }
+
+        self.prune_orphan_blocks();
+        Ok(())
+    }
+
+    ///
+    /// Returns the hashes of the orphan blocks from the canon chain to the given orphan block, in order of
+    /// block height, if they connect to the canon chain within the maximum fork depth.
+    ///
+    fn get_fork(&self, block_hash: &N::BlockHash) -> Option<Vec<N::BlockHash>> {
+        let mut fork = vec![*block_hash];
+        let mut earliest = self.orphan_blocks.get(block_hash)?;
+        loop {
+            let previous_block_hash = earliest.previous_block_hash();
+            let previous_block_height = earliest.height().checked_sub(1)?;
+
+            // Ensure the fork connects to the canon chain at the expected height.
+            if self.canon_blocks.contains_block_hash(&previous_block_hash) {
+                if self.canon_blocks.get_block_hash(previous_block_height).ok()? != previous_block_hash
+                    || self.latest_block_height() - previous_block_height > N::ALEO_MAXIMUM_FORK_DEPTH
+                {
+                    return None;
+                }
+                fork.reverse();
+                return Some(fork);
+            }
+
+            match self.orphan_blocks.get(&previous_block_hash) {
+                Some(previous_block) if previous_block.height() == previous_block_height => {
+                    fork.push(previous_block_hash);
+                    earliest = previous_block;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    ///
+    /// Replaces the canon chain above the parent of the first block in the given fork of orphan blocks with the fork.
+    /// If a block of the fork is invalid, it is discarded with all of its descendants among the orphan blocks,
+    /// and the canon chain is left unchanged.
+    ///
+    /// The rollback and the fork are written to storage as one batch, so a crash during a reorganization
+    /// leaves the stored chain either as it was before, or switched to the fork in full.
+    ///
+    fn reorganize(&mut self, fork: &[N::BlockHash]) -> Result<()> {
+        // Take the blocks of the fork out of the orphan blocks.
+        let mut fork = fork.iter().filter_map(|block_hash| self.orphan_blocks.remove(block_hash)).collect::<Vec<_>>();
+        let fork_height = fork[0].height() - 1;
+
+        let removed = match self.canon_blocks.replace_above(fork_height, &fork) {
+            Ok(Ok(removed)) => removed,
+            // If the fork is invalid, discard the invalid block and its descendants.
+            Ok(Err(index)) => {
+                let invalid = fork.split_off(index);
+                self.orphan_blocks.extend(fork.into_iter().map(|block| (block.hash(), block)));
+
+                let mut discarded = invalid.iter().map(Block::hash).collect::<Vec<_>>();
+                while let Some(block_hash) = discarded.pop() {
+                    self.orphan_blocks.remove(&block_hash);
+                    discarded.extend(
+                        self.orphan_blocks
+                            .values()
+                            .filter(|block| block.previous_block_hash() == block_hash)
+                            .map(Block::hash),
+                    );
+                }
+                return Ok(());
+            }
+            Err(error) => {
+                self.orphan_blocks.extend(fork.into_iter().map(|block| (block.hash(), block)));
+                return Err(error);
+            }
+        };
+        let added = fork.iter().map(Block::hash).collect::<Vec<_>>();
+
+        // Return the transactions of the rolled back blocks to the memory pool,
+        // and drop the unconfirmed transactions that are no longer valid on the new canon chain.
+        let transactions = removed
+            .iter()
+            .flat_map(|block| block.transactions().iter().cloned())
+            .chain(self.memory_pool.transactions())
+            .collect::<Vec<_>>();
+        self.memory_pool.clear_all_transactions();
+        for transaction in &transactions {
+            if !self.canon_blocks.contains_transaction(transaction) {
+                // Note: Coinbase transactions and conflicting transactions are expected to be rejected.
+                let _ = self.add_unconfirmed_transaction(transaction);
+            }
+        }
+
+        // If no blocks were rolled back, the fork only extends the canon chain.
+        match removed.is_empty() {
+            true => fork.iter().for_each(|block| self.emit(LedgerEvent::BlockAdded(block.height(), block.hash()))),
+            false => self.emit(LedgerEvent::Reorganized {
+                fork_height,
+                removed: removed.iter().map(Block::hash).collect(),
+                added,
+            }),
+        }
+
+        // The rolled back blocks remain known, in case their fork becomes the heaviest again.
+        self.orphan_blocks.extend(removed.into_iter().map(|block| (block.hash(), block)));
+
+        Ok(())
+    }
+
+    ///
+    /// Discards the orphan blocks that are too deep to ever become canon, or that are already canon.
+    /// Beyond the maximum number of orphan blocks, the orphan blocks at the greatest heights are discarded,
+    /// as they are the furthest from connecting to the canon chain.
+    ///
+    fn prune_orphan_blocks(&mut self) {
+        let latest_block_height = self.latest_block_height();
+        let canon_blocks = &self.canon_blocks;
+        self.orphan_blocks.retain(|block_hash, block| {
+            block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) > latest_block_height
+                && !canon_blocks.contains_block_hash(block_hash)
+        });
+
+        if self.orphan_blocks.len() > MAXIMUM_ORPHAN_BLOCKS {
+            let mut orphan_blocks =
+                self.orphan_blocks.iter().map(|(block_hash, block)| (block.height(), *block_hash)).collect::<Vec<_>>();
+            orphan_blocks.sort_unstable_by_key(|(height, _)| *height);
+            for (_, block_hash) in orphan_blocks.drain(MAXIMUM_ORPHAN_BLOCKS..) {
+                self.orphan_blocks.remove(&block_hash);
+            }
+        }
+    }
+
+    /// Sends the given event to all subscribers, and drops the subscribers that have disconnected.
+    fn emit(&mut self, event: LedgerEvent<N>) {
+        self.subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -226,6 +462,171 @@ mod tests {
         assert_eq!(0, ledger.latest_block_height());
     }
 
+    #[test]
+    fn test_open() {
+        let rng = &mut thread_rng();
+        let directory = std::env::temp_dir().join(format!("ledger_{}", rand::random::<u64>()));
+
+        let (block_hash, ledger_root) = {
+            let mut ledger = Ledger::<Testnet2>::open(&directory).unwrap();
+            let recipient = Account::<Testnet2>::new(rng);
+
+            assert_eq!(0, ledger.latest_block_height());
+            ledger.mine_next_block(recipient.address(), true, &AtomicBool::new(false), rng).unwrap();
+            assert_eq!(1, ledger.latest_block_height());
+
+            (ledger.latest_block_hash(), ledger.latest_ledger_root())
+        };
+
+        // Ensure the ledger is restored from disk.
+        let ledger = Ledger::<Testnet2>::open(&directory).unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block_hash, ledger.latest_block_hash());
+        assert_eq!(ledger_root, ledger.latest_ledger_root());
+        assert_eq!(block_hash, ledger.latest_block().unwrap().hash());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_reorganize() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        // Mine a canon chain of one block, and a heavier fork of two blocks.
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        ledger.mine_next_block(Account::<Testnet2>::new(rng).address(), true, &terminator, rng).unwrap();
+        let removed_block = ledger.latest_block().unwrap();
+
+        let mut fork = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let fork_blocks = [fork.canon_blocks.get_block(1).unwrap(), fork.latest_block().unwrap()];
+
+        // Add the fork in reverse order, so the first block is an orphan until its parent arrives.
+        let events = ledger.subscribe();
+        ledger.add_block(&fork_blocks[1]).unwrap();
+        assert_eq!(removed_block.hash(), ledger.latest_block_hash());
+        ledger.add_block(&fork_blocks[0]).unwrap();
+
+        assert_eq!(2, ledger.latest_block_height());
+        assert_eq!(fork.latest_block_hash(), ledger.latest_block_hash());
+        assert_eq!(fork.latest_ledger_root(), ledger.latest_ledger_root());
+        assert!(ledger.orphan_blocks.contains_key(&removed_block.hash()));
+        assert!(!ledger.contains_transaction(&removed_block.to_coinbase_transaction().unwrap()));
+        assert_eq!(
+            LedgerEvent::Reorganized {
+                fork_height: 0,
+                removed: vec![removed_block.hash()],
+                added: fork_blocks.iter().map(Block::hash).collect()
+            },
+            events.try_recv().unwrap()
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_reorganize_invalid_fork() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let directory = std::env::temp_dir().join(format!("ledger_{}", rand::random::<u64>()));
+
+        // Mine a canon chain of one block, and a fork whose first block is valid.
+        let mut ledger = Ledger::<Testnet2>::open(&directory).unwrap();
+        ledger.mine_next_block(Account::<Testnet2>::new(rng).address(), true, &terminator, rng).unwrap();
+
+        let mut fork = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        fork.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let valid_block = fork.latest_block().unwrap();
+
+        // Mine a second fork block which claims more cumulative weight than it adds, so it fails `add_next`.
+        let block_height = 2;
+        let block_timestamp = valid_block.timestamp().saturating_add(1);
+        let difficulty_target = Blocks::<Testnet2>::compute_difficulty_target(
+            Testnet2::genesis_block().header(),
+            block_timestamp,
+            block_height,
+        );
+        let (coinbase_transaction, coinbase_record) = Transaction::<Testnet2>::new_coinbase(
+            recipient.address(),
+            Block::<Testnet2>::block_reward(block_height),
+            true,
+            rng,
+        )
+        .unwrap();
+        let template = BlockTemplate::new(
+            valid_block.hash(),
+            block_height,
+            block_timestamp,
+            difficulty_target,
+            u128::MAX,
+            fork.latest_ledger_root(),
+            Transactions::from(&[coinbase_transaction]).unwrap(),
+            coinbase_record,
+        );
+        let invalid_block = Block::mine(&template, &terminator, rng).unwrap();
+
+        let block_hash = ledger.latest_block_hash();
+        let ledger_root = ledger.latest_ledger_root();
+
+        // Add the fork in reverse order, so the heaviest fork is only complete once its first block arrives.
+        let events = ledger.subscribe();
+        ledger.add_block(&invalid_block).unwrap();
+        ledger.add_block(&valid_block).unwrap();
+
+        // Ensure the canon chain is unchanged, and only the invalid block is discarded.
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block_hash, ledger.latest_block_hash());
+        assert_eq!(ledger_root, ledger.latest_ledger_root());
+        assert_eq!(vec![&valid_block.hash()], ledger.orphan_blocks.keys().collect::<Vec<_>>());
+        assert!(events.try_recv().is_err());
+
+        // Ensure the canon chain on disk is unchanged.
+        drop(ledger);
+        let ledger = Ledger::<Testnet2>::open(&directory).unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block_hash, ledger.latest_block_hash());
+        assert_eq!(ledger_root, ledger.latest_ledger_root());
+
+        drop(ledger);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_remove_above() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let (block_hash, ledger_root) = (ledger.latest_block_hash(), ledger.latest_ledger_root());
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng).unwrap();
+        let (latest_block_hash, latest_ledger_root) = (ledger.latest_block_hash(), ledger.latest_ledger_root());
+
+        // Remove the two latest blocks at once.
+        let removed = ledger.canon_blocks.remove_above(1).unwrap();
+        assert_eq!(vec![2, 3], removed.iter().map(Block::height).collect::<Vec<_>>());
+        assert_eq!(latest_block_hash, removed[1].hash());
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(block_hash, ledger.latest_block_hash());
+        assert_eq!(ledger_root, ledger.latest_ledger_root());
+
+        // Ensure heights at or above the current height remove nothing.
+        assert!(ledger.canon_blocks.remove_above(1).unwrap().is_empty());
+        assert!(ledger.canon_blocks.remove_above(2).is_err());
+
+        // Ensure the removed blocks can be added back.
+        for block in &removed {
+            ledger.canon_blocks.add_next(block).unwrap();
+        }
+        assert_eq!(latest_block_hash, ledger.latest_block_hash());
+        assert_eq!(latest_ledger_root, ledger.latest_ledger_root());
+    }
+
     #[test]
     fn test_mine_next_block() {
         let rng = &mut thread_rng();
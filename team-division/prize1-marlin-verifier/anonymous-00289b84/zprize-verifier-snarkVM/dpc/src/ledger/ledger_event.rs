@@ -0,0 +1,27 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+
+/// An event emitted by the ledger when its canon chain changes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LedgerEvent<N: Network> {
+    /// Emits the height and block hash of a block added to the tip of the canon chain.
+    BlockAdded(u32, N::BlockHash),
+    /// Emits a switch of the canon chain to a heavier fork, where the blocks after the fork height
+    /// were rolled back, and the blocks of the fork were added, both in order of block height.
+    Reorganized { fork_height: u32, removed: Vec<N::BlockHash>, added: Vec<N::BlockHash> },
+}
@@ -17,9 +17,15 @@
 pub mod blocks;
 pub use blocks::*;
 
+pub mod difficulty_simulator;
+pub use difficulty_simulator::*;
+
 pub mod ledger;
 pub use ledger::*;
 
+pub mod ledger_event;
+pub use ledger_event::*;
+
 pub mod ledger_proof;
 pub use ledger_proof::*;
 
@@ -35,5 +41,8 @@ pub use memory_pool::*;
 pub(crate) mod record_proof;
 pub(crate) use record_proof::*;
 
+pub mod storage;
+pub use storage::*;
+
 pub(crate) mod transitions;
 pub(crate) use transitions::*;
@@ -0,0 +1,558 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use anyhow::{anyhow, bail, Result};
+use blake2::{Blake2s256, Digest};
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// The name of the log file in the storage directory.
+const LOG_FILE_NAME: &str = "ledger.log";
+/// The name of the lock file in the storage directory, which is locked exclusively while the storage is open.
+const LOCK_FILE_NAME: &str = "ledger.lock";
+/// The name of the compacted log file in the storage directory, while it is being written.
+const COMPACTION_FILE_NAME: &str = "ledger.log.compact";
+/// The minimum length of the log, in bytes, before it is compacted on open.
+const COMPACTION_MIN_LENGTH: u64 = 64 * 1024 * 1024;
+/// The log is compacted on open once it is this many times longer than its live entries.
+const COMPACTION_RATIO: u64 = 2;
+/// The maximum payload length of a record written by compaction.
+const COMPACTION_RECORD_LENGTH: usize = 16 * 1024 * 1024;
+/// The size of a record header, consisting of the payload length and the payload checksum.
+const RECORD_HEADER_SIZE: usize = 4 + 32;
+
+/// The tag of an insertion in a record payload.
+const OP_INSERT: u8 = 0;
+/// The tag of a removal in a record payload.
+const OP_REMOVE: u8 = 1;
+
+/// The operations of a record, with the location of each inserted value relative to the start of the record.
+type RecordOperations = Vec<(DataMap, Vec<u8>, Option<(usize, u32)>)>;
+
+///
+/// A storage that persists data in an append-only log on disk.
+///
+/// Each batch is written as one record, consisting of the payload length, the BLAKE2s checksum
+/// of the payload, and the payload itself, and is synced to disk before the write returns.
+/// On open, the log is replayed one record at a time to index the location of every value.
+/// A trailing record that is incomplete or corrupted (i.e. from a crash during a write) is truncated,
+/// so the storage always recovers to the state after the last fully written batch.
+/// A corrupted record that is followed by further records cannot come from a crash, and fails the open.
+///
+/// Overwritten and removed values remain in the log until it is compacted, which rewrites the log
+/// with only the live entries. This happens on open once the log is large and mostly stale,
+/// or on demand with [`DiskStorage::compact`].
+///
+/// The storage holds an exclusive lock on its directory until it is dropped, so the log
+/// is never written by two instances of the storage, in this or in another process.
+///
+#[derive(Debug)]
+pub struct DiskStorage {
+    /// The path of the log file.
+    path: PathBuf,
+    /// The lock file, which holds the exclusive lock on the directory.
+    _lock: File,
+    /// The log file and its index, locked together so writes are serialized.
+    inner: Mutex<DiskStorageInner>,
+}
+
+#[derive(Debug)]
+struct DiskStorageInner {
+    /// The log file.
+    file: File,
+    /// The offset and length of each value in the log file.
+    index: HashMap<(DataMap, Vec<u8>), (u64, u32)>,
+    /// The length of the valid prefix of the log file.
+    length: u64,
+    /// The encoded length of the live entries in the log file.
+    live_length: u64,
+}
+
+impl DiskStorage {
+    /// Opens the storage in the given directory, creating it if it does not exist,
+    /// and discards any batch that was only partially written.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+        let lock = Self::lock(directory)?;
+        let path = directory.join(LOG_FILE_NAME);
+
+        let file = OpenOptions::new().read(true).write(true).create(true).open(&path)?;
+        let file_length = file.metadata()?.len();
+        let mut reader = BufReader::new(file.try_clone()?);
+        let mut inner = DiskStorageInner { file, index: HashMap::new(), length: 0, live_length: 0 };
+
+        // Replay the log, one record at a time.
+        while inner.length < file_length {
+            let remaining = file_length - inner.length;
+
+            // A record that extends past the end of the log belongs to a batch that was not fully written.
+            let mut record = vec![0u8; RECORD_HEADER_SIZE];
+            if remaining < RECORD_HEADER_SIZE as u64 {
+                break;
+            }
+            reader.read_exact(&mut record)?;
+            let payload_length = u32::from_le_bytes(record[..4].try_into()?);
+            let record_length = RECORD_HEADER_SIZE as u64 + payload_length as u64;
+            if record_length > remaining {
+                break;
+            }
+            record.resize(record_length as usize, 0);
+            reader.read_exact(&mut record[RECORD_HEADER_SIZE..])?;
+
+            match Self::read_record(&record) {
+                Some((_, operations)) => {
+                    let start = inner.length;
+                    inner.apply(start, operations);
+                    inner.length += record_length;
+                }
+                // Only the last record can be corrupted by a crash during a write.
+                None if record_length == remaining => break,
+                None => bail!(
+                    "The ledger log is corrupted at offset {}, with {} bytes of records after it",
+                    inner.length,
+                    remaining - record_length
+                ),
+            }
+        }
+
+        // Truncate the remainder of the log, as it belongs to a batch that was not fully written.
+        if inner.length < file_length {
+            inner.file.set_len(inner.length)?;
+            inner.file.sync_all()?;
+        }
+
+        // Compact the log if it consists mostly of overwritten and removed values.
+        if inner.length >= COMPACTION_MIN_LENGTH && inner.length > COMPACTION_RATIO * inner.live_length {
+            inner.compact(&path)?;
+        }
+
+        Ok(Self { path, _lock: lock, inner: Mutex::new(inner) })
+    }
+
+    /// Opens the lock file in the given directory, and locks it exclusively.
+    /// The lock is released when the returned file is closed, including when the process exits.
+    fn lock(directory: &Path) -> Result<File> {
+        let lock = OpenOptions::new().read(true).write(true).create(true).open(directory.join(LOCK_FILE_NAME))?;
+        #[cfg(not(target_family = "wasm"))]
+        if let Err(error) = fs2::FileExt::try_lock_exclusive(&lock) {
+            bail!("The ledger storage in {} is already open: {}", directory.display(), error);
+        }
+        Ok(lock)
+    }
+
+    /// Rewrites the log with only the live entries, and atomically replaces the current log with it.
+    pub fn compact(&self) -> Result<()> {
+        let mut inner = self.inner.lock().map_err(|_| anyhow!("The disk storage lock is poisoned"))?;
+        inner.compact(&self.path)
+    }
+
+    /// Returns the path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encodes the given batch as a log record.
+    fn to_record(batch: &StorageBatch) -> Result<Vec<u8>> {
+        let mut payload = Vec::new();
+        for operation in batch.operations() {
+            match operation {
+                StorageOp::Insert(map, key, value) => {
+                    payload.extend_from_slice(&[*map as u8, OP_INSERT]);
+                    Self::write_bytes(&mut payload, key)?;
+                    Self::write_bytes(&mut payload, value)?;
+                }
+                StorageOp::Remove(map, key) => {
+                    payload.extend_from_slice(&[*map as u8, OP_REMOVE]);
+                    Self::write_bytes(&mut payload, key)?;
+                }
+            }
+        }
+
+        let payload_length =
+            u32::try_from(payload.len()).map_err(|_| anyhow!("The storage batch exceeds the maximum record size"))?;
+
+        let mut record = Vec::with_capacity(RECORD_HEADER_SIZE + payload.len());
+        record.extend_from_slice(&payload_length.to_le_bytes());
+        record.extend_from_slice(&Blake2s256::digest(&payload));
+        record.extend_from_slice(&payload);
+        Ok(record)
+    }
+
+    /// Writes the given bytes to the payload, prefixed with their length.
+    fn write_bytes(payload: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+        let length = u32::try_from(bytes.len()).map_err(|_| anyhow!("The storage entry exceeds the maximum size"))?;
+        payload.extend_from_slice(&length.to_le_bytes());
+        payload.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Returns the length and the operations of the record at the start of the given bytes,
+    /// or `None` if the record is incomplete or corrupted.
+    fn read_record(bytes: &[u8]) -> Option<(usize, RecordOperations)> {
+        let mut cursor = 0;
+        let payload_length = Self::read_length(bytes, &mut cursor)? as usize;
+        let checksum = Self::read_bytes(bytes, &mut cursor, RECORD_HEADER_SIZE - 4)?;
+        let record_length = RECORD_HEADER_SIZE.checked_add(payload_length)?;
+
+        // Ensure the payload is complete and matches its checksum.
+        let payload = bytes.get(..record_length)?;
+        if Blake2s256::digest(&payload[RECORD_HEADER_SIZE..]).as_slice() != checksum {
+            return None;
+        }
+
+        let mut operations = Vec::new();
+        while cursor < record_length {
+            let map = DataMap::try_from(Self::read_bytes(payload, &mut cursor, 1)?[0]).ok()?;
+            let tag = Self::read_bytes(payload, &mut cursor, 1)?[0];
+            let key_length = Self::read_length(payload, &mut cursor)?;
+            let key = Self::read_bytes(payload, &mut cursor, key_length as usize)?.to_vec();
+
+            match tag {
+                OP_INSERT => {
+                    let value_length = Self::read_length(payload, &mut cursor)?;
+                    let offset = cursor;
+                    Self::read_bytes(payload, &mut cursor, value_length as usize)?;
+                    operations.push((map, key, Some((offset, value_length))));
+                }
+                OP_REMOVE => operations.push((map, key, None)),
+                _ => return None,
+            }
+        }
+
+        Some((record_length, operations))
+    }
+
+    /// Reads a little-endian length from the given bytes, advancing the cursor.
+    fn read_length(bytes: &[u8], cursor: &mut usize) -> Option<u32> {
+        Some(u32::from_le_bytes(Self::read_bytes(bytes, cursor, 4)?.try_into().ok()?))
+    }
+
+    /// Reads the given number of bytes, advancing the cursor.
+    fn read_bytes<'a>(bytes: &'a [u8], cursor: &mut usize, length: usize) -> Option<&'a [u8]> {
+        let result = bytes.get(*cursor..cursor.checked_add(length)?)?;
+        *cursor += length;
+        Some(result)
+    }
+}
+
+impl DiskStorageInner {
+    /// Appends the given record at the end of the valid prefix of the log, and syncs it to disk.
+    fn append(&mut self, record: &[u8]) -> Result<()> {
+        self.file.seek(SeekFrom::Start(self.length))?;
+        self.file.write_all(record)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    /// Updates the index with the operations of the record at the given offset of the log.
+    fn apply(&mut self, start: u64, operations: RecordOperations) {
+        for (map, key, location) in operations {
+            let entry_length = 2 + 4 + key.len() as u64 + 4;
+            let previous = match location {
+                Some((offset, value_length)) => {
+                    self.live_length += entry_length + value_length as u64;
+                    self.index.insert((map, key), (start + offset as u64, value_length))
+                }
+                None => self.index.remove(&(map, key)),
+            };
+            if let Some((_, value_length)) = previous {
+                self.live_length -= entry_length + value_length as u64;
+            }
+        }
+    }
+
+    /// Writes the live entries to a new log at the given path, in records of a bounded length,
+    /// and renames it over the current log once it is durable.
+    fn compact(&mut self, path: &Path) -> Result<()> {
+        let compaction_path = path.with_file_name(COMPACTION_FILE_NAME);
+        let compacted = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&compaction_path)?;
+
+        let mut entries: Vec<_> = self.index.iter().map(|(key, location)| (key.clone(), *location)).collect();
+        entries.sort_unstable();
+
+        let mut index = HashMap::with_capacity(entries.len());
+        let mut length = 0u64;
+        let mut writer = BufWriter::new(&compacted);
+        let mut write_record = |batch: &StorageBatch| -> Result<()> {
+            let record = DiskStorage::to_record(batch)?;
+            let (record_length, operations) =
+                DiskStorage::read_record(&record).ok_or_else(|| anyhow!("Failed to encode the storage batch"))?;
+            writer.write_all(&record)?;
+            for (map, key, location) in operations {
+                if let Some((offset, value_length)) = location {
+                    index.insert((map, key), (length + offset as u64, value_length));
+                }
+            }
+            length += record_length as u64;
+            Ok(())
+        };
+
+        let mut batch = StorageBatch::new();
+        let mut batch_length = 0;
+        for ((map, key), (offset, value_length)) in entries {
+            let mut value = vec![0u8; value_length as usize];
+            self.file.seek(SeekFrom::Start(offset))?;
+            self.file.read_exact(&mut value)?;
+
+            batch_length += key.len() + value.len();
+            batch.insert(map, key, value);
+            if batch_length >= COMPACTION_RECORD_LENGTH {
+                write_record(&batch)?;
+                batch = StorageBatch::new();
+                batch_length = 0;
+            }
+        }
+        if !batch.is_empty() {
+            write_record(&batch)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        compacted.sync_all()?;
+
+        // Replace the log, and persist the rename before discarding the old log.
+        fs::rename(&compaction_path, path)?;
+        #[cfg(unix)]
+        if let Some(directory) = path.parent() {
+            File::open(directory)?.sync_all()?;
+        }
+
+        self.file = compacted;
+        self.index = index;
+        self.length = length;
+        Ok(())
+    }
+}
+
+impl Storage for DiskStorage {
+    fn get(&self, map: DataMap, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let mut inner = self.inner.lock().map_err(|_| anyhow!("The disk storage lock is poisoned"))?;
+
+        let (offset, length) = match inner.index.get(&(map, key.to_vec())) {
+            Some(location) => *location,
+            None => return Ok(None),
+        };
+
+        let mut value = vec![0u8; length as usize];
+        inner.file.seek(SeekFrom::Start(offset))?;
+        inner.file.read_exact(&mut value)?;
+        Ok(Some(value))
+    }
+
+    fn write_batch(&self, batch: StorageBatch) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let record = Self::to_record(&batch)?;
+        let (record_length, operations) =
+            Self::read_record(&record).ok_or_else(|| anyhow!("Failed to encode the storage batch"))?;
+
+        let mut inner = self.inner.lock().map_err(|_| anyhow!("The disk storage lock is poisoned"))?;
+
+        // Append the record, and discard any partial write on failure, so later records remain readable.
+        let start = inner.length;
+        if let Err(error) = inner.append(&record) {
+            inner.file.set_len(start)?;
+            return Err(error);
+        }
+
+        // Only update the index once the record is durable.
+        inner.apply(start, operations);
+        inner.length = start + record_length as u64;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("disk_storage_{}", rand::random::<u64>()))
+    }
+
+    fn batch(entries: &[(&[u8], &[u8])]) -> StorageBatch {
+        let mut batch = StorageBatch::new();
+        for (key, value) in entries {
+            batch.insert(DataMap::Metadata, key.to_vec(), value.to_vec());
+        }
+        batch
+    }
+
+    #[test]
+    fn test_reopen() {
+        let directory = temp_dir();
+        {
+            let storage = DiskStorage::open(&directory).unwrap();
+            storage.write_batch(batch(&[(b"a", b"1"), (b"b", b"2")])).unwrap();
+
+            let mut removal = batch(&[(b"c", b"3")]);
+            removal.remove(DataMap::Metadata, b"a".to_vec());
+            storage.write_batch(removal).unwrap();
+
+            assert_eq!(None, storage.get(DataMap::Metadata, b"a").unwrap());
+            assert_eq!(Some(b"2".to_vec()), storage.get(DataMap::Metadata, b"b").unwrap());
+            assert_eq!(None, storage.get(DataMap::Commitments, b"b").unwrap());
+        }
+        {
+            let storage = DiskStorage::open(&directory).unwrap();
+            assert_eq!(None, storage.get(DataMap::Metadata, b"a").unwrap());
+            assert_eq!(Some(b"2".to_vec()), storage.get(DataMap::Metadata, b"b").unwrap());
+            assert_eq!(Some(b"3".to_vec()), storage.get(DataMap::Metadata, b"c").unwrap());
+        }
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_exclusive_lock() {
+        let directory = temp_dir();
+        {
+            let storage = DiskStorage::open(&directory).unwrap();
+            assert!(DiskStorage::open(&directory).is_err());
+
+            // Ensure the storage remains usable after the rejected open.
+            storage.write_batch(batch(&[(b"a", b"1")])).unwrap();
+        }
+        // The lock is released once the storage is dropped.
+        let storage = DiskStorage::open(&directory).unwrap();
+        assert_eq!(Some(b"1".to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_recover_partial_batch() {
+        let directory = temp_dir();
+        let path = {
+            let storage = DiskStorage::open(&directory).unwrap();
+            storage.write_batch(batch(&[(b"a", b"1")])).unwrap();
+            storage.path().to_path_buf()
+        };
+        let valid_length = fs::metadata(&path).unwrap().len();
+
+        // Simulate a crash in the middle of writing a batch.
+        let record = DiskStorage::to_record(&batch(&[(b"b", b"2"), (b"c", b"3")])).unwrap();
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(&record[..record.len() - 3]).unwrap();
+
+        {
+            let storage = DiskStorage::open(&directory).unwrap();
+            assert_eq!(valid_length, fs::metadata(&path).unwrap().len());
+            assert_eq!(Some(b"1".to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+            assert_eq!(None, storage.get(DataMap::Metadata, b"b").unwrap());
+            assert_eq!(None, storage.get(DataMap::Metadata, b"c").unwrap());
+
+            // Ensure the storage remains writable after recovery.
+            storage.write_batch(batch(&[(b"d", b"4")])).unwrap();
+        }
+        {
+            let storage = DiskStorage::open(&directory).unwrap();
+            assert_eq!(Some(b"1".to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+            assert_eq!(Some(b"4".to_vec()), storage.get(DataMap::Metadata, b"d").unwrap());
+        }
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_recover_corrupted_batch() {
+        let directory = temp_dir();
+        let path = {
+            let storage = DiskStorage::open(&directory).unwrap();
+            storage.write_batch(batch(&[(b"a", b"1")])).unwrap();
+            storage.write_batch(batch(&[(b"b", b"2")])).unwrap();
+            storage.path().to_path_buf()
+        };
+
+        // Corrupt the last byte of the last batch.
+        let mut bytes = fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&path, &bytes).unwrap();
+
+        let storage = DiskStorage::open(&directory).unwrap();
+        assert_eq!(Some(b"1".to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+        assert_eq!(None, storage.get(DataMap::Metadata, b"b").unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_reject_corrupted_batch_before_valid_batches() {
+        let directory = temp_dir();
+        let path = {
+            let storage = DiskStorage::open(&directory).unwrap();
+            storage.write_batch(batch(&[(b"a", b"1")])).unwrap();
+            storage.write_batch(batch(&[(b"b", b"2")])).unwrap();
+            storage.path().to_path_buf()
+        };
+
+        // Corrupt the last byte of the first batch, which is followed by a valid batch.
+        let first_record_length = DiskStorage::to_record(&batch(&[(b"a", b"1")])).unwrap().len();
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[first_record_length - 1] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(DiskStorage::open(&directory).is_err());
+        // Ensure the log is left untouched.
+        assert_eq!(bytes, fs::read(&path).unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_compact() {
+        let directory = temp_dir();
+        let path = {
+            let storage = DiskStorage::open(&directory).unwrap();
+            for i in 0..100u32 {
+                storage.write_batch(batch(&[(b"a", &i.to_le_bytes()), (b"b", b"2")])).unwrap();
+            }
+            let mut removal = StorageBatch::new();
+            removal.remove(DataMap::Metadata, b"b".to_vec());
+            storage.write_batch(removal).unwrap();
+            storage.write_batch(batch(&[(b"c", b"3")])).unwrap();
+
+            let length = fs::metadata(storage.path()).unwrap().len();
+            storage.compact().unwrap();
+            assert!(fs::metadata(storage.path()).unwrap().len() < length);
+            assert!(!directory.join(COMPACTION_FILE_NAME).exists());
+
+            assert_eq!(Some(99u32.to_le_bytes().to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+            assert_eq!(None, storage.get(DataMap::Metadata, b"b").unwrap());
+            assert_eq!(Some(b"3".to_vec()), storage.get(DataMap::Metadata, b"c").unwrap());
+
+            // Ensure the storage remains writable after compaction.
+            storage.write_batch(batch(&[(b"d", b"4")])).unwrap();
+            storage.path().to_path_buf()
+        };
+        assert_eq!(
+            fs::metadata(&path).unwrap().len() as usize,
+            DiskStorage::to_record(&batch(&[(b"a", &99u32.to_le_bytes()), (b"c", b"3")])).unwrap().len()
+                + DiskStorage::to_record(&batch(&[(b"d", b"4")])).unwrap().len()
+        );
+
+        let storage = DiskStorage::open(&directory).unwrap();
+        assert_eq!(Some(99u32.to_le_bytes().to_vec()), storage.get(DataMap::Metadata, b"a").unwrap());
+        assert_eq!(None, storage.get(DataMap::Metadata, b"b").unwrap());
+        assert_eq!(Some(b"3".to_vec()), storage.get(DataMap::Metadata, b"c").unwrap());
+        assert_eq!(Some(b"4".to_vec()), storage.get(DataMap::Metadata, b"d").unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
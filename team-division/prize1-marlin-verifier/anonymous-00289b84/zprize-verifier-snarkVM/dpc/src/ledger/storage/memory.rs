@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, sync::RwLock};
+
+/// A storage that keeps all data in memory, and is lost when dropped.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: RwLock<HashMap<(DataMap, Vec<u8>), Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    /// Initializes an empty in-memory storage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, map: DataMap, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let entries = self.entries.read().map_err(|_| anyhow!("The in-memory storage lock is poisoned"))?;
+        Ok(entries.get(&(map, key.to_vec())).cloned())
+    }
+
+    fn write_batch(&self, batch: StorageBatch) -> Result<()> {
+        // The write lock is held for the whole batch, so readers never observe a partial batch.
+        let mut entries = self.entries.write().map_err(|_| anyhow!("The in-memory storage lock is poisoned"))?;
+        for operation in batch.operations {
+            match operation {
+                StorageOp::Insert(map, key, value) => entries.insert((map, key), value),
+                StorageOp::Remove(map, key) => entries.remove(&(map, key)),
+            };
+        }
+        Ok(())
+    }
+}
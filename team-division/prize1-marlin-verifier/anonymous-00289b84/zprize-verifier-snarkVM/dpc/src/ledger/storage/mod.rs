@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod disk;
+pub use disk::*;
+
+pub mod memory;
+pub use memory::*;
+
+use anyhow::{anyhow, Result};
+
+/// The maps of the ledger storage, each holding one kind of ledger data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum DataMap {
+    /// The block hashes, keyed by block height.
+    BlockHashes = 0,
+    /// The previous block hashes, keyed by block height.
+    PreviousBlockHashes = 1,
+    /// The block headers, keyed by block height.
+    BlockHeaders = 2,
+    /// The block transactions, keyed by block height.
+    BlockTransactions = 3,
+    /// The transitions, keyed by transition ID.
+    Transitions = 4,
+    /// The transition IDs, keyed by the serial numbers they spend.
+    SerialNumbers = 5,
+    /// The transition IDs, keyed by the commitments they create.
+    Commitments = 6,
+    /// The leaves of the ledger tree, keyed by their index in the tree.
+    LedgerTree = 7,
+    /// The storage metadata, such as the latest block height.
+    Metadata = 8,
+}
+
+impl TryFrom<u8> for DataMap {
+    type Error = anyhow::Error;
+
+    fn try_from(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(Self::BlockHashes),
+            1 => Ok(Self::PreviousBlockHashes),
+            2 => Ok(Self::BlockHeaders),
+            3 => Ok(Self::BlockTransactions),
+            4 => Ok(Self::Transitions),
+            5 => Ok(Self::SerialNumbers),
+            6 => Ok(Self::Commitments),
+            7 => Ok(Self::LedgerTree),
+            8 => Ok(Self::Metadata),
+            _ => Err(anyhow!("Unknown data map ID {}", id)),
+        }
+    }
+}
+
+/// A single write operation in a storage batch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageOp {
+    /// Inserts the given value at the given key, overwriting any existing value.
+    Insert(DataMap, Vec<u8>, Vec<u8>),
+    /// Removes the given key, if it exists.
+    Remove(DataMap, Vec<u8>),
+}
+
+/// A list of write operations that is applied to the storage atomically.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageBatch {
+    operations: Vec<StorageOp>,
+}
+
+impl StorageBatch {
+    /// Initializes an empty storage batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an insertion of the given key and value into the given map.
+    pub fn insert(&mut self, map: DataMap, key: Vec<u8>, value: Vec<u8>) {
+        self.operations.push(StorageOp::Insert(map, key, value));
+    }
+
+    /// Adds a removal of the given key from the given map.
+    pub fn remove(&mut self, map: DataMap, key: Vec<u8>) {
+        self.operations.push(StorageOp::Remove(map, key));
+    }
+
+    /// Adds the operations of the given batch, to be applied after the operations of this batch.
+    pub fn extend(&mut self, batch: StorageBatch) {
+        self.operations.extend(batch.operations);
+    }
+
+    /// Returns `true` if the batch contains no operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Returns the number of operations in the batch.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns the operations in the batch, in the order they are applied.
+    pub fn operations(&self) -> &[StorageOp] {
+        &self.operations
+    }
+}
+
+/// A key-value store for the ledger, organized in data maps.
+pub trait Storage: Send + Sync {
+    /// Returns the value of the given key in the given map, if it exists.
+    fn get(&self, map: DataMap, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Returns `true` if the given key exists in the given map.
+    fn contains_key(&self, map: DataMap, key: &[u8]) -> Result<bool> {
+        Ok(self.get(map, key)?.is_some())
+    }
+
+    /// Applies all operations of the given batch, such that either all or none of them persist.
+    fn write_batch(&self, batch: StorageBatch) -> Result<()>;
+}
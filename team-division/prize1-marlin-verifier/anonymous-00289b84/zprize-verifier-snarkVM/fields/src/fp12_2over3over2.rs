@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
+use crate::{fp6_3over2::*, Choice, ConditionallySelectable, ConstantTimeEq, Field, Fp2, Fp2Parameters, One, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBits, ToBytes};
 
 use rand::{
@@ -232,6 +232,20 @@ impl<P: Fp12Parameters> One for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> ConditionallySelectable for Fp12<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(Fp6::conditional_select(&a.c0, &b.c0, choice), Fp6::conditional_select(&a.c1, &b.c1, choice))
+    }
+}
+
+impl<P: Fp12Parameters> ConstantTimeEq for Fp12<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
 impl<P: Fp12Parameters> Field for Fp12<P> {
     type BasePrimeField = <Fp6<P::Fp6Params> as Field>::BasePrimeField;
 
@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+use crate::{
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    Field,
+    LegendreSymbol,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     rand::Uniform,
     serialize::{SerializationError, *},
@@ -104,6 +114,20 @@ impl<P: Fp2Parameters> One for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> ConditionallySelectable for Fp2<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(P::Fp::conditional_select(&a.c0, &b.c0, choice), P::Fp::conditional_select(&a.c1, &b.c1, choice))
+    }
+}
+
+impl<P: Fp2Parameters> ConstantTimeEq for Fp2<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
 impl<P: Fp2Parameters> Field for Fp2<P> {
     type BasePrimeField = P::Fp;
 
@@ -0,0 +1,578 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    Field,
+    LegendreSymbol,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    rand::Uniform,
+    serialize::{SerializationError, *},
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    io::{Read, Result as IoResult, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+pub trait Fp3Parameters: 'static + Send + Sync + Serialize + for<'a> Deserialize<'a> {
+    type Fp: PrimeField;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP3_C1: [Self::Fp; 3];
+    const FROBENIUS_COEFF_FP3_C2: [Self::Fp; 3];
+
+    const NONRESIDUE: Self::Fp;
+
+    /// The two-adicity of `MODULUS^3 - 1`.
+    const TWO_ADICITY: u32;
+
+    /// (T - 1) / 2, where `MODULUS^3 - 1 = 2^TWO_ADICITY * T`.
+    const T_MINUS_ONE_DIV_TWO: &'static [u64];
+
+    /// A quadratic nonresidue raised to the power `T`.
+    const QUADRATIC_NONRESIDUE_TO_T: (Self::Fp, Self::Fp, Self::Fp);
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
+        Self::NONRESIDUE * fe
+    }
+}
+
+/// An element of Fp3, represented by c0 + c1 * u + c2 * u^(2).
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Default(bound = "P: Fp3Parameters"),
+    Hash(bound = "P: Fp3Parameters"),
+    Clone(bound = "P: Fp3Parameters"),
+    Copy(bound = "P: Fp3Parameters"),
+    Debug(bound = "P: Fp3Parameters"),
+    PartialEq(bound = "P: Fp3Parameters"),
+    Eq(bound = "P: Fp3Parameters")
+)]
+pub struct Fp3<P: Fp3Parameters> {
+    pub c0: P::Fp,
+    pub c1: P::Fp,
+    pub c2: P::Fp,
+}
+
+impl<P: Fp3Parameters> Fp3<P> {
+    pub fn new(c0: P::Fp, c1: P::Fp, c2: P::Fp) -> Self {
+        Fp3 { c0, c1, c2 }
+    }
+
+    /// Norm of Fp3 over Fp: Norm(a) = a.x^3 + beta * a.y^3 + beta^2 * a.z^3 - 3 * beta * a.x * a.y * a.z
+    pub fn norm(&self) -> P::Fp {
+        let t0 = self.c0.square() * self.c0;
+        let t1 = P::mul_fp_by_nonresidue(&(self.c1.square() * self.c1));
+        let t2 = P::mul_fp_by_nonresidue(&P::mul_fp_by_nonresidue(&(self.c2.square() * self.c2)));
+        let t3 = P::mul_fp_by_nonresidue(&(self.c0 * self.c1 * self.c2));
+        t0 + t1 + t2 - (t3.double() + t3)
+    }
+
+    pub fn mul_by_fp(&mut self, element: &P::Fp) {
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+        self.c2.mul_assign(element);
+    }
+}
+
+impl<P: Fp3Parameters> Zero for Fp3<P> {
+    fn zero() -> Self {
+        Fp3::new(P::Fp::zero(), P::Fp::zero(), P::Fp::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: Fp3Parameters> One for Fp3<P> {
+    fn one() -> Self {
+        Fp3::new(P::Fp::one(), P::Fp::zero(), P::Fp::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: Fp3Parameters> ConditionallySelectable for Fp3<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::Fp::conditional_select(&a.c0, &b.c0, choice),
+            P::Fp::conditional_select(&a.c1, &b.c1, choice),
+            P::Fp::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<P: Fp3Parameters> ConstantTimeEq for Fp3<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+impl<P: Fp3Parameters> Field for Fp3<P> {
+    type BasePrimeField = P::Fp;
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        Self::new(other, P::Fp::zero(), P::Fp::zero())
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::Fp::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut result = *self;
+        result.double_in_place();
+        result
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self.c2.double_in_place();
+    }
+
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let split_at = bytes.len() / 3;
+        if let Some(c0) = P::Fp::from_random_bytes(&bytes[..split_at]) {
+            if let Some(c1) = P::Fp::from_random_bytes(&bytes[split_at..2 * split_at]) {
+                if let Some((c2, flags)) = P::Fp::from_random_bytes_with_flags::<F>(&bytes[2 * split_at..]) {
+                    return Some((Fp3::new(c0, c1, c2), flags));
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly Fields.pdf; Section 4 (CH-SQR2)
+        let s0 = self.c0.square();
+        let s1 = (self.c0 * self.c1).double();
+        let s2 = (self.c0 - self.c1 + self.c2).square();
+        let s3 = (self.c1 * self.c2).double();
+        let s4 = self.c2.square();
+
+        self.c0 = s0 + P::mul_fp_by_nonresidue(&s3);
+        self.c1 = s1 + P::mul_fp_by_nonresidue(&s4);
+        self.c2 = s1 + s2 + s3 - s0 - s4;
+
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guide to Pairing-based Cryptography, Algorithm 5.23.
+            let t0 = self.c0.square();
+            let t1 = self.c1.square();
+            let t2 = self.c2.square();
+            let t3 = self.c0 * self.c1;
+            let t4 = self.c0 * self.c2;
+            let t5 = self.c1 * self.c2;
+
+            let s0 = t0 - P::mul_fp_by_nonresidue(&t5);
+            let s1 = P::mul_fp_by_nonresidue(&t2) - t3;
+            let s2 = t1 - t4;
+
+            let a = self.c0 * s0 + P::mul_fp_by_nonresidue(&(self.c2 * s1 + self.c1 * s2));
+            a.inverse().map(|t6| Self::new(t6 * s0, t6 * s1, t6 * s2))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c1.mul_assign(&P::FROBENIUS_COEFF_FP3_C1[power % 3]);
+        self.c2.mul_assign(&P::FROBENIUS_COEFF_FP3_C2[power % 3]);
+    }
+}
+
+impl<P: Fp3Parameters> SquareRootField for Fp3<P>
+where
+    P::Fp: SquareRootField,
+{
+    fn legendre(&self) -> LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        // This is Tonelli-Shanks, where `z` is a quadratic nonresidue raised to the power `T`.
+        let (c0, c1, c2) = P::QUADRATIC_NONRESIDUE_TO_T;
+        let mut z = Self::new(c0, c1, c2);
+        let mut w = self.pow(P::T_MINUS_ONE_DIV_TWO);
+        let mut x = w * self;
+        let mut b = x * w;
+
+        let mut v = P::TWO_ADICITY as usize;
+
+        while !b.is_one() {
+            let mut k = 0usize;
+
+            let mut b2k = b;
+            while !b2k.is_one() {
+                // invariant: b2k = b^(2^k) after entering this loop
+                b2k.square_in_place();
+                k += 1;
+
+                // `self` is a quadratic nonresidue.
+                if k == v {
+                    return None;
+                }
+            }
+
+            let j = v - k - 1;
+            w = z;
+            for _ in 0..j {
+                w.square_in_place();
+            }
+
+            z = w.square();
+            b *= &z;
+            x *= &w;
+            v = k;
+        }
+
+        Some(x)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+/// `Fp3` elements are ordered lexicographically.
+impl<P: Fp3Parameters> Ord for Fp3<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.c2.cmp(&other.c2) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => match self.c1.cmp(&other.c1) {
+                Ordering::Greater => Ordering::Greater,
+                Ordering::Less => Ordering::Less,
+                Ordering::Equal => self.c0.cmp(&other.c0),
+            },
+        }
+    }
+}
+
+impl<P: Fp3Parameters> PartialOrd for Fp3<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp3Parameters> From<u128> for Fp3<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u64> for Fp3<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u32> for Fp3<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u16> for Fp3<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u8> for Fp3<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> ToBits for Fp3<P> {
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_le());
+        res.extend_from_slice(&self.c1.to_bits_le());
+        res.extend_from_slice(&self.c2.to_bits_le());
+        res
+    }
+
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_be());
+        res.extend_from_slice(&self.c1.to_bits_be());
+        res.extend_from_slice(&self.c2.to_bits_be());
+        res
+    }
+}
+
+impl<P: Fp3Parameters> ToBytes for Fp3<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write_le(&mut writer)?;
+        self.c1.write_le(&mut writer)?;
+        self.c2.write_le(writer)
+    }
+}
+
+impl<P: Fp3Parameters> FromBytes for Fp3<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = P::Fp::read_le(&mut reader)?;
+        let c1 = P::Fp::read_le(&mut reader)?;
+        let c2 = P::Fp::read_le(reader)?;
+        Ok(Fp3::new(c0, c1, c2))
+    }
+}
+
+impl<P: Fp3Parameters> Neg for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        let mut res = self;
+        res.c0 = res.c0.neg();
+        res.c1 = res.c1.neg();
+        res.c2 = res.c2.neg();
+        res
+    }
+}
+
+impl<P: Fp3Parameters> Distribution<Fp3<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp3<P> {
+        Fp3::new(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng))
+    }
+}
+
+impl_add_sub_from_field_ref!(Fp3, Fp3Parameters);
+impl_mul_div_from_field_ref!(Fp3, Fp3Parameters);
+
+impl<'a, P: Fp3Parameters> Add<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Sub<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Mul<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Div<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> AddAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+        self.c2.add_assign(other.c2);
+    }
+}
+
+impl<'a, P: Fp3Parameters> SubAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+        self.c2.sub_assign(&other.c2);
+    }
+}
+
+impl<'a, P: Fp3Parameters> MulAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly Fields.pdf; Section 4 (Karatsuba)
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let v2 = self.c2 * other.c2;
+
+        let c0 = P::mul_fp_by_nonresidue(&((self.c1 + self.c2) * (other.c1 + other.c2) - v1 - v2)) + v0;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1 + P::mul_fp_by_nonresidue(&v2);
+        let c2 = (self.c0 + self.c2) * (other.c0 + other.c2) - v0 - v2 + v1;
+
+        self.c0 = c0;
+        self.c1 = c1;
+        self.c2 = c2;
+    }
+}
+
+impl<'a, P: Fp3Parameters> DivAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: Fp3Parameters> std::fmt::Display for Fp3<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Fp3({} + {} * u + {} * u^2)", self.c0, self.c1, self.c2)
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalSerializeWithFlags for Fp3<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, mut writer: W, flags: F) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_uncompressed(&self.c0, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.c1, &mut writer)?;
+        self.c2.serialize_with_flags(&mut writer, flags)?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.c0.uncompressed_size() + self.c1.uncompressed_size() + self.c2.serialized_size_with_flags::<F>()
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalSerialize for Fp3<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: W, _compress: Compress) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.c0.serialized_size(compress) + self.c1.serialized_size(compress) + self.c2.serialized_size(compress)
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalDeserializeWithFlags for Fp3<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(mut reader: R) -> Result<(Self, F), SerializationError> {
+        let c0: P::Fp = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let c1: P::Fp = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let (c2, flags): (P::Fp, _) = CanonicalDeserializeWithFlags::deserialize_with_flags(&mut reader)?;
+        Ok((Fp3::new(c0, c1, c2), flags))
+    }
+}
+
+impl<P: Fp3Parameters> Valid for Fp3<P> {
+    fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
+        Ok(())
+    }
+
+    fn batch_check<'a>(
+        _batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), snarkvm_utilities::SerializationError>
+    where
+        Self: 'a,
+    {
+        Ok(())
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalDeserialize for Fp3<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = P::Fp::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = P::Fp::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c2 = P::Fp::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Fp3::new(c0, c1, c2))
+    }
+}
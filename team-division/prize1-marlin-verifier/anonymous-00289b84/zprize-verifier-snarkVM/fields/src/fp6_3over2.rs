@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, Fp2, Fp2Parameters, One, Zero};
+use crate::{Choice, ConditionallySelectable, ConstantTimeEq, Field, Fp2, Fp2Parameters, One, Zero};
 use snarkvm_utilities::{
     rand::Uniform,
     serialize::{SerializationError, *},
@@ -175,6 +175,24 @@ impl<P: Fp6Parameters> One for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> ConditionallySelectable for Fp6<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            Fp2::conditional_select(&a.c0, &b.c0, choice),
+            Fp2::conditional_select(&a.c1, &b.c1, choice),
+            Fp2::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<P: Fp6Parameters> ConstantTimeEq for Fp6<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
 impl<P: Fp6Parameters> Field for Fp6<P> {
     type BasePrimeField = <Fp2<P::Fp2Params> as Field>::BasePrimeField;
 
@@ -46,6 +46,9 @@ use std::{
     str::FromStr,
 };
 
+#[cfg(feature = "ct")]
+use crate::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
 
 #[derive(Derivative)]
@@ -77,8 +80,19 @@ impl<P: Fp256Parameters> Fp256<P> {
 
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
+        #[cfg(not(feature = "ct"))]
+        {
+            if !self.is_valid() {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+        }
+        #[cfg(feature = "ct")]
+        {
+            // Subtract the modulus, and keep the difference unless the subtraction borrowed.
+            let mut reduced = self.0;
+            let borrow = reduced.sub_noborrow(&P::MODULUS);
+            let limbs = <[u64; 4]>::conditional_select(&reduced.0, &(self.0).0, Choice::from_bool(borrow));
+            self.0 = BigInteger::new(limbs);
         }
     }
 
@@ -249,54 +263,61 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn inverse(&self) -> Option<Self> {
-        if self.is_zero() {
-            None
-        } else {
-            // Guajardo Kumar Paar Pelzl
-            // Efficient Software-Implementation of Finite Fields with Applications to
-            // Cryptography
-            // Algorithm 16 (BEA for Inversion in Fp)
-
-            let one = BigInteger::from(1);
-
-            let mut u = self.0;
-            let mut v = P::MODULUS;
-            let mut b = Fp256::<P>(P::R2, PhantomData); // Avoids unnecessary reduction step.
-            let mut c = Self::zero();
+        #[cfg(feature = "ct")]
+        {
+            ct_inverse_impl!(Self, P, self)
+        }
+        #[cfg(not(feature = "ct"))]
+        {
+            if self.is_zero() {
+                None
+            } else {
+                // Guajardo Kumar Paar Pelzl
+                // Efficient Software-Implementation of Finite Fields with Applications to
+                // Cryptography
+                // Algorithm 16 (BEA for Inversion in Fp)
+
+                let one = BigInteger::from(1);
+
+                let mut u = self.0;
+                let mut v = P::MODULUS;
+                let mut b = Fp256::<P>(P::R2, PhantomData); // Avoids unnecessary reduction step.
+                let mut c = Self::zero();
+
+                while u != one && v != one {
+                    while u.is_even() {
+                        u.div2();
+
+                        if b.0.is_even() {
+                            b.0.div2();
+                        } else {
+                            b.0.add_nocarry(&P::MODULUS);
+                            b.0.div2();
+                        }
+                    }
 
-            while u != one && v != one {
-                while u.is_even() {
-                    u.div2();
+                    while v.is_even() {
+                        v.div2();
 
-                    if b.0.is_even() {
-                        b.0.div2();
-                    } else {
-                        b.0.add_nocarry(&P::MODULUS);
-                        b.0.div2();
+                        if c.0.is_even() {
+                            c.0.div2();
+                        } else {
+                            c.0.add_nocarry(&P::MODULUS);
+                            c.0.div2();
+                        }
                     }
-                }
 
-                while v.is_even() {
-                    v.div2();
-
-                    if c.0.is_even() {
-                        c.0.div2();
+                    if v < u {
+                        u.sub_noborrow(&v);
+                        b.sub_assign(&c);
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
-                        c.0.div2();
+                        v.sub_noborrow(&u);
+                        c.sub_assign(&b);
                     }
                 }
 
-                if v < u {
-                    u.sub_noborrow(&v);
-                    b.sub_assign(&c);
-                } else {
-                    v.sub_noborrow(&u);
-                    c.sub_assign(&b);
-                }
+                if u == one { Some(b) } else { Some(c) }
             }
-
-            if u == one { Some(b) } else { Some(c) }
         }
     }
 
@@ -420,7 +441,14 @@ impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
     // Only works for p = 1 (mod 16).
     #[inline]
     fn sqrt(&self) -> Option<Self> {
-        sqrt_impl!(Self, P, self)
+        #[cfg(not(feature = "ct"))]
+        {
+            sqrt_impl!(Self, P, self)
+        }
+        #[cfg(feature = "ct")]
+        {
+            ct_sqrt_impl!(Self, P, self)
+        }
     }
 
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
@@ -559,12 +587,21 @@ impl<P: Fp256Parameters> Neg for Fp256<P> {
     #[inline]
     #[must_use]
     fn neg(self) -> Self {
-        if !self.is_zero() {
+        #[cfg(not(feature = "ct"))]
+        {
+            if !self.is_zero() {
+                let mut tmp = P::MODULUS;
+                tmp.sub_noborrow(&self.0);
+                Fp256::<P>(tmp, PhantomData)
+            } else {
+                self
+            }
+        }
+        #[cfg(feature = "ct")]
+        {
             let mut tmp = P::MODULUS;
             tmp.sub_noborrow(&self.0);
-            Fp256::<P>(tmp, PhantomData)
-        } else {
-            self
+            Self::conditional_select(&Fp256::<P>(tmp, PhantomData), &self, self.ct_eq(&Self::zero()))
         }
     }
 }
@@ -626,12 +663,22 @@ impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
+        #[cfg(not(feature = "ct"))]
+        {
+            // If `other` is larger than `self`, add the modulus to self first.
+            if other.0 > self.0 {
+                self.0.add_nocarry(&P::MODULUS);
+            }
 
-        self.0.sub_noborrow(&other.0);
+            self.0.sub_noborrow(&other.0);
+        }
+        #[cfg(feature = "ct")]
+        {
+            // Subtract `other`, and add back the modulus if the subtraction borrowed.
+            let borrow = self.0.sub_noborrow(&other.0);
+            let modulus = <[u64; 4]>::conditional_select(&[0u64; 4], &P::MODULUS.0, Choice::from_bool(borrow));
+            self.0.add_nocarry(&BigInteger::new(modulus));
+        }
     }
 }
 
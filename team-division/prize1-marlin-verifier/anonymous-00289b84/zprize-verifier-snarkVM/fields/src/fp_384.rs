@@ -48,6 +48,9 @@ use std::{
 
 use crate::api::*;
 
+#[cfg(feature = "ct")]
+use crate::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
 
 #[derive(Derivative)]
@@ -79,8 +82,19 @@ impl<P: Fp384Parameters> Fp384<P> {
 
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
+        #[cfg(not(feature = "ct"))]
+        {
+            if !self.is_valid() {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+        }
+        #[cfg(feature = "ct")]
+        {
+            // Subtract the modulus, and keep the difference unless the subtraction borrowed.
+            let mut reduced = self.0;
+            let borrow = reduced.sub_noborrow(&P::MODULUS);
+            let limbs = <[u64; 6]>::conditional_select(&reduced.0, &(self.0).0, Choice::from_bool(borrow));
+            self.0 = BigInteger::new(limbs);
         }
     }
 
@@ -316,7 +330,11 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn inverse(&self) -> Option<Self> {
-        #[cfg(not(feature = "blst_asm"))]
+        #[cfg(feature = "ct")]
+        {
+            ct_inverse_impl!(Self, P, self)
+        }
+        #[cfg(all(not(feature = "ct"), not(feature = "blst_asm")))]
         {
             if self.is_zero() {
                 None
@@ -368,7 +386,7 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
                 if u == one { Some(b) } else { Some(c) }
             }
         }
-        #[cfg(feature = "blst_asm")]
+        #[cfg(all(not(feature = "ct"), feature = "blst_asm"))]
         {
             if self.is_zero() {
                 None
@@ -528,7 +546,14 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
 
     #[inline]
     fn sqrt(&self) -> Option<Self> {
-        sqrt_impl!(Self, P, self)
+        #[cfg(not(feature = "ct"))]
+        {
+            sqrt_impl!(Self, P, self)
+        }
+        #[cfg(feature = "ct")]
+        {
+            ct_sqrt_impl!(Self, P, self)
+        }
     }
 
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
@@ -664,12 +689,21 @@ impl<P: Fp384Parameters> Neg for Fp384<P> {
     #[inline]
     #[must_use]
     fn neg(self) -> Self {
-        if !self.is_zero() {
+        #[cfg(not(feature = "ct"))]
+        {
+            if !self.is_zero() {
+                let mut tmp = P::MODULUS;
+                tmp.sub_noborrow(&self.0);
+                Fp384::<P>(tmp, PhantomData)
+            } else {
+                self
+            }
+        }
+        #[cfg(feature = "ct")]
+        {
             let mut tmp = P::MODULUS;
             tmp.sub_noborrow(&self.0);
-            Fp384::<P>(tmp, PhantomData)
-        } else {
-            self
+            Self::conditional_select(&Fp384::<P>(tmp, PhantomData), &self, self.ct_eq(&Self::zero()))
         }
     }
 }
@@ -731,12 +765,22 @@ impl<'a, P: Fp384Parameters> AddAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
+        #[cfg(not(feature = "ct"))]
+        {
+            // If `other` is larger than `self`, add the modulus to self first.
+            if other.0 > self.0 {
+                self.0.add_nocarry(&P::MODULUS);
+            }
 
-        self.0.sub_noborrow(&other.0);
+            self.0.sub_noborrow(&other.0);
+        }
+        #[cfg(feature = "ct")]
+        {
+            // Subtract `other`, and add back the modulus if the subtraction borrowed.
+            let borrow = self.0.sub_noborrow(&other.0);
+            let modulus = <[u64; 6]>::conditional_select(&[0u64; 6], &P::MODULUS.0, Choice::from_bool(borrow));
+            self.0.add_nocarry(&BigInteger::new(modulus));
+        }
     }
 }
 
@@ -140,6 +140,63 @@ macro_rules! sqrt_impl {
     }};
 }
 
+/// Computes the inverse of a prime field element as `self^(p - 2)`.
+/// The exponent is public, so the computation does not depend on the value of `self`.
+#[cfg(feature = "ct")]
+macro_rules! ct_inverse_impl {
+    ($Self:ident, $P:tt, $self:expr) => {{
+        let mut exponent = $P::MODULUS;
+        exponent.sub_noborrow(&2u64.into());
+        let inverse = $self.pow(exponent);
+        // Only the (public) fact that there is no inverse is revealed.
+        if $self.ct_eq(&$Self::zero()).unwrap_bool() { None } else { Some(inverse) }
+    }};
+}
+
+#[cfg(feature = "ct")]
+macro_rules! ct_sqrt_impl {
+    ($Self:ident, $P:tt, $self:expr) => {{
+        // https://www.rfc-editor.org/rfc/rfc9380.html#appendix-I.4
+        // This is Tonelli-Shanks with a fixed number of iterations,
+        // where each update is selected, instead of branched on.
+        let mut z = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+        let mut t = z.square() * $self;
+        z *= $self;
+        let mut c = $Self::two_adic_root_of_unity();
+        for i in (2..=$P::TWO_ADICITY).rev() {
+            let mut b = t;
+            for _ in 2..i {
+                b.square_in_place();
+            }
+            let is_one = b.ct_eq(&$Self::one());
+            z = $Self::conditional_select(&(z * c), &z, is_one);
+            c.square_in_place();
+            t = $Self::conditional_select(&(t * c), &t, is_one);
+        }
+        // `z` is a square root of `self` if and only if `self` is a quadratic residue.
+        // Only the (public) fact that there is no square root is revealed.
+        if z.square().ct_eq($self).unwrap_bool() { Some(z) } else { None }
+    }};
+}
+
+macro_rules! impl_primefield_constant_time {
+    ($field: ident, $biginteger: ident, $parameters: ident) => {
+        impl<P: $parameters> ConditionallySelectable for $field<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $field::new($biginteger(ConditionallySelectable::conditional_select(&(a.0).0, &(b.0).0, choice)))
+            }
+        }
+
+        impl<P: $parameters> ConstantTimeEq for $field<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> Choice {
+                (self.0).0.ct_eq(&(other.0).0)
+            }
+        }
+    };
+}
+
 macro_rules! impl_primefield_serializer {
     ($field: ident, $params: ident, $byte_size: expr) => {
         impl<P: $params> CanonicalSerializeWithFlags for $field<P> {
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// A secret boolean, stored as a mask of all zeros or all ones,
+/// which is combined with other values without branching on it.
+#[derive(Copy, Clone, Debug)]
+pub struct Choice(u64);
+
+impl Choice {
+    /// Returns the choice of the given boolean.
+    #[inline]
+    pub fn from_bool(value: bool) -> Self {
+        Self::from_lsb(value as u64)
+    }
+
+    /// Returns the choice of the least significant bit of `bit`, which must be 0 or 1.
+    #[inline]
+    pub fn from_lsb(bit: u64) -> Self {
+        debug_assert!(bit <= 1, "Expected a single bit, found {bit}");
+        Self(optimization_barrier(bit).wrapping_neg())
+    }
+
+    /// Returns the mask of this choice: `u64::MAX` if it is set, and 0 otherwise.
+    #[inline]
+    pub fn mask(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the boolean of this choice.
+    /// This branches on the choice, and should only be used once it is safe to reveal it.
+    #[inline]
+    pub fn unwrap_bool(self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+/// A type whose values can be selected between without branching on the selector.
+pub trait ConditionallySelectable: Copy {
+    /// Returns `b` if `choice` is set, and `a` otherwise.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Replaces `self` with `other` if `choice` is set.
+    #[inline]
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    /// Swaps `a` and `b` if `choice` is set.
+    #[inline]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let (a_old, b_old) = (*a, *b);
+        *a = Self::conditional_select(&a_old, &b_old, choice);
+        *b = Self::conditional_select(&b_old, &a_old, choice);
+    }
+}
+
+/// A type whose values can be compared without branching on them.
+pub trait ConstantTimeEq {
+    /// Returns a set choice if `self` equals `other`.
+    fn ct_eq(&self, other: &Self) -> Choice;
+}
+
+impl ConditionallySelectable for u64 {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        a ^ (choice.mask() & (a ^ b))
+    }
+}
+
+impl ConstantTimeEq for u64 {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        limbs_are_zero(&[self ^ other])
+    }
+}
+
+impl<const N: usize> ConditionallySelectable for [u64; N] {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = *a;
+        result.iter_mut().zip(b).for_each(|(a, b)| *a = u64::conditional_select(a, b, choice));
+        result
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for [u64; N] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let mut difference = [0u64; N];
+        difference.iter_mut().zip(self.iter().zip(other)).for_each(|(d, (a, b))| *d = a ^ b);
+        limbs_are_zero(&difference)
+    }
+}
+
+/// Returns a set choice if every limb in `limbs` is zero.
+#[inline]
+pub(crate) fn limbs_are_zero(limbs: &[u64]) -> Choice {
+    let acc = limbs.iter().fold(0u64, |acc, limb| acc | limb);
+    // The top bit of `acc | -acc` is set if and only if `acc` is nonzero.
+    Choice::from_lsb(((acc | acc.wrapping_neg()) >> 63) ^ 1)
+}
+
+/// Hides `value` from the optimizer, so that it cannot turn the mask arithmetic on it back into branches.
+#[inline(never)]
+fn optimization_barrier(value: u64) -> u64 {
+    // Safety: `value` is a valid, aligned, and initialized `u64` on the stack.
+    unsafe { std::ptr::read_volatile(&value) }
+}
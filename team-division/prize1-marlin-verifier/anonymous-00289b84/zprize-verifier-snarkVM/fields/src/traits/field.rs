@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{One, PrimeField, Zero};
+use crate::{ConditionallySelectable, ConstantTimeEq, One, PrimeField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::Uniform,
@@ -60,6 +60,8 @@ pub trait Field:
     + Zero
     + Sized
     + Hash
+    + ConditionallySelectable
+    + ConstantTimeEq
     + From<u128>
     + From<u64>
     + From<u32>
@@ -16,6 +16,9 @@
 
 pub use num_traits::One;
 
+mod constant_time;
+pub use constant_time::*;
+
 mod fft_field;
 pub use fft_field::*;
 
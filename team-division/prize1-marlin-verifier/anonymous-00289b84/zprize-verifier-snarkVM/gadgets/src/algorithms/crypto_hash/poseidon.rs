@@ -185,10 +185,8 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSpongeGadg
                 &self.state[CAPACITY + rate_start_index..(CAPACITY + num_elements_squeezed + rate_start_index)],
             );
 
-            // Unless we are done with squeezing in this call, permute.
-            if remaining_output.len() != RATE {
-                self.permute(cs.ns(|| format!("permute {}", loop_counter)))?;
-            }
+            // We are not done with squeezing in this call, so permute (as the native sponge does).
+            self.permute(cs.ns(|| format!("permute {}", loop_counter)))?;
             // Repeat with updated output slices and rate start index
             remaining_output = &mut remaining_output[num_elements_squeezed..];
             rate_start_index = 0;
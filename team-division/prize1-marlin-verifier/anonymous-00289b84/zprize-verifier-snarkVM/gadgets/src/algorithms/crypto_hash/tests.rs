@@ -50,3 +50,38 @@ fn absorb_test() {
     assert_eq!(constraint_squeeze[0].get_value().unwrap(), native_squeeze[0]);
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn squeeze_across_rate_boundary_test() {
+    let mut rng = test_rng();
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let absorb: Vec<_> = (0..3).map(|_| Fr::rand(&mut rng)).collect();
+    let absorb_var: Vec<_> = absorb
+        .iter()
+        .enumerate()
+        .map(|(i, v)| FpGadget::<Fr>::alloc_input(cs.ns(|| format!("alloc input {}", i)), || Ok(*v)).unwrap())
+        .collect();
+
+    let sponge_params = Arc::new(Fr::default_poseidon_parameters::<2>().unwrap());
+
+    let mut native_sponge = PoseidonSponge::new(&sponge_params);
+    let mut constraint_sponge = PoseidonSpongeGadget::with_parameters(cs.ns(|| "new sponge"), &sponge_params);
+
+    native_sponge.absorb(&absorb);
+    constraint_sponge.absorb(cs.ns(|| "absorb"), absorb_var.iter()).unwrap();
+
+    // With a rate of 2, these squeezes start and end at different offsets within the rate,
+    // and the first of them spans several permutations.
+    for (i, num_elements) in [5, 1, 2, 3].into_iter().enumerate() {
+        let native_squeeze = native_sponge.squeeze(num_elements);
+        let constraint_squeeze = constraint_sponge.squeeze(cs.ns(|| format!("squeeze {}", i)), num_elements).unwrap();
+
+        assert_eq!(constraint_squeeze.len(), num_elements);
+        for (native, constraint) in native_squeeze.iter().zip(&constraint_squeeze) {
+            assert_eq!(constraint.get_value().unwrap(), *native);
+        }
+    }
+    assert!(cs.is_satisfied());
+}
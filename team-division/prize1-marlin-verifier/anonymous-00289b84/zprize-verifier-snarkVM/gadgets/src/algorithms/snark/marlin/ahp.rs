@@ -0,0 +1,370 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::{
+        CircuitVerifyingKeyVar,
+        FiatShamirAlgebraicSpongeRngVar,
+        LinearCombinationVar,
+        ProofVar,
+    },
+    nonnative::NonNativeFieldVar,
+    traits::curves::PairingGadget,
+    AlgebraicSpongeVar,
+    FieldGadget,
+};
+use snarkvm_algorithms::{
+    fft::EvaluationDomain,
+    polycommit::sonic_pc::LCTerm,
+    snark::marlin::{witness_label, MarlinMode},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+
+/// First message of the verifier, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct FirstMessageVar<E: PairingEngine> {
+    /// Query for the random polynomial.
+    pub alpha: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Randomizer for the lincheck for `B`.
+    pub eta_b: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Randomizer for the lincheck for `C`.
+    pub eta_c: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Randomizers for combining vectors from the batch.
+    pub batch_combiners: Vec<NonNativeFieldVar<E::Fr, E::Fq>>,
+}
+
+/// Second message of the verifier, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct SecondMessageVar<E: PairingEngine> {
+    /// Query for the second round of polynomials.
+    pub beta: NonNativeFieldVar<E::Fr, E::Fq>,
+}
+
+/// Third message of the verifier, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct ThirdMessageVar<E: PairingEngine> {
+    /// Randomizer for the h-polynomial for `B`.
+    pub r_b: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Randomizer for the h-polynomial for `C`.
+    pub r_c: NonNativeFieldVar<E::Fr, E::Fq>,
+}
+
+/// The state of the verifier after all rounds, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct VerifierStateVar<E: PairingEngine> {
+    /// The first message of the verifier.
+    pub first_round_message: FirstMessageVar<E>,
+    /// The second message of the verifier.
+    pub second_round_message: SecondMessageVar<E>,
+    /// The third message of the verifier.
+    pub third_round_message: ThirdMessageVar<E>,
+    /// The query point of the fourth round.
+    pub gamma: NonNativeFieldVar<E::Fr, E::Fq>,
+}
+
+/// The AHP verifier for R1CS, as a gadget.
+///
+/// Mirrors the native `AHPForR1CS` verifier: the challenges are squeezed from the
+/// in-circuit sponge in the same order, and the linear combinations are identical
+/// up to the order of their terms.
+pub struct AHPForR1CSGadget<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+    MM: MarlinMode,
+> {
+    _engine: PhantomData<(E, PG, S, SV, MM)>,
+}
+
+impl<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+    MM: MarlinMode,
+> AHPForR1CSGadget<E, PG, S, SV, MM>
+{
+    /// Output the first message of the verifier.
+    pub fn verifier_first_round<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        batch_size: usize,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<FirstMessageVar<E>, SynthesisError> {
+        if batch_size == 0 {
+            return Err(anyhow!("Cannot verify an empty batch").into());
+        }
+        let mut elems = fs_rng.squeeze_nonnative_field_elements(cs, 3 + batch_size - 1)?;
+        let rest = elems.split_off(3);
+        let [alpha, eta_b, eta_c]: [_; 3] = elems.try_into().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+        let mut batch_combiners = vec![NonNativeFieldVar::Constant(E::Fr::one())];
+        batch_combiners.extend(rest);
+
+        Ok(FirstMessageVar { alpha, eta_b, eta_c, batch_combiners })
+    }
+
+    /// Output the second message of the verifier.
+    pub fn verifier_second_round<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<SecondMessageVar<E>, SynthesisError> {
+        let mut elems = fs_rng.squeeze_nonnative_field_elements(cs, 1)?;
+        Ok(SecondMessageVar { beta: elems.remove(0) })
+    }
+
+    /// Output the third message of the verifier.
+    pub fn verifier_third_round<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<ThirdMessageVar<E>, SynthesisError> {
+        let mut elems = fs_rng.squeeze_nonnative_field_elements(cs, 2)?;
+        let r_c = elems.remove(1);
+        let r_b = elems.remove(0);
+        Ok(ThirdMessageVar { r_b, r_c })
+    }
+
+    /// Output the query point of the fourth round.
+    pub fn verifier_fourth_round<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<NonNativeFieldVar<E::Fr, E::Fq>, SynthesisError> {
+        let mut elems = fs_rng.squeeze_nonnative_field_elements(cs, 1)?;
+        Ok(elems.remove(0))
+    }
+
+    /// Construct the linear combinations that are checked by the AHP.
+    /// Public inputs should be formatted and padded to the size of the input domain.
+    #[allow(non_snake_case)]
+    pub fn construct_linear_combinations<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        circuit_verifying_key: &CircuitVerifyingKeyVar<E, PG, MM>,
+        public_inputs: &[Vec<NonNativeFieldVar<E::Fr, E::Fq>>],
+        proof: &ProofVar<E, PG>,
+        state: &VerifierStateVar<E>,
+    ) -> Result<Vec<LinearCombinationVar<E>>, SynthesisError> {
+        let constraint_domain = &circuit_verifying_key.constraint_domain;
+        let non_zero_domains = [
+            &circuit_verifying_key.non_zero_a_domain,
+            &circuit_verifying_key.non_zero_b_domain,
+            &circuit_verifying_key.non_zero_c_domain,
+        ];
+        let largest_non_zero_domain = *non_zero_domains.iter().max_by_key(|d| d.size()).unwrap();
+
+        let input_domain = EvaluationDomain::<E::Fr>::new(circuit_verifying_key.num_public_inputs)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        if public_inputs.len() != proof.batch_size || public_inputs.iter().any(|x| x.len() != input_domain.size()) {
+            return Err(anyhow!("The public inputs are not formatted for the input domain").into());
+        }
+        if proof.z_b_evals.len() != proof.batch_size || proof.witness_commitments.len() != proof.batch_size {
+            return Err(anyhow!("The proof does not match its batch size").into());
+        }
+
+        let FirstMessageVar { alpha, eta_b, eta_c, batch_combiners } = &state.first_round_message;
+        let beta = &state.second_round_message.beta;
+        let ThirdMessageVar { r_b, r_c } = &state.third_round_message;
+        let gamma = &state.gamma;
+        let [sum_a, sum_b, sum_c] = &proof.msg;
+
+        let t_at_beta = {
+            let mut cs = cs.ns(|| "t_at_beta");
+            let a = sum_a.mul_by_constant(cs.ns(|| "a"), &non_zero_domains[0].size_as_field_element)?;
+            let b = sum_b.mul_by_constant(cs.ns(|| "b_size"), &non_zero_domains[1].size_as_field_element)?;
+            let b = b.mul(cs.ns(|| "b"), eta_b)?;
+            let c = sum_c.mul_by_constant(cs.ns(|| "c_size"), &non_zero_domains[2].size_as_field_element)?;
+            let c = c.mul(cs.ns(|| "c"), eta_c)?;
+            a.add(cs.ns(|| "a_plus_b"), &b)?.add(cs.ns(|| "a_plus_b_plus_c"), &c)?
+        };
+
+        let v_H_at_alpha = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H_at_alpha"), constraint_domain, alpha)?;
+        let v_H_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H_at_beta"), constraint_domain, beta)?;
+        let v_X_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_X_at_beta"), &input_domain, beta)?;
+        let r_alpha_at_beta = {
+            let mut cs = cs.ns(|| "r_alpha_at_beta");
+            let numerator = v_H_at_alpha.sub(cs.ns(|| "numerator"), &v_H_at_beta)?;
+            let denominator = alpha.sub(cs.ns(|| "denominator"), beta)?;
+            numerator.mul(cs.ns(|| "divide"), &denominator.inverse(cs.ns(|| "inverse"))?)?
+        };
+
+        // Lincheck sumcheck:
+        let mut lincheck_sumcheck = LinearCombinationVar { label: "lincheck_sumcheck".into(), terms: vec![] };
+        if MM::ZK {
+            lincheck_sumcheck.terms.push((NonNativeFieldVar::Constant(E::Fr::one()), "mask_poly".into()));
+        }
+
+        let t_times_v_X = t_at_beta.mul(cs.ns(|| "t_times_v_X_at_beta"), &v_X_at_beta)?;
+        let mut batch_z_b_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+        for (i, (z_b_i_at_beta, combiner)) in proof.z_b_evals.iter().zip(batch_combiners).enumerate() {
+            let mut cs = cs.ns(|| format!("instance_{}", i));
+
+            let r_times_combiner = r_alpha_at_beta.mul(cs.ns(|| "r_times_combiner"), combiner)?;
+            let z_a_coeff = eta_c
+                .mul(cs.ns(|| "eta_c_times_z_b"), z_b_i_at_beta)?
+                .add_constant(cs.ns(|| "plus_eta_a"), &E::Fr::one())?
+                .mul(cs.ns(|| "z_a_coeff"), &r_times_combiner)?;
+            let w_coeff = t_times_v_X.mul(cs.ns(|| "w_coeff"), combiner)?.negate(cs.ns(|| "negate_w_coeff"))?;
+            lincheck_sumcheck.terms.push((z_a_coeff, witness_label("z_a", i).into()));
+            lincheck_sumcheck.terms.push((w_coeff, witness_label("w", i).into()));
+
+            let combined_z_b = z_b_i_at_beta.mul(cs.ns(|| "combine_z_b"), combiner)?;
+            batch_z_b_at_beta = batch_z_b_at_beta.add(cs.ns(|| "accumulate_z_b"), &combined_z_b)?;
+        }
+
+        let lagrange_at_beta =
+            Self::evaluate_all_lagrange_coefficients(cs.ns(|| "lagrange_at_beta"), &input_domain, beta)?;
+        let mut combined_x_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+        for (i, (input, combiner)) in public_inputs.iter().zip(batch_combiners).enumerate() {
+            let mut cs = cs.ns(|| format!("input_{}", i));
+            let mut x_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+            for (j, (x, l)) in input.iter().zip(&lagrange_at_beta).enumerate() {
+                let term = x.mul(cs.ns(|| format!("x_{}_times_lagrange", j)), l)?;
+                x_at_beta = x_at_beta.add(cs.ns(|| format!("accumulate_x_{}", j)), &term)?;
+            }
+            let x_at_beta = x_at_beta.mul(cs.ns(|| "combine_x"), combiner)?;
+            combined_x_at_beta = combined_x_at_beta.add(cs.ns(|| "accumulate_combined_x"), &x_at_beta)?;
+        }
+
+        let z_b_constant = r_alpha_at_beta
+            .mul(cs.ns(|| "r_times_eta_b"), eta_b)?
+            .mul(cs.ns(|| "r_times_eta_b_times_z_b"), &batch_z_b_at_beta)?;
+        let x_constant =
+            t_at_beta.mul(cs.ns(|| "t_times_x"), &combined_x_at_beta)?.negate(cs.ns(|| "negate_t_times_x"))?;
+        let g_1_constant =
+            beta.mul(cs.ns(|| "beta_times_g_1"), &proof.g_1_eval)?.negate(cs.ns(|| "negate_beta_times_g_1"))?;
+        lincheck_sumcheck.terms.push((z_b_constant, LCTerm::One));
+        lincheck_sumcheck.terms.push((x_constant, LCTerm::One));
+        lincheck_sumcheck.terms.push((v_H_at_beta.negate(cs.ns(|| "negate_v_H_at_beta"))?, "h_1".into()));
+        lincheck_sumcheck.terms.push((g_1_constant, LCTerm::One));
+
+        // Matrix sumcheck:
+        let mut matrix_sumcheck = LinearCombinationVar { label: "matrix_sumcheck".into(), terms: vec![] };
+
+        let v_H_at_alpha_beta = v_H_at_alpha.mul(cs.ns(|| "v_H_at_alpha_beta"), &v_H_at_beta)?;
+        let alpha_beta = alpha.mul(cs.ns(|| "alpha_beta"), beta)?;
+        let v_K_at_gamma =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_K_at_gamma"), largest_non_zero_domain, gamma)?;
+
+        let multipliers = [NonNativeFieldVar::Constant(E::Fr::one()), r_b.clone(), r_c.clone()];
+        let g_at_gamma = [&proof.g_a_eval, &proof.g_b_eval, &proof.g_c_eval];
+        let sums = [sum_a, sum_b, sum_c];
+        for (i, label) in ["a", "b", "c"].into_iter().enumerate() {
+            let mut cs = cs.ns(|| format!("matrix_{}", label));
+
+            let selector = Self::evaluate_selector_polynomial(
+                cs.ns(|| "selector"),
+                largest_non_zero_domain,
+                non_zero_domains[i],
+                gamma,
+            )?;
+            let coeff = match &multipliers[i] {
+                NonNativeFieldVar::Constant(c) if c.is_one() => selector,
+                multiplier => selector.mul(cs.ns(|| "selector_times_multiplier"), multiplier)?,
+            };
+            // The denominator is multiplied by `gamma * g(gamma) + sum`.
+            let denominator_coeff = gamma
+                .mul(cs.ns(|| "gamma_times_g"), g_at_gamma[i])?
+                .add(cs.ns(|| "plus_sum"), sums[i])?
+                .mul(cs.ns(|| "denominator_coeff"), &coeff)?;
+
+            let val_coeff = coeff.mul(cs.ns(|| "val_coeff"), &v_H_at_alpha_beta)?;
+            let one_coeff =
+                denominator_coeff.mul(cs.ns(|| "one_coeff"), &alpha_beta)?.negate(cs.ns(|| "negate_one"))?;
+            let row_coeff = denominator_coeff.mul(cs.ns(|| "row_coeff"), alpha)?;
+            let col_coeff = denominator_coeff.mul(cs.ns(|| "col_coeff"), beta)?;
+            let row_col_coeff = denominator_coeff.negate(cs.ns(|| "row_col_coeff"))?;
+
+            matrix_sumcheck.terms.push((val_coeff, format!("val_{}", label).into()));
+            matrix_sumcheck.terms.push((one_coeff, LCTerm::One));
+            matrix_sumcheck.terms.push((row_coeff, format!("row_{}", label).into()));
+            matrix_sumcheck.terms.push((col_coeff, format!("col_{}", label).into()));
+            matrix_sumcheck.terms.push((row_col_coeff, format!("row_col_{}", label).into()));
+        }
+        matrix_sumcheck.terms.push((v_K_at_gamma.negate(cs.ns(|| "negate_v_K_at_gamma"))?, "h_2".into()));
+
+        let one = || NonNativeFieldVar::Constant(E::Fr::one());
+        let mut linear_combinations = (0..proof.batch_size)
+            .map(|i| {
+                let z_b_i = witness_label("z_b", i);
+                LinearCombinationVar { label: z_b_i.clone(), terms: vec![(one(), z_b_i.into())] }
+            })
+            .collect::<Vec<_>>();
+        for label in ["g_1", "g_a", "g_b", "g_c"] {
+            linear_combinations.push(LinearCombinationVar { label: label.into(), terms: vec![(one(), label.into())] });
+        }
+        linear_combinations.push(lincheck_sumcheck);
+        linear_combinations.push(matrix_sumcheck);
+        linear_combinations.sort_by(|a, b| a.label.cmp(&b.label));
+
+        Ok(linear_combinations)
+    }
+
+    /// Evaluates the vanishing polynomial of `domain` at `point`.
+    pub fn evaluate_vanishing_polynomial<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        point: &NonNativeFieldVar<E::Fr, E::Fq>,
+    ) -> Result<NonNativeFieldVar<E::Fr, E::Fq>, SynthesisError> {
+        point.pow_by_constant(cs.ns(|| "pow"), &[domain.size])?.sub_constant(cs.ns(|| "minus_one"), &E::Fr::one())
+    }
+
+    /// Evaluates the polynomial that is one on `other` and zero on the rest of `domain`, at `point`.
+    pub fn evaluate_selector_polynomial<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        other: &EvaluationDomain<E::Fr>,
+        point: &NonNativeFieldVar<E::Fr, E::Fq>,
+    ) -> Result<NonNativeFieldVar<E::Fr, E::Fq>, SynthesisError> {
+        if domain.size() == other.size() {
+            return Ok(NonNativeFieldVar::Constant(E::Fr::one()));
+        }
+        let numerator = Self::evaluate_vanishing_polynomial(cs.ns(|| "numerator"), domain, point)?;
+        let denominator = Self::evaluate_vanishing_polynomial(cs.ns(|| "denominator"), other, point)?;
+        let ratio = other.size_as_field_element * domain.size_inv;
+        numerator
+            .mul(cs.ns(|| "divide"), &denominator.inverse(cs.ns(|| "inverse"))?)?
+            .mul_by_constant(cs.ns(|| "scale"), &ratio)
+    }
+
+    /// Evaluates all Lagrange polynomials of `domain` at `tau`, which must lie outside of `domain`.
+    pub fn evaluate_all_lagrange_coefficients<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        tau: &NonNativeFieldVar<E::Fr, E::Fq>,
+    ) -> Result<Vec<NonNativeFieldVar<E::Fr, E::Fq>>, SynthesisError> {
+        let vanishing = Self::evaluate_vanishing_polynomial(cs.ns(|| "vanishing"), domain, tau)?;
+        let l = vanishing.mul_by_constant(cs.ns(|| "normalize"), &domain.size_inv)?;
+
+        let mut coefficients = Vec::with_capacity(domain.size());
+        let mut r = E::Fr::one();
+        for i in 0..domain.size() {
+            let mut cs = cs.ns(|| format!("coefficient_{}", i));
+            let denominator = tau.sub_constant(cs.ns(|| "tau_minus_r"), &r)?.inverse(cs.ns(|| "inverse"))?;
+            coefficients.push(l.mul_by_constant(cs.ns(|| "scale"), &r)?.mul(cs.ns(|| "divide"), &denominator)?);
+            r *= &domain.group_gen;
+        }
+        Ok(coefficients)
+    }
+}
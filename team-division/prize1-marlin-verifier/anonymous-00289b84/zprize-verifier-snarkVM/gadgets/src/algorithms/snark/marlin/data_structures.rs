@@ -0,0 +1,369 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{nonnative::NonNativeFieldVar, traits::curves::PairingGadget, AllocGadget};
+use snarkvm_algorithms::{
+    fft::EvaluationDomain,
+    snark::marlin::{CircuitVerifyingKey, MarlinMode, Proof},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::{borrow::Borrow, marker::PhantomData};
+
+/// The SonicKZG10 verifier key, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct VerifierKeyVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The generator of G1.
+    pub g: PG::G1Gadget,
+    /// The generator of G1 that is used for making a commitment hiding.
+    pub gamma_g: PG::G1Gadget,
+    /// The generator of G2.
+    pub h: PG::G2Gadget,
+    /// \beta times the above generator of G2.
+    pub beta_h: PG::G2Gadget,
+    /// Pairs a degree bound with its corresponding G2 element, sorted by degree bound.
+    pub degree_bounds_and_neg_powers_of_h: Vec<(usize, PG::G2Gadget)>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> VerifierKeyVar<E, PG> {
+    /// Returns the G2 element that enforces `degree_bound`, if it is supported by this key.
+    pub fn get_shift_power(&self, degree_bound: usize) -> Option<&PG::G2Gadget> {
+        self.degree_bounds_and_neg_powers_of_h
+            .binary_search_by(|(d, _)| d.cmp(&degree_bound))
+            .ok()
+            .map(|i| &self.degree_bounds_and_neg_powers_of_h[i].1)
+    }
+}
+
+/// A Marlin circuit verifying key, allocated in the constraint system.
+///
+/// The sizes of the circuit are constants of the verification circuit,
+/// while the index commitments and the SRS elements are allocated.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct CircuitVerifyingKeyVar<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> {
+    /// The number of public inputs after padding, including the constant one.
+    pub num_public_inputs: usize,
+    /// The domain of the constraints.
+    pub constraint_domain: EvaluationDomain<E::Fr>,
+    /// The domain that represents A.
+    pub non_zero_a_domain: EvaluationDomain<E::Fr>,
+    /// The domain that represents B.
+    pub non_zero_b_domain: EvaluationDomain<E::Fr>,
+    /// The domain that represents C.
+    pub non_zero_c_domain: EvaluationDomain<E::Fr>,
+    /// Commitments to the indexed polynomials.
+    pub circuit_commitments: Vec<PG::G1Gadget>,
+    /// The verifier key for this index, trimmed from the universal SRS.
+    pub verifier_key: VerifierKeyVar<E, PG>,
+    #[doc(hidden)]
+    pub mode: PhantomData<MM>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> CircuitVerifyingKeyVar<E, PG, MM> {
+    fn alloc_with<CS, FG1, FG2>(
+        mut cs: CS,
+        vk: &CircuitVerifyingKey<E, MM>,
+        mut alloc_g1: FG1,
+        mut alloc_g2: FG2,
+    ) -> Result<Self, SynthesisError>
+    where
+        CS: ConstraintSystem<E::Fq>,
+        FG1: FnMut(&mut CS, String, &E::G1Affine) -> Result<PG::G1Gadget, SynthesisError>,
+        FG2: FnMut(&mut CS, String, &E::G2Affine) -> Result<PG::G2Gadget, SynthesisError>,
+    {
+        let info = &vk.circuit_info;
+        // The native verifier rejects non-square matrices in the first round.
+        if info.num_constraints != info.num_variables {
+            return Err(anyhow!(
+                "The verifying key is for a non-square constraint matrix ({} constraints, {} variables)",
+                info.num_constraints,
+                info.num_variables
+            )
+            .into());
+        }
+        let constraint_domain =
+            EvaluationDomain::new(info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_a_domain =
+            EvaluationDomain::new(info.num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_b_domain =
+            EvaluationDomain::new(info.num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_c_domain =
+            EvaluationDomain::new(info.num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let mut circuit_commitments = Vec::with_capacity(vk.circuit_commitments.len());
+        for (i, comm) in vk.circuit_commitments.iter().enumerate() {
+            circuit_commitments.push(alloc_g1(&mut cs, format!("circuit_commitment_{}", i), &comm.0)?);
+        }
+
+        let pc_vk = &vk.verifier_key;
+        let g = alloc_g1(&mut cs, "g".into(), &pc_vk.vk.g)?;
+        let gamma_g = alloc_g1(&mut cs, "gamma_g".into(), &pc_vk.vk.gamma_g)?;
+        let h = alloc_g2(&mut cs, "h".into(), &pc_vk.vk.h)?;
+        let beta_h = alloc_g2(&mut cs, "beta_h".into(), &pc_vk.vk.beta_h)?;
+        let mut degree_bounds_and_neg_powers_of_h = Vec::new();
+        for (degree_bound, power) in pc_vk.degree_bounds_and_neg_powers_of_h.iter().flatten() {
+            let power = alloc_g2(&mut cs, format!("neg_power_of_h_{}", degree_bound), power)?;
+            degree_bounds_and_neg_powers_of_h.push((*degree_bound, power));
+        }
+
+        Ok(Self {
+            num_public_inputs: info.num_public_inputs,
+            constraint_domain,
+            non_zero_a_domain,
+            non_zero_b_domain,
+            non_zero_c_domain,
+            circuit_commitments,
+            verifier_key: VerifierKeyVar { g, gamma_g, h, beta_h, degree_bounds_and_neg_powers_of_h },
+            mode: PhantomData,
+        })
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> AllocGadget<CircuitVerifyingKey<E, MM>, E::Fq>
+    for CircuitVerifyingKeyVar<E, PG, MM>
+{
+    fn alloc_constant<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+    {
+        let vk = value_gen()?;
+        Self::alloc_with(
+            cs,
+            vk.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_constant(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| PG::G2Gadget::alloc_constant(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+
+    fn alloc<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+    {
+        let vk = value_gen()?;
+        Self::alloc_with(
+            cs,
+            vk.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_checked(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| PG::G2Gadget::alloc_checked(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+    {
+        let vk = value_gen()?;
+        Self::alloc_with(
+            cs,
+            vk.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_input(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| PG::G2Gadget::alloc_input(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+}
+
+/// Commitments to the `w`, `z_a`, and `z_b` polynomials, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct WitnessCommitmentsVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// Commitment to the `w` polynomial.
+    pub w: PG::G1Gadget,
+    /// Commitment to the `z_a` polynomial.
+    pub z_a: PG::G1Gadget,
+    /// Commitment to the `z_b` polynomial.
+    pub z_b: PG::G1Gadget,
+}
+
+/// A KZG10 evaluation proof, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct KZGProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The commitment to the witness polynomial.
+    pub w: PG::G1Gadget,
+    /// The evaluation of the random polynomial, if the proof is hiding.
+    pub random_v: Option<NonNativeFieldVar<E::Fr, E::Fq>>,
+}
+
+/// A Marlin proof, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct ProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The number of instances being proven in this proof.
+    pub batch_size: usize,
+    /// Commitments to the witness polynomials of each instance.
+    pub witness_commitments: Vec<WitnessCommitmentsVar<E, PG>>,
+    /// Commitment to the masking polynomial.
+    pub mask_poly: Option<PG::G1Gadget>,
+    /// Commitment to the `g_1` polynomial.
+    pub g_1: PG::G1Gadget,
+    /// Commitment to the `h_1` polynomial.
+    pub h_1: PG::G1Gadget,
+    /// Commitment to the `g_a` polynomial.
+    pub g_a: PG::G1Gadget,
+    /// Commitment to the `g_b` polynomial.
+    pub g_b: PG::G1Gadget,
+    /// Commitment to the `g_c` polynomial.
+    pub g_c: PG::G1Gadget,
+    /// Commitment to the `h_2` polynomial.
+    pub h_2: PG::G1Gadget,
+    /// Evaluations of the `z_b_i`'s at `beta`.
+    pub z_b_evals: Vec<NonNativeFieldVar<E::Fr, E::Fq>>,
+    /// Evaluation of `g_1` at `beta`.
+    pub g_1_eval: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Evaluation of `g_a` at `gamma`.
+    pub g_a_eval: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Evaluation of `g_b` at `gamma`.
+    pub g_b_eval: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Evaluation of `g_c` at `gamma`.
+    pub g_c_eval: NonNativeFieldVar<E::Fr, E::Fq>,
+    /// Prover message: sum_a, sum_b, sum_c
+    pub msg: [NonNativeFieldVar<E::Fr, E::Fq>; 3],
+    /// The evaluation proofs from the polynomial commitment, one per query point.
+    pub pc_proof: Vec<KZGProofVar<E, PG>>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> ProofVar<E, PG> {
+    /// Returns the evaluations in the order in which the native verifier absorbs them.
+    pub fn evaluations(&self) -> Vec<NonNativeFieldVar<E::Fr, E::Fq>> {
+        let mut result = self.z_b_evals.clone();
+        result.extend([self.g_1_eval.clone(), self.g_a_eval.clone(), self.g_b_eval.clone(), self.g_c_eval.clone()]);
+        result
+    }
+
+    fn alloc_with<CS, FG1, FF>(
+        mut cs: CS,
+        proof: &Proof<E>,
+        mut alloc_g1: FG1,
+        mut alloc_fr: FF,
+    ) -> Result<Self, SynthesisError>
+    where
+        CS: ConstraintSystem<E::Fq>,
+        FG1: FnMut(&mut CS, String, &E::G1Affine) -> Result<PG::G1Gadget, SynthesisError>,
+        FF: FnMut(&mut CS, String, &E::Fr) -> Result<NonNativeFieldVar<E::Fr, E::Fq>, SynthesisError>,
+    {
+        // The gadget verifies the AHP of a single circuit.
+        if proof.num_circuits() != 1 {
+            return Err(anyhow!("Expected a proof of a single circuit, found {} circuits", proof.num_circuits()).into());
+        }
+        let comms = &proof.commitments;
+        let evals = &proof.evaluations;
+        let matrix_comms = &comms.matrix_commitments[0];
+        let matrix_evals = &evals.matrix_evals[0];
+        let sums = &proof.msg.sums[0];
+
+        let mut witness_commitments = Vec::with_capacity(comms.witness_commitments.len());
+        for (i, c) in comms.witness_commitments.iter().enumerate() {
+            witness_commitments.push(WitnessCommitmentsVar {
+                w: alloc_g1(&mut cs, format!("w_{}", i), &c.w.0)?,
+                z_a: alloc_g1(&mut cs, format!("z_a_{}", i), &c.z_a.0)?,
+                z_b: alloc_g1(&mut cs, format!("z_b_{}", i), &c.z_b.0)?,
+            });
+        }
+        let mask_poly = match &comms.mask_poly {
+            Some(c) => Some(alloc_g1(&mut cs, "mask_poly".into(), &c.0)?),
+            None => None,
+        };
+
+        let mut z_b_evals = Vec::with_capacity(evals.z_b_evals.len());
+        for (i, eval) in evals.z_b_evals.iter().enumerate() {
+            z_b_evals.push(alloc_fr(&mut cs, format!("z_b_eval_{}", i), eval)?);
+        }
+
+        let mut pc_proof = Vec::with_capacity(proof.pc_proof.proof.proofs().len());
+        for (i, p) in proof.pc_proof.proof.proofs().iter().enumerate() {
+            let w = alloc_g1(&mut cs, format!("pc_proof_w_{}", i), &p.w)?;
+            let random_v = match &p.random_v {
+                Some(v) => Some(alloc_fr(&mut cs, format!("pc_proof_random_v_{}", i), v)?),
+                None => None,
+            };
+            pc_proof.push(KZGProofVar { w, random_v });
+        }
+
+        Ok(Self {
+            batch_size: proof.batch_size(),
+            witness_commitments,
+            mask_poly,
+            g_1: alloc_g1(&mut cs, "g_1".into(), &comms.g_1.0)?,
+            h_1: alloc_g1(&mut cs, "h_1".into(), &comms.h_1.0)?,
+            g_a: alloc_g1(&mut cs, "g_a".into(), &matrix_comms.g_a.0)?,
+            g_b: alloc_g1(&mut cs, "g_b".into(), &matrix_comms.g_b.0)?,
+            g_c: alloc_g1(&mut cs, "g_c".into(), &matrix_comms.g_c.0)?,
+            h_2: alloc_g1(&mut cs, "h_2".into(), &comms.h_2.0)?,
+            z_b_evals,
+            g_1_eval: alloc_fr(&mut cs, "g_1_eval".into(), &evals.g_1_eval)?,
+            g_a_eval: alloc_fr(&mut cs, "g_a_eval".into(), &matrix_evals.g_a_eval)?,
+            g_b_eval: alloc_fr(&mut cs, "g_b_eval".into(), &matrix_evals.g_b_eval)?,
+            g_c_eval: alloc_fr(&mut cs, "g_c_eval".into(), &matrix_evals.g_c_eval)?,
+            msg: [
+                alloc_fr(&mut cs, "sum_a".into(), &sums.sum_a)?,
+                alloc_fr(&mut cs, "sum_b".into(), &sums.sum_b)?,
+                alloc_fr(&mut cs, "sum_c".into(), &sums.sum_c)?,
+            ],
+            pc_proof,
+        })
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<Proof<E>, E::Fq> for ProofVar<E, PG> {
+    fn alloc_constant<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Proof<E>>,
+    {
+        let proof = value_gen()?;
+        Self::alloc_with(
+            cs,
+            proof.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_constant(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| NonNativeFieldVar::alloc_constant(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+
+    fn alloc<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Proof<E>>,
+    {
+        let proof = value_gen()?;
+        Self::alloc_with(
+            cs,
+            proof.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_checked(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| NonNativeFieldVar::alloc(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<E::Fq>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Proof<E>>,
+    {
+        let proof = value_gen()?;
+        Self::alloc_with(
+            cs,
+            proof.borrow(),
+            |cs, name, value| PG::G1Gadget::alloc_input(cs.ns(|| name), || Ok(*value)),
+            |cs, name, value| NonNativeFieldVar::alloc_input(cs.ns(|| name), || Ok(*value)),
+        )
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    nonnative::{AllocatedNonNativeFieldVar, NonNativeFieldVar},
+    AlgebraicSpongeVar,
+    Boolean,
+    FieldGadget,
+    FpGadget,
+    ToBitsBEGadget,
+    UInt8,
+};
+use snarkvm_algorithms::{
+    overhead,
+    snark::marlin::params::{get_params, OptimizationType},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+use snarkvm_utilities::ToBits;
+
+use core::marker::PhantomData;
+
+/// The constraint counterpart of `FiatShamirAlgebraicSpongeRng`.
+///
+/// Every method mirrors the native method of the same name, so a transcript
+/// replayed in the constraint system squeezes exactly the same challenges
+/// as the native verifier.
+#[derive(Clone)]
+pub struct FiatShamirAlgebraicSpongeRngVar<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    S: DefaultCapacityAlgebraicSponge<BaseField, 6>,
+    SV: AlgebraicSpongeVar<BaseField, S, 6, 1>,
+> {
+    /// The algebraic sponge gadget.
+    pub(crate) s: SV,
+    #[doc(hidden)]
+    _phantom: PhantomData<(TargetField, BaseField, S)>,
+}
+
+impl<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    S: DefaultCapacityAlgebraicSponge<BaseField, 6>,
+    SV: AlgebraicSpongeVar<BaseField, S, 6, 1>,
+> FiatShamirAlgebraicSpongeRngVar<TargetField, BaseField, S, SV>
+{
+    /// Initializes the sponge gadget with the given parameters.
+    pub fn new_with_parameters<CS: ConstraintSystem<BaseField>>(cs: CS, parameters: &S::Parameters) -> Self {
+        Self { s: SV::with_parameters(cs, parameters), _phantom: PhantomData }
+    }
+
+    /// Takes in non-native field elements.
+    ///
+    /// The elements must be freshly allocated (or constant), as the limbs are absorbed as is.
+    pub fn absorb_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[NonNativeFieldVar<TargetField, BaseField>],
+        ty: OptimizationType,
+    ) -> Result<(), SynthesisError> {
+        let mut src_limbs = Vec::<(FpGadget<BaseField>, BaseField)>::new();
+
+        for elem in elems.iter() {
+            match elem {
+                NonNativeFieldVar::Constant(c) => {
+                    let limbs = AllocatedNonNativeFieldVar::<TargetField, BaseField>::get_limbs_representations(c, ty)?;
+                    for limb in limbs.into_iter() {
+                        // The native sponge always assumes one addition over the normal form.
+                        src_limbs.push((FpGadget::Constant(limb), BaseField::one()));
+                    }
+                }
+                NonNativeFieldVar::Var(v) => {
+                    for limb in v.limbs.iter() {
+                        src_limbs.push((limb.clone(), BaseField::one()));
+                    }
+                }
+            }
+        }
+
+        let dest_limbs = Self::compress_gadgets(cs.ns(|| "compress_limbs"), &src_limbs, ty)?;
+        self.s.absorb(cs.ns(|| "absorb"), dest_limbs.iter())
+    }
+
+    /// Takes in native field elements.
+    pub fn absorb_native_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        elems: &[FpGadget<BaseField>],
+    ) -> Result<(), SynthesisError> {
+        self.s.absorb(cs, elems.iter())
+    }
+
+    /// Takes in bytes.
+    pub fn absorb_bytes<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[UInt8],
+    ) -> Result<(), SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        // `UInt8` stores its bits in little-endian order, while the native sponge reads bytes big-endian.
+        let bits = elems.iter().flat_map(|elem| elem.bits.iter().rev().copied()).collect::<Vec<Boolean>>();
+
+        let mut elements = Vec::with_capacity((bits.len() + capacity - 1) / capacity);
+        for (i, chunk) in bits.chunks(capacity).enumerate() {
+            let mut le_bits = chunk.to_vec();
+            le_bits.reverse();
+            elements.push(Self::bits_le_to_fp(cs.ns(|| format!("bits_to_field_element_{}", i)), &le_bits)?);
+        }
+
+        self.s.absorb(cs.ns(|| "absorb"), elements.iter())
+    }
+
+    /// Takes out native field elements.
+    pub fn squeeze_native_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        self.s.squeeze(cs, num)
+    }
+
+    /// Takes out non-native field elements.
+    pub fn squeeze_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        self.get_gadgets_from_sponge(cs, num, false)
+    }
+
+    /// Takes out non-native field elements of 168 bits.
+    pub fn squeeze_short_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        self.get_gadgets_from_sponge(cs, num, true)
+    }
+
+    /// Takes out a non-native field element of 168 bits.
+    pub fn squeeze_short_nonnative_field_element<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+    ) -> Result<NonNativeFieldVar<TargetField, BaseField>, SynthesisError> {
+        self.get_gadgets_from_sponge(cs, 1, true).map(|mut v| v.remove(0))
+    }
+
+    /// Compress every two elements if possible, exactly as `FiatShamirAlgebraicSpongeRng::compress_elements` does.
+    /// Takes a vector of (limb, num_of_additions) pairs.
+    pub fn compress_gadgets<CS: ConstraintSystem<BaseField>>(
+        mut cs: CS,
+        src_limbs: &[(FpGadget<BaseField>, BaseField)],
+        ty: OptimizationType,
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        let mut dest_limbs = Vec::<FpGadget<BaseField>>::new();
+
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), ty);
+
+        let adjustment_factor_lookup_table = {
+            let mut table = Vec::<BaseField>::new();
+
+            let mut cur = BaseField::one();
+            for _ in 1..=capacity {
+                table.push(cur);
+                cur.double_in_place();
+            }
+
+            table
+        };
+
+        let mut i = 0;
+        let src_len = src_limbs.len();
+        while i < src_len {
+            let first = &src_limbs[i];
+            let second = if i + 1 < src_len { Some(&src_limbs[i + 1]) } else { None };
+
+            let first_max_bits_per_limb = params.bits_per_limb + overhead!(first.1 + BaseField::one());
+            let second_max_bits_per_limb = if let Some(second) = second {
+                params.bits_per_limb + overhead!(second.1 + BaseField::one())
+            } else {
+                0
+            };
+
+            if let Some(second) = second {
+                if first_max_bits_per_limb + second_max_bits_per_limb <= capacity {
+                    let adjustment_factor = &adjustment_factor_lookup_table[second_max_bits_per_limb];
+
+                    let shifted = first.0.mul_by_constant(cs.ns(|| format!("shift_limb_{}", i)), adjustment_factor)?;
+                    dest_limbs.push(shifted.add(cs.ns(|| format!("combine_limbs_{}", i)), &second.0)?);
+                    i += 2;
+                } else {
+                    dest_limbs.push(first.0.clone());
+                    i += 1;
+                }
+            } else {
+                dest_limbs.push(first.0.clone());
+                i += 1;
+            }
+        }
+
+        Ok(dest_limbs)
+    }
+
+    /// Obtain random bits from the hashchain, as `FiatShamirAlgebraicSpongeRng::get_bits_from_sponge` does.
+    pub fn get_bits_from_sponge<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let bits_per_element = BaseField::size_in_bits() - 1;
+        let num_elements = (num_bits + bits_per_element - 1) / bits_per_element;
+
+        let src_elements = self.s.squeeze(cs.ns(|| "squeeze"), num_elements)?;
+        let mut dest_bits = Vec::<Boolean>::with_capacity(num_elements * bits_per_element);
+
+        for (i, elem) in src_elements.iter().enumerate() {
+            match elem {
+                FpGadget::Constant(c) => {
+                    // Skip the padding of the representation, and the highest bit.
+                    let skip = (BaseField::Parameters::REPR_SHAVE_BITS + 1) as usize;
+                    dest_bits.extend(c.to_repr().to_bits_be()[skip..].iter().map(|b| Boolean::constant(*b)));
+                }
+                FpGadget::Variable(_) => {
+                    // Discard the highest bit.
+                    let elem_bits = elem.to_bits_be_strict(cs.ns(|| format!("element_to_bits_{}", i)))?;
+                    dest_bits.extend_from_slice(&elem_bits[1..]);
+                }
+            }
+        }
+        dest_bits.truncate(num_bits);
+
+        Ok(dest_bits)
+    }
+
+    /// Obtain random non-native elements from the hashchain,
+    /// as `FiatShamirAlgebraicSpongeRng::get_elements_from_sponge` does.
+    /// The limbs of each element are linear combinations of the squeezed bits, so no reduction is needed.
+    pub fn get_gadgets_from_sponge<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num_elements: usize,
+        outputs_short_elements: bool,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        let num_bits_per_nonnative = if outputs_short_elements {
+            168
+        } else {
+            TargetField::size_in_bits() - 1 // also omit the highest bit
+        };
+        let bits = self.get_bits_from_sponge(cs.ns(|| "get_bits"), num_bits_per_nonnative * num_elements)?;
+
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), OptimizationType::Weight);
+
+        let mut dest_gadgets = Vec::with_capacity(num_elements);
+        for (i, per_nonnative_bits) in bits.chunks_exact(num_bits_per_nonnative).enumerate() {
+            let mut cs = cs.ns(|| format!("element_{}", i));
+
+            // The native sponge reads each chunk as a big-endian integer.
+            let mut le_bits = per_nonnative_bits.to_vec();
+            le_bits.reverse();
+
+            // Push the lower limbs first.
+            let mut limbs = Vec::with_capacity(params.num_limbs);
+            for (j, limb_bits) in le_bits.chunks(params.bits_per_limb).enumerate() {
+                limbs.push(Self::bits_le_to_fp(cs.ns(|| format!("limb_{}", j)), limb_bits)?);
+            }
+            while limbs.len() < params.num_limbs {
+                limbs.push(FpGadget::Constant(BaseField::zero()));
+            }
+            // Then reverse, so that the limbs are ``big limb first''.
+            limbs.reverse();
+
+            dest_gadgets.push(NonNativeFieldVar::Var(AllocatedNonNativeFieldVar {
+                limbs,
+                num_of_additions_over_normal_form: BaseField::zero(),
+                is_in_the_normal_form: true,
+                target_phantom: PhantomData,
+            }));
+        }
+        debug_assert_eq!(dest_gadgets.len(), num_elements);
+
+        Ok(dest_gadgets)
+    }
+
+    /// Packs little-endian bits into a linear combination, without allocating new variables.
+    fn bits_le_to_fp<CS: ConstraintSystem<BaseField>>(
+        mut cs: CS,
+        bits: &[Boolean],
+    ) -> Result<FpGadget<BaseField>, SynthesisError> {
+        // `FpGadget::zero` is a variable, so that adding constants conditioned on allocated bits keeps the bits bound.
+        let mut result = FpGadget::<BaseField>::zero(cs.ns(|| "zero"))?;
+        let mut power = BaseField::one();
+        for (i, bit) in bits.iter().enumerate() {
+            result = result.conditionally_add_constant(cs.ns(|| format!("add_bit_{}", i)), bit, power)?;
+            power.double_in_place();
+        }
+        Ok(result)
+    }
+}
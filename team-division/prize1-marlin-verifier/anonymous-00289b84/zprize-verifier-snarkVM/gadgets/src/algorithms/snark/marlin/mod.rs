@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod ahp;
+pub use ahp::*;
+
+pub mod data_structures;
+pub use data_structures::*;
+
+pub mod fiat_shamir;
+pub use fiat_shamir::*;
+
+pub mod polycommit;
+pub use polycommit::*;
+
+pub mod verifier;
+pub use verifier::*;
+
+#[cfg(test)]
+pub mod tests;
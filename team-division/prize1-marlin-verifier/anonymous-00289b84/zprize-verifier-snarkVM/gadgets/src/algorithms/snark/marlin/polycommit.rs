@@ -0,0 +1,376 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::{FiatShamirAlgebraicSpongeRngVar, KZGProofVar, VerifierKeyVar},
+    nonnative::NonNativeFieldVar,
+    traits::curves::PairingGadget,
+    AlgebraicSpongeVar,
+    AllocGadget,
+    Boolean,
+    EqGadget,
+    FieldGadget,
+    GroupGadget,
+    ToBitsLEGadget,
+    ToConstraintFieldGadget,
+};
+use snarkvm_algorithms::{
+    polycommit::sonic_pc::LCTerm,
+    snark::marlin::params::OptimizationType,
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_curves::{AffineCurve, PairingEngine};
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The seed from which the offset point of the in-circuit multi-scalar multiplications is derived.
+const OFFSET_SEED: u64 = 0x4d41_524c_494e_3139;
+
+/// A labeled commitment, allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct LabeledCommitmentVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The label of the committed polynomial.
+    pub label: String,
+    /// The commitment.
+    pub commitment: PG::G1Gadget,
+    /// The degree bound enforced on the committed polynomial, if any.
+    pub degree_bound: Option<usize>,
+}
+
+/// A linear combination of polynomials, with coefficients allocated in the constraint system.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct LinearCombinationVar<E: PairingEngine> {
+    /// The label of the linear combination.
+    pub label: String,
+    /// The terms of the linear combination.
+    pub terms: Vec<(NonNativeFieldVar<E::Fr, E::Fq>, LCTerm)>,
+}
+
+/// A commitment that has not been combined yet, represented as a list of `(base, scalar)` terms.
+type PendingCommitment<E, PG> = (
+    Vec<(<PG as PairingGadget<E>>::G1Gadget, NonNativeFieldVar<<E as PairingEngine>::Fr, <E as PairingEngine>::Fq>)>,
+    Option<usize>,
+);
+
+/// The SonicKZG10 verifier, as a gadget.
+///
+/// The group gadgets use incomplete addition formulas, so every multi-scalar multiplication is
+/// accumulated on top of a fixed offset point of unknown discrete logarithm, which is removed at the end.
+/// As a consequence, commitments at infinity are not supported.
+pub struct SonicKZG10Gadget<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+> {
+    _engine: PhantomData<(E, PG, S, SV)>,
+}
+
+impl<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+> SonicKZG10Gadget<E, PG, S, SV>
+{
+    /// Checks that `evaluations` are the true evaluations at `query_set` of the
+    /// linear combinations of the polynomials committed in `commitments`.
+    ///
+    /// The query set maps each linear combination label to the name and value of its query point.
+    /// Returns a `Boolean` that is true if and only if the check passes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_combinations<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        verifier_key: &VerifierKeyVar<E, PG>,
+        linear_combinations: &[LinearCombinationVar<E>],
+        commitments: &[LabeledCommitmentVar<E, PG>],
+        query_set: &BTreeMap<String, (String, NonNativeFieldVar<E::Fr, E::Fq>)>,
+        evaluations: &BTreeMap<String, NonNativeFieldVar<E::Fr, E::Fq>>,
+        proof: &[KZGProofVar<E, PG>],
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+        fs_parameters: &S::Parameters,
+    ) -> Result<Boolean, SynthesisError> {
+        let label_comm_map = commitments.iter().map(|c| (c.label.as_str(), c)).collect::<BTreeMap<_, _>>();
+
+        let mut evaluations = evaluations.clone();
+        let mut lc_commitments = BTreeMap::new();
+
+        for (i, lc) in linear_combinations.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("linear_combination_{}", i));
+            let num_polys = lc.terms.len();
+
+            let mut degree_bound = None;
+            let mut terms = Vec::with_capacity(num_polys);
+            for (j, (coeff, term)) in lc.terms.iter().enumerate() {
+                match term {
+                    LCTerm::One => {
+                        if let Some(eval) = evaluations.get_mut(&lc.label) {
+                            *eval = eval.sub(cs.ns(|| format!("subtract_constant_term_{}", j)), coeff)?;
+                        }
+                    }
+                    LCTerm::PolyLabel(label) => {
+                        let comm = label_comm_map
+                            .get(label.as_str())
+                            .ok_or_else(|| anyhow!("Missing commitment for polynomial {}", label))?;
+                        if comm.degree_bound.is_some() {
+                            if num_polys != 1 {
+                                return Err(anyhow!("Linear combination {} has a degree-bounded term", lc.label).into());
+                            }
+                            degree_bound = comm.degree_bound;
+                        }
+                        terms.push((comm.commitment.clone(), coeff.clone()));
+                    }
+                }
+            }
+            lc_commitments.insert(lc.label.clone(), (terms, degree_bound));
+        }
+
+        Self::batch_check_pending(
+            cs.ns(|| "batch_check"),
+            verifier_key,
+            &lc_commitments,
+            query_set,
+            &evaluations,
+            proof,
+            fs_rng,
+            fs_parameters,
+        )
+    }
+
+    /// Checks that `evaluations` are the true evaluations at `query_set` of the polynomials committed in `commitments`.
+    ///
+    /// The query set maps each polynomial label to the name and value of its query point.
+    /// Returns a `Boolean` that is true if and only if the check passes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_check<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        verifier_key: &VerifierKeyVar<E, PG>,
+        commitments: &[LabeledCommitmentVar<E, PG>],
+        query_set: &BTreeMap<String, (String, NonNativeFieldVar<E::Fr, E::Fq>)>,
+        evaluations: &BTreeMap<String, NonNativeFieldVar<E::Fr, E::Fq>>,
+        proof: &[KZGProofVar<E, PG>],
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+        fs_parameters: &S::Parameters,
+    ) -> Result<Boolean, SynthesisError> {
+        let commitments = commitments
+            .iter()
+            .map(|c| {
+                let one = NonNativeFieldVar::Constant(E::Fr::one());
+                (c.label.clone(), (vec![(c.commitment.clone(), one)], c.degree_bound))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Self::batch_check_pending(cs, verifier_key, &commitments, query_set, evaluations, proof, fs_rng, fs_parameters)
+    }
+
+    /// Mirrors `SonicKZG10::batch_check_elems`, on commitments that are not combined yet.
+    ///
+    /// Folding the linear combination coefficients into the batching scalars
+    /// lets the whole check use a single multi-scalar multiplication per degree bound.
+    #[allow(clippy::too_many_arguments)]
+    fn batch_check_pending<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        verifier_key: &VerifierKeyVar<E, PG>,
+        commitments: &BTreeMap<String, PendingCommitment<E, PG>>,
+        query_set: &BTreeMap<String, (String, NonNativeFieldVar<E::Fr, E::Fq>)>,
+        evaluations: &BTreeMap<String, NonNativeFieldVar<E::Fr, E::Fq>>,
+        proof: &[KZGProofVar<E, PG>],
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+        fs_parameters: &S::Parameters,
+    ) -> Result<Boolean, SynthesisError> {
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+        if proof.len() != query_to_labels_map.len() {
+            let num_points = query_to_labels_map.len();
+            return Err(anyhow!("Expected {} evaluation proofs, found {}", num_points, proof.len()).into());
+        }
+
+        // The batching randomizers are derived from a fresh sponge over the entire claim, as natively.
+        let mut batch_fs_rng = FiatShamirAlgebraicSpongeRngVar::<E::Fr, E::Fq, S, SV>::new_with_parameters(
+            cs.ns(|| "batch_fs_rng"),
+            fs_parameters,
+        );
+        let points = query_set.values().map(|(_, point)| point.clone()).collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative_field_elements(
+            cs.ns(|| "absorb_query_set"),
+            &points,
+            OptimizationType::Weight,
+        )?;
+        let values = evaluations.values().cloned().collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative_field_elements(
+            cs.ns(|| "absorb_evaluations"),
+            &values,
+            OptimizationType::Weight,
+        )?;
+        for (i, p) in proof.iter().enumerate() {
+            let w = p.w.to_constraint_field(cs.ns(|| format!("proof_{}_to_constraint_field", i)))?;
+            batch_fs_rng.absorb_native_field_elements(cs.ns(|| format!("absorb_proof_{}", i)), &w)?;
+            if let Some(random_v) = &p.random_v {
+                batch_fs_rng.absorb_nonnative_field_elements(
+                    cs.ns(|| format!("absorb_random_v_{}", i)),
+                    &[random_v.clone()],
+                    OptimizationType::Weight,
+                )?;
+            }
+        }
+
+        let mut randomizer = NonNativeFieldVar::Constant(E::Fr::one());
+        let mut combined_comms = BTreeMap::<Option<usize>, Vec<_>>::new();
+        let mut combined_witness = Vec::with_capacity(proof.len());
+        let mut adjusted_witness = Vec::with_capacity(proof.len() + 2);
+        let mut g_multiplier = NonNativeFieldVar::Constant(E::Fr::zero());
+        let mut gamma_g_multiplier = NonNativeFieldVar::Constant(E::Fr::zero());
+
+        let num_points = query_to_labels_map.len();
+        for (i, ((_, (point, labels)), p)) in query_to_labels_map.into_iter().zip(proof).enumerate() {
+            let mut cs = cs.ns(|| format!("query_point_{}", i));
+
+            let mut combined_value = NonNativeFieldVar::Constant(E::Fr::zero());
+            for (j, label) in labels.into_iter().enumerate() {
+                let mut cs = cs.ns(|| format!("label_{}", j));
+                let (terms, degree_bound) =
+                    commitments.get(label).ok_or_else(|| anyhow!("Missing commitment for {}", label))?;
+                let value = evaluations.get(label).ok_or_else(|| anyhow!("Missing evaluation for {}", label))?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "squeeze_challenge"))?;
+                let challenge_value = value.mul(cs.ns(|| "value_times_challenge"), &challenge)?;
+                combined_value = combined_value.add(cs.ns(|| "accumulate_value"), &challenge_value)?;
+
+                let multiplier = Self::mul(cs.ns(|| "challenge_times_randomizer"), &challenge, &randomizer)?;
+                let entry = combined_comms.entry(*degree_bound).or_default();
+                for (k, (base, coeff)) in terms.iter().enumerate() {
+                    let scalar = Self::mul(cs.ns(|| format!("scale_term_{}", k)), coeff, &multiplier)?;
+                    entry.push((base.clone(), scalar));
+                }
+            }
+
+            let value_multiplier = Self::mul(cs.ns(|| "value_times_randomizer"), &combined_value, &randomizer)?;
+            g_multiplier = g_multiplier.add(cs.ns(|| "accumulate_g_multiplier"), &value_multiplier)?;
+            if let Some(random_v) = &p.random_v {
+                let v_multiplier = Self::mul(cs.ns(|| "random_v_times_randomizer"), random_v, &randomizer)?;
+                gamma_g_multiplier =
+                    gamma_g_multiplier.add(cs.ns(|| "accumulate_gamma_g_multiplier"), &v_multiplier)?;
+            }
+            let point_multiplier = Self::mul(cs.ns(|| "point_times_randomizer"), point, &randomizer)?;
+            adjusted_witness.push((p.w.clone(), point_multiplier.negate(cs.ns(|| "negate_point_multiplier"))?));
+            combined_witness.push((p.w.clone(), randomizer.clone()));
+
+            // The native verifier squeezes one more randomizer after the last point, which is never used.
+            if i + 1 != num_points {
+                randomizer = batch_fs_rng.squeeze_short_nonnative_field_element(cs.ns(|| "squeeze_randomizer"))?;
+            }
+        }
+
+        adjusted_witness.push((verifier_key.g.clone(), g_multiplier));
+        if proof.iter().any(|p| p.random_v.is_some()) {
+            adjusted_witness.push((verifier_key.gamma_g.clone(), gamma_g_multiplier));
+        }
+
+        let mut g1_elements = Vec::with_capacity(combined_comms.len() + 2);
+        let mut g2_elements = Vec::with_capacity(combined_comms.len() + 2);
+        for (i, (degree_bound, terms)) in combined_comms.into_iter().enumerate() {
+            let shift_power = match degree_bound {
+                Some(d) => verifier_key.get_shift_power(d).ok_or_else(|| anyhow!("Unsupported degree bound {}", d))?,
+                None => &verifier_key.h,
+            };
+            g1_elements.push(Self::msm(cs.ns(|| format!("combine_commitments_{}", i)), &terms)?);
+            g2_elements.push(shift_power.clone());
+        }
+        let adjusted_witness = Self::msm(cs.ns(|| "adjusted_witness"), &adjusted_witness)?;
+        g1_elements.push(adjusted_witness.negate(cs.ns(|| "negate_adjusted_witness"))?);
+        g2_elements.push(verifier_key.h.clone());
+        let combined_witness = Self::msm(cs.ns(|| "combined_witness"), &combined_witness)?;
+        g1_elements.push(combined_witness.negate(cs.ns(|| "negate_combined_witness"))?);
+        g2_elements.push(verifier_key.beta_h.clone());
+
+        let mut g1_prepared = Vec::with_capacity(g1_elements.len());
+        for (i, g1) in g1_elements.into_iter().enumerate() {
+            g1_prepared.push(PG::prepare_g1(cs.ns(|| format!("prepare_g1_{}", i)), g1)?);
+        }
+        let mut g2_prepared = Vec::with_capacity(g2_elements.len());
+        for (i, g2) in g2_elements.into_iter().enumerate() {
+            g2_prepared.push(PG::prepare_g2(cs.ns(|| format!("prepare_g2_{}", i)), g2)?);
+        }
+
+        let result = PG::product_of_pairings(cs.ns(|| "product_of_pairings"), &g1_prepared, &g2_prepared)?;
+        let one = PG::GTGadget::one(cs.ns(|| "gt_one"))?;
+        result.is_eq(cs.ns(|| "is_one"), &one)
+    }
+
+    /// Multiplies two scalars, skipping the multiplication if either one is the constant one.
+    fn mul<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        a: &NonNativeFieldVar<E::Fr, E::Fq>,
+        b: &NonNativeFieldVar<E::Fr, E::Fq>,
+    ) -> Result<NonNativeFieldVar<E::Fr, E::Fq>, SynthesisError> {
+        match (a, b) {
+            (NonNativeFieldVar::Constant(c), other) | (other, NonNativeFieldVar::Constant(c)) if c.is_one() => {
+                Ok(other.clone())
+            }
+            _ => a.mul(cs, b),
+        }
+    }
+
+    /// Returns the offset point, a deterministic point of unknown discrete logarithm.
+    fn offset() -> E::G1Affine {
+        let mut x = E::Fq::from(OFFSET_SEED);
+        loop {
+            if let Some(point) = E::G1Affine::from_x_coordinate(x, true) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    return point;
+                }
+            }
+            x += E::Fq::one();
+        }
+    }
+
+    /// Computes `\sum_i scalar_i * base_i`.
+    fn msm<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        bases_and_scalars: &[(PG::G1Gadget, NonNativeFieldVar<E::Fr, E::Fq>)],
+    ) -> Result<PG::G1Gadget, SynthesisError> {
+        if let [(base, NonNativeFieldVar::Constant(c))] = bases_and_scalars {
+            if c.is_one() {
+                return Ok(base.clone());
+            }
+        }
+
+        let offset = Self::offset();
+        let mut result = PG::G1Gadget::alloc_constant(cs.ns(|| "offset"), || Ok(offset))?;
+        for (i, (base, scalar)) in bases_and_scalars.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("term_{}", i));
+            result = match scalar {
+                NonNativeFieldVar::Constant(c) if c.is_zero() => continue,
+                NonNativeFieldVar::Constant(c) if c.is_one() => result.add(cs.ns(|| "add"), base)?,
+                _ => {
+                    let bits = scalar.to_bits_le(cs.ns(|| "scalar_to_bits"))?;
+                    base.mul_bits(cs.ns(|| "mul_bits"), &result, bits.into_iter())?
+                }
+            };
+        }
+        result.sub_constant(cs.ns(|| "remove_offset"), &offset)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::{
+        crypto_hash::PoseidonSpongeGadget,
+        snark::marlin::{CircuitVerifyingKeyVar, FiatShamirAlgebraicSpongeRngVar, MarlinVerificationGadget, ProofVar},
+    },
+    curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
+    nonnative::NonNativeFieldVar,
+    AllocGadget,
+    FieldGadget,
+    FpGadget,
+    UInt8,
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    snark::marlin::{
+        params::OptimizationType,
+        FiatShamirAlgebraicSpongeRng,
+        FiatShamirRng,
+        MarlinHidingMode,
+        MarlinMode,
+        MarlinNonHidingMode,
+        MarlinSNARK,
+    },
+    traits::DefaultCapacityAlgebraicSponge,
+    SNARK,
+    SRS,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, TestConstraintSystem};
+use snarkvm_utilities::{test_crypto_rng, test_rng, Uniform};
+
+use core::ops::MulAssign;
+
+type Sponge = PoseidonSponge<Fq, 6, 1>;
+type SpongeVar = PoseidonSpongeGadget<Fq, 6, 1>;
+type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, Sponge>;
+type FSVar = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, Sponge, SpongeVar>;
+type PG = Bls12_377PairingGadget;
+
+#[derive(Copy, Clone)]
+struct Circuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+    num_constraints: usize,
+    num_variables: usize,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<ConstraintF> {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || {
+                let mut a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+
+                a.mul_assign(&b);
+                Ok(a)
+            },
+        )?;
+
+        for i in 0..(self.num_variables - 3) {
+            let _ = cs.alloc(|| format!("var {}", i), || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        }
+
+        for i in 0..(self.num_constraints - 1) {
+            cs.enforce(|| format!("constraint {}", i), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn fiat_shamir_transcript_test() {
+    let mut rng = test_rng();
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let bytes = b"MARLIN-2019";
+    let native = (0..5).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+    let nonnative = (0..7).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+    let native_var = native
+        .iter()
+        .enumerate()
+        .map(|(i, v)| FpGadget::alloc(cs.ns(|| format!("alloc_native_{}", i)), || Ok(*v)).unwrap())
+        .collect::<Vec<_>>();
+    let nonnative_var = nonnative
+        .iter()
+        .enumerate()
+        .map(|(i, v)| NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_nonnative_{}", i)), || Ok(*v)).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut fs_rng = FS::new();
+    let mut fs_rng_var = FSVar::new_with_parameters(cs.ns(|| "fs_rng"), &FS::parameters());
+
+    fs_rng.absorb_bytes(bytes);
+    fs_rng.absorb_native_field_elements(&native);
+    fs_rng.absorb_nonnative_field_elements(nonnative.iter().copied(), OptimizationType::Weight);
+    fs_rng_var.absorb_bytes(cs.ns(|| "absorb_bytes"), &UInt8::constant_vec(bytes)).unwrap();
+    fs_rng_var.absorb_native_field_elements(cs.ns(|| "absorb_native"), &native_var).unwrap();
+    fs_rng_var
+        .absorb_nonnative_field_elements(cs.ns(|| "absorb_nonnative"), &nonnative_var, OptimizationType::Weight)
+        .unwrap();
+
+    let expected = fs_rng.squeeze_nonnative_field_elements(9, OptimizationType::Weight).unwrap();
+    let candidate = fs_rng_var.squeeze_nonnative_field_elements(cs.ns(|| "squeeze"), 9).unwrap();
+    for (expected, candidate) in expected.iter().zip(&candidate) {
+        assert_eq!(*expected, candidate.value().unwrap());
+    }
+
+    let expected = fs_rng.squeeze_short_nonnative_field_elements(3).unwrap();
+    let candidate = fs_rng_var.squeeze_short_nonnative_field_elements(cs.ns(|| "squeeze_short"), 3).unwrap();
+    for (expected, candidate) in expected.iter().zip(&candidate) {
+        assert_eq!(*expected, candidate.value().unwrap());
+    }
+
+    let expected = fs_rng.squeeze_native_field_elements(2).unwrap();
+    let candidate = fs_rng_var.squeeze_native_field_elements(cs.ns(|| "squeeze_native"), 2).unwrap();
+    for (expected, candidate) in expected.iter().zip(&candidate) {
+        assert_eq!(*expected, candidate.get_value().unwrap());
+    }
+
+    assert!(cs.is_satisfied());
+}
+
+macro_rules! impl_verification_test {
+    ($test_name:ident, $mode:ty) => {
+        #[test]
+        fn $test_name() {
+            type TestSNARK = MarlinSNARK<Bls12_377, FS, $mode, Vec<Fr>>;
+            type VerificationGadget = MarlinVerificationGadget<Bls12_377, PG, Sponge, SpongeVar, $mode>;
+
+            let mut rng = test_crypto_rng();
+
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            let mut c = a;
+            c.mul_assign(&b);
+
+            let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 20, num_variables: 10 };
+            let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+            let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+            assert_eq!(proof.pc_proof.is_hiding(), <$mode as MarlinMode>::ZK);
+            assert!(TestSNARK::verify(&vk, &vec![c], &proof).unwrap());
+
+            let fs_parameters = Sponge::sample_parameters();
+            for (input, expected) in [(c, true), (a, false)] {
+                let mut cs = TestConstraintSystem::<Fq>::new();
+
+                let vk_var = CircuitVerifyingKeyVar::<_, PG, $mode>::alloc(cs.ns(|| "vk"), || Ok(&vk)).unwrap();
+                let proof_var = ProofVar::<_, PG>::alloc(cs.ns(|| "proof"), || Ok(&proof)).unwrap();
+                let input_var = NonNativeFieldVar::alloc_input(cs.ns(|| "input"), || Ok(input)).unwrap();
+
+                let result = VerificationGadget::verify(
+                    cs.ns(|| "verify"),
+                    &fs_parameters,
+                    &vk_var,
+                    &[vec![input_var]],
+                    &proof_var,
+                )
+                .unwrap();
+
+                assert_eq!(result.get_value(), Some(expected));
+                assert!(cs.is_satisfied(), "Unsatisfied constraint: {:?}", cs.which_is_unsatisfied());
+            }
+
+            // A proof for a different batch size is rejected rather than failing synthesis.
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let vk_var = CircuitVerifyingKeyVar::<_, PG, $mode>::alloc(cs.ns(|| "vk"), || Ok(&vk)).unwrap();
+            let proof_var = ProofVar::<_, PG>::alloc(cs.ns(|| "proof"), || Ok(&proof)).unwrap();
+            let input_var = NonNativeFieldVar::alloc_input(cs.ns(|| "input"), || Ok(c)).unwrap();
+            let inputs = [vec![input_var.clone()], vec![input_var]];
+            let result =
+                VerificationGadget::verify(cs.ns(|| "verify"), &fs_parameters, &vk_var, &inputs, &proof_var).unwrap();
+            assert_eq!(result.get_value(), Some(false));
+        }
+    };
+}
+
+impl_verification_test!(marlin_verification_gadget_test, MarlinNonHidingMode);
+impl_verification_test!(marlin_verification_gadget_hiding_test, MarlinHidingMode);
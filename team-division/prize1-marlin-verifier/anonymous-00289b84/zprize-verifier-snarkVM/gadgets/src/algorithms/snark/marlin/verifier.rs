@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::snark::marlin::{
+        AHPForR1CSGadget,
+        CircuitVerifyingKeyVar,
+        FiatShamirAlgebraicSpongeRngVar,
+        LabeledCommitmentVar,
+        ProofVar,
+        SonicKZG10Gadget,
+        VerifierStateVar,
+    },
+    nonnative::NonNativeFieldVar,
+    traits::curves::PairingGadget,
+    AlgebraicSpongeVar,
+    Boolean,
+    FpGadget,
+    ToConstraintFieldGadget,
+    UInt8,
+};
+use snarkvm_algorithms::{
+    fft::EvaluationDomain,
+    snark::marlin::{params::OptimizationType, witness_label, AHPForR1CS, MarlinMode},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+/// The Marlin verifier, as a gadget.
+///
+/// The verification circuit replays the Fiat-Shamir transcript of the native
+/// `MarlinSNARK` verifier, so it accepts exactly the proofs that are produced
+/// with a `FiatShamirAlgebraicSpongeRng` over the same sponge and parameters.
+pub struct MarlinVerificationGadget<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+    MM: MarlinMode,
+> {
+    _engine: PhantomData<(E, PG, S, SV, MM)>,
+}
+
+impl<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    S: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    SV: AlgebraicSpongeVar<E::Fq, S, 6, 1>,
+    MM: MarlinMode,
+> MarlinVerificationGadget<E, PG, S, SV, MM>
+{
+    /// The personalization string of the native Marlin transcript.
+    pub const PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019";
+
+    /// Verifies `proof` for a batch of `public_inputs` in the constraint system.
+    ///
+    /// The public inputs are unformatted, i.e. they do not include the constant one,
+    /// and must be in their normal form (as they are when freshly allocated).
+    /// Returns a `Boolean` that is true if and only if the proof is valid. A proof for a different
+    /// number of instances, or in a different Marlin mode, is rejected with the constant `false`.
+    pub fn verify<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        fs_parameters: &S::Parameters,
+        circuit_verifying_key: &CircuitVerifyingKeyVar<E, PG, MM>,
+        public_inputs: &[Vec<NonNativeFieldVar<E::Fr, E::Fq>>],
+        proof: &ProofVar<E, PG>,
+    ) -> Result<Boolean, SynthesisError> {
+        let batch_size = public_inputs.len();
+        if batch_size == 0 || proof.batch_size != batch_size {
+            return Ok(Boolean::constant(false));
+        }
+        let proof_has_correct_zk_mode = match MM::ZK {
+            true => proof.mask_poly.is_some() && proof.pc_proof.iter().any(|p| p.random_v.is_some()),
+            false => proof.mask_poly.is_none() && proof.pc_proof.iter().all(|p| p.random_v.is_none()),
+        };
+        if !proof_has_correct_zk_mode {
+            return Ok(Boolean::constant(false));
+        }
+
+        // Format and pad the public inputs, as the native verifier does.
+        let input_domain = EvaluationDomain::<E::Fr>::new(circuit_verifying_key.num_public_inputs)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let mut padded_public_inputs = Vec::with_capacity(batch_size);
+        for input in public_inputs {
+            if input.len() >= circuit_verifying_key.num_public_inputs {
+                return Err(SynthesisError::MalformedVerifyingKey(
+                    input.len(),
+                    circuit_verifying_key.num_public_inputs - 1,
+                ));
+            }
+            let mut new_input = vec![NonNativeFieldVar::Constant(E::Fr::one())];
+            new_input.extend_from_slice(input);
+            new_input.resize(input_domain.size(), NonNativeFieldVar::Constant(E::Fr::zero()));
+            padded_public_inputs.push(new_input);
+        }
+
+        let mut fs_rng = FiatShamirAlgebraicSpongeRngVar::<E::Fr, E::Fq, S, SV>::new_with_parameters(
+            cs.ns(|| "fs_rng"),
+            fs_parameters,
+        );
+        fs_rng.absorb_bytes(cs.ns(|| "absorb_protocol_name"), &UInt8::constant_vec(Self::PROTOCOL_NAME))?;
+        fs_rng.absorb_bytes(cs.ns(|| "absorb_batch_size"), &UInt8::constant_vec(&batch_size.to_le_bytes()))?;
+        Self::absorb_commitments(
+            cs.ns(|| "absorb_circuit_commitments"),
+            &circuit_verifying_key.circuit_commitments,
+            &mut fs_rng,
+        )?;
+        for (i, input) in padded_public_inputs.iter().enumerate() {
+            fs_rng.absorb_nonnative_field_elements(
+                cs.ns(|| format!("absorb_public_input_{}", i)),
+                input,
+                OptimizationType::Weight,
+            )?;
+        }
+
+        // --------------------------------------------------------------------
+        // First round
+        let mut first_commitments = Vec::with_capacity(3 * batch_size + 1);
+        for (i, c) in proof.witness_commitments.iter().enumerate() {
+            first_commitments.push(Self::labeled(witness_label("w", i), &c.w, None));
+            first_commitments.push(Self::labeled(witness_label("z_a", i), &c.z_a, None));
+            first_commitments.push(Self::labeled(witness_label("z_b", i), &c.z_b, None));
+        }
+        if let Some(mask_poly) = &proof.mask_poly {
+            first_commitments.push(Self::labeled("mask_poly".into(), mask_poly, None));
+        }
+        Self::absorb_labeled(cs.ns(|| "absorb_first_commitments"), &first_commitments, &mut fs_rng)?;
+        let first_round_message = AHPForR1CSGadget::<E, PG, S, SV, MM>::verifier_first_round(
+            cs.ns(|| "first_round"),
+            batch_size,
+            &mut fs_rng,
+        )?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        let constraint_domain_size = circuit_verifying_key.constraint_domain.size();
+        let second_commitments = [
+            Self::labeled("g_1".into(), &proof.g_1, Some(constraint_domain_size - 2)),
+            Self::labeled("h_1".into(), &proof.h_1, None),
+        ];
+        Self::absorb_labeled(cs.ns(|| "absorb_second_commitments"), &second_commitments, &mut fs_rng)?;
+        let second_round_message =
+            AHPForR1CSGadget::<E, PG, S, SV, MM>::verifier_second_round(cs.ns(|| "second_round"), &mut fs_rng)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        let third_commitments = [
+            Self::labeled("g_a".into(), &proof.g_a, Some(circuit_verifying_key.non_zero_a_domain.size() - 2)),
+            Self::labeled("g_b".into(), &proof.g_b, Some(circuit_verifying_key.non_zero_b_domain.size() - 2)),
+            Self::labeled("g_c".into(), &proof.g_c, Some(circuit_verifying_key.non_zero_c_domain.size() - 2)),
+        ];
+        Self::absorb_labeled(cs.ns(|| "absorb_third_commitments"), &third_commitments, &mut fs_rng)?;
+        fs_rng.absorb_nonnative_field_elements(cs.ns(|| "absorb_sums"), &proof.msg, OptimizationType::Weight)?;
+        let third_round_message =
+            AHPForR1CSGadget::<E, PG, S, SV, MM>::verifier_third_round(cs.ns(|| "third_round"), &mut fs_rng)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        let fourth_commitments = [Self::labeled("h_2".into(), &proof.h_2, None)];
+        Self::absorb_labeled(cs.ns(|| "absorb_fourth_commitments"), &fourth_commitments, &mut fs_rng)?;
+        let gamma = AHPForR1CSGadget::<E, PG, S, SV, MM>::verifier_fourth_round(cs.ns(|| "fourth_round"), &mut fs_rng)?;
+        // --------------------------------------------------------------------
+
+        // Gather commitments in one vector. Indexed polynomials have *no* degree bounds.
+        let index_labels = AHPForR1CS::<E::Fr, MM>::index_polynomial_info().into_keys().collect::<Vec<_>>();
+        if index_labels.len() != circuit_verifying_key.circuit_commitments.len() {
+            return Err(anyhow!("Expected {} circuit commitments", index_labels.len()).into());
+        }
+        let commitments = index_labels
+            .into_iter()
+            .zip(&circuit_verifying_key.circuit_commitments)
+            .map(|(label, c)| Self::labeled(label, c, None))
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
+            .chain(fourth_commitments)
+            .collect::<Vec<_>>();
+
+        let beta = &second_round_message.beta;
+        let mut query_set = BTreeMap::new();
+        for i in 0..batch_size {
+            query_set.insert(witness_label("z_b", i), ("beta".to_string(), beta.clone()));
+        }
+        for label in ["g_1", "lincheck_sumcheck"] {
+            query_set.insert(label.to_string(), ("beta".to_string(), beta.clone()));
+        }
+        for label in ["g_a", "g_b", "g_c", "matrix_sumcheck"] {
+            query_set.insert(label.to_string(), ("gamma".to_string(), gamma.clone()));
+        }
+
+        fs_rng.absorb_nonnative_field_elements(
+            cs.ns(|| "absorb_evaluations"),
+            &proof.evaluations(),
+            OptimizationType::Weight,
+        )?;
+
+        let mut evaluations = BTreeMap::new();
+        for (i, eval) in proof.z_b_evals.iter().enumerate() {
+            evaluations.insert(witness_label("z_b", i), eval.clone());
+        }
+        evaluations.insert("g_1".to_string(), proof.g_1_eval.clone());
+        evaluations.insert("g_a".to_string(), proof.g_a_eval.clone());
+        evaluations.insert("g_b".to_string(), proof.g_b_eval.clone());
+        evaluations.insert("g_c".to_string(), proof.g_c_eval.clone());
+        for label in AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL {
+            evaluations.insert(label.to_string(), NonNativeFieldVar::Constant(E::Fr::zero()));
+        }
+
+        let state = VerifierStateVar { first_round_message, second_round_message, third_round_message, gamma };
+        let lc_s = AHPForR1CSGadget::<E, PG, S, SV, MM>::construct_linear_combinations(
+            cs.ns(|| "construct_linear_combinations"),
+            circuit_verifying_key,
+            &padded_public_inputs,
+            proof,
+            &state,
+        )?;
+
+        SonicKZG10Gadget::<E, PG, S, SV>::check_combinations(
+            cs.ns(|| "check_combinations"),
+            &circuit_verifying_key.verifier_key,
+            &lc_s,
+            &commitments,
+            &query_set,
+            &evaluations,
+            &proof.pc_proof,
+            &mut fs_rng,
+            fs_parameters,
+        )
+    }
+
+    fn labeled(label: String, commitment: &PG::G1Gadget, degree_bound: Option<usize>) -> LabeledCommitmentVar<E, PG> {
+        LabeledCommitmentVar { label, commitment: commitment.clone(), degree_bound }
+    }
+
+    fn absorb_labeled<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        commitments: &[LabeledCommitmentVar<E, PG>],
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<(), SynthesisError> {
+        let commitments = commitments.iter().map(|c| c.commitment.clone()).collect::<Vec<_>>();
+        Self::absorb_commitments(cs, &commitments, fs_rng)
+    }
+
+    fn absorb_commitments<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        commitments: &[PG::G1Gadget],
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, S, SV>,
+    ) -> Result<(), SynthesisError> {
+        let mut elements = Vec::<FpGadget<E::Fq>>::with_capacity(3 * commitments.len());
+        for (i, commitment) in commitments.iter().enumerate() {
+            elements.extend(commitment.to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))?);
+        }
+        fs_rng.absorb_native_field_elements(cs.ns(|| "absorb"), &elements)
+    }
+}
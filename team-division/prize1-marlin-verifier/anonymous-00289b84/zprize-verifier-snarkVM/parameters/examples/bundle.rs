@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_parameters::bundle::{bundle_entries, export_bundle, unpack_bundle};
+
+use anyhow::{bail, Result};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// Exports the remote parameters into a bundle at `path`, with the universal SRS up to `2^max_degree`.
+fn export(path: &Path, max_degree: u8) -> Result<()> {
+    let entries = bundle_entries(max_degree);
+    for entry in &entries {
+        println!("Bundling {} ({}, {} bytes)", entry.filename, entry.network, entry.size);
+    }
+    export_bundle(BufWriter::new(File::create(path)?), &entries)?;
+    println!("Wrote {} parameter files to {:?}", entries.len(), path);
+    Ok(())
+}
+
+/// Unpacks the bundle at `path` into `directory`.
+fn unpack(path: &Path, directory: &Path) -> Result<()> {
+    let paths = unpack_bundle(BufReader::new(File::open(path)?), directory)?;
+    for path in &paths {
+        println!("Unpacked {:?}", path);
+    }
+    println!("Set SNARKVM_PARAMETERS_DIR={:?} to load these parameters offline", directory);
+    Ok(())
+}
+
+pub fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[1..] {
+        ["export", path] => export(Path::new(path), 28),
+        ["export", path, max_degree] => export(Path::new(path), max_degree.parse()?),
+        ["unpack", path, directory] => unpack(Path::new(path), Path::new(directory)),
+        _ => bail!("Usage: bundle export <archive> [max_degree] | bundle unpack <archive> <directory>"),
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Offline bundles of the remote parameters.
+//!
+//! A bundle is a tar archive holding every remote parameter file under its versioned filename,
+//! next to a `manifest.json` that records the checksum and size of each one. Unpacking a bundle
+//! into the directory of a [`ParameterConfig`](crate::ParameterConfig) lets the parameters load
+//! without network access. The local parameters are compiled in, and are not bundled.
+
+use crate::{errors::ParameterError, testnet2, testnet3};
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// The name of the manifest inside a bundle.
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// A remote parameter file that belongs in a bundle.
+#[derive(Clone, Debug)]
+pub struct BundleEntry {
+    /// The network the parameters belong to.
+    pub network: &'static str,
+    /// The versioned filename of the parameters.
+    pub filename: String,
+    /// The expected checksum of the parameters.
+    pub checksum: String,
+    /// The expected size of the parameters, in bytes.
+    pub size: usize,
+    /// Loads the parameters, from the configured directories or over the network.
+    load: fn() -> Result<Vec<u8>, ParameterError>,
+}
+
+impl BundleEntry {
+    /// Loads and returns the parameters.
+    pub fn load_bytes(&self) -> Result<Vec<u8>, ParameterError> {
+        (self.load)()
+    }
+}
+
+macro_rules! bundle_entry {
+    ($network: expr, $name: ty) => {{
+        let (checksum, size) = <$name>::metadata();
+        BundleEntry { network: $network, filename: <$name>::filename(), checksum, size, load: <$name>::load_bytes }
+    }};
+}
+
+/// Returns the remote testnet2 and testnet3 parameters, including the powers of the universal SRS
+/// up to `2^max_degree`.
+pub fn bundle_entries(max_degree: u8) -> Vec<BundleEntry> {
+    let mut entries = vec![
+        bundle_entry!("testnet2", testnet2::InputProvingKeyBytes),
+        bundle_entry!("testnet2", testnet2::OutputProvingKeyBytes),
+        bundle_entry!("testnet2", testnet2::PoSWProvingKeyBytes),
+    ];
+    let powers = [
+        bundle_entry!("testnet3", testnet3::Degree16),
+        bundle_entry!("testnet3", testnet3::Degree17),
+        bundle_entry!("testnet3", testnet3::Degree18),
+        bundle_entry!("testnet3", testnet3::Degree19),
+        bundle_entry!("testnet3", testnet3::Degree20),
+        bundle_entry!("testnet3", testnet3::Degree21),
+        bundle_entry!("testnet3", testnet3::Degree22),
+        bundle_entry!("testnet3", testnet3::Degree23),
+        bundle_entry!("testnet3", testnet3::Degree24),
+        bundle_entry!("testnet3", testnet3::Degree25),
+        bundle_entry!("testnet3", testnet3::Degree26),
+        bundle_entry!("testnet3", testnet3::Degree27),
+        bundle_entry!("testnet3", testnet3::Degree28),
+    ];
+    entries.extend(powers.into_iter().zip(16u8..).filter(|(_, degree)| *degree <= max_degree).map(|(entry, _)| entry));
+    entries
+}
+
+/// Writes a bundle of the given entries to `writer`, loading each of them first.
+pub fn export_bundle<W: Write>(writer: W, entries: &[BundleEntry]) -> Result<(), ParameterError> {
+    let mut builder = tar::Builder::new(writer);
+    let mut manifest = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let bytes = entry.load_bytes()?;
+        append_file(&mut builder, &entry.filename, &bytes)?;
+        manifest.push(json!({
+            "network": entry.network,
+            "filename": entry.filename,
+            "checksum": entry.checksum,
+            "size": entry.size,
+        }));
+    }
+    let manifest = serde_json::to_vec_pretty(&Value::Array(manifest))
+        .map_err(|error| ParameterError::Crate("serde_json", format!("{:?}", error)))?;
+    append_file(&mut builder, MANIFEST_FILENAME, &manifest)?;
+    builder.into_inner()?.flush()?;
+    Ok(())
+}
+
+/// Unpacks the bundle read from `reader` into `directory`, checking every file against both the
+/// manifest and the checksums compiled into this crate. Files are streamed to temporary paths, and
+/// only moved into place once the whole bundle has been checked. Returns the unpacked paths.
+pub fn unpack_bundle<R: Read>(reader: R, directory: &Path) -> Result<Vec<PathBuf>, ParameterError> {
+    unpack_bundle_of(reader, directory, bundle_entries(u8::MAX))
+}
+
+/// Unpacks the bundle read from `reader` into `directory`, accepting only the `known` entries.
+fn unpack_bundle_of<R: Read>(
+    reader: R,
+    directory: &Path,
+    known: Vec<BundleEntry>,
+) -> Result<Vec<PathBuf>, ParameterError> {
+    fs::create_dir_all(directory)?;
+    let known = known.into_iter().map(|entry| (entry.filename.clone(), entry)).collect::<BTreeMap<_, _>>();
+    let mut unpacked = BTreeMap::new();
+    let result = unpack_files(reader, directory, &known, &mut unpacked);
+
+    // Move the checked files into place, or remove them all if the bundle is invalid.
+    let mut paths = Vec::with_capacity(unpacked.len());
+    for (filename, partial_path) in unpacked {
+        if result.is_ok() {
+            let path = directory.join(filename);
+            fs::rename(partial_path, &path)?;
+            paths.push(path);
+        } else {
+            let _ = fs::remove_file(partial_path);
+        }
+    }
+    result.map(|()| paths)
+}
+
+/// Streams the files of the bundle into temporary paths in `directory`, recording each of them
+/// in `unpacked`, and checks them against the manifest and the known parameters.
+fn unpack_files<R: Read>(
+    reader: R,
+    directory: &Path,
+    known: &BTreeMap<String, BundleEntry>,
+    unpacked: &mut BTreeMap<String, PathBuf>,
+) -> Result<(), ParameterError> {
+    let mut manifest = None;
+    let mut archive = tar::Archive::new(reader);
+    for file in archive.entries()? {
+        let mut file = file?;
+        let filename = file.path()?.to_string_lossy().into_owned();
+        // A repeated file would overwrite the temporary file of its first copy.
+        if unpacked.contains_key(&filename) || (filename == MANIFEST_FILENAME && manifest.is_some()) {
+            return Err(ParameterError::Message(format!("The parameter bundle holds {:?} more than once", filename)));
+        }
+        if filename == MANIFEST_FILENAME {
+            let mut bytes = Vec::new();
+            file.read_to_end(&mut bytes)?;
+            manifest = Some(bytes);
+            continue;
+        }
+
+        let entry = known.get(&filename).ok_or_else(|| {
+            ParameterError::Message(format!("The parameter bundle holds unknown parameters {:?}", filename))
+        })?;
+        let partial_path = directory.join(format!(".{}.partial", filename));
+        unpacked.insert(filename, partial_path.clone());
+
+        // Copy the file out, hashing it along the way.
+        let mut output = fs::File::create(&partial_path)?;
+        let mut hasher = Sha256::new();
+        let mut size = 0;
+        let mut buffer = vec![0u8; 1 << 20];
+        loop {
+            let num_bytes = file.read(&mut buffer)?;
+            if num_bytes == 0 {
+                break;
+            }
+            hasher.update(&buffer[..num_bytes]);
+            output.write_all(&buffer[..num_bytes])?;
+            size += num_bytes;
+        }
+        output.sync_all()?;
+
+        if size != entry.size {
+            return Err(ParameterError::SizeMismatch(entry.size, size));
+        }
+        let candidate_checksum = hex::encode(hasher.finalize());
+        if candidate_checksum != entry.checksum {
+            return checksum_error!(entry.checksum.clone(), candidate_checksum);
+        }
+    }
+
+    // Ensure the manifest lists exactly the files in the bundle.
+    let manifest: Vec<Value> = match manifest {
+        Some(manifest) => serde_json::from_slice(&manifest)
+            .map_err(|error| ParameterError::Crate("serde_json", format!("{:?}", error)))?,
+        None => return Err(ParameterError::Message("The parameter bundle has no manifest".to_string())),
+    };
+    let mut listed = manifest.iter().filter_map(|record| record["filename"].as_str()).collect::<Vec<_>>();
+    listed.sort_unstable();
+    if listed.len() != manifest.len() || !listed.into_iter().eq(unpacked.keys().map(String::as_str)) {
+        return Err(ParameterError::Message("The parameter bundle does not match its manifest".to_string()));
+    }
+    Ok(())
+}
+
+/// Appends a regular file with the given name and contents to the archive.
+fn append_file<W: Write>(builder: &mut tar::Builder<W>, filename: &str, bytes: &[u8]) -> Result<(), ParameterError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, filename, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &[u8] = b"the first parameters";
+    const SECOND: &[u8] = b"the second parameters, which are longer";
+
+    fn load_first() -> Result<Vec<u8>, ParameterError> {
+        Ok(FIRST.to_vec())
+    }
+
+    fn load_second() -> Result<Vec<u8>, ParameterError> {
+        Ok(SECOND.to_vec())
+    }
+
+    fn sample_entries() -> Vec<BundleEntry> {
+        let entry = |filename: &str, bytes: &[u8], load| BundleEntry {
+            network: "testnet3",
+            filename: filename.to_string(),
+            checksum: hex::encode(Sha256::digest(bytes)),
+            size: bytes.len(),
+            load,
+        };
+        vec![
+            entry("first.0123456", FIRST, load_first as fn() -> _),
+            entry("second.789abcd", SECOND, load_second as fn() -> _),
+        ]
+    }
+
+    fn sample_bundle() -> Vec<u8> {
+        let mut bundle = Vec::new();
+        export_bundle(&mut bundle, &sample_entries()).unwrap();
+        bundle
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("bundle_{}", rand::random::<u32>()))
+    }
+
+    /// Returns the names of the files in `directory`.
+    fn list(directory: &Path) -> Vec<String> {
+        let mut names = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let directory = temp_dir();
+        let paths = unpack_bundle_of(&sample_bundle()[..], &directory, sample_entries()).unwrap();
+        assert_eq!(paths, vec![directory.join("first.0123456"), directory.join("second.789abcd")]);
+        assert_eq!(list(&directory), vec!["first.0123456", "second.789abcd"]);
+        assert_eq!(fs::read(&paths[0]).unwrap(), FIRST);
+        assert_eq!(fs::read(&paths[1]).unwrap(), SECOND);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_bundle_with_corrupt_file_is_rejected() {
+        let mut bundle = sample_bundle();
+        let offset = bundle.windows(SECOND.len()).position(|window| window == SECOND).unwrap();
+        bundle[offset] ^= 1;
+
+        let directory = temp_dir();
+        let result = unpack_bundle_of(&bundle[..], &directory, sample_entries());
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+        assert!(list(&directory).is_empty());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_truncated_bundle_is_rejected() {
+        let bundle = sample_bundle();
+        let offset = bundle.windows(SECOND.len()).position(|window| window == SECOND).unwrap();
+
+        let directory = temp_dir();
+        assert!(unpack_bundle_of(&bundle[..offset + 4], &directory, sample_entries()).is_err());
+        assert!(list(&directory).is_empty());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_bundle_with_duplicate_file_is_rejected() {
+        let mut builder = tar::Builder::new(Vec::new());
+        append_file(&mut builder, "first.0123456", FIRST).unwrap();
+        append_file(&mut builder, "first.0123456", FIRST).unwrap();
+        let manifest = json!([{ "filename": "first.0123456" }]).to_string();
+        append_file(&mut builder, MANIFEST_FILENAME, manifest.as_bytes()).unwrap();
+        let bundle = builder.into_inner().unwrap();
+
+        let directory = temp_dir();
+        let result = unpack_bundle_of(&bundle[..], &directory, sample_entries());
+        assert!(matches!(result, Err(ParameterError::Message(_))));
+        assert!(list(&directory).is_empty());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
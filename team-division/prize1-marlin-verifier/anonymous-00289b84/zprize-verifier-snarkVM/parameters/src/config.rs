@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+lazy_static::lazy_static! {
+    static ref INSTALLED_CONFIG: RwLock<Option<ParameterConfig>> = RwLock::new(None);
+}
+
+/// Where remote parameters are searched for, and whether they may be fetched over the network.
+///
+/// Unless a configuration is installed with [`ParameterConfig::install`], it is read from the
+/// `SNARKVM_PARAMETERS_DIR` and `SNARKVM_PARAMETERS_OFFLINE` environment variables.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParameterConfig {
+    /// A directory that is searched before the default location of the parameters.
+    directory: Option<PathBuf>,
+    /// If `true`, missing parameters are an error instead of being downloaded.
+    offline: bool,
+}
+
+impl ParameterConfig {
+    /// The environment variable naming the parameter directory.
+    pub const DIRECTORY_ENV: &'static str = "SNARKVM_PARAMETERS_DIR";
+    /// The environment variable that forbids network fetches when set to anything but `0` or `false`.
+    pub const OFFLINE_ENV: &'static str = "SNARKVM_PARAMETERS_OFFLINE";
+
+    /// Returns a configuration that searches only the default location, and may fetch over the network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the configuration given by the environment variables.
+    pub fn from_env() -> Self {
+        let directory = std::env::var_os(Self::DIRECTORY_ENV).filter(|dir| !dir.is_empty()).map(PathBuf::from);
+        let offline = std::env::var(Self::OFFLINE_ENV)
+            .map(|value| !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false"))
+            .unwrap_or(false);
+        Self { directory, offline }
+    }
+
+    /// Returns the installed configuration, or the one given by the environment variables.
+    pub fn current() -> Self {
+        match &*INSTALLED_CONFIG.read().expect("The parameter configuration lock was poisoned") {
+            Some(config) => config.clone(),
+            None => Self::from_env(),
+        }
+    }
+
+    /// Searches `directory` for parameters before their default location.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Forbids, or allows, fetching missing parameters over the network.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Installs this configuration for the whole process, taking precedence over the environment.
+    pub fn install(self) {
+        *INSTALLED_CONFIG.write().expect("The parameter configuration lock was poisoned") = Some(self);
+    }
+
+    /// Returns the parameter directory, if any.
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+
+    /// Returns `true` if network fetches are forbidden.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the paths searched for `filename`, in order, ending with its default location.
+    pub fn search_paths(&self, filename: &str, default_path: &Path) -> Vec<PathBuf> {
+        self.directory.iter().map(|directory| directory.join(filename)).chain([default_path.to_path_buf()]).collect()
+    }
+
+    /// Returns the first of the search paths for `filename` that exists.
+    pub fn locate(&self, filename: &str, default_path: &Path) -> Option<PathBuf> {
+        self.search_paths(filename, default_path).into_iter().find(|path| path.exists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_is_searched_first() {
+        let directory = std::env::temp_dir().join(format!("parameters_{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let default_path = directory.join("default").join("posw.proving.0123456");
+        let config = ParameterConfig::new().with_directory(&directory).with_offline(true);
+
+        assert_eq!(config.search_paths("posw.proving.0123456", &default_path), vec![
+            directory.join("posw.proving.0123456"),
+            default_path.clone()
+        ]);
+        assert_eq!(config.locate("posw.proving.0123456", &default_path), None);
+
+        std::fs::write(directory.join("posw.proving.0123456"), b"parameters").unwrap();
+        assert_eq!(config.locate("posw.proving.0123456", &default_path), Some(directory.join("posw.proving.0123456")));
+        assert!(config.is_offline());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
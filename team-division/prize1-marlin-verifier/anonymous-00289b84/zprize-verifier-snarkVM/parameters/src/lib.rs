@@ -27,6 +27,12 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+#[cfg(not(target_family = "wasm"))]
+pub mod bundle;
+
+pub mod config;
+pub use config::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -120,27 +120,42 @@ macro_rules! impl_remote {
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+            /// Returns the expected checksum and size of the parameters.
+            pub fn metadata() -> (String, usize) {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
                 let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String = metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
                 let expected_size: usize = metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+                (expected_checksum, expected_size)
+            }
 
-                // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
+            /// Returns the versioned filename under which the parameters are stored.
+            pub fn filename() -> String {
+                let (expected_checksum, _) = Self::metadata();
+                match expected_checksum.get(0..7) {
                     Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
                     _ => format!("{}.{}", $fname, $ftype),
-                };
+                }
+            }
+
+            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let (expected_checksum, expected_size) = Self::metadata();
+                let filename = Self::filename();
 
-                // Compose the correct file path for the parameter file.
+                // Compose the default file path for the parameter file.
                 let mut file_path = aleo_std::aleo_dir();
                 file_path.push($local_dir);
                 file_path.push(&filename);
 
-                let buffer = if file_path.exists() {
+                // Search the configured parameter directory before the default file path.
+                let config = $crate::ParameterConfig::current();
+                let buffer = if let Some(local_path) = config.locate(&filename, &file_path) {
                     // Attempts to load the parameter file locally with an absolute path.
-                    std::fs::read(file_path)?
+                    std::fs::read(local_path)?
+                } else if config.is_offline() {
+                    let search_paths = config.search_paths(&filename, &file_path);
+                    return Err($crate::errors::ParameterError::NotFoundOffline(filename, search_paths));
                 } else {
                     // Downloads the missing parameters and stores it in the local directory for use.
                     eprintln!(
@@ -305,27 +320,42 @@ macro_rules! impl_remote {
         pub struct $name;
 
         impl $name {
-            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+            /// Returns the expected checksum and size of the parameters.
+            pub fn metadata() -> (String, usize) {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
 
                 let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String = metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
                 let expected_size: usize = metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+                (expected_checksum, expected_size)
+            }
 
-                // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
+            /// Returns the versioned filename under which the parameters are stored.
+            pub fn filename() -> String {
+                let (expected_checksum, _) = Self::metadata();
+                match expected_checksum.get(0..7) {
                     Some(sum) => format!("{}.{}.{}.{}", $fname, $ftype, $fdegree, sum),
                     _ => format!("{}.{}.{}", $fname, $ftype, $fdegree),
-                };
+                }
+            }
+
+            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let (expected_checksum, expected_size) = Self::metadata();
+                let filename = Self::filename();
 
-                // Compose the correct file path for the parameter file.
+                // Compose the default file path for the parameter file.
                 let mut file_path = aleo_std::aleo_dir();
                 file_path.push($local_dir);
                 file_path.push(&filename);
 
-                let buffer = if file_path.exists() {
+                // Search the configured parameter directory before the default file path.
+                let config = $crate::ParameterConfig::current();
+                let buffer = if let Some(local_path) = config.locate(&filename, &file_path) {
                     // Attempts to load the parameter file locally with an absolute path.
-                    std::fs::read(file_path)?
+                    std::fs::read(local_path)?
+                } else if config.is_offline() {
+                    let search_paths = config.search_paths(&filename, &file_path);
+                    return Err($crate::errors::ParameterError::NotFoundOffline(filename, search_paths));
                 } else {
                     // Downloads the missing parameters and stores it in the local directory for use.
                     eprintln!(
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::traits::{AffineCurve, PairingEngine};
+use snarkvm_utilities::{mmap::MappedFile, CanonicalDeserialize, CanonicalSerialize, Compress, Valid, Validate};
+
+use anyhow::{bail, ensure, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// The magic bytes at the start of a mapped SRS file.
+const MAGIC: &[u8; 8] = b"SVMSRS01";
+/// The size of the fixed header: the magic bytes, the number of powers, and the chunk size.
+const HEADER_SIZE: usize = 24;
+/// The size of the fixed trailer: the metadata length, and the metadata checksum.
+const TRAILER_SIZE: usize = 40;
+
+/// The default number of powers of G covered by each checksum.
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+/// The parts of the universal SRS that are small enough to be loaded eagerly.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+struct Metadata<E: PairingEngine> {
+    /// The SHA-256 checksum of each chunk of powers of G.
+    checksums: Vec<Vec<u8>>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, for the `i` used in hiding and degree bounds.
+    powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+    /// The generator of G2.
+    h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    beta_h: E::G2Affine,
+    /// Group elements of the form `{ \beta^{max_degree -i} G2 }`, where `i` is the supported degree bound.
+    inverse_neg_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+}
+
+/// A universal SRS backed by a memory-mapped file.
+///
+/// The file holds the powers of G uncompressed, so that any one of them can be located without
+/// reading the ones before it, followed by the remaining, small parameters. The powers are split
+/// into chunks, each with its own checksum, and a chunk is only checked the first time a power
+/// inside it is requested.
+pub struct MappedUniversalSRS<E: PairingEngine> {
+    /// The memory-mapped file.
+    mmap: MappedFile,
+    /// The number of powers of G in the file.
+    num_powers: usize,
+    /// The number of powers of G covered by each checksum.
+    chunk_size: usize,
+    /// The size of a serialized power of G.
+    power_size: usize,
+    /// Whether each chunk has been checked against its checksum.
+    verified_chunks: Vec<bool>,
+    /// The powers of G that have been decoded so far, starting from `G`. The mapping holds them
+    /// in their serialized form, so they are decoded into this cache before they can be used.
+    prefix: Vec<E::G1Affine>,
+    /// The eagerly loaded parameters.
+    metadata: Metadata<E>,
+}
+
+impl<E: PairingEngine> MappedUniversalSRS<E> {
+    /// Maps the SRS stored at `path`, checking only its header and metadata.
+    pub fn open(path: &Path) -> Result<Self> {
+        // Modifying the file while it is mapped is not supported; every chunk is still checked
+        // against its checksum before it is decoded.
+        let mmap = MappedFile::open(path)?;
+
+        ensure!(mmap.len() >= HEADER_SIZE + TRAILER_SIZE, "The mapped SRS at {path:?} is truncated");
+        ensure!(&mmap[..8] == MAGIC, "The file at {path:?} is not a mapped SRS");
+        let num_powers = read_u64(&mmap[8..16]) as usize;
+        let chunk_size = read_u64(&mmap[16..24]) as usize;
+        ensure!(num_powers > 0 && chunk_size > 0, "The mapped SRS at {path:?} is empty");
+
+        // Check and load the metadata.
+        let trailer_start = mmap.len() - TRAILER_SIZE;
+        let metadata_len = read_u64(&mmap[trailer_start..trailer_start + 8]) as usize;
+        let metadata_start = match trailer_start.checked_sub(metadata_len) {
+            Some(metadata_start) => metadata_start,
+            None => bail!("The mapped SRS at {path:?} is truncated"),
+        };
+        let metadata_bytes = &mmap[metadata_start..trailer_start];
+        if Sha256::digest(metadata_bytes).as_slice() != &mmap[trailer_start + 8..] {
+            bail!("The metadata of the mapped SRS at {path:?} does not match its checksum");
+        }
+        let metadata = Metadata::<E>::deserialize_compressed(metadata_bytes)?;
+
+        // Ensure the powers fill the space between the header and the metadata.
+        let power_size = E::G1Affine::prime_subgroup_generator().uncompressed_size();
+        ensure!(
+            num_powers.checked_mul(power_size).and_then(|size| size.checked_add(HEADER_SIZE)) == Some(metadata_start),
+            "The mapped SRS at {path:?} has the wrong number of powers"
+        );
+        let num_chunks = (num_powers + chunk_size - 1) / chunk_size;
+        ensure!(metadata.checksums.len() == num_chunks, "The mapped SRS at {path:?} has the wrong number of checksums");
+
+        Ok(Self {
+            mmap,
+            num_powers,
+            chunk_size,
+            power_size,
+            verified_chunks: vec![false; num_chunks],
+            prefix: Vec::new(),
+            metadata,
+        })
+    }
+
+    /// Writes an SRS to `path`, reading the powers of G in chunks from `powers_of_beta_g`,
+    /// which is called with the range of the powers to return.
+    #[allow(clippy::too_many_arguments)]
+    pub fn write(
+        path: &Path,
+        num_powers: usize,
+        chunk_size: usize,
+        mut powers_of_beta_g: impl FnMut(usize, usize) -> Result<Vec<E::G1Affine>>,
+        powers_of_beta_times_gamma_g: &BTreeMap<usize, E::G1Affine>,
+        h: E::G2Affine,
+        beta_h: E::G2Affine,
+        inverse_neg_powers_of_beta_h: &BTreeMap<usize, E::G2Affine>,
+    ) -> Result<()> {
+        ensure!(num_powers > 0 && chunk_size > 0, "Cannot write an empty mapped SRS");
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(num_powers as u64).to_le_bytes())?;
+        writer.write_all(&(chunk_size as u64).to_le_bytes())?;
+
+        // Write the powers of G, hashing each chunk as it goes.
+        let mut checksums = Vec::with_capacity((num_powers + chunk_size - 1) / chunk_size);
+        let mut bytes = Vec::new();
+        for lower in (0..num_powers).step_by(chunk_size) {
+            let upper = num_powers.min(lower + chunk_size);
+            let powers = powers_of_beta_g(lower, upper)?;
+            ensure!(powers.len() == upper - lower, "Expected {} powers of G, found {}", upper - lower, powers.len());
+
+            bytes.clear();
+            for power in &powers {
+                power.serialize_uncompressed(&mut bytes)?;
+            }
+            checksums.push(Sha256::digest(&bytes).to_vec());
+            writer.write_all(&bytes)?;
+        }
+
+        // Write the metadata and its checksum.
+        let metadata = Metadata::<E> {
+            checksums,
+            powers_of_beta_times_gamma_g: powers_of_beta_times_gamma_g.clone(),
+            h,
+            beta_h,
+            inverse_neg_powers_of_beta_h: inverse_neg_powers_of_beta_h.clone(),
+        };
+        bytes.clear();
+        metadata.serialize_compressed(&mut bytes)?;
+        writer.write_all(&bytes)?;
+        writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        writer.write_all(&Sha256::digest(&bytes))?;
+
+        writer.into_inner()?.sync_all()?;
+        Ok(())
+    }
+
+    /// Returns the maximum degree supported by the powers of G.
+    pub fn max_degree(&self) -> usize {
+        self.num_powers - 1
+    }
+
+    /// Returns the powers of G between `lower` and `upper`, decoding them from the mapping.
+    pub fn powers_of_beta_g(&mut self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>> {
+        ensure!(lower <= upper, "Invalid range of powers of G: {lower}..{upper}");
+        ensure!(upper <= self.num_powers, "Attempted to load {upper} powers of G, but only {} exist", self.num_powers);
+        if lower == upper {
+            return Ok(Vec::new());
+        }
+
+        // Check the checksum of every chunk that overlaps the range.
+        for chunk in lower / self.chunk_size..=(upper - 1) / self.chunk_size {
+            self.verify_chunk(chunk)?;
+        }
+
+        let bytes = &self.mmap[HEADER_SIZE + lower * self.power_size..HEADER_SIZE + upper * self.power_size];
+        let powers = bytes
+            .chunks_exact(self.power_size)
+            .map(|bytes| E::G1Affine::deserialize_with_mode(bytes, Compress::No, Validate::No))
+            .collect::<Result<Vec<_>, _>>()?;
+        E::G1Affine::batch_check(powers.iter())?;
+        Ok(powers)
+    }
+
+    /// Returns the first `upper` powers of G. Each power is decoded at most once, into a cache
+    /// held by `self`; the returned slice borrows from that cache, not from the mapping.
+    pub fn prefix(&mut self, upper: usize) -> Result<&[E::G1Affine]> {
+        if upper > self.prefix.len() {
+            let powers = self.powers_of_beta_g(self.prefix.len(), upper)?;
+            self.prefix.extend(powers);
+        }
+        Ok(&self.prefix[..upper])
+    }
+
+    /// Returns the powers of `\beta^i \gamma G`.
+    pub fn get_powers_times_gamma_g(&self) -> &BTreeMap<usize, E::G1Affine> {
+        &self.metadata.powers_of_beta_times_gamma_g
+    }
+
+    /// Returns the generator of G2.
+    pub fn h(&self) -> E::G2Affine {
+        self.metadata.h
+    }
+
+    /// Returns \beta times the generator of G2.
+    pub fn beta_h(&self) -> E::G2Affine {
+        self.metadata.beta_h
+    }
+
+    /// Returns the powers `\beta^{max_degree - i} H` for the supported degree bounds `i`.
+    pub fn inverse_neg_powers_of_beta_h(&self) -> &BTreeMap<usize, E::G2Affine> {
+        &self.metadata.inverse_neg_powers_of_beta_h
+    }
+
+    /// Checks the chunk at the given index against its checksum, if it has not been checked yet.
+    fn verify_chunk(&mut self, chunk: usize) -> Result<()> {
+        if self.verified_chunks[chunk] {
+            return Ok(());
+        }
+        let lower = chunk * self.chunk_size;
+        let upper = self.num_powers.min(lower + self.chunk_size);
+        let bytes = &self.mmap[HEADER_SIZE + lower * self.power_size..HEADER_SIZE + upper * self.power_size];
+        if Sha256::digest(bytes).as_slice() != self.metadata.checksums[chunk].as_slice() {
+            bail!("Chunk {chunk} of the mapped SRS does not match its checksum");
+        }
+        self.verified_chunks[chunk] = true;
+        Ok(())
+    }
+}
+
+/// Reads a little-endian `u64` from the given bytes.
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(bytes);
+    u64::from_le_bytes(buffer)
+}
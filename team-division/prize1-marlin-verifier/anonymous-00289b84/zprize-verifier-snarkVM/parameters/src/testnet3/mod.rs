@@ -14,6 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(not(target_family = "wasm"))]
+pub mod mapped;
+#[cfg(not(target_family = "wasm"))]
+pub use mapped::*;
+
 pub mod powers;
 pub use powers::*;
 
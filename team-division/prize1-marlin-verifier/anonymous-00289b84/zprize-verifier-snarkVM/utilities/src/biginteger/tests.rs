@@ -128,3 +128,8 @@ fn test_biginteger256() {
 fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
+
+#[test]
+fn test_biginteger768() {
+    test_biginteger(BigInteger768::new([0u64; 12]));
+}
@@ -70,6 +70,9 @@ pub use parallel::*;
 pub mod rand;
 pub use self::rand::*;
 
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+pub mod mmap;
+
 pub mod serialize;
 pub use serialize::*;
 
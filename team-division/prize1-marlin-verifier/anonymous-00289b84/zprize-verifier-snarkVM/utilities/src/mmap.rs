@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Memory-mapped files.
+//!
+//! This is the only module that maps files into memory, so that crates which forbid `unsafe`
+//! code can still read and write large files without loading them eagerly.
+
+use std::{
+    fs::File,
+    io,
+    ops::{Deref, DerefMut},
+    path::Path,
+};
+
+/// A read-only memory mapping of a file.
+pub struct MappedFile {
+    mmap: memmap2::Mmap,
+}
+
+impl MappedFile {
+    /// Maps the file at `path` into memory.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Self::map(&File::open(path)?)
+    }
+
+    /// Maps `file` into memory.
+    pub fn map(file: &File) -> io::Result<Self> {
+        // SAFETY: The mapping is read-only and is never written through. Modifying or truncating
+        // the file while it is mapped is not supported; callers that read untrusted or mutable
+        // files must check the bytes they read, e.g. against a checksum, before relying on them.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::Mmap::map(file)? };
+        Ok(Self { mmap })
+    }
+}
+
+impl Deref for MappedFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+/// A writable memory mapping of a file. Writes to the mapping are written back to the file.
+pub struct MappedFileMut {
+    mmap: memmap2::MmapMut,
+}
+
+impl MappedFileMut {
+    /// Maps `file`, which must be open for reading and writing, into memory.
+    pub fn map(file: &File) -> io::Result<Self> {
+        // SAFETY: The mapping is the only handle through which the file is accessed while it is mapped.
+        // Modifying or truncating the file through another handle while it is mapped is not supported.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::MmapMut::map_mut(file)? };
+        Ok(Self { mmap })
+    }
+
+    /// Writes the outstanding changes back to the file, and waits for them to complete.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+}
+
+impl Deref for MappedFileMut {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+impl DerefMut for MappedFileMut {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.mmap
+    }
+}
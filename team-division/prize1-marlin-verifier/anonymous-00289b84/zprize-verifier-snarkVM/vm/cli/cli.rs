@@ -14,10 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::Updater;
+use crate::{
+    cli::Updater,
+    compiler::{Proof, VerifyingKey},
+    console::{
+        network::{prelude::*, Testnet3},
+        types::Field,
+    },
+};
 
-use anyhow::Result;
 use clap::Parser;
+use std::path::{Path, PathBuf};
+
+type CurrentNetwork = Testnet3;
 
 #[derive(Debug, Parser)]
 #[clap(name = "snarkVM", author = "The Aleo Team <hello@aleo.org>", setting = clap::AppSettings::ColoredHelp)]
@@ -41,6 +50,30 @@ pub enum Command {
         #[clap(short = 'q', long)]
         quiet: bool,
     },
+    /// Verify a proof against a verifying key and public inputs
+    Verify {
+        /// Path to the proof, as a bech32m string or in bytes
+        #[clap(long, parse(from_os_str))]
+        proof: PathBuf,
+        /// Path to the verifying key, as a bech32m string or in bytes
+        #[clap(long, parse(from_os_str))]
+        verifying_key: PathBuf,
+        /// Path to the public inputs, as a JSON array of field elements
+        #[clap(long, parse(from_os_str))]
+        inputs: PathBuf,
+    },
+    /// Print the circuit count, batch size, hiding mode and commitment counts of a proof
+    InspectProof {
+        /// Path to the proof, as a bech32m string or in bytes
+        #[clap(parse(from_os_str))]
+        proof: PathBuf,
+    },
+    /// Print the circuit information and commitment counts of a verifying key
+    InspectVk {
+        /// Path to the verifying key, as a bech32m string or in bytes
+        #[clap(parse(from_os_str))]
+        verifying_key: PathBuf,
+    },
 }
 
 impl Command {
@@ -71,7 +104,172 @@ impl Command {
                         Ok("".to_string())
                     }
                 }
-            }, // _ => Err(anyhow!("\nUnknown command\n")),
+            },
+            Command::Verify { proof, verifying_key, inputs } => {
+                let proof = read_object::<Proof<CurrentNetwork>>(proof)?;
+                let verifying_key = read_object::<VerifyingKey<CurrentNetwork>>(verifying_key)?;
+                let inputs = read_inputs(inputs)?;
+
+                // Exit with a non-zero code if the proof is rejected.
+                match verifying_key.verify_detailed(&inputs, &proof) {
+                    Ok(()) => Ok("The proof is valid".to_string()),
+                    Err(error) => bail!("The proof is invalid: {error}"),
+                }
+            }
+            Command::InspectProof { proof } => {
+                let proof = read_object::<Proof<CurrentNetwork>>(proof)?;
+                let commitments = &proof.commitments;
+
+                // Each instance commits to `w`, `z_a` and `z_b`, each circuit to `g_a`, `g_b` and `g_c`,
+                // and the batch shares the remaining commitments.
+                let num_witness_commitments = 3 * commitments.witness_commitments.len();
+                let num_batch_commitments =
+                    3 + 3 * commitments.matrix_commitments.len() + usize::from(commitments.mask_poly.is_some());
+
+                let mut output = String::new();
+                output += &format!("Circuits: {}\n", proof.num_circuits());
+                output += &format!("Batch size: {}\n", proof.batch_size());
+                output += &format!("Hiding: {}\n", proof.pc_proof.is_hiding());
+                output += &format!("Witness commitments: {num_witness_commitments}\n");
+                output += &format!("Batch commitments: {num_batch_commitments}\n");
+                output += &format!("Total commitments: {}", num_witness_commitments + num_batch_commitments);
+                Ok(output)
+            }
+            Command::InspectVk { verifying_key } => {
+                let verifying_key = read_object::<VerifyingKey<CurrentNetwork>>(verifying_key)?;
+                let circuit_info = &verifying_key.circuit_info;
+
+                let mut output = String::new();
+                output += &format!("Public inputs: {}\n", circuit_info.num_public_inputs);
+                output += &format!("Variables: {}\n", circuit_info.num_variables);
+                output += &format!("Constraints: {}\n", circuit_info.num_constraints);
+                output += &format!("Non-zero entries in A: {}\n", circuit_info.num_non_zero_a);
+                output += &format!("Non-zero entries in B: {}\n", circuit_info.num_non_zero_b);
+                output += &format!("Non-zero entries in C: {}\n", circuit_info.num_non_zero_c);
+                output += &format!("Circuit commitments: {}", verifying_key.circuit_commitments.len());
+                Ok(output)
+            }
+        }
+    }
+}
+
+/// Reads an object from `path`, either as a bech32m string or in bytes.
+fn read_object<T: FromStr<Err = Error> + FromBytes>(path: &Path) -> Result<T> {
+    let bytes = std::fs::read(path).map_err(|error| anyhow!("Failed to read '{}': {error}", path.display()))?;
+    match str::from_utf8(&bytes) {
+        // Strip the surrounding quotes left by the JSON encoding of the object.
+        Ok(string) if string.trim().trim_matches('"').chars().all(|c| c.is_ascii_alphanumeric()) => {
+            T::from_str(string.trim().trim_matches('"'))
         }
+        _ => Ok(T::read_le(&bytes[..])?),
+    }
+}
+
+/// Reads the public inputs from `path`, as a JSON array of field elements.
+fn read_inputs(path: &Path) -> Result<Vec<<CurrentNetwork as Environment>::Field>> {
+    let string =
+        std::fs::read_to_string(path).map_err(|error| anyhow!("Failed to read '{}': {error}", path.display()))?;
+    let inputs: Vec<Field<CurrentNetwork>> = serde_json::from_str(&string)?;
+    Ok(inputs.iter().map(|input| **input).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        circuit::AleoV0,
+        compiler::{Process, Program},
+        console::{
+            account::PrivateKey,
+            program::{Identifier, Value},
+            types::I64,
+        },
+    };
+
+    /// Executes a sample program, and writes the proof, the verifying key and the public inputs
+    /// of its transition to a temporary directory. Returns their paths, in that order.
+    fn sample_files() -> (PathBuf, PathBuf, PathBuf) {
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        let rng = &mut rand::thread_rng();
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Execute the function.
+        let process = Process::<CurrentNetwork, AleoV0>::new(program.clone()).unwrap();
+        let authorization = process
+            .authorize(
+                &caller_private_key,
+                program.id(),
+                function_name,
+                &[Value::from_str("5u32").unwrap(), Value::from_str("10u32").unwrap()],
+                rng,
+            )
+            .unwrap();
+        let (_response, execution) = process.execute(authorization, rng).unwrap();
+        let transition = execution.get(0).unwrap();
+        let (_proving_key, verifying_key) = process.circuit_key(program.id(), &function_name).unwrap();
+
+        // Construct the public inputs of the transition, as the process does.
+        let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinate();
+        let mut inputs = vec![Field::<CurrentNetwork>::one(), tpk_x, tpk_y];
+        inputs.extend(transition.input_ids());
+        inputs.extend(transition.output_ids());
+        inputs.push(I64::<CurrentNetwork>::new(*transition.fee()).to_field().unwrap());
+
+        let directory = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        let (proof_path, verifying_key_path, inputs_path) =
+            (directory.join("proof"), directory.join("verifying_key"), directory.join("inputs.json"));
+        std::fs::write(&proof_path, transition.proof().to_bytes_le().unwrap()).unwrap();
+        std::fs::write(&verifying_key_path, verifying_key.to_bytes_le().unwrap()).unwrap();
+        std::fs::write(&inputs_path, serde_json::to_string(&inputs).unwrap()).unwrap();
+        (proof_path, verifying_key_path, inputs_path)
+    }
+
+    #[test]
+    fn test_verify() {
+        let (proof, verifying_key, inputs) = sample_files();
+
+        let command = Command::Verify { proof: proof.clone(), verifying_key: verifying_key.clone(), inputs };
+        assert_eq!(command.start().unwrap(), "The proof is valid");
+
+        // A proof for other public inputs is rejected.
+        let tampered = proof.with_file_name("tampered.json");
+        std::fs::write(&tampered, serde_json::to_string(&[Field::<CurrentNetwork>::one()]).unwrap()).unwrap();
+        let command = Command::Verify { proof, verifying_key, inputs: tampered };
+        assert!(command.start().is_err());
+    }
+
+    #[test]
+    fn test_inspect_proof() {
+        let (proof_path, _, _) = sample_files();
+        let proof = read_object::<Proof<CurrentNetwork>>(&proof_path).unwrap();
+
+        let output = Command::InspectProof { proof: proof_path }.start().unwrap();
+        assert!(output.contains("Circuits: 1\n"));
+        assert!(output.contains("Batch size: 1\n"));
+        assert!(output.contains(&format!("Hiding: {}\n", proof.pc_proof.is_hiding())));
+        assert!(output.contains("Witness commitments: 3\n"));
+    }
+
+    #[test]
+    fn test_inspect_vk() {
+        let (_, verifying_key_path, _) = sample_files();
+        let verifying_key = read_object::<VerifyingKey<CurrentNetwork>>(&verifying_key_path).unwrap();
+
+        let output = Command::InspectVk { verifying_key: verifying_key_path }.start().unwrap();
+        assert!(output.contains(&format!("Constraints: {}\n", verifying_key.circuit_info.num_constraints)));
+        assert!(output.ends_with(&format!("Circuit commitments: {}", verifying_key.circuit_commitments.len())));
     }
 }
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use console::network::prelude::*;
-use snarkvm_algorithms::{crypto_hash::PoseidonSponge, snark::marlin, SNARK};
+use snarkvm_algorithms::{crypto_hash::PoseidonSponge, snark::marlin, Prepare, VerificationError, SNARK};
 use snarkvm_curves::PairingEngine;
 
 use colored::Colorize;
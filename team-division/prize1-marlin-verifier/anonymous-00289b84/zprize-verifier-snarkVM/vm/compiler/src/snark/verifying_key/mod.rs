@@ -41,6 +41,11 @@ impl<N: Network> VerifyingKey<N> {
         println!("{}", format!(" • Called verifier: {} ms", timer.elapsed().as_millis()).dimmed());
         is_valid
     }
+
+    /// Verifies the proof for the given public inputs, and returns the reason for rejecting it, if any.
+    pub fn verify_detailed(&self, inputs: &[N::Field], proof: &Proof<N>) -> Result<(), VerificationError> {
+        Marlin::<N>::verify_detailed(None, &self.verifying_key.prepare(), std::slice::from_ref(&inputs), proof)
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
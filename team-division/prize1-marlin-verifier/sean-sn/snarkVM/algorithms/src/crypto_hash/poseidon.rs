@@ -17,12 +17,37 @@
 use crate::{AlgebraicSponge, DefaultCapacityAlgebraicSponge, DuplexSpongeMode};
 use snarkvm_fields::{PoseidonParameters, PrimeField};
 
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 use smallvec::SmallVec;
 use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
     ops::{Index, IndexMut},
     sync::Arc,
 };
 
+/// The default Poseidon parameters derived so far, keyed by the field and the rate.
+static DEFAULT_PARAMETERS: Lazy<RwLock<HashMap<(TypeId, usize), Arc<dyn Any + Send + Sync>>>> =
+    Lazy::new(Default::default);
+
+/// Returns the default Poseidon parameters for the field `F` and this rate, including their optimized form.
+/// They are derived on first use, and shared by every later use.
+pub fn default_poseidon_parameters<F: PrimeField, const RATE: usize>() -> Result<Arc<PoseidonParameters<F, RATE, 1>>> {
+    let key = (TypeId::of::<F>(), RATE);
+    let cached = DEFAULT_PARAMETERS.read().get(&key).cloned();
+    let parameters = match cached {
+        Some(parameters) => parameters,
+        None => {
+            let parameters: Arc<dyn Any + Send + Sync> = Arc::new(F::default_poseidon_parameters::<RATE>()?);
+            // Another thread may have derived the same parameters in the meantime.
+            DEFAULT_PARAMETERS.write().entry(key).or_insert(parameters).clone()
+        }
+    };
+    Ok(parameters.downcast().expect("The cached Poseidon parameters do not match their key"))
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct State<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
     capacity_state: [F; CAPACITY],
@@ -71,7 +96,7 @@ pub struct Poseidon<F: PrimeField, const RATE: usize> {
 impl<F: PrimeField, const RATE: usize> Poseidon<F, RATE> {
     /// Initializes a new instance of the cryptographic hash function.
     pub fn setup() -> Self {
-        Self { parameters: Arc::new(F::default_poseidon_parameters::<RATE>().unwrap()) }
+        Self { parameters: default_poseidon_parameters::<F, RATE>().unwrap() }
     }
 
     /// Evaluate the cryptographic hash function over a list of field elements as input.
@@ -116,7 +141,7 @@ pub struct PoseidonSponge<F: PrimeField, const RATE: usize, const CAPACITY: usiz
 
 impl<F: PrimeField, const RATE: usize> DefaultCapacityAlgebraicSponge<F, RATE> for PoseidonSponge<F, RATE, 1> {
     fn sample_parameters() -> Arc<PoseidonParameters<F, RATE, 1>> {
-        Arc::new(F::default_poseidon_parameters::<RATE>().unwrap())
+        default_poseidon_parameters::<F, RATE>().unwrap()
     }
 }
 
@@ -216,14 +241,15 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F,
         self.state = new_state;
     }
 
+    /// Multiplies the state by the sparse matrix `M''` of a partial round,
+    /// where `round_number` counts back from the last partial round.
     #[inline]
     fn apply_mds_opt(&mut self, round_number: usize) {
         let mut new_state = State::default();
 
-        new_state[0] = self.parameters.mds_m_0_0 * self.state[0];
-        for (i, w) in self.parameters.mds_w_hat[round_number].iter().enumerate() {
-            new_state[0] += *w * self.state[i + 1];
-        }
+        let w_hat = &self.parameters.mds_w_hat[round_number];
+        new_state[0] =
+            self.parameters.mds_m_0_0 * self.state[0] + F::sum_of_products(self.state.iter().skip(1), w_hat.iter());
 
         for (i, v) in self.parameters.mds_v[round_number].iter().enumerate() {
             new_state[i + 1] = (*v * self.state[0]) + self.state[i + 1];
@@ -14,9 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
-use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use crate::{
+    crypto_hash::{default_poseidon_parameters, PoseidonSponge},
+    AlgebraicSponge,
+    DuplexSpongeMode,
+};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR, PoseidonParameters, PrimeField};
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -88,3 +92,132 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn bls12_377_fq_rate_6_poseidon_parameters_match_the_hard_coded_constants() {
+    use blake2::{Blake2s256, Digest};
+    use snarkvm_utilities::ToBytes;
+
+    // The digests of the round keys, the MDS matrix, and its sparse-MDS form, which were hard-coded
+    // into `Fp384` for the Fiat-Shamir sponge of Marlin, over the canonical little-endian encodings.
+    let digest = |matrix: &[Vec<Fq>]| {
+        let bytes = matrix.iter().flatten().flat_map(|element| element.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        hex::encode(Blake2s256::digest(&bytes))
+    };
+
+    let params = Fq::default_poseidon_parameters::<6>().unwrap();
+    assert_eq!((params.full_rounds, params.partial_rounds, params.alpha), (8, 57, 5));
+    assert_eq!(digest(&params.ark), "b5966e8280dac40f984965d195055cc5093d5798e8a73f334bd77c43a24778f9");
+    assert_eq!(digest(&params.mds), "7b33cca8a8e8da163629c741490b48739bfc2be1d49a5a1499c4363a1d97a846");
+    assert_eq!(digest(&params.ark_opt), "d47cd7b5b58ffd8c1f1dff78656d8595f80d531c110812cf32c5ecdaa18ec0ca");
+    assert_eq!(digest(&params.mds_m_i), "495bb4c2b4d03cf3ca023389fc5912c38a52edb2d318ed123db7fdab35420707");
+    assert_eq!(digest(&params.mds_v), "7b75a84f118e20974f971ac6fa3255eaaf7d1863ed845b3b0a6eedd9a2fd7f52");
+    assert_eq!(digest(&params.mds_w_hat), "b800682acb6a7a7e51471791ff5372f66c712ea88570197d911fb4d3c37e7f6f");
+    assert_eq!(params.mds_m_0_0, params.mds[0][0]);
+}
+
+/// Applies the Poseidon permutation round by round, with a dense MDS matrix in every round.
+fn unoptimized_permutation<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
+    parameters: &PoseidonParameters<F, RATE, CAPACITY>,
+    state: &mut [F],
+) {
+    let half_full_rounds = parameters.full_rounds / 2;
+    let partial_round_range = half_full_rounds..(half_full_rounds + parameters.partial_rounds);
+    for (i, round_keys) in parameters.ark.iter().enumerate() {
+        state.iter_mut().zip(round_keys).for_each(|(elem, key)| *elem += key);
+        match partial_round_range.contains(&i) {
+            true => state[0] = state[0].pow([parameters.alpha]),
+            false => state.iter_mut().for_each(|elem| *elem = elem.pow([parameters.alpha])),
+        }
+        let new_state: Vec<F> =
+            parameters.mds.iter().map(|row| row.iter().zip(state.iter()).map(|(m, elem)| *m * elem).sum()).collect();
+        state.copy_from_slice(&new_state);
+    }
+}
+
+/// Absorbs `input` and squeezes `num_outputs` elements, with both the sponge and the unoptimized permutation.
+fn check_sponge_matches_unoptimized<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
+    parameters: PoseidonParameters<F, RATE, CAPACITY>,
+    input: &[F],
+    num_outputs: usize,
+) {
+    let parameters = Arc::new(parameters);
+    let mut sponge = PoseidonSponge::<F, RATE, CAPACITY>::new(&parameters);
+    sponge.absorb(input);
+    let output = sponge.squeeze(num_outputs);
+
+    // Absorb into the rate, permuting whenever it is full, then squeeze the rate, permuting before each block.
+    let mut state = vec![F::zero(); RATE + CAPACITY];
+    for chunk in input.chunks(RATE) {
+        state[CAPACITY..].iter_mut().zip(chunk).for_each(|(elem, value)| *elem += value);
+        if chunk.len() == RATE {
+            unoptimized_permutation(&parameters, &mut state);
+        }
+    }
+    if input.is_empty() || input.len() % RATE != 0 {
+        unoptimized_permutation(&parameters, &mut state);
+    }
+    let mut expected = Vec::with_capacity(num_outputs);
+    while expected.len() < num_outputs {
+        if !expected.is_empty() {
+            unoptimized_permutation(&parameters, &mut state);
+        }
+        expected.extend(state[CAPACITY..].iter().take(num_outputs - expected.len()));
+    }
+    assert_eq!(output.to_vec(), expected, "rate = {}, capacity = {}, alpha = {}", RATE, CAPACITY, parameters.alpha);
+}
+
+#[test]
+fn test_poseidon_sponge_matches_unoptimized_permutation() {
+    let inputs = |n: u64| (0..n).map(|i| Fq::from(i * 1237812 + 1)).collect::<Vec<_>>();
+
+    // The Fiat-Shamir sponge of Marlin, over Fq at rate 6.
+    let parameters = Fq::default_poseidon_parameters::<6>().unwrap();
+    for (num_inputs, num_outputs) in [(0, 1), (1, 1), (6, 6), (7, 13), (13, 2)] {
+        check_sponge_matches_unoptimized(parameters.clone(), &inputs(num_inputs), num_outputs);
+    }
+
+    // The other default rates, with alpha = 17 over Fq at rate 2.
+    check_sponge_matches_unoptimized(Fq::default_poseidon_parameters::<2>().unwrap(), &inputs(5), 3);
+    check_sponge_matches_unoptimized(Fq::default_poseidon_parameters::<8>().unwrap(), &inputs(9), 9);
+    check_sponge_matches_unoptimized(Fr::default_poseidon_parameters::<2>().unwrap(), &[Fr::from(7u64); 5], 3);
+    check_sponge_matches_unoptimized(Fr::default_poseidon_parameters::<4>().unwrap(), &[Fr::from(7u64); 5], 3);
+
+    // Other capacities, exponents and round counts.
+    let fr_inputs = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64), Fr::from(4u64)];
+    check_sponge_matches_unoptimized(
+        PoseidonParameters::<Fr, 3, 2>::from_grain_lfsr(5, 8, 60, 0).unwrap(),
+        &fr_inputs,
+        4,
+    );
+    check_sponge_matches_unoptimized(
+        PoseidonParameters::<Fr, 1, 1>::from_grain_lfsr(3, 4, 10, 0).unwrap(),
+        &fr_inputs,
+        2,
+    );
+    check_sponge_matches_unoptimized(
+        PoseidonParameters::<Fq, 2, 1>::from_grain_lfsr(11, 6, 1, 0).unwrap(),
+        &inputs(3),
+        3,
+    );
+}
+
+#[test]
+fn test_default_poseidon_parameters_are_cached() {
+    let first = default_poseidon_parameters::<Fq, 6>().unwrap();
+    let second = default_poseidon_parameters::<Fq, 6>().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(*first, Fq::default_poseidon_parameters::<6>().unwrap());
+
+    // Other rates and fields are cached separately.
+    let other_rate = default_poseidon_parameters::<Fq, 4>().unwrap();
+    assert_ne!(other_rate.mds, first.mds);
+    assert_eq!(
+        default_poseidon_parameters::<Fr, 6>().unwrap().full_rounds,
+        Fr::default_poseidon_parameters::<6>().unwrap().full_rounds
+    );
+
+    // Parameters without an even number of full rounds, or without partial rounds, are rejected.
+    assert!(PoseidonParameters::<Fq, 2, 1>::from_grain_lfsr(5, 3, 8, 0).is_err());
+    assert!(PoseidonParameters::<Fq, 2, 1>::from_grain_lfsr(5, 8, 0, 0).is_err());
+}
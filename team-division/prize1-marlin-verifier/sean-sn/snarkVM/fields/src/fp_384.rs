@@ -412,14161 +412,6 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
         //if r.is_valid() { r } else { Self::zero() }
         Fp384(r, PhantomData)
     }
-
-    fn get_ark_consts() -> Vec<Vec<Self>> {
-        const ARK_RAW_MONT: [[BigInteger; 7]; 65] = [
-            [
-                BigInteger([
-                    1504146516004890299u64,
-                    5191845523978117041u64,
-                    2697835982163585511u64,
-                    8173143837212697743u64,
-                    2798146286342024329u64,
-                    76443817350796328u64,
-                ]),
-                BigInteger([
-                    5389994602608877828u64,
-                    9786295965899834471u64,
-                    14957792512719787728u64,
-                    16442825313190552395u64,
-                    15941852526598907249u64,
-                    78329317172234101u64,
-                ]),
-                BigInteger([
-                    6204055390205055859u64,
-                    15055613746620039876u64,
-                    17369260415440106951u64,
-                    7601135635779353882u64,
-                    4557033530050655267u64,
-                    117925862831899491u64,
-                ]),
-                BigInteger([
-                    11960673606777769562u64,
-                    11989955156868188018u64,
-                    998228376274877163u64,
-                    10589489859353568014u64,
-                    6646726193831319281u64,
-                    34426068033518842u64,
-                ]),
-                BigInteger([
-                    7880502611372126655u64,
-                    5636840868346524232u64,
-                    15496967069559444662u64,
-                    12535640858582664989u64,
-                    13484168960063542742u64,
-                    106476553184112598u64,
-                ]),
-                BigInteger([
-                    15790554118372822977u64,
-                    1801520977369158637u64,
-                    8445788818144994194u64,
-                    11850592177218532653u64,
-                    9934317174198538860u64,
-                    115891036379791490u64,
-                ]),
-                BigInteger([
-                    14964375046498872717u64,
-                    15080611439012712337u64,
-                    16268406582264335352u64,
-                    4761710220731009267u64,
-                    3804541201341275552u64,
-                    72092258836262735u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7700301302382995101u64,
-                    15126980304804008921u64,
-                    14360672491001394288u64,
-                    5105329412407803168u64,
-                    15792704004987533577u64,
-                    85989405804326520u64,
-                ]),
-                BigInteger([
-                    972703151959427025u64,
-                    10768378208460351946u64,
-                    3951240123539262067u64,
-                    5848639655946443911u64,
-                    17458605183770260574u64,
-                    2539123042456015u64,
-                ]),
-                BigInteger([
-                    3237584163711619651u64,
-                    6892495680471421254u64,
-                    17790494676459930000u64,
-                    7188732890889265959u64,
-                    11943721334025845286u64,
-                    6259053514385785u64,
-                ]),
-                BigInteger([
-                    11445062139170095723u64,
-                    14770514971862235188u64,
-                    12070499158436078399u64,
-                    13065314973508018206u64,
-                    950577118507124527u64,
-                    64768442763030083u64,
-                ]),
-                BigInteger([
-                    2110338812687686495u64,
-                    3345174185075402195u64,
-                    5996808469636743401u64,
-                    9513502111197295967u64,
-                    12919850211076713012u64,
-                    77912006441795704u64,
-                ]),
-                BigInteger([
-                    14694324360987437841u64,
-                    12620412878306767361u64,
-                    762463711377916866u64,
-                    7500493642736892438u64,
-                    5507778487057498949u64,
-                    54504069351057351u64,
-                ]),
-                BigInteger([
-                    6468263337333553411u64,
-                    2511225626106093114u64,
-                    2722930689520222186u64,
-                    9005757845385948714u64,
-                    2133876554448820761u64,
-                    57905823377493050u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1638022881081861464u64,
-                    17347628339588340053u64,
-                    2877129804039829458u64,
-                    11136934192903453179u64,
-                    5243353842829040136u64,
-                    4147317391199996u64,
-                ]),
-                BigInteger([
-                    4132395126339299202u64,
-                    16336005127466142033u64,
-                    10586728948984679074u64,
-                    757625854250483881u64,
-                    13606448047433387004u64,
-                    36102016139837512u64,
-                ]),
-                BigInteger([
-                    13499941607855748403u64,
-                    13843465104755221755u64,
-                    1011167409411883667u64,
-                    8407973351399316249u64,
-                    689069071606772946u64,
-                    61675625376267808u64,
-                ]),
-                BigInteger([
-                    3322566255628090715u64,
-                    11431775112935668238u64,
-                    4990876137355815178u64,
-                    13548742375506061146u64,
-                    12192507874822243150u64,
-                    38426529269124577u64,
-                ]),
-                BigInteger([
-                    17519516578862378157u64,
-                    3496012659602892981u64,
-                    14924232906393981u64,
-                    6605731533832306392u64,
-                    13268447572107787334u64,
-                    18063194999411246u64,
-                ]),
-                BigInteger([
-                    8226889812996148980u64,
-                    270023081147009326u64,
-                    3826842023129680968u64,
-                    15366143308419252048u64,
-                    5510960207954878343u64,
-                    104381407059064295u64,
-                ]),
-                BigInteger([
-                    15380462820515678025u64,
-                    8239176710724119024u64,
-                    11852875838523362747u64,
-                    13150936481503395781u64,
-                    6884117919269476861u64,
-                    28641595223038750u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10386704902707958136u64,
-                    2708654219289837508u64,
-                    7399526516761831514u64,
-                    13700990428262287161u64,
-                    12962145523599496767u64,
-                    20474056460069716u64,
-                ]),
-                BigInteger([
-                    59985485941703479u64,
-                    17754006240266545295u64,
-                    11700801270633295858u64,
-                    4835722503637445646u64,
-                    14231200945523592160u64,
-                    83037278624848656u64,
-                ]),
-                BigInteger([
-                    4907707277946862151u64,
-                    11596959790358891684u64,
-                    8623820269642361603u64,
-                    1096654450014182670u64,
-                    4779660082823367318u64,
-                    43028617899893314u64,
-                ]),
-                BigInteger([
-                    12578669374779617092u64,
-                    16915692927781744988u64,
-                    4133593824670948945u64,
-                    1163883362174304015u64,
-                    3344928139234752404u64,
-                    95543052666035001u64,
-                ]),
-                BigInteger([
-                    1421028894434782273u64,
-                    18193063919519497419u64,
-                    8345125786328622331u64,
-                    14120841744427168241u64,
-                    1679610735817950829u64,
-                    69715860550769522u64,
-                ]),
-                BigInteger([
-                    2883319663066708270u64,
-                    14103971759157160725u64,
-                    13106035655786933233u64,
-                    12613561050078946903u64,
-                    3312263043591349136u64,
-                    115764107813575407u64,
-                ]),
-                BigInteger([
-                    5024449258477057361u64,
-                    3290561270809477657u64,
-                    16040956910956811590u64,
-                    13910431715309071554u64,
-                    13669422425632746417u64,
-                    88958130386103986u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    887394296622674955u64,
-                    14714726303286049367u64,
-                    6219013988541403275u64,
-                    15282623554174970743u64,
-                    16228231447938349656u64,
-                    7528095540163340u64,
-                ]),
-                BigInteger([
-                    9419225516884492432u64,
-                    8806351133361527349u64,
-                    15247720684382384290u64,
-                    18309020215459864994u64,
-                    16363954219257633531u64,
-                    33923321533466532u64,
-                ]),
-                BigInteger([
-                    18441464156386720462u64,
-                    6523632072030443474u64,
-                    14405785007117885518u64,
-                    17562043062969577221u64,
-                    11774693226575693947u64,
-                    68085390603055746u64,
-                ]),
-                BigInteger([
-                    13441185843808657235u64,
-                    10611247430658410506u64,
-                    17221340070754536520u64,
-                    6864697262976533586u64,
-                    8275948446575449037u64,
-                    15954032187218547u64,
-                ]),
-                BigInteger([
-                    10482895116250909533u64,
-                    2786981992798620259u64,
-                    5608624443155752350u64,
-                    15455439082186487805u64,
-                    10608118354736170890u64,
-                    46361182804339709u64,
-                ]),
-                BigInteger([
-                    8804750737045853172u64,
-                    4033010085075717087u64,
-                    8394602896383538651u64,
-                    17089063348971074872u64,
-                    2317950637104529521u64,
-                    94312933946854984u64,
-                ]),
-                BigInteger([
-                    3158311340610347476u64,
-                    16644885602342139389u64,
-                    7238020012460282403u64,
-                    9807459963126314251u64,
-                    16814660470391566660u64,
-                    11830724765818693u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    18309368571564049000u64,
-                    10553069700809324715u64,
-                    15488270660178213291u64,
-                    7339929051129202863u64,
-                    13754754343500468632u64,
-                    39598719902019167u64,
-                ]),
-                BigInteger([
-                    9914268026453459711u64,
-                    9563838374315498813u64,
-                    9877559714481322113u64,
-                    4110433279224069400u64,
-                    13609268400715425543u64,
-                    58323335633656347u64,
-                ]),
-                BigInteger([
-                    14856397322401888478u64,
-                    15988612684931529833u64,
-                    11467277884308445691u64,
-                    6470022886405913591u64,
-                    6309200465594889370u64,
-                    51683445442268279u64,
-                ]),
-                BigInteger([
-                    5494168099480712411u64,
-                    17084259002037662999u64,
-                    16485008617932260193u64,
-                    4425702530178410487u64,
-                    15060663376924934125u64,
-                    112788051647310903u64,
-                ]),
-                BigInteger([
-                    16510521694733974120u64,
-                    16373512000960330462u64,
-                    650881866957760661u64,
-                    1221960682669295710u64,
-                    10304297665760922808u64,
-                    12161662202448253u64,
-                ]),
-                BigInteger([
-                    16910399609898147440u64,
-                    7271060649079158295u64,
-                    16994507422338991868u64,
-                    2731546250097824008u64,
-                    12986953294473574052u64,
-                    97298067708698771u64,
-                ]),
-                BigInteger([
-                    13785077481498954891u64,
-                    3670541778639159802u64,
-                    12281419104849775524u64,
-                    17255611335741402551u64,
-                    12536998139332857485u64,
-                    29337001868085238u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1199387623467449741u64,
-                    4498807371117195431u64,
-                    170293435054570164u64,
-                    3537540323613857941u64,
-                    3008965312668410610u64,
-                    106528099351477845u64,
-                ]),
-                BigInteger([
-                    13009962829229228630u64,
-                    888904575901401477u64,
-                    4629274040237771961u64,
-                    15273827386645427576u64,
-                    13779866601687686531u64,
-                    13624470485347540u64,
-                ]),
-                BigInteger([
-                    902969442859404058u64,
-                    7472126978865316097u64,
-                    17133458531736018606u64,
-                    11326763598693346000u64,
-                    6266786309788049424u64,
-                    75731573396868089u64,
-                ]),
-                BigInteger([
-                    12284210306163819704u64,
-                    15767308910966891586u64,
-                    7482731321886900193u64,
-                    15359283850629190053u64,
-                    1228730195132527266u64,
-                    97321953389150063u64,
-                ]),
-                BigInteger([
-                    17116536196633466154u64,
-                    17779877665576706266u64,
-                    6759790055632890241u64,
-                    13903671137306458244u64,
-                    14215553856972595532u64,
-                    40902407840573720u64,
-                ]),
-                BigInteger([
-                    17396257270331054971u64,
-                    12826380544114680279u64,
-                    12462050679423006508u64,
-                    11193179024443698691u64,
-                    3786526182045388641u64,
-                    19747016632599617u64,
-                ]),
-                BigInteger([
-                    6699360519027879320u64,
-                    18391995979880892588u64,
-                    699430677003276125u64,
-                    18302744482342813015u64,
-                    10017537538322453619u64,
-                    86247924423106070u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7232487532383369136u64,
-                    224815727920205698u64,
-                    13905618978486776536u64,
-                    15297976953496686580u64,
-                    1230585535938394686u64,
-                    24790531710484030u64,
-                ]),
-                BigInteger([
-                    54219608593436280u64,
-                    6944661840156092878u64,
-                    112517667018328929u64,
-                    3996803671753413297u64,
-                    9438123719726296309u64,
-                    3535318890689522u64,
-                ]),
-                BigInteger([
-                    7301722779210417061u64,
-                    6629127057876774938u64,
-                    2404134276426123786u64,
-                    270345116120362398u64,
-                    2597476441693976064u64,
-                    67775262339131600u64,
-                ]),
-                BigInteger([
-                    894565765155295083u64,
-                    15781972677373579261u64,
-                    1837611266053233520u64,
-                    2356668922929279887u64,
-                    1016763016394142454u64,
-                    6307536738689310u64,
-                ]),
-                BigInteger([
-                    17667173470106373694u64,
-                    11477007887348449035u64,
-                    4770500514707605567u64,
-                    14829043845721608374u64,
-                    4162677161439396074u64,
-                    63787209804648770u64,
-                ]),
-                BigInteger([
-                    4076317305723673212u64,
-                    5696015476088218357u64,
-                    16081292069987304061u64,
-                    13158053544920147136u64,
-                    17883424926610528582u64,
-                    100205008263224665u64,
-                ]),
-                BigInteger([
-                    8317796297243068426u64,
-                    6130558809427889778u64,
-                    3559406416571626268u64,
-                    2490840329863475738u64,
-                    16049805497602357641u64,
-                    73087352249723059u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    508018566816690532u64,
-                    9585037610303570428u64,
-                    17381662247649992444u64,
-                    711605369169114867u64,
-                    4685091558591180900u64,
-                    52703094421150654u64,
-                ]),
-                BigInteger([
-                    13989574177663629056u64,
-                    12353171690602185284u64,
-                    11687414910313964772u64,
-                    15375411818223351688u64,
-                    2880315312064272148u64,
-                    20466452374578300u64,
-                ]),
-                BigInteger([
-                    1300958359210342709u64,
-                    6702704715278214139u64,
-                    9819165687152227765u64,
-                    12366023108258059479u64,
-                    3297057319594276405u64,
-                    76647156803569034u64,
-                ]),
-                BigInteger([
-                    16349566816812832903u64,
-                    5276041238051669620u64,
-                    1712078398954029636u64,
-                    3878109191122784455u64,
-                    1785979823364279987u64,
-                    20130929066749830u64,
-                ]),
-                BigInteger([
-                    11617374401704507344u64,
-                    5277247225626638501u64,
-                    1018397701201256363u64,
-                    12589120909636129994u64,
-                    8346910486903358466u64,
-                    47730543443950987u64,
-                ]),
-                BigInteger([
-                    2365204869810889758u64,
-                    8004193821164832090u64,
-                    231534850101633970u64,
-                    12580583723804627501u64,
-                    2908117749282383408u64,
-                    22057137964957115u64,
-                ]),
-                BigInteger([
-                    14275860825805784596u64,
-                    2620128457297086667u64,
-                    9945967381960491824u64,
-                    14367081009796277407u64,
-                    4260865800961339679u64,
-                    73565921411688964u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9076049786384662843u64,
-                    2544987682720894758u64,
-                    15985004115621285113u64,
-                    12603573921810509283u64,
-                    12047247580038360840u64,
-                    105572886339003627u64,
-                ]),
-                BigInteger([
-                    104577266396019573u64,
-                    5794501412346339624u64,
-                    1243276832815351261u64,
-                    3945764058302949771u64,
-                    14231358724446827271u64,
-                    23038807162911912u64,
-                ]),
-                BigInteger([
-                    4540633731147210857u64,
-                    95729015147555149u64,
-                    8134796093159185001u64,
-                    10505599816228070071u64,
-                    6390363778917904737u64,
-                    47145380127636361u64,
-                ]),
-                BigInteger([
-                    625061553478275729u64,
-                    16281670893660753887u64,
-                    7813352860350960450u64,
-                    2370946177442145036u64,
-                    13005806315194774573u64,
-                    39619765531379145u64,
-                ]),
-                BigInteger([
-                    5815722347923761726u64,
-                    2069234305912293399u64,
-                    11258834912947670585u64,
-                    11982480966948911774u64,
-                    9520560849269872496u64,
-                    108735240182702142u64,
-                ]),
-                BigInteger([
-                    15276434293310883639u64,
-                    5419021926422467702u64,
-                    8666614141587942491u64,
-                    1069213678897345151u64,
-                    9543596738215186150u64,
-                    55191653341876508u64,
-                ]),
-                BigInteger([
-                    6103356682253161199u64,
-                    4120270031830304409u64,
-                    10123764318315183467u64,
-                    7198430783598769279u64,
-                    2331422846137785495u64,
-                    26383824641161542u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8705258812433156380u64,
-                    7297539369713740965u64,
-                    5127437357273889317u64,
-                    13831047910195481606u64,
-                    3727876512308286840u64,
-                    13006662357167912u64,
-                ]),
-                BigInteger([
-                    7884103968207018275u64,
-                    13372644564160905318u64,
-                    5093761362444256402u64,
-                    17193030694745453282u64,
-                    3327514876987506815u64,
-                    74004819999110683u64,
-                ]),
-                BigInteger([
-                    10211039647277683905u64,
-                    14418197380735102059u64,
-                    3847681148474159063u64,
-                    7218158100656597162u64,
-                    9327629710444660274u64,
-                    19924525046802257u64,
-                ]),
-                BigInteger([
-                    1237839654340190214u64,
-                    11847389608365983330u64,
-                    5945941441746442629u64,
-                    735498140322852285u64,
-                    17814774150804059860u64,
-                    70957665620773053u64,
-                ]),
-                BigInteger([
-                    12099117233629274025u64,
-                    10662872733432634859u64,
-                    15896308135923798737u64,
-                    3644845206246419613u64,
-                    1555716538948271370u64,
-                    120388024255510355u64,
-                ]),
-                BigInteger([
-                    7573316203780605859u64,
-                    1841812588986797928u64,
-                    9035126394746878962u64,
-                    14772785988753867730u64,
-                    15029043379436350204u64,
-                    86744050698264689u64,
-                ]),
-                BigInteger([
-                    5195345590276437031u64,
-                    12089443285598071504u64,
-                    12096834732382728616u64,
-                    9954778497699499321u64,
-                    12719317410247695267u64,
-                    7857373274771972u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17958814866285109422u64,
-                    16267227836467663190u64,
-                    1680196632322652950u64,
-                    5973790811585268159u64,
-                    18233225708111626651u64,
-                    86193085262188704u64,
-                ]),
-                BigInteger([
-                    9323971306789145440u64,
-                    15838034094994411314u64,
-                    8498530604831519292u64,
-                    7825535809641549189u64,
-                    9788368149204271009u64,
-                    47441691417026747u64,
-                ]),
-                BigInteger([
-                    3944724488064156224u64,
-                    292353992545576362u64,
-                    993712948032646735u64,
-                    15411292015801116927u64,
-                    15145429336374756513u64,
-                    38852705376971213u64,
-                ]),
-                BigInteger([
-                    1643207523069451469u64,
-                    16834345416840216295u64,
-                    11220131462579363417u64,
-                    5771085700497786227u64,
-                    5267428510108466159u64,
-                    110609712326052238u64,
-                ]),
-                BigInteger([
-                    11774532639610839558u64,
-                    13601251112371842687u64,
-                    15338257787299687543u64,
-                    619304645349841326u64,
-                    16953634584793952145u64,
-                    67823471289058316u64,
-                ]),
-                BigInteger([
-                    7242464144318033529u64,
-                    6148417030373855032u64,
-                    11945369523208609332u64,
-                    2336213038630983630u64,
-                    3542351635405770455u64,
-                    105934480100450997u64,
-                ]),
-                BigInteger([
-                    13590122401045087747u64,
-                    5571473517857240636u64,
-                    8572663113609237259u64,
-                    8040528222586872658u64,
-                    8379457989072245393u64,
-                    23221050111438658u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3349914892989122937u64,
-                    2853863737146333461u64,
-                    17040189398671086965u64,
-                    13538761090799794731u64,
-                    16681152731903558987u64,
-                    31320393078453686u64,
-                ]),
-                BigInteger([
-                    5515285650694598775u64,
-                    12043530498156961502u64,
-                    15725485046309806777u64,
-                    16369647615937504849u64,
-                    2249929961644666899u64,
-                    65593501998609245u64,
-                ]),
-                BigInteger([
-                    4620333571496923584u64,
-                    3762758971161098205u64,
-                    12660657228936833390u64,
-                    11337362464593799854u64,
-                    7844879431595535003u64,
-                    67620574137145676u64,
-                ]),
-                BigInteger([
-                    5220564246031823156u64,
-                    13831274411841922361u64,
-                    3856843243161236904u64,
-                    12922045466416535130u64,
-                    1719883745664268126u64,
-                    43715772578651820u64,
-                ]),
-                BigInteger([
-                    11658572790780825152u64,
-                    12278716841861833062u64,
-                    12162159446528561499u64,
-                    5279111324903084540u64,
-                    7113532777764080797u64,
-                    13491705431873792u64,
-                ]),
-                BigInteger([
-                    7289899114408793781u64,
-                    17507359465889261692u64,
-                    1279418263263708531u64,
-                    3304340293232974223u64,
-                    14708436856340447176u64,
-                    112060123443559752u64,
-                ]),
-                BigInteger([
-                    5011806820471889084u64,
-                    3140839737988610958u64,
-                    15442485569012217787u64,
-                    4521325877580899039u64,
-                    4486863724569711293u64,
-                    48528648135704186u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9610555307852609762u64,
-                    17520750890339633259u64,
-                    13891253011636450935u64,
-                    14139464857365861468u64,
-                    18011923310953070661u64,
-                    111729634861844637u64,
-                ]),
-                BigInteger([
-                    12967820519393211262u64,
-                    17806260316433776535u64,
-                    7314967276302908089u64,
-                    12008243363599936970u64,
-                    18199126102221909697u64,
-                    35116103137812590u64,
-                ]),
-                BigInteger([
-                    9010470331778802098u64,
-                    9190270414134747841u64,
-                    2695080311422371920u64,
-                    956307228246484985u64,
-                    16490098488890778397u64,
-                    65679928437975381u64,
-                ]),
-                BigInteger([
-                    1988738931128256954u64,
-                    988326784246754033u64,
-                    13590742592909898172u64,
-                    11940176861200100199u64,
-                    6197623356876938884u64,
-                    34812976779236940u64,
-                ]),
-                BigInteger([
-                    8835486465204935179u64,
-                    13033687527635366693u64,
-                    9078431869418342318u64,
-                    13842480080258331576u64,
-                    13179226740471788049u64,
-                    30733256337933150u64,
-                ]),
-                BigInteger([
-                    16055815932440489758u64,
-                    11100591990179237472u64,
-                    7000194910496006683u64,
-                    6058455169240780513u64,
-                    6353275485431507564u64,
-                    64025603257376336u64,
-                ]),
-                BigInteger([
-                    16869954862336749790u64,
-                    16167040862555909597u64,
-                    2115812831489159207u64,
-                    88515531153664292u64,
-                    18302554740800638912u64,
-                    92491049332394591u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2692397375324561806u64,
-                    15464216388281647199u64,
-                    9167738142315160643u64,
-                    4850727533007820224u64,
-                    12765808330120063040u64,
-                    14958147615890298u64,
-                ]),
-                BigInteger([
-                    12042606288146423701u64,
-                    7512957259637453441u64,
-                    5280598870654437333u64,
-                    2638963831323652098u64,
-                    6591298339190175520u64,
-                    7662302793928182u64,
-                ]),
-                BigInteger([
-                    8789411774272401865u64,
-                    15332488451859445961u64,
-                    11376333742044432076u64,
-                    13644166422213288618u64,
-                    8180952148470915608u64,
-                    92938084816588300u64,
-                ]),
-                BigInteger([
-                    17698437008660465473u64,
-                    3485239920889081210u64,
-                    16614384932226895996u64,
-                    15453255647135761991u64,
-                    15531988416106532577u64,
-                    55040049787829980u64,
-                ]),
-                BigInteger([
-                    4717514738783718422u64,
-                    7471016296100001909u64,
-                    2585131137641358328u64,
-                    3365624375576948744u64,
-                    14702656721922622323u64,
-                    77800420600470231u64,
-                ]),
-                BigInteger([
-                    3996904548429097770u64,
-                    16917554505428551856u64,
-                    12709948504169229623u64,
-                    1939683132979536659u64,
-                    14800804753921088684u64,
-                    5800386199064169u64,
-                ]),
-                BigInteger([
-                    9998853601252240281u64,
-                    18090365430508896470u64,
-                    8185270834056254299u64,
-                    7565391405118101257u64,
-                    15682110589345588695u64,
-                    54107492946647464u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6861934304566459367u64,
-                    5140010428494426978u64,
-                    11235419659805806024u64,
-                    13096879175817875974u64,
-                    12548367544386290559u64,
-                    68113773962182074u64,
-                ]),
-                BigInteger([
-                    8159516604889314431u64,
-                    15832932308479887682u64,
-                    2924039006451973811u64,
-                    4234091508722611764u64,
-                    16573518716563389337u64,
-                    56548380179595786u64,
-                ]),
-                BigInteger([
-                    5724828547906966342u64,
-                    9399252291982931179u64,
-                    14709207394805335319u64,
-                    13325044723865602514u64,
-                    11926446951996890964u64,
-                    118344348017846366u64,
-                ]),
-                BigInteger([
-                    13236797026782275503u64,
-                    10249298288647562750u64,
-                    16463130679172063181u64,
-                    5524818848800365568u64,
-                    11445337181084871106u64,
-                    67508721598673856u64,
-                ]),
-                BigInteger([
-                    15583748812430863325u64,
-                    9828635119666085862u64,
-                    16496961843805149165u64,
-                    15215566971635327659u64,
-                    7490985940367700402u64,
-                    10408204946759136u64,
-                ]),
-                BigInteger([
-                    3890747966339562329u64,
-                    14261944057195678223u64,
-                    15408290148209186593u64,
-                    8840824806639118u64,
-                    4046228507327548996u64,
-                    82681079190148473u64,
-                ]),
-                BigInteger([
-                    9773940870927650381u64,
-                    3779221575896019864u64,
-                    4737242842944515260u64,
-                    7280497081669679824u64,
-                    6213402062720951257u64,
-                    116413995592482616u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14261520664686174222u64,
-                    10246372991241620519u64,
-                    14350955214864869147u64,
-                    10204523197958095108u64,
-                    13447123997666361474u64,
-                    109634036747657217u64,
-                ]),
-                BigInteger([
-                    12188938675184592538u64,
-                    15698254332557853550u64,
-                    14475325885888475475u64,
-                    13622235835480191320u64,
-                    11312896046412335300u64,
-                    107646416667004060u64,
-                ]),
-                BigInteger([
-                    5036956791295519083u64,
-                    14984815000746219105u64,
-                    8850034841523083334u64,
-                    3899723998446683419u64,
-                    9319938660972119580u64,
-                    102402898213171247u64,
-                ]),
-                BigInteger([
-                    2126197400903403581u64,
-                    15234897263710740804u64,
-                    10987582491309902175u64,
-                    12173995662489725442u64,
-                    3817021665118976663u64,
-                    3740818881363241u64,
-                ]),
-                BigInteger([
-                    17127329160833372874u64,
-                    17364457289991929648u64,
-                    6410799927076292036u64,
-                    13760660293743207472u64,
-                    11712141599462461553u64,
-                    96996677234027483u64,
-                ]),
-                BigInteger([
-                    16511197582243829034u64,
-                    14336453351436392926u64,
-                    6700355229812623397u64,
-                    248710427424969170u64,
-                    14151215166832672258u64,
-                    98150085800178713u64,
-                ]),
-                BigInteger([
-                    7964742385720768635u64,
-                    15711705503848957181u64,
-                    3959202045938029161u64,
-                    1682579203754763446u64,
-                    16972462177859363692u64,
-                    33169259222596679u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5524162289225068846u64,
-                    3940693695486105588u64,
-                    7635168135023895890u64,
-                    15810145510972276058u64,
-                    4049056806341347792u64,
-                    88632643660292336u64,
-                ]),
-                BigInteger([
-                    527676235468714960u64,
-                    10069519070373184579u64,
-                    5876422136528079985u64,
-                    10946371543175103812u64,
-                    12023688759236368423u64,
-                    38707709679928630u64,
-                ]),
-                BigInteger([
-                    751026060918651177u64,
-                    16348214734453294976u64,
-                    6929984846076276022u64,
-                    13013380572250394602u64,
-                    11531259152226004887u64,
-                    41168697259193657u64,
-                ]),
-                BigInteger([
-                    4683547509259019784u64,
-                    7205120400441133205u64,
-                    11252360170528254104u64,
-                    16622936526691541433u64,
-                    5561449325140628606u64,
-                    80730927688855354u64,
-                ]),
-                BigInteger([
-                    3502705204677290579u64,
-                    11230085148492702892u64,
-                    459890158431662323u64,
-                    4878084512767989841u64,
-                    4377265855882279264u64,
-                    109818108935535269u64,
-                ]),
-                BigInteger([
-                    16285630279831777338u64,
-                    1791696373243807130u64,
-                    14516428728529323342u64,
-                    15096493633028079824u64,
-                    7578672850559733886u64,
-                    17888189608492407u64,
-                ]),
-                BigInteger([
-                    18276574658097375281u64,
-                    805778033767626875u64,
-                    642015477889200786u64,
-                    207820296393348618u64,
-                    8652781690967488888u64,
-                    28899258617637141u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    380766960108903223u64,
-                    15687779331115194610u64,
-                    12089928463060077438u64,
-                    16735823402565750372u64,
-                    8904861512005694105u64,
-                    102038357787192827u64,
-                ]),
-                BigInteger([
-                    14644851875857485082u64,
-                    4531098406056313931u64,
-                    1666654574627021435u64,
-                    1837147481376863725u64,
-                    10534704154737462738u64,
-                    114710343304311950u64,
-                ]),
-                BigInteger([
-                    14520823104150429904u64,
-                    5374198416618408818u64,
-                    12895565015086395302u64,
-                    7611934233115228351u64,
-                    12291512282482701633u64,
-                    78476718690905876u64,
-                ]),
-                BigInteger([
-                    6917582170014730391u64,
-                    12978623733394424u64,
-                    16289348653174083780u64,
-                    9147305412812544531u64,
-                    2598642830356548261u64,
-                    60744596693075853u64,
-                ]),
-                BigInteger([
-                    14261492527932229267u64,
-                    16383898249874652753u64,
-                    8823176600889745350u64,
-                    1977521906607124941u64,
-                    3807204065263615167u64,
-                    48598951270390285u64,
-                ]),
-                BigInteger([
-                    12551192254928116587u64,
-                    17272865888856923083u64,
-                    5847898560420731053u64,
-                    3354188056606476415u64,
-                    992213545923072246u64,
-                    72535207640368094u64,
-                ]),
-                BigInteger([
-                    9094481639320902320u64,
-                    14613495233134514653u64,
-                    3102303456715589932u64,
-                    17995633657845517834u64,
-                    11097412409215767689u64,
-                    24427584060281067u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10078893529334269218u64,
-                    14805800222378367249u64,
-                    12058746152009108700u64,
-                    17591394091832588893u64,
-                    14612151324446572565u64,
-                    11320898076176244u64,
-                ]),
-                BigInteger([
-                    3579980748150927372u64,
-                    15230675898904746678u64,
-                    15219836490937304634u64,
-                    17952625753715589337u64,
-                    2077121792987944180u64,
-                    110927943673853910u64,
-                ]),
-                BigInteger([
-                    9815394401752538586u64,
-                    5585602099805688068u64,
-                    1243995516813831411u64,
-                    14754784596024401348u64,
-                    6674219108001671680u64,
-                    55431733319806584u64,
-                ]),
-                BigInteger([
-                    13050581923041783902u64,
-                    304865560017384254u64,
-                    1343770339156122985u64,
-                    8695767978499047754u64,
-                    14009820940376769353u64,
-                    94997350706242340u64,
-                ]),
-                BigInteger([
-                    17957030920112134654u64,
-                    3796500780305978786u64,
-                    15213550172488953317u64,
-                    8989796618033066733u64,
-                    12434835771764553579u64,
-                    77891603021773969u64,
-                ]),
-                BigInteger([
-                    1916833984809164661u64,
-                    15685571162006765033u64,
-                    5086714207859566428u64,
-                    14501570316455944357u64,
-                    5548315973778225388u64,
-                    59636394879346656u64,
-                ]),
-                BigInteger([
-                    7732381077776013521u64,
-                    14340869852424738964u64,
-                    10535276996171978463u64,
-                    14800758687577082974u64,
-                    18298882945181269842u64,
-                    63446445714700963u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11372619160652285382u64,
-                    1198830389921012287u64,
-                    4933398352271003114u64,
-                    7133006422366400809u64,
-                    3004959081850450737u64,
-                    57398063634827692u64,
-                ]),
-                BigInteger([
-                    15041431046644655323u64,
-                    15007778856125779978u64,
-                    11492145636660504014u64,
-                    9789460169246621050u64,
-                    5658973033303877703u64,
-                    88603048603227040u64,
-                ]),
-                BigInteger([
-                    6821131712093022018u64,
-                    12613109347395810447u64,
-                    5517140748565080947u64,
-                    8923453279731418871u64,
-                    15791088832836363502u64,
-                    104842503688199304u64,
-                ]),
-                BigInteger([
-                    12391545819633964837u64,
-                    13177635287101125387u64,
-                    4785105681755483938u64,
-                    13943222169727236498u64,
-                    18140533934954689559u64,
-                    64305108139900633u64,
-                ]),
-                BigInteger([
-                    1128398998633533858u64,
-                    8448884948977607477u64,
-                    15446309885516858757u64,
-                    14958586997285500968u64,
-                    18394232384756039893u64,
-                    35806233578538547u64,
-                ]),
-                BigInteger([
-                    12507986548689514624u64,
-                    15499313023623630567u64,
-                    4485019805853035992u64,
-                    2389928409069598247u64,
-                    242381257917050284u64,
-                    48117479195560835u64,
-                ]),
-                BigInteger([
-                    17166843736823751953u64,
-                    11293447070223195636u64,
-                    6756335447925626899u64,
-                    12219250302479295914u64,
-                    4820883637824854165u64,
-                    12137510895286287u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8886964736835410709u64,
-                    16951708395812917336u64,
-                    12773816548892927133u64,
-                    17521697558707087479u64,
-                    7820438042927998606u64,
-                    31870870514701923u64,
-                ]),
-                BigInteger([
-                    15125079704157354548u64,
-                    6591291901520865935u64,
-                    9277130405507059938u64,
-                    13268055637787596349u64,
-                    15280090933772253311u64,
-                    21287617089036656u64,
-                ]),
-                BigInteger([
-                    16820029463020225254u64,
-                    4038521858077264855u64,
-                    12858708274094987201u64,
-                    12967432643263554190u64,
-                    2929583425157551744u64,
-                    92215160944047223u64,
-                ]),
-                BigInteger([
-                    4495858427774038025u64,
-                    3622625550681239858u64,
-                    12500232426269180105u64,
-                    14723337122403575884u64,
-                    12924991385044430779u64,
-                    67760985867089342u64,
-                ]),
-                BigInteger([
-                    3501807433385164210u64,
-                    13744635145453291663u64,
-                    12223811475980733316u64,
-                    9587904333139718639u64,
-                    17860813370729812799u64,
-                    61495074444539338u64,
-                ]),
-                BigInteger([
-                    6499995376087634751u64,
-                    16148407514234177632u64,
-                    2875695413121418791u64,
-                    882413138856677349u64,
-                    1599320421116720113u64,
-                    14327662709686552u64,
-                ]),
-                BigInteger([
-                    1260981703010929137u64,
-                    15518100877472429972u64,
-                    9180131967777530371u64,
-                    7122326251243814163u64,
-                    17574592606249896270u64,
-                    104970960553605550u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8995124358051051762u64,
-                    10752151033682069820u64,
-                    8778938458053681750u64,
-                    10805922039978644407u64,
-                    13713283943419383801u64,
-                    4411782009639510u64,
-                ]),
-                BigInteger([
-                    2208000614389684617u64,
-                    16435101832873118992u64,
-                    9923127430651546830u64,
-                    774369680020893636u64,
-                    6389309426870079352u64,
-                    35913475445773986u64,
-                ]),
-                BigInteger([
-                    10873895574460045019u64,
-                    6364331692257434291u64,
-                    6091347917509832957u64,
-                    12177777808068285307u64,
-                    11184113866383160256u64,
-                    38720250914368832u64,
-                ]),
-                BigInteger([
-                    5930098992275747482u64,
-                    15585037086621764295u64,
-                    17793509003822507674u64,
-                    4183624152175809171u64,
-                    2795452780407149298u64,
-                    59640368998080848u64,
-                ]),
-                BigInteger([
-                    3040861509589141062u64,
-                    833925235699428424u64,
-                    7654802133194752520u64,
-                    12367984692006866842u64,
-                    7670319442254812853u64,
-                    67043555957463403u64,
-                ]),
-                BigInteger([
-                    7600934451396253907u64,
-                    5802748102371082098u64,
-                    6410700888414323706u64,
-                    2641519747390798906u64,
-                    17766309450100108012u64,
-                    68045842587239532u64,
-                ]),
-                BigInteger([
-                    6723392192853971825u64,
-                    13458336185345230886u64,
-                    9590570049312274135u64,
-                    14298144542680429831u64,
-                    14754616338589175671u64,
-                    68479456562911537u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17537168846282145605u64,
-                    13701915074879635617u64,
-                    4101906500954599027u64,
-                    9391510807338023124u64,
-                    15245766694742620270u64,
-                    65017781779012961u64,
-                ]),
-                BigInteger([
-                    11436736312474947585u64,
-                    5500923808604716263u64,
-                    11055822567278382423u64,
-                    12489836431008163799u64,
-                    9457109625245673826u64,
-                    109211975832444856u64,
-                ]),
-                BigInteger([
-                    6278715576998879398u64,
-                    6687007641172251567u64,
-                    9717950713505184383u64,
-                    6444713302575920356u64,
-                    16170762810316640311u64,
-                    32460008066778659u64,
-                ]),
-                BigInteger([
-                    15003874754238195870u64,
-                    8177022593599332333u64,
-                    353888974830035825u64,
-                    8152502486153937760u64,
-                    11090554566003765128u64,
-                    13628215643948155u64,
-                ]),
-                BigInteger([
-                    3792070100125513025u64,
-                    10077074899478341499u64,
-                    16909974258879936751u64,
-                    16325787461580381972u64,
-                    17234442106571822202u64,
-                    21110610751498555u64,
-                ]),
-                BigInteger([
-                    10729128649699519364u64,
-                    12541165815468772237u64,
-                    18371290861720493307u64,
-                    2934499049868300588u64,
-                    10759579642339942542u64,
-                    28664327006839258u64,
-                ]),
-                BigInteger([
-                    14295891649685431554u64,
-                    15455594308654011223u64,
-                    16435221080116946822u64,
-                    1602600604647991751u64,
-                    8776015059763976692u64,
-                    52533097047312253u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5335501634394230466u64,
-                    17081835670023014404u64,
-                    10923867705588712636u64,
-                    9227462145616328628u64,
-                    16588794832003805810u64,
-                    37644632921709830u64,
-                ]),
-                BigInteger([
-                    18227954031627278089u64,
-                    10520792766315647143u64,
-                    2281817278471828052u64,
-                    18027957351667329993u64,
-                    18305782002873031528u64,
-                    83621218649176051u64,
-                ]),
-                BigInteger([
-                    6554577915781115555u64,
-                    3265115586783910854u64,
-                    10321724319913240060u64,
-                    10884711497489075443u64,
-                    934257931477549207u64,
-                    101898309690388454u64,
-                ]),
-                BigInteger([
-                    9024392947513197748u64,
-                    18392615766558640067u64,
-                    9519781529397440453u64,
-                    3308455163873832452u64,
-                    17356516315582069684u64,
-                    116173590060704049u64,
-                ]),
-                BigInteger([
-                    8356381359566886018u64,
-                    1875761970551081115u64,
-                    7334868604030919655u64,
-                    9915693945948005613u64,
-                    14017938535075621839u64,
-                    86526311939219148u64,
-                ]),
-                BigInteger([
-                    7524864367860977449u64,
-                    14332110247820627044u64,
-                    12696584453818144352u64,
-                    3137989516291331565u64,
-                    359993181446261715u64,
-                    85685547717925558u64,
-                ]),
-                BigInteger([
-                    14615992413893907150u64,
-                    15916381471184384827u64,
-                    12430778008224633162u64,
-                    4101774397883414573u64,
-                    4647526107775095822u64,
-                    40678921611715964u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10190701734551873973u64,
-                    17222220559016637078u64,
-                    9088026740743144553u64,
-                    3743387837859681720u64,
-                    14010096148299799683u64,
-                    30127071166114214u64,
-                ]),
-                BigInteger([
-                    7130538853512572098u64,
-                    15342401409275056939u64,
-                    8814460507517111696u64,
-                    16304731422823613127u64,
-                    15463651595122687066u64,
-                    70597465873500060u64,
-                ]),
-                BigInteger([
-                    6108941378138896320u64,
-                    15325171846909799205u64,
-                    10375175766115873147u64,
-                    3093248019136792898u64,
-                    17240629098253666018u64,
-                    86464350571271046u64,
-                ]),
-                BigInteger([
-                    6993937887047180719u64,
-                    459417434912968439u64,
-                    2321969175542590171u64,
-                    2654309903932148113u64,
-                    10133832891035259442u64,
-                    93902378518830303u64,
-                ]),
-                BigInteger([
-                    17764847088463538593u64,
-                    6877618859204153470u64,
-                    11687921422790603332u64,
-                    5251830215395818477u64,
-                    7506472913704583926u64,
-                    75407712127343268u64,
-                ]),
-                BigInteger([
-                    10616045462393897085u64,
-                    2689443110626170205u64,
-                    14218469022429542399u64,
-                    16647036839817636618u64,
-                    13704276334890028051u64,
-                    64003290193882454u64,
-                ]),
-                BigInteger([
-                    6885935607091133079u64,
-                    14372008044248637371u64,
-                    13890771938174320844u64,
-                    8200774592050609600u64,
-                    15569862548020584592u64,
-                    84086098347719036u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5778318947788154652u64,
-                    11146069673667401986u64,
-                    15162129187600429560u64,
-                    12559630618511533491u64,
-                    7545478352070211093u64,
-                    94999854677818684u64,
-                ]),
-                BigInteger([
-                    13746331726878474028u64,
-                    624644232765356298u64,
-                    7027004897892389635u64,
-                    13244274509649384269u64,
-                    11323586239522445353u64,
-                    43617778636639649u64,
-                ]),
-                BigInteger([
-                    6608062871229657741u64,
-                    16658847250858905306u64,
-                    4131186843955194540u64,
-                    12615666725289723507u64,
-                    17485260007919949099u64,
-                    99555416862250510u64,
-                ]),
-                BigInteger([
-                    7191603884595629932u64,
-                    16507105695281339024u64,
-                    13632333455587765410u64,
-                    17701645558411803032u64,
-                    6130609952747394325u64,
-                    27691587853048943u64,
-                ]),
-                BigInteger([
-                    8379014148541045696u64,
-                    6717180851158195761u64,
-                    611928306375644896u64,
-                    10174543829124017173u64,
-                    5265573567708891034u64,
-                    51475335170231731u64,
-                ]),
-                BigInteger([
-                    3966973932947712000u64,
-                    7606533525357308909u64,
-                    9621714526296271177u64,
-                    15062711006007093287u64,
-                    17609034016937212117u64,
-                    119301988660715231u64,
-                ]),
-                BigInteger([
-                    6337682173159859222u64,
-                    15987301341744287497u64,
-                    8807301820082565935u64,
-                    9802953740035183300u64,
-                    12912555275757498288u64,
-                    84385476257546782u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16180737913562518310u64,
-                    15071647016621288792u64,
-                    4473844644609154666u64,
-                    12433996893643590885u64,
-                    17930794346309941462u64,
-                    119272861728314085u64,
-                ]),
-                BigInteger([
-                    8212082230117212942u64,
-                    12020312255927107460u64,
-                    15281070081786131983u64,
-                    6053975317707781211u64,
-                    11474147296714167500u64,
-                    32672446596511980u64,
-                ]),
-                BigInteger([
-                    17029762699455959970u64,
-                    16265451542647180057u64,
-                    3780813993051664968u64,
-                    13373414545038750597u64,
-                    13691372061369519207u64,
-                    99768775026677221u64,
-                ]),
-                BigInteger([
-                    3698700307175054517u64,
-                    13443865022617630147u64,
-                    8502389885487682940u64,
-                    14191302683790569645u64,
-                    15311775187666693836u64,
-                    72537626694952335u64,
-                ]),
-                BigInteger([
-                    7219729446721280150u64,
-                    3767210579302198771u64,
-                    883043933443298355u64,
-                    16649709574896933589u64,
-                    5306415774730002295u64,
-                    3267598453737816u64,
-                ]),
-                BigInteger([
-                    4529927549419206337u64,
-                    915804954293083363u64,
-                    13405547657329272784u64,
-                    3333839047599239624u64,
-                    5041612764980072771u64,
-                    7454071060918147u64,
-                ]),
-                BigInteger([
-                    4445854159204414447u64,
-                    3418709548188446593u64,
-                    2138217749133532156u64,
-                    11825656455780743465u64,
-                    8051683537451187296u64,
-                    80854773348043041u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9981196554003102397u64,
-                    12229831819200385069u64,
-                    7876821565629853012u64,
-                    691443182242184917u64,
-                    10933077247512731263u64,
-                    26619645698317154u64,
-                ]),
-                BigInteger([
-                    3039679198914975151u64,
-                    4335441186147636005u64,
-                    7315929474336142406u64,
-                    2728528238982801947u64,
-                    3424397195475493470u64,
-                    29216467417403345u64,
-                ]),
-                BigInteger([
-                    4724545625945680790u64,
-                    5731343212906786184u64,
-                    4499429469878684374u64,
-                    17900372821896916713u64,
-                    8165483701310413016u64,
-                    63317690578578190u64,
-                ]),
-                BigInteger([
-                    13093946844891126525u64,
-                    11913621301028097258u64,
-                    9469056355938136072u64,
-                    13352436803895297537u64,
-                    15629112731490924724u64,
-                    98444011343269049u64,
-                ]),
-                BigInteger([
-                    99824939249153897u64,
-                    9749858867439809771u64,
-                    7025419221851506714u64,
-                    13292429195388345068u64,
-                    13384275955150743383u64,
-                    7779227255660765u64,
-                ]),
-                BigInteger([
-                    2545840908125176375u64,
-                    4870408880853098928u64,
-                    9410726542774947884u64,
-                    10424888529908713363u64,
-                    15555176851050883949u64,
-                    111923503048805179u64,
-                ]),
-                BigInteger([
-                    8605274935789222317u64,
-                    595589727796195794u64,
-                    6393526260855498943u64,
-                    5956567348278636804u64,
-                    8865893634605769927u64,
-                    43557162702075610u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4085466693991072414u64,
-                    10933105415092882537u64,
-                    2770840071271644693u64,
-                    11016941630522638182u64,
-                    1914822961227040154u64,
-                    8022156338981000u64,
-                ]),
-                BigInteger([
-                    5398992382658553560u64,
-                    16466189984836195576u64,
-                    4761169489992600887u64,
-                    12555437025286271674u64,
-                    1448854528358924264u64,
-                    11045955402298241u64,
-                ]),
-                BigInteger([
-                    11708968906594495558u64,
-                    3837306489545658694u64,
-                    8381475962717857689u64,
-                    15719083004060723378u64,
-                    15374343892022136430u64,
-                    29074063246099830u64,
-                ]),
-                BigInteger([
-                    12115197285825494347u64,
-                    5672603373403451357u64,
-                    7386122338359731877u64,
-                    9568141546233273735u64,
-                    2638253987466439437u64,
-                    77019865722206124u64,
-                ]),
-                BigInteger([
-                    6219381731703731579u64,
-                    13786288154966917993u64,
-                    6370980948720138201u64,
-                    3388355497873165203u64,
-                    17339579813408114090u64,
-                    109625641949646640u64,
-                ]),
-                BigInteger([
-                    2977590461990460750u64,
-                    10472205757414566455u64,
-                    5475933787965861033u64,
-                    9446015697527696777u64,
-                    7455210322855437440u64,
-                    109792964271726150u64,
-                ]),
-                BigInteger([
-                    11182134442118824925u64,
-                    15240603662684623153u64,
-                    6474256823989996497u64,
-                    4883667511907673415u64,
-                    6003030311087828417u64,
-                    97593254129070698u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    18145468123157421707u64,
-                    5748784529953138513u64,
-                    10682493830077300627u64,
-                    17130559305512907548u64,
-                    13069100289324041154u64,
-                    39755717669836068u64,
-                ]),
-                BigInteger([
-                    9004808635090964742u64,
-                    513712892303414936u64,
-                    13670043518722744562u64,
-                    9997358098859706208u64,
-                    7560268096956452772u64,
-                    108410549008427847u64,
-                ]),
-                BigInteger([
-                    2155183340710653239u64,
-                    10337792093613267079u64,
-                    15936632121118504088u64,
-                    14043179209726960583u64,
-                    15861075480247054175u64,
-                    98891767260355669u64,
-                ]),
-                BigInteger([
-                    7057512851454347426u64,
-                    861869488081902061u64,
-                    3976142824373043232u64,
-                    687592446673760652u64,
-                    2023530873756631705u64,
-                    80150909620209135u64,
-                ]),
-                BigInteger([
-                    8213151512025987372u64,
-                    9048078526716421619u64,
-                    6352813218243565621u64,
-                    7361067361684592453u64,
-                    14811348758691032903u64,
-                    31589798853126315u64,
-                ]),
-                BigInteger([
-                    5926522762879684997u64,
-                    4703321504823691160u64,
-                    5388613043912406570u64,
-                    17431787399708665735u64,
-                    1056652491290898661u64,
-                    13030816779285468u64,
-                ]),
-                BigInteger([
-                    5799156851116478370u64,
-                    11493044848572612942u64,
-                    8772269809714568489u64,
-                    2522726030344566094u64,
-                    8897805145507314999u64,
-                    38574499321834268u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2766468839610026578u64,
-                    7851160088852291051u64,
-                    6426505970144313631u64,
-                    7247990158049828247u64,
-                    18254926055591005640u64,
-                    112590445013822404u64,
-                ]),
-                BigInteger([
-                    9274358013431974426u64,
-                    9989372470471679973u64,
-                    12775043216203207122u64,
-                    4474226952898330783u64,
-                    14521925814834520079u64,
-                    2281147132346921u64,
-                ]),
-                BigInteger([
-                    13763013518367527971u64,
-                    16923565173022277209u64,
-                    10755655278404146528u64,
-                    1583815873243095176u64,
-                    15750951943245516706u64,
-                    94077637391070388u64,
-                ]),
-                BigInteger([
-                    18256644221961547674u64,
-                    1945021742064335357u64,
-                    13143477641534676091u64,
-                    18149860365771973144u64,
-                    1843464815070674767u64,
-                    46762014761713097u64,
-                ]),
-                BigInteger([
-                    10608731604165870700u64,
-                    4282046538713145104u64,
-                    14888882657375935224u64,
-                    14917359100432813488u64,
-                    16033873951339219018u64,
-                    26533655369567833u64,
-                ]),
-                BigInteger([
-                    5315116756737129459u64,
-                    7289789451476103123u64,
-                    6892564398176684120u64,
-                    10680680143514567475u64,
-                    3543899914375673023u64,
-                    63731995636774722u64,
-                ]),
-                BigInteger([
-                    82643774620735822u64,
-                    13684330925261078599u64,
-                    11714852286445045741u64,
-                    17879449994240914223u64,
-                    650842123104562267u64,
-                    112712762104226806u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9028531634420638860u64,
-                    5052142442368798441u64,
-                    5600727350619006884u64,
-                    7577436796031401082u64,
-                    17393902150635396792u64,
-                    44692598678399840u64,
-                ]),
-                BigInteger([
-                    17785724534524287279u64,
-                    9871875052890775614u64,
-                    6560207068725673639u64,
-                    9335411079916055522u64,
-                    3050002609834756005u64,
-                    108243971865349947u64,
-                ]),
-                BigInteger([
-                    11927580369402598196u64,
-                    16117407746100776939u64,
-                    13546132174723145149u64,
-                    17742662025192391302u64,
-                    7594010064669607848u64,
-                    58836902831584912u64,
-                ]),
-                BigInteger([
-                    4842672147523445355u64,
-                    14932982903017292638u64,
-                    3246023540111182899u64,
-                    1691957909666825077u64,
-                    11411439509082967783u64,
-                    68463974255367860u64,
-                ]),
-                BigInteger([
-                    1230306260836287008u64,
-                    10782224082749012820u64,
-                    18249008144350084878u64,
-                    17498519218949986322u64,
-                    17096891164126286286u64,
-                    116767104888964161u64,
-                ]),
-                BigInteger([
-                    13047508493562447776u64,
-                    1946642157782469619u64,
-                    6530677189805050790u64,
-                    8758118940922817413u64,
-                    6482984944071466416u64,
-                    94830702187949803u64,
-                ]),
-                BigInteger([
-                    52505915416834067u64,
-                    17839747529753206577u64,
-                    8360594813902092184u64,
-                    10021391578842969233u64,
-                    9436792678186149198u64,
-                    42443431076330398u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9527302511164052371u64,
-                    9323379765934117207u64,
-                    7103002963670396907u64,
-                    15549782845790654695u64,
-                    16370382675415008768u64,
-                    1476089662835600u64,
-                ]),
-                BigInteger([
-                    11087007556775543446u64,
-                    13521411703121375390u64,
-                    5929019908962497259u64,
-                    14279006825563741019u64,
-                    5950458889830953863u64,
-                    67181707667943249u64,
-                ]),
-                BigInteger([
-                    5899407426880050988u64,
-                    6532455579835644332u64,
-                    5123739799086965431u64,
-                    7125624094483685081u64,
-                    5200100440957252682u64,
-                    9419911666482827u64,
-                ]),
-                BigInteger([
-                    4635820969854721636u64,
-                    12859925575036833597u64,
-                    6148506386832185750u64,
-                    937395702803218715u64,
-                    119915264702357092u64,
-                    40204585192103408u64,
-                ]),
-                BigInteger([
-                    17255721452532396484u64,
-                    2173860913274383823u64,
-                    2844416332512295135u64,
-                    14397412057022869560u64,
-                    14663538182607373743u64,
-                    46465045612546212u64,
-                ]),
-                BigInteger([
-                    10006479713680372972u64,
-                    7207294943577533137u64,
-                    15512832254255506651u64,
-                    12807177811577187611u64,
-                    1621302446993917987u64,
-                    44891546186858720u64,
-                ]),
-                BigInteger([
-                    10236787514444636294u64,
-                    12339021377266359707u64,
-                    16508845069530474447u64,
-                    1425889484445473667u64,
-                    9530613035746088437u64,
-                    116141520908185630u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9635746003151186183u64,
-                    8267416617285490385u64,
-                    17560337594760341437u64,
-                    385970646232689762u64,
-                    94176709522302107u64,
-                    60505679509859414u64,
-                ]),
-                BigInteger([
-                    882454855730119333u64,
-                    5916924857488793789u64,
-                    2330225783791114878u64,
-                    8049505866582536582u64,
-                    7947188564329074626u64,
-                    45874354415349252u64,
-                ]),
-                BigInteger([
-                    1808037134705124285u64,
-                    1484871806474054823u64,
-                    8525732231221651627u64,
-                    15275160951470175345u64,
-                    13993336775170438340u64,
-                    117118266414467075u64,
-                ]),
-                BigInteger([
-                    1832819236226053274u64,
-                    8852994419894140503u64,
-                    5545289826650600689u64,
-                    10371513446564818230u64,
-                    9037176616445749978u64,
-                    49707358992988270u64,
-                ]),
-                BigInteger([
-                    15027308292309041596u64,
-                    4349608225074942320u64,
-                    11426894677088696044u64,
-                    16915648317162077510u64,
-                    16019702520272696152u64,
-                    107375918475156139u64,
-                ]),
-                BigInteger([
-                    16453038437169815002u64,
-                    10096768981406815963u64,
-                    14824937672065986347u64,
-                    16770550570214297224u64,
-                    9314218559233024748u64,
-                    72102967538715734u64,
-                ]),
-                BigInteger([
-                    16912663281409470678u64,
-                    4501346366170663025u64,
-                    7659416895486438732u64,
-                    7516061242252865657u64,
-                    10434684506905625104u64,
-                    14122417031023788u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13723439125169852569u64,
-                    13531178729006523561u64,
-                    13736279662624838642u64,
-                    5508541978192523593u64,
-                    9070630126132127521u64,
-                    65417861406546524u64,
-                ]),
-                BigInteger([
-                    8169789455681358504u64,
-                    13838913910506563923u64,
-                    12767704025070860754u64,
-                    12401721478594367083u64,
-                    233487229907398796u64,
-                    52651626563294110u64,
-                ]),
-                BigInteger([
-                    15106578939550748272u64,
-                    14200292526421468536u64,
-                    2698641709579442874u64,
-                    491700638764886978u64,
-                    15482402212181541583u64,
-                    102783074420685030u64,
-                ]),
-                BigInteger([
-                    10011523524127211899u64,
-                    14541867744030348361u64,
-                    17423120535696365636u64,
-                    8043117163651567511u64,
-                    12554484485122432568u64,
-                    108349322622704344u64,
-                ]),
-                BigInteger([
-                    3975957839714347848u64,
-                    15074952916169659410u64,
-                    10379653661358895306u64,
-                    5510286237680161341u64,
-                    289455737815953629u64,
-                    90779054314610106u64,
-                ]),
-                BigInteger([
-                    14714869377676451369u64,
-                    14435057559247847575u64,
-                    2076464147577104369u64,
-                    4574073520153826661u64,
-                    8850978932772338447u64,
-                    57380460247954400u64,
-                ]),
-                BigInteger([
-                    10530910337277370540u64,
-                    11450416404875770685u64,
-                    7676806762652400878u64,
-                    5565556516519554328u64,
-                    14892502734522329293u64,
-                    3282801326987375u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16168460950397269360u64,
-                    3427758006126927382u64,
-                    4729431576527951131u64,
-                    12605830365174485694u64,
-                    17557360630541931117u64,
-                    91029098411488419u64,
-                ]),
-                BigInteger([
-                    14231687401676786853u64,
-                    1884561439885669108u64,
-                    13893951943798754063u64,
-                    7897375702309059234u64,
-                    5322813551222588796u64,
-                    57351567307344395u64,
-                ]),
-                BigInteger([
-                    12817600601116312590u64,
-                    889877536706086249u64,
-                    16928031335443891172u64,
-                    17226189957283682510u64,
-                    4642804471632436836u64,
-                    120852471434611767u64,
-                ]),
-                BigInteger([
-                    13215805820934356310u64,
-                    6819618705260677652u64,
-                    16676633070901261564u64,
-                    11620386445203000971u64,
-                    4816498213103368749u64,
-                    52489751872502894u64,
-                ]),
-                BigInteger([
-                    6403792605863959751u64,
-                    17017972650537477854u64,
-                    10918884126472610575u64,
-                    13155264566819774978u64,
-                    12610480358048897687u64,
-                    29955718687641373u64,
-                ]),
-                BigInteger([
-                    10444468737211257725u64,
-                    7292327609388691418u64,
-                    18399828928783496287u64,
-                    17216545089839090281u64,
-                    10557112979925600435u64,
-                    111268437839841938u64,
-                ]),
-                BigInteger([
-                    8999638218182493199u64,
-                    14831098413653209621u64,
-                    15221297692153761040u64,
-                    18104606169709239987u64,
-                    7938371273810088267u64,
-                    89125662093383464u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7945911647912038304u64,
-                    10003048463985604626u64,
-                    3397780586262352984u64,
-                    17093217885674794572u64,
-                    15513686876996343488u64,
-                    68173981130166735u64,
-                ]),
-                BigInteger([
-                    16106724575531021029u64,
-                    176709917420167913u64,
-                    15364244483602143296u64,
-                    8551678497581308046u64,
-                    11328339247418468289u64,
-                    118069382036296133u64,
-                ]),
-                BigInteger([
-                    15812418494751862331u64,
-                    1912087596038617634u64,
-                    10236596012977874709u64,
-                    18187228923761142631u64,
-                    2414976699748342370u64,
-                    533097539313818u64,
-                ]),
-                BigInteger([
-                    16775738557748761410u64,
-                    15895439190966979032u64,
-                    10750750023877829556u64,
-                    8381942172817953497u64,
-                    15565574171719926110u64,
-                    22752491889000945u64,
-                ]),
-                BigInteger([
-                    6872299677128387457u64,
-                    12311264449290727949u64,
-                    10206167914388769481u64,
-                    5260249199542566556u64,
-                    4994143794222882049u64,
-                    75365654741236146u64,
-                ]),
-                BigInteger([
-                    9376518557762714133u64,
-                    5184334385166857310u64,
-                    12664807630201847304u64,
-                    1643007364051715370u64,
-                    4933710246157378415u64,
-                    75874209259636559u64,
-                ]),
-                BigInteger([
-                    8083814306632347886u64,
-                    7967405971743319857u64,
-                    17524630910672171036u64,
-                    14595165922906597584u64,
-                    1426586070108594553u64,
-                    105490348268480419u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2553355176559998567u64,
-                    14394997792061164594u64,
-                    4644020219095862230u64,
-                    13299456250000969782u64,
-                    8851037195331354766u64,
-                    83848616993550746u64,
-                ]),
-                BigInteger([
-                    16225494339703367057u64,
-                    2916504609528219184u64,
-                    2499273887166787477u64,
-                    16508462689234788440u64,
-                    17546455976021480649u64,
-                    119095527784784696u64,
-                ]),
-                BigInteger([
-                    2336805000619698661u64,
-                    14675047604481945030u64,
-                    17772968321566475014u64,
-                    5992313288665863u64,
-                    5799874831061313455u64,
-                    20795698799601347u64,
-                ]),
-                BigInteger([
-                    12425077352363256424u64,
-                    6380414383620852447u64,
-                    10452507376446816671u64,
-                    14153631825389714035u64,
-                    13459540375034459844u64,
-                    11521720277666963u64,
-                ]),
-                BigInteger([
-                    588829094416917699u64,
-                    8112606457146169200u64,
-                    5169633878141377473u64,
-                    10734602003652876570u64,
-                    5203770512799377878u64,
-                    112742847118457779u64,
-                ]),
-                BigInteger([
-                    8046491566151084244u64,
-                    7942035637284424992u64,
-                    14768937654593951896u64,
-                    11171407620001346128u64,
-                    8304691679997701115u64,
-                    56456365072660744u64,
-                ]),
-                BigInteger([
-                    17079561344839057350u64,
-                    3525045143068651625u64,
-                    18299492570191735690u64,
-                    3418528101423906276u64,
-                    10305554003714607430u64,
-                    32369294724850190u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4324988562440048918u64,
-                    14363822453605760198u64,
-                    14206676591740618802u64,
-                    16453789276291237596u64,
-                    12229637893019975004u64,
-                    38221674931064065u64,
-                ]),
-                BigInteger([
-                    2640544791367294332u64,
-                    3859317148621320114u64,
-                    15568635954378505915u64,
-                    17634425004313698672u64,
-                    5450214550005260263u64,
-                    95095086461417838u64,
-                ]),
-                BigInteger([
-                    14793559050352414354u64,
-                    2079400582941623034u64,
-                    11038971013908032361u64,
-                    367238362711012510u64,
-                    5719023660575512619u64,
-                    46067606267841166u64,
-                ]),
-                BigInteger([
-                    7228491156704656547u64,
-                    975527070591374882u64,
-                    4978064037834001449u64,
-                    10403836038079736010u64,
-                    11843678936985720593u64,
-                    71616252418502510u64,
-                ]),
-                BigInteger([
-                    9746451660862238822u64,
-                    13728320133155076026u64,
-                    4149874984538263696u64,
-                    13190508824846885657u64,
-                    15615207855431180486u64,
-                    114151417367294350u64,
-                ]),
-                BigInteger([
-                    9875690825138354591u64,
-                    3569433794023934904u64,
-                    3132294961547040852u64,
-                    425442868271942410u64,
-                    4944043158278734258u64,
-                    49595817824492180u64,
-                ]),
-                BigInteger([
-                    18339748563631762903u64,
-                    16872809814919784402u64,
-                    15701396631176480377u64,
-                    13511564329469545900u64,
-                    13925068808646024376u64,
-                    1522367418577558u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7295094328524818515u64,
-                    18079037859797546018u64,
-                    16354600316212471261u64,
-                    9080188732369622444u64,
-                    6879197661020764617u64,
-                    30902245619325352u64,
-                ]),
-                BigInteger([
-                    4289940353405283222u64,
-                    2136967471155962456u64,
-                    10496458402115512880u64,
-                    11677696715986797621u64,
-                    4615796880128864933u64,
-                    69416041487305437u64,
-                ]),
-                BigInteger([
-                    7055977995235898595u64,
-                    9703714784441992063u64,
-                    4034367876076379714u64,
-                    11650924385838457280u64,
-                    2641517887664510759u64,
-                    77845919267583982u64,
-                ]),
-                BigInteger([
-                    13340573849073038858u64,
-                    6369540900354487091u64,
-                    2294186694848178373u64,
-                    11589664394730932594u64,
-                    5481847703837782983u64,
-                    29463270801466567u64,
-                ]),
-                BigInteger([
-                    16726757544137083068u64,
-                    12197813634793782238u64,
-                    4635265136303614349u64,
-                    237600287896941165u64,
-                    6841611362456838199u64,
-                    69493667287989621u64,
-                ]),
-                BigInteger([
-                    2805661858109054820u64,
-                    3437160642710007042u64,
-                    15432783914539530461u64,
-                    11049505329536254642u64,
-                    1185570378266128185u64,
-                    28020967346974279u64,
-                ]),
-                BigInteger([
-                    1510840665671656910u64,
-                    4583940755603287655u64,
-                    1787282383523416853u64,
-                    5097666924154920530u64,
-                    13656251307729685329u64,
-                    9129947049274862u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13288944934843394108u64,
-                    17759644162362631045u64,
-                    10073727666133166365u64,
-                    408712683371530812u64,
-                    17374308346824658219u64,
-                    117607707218853930u64,
-                ]),
-                BigInteger([
-                    14071395365749600293u64,
-                    9091122068105007984u64,
-                    7709200345793677468u64,
-                    10052335154442721394u64,
-                    9509284923520355173u64,
-                    42536768181568387u64,
-                ]),
-                BigInteger([
-                    6350295966318369402u64,
-                    11113043944162569654u64,
-                    4530286989666468842u64,
-                    9763036330270441427u64,
-                    17096918591619303515u64,
-                    105960822974635203u64,
-                ]),
-                BigInteger([
-                    16550349163564307661u64,
-                    12468580902658777123u64,
-                    10735932008165380228u64,
-                    10039219769766926874u64,
-                    10837740797979978927u64,
-                    28021416486423321u64,
-                ]),
-                BigInteger([
-                    15345937130013794392u64,
-                    11577711806917216398u64,
-                    3320518303954501634u64,
-                    8857203225985747381u64,
-                    13264440067886033784u64,
-                    16489623644506137u64,
-                ]),
-                BigInteger([
-                    16017008385390104564u64,
-                    10019778332011653876u64,
-                    9966812539590234961u64,
-                    4387033240110029021u64,
-                    14998106030421464677u64,
-                    111335381179367115u64,
-                ]),
-                BigInteger([
-                    238507000666466558u64,
-                    13317539704527449610u64,
-                    10285476363839435053u64,
-                    682628232327560456u64,
-                    6079817975217669861u64,
-                    36821795598037776u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17415199511532679307u64,
-                    1796827289574476709u64,
-                    3965421132714456129u64,
-                    11228059758033755047u64,
-                    1855132379492344436u64,
-                    18338715127037191u64,
-                ]),
-                BigInteger([
-                    5616505119279057753u64,
-                    3871315813715240407u64,
-                    5904906839164019527u64,
-                    13014152490842973788u64,
-                    33617854985602773u64,
-                    81212718705980229u64,
-                ]),
-                BigInteger([
-                    2927007296341163876u64,
-                    2977727510685587603u64,
-                    15077805888045285941u64,
-                    9788069930439072905u64,
-                    11950771137433697982u64,
-                    98888197386183014u64,
-                ]),
-                BigInteger([
-                    1299438379116792316u64,
-                    17938709064226818931u64,
-                    7007360195682114663u64,
-                    1170940570977449272u64,
-                    13203206802931633514u64,
-                    59991268361961776u64,
-                ]),
-                BigInteger([
-                    6548625039649375213u64,
-                    15112249461841236070u64,
-                    6545114497856955970u64,
-                    8613109278408006312u64,
-                    10640878832408082359u64,
-                    107589016525446332u64,
-                ]),
-                BigInteger([
-                    11815179711317954427u64,
-                    17394079944533903211u64,
-                    9108685411322755012u64,
-                    15275109605813923780u64,
-                    1743437218015737939u64,
-                    91192136195830988u64,
-                ]),
-                BigInteger([
-                    1972717060342838449u64,
-                    2693599509511803320u64,
-                    12017109698314478171u64,
-                    7618601403901690595u64,
-                    18024480940848148157u64,
-                    79550796316258634u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12206441121753185206u64,
-                    379087721099234609u64,
-                    6462808277450230093u64,
-                    6845038839948065888u64,
-                    85913616608661784u64,
-                    98151751516563804u64,
-                ]),
-                BigInteger([
-                    13664223312547100477u64,
-                    17526266315985562712u64,
-                    12366478995218021726u64,
-                    3278348921580795904u64,
-                    6186881561577250958u64,
-                    53638671391611384u64,
-                ]),
-                BigInteger([
-                    5432548884051700159u64,
-                    2929173531681147244u64,
-                    16591647434092390190u64,
-                    1318813792219989117u64,
-                    10973292429917269620u64,
-                    42118090091944172u64,
-                ]),
-                BigInteger([
-                    12595590846527511331u64,
-                    16637327690923522529u64,
-                    14812780517315619641u64,
-                    12402223518808223912u64,
-                    3677086196445290215u64,
-                    21029179677618326u64,
-                ]),
-                BigInteger([
-                    5051941280663750675u64,
-                    1860679317824882685u64,
-                    13648668317276565490u64,
-                    6200506095832952496u64,
-                    11334002258052924335u64,
-                    111280954901047837u64,
-                ]),
-                BigInteger([
-                    3215129792457650631u64,
-                    702874624357674081u64,
-                    10307086353513348108u64,
-                    4580416986180789709u64,
-                    18097370117066336544u64,
-                    5267076793070484u64,
-                ]),
-                BigInteger([
-                    2382006684079842301u64,
-                    11827511184924081904u64,
-                    2367294184185094072u64,
-                    6033790543241982928u64,
-                    2620858158016272707u64,
-                    57965206893375169u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3530150697549353913u64,
-                    15406800116419159335u64,
-                    6543959798512330991u64,
-                    18203816921204772190u64,
-                    13932636604319598915u64,
-                    22833495624318857u64,
-                ]),
-                BigInteger([
-                    2983221921497182971u64,
-                    11859490031522867959u64,
-                    3875169554746469781u64,
-                    5047958027497247052u64,
-                    17234495847703235787u64,
-                    455839367486916u64,
-                ]),
-                BigInteger([
-                    10442310932706738421u64,
-                    15513082230902189195u64,
-                    7102482204757993882u64,
-                    5862222893986278746u64,
-                    18219862488836175460u64,
-                    68853179500368018u64,
-                ]),
-                BigInteger([
-                    12837774080771833603u64,
-                    16416199067481696305u64,
-                    5307629888175929265u64,
-                    10523817573937245436u64,
-                    7257008115330498585u64,
-                    73048672358561604u64,
-                ]),
-                BigInteger([
-                    15073847673725312118u64,
-                    18286573350075628597u64,
-                    15327135292846341985u64,
-                    17204362192374425918u64,
-                    18177296352066850651u64,
-                    46682196396446573u64,
-                ]),
-                BigInteger([
-                    14452163119059942319u64,
-                    2207220603036072462u64,
-                    8053838627492732707u64,
-                    3591789665669458134u64,
-                    13155080611694676080u64,
-                    39318026018241301u64,
-                ]),
-                BigInteger([
-                    12429398970029941473u64,
-                    7783619229593900790u64,
-                    13334932104639619073u64,
-                    3397175750303724893u64,
-                    9464326074966175966u64,
-                    27164396893114381u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7732224897778077769u64,
-                    12089612704114584308u64,
-                    13296062057460361719u64,
-                    15796167648794669803u64,
-                    12514483434670670523u64,
-                    92791416249427063u64,
-                ]),
-                BigInteger([
-                    12826619609324249481u64,
-                    5807451759123145239u64,
-                    17301744870208434779u64,
-                    13565883514623337886u64,
-                    11125811354235023561u64,
-                    97682619952170860u64,
-                ]),
-                BigInteger([
-                    12879938839909826662u64,
-                    6426710451372348975u64,
-                    14794193151755105002u64,
-                    15771993890547359876u64,
-                    1511902931144618146u64,
-                    104251583210418077u64,
-                ]),
-                BigInteger([
-                    16534524397137323215u64,
-                    14066100882577629362u64,
-                    1059732046455549124u64,
-                    13992650440235965265u64,
-                    13145918194459930298u64,
-                    90289212461696546u64,
-                ]),
-                BigInteger([
-                    8778822411517356781u64,
-                    5302892104314937263u64,
-                    13240106752389950128u64,
-                    6217970788226761527u64,
-                    2197485638523404519u64,
-                    47298042897052024u64,
-                ]),
-                BigInteger([
-                    9095346442531205188u64,
-                    5976888421546233331u64,
-                    14446352866086987972u64,
-                    5089707279641213338u64,
-                    3495870458624721463u64,
-                    59967636060358826u64,
-                ]),
-                BigInteger([
-                    1207619223934666641u64,
-                    3968672946473516362u64,
-                    8030979608306022670u64,
-                    4774297549493714462u64,
-                    10886641393360941690u64,
-                    50481117963238629u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8912691966132619032u64,
-                    6478383967953263189u64,
-                    2483310384683382444u64,
-                    3085492422910224318u64,
-                    12277502015900597247u64,
-                    109232546099890449u64,
-                ]),
-                BigInteger([
-                    8230379741168782956u64,
-                    12168405884616371327u64,
-                    6062233310440090758u64,
-                    7978570412765988328u64,
-                    10480754280453350214u64,
-                    83688842715880896u64,
-                ]),
-                BigInteger([
-                    7103669658208237975u64,
-                    6614510463291039502u64,
-                    15009763240863728813u64,
-                    4332303514556277269u64,
-                    13560085657578790220u64,
-                    8512998561473415u64,
-                ]),
-                BigInteger([
-                    4629062546838188875u64,
-                    602537034589413385u64,
-                    17654851223942974881u64,
-                    6712885173136780642u64,
-                    13294466704708850190u64,
-                    24775585784415316u64,
-                ]),
-                BigInteger([
-                    10504181069356211676u64,
-                    13377687276556256096u64,
-                    9911970826721109528u64,
-                    2598278785214789691u64,
-                    9063998901423682871u64,
-                    113946550903124804u64,
-                ]),
-                BigInteger([
-                    3147670688740171102u64,
-                    5286399769150495182u64,
-                    17411424935801776982u64,
-                    9238756229950344547u64,
-                    17592329894391445711u64,
-                    68197688176265775u64,
-                ]),
-                BigInteger([
-                    11381908438511196402u64,
-                    4661738700066727041u64,
-                    6718938328354726197u64,
-                    14258750729166835634u64,
-                    7123694665337877596u64,
-                    9705005463869057u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2576063739520328781u64,
-                    10631059820257089217u64,
-                    15848445234140994953u64,
-                    18352646685561114263u64,
-                    6960177866756326038u64,
-                    119237519717753315u64,
-                ]),
-                BigInteger([
-                    5478202596311584661u64,
-                    13120856495715359906u64,
-                    1054087737773789975u64,
-                    13452477726044356473u64,
-                    4252603119037151675u64,
-                    60784911719562884u64,
-                ]),
-                BigInteger([
-                    9253942177870024627u64,
-                    12052567239259975315u64,
-                    16612608906482496879u64,
-                    10234683656205646294u64,
-                    14274766901229951876u64,
-                    113319526796763838u64,
-                ]),
-                BigInteger([
-                    193609566485952520u64,
-                    5882501267052422414u64,
-                    12543030002867802258u64,
-                    7177741999603721753u64,
-                    18030907214470396307u64,
-                    6800003122591559u64,
-                ]),
-                BigInteger([
-                    13232366812457189163u64,
-                    5798851758864764197u64,
-                    2517679791747392161u64,
-                    348694138509250116u64,
-                    7444611122550440806u64,
-                    267337078171652u64,
-                ]),
-                BigInteger([
-                    8993835523986439207u64,
-                    9632154752849180253u64,
-                    14729750155965886814u64,
-                    11275787907987880023u64,
-                    8618144654449178007u64,
-                    17181231778962311u64,
-                ]),
-                BigInteger([
-                    6783929281846303133u64,
-                    300956510222408167u64,
-                    10298463334606665153u64,
-                    13130558211106283218u64,
-                    13948015104614249589u64,
-                    67079721356076906u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8724216085125159134u64,
-                    2393814321590710263u64,
-                    2073756455832644051u64,
-                    7823997437377074979u64,
-                    7886203442945011657u64,
-                    822771662316578u64,
-                ]),
-                BigInteger([
-                    5741041500515004983u64,
-                    11693434721473466071u64,
-                    6802052425293800518u64,
-                    4792924574701256037u64,
-                    18405711398786260069u64,
-                    86771096697222668u64,
-                ]),
-                BigInteger([
-                    1984496026143161656u64,
-                    8111795364769562109u64,
-                    837601002001222736u64,
-                    10149897913382874988u64,
-                    14344615847908379408u64,
-                    72156105846388786u64,
-                ]),
-                BigInteger([
-                    7482795051695228132u64,
-                    10357442039585598284u64,
-                    9145890237289706552u64,
-                    15119795450545506332u64,
-                    14508659932909770398u64,
-                    34996133475116954u64,
-                ]),
-                BigInteger([
-                    11285848804285313760u64,
-                    16451998357993661842u64,
-                    14616928353986952068u64,
-                    15203269620959630933u64,
-                    2946938085553047863u64,
-                    77266633605674113u64,
-                ]),
-                BigInteger([
-                    130806696930789771u64,
-                    9833415863607524831u64,
-                    1745592502749258815u64,
-                    7132446815930991634u64,
-                    7845237487631375336u64,
-                    120301397108421952u64,
-                ]),
-                BigInteger([
-                    14528268440659987547u64,
-                    3082489068258930386u64,
-                    17092880896715356914u64,
-                    6727534841637486352u64,
-                    18109477458554845482u64,
-                    29081251521030406u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12518890646214103598u64,
-                    15331141518167359946u64,
-                    5010824650215329801u64,
-                    112610928654132598u64,
-                    9032953755881612214u64,
-                    83243629663686542u64,
-                ]),
-                BigInteger([
-                    6268133391816165359u64,
-                    14849511397161578471u64,
-                    13130348465478063826u64,
-                    5706085120703717120u64,
-                    9620679664354547721u64,
-                    34986661005434323u64,
-                ]),
-                BigInteger([
-                    2539454371394937175u64,
-                    11246595353824771359u64,
-                    13022197325994206096u64,
-                    16763473292516020885u64,
-                    11652572055543198415u64,
-                    67052803707958421u64,
-                ]),
-                BigInteger([
-                    15867505817047677875u64,
-                    1529760387556676107u64,
-                    796432021165811818u64,
-                    7983470031958187401u64,
-                    9051199071425042913u64,
-                    50722658147029850u64,
-                ]),
-                BigInteger([
-                    4292960552547563703u64,
-                    708937101049889495u64,
-                    16900896035350080161u64,
-                    10029799647322975938u64,
-                    10132951664661426478u64,
-                    213199958068292u64,
-                ]),
-                BigInteger([
-                    3237095136749823763u64,
-                    12368917011517577678u64,
-                    9996517149187972784u64,
-                    10187201287812741295u64,
-                    16778557956636487693u64,
-                    94409683563492490u64,
-                ]),
-                BigInteger([
-                    3022801991814169072u64,
-                    4065354715282583845u64,
-                    3703544795759127548u64,
-                    14580263864739537622u64,
-                    4062597793127897668u64,
-                    69323182623833540u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    63983598536641319u64,
-                    9838893596181533215u64,
-                    17243099822111914244u64,
-                    18275362058050156720u64,
-                    14222582317656399967u64,
-                    37292330841435638u64,
-                ]),
-                BigInteger([
-                    2406686139934541037u64,
-                    1477473141488879810u64,
-                    12284885448251298011u64,
-                    11180130932951329749u64,
-                    11559341059365743059u64,
-                    86441347330384204u64,
-                ]),
-                BigInteger([
-                    12981683113342045644u64,
-                    15879636692348919406u64,
-                    3859469607352780480u64,
-                    15002437554343555979u64,
-                    2097901047671967424u64,
-                    24528896499059525u64,
-                ]),
-                BigInteger([
-                    8193383560135226538u64,
-                    1665044287708576542u64,
-                    6653331374753354500u64,
-                    11144608258727421006u64,
-                    17975874518488500873u64,
-                    58380443964809233u64,
-                ]),
-                BigInteger([
-                    4323833130012545275u64,
-                    16095091511357238146u64,
-                    16072961695124144081u64,
-                    10747537276034147952u64,
-                    17575997979117085927u64,
-                    41584342327170240u64,
-                ]),
-                BigInteger([
-                    2241372943738170203u64,
-                    16747206963441667034u64,
-                    18202208881031825642u64,
-                    9251283233167415253u64,
-                    12375828579656839169u64,
-                    104024371958021168u64,
-                ]),
-                BigInteger([
-                    17027282347279079995u64,
-                    16299976513249638286u64,
-                    10353122673632959641u64,
-                    15632062679068451973u64,
-                    3848590880790510244u64,
-                    79778253353141639u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7163168731050533044u64,
-                    3664399303673211562u64,
-                    9209620815226787420u64,
-                    3532388309326497409u64,
-                    17400039297125874005u64,
-                    54867274637146015u64,
-                ]),
-                BigInteger([
-                    15008444940280181160u64,
-                    10595060642866595353u64,
-                    15497038776078896560u64,
-                    3672300772824898276u64,
-                    7011238857061489015u64,
-                    34624136294691380u64,
-                ]),
-                BigInteger([
-                    8400325891210357853u64,
-                    16072371881728480845u64,
-                    11128236084296298690u64,
-                    17484772151134717949u64,
-                    10528208154977418701u64,
-                    55905922042661017u64,
-                ]),
-                BigInteger([
-                    4453709884268398695u64,
-                    14267942265065560654u64,
-                    6786596693893752798u64,
-                    539430645261563893u64,
-                    13336993648913581277u64,
-                    65067673628690540u64,
-                ]),
-                BigInteger([
-                    9202698744682604300u64,
-                    6233206941526451048u64,
-                    18147375752075818321u64,
-                    8271078758237196329u64,
-                    4408158646856840462u64,
-                    40031256583047207u64,
-                ]),
-                BigInteger([
-                    248665829373378293u64,
-                    12222943746224126082u64,
-                    11190643602708593628u64,
-                    11849355085363388661u64,
-                    1722908530718722413u64,
-                    106999952510498723u64,
-                ]),
-                BigInteger([
-                    7174157405332043039u64,
-                    17153461122888102374u64,
-                    5059452801122695717u64,
-                    15348800756670157493u64,
-                    14649218262500894668u64,
-                    60893651025360767u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2952275686502597238u64,
-                    12552421043287440205u64,
-                    8418720333751500529u64,
-                    18227959342387956053u64,
-                    9228855808824035027u64,
-                    254628251615849u64,
-                ]),
-                BigInteger([
-                    7418546629704599474u64,
-                    5197832036327562234u64,
-                    16034726649087493102u64,
-                    12039240631004998889u64,
-                    1967292333138750019u64,
-                    36849946056620331u64,
-                ]),
-                BigInteger([
-                    13385654328976318089u64,
-                    8491905922504291095u64,
-                    17144756716840966463u64,
-                    6650542315478020874u64,
-                    356816743820053483u64,
-                    30438366870938461u64,
-                ]),
-                BigInteger([
-                    3816677028891613633u64,
-                    11647183032885166151u64,
-                    2570510788879333251u64,
-                    12920026328080311977u64,
-                    7535044499205934934u64,
-                    31833863123202745u64,
-                ]),
-                BigInteger([
-                    18256374243870987962u64,
-                    8703831023915597696u64,
-                    10179963559948740902u64,
-                    15070232635974563926u64,
-                    8460227684795095065u64,
-                    89046965570359737u64,
-                ]),
-                BigInteger([
-                    3823725625811876311u64,
-                    9338880136230267209u64,
-                    17126630998948977499u64,
-                    15629258726983743099u64,
-                    15680865173096984881u64,
-                    13972819698394381u64,
-                ]),
-                BigInteger([
-                    4665990582514632951u64,
-                    16712231441455388942u64,
-                    6239556363765921712u64,
-                    13392337449593396287u64,
-                    16558493537754736628u64,
-                    118237606407210133u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11840150337299450117u64,
-                    8827233753535075087u64,
-                    251688632708386945u64,
-                    17187928656283963189u64,
-                    14561768935193085223u64,
-                    17513395061242266u64,
-                ]),
-                BigInteger([
-                    1129118724983834179u64,
-                    4728833134186739096u64,
-                    16693908331127208470u64,
-                    18139857432784200404u64,
-                    9179456572104724397u64,
-                    115207816980815197u64,
-                ]),
-                BigInteger([
-                    15480967737593669025u64,
-                    17186728816096081397u64,
-                    6137771734859284025u64,
-                    18288077720617600382u64,
-                    15282368468132543345u64,
-                    15169126388503569u64,
-                ]),
-                BigInteger([
-                    16287836130709664160u64,
-                    1162186993303476855u64,
-                    10820066988349919657u64,
-                    4537868049349948219u64,
-                    13852948351893155978u64,
-                    37398653358622853u64,
-                ]),
-                BigInteger([
-                    75322880057009777u64,
-                    11181522600351227526u64,
-                    11603480097338335078u64,
-                    6893906101289610912u64,
-                    12350976535851304104u64,
-                    77423704533648888u64,
-                ]),
-                BigInteger([
-                    299721933413592676u64,
-                    16876574457917733742u64,
-                    11317860734260247910u64,
-                    8407842309155265119u64,
-                    14532697727534900595u64,
-                    89972343281643847u64,
-                ]),
-                BigInteger([
-                    12759330599598382446u64,
-                    10563307242873936144u64,
-                    5283218445589408463u64,
-                    2547691190175119759u64,
-                    18037401434572755561u64,
-                    62963740102953174u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2459151345008137354u64,
-                    4689977682815503165u64,
-                    14787814935998362132u64,
-                    4090317706929301969u64,
-                    7483791338880250660u64,
-                    7392741149151210u64,
-                ]),
-                BigInteger([
-                    17907749564300406301u64,
-                    7969702757078841565u64,
-                    3775729919007012824u64,
-                    5442248176131363218u64,
-                    9513675966652884335u64,
-                    116625517247881097u64,
-                ]),
-                BigInteger([
-                    5431033401235623117u64,
-                    16608865303920088356u64,
-                    966362938787943907u64,
-                    89359541810821571u64,
-                    10055660979284426850u64,
-                    82778008254890272u64,
-                ]),
-                BigInteger([
-                    17093625142680400992u64,
-                    18414522624891355887u64,
-                    15337116705475715133u64,
-                    13579197769009391194u64,
-                    9987800778992258799u64,
-                    90909819476985399u64,
-                ]),
-                BigInteger([
-                    2329528026916710254u64,
-                    1683195001705251371u64,
-                    5282393804695339809u64,
-                    16567935306116980870u64,
-                    4196422860596051401u64,
-                    107017365042264202u64,
-                ]),
-                BigInteger([
-                    10153733471132895057u64,
-                    1608225811037153436u64,
-                    6549039702991625316u64,
-                    15101227858269519509u64,
-                    7209516403459116556u64,
-                    31513247344701189u64,
-                ]),
-                BigInteger([
-                    465873681725057914u64,
-                    739684366822991792u64,
-                    16654742299825576912u64,
-                    4193858601404342262u64,
-                    10311702456198581378u64,
-                    89039243587380717u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13384584772044515681u64,
-                    9638452624245352891u64,
-                    508686951598358641u64,
-                    13932470011052705222u64,
-                    804037342641731586u64,
-                    77933999884602576u64,
-                ]),
-                BigInteger([
-                    18375088864177387103u64,
-                    6675081719052705599u64,
-                    16604273841766439263u64,
-                    13404313770013629443u64,
-                    4271111629068794167u64,
-                    8929718022234320u64,
-                ]),
-                BigInteger([
-                    13264438089704340947u64,
-                    10568988407953346900u64,
-                    17921679724352046509u64,
-                    1547256627105532893u64,
-                    693669780989348903u64,
-                    11003501316270934u64,
-                ]),
-                BigInteger([
-                    766557664404005062u64,
-                    6506906092660481855u64,
-                    11046041396480692894u64,
-                    13546243536525781585u64,
-                    15851109850471234216u64,
-                    66906118881925444u64,
-                ]),
-                BigInteger([
-                    10917542459597372642u64,
-                    13545018834781496323u64,
-                    7740776706121341522u64,
-                    7066672296749282606u64,
-                    15508884589706191597u64,
-                    92366965243850698u64,
-                ]),
-                BigInteger([
-                    13872666458486818011u64,
-                    17768386770588593061u64,
-                    7613944173316316184u64,
-                    1931407366357360875u64,
-                    14556126737953597855u64,
-                    100911550577921696u64,
-                ]),
-                BigInteger([
-                    1584582245902999983u64,
-                    3016276023672999372u64,
-                    1135910332620157090u64,
-                    7855878434026030603u64,
-                    5128016438106910529u64,
-                    49933237234317726u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3366650101990130110u64,
-                    1200355348567734857u64,
-                    10076071413124843600u64,
-                    4748787154623937984u64,
-                    4635431623045689608u64,
-                    94678572187085230u64,
-                ]),
-                BigInteger([
-                    2013025976627197342u64,
-                    8408809500390438771u64,
-                    14104333318109145438u64,
-                    11431871778172310905u64,
-                    7297535298353082983u64,
-                    119155095179956854u64,
-                ]),
-                BigInteger([
-                    2513654628420947684u64,
-                    8941556534003986461u64,
-                    16651936720070482817u64,
-                    10812704794518251333u64,
-                    6278617893115108228u64,
-                    72309346217149541u64,
-                ]),
-                BigInteger([
-                    15587119813348270870u64,
-                    6329839557306615848u64,
-                    14878904316074864182u64,
-                    13745516935276863119u64,
-                    5528792919367104908u64,
-                    88367304589191278u64,
-                ]),
-                BigInteger([
-                    13389131144677922921u64,
-                    7860659539062778735u64,
-                    4142160655648457916u64,
-                    17664245698090973758u64,
-                    18030021184042880363u64,
-                    16570593479224554u64,
-                ]),
-                BigInteger([
-                    583079486432024633u64,
-                    9499502319093312632u64,
-                    12384284093582830579u64,
-                    10919554424726849662u64,
-                    10909828242796986828u64,
-                    12589201894420182u64,
-                ]),
-                BigInteger([
-                    3705168932180181908u64,
-                    14893992500091675589u64,
-                    16604031443845534853u64,
-                    860368309808983828u64,
-                    3131186500409554357u64,
-                    91333789726851809u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13368222826152606448u64,
-                    4091820920528002013u64,
-                    10717206454823897415u64,
-                    16413391777769720757u64,
-                    10324155506932041176u64,
-                    17306784304177182u64,
-                ]),
-                BigInteger([
-                    11633473726880869525u64,
-                    15234653415016641424u64,
-                    1051367120590219588u64,
-                    13460166356692188786u64,
-                    6767304209228548454u64,
-                    31415024921940396u64,
-                ]),
-                BigInteger([
-                    7220456965219341829u64,
-                    2114973250060399001u64,
-                    1063185725870184046u64,
-                    8845751678765372760u64,
-                    11989065819867500100u64,
-                    5456645962863777u64,
-                ]),
-                BigInteger([
-                    13399791783221259328u64,
-                    13867355151382202638u64,
-                    8078270723320528075u64,
-                    9121925522257697825u64,
-                    15073270529453992130u64,
-                    35507399913287180u64,
-                ]),
-                BigInteger([
-                    16187388808334635704u64,
-                    6520144849081130480u64,
-                    1508232165989284399u64,
-                    13087008318476870636u64,
-                    15805476070076930655u64,
-                    62873158152272750u64,
-                ]),
-                BigInteger([
-                    4289710421472803923u64,
-                    13788667263215992244u64,
-                    1438454538867681138u64,
-                    807580819806616607u64,
-                    410611878103629990u64,
-                    85737868702416840u64,
-                ]),
-                BigInteger([
-                    12627569687666664927u64,
-                    14254369781052279176u64,
-                    13541031255285109730u64,
-                    16600961618262224459u64,
-                    2241369473798819388u64,
-                    112022660881236726u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16568061099122349547u64,
-                    574064858613258054u64,
-                    3902893952526338491u64,
-                    8465540576977939150u64,
-                    13113234128471316953u64,
-                    24700924008656453u64,
-                ]),
-                BigInteger([
-                    3951765213227958389u64,
-                    17051206986063381325u64,
-                    7424312183357337658u64,
-                    3464471529713198827u64,
-                    16232383219985012601u64,
-                    947420937849692u64,
-                ]),
-                BigInteger([
-                    18040627622835982903u64,
-                    9567299723781300118u64,
-                    13132415142321521408u64,
-                    2128312444203772171u64,
-                    17190374943996603179u64,
-                    119026431473676519u64,
-                ]),
-                BigInteger([
-                    5625782494266754986u64,
-                    729315365663663370u64,
-                    2869154934991327400u64,
-                    2735560111524086178u64,
-                    1866608274193258830u64,
-                    94891935285529407u64,
-                ]),
-                BigInteger([
-                    9746617051611477040u64,
-                    6924142091896967703u64,
-                    9976890905624518621u64,
-                    11883427767278674374u64,
-                    17691602637266847673u64,
-                    2854509365929683u64,
-                ]),
-                BigInteger([
-                    12956819632135225308u64,
-                    4494021194554033852u64,
-                    3895666914627039521u64,
-                    6481489649972278670u64,
-                    10280640530844441501u64,
-                    37535839971570236u64,
-                ]),
-                BigInteger([
-                    12556230371907498598u64,
-                    13190567254735406365u64,
-                    18399754120123826263u64,
-                    17141483241798445427u64,
-                    8096194977972142321u64,
-                    65355631863909511u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1854746777252088310u64,
-                    3469096777812853428u64,
-                    12311342900426925478u64,
-                    15978598061419691831u64,
-                    8588514817583276246u64,
-                    67374241528317882u64,
-                ]),
-                BigInteger([
-                    2018368925338710112u64,
-                    5594443997962671487u64,
-                    8431123902846426437u64,
-                    5288816658975189440u64,
-                    2732569260738870688u64,
-                    32968302576818505u64,
-                ]),
-                BigInteger([
-                    16619841377007627136u64,
-                    17418761035861695928u64,
-                    980559716242677523u64,
-                    16682323926586659154u64,
-                    17891653190971361281u64,
-                    117774568651343541u64,
-                ]),
-                BigInteger([
-                    5840564031423347119u64,
-                    615208404454565505u64,
-                    10420960244365213254u64,
-                    16411179759610613424u64,
-                    2262568289944436574u64,
-                    18169953509197155u64,
-                ]),
-                BigInteger([
-                    9477508171782749163u64,
-                    1040737049570979649u64,
-                    10533739136260876484u64,
-                    7491565182387794225u64,
-                    844219074051567237u64,
-                    55240637273858297u64,
-                ]),
-                BigInteger([
-                    3951094713376170042u64,
-                    16904621752262075414u64,
-                    15054598772879194752u64,
-                    1138252989732149942u64,
-                    16203096755839092802u64,
-                    35057532530129462u64,
-                ]),
-                BigInteger([
-                    9269171858047426688u64,
-                    11099157815955240567u64,
-                    1103734981458360879u64,
-                    5971288106723050521u64,
-                    10392037170337647533u64,
-                    62159971140058532u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1158631942517855496u64,
-                    4960544018038442527u64,
-                    10954300428413861998u64,
-                    7002541385748403383u64,
-                    726907593225549200u64,
-                    56553702683288544u64,
-                ]),
-                BigInteger([
-                    14533958135633037851u64,
-                    2329120615594198769u64,
-                    6230339406317534637u64,
-                    13579065461332513892u64,
-                    9473991543088633921u64,
-                    111983528918046897u64,
-                ]),
-                BigInteger([
-                    13676980235274941840u64,
-                    13868416877004897472u64,
-                    12770150137203363159u64,
-                    16267635971831904433u64,
-                    14120154204820293247u64,
-                    30631943036655127u64,
-                ]),
-                BigInteger([
-                    8462818274184877037u64,
-                    16840222095230024941u64,
-                    16644143400512042742u64,
-                    1447142014370958946u64,
-                    9847112187370549442u64,
-                    18324832226467873u64,
-                ]),
-                BigInteger([
-                    4024083389747961715u64,
-                    10069399244641015915u64,
-                    14080484041374495238u64,
-                    5001816035760984266u64,
-                    16067851919120112540u64,
-                    18683496069411653u64,
-                ]),
-                BigInteger([
-                    15828845074311217293u64,
-                    670341592529117204u64,
-                    14741894521597273972u64,
-                    9003771056423982701u64,
-                    4156019836397139823u64,
-                    62503941339138905u64,
-                ]),
-                BigInteger([
-                    2437343977591339343u64,
-                    2889776875777401476u64,
-                    7117912592902838692u64,
-                    14106657053762511927u64,
-                    14545649291771711645u64,
-                    20185248094820557u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9629624410147464445u64,
-                    5375751808908842382u64,
-                    10766944429171285756u64,
-                    16924766044221334062u64,
-                    17930231856038839603u64,
-                    7399558664301194u64,
-                ]),
-                BigInteger([
-                    15976453535216284143u64,
-                    12104680141586616492u64,
-                    5506836328202543807u64,
-                    12614113875140169674u64,
-                    11993535173273120001u64,
-                    102388634419228646u64,
-                ]),
-                BigInteger([
-                    17336760898023953964u64,
-                    10794679403484672854u64,
-                    7126702110379145912u64,
-                    14980196838395100752u64,
-                    8603395872459561280u64,
-                    89352928607311741u64,
-                ]),
-                BigInteger([
-                    8172674102970413069u64,
-                    14285677965126947155u64,
-                    10634017298480619506u64,
-                    5619901030475938429u64,
-                    7343521958162265672u64,
-                    76688603173333153u64,
-                ]),
-                BigInteger([
-                    7128711173157411091u64,
-                    7095791948825986198u64,
-                    14948077404608430854u64,
-                    13639211779059794675u64,
-                    15213793678976725142u64,
-                    22194613544470408u64,
-                ]),
-                BigInteger([
-                    10048509786593036476u64,
-                    14810622426540609950u64,
-                    17851216145433368853u64,
-                    15899373311478494698u64,
-                    10658741917454649632u64,
-                    116289338892654372u64,
-                ]),
-                BigInteger([
-                    13153315262663195485u64,
-                    13331167978525689261u64,
-                    17354123031605594465u64,
-                    11998173807683346993u64,
-                    17237847269570776197u64,
-                    67593957550590707u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16335183828315804946u64,
-                    15841779921026534358u64,
-                    3423408387059336295u64,
-                    4245762953662105499u64,
-                    742390488080658034u64,
-                    1227074472987953u64,
-                ]),
-                BigInteger([
-                    4921103127895474240u64,
-                    10124643882828773967u64,
-                    5132959708188850812u64,
-                    15306571106660832540u64,
-                    12235402498374291069u64,
-                    38762081562517282u64,
-                ]),
-                BigInteger([
-                    10929478945449841785u64,
-                    1605689253707320320u64,
-                    3407123937692930394u64,
-                    9480667846696798417u64,
-                    6157686005962423313u64,
-                    50323895904637977u64,
-                ]),
-                BigInteger([
-                    9623034554481263322u64,
-                    1102546741864826323u64,
-                    7346546707242776579u64,
-                    15394106080041862574u64,
-                    16908576454861581289u64,
-                    27219533154890964u64,
-                ]),
-                BigInteger([
-                    4412589134135808401u64,
-                    17874921843293230044u64,
-                    1947728802686576567u64,
-                    12834682297200342889u64,
-                    10607305598736726750u64,
-                    20431175958334920u64,
-                ]),
-                BigInteger([
-                    945320920214508917u64,
-                    15367779605215370787u64,
-                    8976245667267570313u64,
-                    6127115984038969250u64,
-                    9065215817540841972u64,
-                    66244310988556561u64,
-                ]),
-                BigInteger([
-                    9098198007425634209u64,
-                    18149797193699629057u64,
-                    15945452781425757478u64,
-                    10159332239838465307u64,
-                    8366449037861738114u64,
-                    42396643992023261u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    237503213390186892u64,
-                    17637555558680675663u64,
-                    12821357746078606476u64,
-                    8317613088941066321u64,
-                    1242739732735957756u64,
-                    52383963048884146u64,
-                ]),
-                BigInteger([
-                    210323306148131243u64,
-                    10657212579791263196u64,
-                    3233730566199720152u64,
-                    608805217214113763u64,
-                    5046725873431495572u64,
-                    9929836019172775u64,
-                ]),
-                BigInteger([
-                    1753931101453161290u64,
-                    3453234781589233999u64,
-                    3060749495390633883u64,
-                    16378110900914173189u64,
-                    10091226318988332042u64,
-                    24414926135593405u64,
-                ]),
-                BigInteger([
-                    6030668957188050364u64,
-                    6924414960604047616u64,
-                    8350220393755413661u64,
-                    14416070097663659017u64,
-                    18254480847350273614u64,
-                    59442499235686654u64,
-                ]),
-                BigInteger([
-                    16391166656428868152u64,
-                    7933951912476542274u64,
-                    17241131211642620354u64,
-                    10616281951813051456u64,
-                    15270866962496671907u64,
-                    42693135276942131u64,
-                ]),
-                BigInteger([
-                    15908647422108589002u64,
-                    7871581937849160205u64,
-                    11485391166707568454u64,
-                    8967890674443187949u64,
-                    2953704419511027860u64,
-                    83708094429939551u64,
-                ]),
-                BigInteger([
-                    957752937437919067u64,
-                    3691868197671849193u64,
-                    7516536878432022244u64,
-                    17395842416999095332u64,
-                    17493251045788477486u64,
-                    90832467393483731u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14817460926672108382u64,
-                    15161533026653973158u64,
-                    5664147848673190108u64,
-                    9370956485840954820u64,
-                    7764123665377263195u64,
-                    108796662266842505u64,
-                ]),
-                BigInteger([
-                    10730352730723513847u64,
-                    970193137864205454u64,
-                    15736210237559180410u64,
-                    2497225475880021619u64,
-                    5327366169697658891u64,
-                    102377637754239488u64,
-                ]),
-                BigInteger([
-                    18336643944562640289u64,
-                    2821060908165335341u64,
-                    5961317955036205193u64,
-                    10450831854122836558u64,
-                    10545846292554080433u64,
-                    116566660199970119u64,
-                ]),
-                BigInteger([
-                    13093440903263085605u64,
-                    11527161211963754812u64,
-                    6494425250285444918u64,
-                    7366742095057089445u64,
-                    9451203523132946797u64,
-                    97038893027697752u64,
-                ]),
-                BigInteger([
-                    9731367256186495359u64,
-                    18307826426968714182u64,
-                    15355095671789482859u64,
-                    10716019696054083024u64,
-                    10403644834948680559u64,
-                    82588883607518769u64,
-                ]),
-                BigInteger([
-                    11349353324154694851u64,
-                    4124826686205133342u64,
-                    4223780857499140461u64,
-                    3036895890549457161u64,
-                    17834183844358627834u64,
-                    58501984063342835u64,
-                ]),
-                BigInteger([
-                    8480089438397568966u64,
-                    9640092278567783455u64,
-                    2520402773442502694u64,
-                    6026360287268518924u64,
-                    3975118429483272380u64,
-                    113906773438490951u64,
-                ]),
-            ],
-        ];
-        let mut ark = Vec::<Vec<Self>>::new();
-        for row in ARK_RAW_MONT.iter() {
-            ark.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        ark
-    }
-
-    fn get_mds_consts() -> Vec<Vec<Self>> {
-        const MDS_RAW_MONT: [[BigInteger; 7]; 7] = [
-            [
-                BigInteger([
-                    18176648193947600990u64,
-                    1011703635580988350u64,
-                    11606455264118807696u64,
-                    7348521216039876994u64,
-                    4402097446447690439u64,
-                    30768671281273630u64,
-                ]),
-                BigInteger([
-                    1453447912770901894u64,
-                    13244054465087941568u64,
-                    5117783013692537809u64,
-                    11731151534915956u64,
-                    8842167713614468397u64,
-                    19384503205087261u64,
-                ]),
-                BigInteger([
-                    16858644217789408341u64,
-                    14524255662426642862u64,
-                    8104590817415679364u64,
-                    16053001771966722245u64,
-                    6167850208573708982u64,
-                    33150100585260340u64,
-                ]),
-                BigInteger([
-                    10702298604607026141u64,
-                    7334304199636576513u64,
-                    11942954398133992109u64,
-                    4657037974792417333u64,
-                    1236870929837458010u64,
-                    78791054139873456u64,
-                ]),
-                BigInteger([
-                    13133888233359569012u64,
-                    11721488948040788795u64,
-                    917270391942114389u64,
-                    12977488310981810202u64,
-                    12563209391926001498u64,
-                    71411322783030348u64,
-                ]),
-                BigInteger([
-                    9483167681456156391u64,
-                    3044714318131315087u64,
-                    13002805566405672558u64,
-                    3545166320981386063u64,
-                    18007138213751695077u64,
-                    91962196395262515u64,
-                ]),
-                BigInteger([
-                    2853008514628563925u64,
-                    17053666546915110479u64,
-                    10730409226248321597u64,
-                    1910950679933150541u64,
-                    370831175443592134u64,
-                    97922854981499054u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3314561676522635927u64,
-                    7525293652212157412u64,
-                    1398224261259657484u64,
-                    17242230281088902361u64,
-                    17553233935997165145u64,
-                    2642776596808498u64,
-                ]),
-                BigInteger([
-                    9051303009866275681u64,
-                    11217435558597687486u64,
-                    7487271215868128290u64,
-                    14161729281202115971u64,
-                    18118111371728231474u64,
-                    64229876081272689u64,
-                ]),
-                BigInteger([
-                    13058499746074770015u64,
-                    7499228815714843437u64,
-                    6140564097259576533u64,
-                    10975222621213748127u64,
-                    1800396831124875304u64,
-                    79488921401853440u64,
-                ]),
-                BigInteger([
-                    15524647282749353519u64,
-                    18309860871860264194u64,
-                    1041724555050592986u64,
-                    4600417316539914479u64,
-                    1933237404091968761u64,
-                    63640438244462328u64,
-                ]),
-                BigInteger([
-                    6672539588966309257u64,
-                    14694803320214140981u64,
-                    1264732704296278718u64,
-                    14835220288851624947u64,
-                    7964678936380271300u64,
-                    12523523575002777u64,
-                ]),
-                BigInteger([
-                    4248700204999103687u64,
-                    11694435529474875306u64,
-                    6575102026653336488u64,
-                    474636410153025789u64,
-                    15028081615746956612u64,
-                    46888392994358723u64,
-                ]),
-                BigInteger([
-                    4599788575486822769u64,
-                    9469614956797797837u64,
-                    9127387399423656625u64,
-                    15115621248308060732u64,
-                    5443987576485224986u64,
-                    105510050112767307u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7113852607004552102u64,
-                    9019893093088095225u64,
-                    17517239983448490194u64,
-                    3275267529616745874u64,
-                    17295235992634887013u64,
-                    78684331332032496u64,
-                ]),
-                BigInteger([
-                    14415753872026385658u64,
-                    16607585162543425457u64,
-                    4746538402107531823u64,
-                    14743350628331740973u64,
-                    10088498909507959719u64,
-                    87472093416509331u64,
-                ]),
-                BigInteger([
-                    15883124588688636224u64,
-                    4306142715874250087u64,
-                    9487741865623614654u64,
-                    434779940272261993u64,
-                    18218903308781533632u64,
-                    85928406290912518u64,
-                ]),
-                BigInteger([
-                    13218350184444112746u64,
-                    16234399335232956129u64,
-                    13945179284768559501u64,
-                    8329567635501088376u64,
-                    9875659406299442366u64,
-                    83946889231795195u64,
-                ]),
-                BigInteger([
-                    6689170776738342530u64,
-                    15944042990169945958u64,
-                    1425553441534247432u64,
-                    85675982284761109u64,
-                    15883321418116609074u64,
-                    63056044299723989u64,
-                ]),
-                BigInteger([
-                    14684694831830668764u64,
-                    12783014288463640474u64,
-                    15380541132977961881u64,
-                    14730824674832354860u64,
-                    4858235627181743966u64,
-                    108303704811216348u64,
-                ]),
-                BigInteger([
-                    7835982045586930392u64,
-                    9980856950236988399u64,
-                    13243197829226779161u64,
-                    6832259565715035501u64,
-                    10595105539555780426u64,
-                    12290238939721361u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16227771514108523668u64,
-                    17668204833283847399u64,
-                    9549995630772261786u64,
-                    14938176506932023528u64,
-                    4477798300112250436u64,
-                    120661880101332957u64,
-                ]),
-                BigInteger([
-                    14340800048054413896u64,
-                    10210397605290363614u64,
-                    4936476393360261488u64,
-                    18037944350551891592u64,
-                    618015766843711777u64,
-                    60314290291483639u64,
-                ]),
-                BigInteger([
-                    16206991997578487666u64,
-                    148007276678734781u64,
-                    1497437648505645215u64,
-                    2320041382898199539u64,
-                    17346430721513014954u64,
-                    46934387898089500u64,
-                ]),
-                BigInteger([
-                    13455246397326005723u64,
-                    11892421721574370264u64,
-                    8965723776770039355u64,
-                    16975605432230544473u64,
-                    18216824694309604965u64,
-                    85728030757289462u64,
-                ]),
-                BigInteger([
-                    13538199095401632385u64,
-                    16057672309507253357u64,
-                    17930971957175433200u64,
-                    3839790828465601753u64,
-                    7688094852788378745u64,
-                    41951871212036897u64,
-                ]),
-                BigInteger([
-                    13572208831103215756u64,
-                    16083536642533136464u64,
-                    15603768668297464512u64,
-                    2314864115926801960u64,
-                    18146700978008679494u64,
-                    64337413617722320u64,
-                ]),
-                BigInteger([
-                    4740061776418924255u64,
-                    5788657266790125090u64,
-                    11471724918288171475u64,
-                    2580658509424915305u64,
-                    10225514521210013209u64,
-                    102762719766162421u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5971376410208506003u64,
-                    1490502035286856397u64,
-                    4729893550445538818u64,
-                    3667632115891131054u64,
-                    2546458993956670401u64,
-                    120326675786841715u64,
-                ]),
-                BigInteger([
-                    12172723562387249056u64,
-                    1519792593393123396u64,
-                    3491553579805451118u64,
-                    11149911132801950127u64,
-                    4020911907680011398u64,
-                    103829642805454087u64,
-                ]),
-                BigInteger([
-                    1779811475233620258u64,
-                    13955330272339020612u64,
-                    2700892617398656868u64,
-                    8900019240118862848u64,
-                    16356362552997975851u64,
-                    57287451818285698u64,
-                ]),
-                BigInteger([
-                    10315046960093032905u64,
-                    11227286481270815664u64,
-                    3766148455261394775u64,
-                    2113133396753337221u64,
-                    3651299192618781455u64,
-                    64834762764861251u64,
-                ]),
-                BigInteger([
-                    3809570668299657992u64,
-                    58047159572798445u64,
-                    17771000747530135481u64,
-                    17940105031504177862u64,
-                    4261697778424431799u64,
-                    2244545125176505u64,
-                ]),
-                BigInteger([
-                    10377448411945805484u64,
-                    15231810527912262800u64,
-                    9777765567936878762u64,
-                    15205561581029743770u64,
-                    803128649163249696u64,
-                    31617031722839260u64,
-                ]),
-                BigInteger([
-                    9446647086434004261u64,
-                    17621004951227652386u64,
-                    9875375624866157858u64,
-                    9044222120928310419u64,
-                    10280876618519501454u64,
-                    61700739268824057u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9715403829565247466u64,
-                    12282298131047398368u64,
-                    14483228532839985069u64,
-                    7502710473374049084u64,
-                    5741628481483393247u64,
-                    923380980593259u64,
-                ]),
-                BigInteger([
-                    417500861771051187u64,
-                    16776550178751587932u64,
-                    7937946810133458071u64,
-                    10858868556261865904u64,
-                    14477244862134075228u64,
-                    71519176935406889u64,
-                ]),
-                BigInteger([
-                    15674440079095681069u64,
-                    10124626677920349011u64,
-                    8867763917115138779u64,
-                    16157700636535404177u64,
-                    12606815802002853905u64,
-                    91657829154545300u64,
-                ]),
-                BigInteger([
-                    16631601608288557608u64,
-                    7809881574039589190u64,
-                    14645158342200699082u64,
-                    9931429710738598179u64,
-                    8151519588745485205u64,
-                    23695515902013369u64,
-                ]),
-                BigInteger([
-                    3194304373722616553u64,
-                    10678478069685368125u64,
-                    970736837701822509u64,
-                    1778915902974179938u64,
-                    3353262863860669458u64,
-                    19401537535692907u64,
-                ]),
-                BigInteger([
-                    4254932684728016847u64,
-                    6410105474534076095u64,
-                    7867552225291042551u64,
-                    7926562444384675114u64,
-                    7337620604262413780u64,
-                    5778731279387303u64,
-                ]),
-                BigInteger([
-                    8165611391185547455u64,
-                    6272530738637317092u64,
-                    5754492308077079394u64,
-                    18283323741777781155u64,
-                    7726445553466703412u64,
-                    101372040617079361u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12110562233023054432u64,
-                    4809583742653161905u64,
-                    16503690026915056244u64,
-                    5033293641676219813u64,
-                    14085802817019799496u64,
-                    118013685849486794u64,
-                ]),
-                BigInteger([
-                    10632123958920120266u64,
-                    11329147506922862070u64,
-                    5927050193459641769u64,
-                    3542817550218150779u64,
-                    12580303319857021153u64,
-                    104885890069369709u64,
-                ]),
-                BigInteger([
-                    1591309493141948203u64,
-                    1967348974115474841u64,
-                    9235392698309346064u64,
-                    2499223786051561028u64,
-                    1313227323697292390u64,
-                    61764487589352032u64,
-                ]),
-                BigInteger([
-                    9548213011149478866u64,
-                    13170649565043042321u64,
-                    5199898157983549304u64,
-                    13627046633693294898u64,
-                    12810891398852174991u64,
-                    73139281387611817u64,
-                ]),
-                BigInteger([
-                    12170125859407421175u64,
-                    9888893609819134882u64,
-                    14556224799309653688u64,
-                    12041326782872629938u64,
-                    2396060657180762006u64,
-                    84348938954786138u64,
-                ]),
-                BigInteger([
-                    5769824486419741826u64,
-                    10896589408320704181u64,
-                    18409041611556311688u64,
-                    6338866683451126091u64,
-                    1360486135768138630u64,
-                    119330286520763922u64,
-                ]),
-                BigInteger([
-                    12137279924449546234u64,
-                    3210619008406499464u64,
-                    11440696284564612319u64,
-                    9007159098468432527u64,
-                    17482477105593257300u64,
-                    43156859186503601u64,
-                ]),
-            ],
-        ];
-        let mut mds = Vec::<Vec<Self>>::new();
-        for row in MDS_RAW_MONT.iter() {
-            mds.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        mds
-    }
-
-    fn get_mds_m_i_consts() -> Vec<Vec<Self>> {
-        const MDS_M_I_MONT: [[BigInteger; 7]; 7] = [
-            [
-                BigInteger([
-                    202099033278250856u64,
-                    5854854902718660529u64,
-                    11492539364873682930u64,
-                    8885205928937022213u64,
-                    5545221690922665192u64,
-                    39800542322357402u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    5284471054811250811u64,
-                    3682807102027598046u64,
-                    1690636188572859671u64,
-                    2961633948706210658u64,
-                    12069112165506958160u64,
-                    57386179028811649u64,
-                ]),
-                BigInteger([
-                    12099486912165025232u64,
-                    14017182341117445916u64,
-                    5555480565035788572u64,
-                    3393682985724070837u64,
-                    15441321846324359944u64,
-                    9148669684783008u64,
-                ]),
-                BigInteger([
-                    9989618929462008200u64,
-                    16572071716338403799u64,
-                    8105867982504744055u64,
-                    11722372115937686058u64,
-                    1341638636023306551u64,
-                    31296752426896123u64,
-                ]),
-                BigInteger([
-                    4619751225966057348u64,
-                    5935074180433564620u64,
-                    14770248522279387316u64,
-                    773238875351696738u64,
-                    6491277540664150919u64,
-                    33629839079947872u64,
-                ]),
-                BigInteger([
-                    13259153740900089987u64,
-                    1635089422747184235u64,
-                    17109473949669376788u64,
-                    2832554625404846565u64,
-                    14923656756968700092u64,
-                    30052710739932326u64,
-                ]),
-                BigInteger([
-                    10709636770507028739u64,
-                    8370560850363809492u64,
-                    2849663332173951840u64,
-                    3759180355493781856u64,
-                    18176089069693625400u64,
-                    19580382585976809u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    11815506025876137471u64,
-                    1473332641559046351u64,
-                    1563081756560946346u64,
-                    4587093454294571761u64,
-                    16617920791042448536u64,
-                    20499670438514279u64,
-                ]),
-                BigInteger([
-                    8480359397976873311u64,
-                    7700036255025125918u64,
-                    10148702850628654189u64,
-                    5679129872536554352u64,
-                    9639632866985728189u64,
-                    92931954757969393u64,
-                ]),
-                BigInteger([
-                    17652630109311839825u64,
-                    746839139729659226u64,
-                    6042497533968155870u64,
-                    14211496061712212474u64,
-                    5261531175505580854u64,
-                    114991242494694558u64,
-                ]),
-                BigInteger([
-                    5387941560540391626u64,
-                    11326600240342659486u64,
-                    6714724785641676382u64,
-                    16783471524299296493u64,
-                    14721401431712073261u64,
-                    118745429765696630u64,
-                ]),
-                BigInteger([
-                    16434363057486214978u64,
-                    11225912258597371776u64,
-                    1615955048681381050u64,
-                    6601477301205949089u64,
-                    12284281745577436184u64,
-                    65276798617590333u64,
-                ]),
-                BigInteger([
-                    14598483283306675235u64,
-                    8410833583220174872u64,
-                    9493109117937849463u64,
-                    9470383250509742282u64,
-                    15677124357533326574u64,
-                    56699181818939677u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    764410063447568076u64,
-                    735386214848646864u64,
-                    11136727730576320543u64,
-                    5571017948754254979u64,
-                    3027646054624172202u64,
-                    44747973614205467u64,
-                ]),
-                BigInteger([
-                    507346204999055291u64,
-                    12413225823368307866u64,
-                    13746311706513548441u64,
-                    12429820901245980260u64,
-                    3231579332336084633u64,
-                    106969138489637291u64,
-                ]),
-                BigInteger([
-                    7472647866367934196u64,
-                    9263050524953889854u64,
-                    15209932143740531010u64,
-                    16531348842438047020u64,
-                    5473243152761000497u64,
-                    1805330571204618u64,
-                ]),
-                BigInteger([
-                    17184174639088444271u64,
-                    2747541765258072939u64,
-                    17056038258168238316u64,
-                    2356511221769908592u64,
-                    4137177850552627704u64,
-                    100405570228575240u64,
-                ]),
-                BigInteger([
-                    1968946826737193406u64,
-                    13164002029198610290u64,
-                    16881544940608375723u64,
-                    4671306703695839926u64,
-                    7360244451356466818u64,
-                    114943237719529342u64,
-                ]),
-                BigInteger([
-                    10326974457377886596u64,
-                    7143440093525620437u64,
-                    4276557358323206750u64,
-                    5447675825518239067u64,
-                    14044639742346801918u64,
-                    41436838209186295u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    3348909815580538141u64,
-                    504429207603961705u64,
-                    15681572522011763084u64,
-                    10575297496984520603u64,
-                    7657711560698486178u64,
-                    94582251402799510u64,
-                ]),
-                BigInteger([
-                    2791990522326079602u64,
-                    399345812560116705u64,
-                    9644898813923314570u64,
-                    10258824195505514622u64,
-                    14372435130341013017u64,
-                    91123142755684652u64,
-                ]),
-                BigInteger([
-                    6264219085562635239u64,
-                    8681097426454803331u64,
-                    9243046178295171386u64,
-                    18244028058155618705u64,
-                    13207292012687313247u64,
-                    35238988979404809u64,
-                ]),
-                BigInteger([
-                    1685491255769799093u64,
-                    6928430552538073362u64,
-                    13731776716126972685u64,
-                    1251117248818646741u64,
-                    1071209522273053734u64,
-                    27410767537649996u64,
-                ]),
-                BigInteger([
-                    16466910164394742124u64,
-                    1251292331044372213u64,
-                    7646304898695209494u64,
-                    11681412041924747024u64,
-                    7969144524674491516u64,
-                    84744272736849017u64,
-                ]),
-                BigInteger([
-                    12704644798131764409u64,
-                    17793007529007129391u64,
-                    6812610832138605498u64,
-                    9315162653394433380u64,
-                    3154693879227700487u64,
-                    93472628116847617u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    11774177004458771854u64,
-                    14781985694272669502u64,
-                    7443819461620446228u64,
-                    10835386081744206474u64,
-                    12040048054739223343u64,
-                    111691209575561419u64,
-                ]),
-                BigInteger([
-                    8016813604697393366u64,
-                    8273379935897419944u64,
-                    5555992790639557367u64,
-                    6905800487903075402u64,
-                    4684761508314220112u64,
-                    11696477794262211u64,
-                ]),
-                BigInteger([
-                    1043569736952269354u64,
-                    3554020614326394579u64,
-                    14681706581206929694u64,
-                    11214269421475506863u64,
-                    5797926299102688362u64,
-                    88000651332878682u64,
-                ]),
-                BigInteger([
-                    8091849914282026242u64,
-                    4599155035622181346u64,
-                    14145256627773506373u64,
-                    3676112169883898624u64,
-                    17623076271865076986u64,
-                    90357968767303131u64,
-                ]),
-                BigInteger([
-                    12165942386695108199u64,
-                    10379593550818981962u64,
-                    3033941484399900668u64,
-                    15092780330387996031u64,
-                    8502742999154560582u64,
-                    23307053265710535u64,
-                ]),
-                BigInteger([
-                    12855812537407702964u64,
-                    13344019717026889389u64,
-                    3498117548397588600u64,
-                    2007530998093616349u64,
-                    14539108379411623903u64,
-                    74148601807073191u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    993191696763083825u64,
-                    12335971633778082209u64,
-                    16839645971760540311u64,
-                    9430906306325352605u64,
-                    14888514768801269256u64,
-                    38798854892714634u64,
-                ]),
-                BigInteger([
-                    3926769935876424370u64,
-                    17413493191260449865u64,
-                    5291342513069554708u64,
-                    10225139848202332937u64,
-                    14431490598316809229u64,
-                    98362254538549542u64,
-                ]),
-                BigInteger([
-                    12894270209448315489u64,
-                    6871926998163316046u64,
-                    17024532822906977385u64,
-                    597592972495240615u64,
-                    18370193459702015669u64,
-                    14296758697463263u64,
-                ]),
-                BigInteger([
-                    8374507095540545316u64,
-                    18233626963646130353u64,
-                    2711152506021425169u64,
-                    12288199248018244326u64,
-                    16486567506200108318u64,
-                    66185104532087932u64,
-                ]),
-                BigInteger([
-                    6834912211154117483u64,
-                    11509391902736017847u64,
-                    6426632794932401609u64,
-                    7350461755471460859u64,
-                    11311152298522476067u64,
-                    60680812364113975u64,
-                ]),
-                BigInteger([
-                    2494079660769109146u64,
-                    2683111166143789429u64,
-                    10404224151736219640u64,
-                    2178185207975594713u64,
-                    18206097259168188617u64,
-                    102191907644336414u64,
-                ]),
-            ],
-        ];
-        let mut mds = Vec::<Vec<Self>>::new();
-        for row in MDS_M_I_MONT.iter() {
-            mds.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        mds
-    }
-
-    fn get_mds_v_consts() -> Vec<Vec<Self>> {
-        const MDS_V_MONT: [[BigInteger; 6]; 57] = [
-            [
-                BigInteger([
-                    3314561676522635927u64,
-                    7525293652212157412u64,
-                    1398224261259657484u64,
-                    17242230281088902361u64,
-                    17553233935997165145u64,
-                    2642776596808498u64,
-                ]),
-                BigInteger([
-                    7113852607004552102u64,
-                    9019893093088095225u64,
-                    17517239983448490194u64,
-                    3275267529616745874u64,
-                    17295235992634887013u64,
-                    78684331332032496u64,
-                ]),
-                BigInteger([
-                    16227771514108523668u64,
-                    17668204833283847399u64,
-                    9549995630772261786u64,
-                    14938176506932023528u64,
-                    4477798300112250436u64,
-                    120661880101332957u64,
-                ]),
-                BigInteger([
-                    5971376410208506003u64,
-                    1490502035286856397u64,
-                    4729893550445538818u64,
-                    3667632115891131054u64,
-                    2546458993956670401u64,
-                    120326675786841715u64,
-                ]),
-                BigInteger([
-                    9715403829565247466u64,
-                    12282298131047398368u64,
-                    14483228532839985069u64,
-                    7502710473374049084u64,
-                    5741628481483393247u64,
-                    923380980593259u64,
-                ]),
-                BigInteger([
-                    12110562233023054432u64,
-                    4809583742653161905u64,
-                    16503690026915056244u64,
-                    5033293641676219813u64,
-                    14085802817019799496u64,
-                    118013685849486794u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13943536529642092046u64,
-                    12091769205931670957u64,
-                    1887253337027155186u64,
-                    16412513690829171037u64,
-                    7647833295055814930u64,
-                    51879382882338750u64,
-                ]),
-                BigInteger([
-                    13837717041745548990u64,
-                    16233795971927719656u64,
-                    1105175023276060624u64,
-                    9040951179390053407u64,
-                    7969722152639615023u64,
-                    114213712119465265u64,
-                ]),
-                BigInteger([
-                    6978804513948049942u64,
-                    981405677481325036u64,
-                    8832468913770274972u64,
-                    16739978374160009715u64,
-                    13351954933724938904u64,
-                    115563413271868175u64,
-                ]),
-                BigInteger([
-                    18284435714793387466u64,
-                    17742834194873273031u64,
-                    13512302222221341306u64,
-                    12901183834675712875u64,
-                    11817569114451932791u64,
-                    94071231894197574u64,
-                ]),
-                BigInteger([
-                    5789161061117348765u64,
-                    13431152120554921194u64,
-                    6080813256351188711u64,
-                    3169696485620398698u64,
-                    1407009335958425817u64,
-                    26445233058084181u64,
-                ]),
-                BigInteger([
-                    577347630065406525u64,
-                    7096092607539715853u64,
-                    15868112144027905730u64,
-                    11659336119129499370u64,
-                    16493600127336950804u64,
-                    87800581606051318u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6279307372613654982u64,
-                    5646359650526049194u64,
-                    437130506581412541u64,
-                    18306007627631541630u64,
-                    2866361093395711147u64,
-                    8921499002191725u64,
-                ]),
-                BigInteger([
-                    15306291680779852482u64,
-                    10182703797232469510u64,
-                    16856973519811365094u64,
-                    16686746169757960185u64,
-                    12871145017660465120u64,
-                    86330664636640484u64,
-                ]),
-                BigInteger([
-                    4270465676266250451u64,
-                    11992159382541231247u64,
-                    12216573647814435908u64,
-                    14977558547983361128u64,
-                    9726385568016993022u64,
-                    117318226256292743u64,
-                ]),
-                BigInteger([
-                    12186666490615908186u64,
-                    704863594602429569u64,
-                    6392911133473722613u64,
-                    5873936867710978558u64,
-                    4982287292112858236u64,
-                    72890604454980199u64,
-                ]),
-                BigInteger([
-                    10080701994831287846u64,
-                    4997733392175708949u64,
-                    16740308229833804856u64,
-                    12179104816207211404u64,
-                    3554610895348656413u64,
-                    73513645773972651u64,
-                ]),
-                BigInteger([
-                    5374939697261471298u64,
-                    9553546411914604055u64,
-                    10594481924876179884u64,
-                    13281086315397038624u64,
-                    2152531476593948574u64,
-                    49305227757109406u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14901709521340620492u64,
-                    4701484547041406510u64,
-                    17810508249491629003u64,
-                    10580130905002805931u64,
-                    3092942556748025845u64,
-                    31489125758317747u64,
-                ]),
-                BigInteger([
-                    9993291558927678108u64,
-                    7341276548367838222u64,
-                    7737189126514608415u64,
-                    4801714739336887551u64,
-                    6177925295247762039u64,
-                    6049318429698673u64,
-                ]),
-                BigInteger([
-                    5075711915850872794u64,
-                    9947623124304715699u64,
-                    14549799499585155354u64,
-                    11536166424249064998u64,
-                    16434086956800406104u64,
-                    114530955664726699u64,
-                ]),
-                BigInteger([
-                    16714845800696556579u64,
-                    2916888470506371105u64,
-                    18208132607577985472u64,
-                    5326504869486427653u64,
-                    10333977552661055537u64,
-                    47751631819431819u64,
-                ]),
-                BigInteger([
-                    1077179275830959568u64,
-                    13932508266993437549u64,
-                    4350997638225745240u64,
-                    13268847036999965812u64,
-                    17704650442283306318u64,
-                    68311772386131426u64,
-                ]),
-                BigInteger([
-                    5745128866697751115u64,
-                    14472837522416457722u64,
-                    5196450606965928447u64,
-                    10297969486680648749u64,
-                    2306984032456158281u64,
-                    39451662310414403u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17786524046604134139u64,
-                    61068200570663591u64,
-                    13606037797306282050u64,
-                    2603034088395543280u64,
-                    17848125504368930834u64,
-                    38330821581290864u64,
-                ]),
-                BigInteger([
-                    3883464407479372757u64,
-                    14868905756245870844u64,
-                    6475185569157738758u64,
-                    14207388791615974321u64,
-                    10496229682861366861u64,
-                    89319873552182580u64,
-                ]),
-                BigInteger([
-                    8673956321583232844u64,
-                    355841843252436393u64,
-                    5142876765028560203u64,
-                    3315061799618361385u64,
-                    6969719513839760601u64,
-                    55165956997291674u64,
-                ]),
-                BigInteger([
-                    11350662584267789872u64,
-                    9519047480126133026u64,
-                    13281846106434185918u64,
-                    17763090140570463474u64,
-                    10545807207755983423u64,
-                    100847139328458173u64,
-                ]),
-                BigInteger([
-                    16547758300655131503u64,
-                    7794923042509131748u64,
-                    15971110032889284997u64,
-                    6644363522754555559u64,
-                    17058466052491155864u64,
-                    47480633991844703u64,
-                ]),
-                BigInteger([
-                    14915011241414518221u64,
-                    16051959895059981656u64,
-                    11540176651093725959u64,
-                    7663415016184277010u64,
-                    17536948488060160842u64,
-                    1926598005888670u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11912079528434403137u64,
-                    10015318934261760300u64,
-                    7992150167427833488u64,
-                    1166769889948288012u64,
-                    18208066949273003201u64,
-                    110704499793225573u64,
-                ]),
-                BigInteger([
-                    16952845913781150722u64,
-                    10945894302034832066u64,
-                    13910103566923780707u64,
-                    17458478510167546770u64,
-                    6125039178839739771u64,
-                    114009756296751592u64,
-                ]),
-                BigInteger([
-                    7219933964902558154u64,
-                    11242465980165235987u64,
-                    11533094126423972310u64,
-                    13080277260360261042u64,
-                    660584388641514619u64,
-                    50933715747980513u64,
-                ]),
-                BigInteger([
-                    9457069046176756193u64,
-                    6382137380054596040u64,
-                    16172535188269096122u64,
-                    16848500740854851799u64,
-                    5406202019651949034u64,
-                    26192505371486491u64,
-                ]),
-                BigInteger([
-                    12511698307851927050u64,
-                    129535211886010070u64,
-                    8835324512577797228u64,
-                    9566855356306829510u64,
-                    6270474658547167663u64,
-                    68597354909031864u64,
-                ]),
-                BigInteger([
-                    14203494120519001676u64,
-                    15283095375096238932u64,
-                    16905510280197061469u64,
-                    15757597251434010640u64,
-                    5377886500213033441u64,
-                    23684245360553878u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8319306011591022511u64,
-                    937888598106064238u64,
-                    6486549655682120276u64,
-                    16329864240310700869u64,
-                    13139950399193537283u64,
-                    75808928010070070u64,
-                ]),
-                BigInteger([
-                    4628283801202370544u64,
-                    14416127700265996605u64,
-                    8146947514316020891u64,
-                    12856547974251176220u64,
-                    9844283488974671033u64,
-                    39704048615045998u64,
-                ]),
-                BigInteger([
-                    11577340321743579208u64,
-                    11452212482619441685u64,
-                    4842218585669219057u64,
-                    13710373778449901278u64,
-                    16685260895338619397u64,
-                    35229150894300924u64,
-                ]),
-                BigInteger([
-                    1118433707598013235u64,
-                    10218023417332397967u64,
-                    12192507564061923957u64,
-                    8911920721733798116u64,
-                    2806110550006322168u64,
-                    40152713999648117u64,
-                ]),
-                BigInteger([
-                    4326693384176095502u64,
-                    16886540815331896864u64,
-                    14741214084083901498u64,
-                    2938757535486188826u64,
-                    4827668818486673318u64,
-                    47389407263750394u64,
-                ]),
-                BigInteger([
-                    12727335606361632530u64,
-                    4549549349006471570u64,
-                    17731701989939669141u64,
-                    11317209284288533071u64,
-                    2868048812122937048u64,
-                    27843955348881338u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8463626029667132984u64,
-                    9079225431003169102u64,
-                    8305359860786799053u64,
-                    5838951206477557234u64,
-                    4893767656479253276u64,
-                    641028472637742u64,
-                ]),
-                BigInteger([
-                    5929057951969761179u64,
-                    3530226641098282484u64,
-                    5735517286194829355u64,
-                    12124423916129666266u64,
-                    4598169127294041724u64,
-                    30165995654144042u64,
-                ]),
-                BigInteger([
-                    5920248582446424607u64,
-                    8280980395340970082u64,
-                    1779845014584045826u64,
-                    3196970249640627366u64,
-                    10861740178016606446u64,
-                    64278001493098361u64,
-                ]),
-                BigInteger([
-                    4487080109758549218u64,
-                    13797380630622420148u64,
-                    5337872971382614040u64,
-                    5693436878580311828u64,
-                    9779119428820544979u64,
-                    108354367653184483u64,
-                ]),
-                BigInteger([
-                    2553057134185930623u64,
-                    8820748102164555624u64,
-                    12389767114064173384u64,
-                    16666234313081467213u64,
-                    4561456802312935979u64,
-                    23236438666056268u64,
-                ]),
-                BigInteger([
-                    11489215426984785358u64,
-                    7883791351087440060u64,
-                    10053598267644840491u64,
-                    4231398809273546326u64,
-                    13707491455811792014u64,
-                    54746773763321899u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17188167437728176013u64,
-                    8642432425920784305u64,
-                    328256041316912863u64,
-                    13528289553285980621u64,
-                    14455994946045275654u64,
-                    32383160644579440u64,
-                ]),
-                BigInteger([
-                    256877380006030434u64,
-                    10953176826672354050u64,
-                    9841373421766998020u64,
-                    2992992522472127193u64,
-                    11392252268863249479u64,
-                    56755771665042289u64,
-                ]),
-                BigInteger([
-                    3065473024998015367u64,
-                    4462544021400135807u64,
-                    4272497832475403646u64,
-                    7179809001807438664u64,
-                    233609475502577818u64,
-                    37598627763540598u64,
-                ]),
-                BigInteger([
-                    8492374266234551927u64,
-                    16407601213561932740u64,
-                    12142577965684905702u64,
-                    7306514843959604582u64,
-                    8164162157911977430u64,
-                    13883835367322909u64,
-                ]),
-                BigInteger([
-                    9436584188959214341u64,
-                    9510935473596317529u64,
-                    17309862067034341958u64,
-                    1204220175593328763u64,
-                    11588159288902869420u64,
-                    93545212796508106u64,
-                ]),
-                BigInteger([
-                    17585217156773127729u64,
-                    7037438638329881634u64,
-                    8992340860115326904u64,
-                    7901501868588869516u64,
-                    987463413089161259u64,
-                    41755273027775696u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12658020581460427906u64,
-                    14862471757910589242u64,
-                    7266028173093778744u64,
-                    14404170644372349382u64,
-                    1599275799888088126u64,
-                    84773582119969194u64,
-                ]),
-                BigInteger([
-                    10625246843210954968u64,
-                    3385775609609969762u64,
-                    17772779832779240909u64,
-                    16691144503263592700u64,
-                    8005700030588048890u64,
-                    105065256031115328u64,
-                ]),
-                BigInteger([
-                    18237558964362992924u64,
-                    1821482028085972951u64,
-                    4381219793527287604u64,
-                    18153348024991543423u64,
-                    15557174541870313797u64,
-                    29388185602355102u64,
-                ]),
-                BigInteger([
-                    1064089919138861013u64,
-                    2478115630333456063u64,
-                    1153638357488801293u64,
-                    10711025854968864165u64,
-                    3120734334081962650u64,
-                    79464747289850494u64,
-                ]),
-                BigInteger([
-                    11600188930293936784u64,
-                    14332338198064351022u64,
-                    1603274992816448702u64,
-                    4873724550361619248u64,
-                    16200285511668943333u64,
-                    62212959364694327u64,
-                ]),
-                BigInteger([
-                    13572889040826733311u64,
-                    732100258410041322u64,
-                    9914344914397699590u64,
-                    4430286367830623205u64,
-                    11104337757692797019u64,
-                    11866272983173611u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12907141310002469376u64,
-                    14933397820446969146u64,
-                    5613659962519870516u64,
-                    3244545445321024727u64,
-                    851412916502436091u64,
-                    9715172384868202u64,
-                ]),
-                BigInteger([
-                    18231387423165136253u64,
-                    16870319986567247244u64,
-                    17986271035358939264u64,
-                    11934290581273284876u64,
-                    2873049384660223293u64,
-                    54249273117977469u64,
-                ]),
-                BigInteger([
-                    6382389490231431126u64,
-                    6496188486898841068u64,
-                    1417586544433045722u64,
-                    9294296959223053561u64,
-                    4742849294314775204u64,
-                    11964743984173641u64,
-                ]),
-                BigInteger([
-                    12599304309407916931u64,
-                    8928556116140560720u64,
-                    10810402395092607041u64,
-                    5567111391118889547u64,
-                    15739919675868573695u64,
-                    63977234171911191u64,
-                ]),
-                BigInteger([
-                    3562945103147276735u64,
-                    14522836035640115771u64,
-                    6811758394448941991u64,
-                    1950458302924423556u64,
-                    13087241795379318730u64,
-                    35053693477945427u64,
-                ]),
-                BigInteger([
-                    12237379905846288956u64,
-                    9292752610007772306u64,
-                    10558864856305663551u64,
-                    468092048765508978u64,
-                    13603057513471544394u64,
-                    110231829825110946u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14288454886029070690u64,
-                    13957300592500473959u64,
-                    3811701930066328501u64,
-                    18170044772009185240u64,
-                    17895521412426093050u64,
-                    98909700153477465u64,
-                ]),
-                BigInteger([
-                    14294373740107630316u64,
-                    15455541104595205925u64,
-                    10275825832656347415u64,
-                    2375433225538351791u64,
-                    13822623416052579860u64,
-                    78417852736375956u64,
-                ]),
-                BigInteger([
-                    2269581273836499718u64,
-                    14448719511993445876u64,
-                    4887690972499951894u64,
-                    7639143354733211831u64,
-                    17951076359471033050u64,
-                    114890178977830328u64,
-                ]),
-                BigInteger([
-                    6325783025409997432u64,
-                    15432019532768141313u64,
-                    10566030173573881617u64,
-                    11918628507543338497u64,
-                    15690367216537674951u64,
-                    55421931309800289u64,
-                ]),
-                BigInteger([
-                    13822326533443974138u64,
-                    8069828597376082479u64,
-                    16048318368238199483u64,
-                    15928521100997867723u64,
-                    9551083724040109742u64,
-                    10721207207693074u64,
-                ]),
-                BigInteger([
-                    16276952791559070416u64,
-                    2578839349502191275u64,
-                    16548092744897577664u64,
-                    15464903460549909045u64,
-                    15446574252506185155u64,
-                    27730299869241531u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12853388692628096172u64,
-                    2492152717211936522u64,
-                    11194447895757581166u64,
-                    5091143558307063036u64,
-                    11223963737814512302u64,
-                    14384207523897363u64,
-                ]),
-                BigInteger([
-                    10715797186726630456u64,
-                    13302924522434499164u64,
-                    8337585943262326860u64,
-                    6646732747336588819u64,
-                    3580482898875474357u64,
-                    40192844607990661u64,
-                ]),
-                BigInteger([
-                    5701395062351765767u64,
-                    11219337054928179029u64,
-                    1624396920125773339u64,
-                    4516455115416639127u64,
-                    15391264802897120201u64,
-                    18093826178988282u64,
-                ]),
-                BigInteger([
-                    4210922188539379649u64,
-                    14319015519950034864u64,
-                    3041358653961136796u64,
-                    12122169750229035910u64,
-                    11042864028595068520u64,
-                    21510680969722309u64,
-                ]),
-                BigInteger([
-                    8894953674528534850u64,
-                    30564974355056324u64,
-                    7771855240580211438u64,
-                    18075134656032959925u64,
-                    16364694373056290447u64,
-                    2586520238681484u64,
-                ]),
-                BigInteger([
-                    8527375780350347622u64,
-                    14041945300178675520u64,
-                    13280272239791006832u64,
-                    18358130437748336709u64,
-                    5872658417512467945u64,
-                    28503541880543278u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7656967727870954451u64,
-                    2858351332785781244u64,
-                    2213610937515996234u64,
-                    17984329608743263839u64,
-                    9002924340077475495u64,
-                    30858087559886988u64,
-                ]),
-                BigInteger([
-                    3334568122548371471u64,
-                    15992817333398024408u64,
-                    9380102763311393421u64,
-                    4013951598150094391u64,
-                    15302283428021400302u64,
-                    67052429043861857u64,
-                ]),
-                BigInteger([
-                    10041187700640591273u64,
-                    7062176894174244137u64,
-                    16284344444572749257u64,
-                    15980269869056584046u64,
-                    9733563078921244881u64,
-                    98004400462612076u64,
-                ]),
-                BigInteger([
-                    11767310918938271499u64,
-                    1036184041292648877u64,
-                    11594847279283590857u64,
-                    1209386347494772399u64,
-                    3759295130748027731u64,
-                    60584433357144501u64,
-                ]),
-                BigInteger([
-                    10331811853713239080u64,
-                    10441202305577722295u64,
-                    9085105761410113978u64,
-                    13904782418042348998u64,
-                    5008868176601572435u64,
-                    78457968323761684u64,
-                ]),
-                BigInteger([
-                    17348515543645011112u64,
-                    2325715922514739299u64,
-                    11221995487845360846u64,
-                    10340669445673545582u64,
-                    10464635859280257708u64,
-                    88955909445072237u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17923752102391847229u64,
-                    17559315719195946819u64,
-                    15402585097779817458u64,
-                    10118830462784283980u64,
-                    2892358383014236540u64,
-                    46214599696930126u64,
-                ]),
-                BigInteger([
-                    13466894276709751380u64,
-                    270304200188402756u64,
-                    5377714636295098741u64,
-                    8589445010388665665u64,
-                    10886220308454410417u64,
-                    18912465166909859u64,
-                ]),
-                BigInteger([
-                    8977026101875381725u64,
-                    15695970261652685918u64,
-                    510474847629741254u64,
-                    1107055134172069852u64,
-                    16488717337438561428u64,
-                    118656572469405085u64,
-                ]),
-                BigInteger([
-                    6492205605835806692u64,
-                    3166044860381525660u64,
-                    18327963000989576135u64,
-                    6226480632945498285u64,
-                    11783413097394750401u64,
-                    71633016090581029u64,
-                ]),
-                BigInteger([
-                    7929834630264464187u64,
-                    4836780977677413172u64,
-                    14957741332517123738u64,
-                    14725047094424440091u64,
-                    4911693383191773745u64,
-                    48062023173049377u64,
-                ]),
-                BigInteger([
-                    4489705598113519669u64,
-                    15801018353738881420u64,
-                    2161872619033949u64,
-                    11885488373801533948u64,
-                    5692753761244295364u64,
-                    25655461599443755u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11718218744899603962u64,
-                    10610413417036756470u64,
-                    15994791185290083182u64,
-                    17025669508520384254u64,
-                    16857286643390326815u64,
-                    108112656440260913u64,
-                ]),
-                BigInteger([
-                    17850839155936906205u64,
-                    10616795690716961530u64,
-                    9548769616103156729u64,
-                    14997836347791440416u64,
-                    1204890039819899440u64,
-                    6086210246577979u64,
-                ]),
-                BigInteger([
-                    3891702150974316120u64,
-                    11255841707456398818u64,
-                    6153622301082552349u64,
-                    865763814488303416u64,
-                    17803609119158109647u64,
-                    9295993470827187u64,
-                ]),
-                BigInteger([
-                    1566817262236459157u64,
-                    7818090689342903583u64,
-                    13462427069558488063u64,
-                    6554508901642038890u64,
-                    17365833544773975671u64,
-                    111860950461468009u64,
-                ]),
-                BigInteger([
-                    4269898605043244188u64,
-                    6350064094565404430u64,
-                    10069915972589780459u64,
-                    13089508889896129709u64,
-                    6351392251302069693u64,
-                    43899547602516546u64,
-                ]),
-                BigInteger([
-                    15820649722205022495u64,
-                    7501865649719589193u64,
-                    1319642432948567438u64,
-                    4511016387261268493u64,
-                    9208937116927328476u64,
-                    9982138404753697u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5707250888299695072u64,
-                    9942286669683718199u64,
-                    8934384309338056138u64,
-                    14271036046318050821u64,
-                    13507646573093451824u64,
-                    38814910985585615u64,
-                ]),
-                BigInteger([
-                    902680678921348962u64,
-                    2743553404569606246u64,
-                    11648720470237744426u64,
-                    10536843096331560415u64,
-                    8124487158405882481u64,
-                    85317664493521850u64,
-                ]),
-                BigInteger([
-                    2310444726034556743u64,
-                    11885012813372775188u64,
-                    13020948472635857072u64,
-                    8073734297533650988u64,
-                    6162745666100968543u64,
-                    64324519568727918u64,
-                ]),
-                BigInteger([
-                    16930205330991594915u64,
-                    3165783716988264216u64,
-                    14004319494938167109u64,
-                    14987238391045533937u64,
-                    906117995569431591u64,
-                    21958638371318732u64,
-                ]),
-                BigInteger([
-                    18313308500686602107u64,
-                    10740715554836817813u64,
-                    17572874544460333993u64,
-                    5317479271194196025u64,
-                    12443834997294134629u64,
-                    84779820432191016u64,
-                ]),
-                BigInteger([
-                    17955239486511423082u64,
-                    18288268508918075872u64,
-                    4487820087735786178u64,
-                    8247572860579375239u64,
-                    1373457288067524702u64,
-                    77241998444250036u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4699986030361166703u64,
-                    16926747119224629569u64,
-                    12282917696094983107u64,
-                    7275899490038950908u64,
-                    9581907392801487620u64,
-                    52618713048172707u64,
-                ]),
-                BigInteger([
-                    5050325889639742900u64,
-                    8153890172290688903u64,
-                    13477403722058654897u64,
-                    3301821180187550803u64,
-                    5086255286310567154u64,
-                    38460540458949264u64,
-                ]),
-                BigInteger([
-                    6795691247861367262u64,
-                    5898847151829589834u64,
-                    8650326942733100436u64,
-                    15520596015805276954u64,
-                    9504259965668262467u64,
-                    39563771618471502u64,
-                ]),
-                BigInteger([
-                    12599014554745600331u64,
-                    13381779618581745515u64,
-                    760129992914945657u64,
-                    14281766540436895651u64,
-                    16550338789790201912u64,
-                    86033364779074951u64,
-                ]),
-                BigInteger([
-                    8022414817686496047u64,
-                    17769763930998373546u64,
-                    14251792606889825512u64,
-                    6564452565165291521u64,
-                    10186235239185423787u64,
-                    34179284948111601u64,
-                ]),
-                BigInteger([
-                    12810943563263841217u64,
-                    5621475761998932457u64,
-                    13220544946328821869u64,
-                    17758630622485584095u64,
-                    15787033792989192291u64,
-                    25662076971173057u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8704052849779584039u64,
-                    1347338454606416531u64,
-                    15700548803583738801u64,
-                    9211848780097398746u64,
-                    857687831203464176u64,
-                    25762101034340322u64,
-                ]),
-                BigInteger([
-                    1011677844337330432u64,
-                    4293524494420420468u64,
-                    10773139868300068284u64,
-                    9753883170233271457u64,
-                    13052286427333619610u64,
-                    50291462422630648u64,
-                ]),
-                BigInteger([
-                    8507973228716841158u64,
-                    7992785501934134846u64,
-                    16729823538529223529u64,
-                    13595876090328327945u64,
-                    7108174004030078690u64,
-                    100513367579726683u64,
-                ]),
-                BigInteger([
-                    5263607032521559785u64,
-                    17648803240118867695u64,
-                    9313451190568370374u64,
-                    16625741434416724264u64,
-                    6602348321090666286u64,
-                    2727981295561759u64,
-                ]),
-                BigInteger([
-                    4381305905019901107u64,
-                    11914560299641924252u64,
-                    10903752908410666861u64,
-                    16017432305131996841u64,
-                    10390212289333165138u64,
-                    32400693252694745u64,
-                ]),
-                BigInteger([
-                    17853581411809263985u64,
-                    7038237820461248401u64,
-                    10154330606387194183u64,
-                    17880063310836825625u64,
-                    3989094914513786480u64,
-                    84412044483945672u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    645752737159884056u64,
-                    14639945630440965055u64,
-                    16521462057233502778u64,
-                    2311229970520874947u64,
-                    10859403923034361624u64,
-                    59790297255598765u64,
-                ]),
-                BigInteger([
-                    13899922060871997972u64,
-                    105978347849428647u64,
-                    11036623417134146147u64,
-                    16288784876538843630u64,
-                    17012433093879589364u64,
-                    87271182893793562u64,
-                ]),
-                BigInteger([
-                    16851093559744284147u64,
-                    5985196147440967137u64,
-                    14664424624638242267u64,
-                    14504030783228375011u64,
-                    11665585480059710124u64,
-                    47816624286020294u64,
-                ]),
-                BigInteger([
-                    8063342032670165971u64,
-                    4605355954739074350u64,
-                    7087595708388221512u64,
-                    16076089283850566612u64,
-                    12031633565302241042u64,
-                    48004531734204117u64,
-                ]),
-                BigInteger([
-                    16274782554351299653u64,
-                    15591412593579892400u64,
-                    15437300896182774909u64,
-                    15681661479267690537u64,
-                    8224605540276478563u64,
-                    72906005357170569u64,
-                ]),
-                BigInteger([
-                    17765217261326759797u64,
-                    417641976130644401u64,
-                    17453554959215650649u64,
-                    12102391901747098213u64,
-                    9515966949997789324u64,
-                    34413538947594454u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13974447186496707218u64,
-                    12918023057071481297u64,
-                    1704692914749819141u64,
-                    4465594329702108834u64,
-                    1345925742746785843u64,
-                    115770502511235430u64,
-                ]),
-                BigInteger([
-                    2446215438934272850u64,
-                    2898262179280807015u64,
-                    7354116928979580655u64,
-                    2601251117571180885u64,
-                    2110630780743135317u64,
-                    21402539626944985u64,
-                ]),
-                BigInteger([
-                    9065085472126429819u64,
-                    7735678564168168450u64,
-                    547230554561884282u64,
-                    13285654921202147505u64,
-                    15233113109375947859u64,
-                    91788339865582365u64,
-                ]),
-                BigInteger([
-                    10839197637605101966u64,
-                    13466682480522410710u64,
-                    17461159267240639264u64,
-                    5651873166573380064u64,
-                    904295263001084470u64,
-                    89650733209444040u64,
-                ]),
-                BigInteger([
-                    1361542183752330973u64,
-                    10709994926918371981u64,
-                    12402347586938556000u64,
-                    6135747690077999111u64,
-                    571554956075299679u64,
-                    45472076571852377u64,
-                ]),
-                BigInteger([
-                    5431344921240273999u64,
-                    8326252045496020266u64,
-                    7685662038877361441u64,
-                    7483229648267147002u64,
-                    11016379647191555412u64,
-                    58385128595674652u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3153404794597170989u64,
-                    4163718051705581006u64,
-                    5505507373960980943u64,
-                    1265949502306438498u64,
-                    4069444279744877235u64,
-                    117283424174862098u64,
-                ]),
-                BigInteger([
-                    2790726210456918070u64,
-                    5377729840050005012u64,
-                    7966314029217964340u64,
-                    7758866131283473850u64,
-                    3185890976110533688u64,
-                    108013609584416058u64,
-                ]),
-                BigInteger([
-                    13037366644111652130u64,
-                    3272071209133746864u64,
-                    11023288075796182080u64,
-                    4434101533418529826u64,
-                    2353134114510742352u64,
-                    5734720319543615u64,
-                ]),
-                BigInteger([
-                    11287114898400226112u64,
-                    15314980205257361893u64,
-                    8485437730228724656u64,
-                    4213164109288026130u64,
-                    4199332226832497194u64,
-                    30186971395058928u64,
-                ]),
-                BigInteger([
-                    10593953267748459441u64,
-                    17487614648548049238u64,
-                    7667714264811514898u64,
-                    2017526644032365160u64,
-                    14445471962390188738u64,
-                    55701820185118704u64,
-                ]),
-                BigInteger([
-                    2690486505188788816u64,
-                    1832442141171489900u64,
-                    10141671871241445463u64,
-                    15163489957348238430u64,
-                    15067093511511029537u64,
-                    66691879591151233u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3649364847752783639u64,
-                    7335593182690133907u64,
-                    4710063117053231129u64,
-                    10734208893242565775u64,
-                    11579273641638903192u64,
-                    43537124422342668u64,
-                ]),
-                BigInteger([
-                    16211320241496160280u64,
-                    16440005239308054318u64,
-                    10718582718962030351u64,
-                    2730902664073138343u64,
-                    18326325134870517053u64,
-                    68007193834839028u64,
-                ]),
-                BigInteger([
-                    3637945443452364461u64,
-                    3306731878819144243u64,
-                    15542501511921245155u64,
-                    13213697404443113550u64,
-                    17657740010655082839u64,
-                    117371722299084094u64,
-                ]),
-                BigInteger([
-                    3191336758717915956u64,
-                    4287733655315710693u64,
-                    3002689813707242801u64,
-                    7386445471023320188u64,
-                    2125152394525987630u64,
-                    73533614312029302u64,
-                ]),
-                BigInteger([
-                    11364761252585079313u64,
-                    16481858388198214907u64,
-                    13539362005656126794u64,
-                    11131765852443093060u64,
-                    16032773256633253360u64,
-                    9261314655196893u64,
-                ]),
-                BigInteger([
-                    12028721272470978902u64,
-                    8622333359814441603u64,
-                    13018046297240436018u64,
-                    8360385894306317266u64,
-                    4124311102450330834u64,
-                    58885733553541881u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3396565637901922471u64,
-                    10263215285540232428u64,
-                    5275879531739874841u64,
-                    17369759863027705956u64,
-                    1555492408804556532u64,
-                    58763198552783216u64,
-                ]),
-                BigInteger([
-                    3691781611899037011u64,
-                    3870640123140404869u64,
-                    3159887229046315254u64,
-                    8495916937169174117u64,
-                    14372356458495837060u64,
-                    66156423598380144u64,
-                ]),
-                BigInteger([
-                    3872554927768319443u64,
-                    6211211890706650345u64,
-                    8861910722131386105u64,
-                    7272216049528814342u64,
-                    10867842307290064u64,
-                    25925975642469789u64,
-                ]),
-                BigInteger([
-                    10225727421011963870u64,
-                    11587221509414957247u64,
-                    18038671298729675812u64,
-                    8032116250376392364u64,
-                    5873680269428846985u64,
-                    15832167753284110u64,
-                ]),
-                BigInteger([
-                    6145575550680947056u64,
-                    7200673869973974699u64,
-                    6197120199998287039u64,
-                    16346557615010705536u64,
-                    17918582654922599070u64,
-                    114935797576504666u64,
-                ]),
-                BigInteger([
-                    85001568422986859u64,
-                    16039901658619550859u64,
-                    2082962351481851757u64,
-                    7411149535386137051u64,
-                    15696938940165079895u64,
-                    96420212077044771u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9990528714556175109u64,
-                    17011744286331199190u64,
-                    10379781669272929441u64,
-                    17972691745135838230u64,
-                    6663760745060076934u64,
-                    73467789368453556u64,
-                ]),
-                BigInteger([
-                    794697334095623809u64,
-                    16193894803324209040u64,
-                    14310792984513558828u64,
-                    14373930869142170669u64,
-                    8389959911971488423u64,
-                    13837942733601134u64,
-                ]),
-                BigInteger([
-                    2508139719909809194u64,
-                    9714102909010317330u64,
-                    10350107230468277399u64,
-                    12232821358874534572u64,
-                    32657244372261398u64,
-                    85448059803197049u64,
-                ]),
-                BigInteger([
-                    16445786667127590709u64,
-                    8392448336526842892u64,
-                    7453995021150680906u64,
-                    17199019872452983568u64,
-                    14868152397903320557u64,
-                    39584816343708425u64,
-                ]),
-                BigInteger([
-                    6819715632192930960u64,
-                    13775160138580750495u64,
-                    7724744125064328545u64,
-                    18162866296551247079u64,
-                    3167632929424486478u64,
-                    93124135005601392u64,
-                ]),
-                BigInteger([
-                    18046554626407763663u64,
-                    8155122968601546980u64,
-                    2772213695781587586u64,
-                    12129595664938436340u64,
-                    3909506974448925083u64,
-                    21581614494841915u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3725726792428922475u64,
-                    6981898977427574729u64,
-                    12619418860448594723u64,
-                    9623275519229979354u64,
-                    7950288841142004176u64,
-                    34018659278278559u64,
-                ]),
-                BigInteger([
-                    7581676938626124131u64,
-                    12347359793278700959u64,
-                    891898086938166674u64,
-                    5855998816522407242u64,
-                    17325152306791938689u64,
-                    51685658441167091u64,
-                ]),
-                BigInteger([
-                    8190296239695586763u64,
-                    12334413290369556935u64,
-                    929028043047321969u64,
-                    17584163101314929673u64,
-                    7838907322481922930u64,
-                    88792010904851103u64,
-                ]),
-                BigInteger([
-                    9569907864862956352u64,
-                    1094089227800624574u64,
-                    3564175154102111871u64,
-                    16476645080826402960u64,
-                    1834279710186738831u64,
-                    40613874082555673u64,
-                ]),
-                BigInteger([
-                    13286851207881360901u64,
-                    8235210823175615830u64,
-                    12876828269735078598u64,
-                    9285129599313637166u64,
-                    12795343831436847705u64,
-                    95755441654851164u64,
-                ]),
-                BigInteger([
-                    12390724761695664348u64,
-                    13188493368722412536u64,
-                    8378952575749633279u64,
-                    10705680115008082091u64,
-                    6775036063237459808u64,
-                    113378166276465976u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6112513729321476635u64,
-                    6860303834665165766u64,
-                    18127314837954720349u64,
-                    1716404140471083322u64,
-                    8651029567955977969u64,
-                    109346404337977714u64,
-                ]),
-                BigInteger([
-                    3116008148692717064u64,
-                    16067614800415196993u64,
-                    6279367852241507651u64,
-                    12768280236466075052u64,
-                    9810605931803578029u64,
-                    93659748999534321u64,
-                ]),
-                BigInteger([
-                    17480210081981067113u64,
-                    17843579790814556132u64,
-                    10119177118728005172u64,
-                    3748197415410585708u64,
-                    13671443982088942565u64,
-                    19644662283225870u64,
-                ]),
-                BigInteger([
-                    5033938495518626117u64,
-                    4418946862265010234u64,
-                    14167431011043825485u64,
-                    3378897450358948412u64,
-                    13696547923466449997u64,
-                    43385964938283910u64,
-                ]),
-                BigInteger([
-                    15046615726972008484u64,
-                    17046092888174329940u64,
-                    1321606532613222281u64,
-                    10575507325579731203u64,
-                    13312430851913063122u64,
-                    22718538244752234u64,
-                ]),
-                BigInteger([
-                    2697352764755236123u64,
-                    17597607621266343071u64,
-                    10529158793154625472u64,
-                    3457325641417321824u64,
-                    16849369709034830269u64,
-                    80188510163133538u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1523544367104009168u64,
-                    10365017933996707965u64,
-                    7657764081704961460u64,
-                    10850841523752561966u64,
-                    7326762086782551016u64,
-                    83020285552319166u64,
-                ]),
-                BigInteger([
-                    1366158331299111591u64,
-                    17896888005885368536u64,
-                    12313597465452791414u64,
-                    9469119374782176034u64,
-                    7260074594200344547u64,
-                    108139600758962127u64,
-                ]),
-                BigInteger([
-                    2451710707857980602u64,
-                    10914389197633406456u64,
-                    7487510438307708514u64,
-                    10021945499834236779u64,
-                    14196262362779521851u64,
-                    104722882583526794u64,
-                ]),
-                BigInteger([
-                    12369142907581854411u64,
-                    8991791552735340331u64,
-                    1407173882384752578u64,
-                    4285667684968908210u64,
-                    18101026516853288705u64,
-                    2477867571388433u64,
-                ]),
-                BigInteger([
-                    15504375472061779315u64,
-                    5468875520419911076u64,
-                    6092757622631979087u64,
-                    10451517577337476124u64,
-                    3534176409736312826u64,
-                    79302294225243372u64,
-                ]),
-                BigInteger([
-                    9035783147830134929u64,
-                    17609640337746946371u64,
-                    6335670353601723092u64,
-                    12783416434390304951u64,
-                    4714338034689523970u64,
-                    24028234206909152u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7993222795263838946u64,
-                    10414233976072700369u64,
-                    7865871513644509551u64,
-                    8741749172339441334u64,
-                    10522381642612614046u64,
-                    75621485488853645u64,
-                ]),
-                BigInteger([
-                    15905262783747177297u64,
-                    18017843861799730956u64,
-                    4185601388445087531u64,
-                    13178384416681867635u64,
-                    2560170106656594041u64,
-                    90438487634770488u64,
-                ]),
-                BigInteger([
-                    16893502073395942960u64,
-                    2616258500940620174u64,
-                    9729941130841422924u64,
-                    17543546351714442091u64,
-                    878477083701821346u64,
-                    110283760307137519u64,
-                ]),
-                BigInteger([
-                    14359888350037191287u64,
-                    16597217674963655376u64,
-                    168604674607825858u64,
-                    10907768470684579098u64,
-                    5045213501164034128u64,
-                    54581249295836340u64,
-                ]),
-                BigInteger([
-                    6409245242595860074u64,
-                    13226918939469008610u64,
-                    16331121527437667745u64,
-                    3073898017221869456u64,
-                    9183102041999818696u64,
-                    102342200593606096u64,
-                ]),
-                BigInteger([
-                    3327769698177034226u64,
-                    5009193321584165950u64,
-                    12727020200147656152u64,
-                    1815398053628888269u64,
-                    17919922784288686156u64,
-                    87245395684524331u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14218912047134080315u64,
-                    4713734138162620747u64,
-                    12526394575046886758u64,
-                    16838006615099672085u64,
-                    5313361381267711519u64,
-                    54603814362608235u64,
-                ]),
-                BigInteger([
-                    9829910890129139883u64,
-                    12838272446676695333u64,
-                    5089153527593119822u64,
-                    11551488796222820782u64,
-                    11989375870492938952u64,
-                    5453083241620434u64,
-                ]),
-                BigInteger([
-                    6323376584637280310u64,
-                    3197091110820426732u64,
-                    17303233509414818032u64,
-                    9341525200822199719u64,
-                    6912451429895406191u64,
-                    66639221500310386u64,
-                ]),
-                BigInteger([
-                    5549303693652169726u64,
-                    202896949710766796u64,
-                    9396002621008490635u64,
-                    2683463657123848883u64,
-                    3617869462565862039u64,
-                    60100340117949607u64,
-                ]),
-                BigInteger([
-                    4182051925201761694u64,
-                    9812018179085311843u64,
-                    471997996731324537u64,
-                    662473271127149276u64,
-                    1183198671237448014u64,
-                    6756339246369035u64,
-                ]),
-                BigInteger([
-                    16080130294703157917u64,
-                    9974526902829907978u64,
-                    10742035514417028252u64,
-                    5733804055241836784u64,
-                    12507776645905438781u64,
-                    39331012271227541u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1963600759421235397u64,
-                    17112014108196309721u64,
-                    10999111758702742507u64,
-                    3103936418254575237u64,
-                    5458795078443834495u64,
-                    119623804459997464u64,
-                ]),
-                BigInteger([
-                    15035052936691831797u64,
-                    7492531228685370070u64,
-                    3335713082973981013u64,
-                    17315707044012635684u64,
-                    5637779407733712654u64,
-                    51156101616387879u64,
-                ]),
-                BigInteger([
-                    10915654018819751961u64,
-                    7009597384367111565u64,
-                    3485260953527537243u64,
-                    5623131242203202837u64,
-                    13140492796612818035u64,
-                    51508273017201413u64,
-                ]),
-                BigInteger([
-                    8571654666383677055u64,
-                    8193257430045965995u64,
-                    14403470445847412455u64,
-                    18242111787149814282u64,
-                    8895408093001282875u64,
-                    111567571034896283u64,
-                ]),
-                BigInteger([
-                    17332045037978047194u64,
-                    15413245883173996390u64,
-                    11309403874038751978u64,
-                    13143178627228616499u64,
-                    12051714577357075146u64,
-                    61806163139996648u64,
-                ]),
-                BigInteger([
-                    15417254066282204369u64,
-                    15911467427550768402u64,
-                    5410905790085355471u64,
-                    6685707437631823983u64,
-                    238192294017805068u64,
-                    33441400008110975u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8952518963031693795u64,
-                    9798594242698039908u64,
-                    583117372917095586u64,
-                    9854760742493760836u64,
-                    582636686123984603u64,
-                    86993050002926945u64,
-                ]),
-                BigInteger([
-                    9346428342078842762u64,
-                    6399654898755974418u64,
-                    2102972004520231882u64,
-                    5905482475825278804u64,
-                    11257013051665189442u64,
-                    30732125779076533u64,
-                ]),
-                BigInteger([
-                    11574371920677000117u64,
-                    14056899202477049012u64,
-                    12063456531861873944u64,
-                    5923899390982902677u64,
-                    5120858001010320184u64,
-                    100196225738461023u64,
-                ]),
-                BigInteger([
-                    18085166112386227382u64,
-                    4163723017316450845u64,
-                    2214251694302585887u64,
-                    17872968140798990935u64,
-                    870080188932061524u64,
-                    30785630218602549u64,
-                ]),
-                BigInteger([
-                    2108104698678400775u64,
-                    4043290317828453055u64,
-                    13275221113454156264u64,
-                    13949320140513057308u64,
-                    16432158887599217085u64,
-                    53021978065362086u64,
-                ]),
-                BigInteger([
-                    6387114957191205492u64,
-                    2768602691244012297u64,
-                    16179125142747829554u64,
-                    16853870198125097179u64,
-                    18292738978379063315u64,
-                    87120187547153714u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12994876557737363622u64,
-                    356682029851335000u64,
-                    5476315115696667869u64,
-                    12538582589087628274u64,
-                    2634738198466429233u64,
-                    50321135856961287u64,
-                ]),
-                BigInteger([
-                    7033724957648187464u64,
-                    15471512823425287033u64,
-                    9143764104580729683u64,
-                    1066130565479470744u64,
-                    17258326568399428163u64,
-                    119388027256247590u64,
-                ]),
-                BigInteger([
-                    16636472917976462791u64,
-                    8710917340693642270u64,
-                    3645687140143991622u64,
-                    10280609316656424230u64,
-                    8268994525673843636u64,
-                    74011540501851924u64,
-                ]),
-                BigInteger([
-                    12988675133637658422u64,
-                    2082583884881359097u64,
-                    3577976581174047597u64,
-                    10800736497411327215u64,
-                    12937755770585875933u64,
-                    20304958275614203u64,
-                ]),
-                BigInteger([
-                    14648372117257075236u64,
-                    13279418352553167437u64,
-                    13240852566106593698u64,
-                    11943665703960570960u64,
-                    17401961801036848276u64,
-                    94328781683982000u64,
-                ]),
-                BigInteger([
-                    2821756878708494381u64,
-                    1324952352382194571u64,
-                    8379663150386552062u64,
-                    11788744207843676781u64,
-                    7257785336837249802u64,
-                    90715174887748185u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8936355644098356248u64,
-                    3740298916447440863u64,
-                    14296654583760204680u64,
-                    12985079152185373665u64,
-                    300315077026672703u64,
-                    6705138981322028u64,
-                ]),
-                BigInteger([
-                    5500188072280394544u64,
-                    10376797791852444131u64,
-                    17644858426026785043u64,
-                    17748607403901988835u64,
-                    967130241020188674u64,
-                    89871977709087177u64,
-                ]),
-                BigInteger([
-                    10666428787184025725u64,
-                    14915610578797689162u64,
-                    10276366167603649497u64,
-                    9576751289251990197u64,
-                    5349353291980409496u64,
-                    84930920323486627u64,
-                ]),
-                BigInteger([
-                    13926539781794885632u64,
-                    4218474716760107067u64,
-                    9769390845581370992u64,
-                    7008369315283679615u64,
-                    14196351442854966134u64,
-                    33018176690362631u64,
-                ]),
-                BigInteger([
-                    10408865872302178333u64,
-                    6005155820196560541u64,
-                    14158362628703993208u64,
-                    11876405686460896239u64,
-                    9041988562094039408u64,
-                    33545217515233966u64,
-                ]),
-                BigInteger([
-                    11524687765311226909u64,
-                    5494603110737693932u64,
-                    11842572695753805505u64,
-                    12520853058301551082u64,
-                    5953340793135886813u64,
-                    95886591356288508u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6566950245399223620u64,
-                    6828917838014623512u64,
-                    12219736492637193322u64,
-                    9060241427663241340u64,
-                    18326972187699683552u64,
-                    36024484547697001u64,
-                ]),
-                BigInteger([
-                    856760960606252770u64,
-                    18206355519218171898u64,
-                    14931549154361610992u64,
-                    17888890862229145133u64,
-                    7236281748797827725u64,
-                    93672889946634755u64,
-                ]),
-                BigInteger([
-                    11568890617413162020u64,
-                    2256390766386226788u64,
-                    14052545515265929410u64,
-                    4027042504734786466u64,
-                    1952073310503160039u64,
-                    47960630022845778u64,
-                ]),
-                BigInteger([
-                    6131450101378153977u64,
-                    14098148399425021600u64,
-                    17507019373348436478u64,
-                    9614344276458159693u64,
-                    1531487179282136501u64,
-                    89295717198192549u64,
-                ]),
-                BigInteger([
-                    1299273862080256536u64,
-                    15283003532267100421u64,
-                    17459245926625197963u64,
-                    7965347028258619322u64,
-                    9312579815060783548u64,
-                    67823700200398797u64,
-                ]),
-                BigInteger([
-                    9255591713499053335u64,
-                    12602836563999584297u64,
-                    8225619778794316399u64,
-                    2293657922412156489u64,
-                    12295064468125401834u64,
-                    71060785075514305u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2534809925828960248u64,
-                    5620448182159548294u64,
-                    175661323755622815u64,
-                    1976518933659031626u64,
-                    3978275976905859114u64,
-                    82942205823033440u64,
-                ]),
-                BigInteger([
-                    6654267142938639575u64,
-                    1012556974713504571u64,
-                    5007686262846659991u64,
-                    12459403020477256402u64,
-                    16271868569378143102u64,
-                    70232995036805105u64,
-                ]),
-                BigInteger([
-                    15326105070031791123u64,
-                    9247778280688382146u64,
-                    9661206225001862471u64,
-                    14541311189586476586u64,
-                    11251672485357906927u64,
-                    24013088820507576u64,
-                ]),
-                BigInteger([
-                    3353396037405676729u64,
-                    13383219799992961497u64,
-                    16603781751178745302u64,
-                    4111844922428891338u64,
-                    12471808206770260621u64,
-                    43709121978601310u64,
-                ]),
-                BigInteger([
-                    10680856904376270600u64,
-                    12743331476214764648u64,
-                    15370046747991523708u64,
-                    17810167620137334933u64,
-                    14425271940900662501u64,
-                    60529487558356987u64,
-                ]),
-                BigInteger([
-                    6414701617977400394u64,
-                    11507242471987383035u64,
-                    1367472601414108799u64,
-                    16931433068743219376u64,
-                    9220780948030277904u64,
-                    100680179953984705u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10817494208657202175u64,
-                    16160623195184453299u64,
-                    14880222193740766285u64,
-                    9902710997765117083u64,
-                    16030463705638732182u64,
-                    35532772709253516u64,
-                ]),
-                BigInteger([
-                    18364738383729606612u64,
-                    17293165464548504706u64,
-                    12938167937142634488u64,
-                    3110847807271728483u64,
-                    4311495611401724437u64,
-                    83700348902275819u64,
-                ]),
-                BigInteger([
-                    5608101071235709055u64,
-                    7287692012874214673u64,
-                    1398797656521207518u64,
-                    13843992419616751248u64,
-                    11255538669914729350u64,
-                    114756107726877141u64,
-                ]),
-                BigInteger([
-                    18154281776444313780u64,
-                    290801560081041437u64,
-                    11587183919616917900u64,
-                    16507326648707437686u64,
-                    8717742941708157927u64,
-                    121071627325261157u64,
-                ]),
-                BigInteger([
-                    16540283838257843920u64,
-                    1047043358712680448u64,
-                    13119503097256855323u64,
-                    18192631817979550183u64,
-                    8324772177105536548u64,
-                    77768283419824821u64,
-                ]),
-                BigInteger([
-                    9124146504210331520u64,
-                    16452656389878850226u64,
-                    17098931231643904548u64,
-                    14928765233772954975u64,
-                    5791747360821976000u64,
-                    40838916235822192u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4307658565779682464u64,
-                    6736852448978189659u64,
-                    3009519996027798575u64,
-                    13199832731472365128u64,
-                    1378010348429471987u64,
-                    80344443265664964u64,
-                ]),
-                BigInteger([
-                    1815540073136719277u64,
-                    6669447801911448229u64,
-                    45124275063910321u64,
-                    10181430515282316485u64,
-                    16437348061929587606u64,
-                    119417086098251861u64,
-                ]),
-                BigInteger([
-                    8660454161113204794u64,
-                    4088369797727877451u64,
-                    15775929974365086705u64,
-                    17314816412447936558u64,
-                    16243084056475635424u64,
-                    35674830933632667u64,
-                ]),
-                BigInteger([
-                    11566320211891646332u64,
-                    5665980886988873600u64,
-                    686470716079999375u64,
-                    600806805520801241u64,
-                    18360410501521089041u64,
-                    42280930754956335u64,
-                ]),
-                BigInteger([
-                    7471112284229840210u64,
-                    13820801231104326376u64,
-                    9834328588352127215u64,
-                    1626002465075353673u64,
-                    17303271931317388251u64,
-                    31933976527153650u64,
-                ]),
-                BigInteger([
-                    12448078414661638144u64,
-                    6477686645305820190u64,
-                    407161622551886507u64,
-                    18204448761505008768u64,
-                    4152468628421205081u64,
-                    56053670426623455u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15200118869313087158u64,
-                    16017977123686626007u64,
-                    13096864355765967698u64,
-                    17751569290586119079u64,
-                    17386129148930072866u64,
-                    87784819763895499u64,
-                ]),
-                BigInteger([
-                    17263507236835326604u64,
-                    3604631949752010490u64,
-                    11164013533688631029u64,
-                    15227867025588514593u64,
-                    926105912243644599u64,
-                    21180438375330098u64,
-                ]),
-                BigInteger([
-                    17029669052811742603u64,
-                    6073688294321610510u64,
-                    2889329587863379880u64,
-                    6827051625467256676u64,
-                    1310368698072327790u64,
-                    10615643172546206u64,
-                ]),
-                BigInteger([
-                    17624351562678556587u64,
-                    8144046717663511528u64,
-                    6693347007410135294u64,
-                    17811792082515778637u64,
-                    119087342038484417u64,
-                    71601303891896695u64,
-                ]),
-                BigInteger([
-                    6297673031738503969u64,
-                    2810981296065171210u64,
-                    7626180151126758772u64,
-                    8823544483029949851u64,
-                    17978929609655247949u64,
-                    51828911701098452u64,
-                ]),
-                BigInteger([
-                    8417515884328659491u64,
-                    17998077959890804204u64,
-                    10485828315369685186u64,
-                    8330366717852476377u64,
-                    6306385628381333636u64,
-                    84462654719931186u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3386393474085839704u64,
-                    4714901286443515924u64,
-                    8538381186127834866u64,
-                    2167927801150347933u64,
-                    6367068629016026022u64,
-                    110067044940431820u64,
-                ]),
-                BigInteger([
-                    4163945676835827146u64,
-                    14203337993615052908u64,
-                    1289661968687050972u64,
-                    7574281719418464764u64,
-                    15140132764473643618u64,
-                    8471452408953271u64,
-                ]),
-                BigInteger([
-                    17053836175626243388u64,
-                    11431691332443825159u64,
-                    13760877172281200978u64,
-                    6137348431156177820u64,
-                    2833296145812852100u64,
-                    30758899120805408u64,
-                ]),
-                BigInteger([
-                    14580022019632193567u64,
-                    1765966081314336855u64,
-                    11660785380041904815u64,
-                    8804231075518203083u64,
-                    16619441856809469987u64,
-                    25662927008263602u64,
-                ]),
-                BigInteger([
-                    13532748668433825210u64,
-                    2613923005366143654u64,
-                    8713251954611479430u64,
-                    6282306701512200566u64,
-                    13324453186103980603u64,
-                    78283308040535944u64,
-                ]),
-                BigInteger([
-                    2561558094546481150u64,
-                    4149007511016613998u64,
-                    3312574153597542589u64,
-                    11602748881170476978u64,
-                    5542990353871764983u64,
-                    32091184377760226u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1796734168164852329u64,
-                    10749729675865393177u64,
-                    1162887624247044375u64,
-                    16993227576216872521u64,
-                    14024860417596578184u64,
-                    2707256401776326u64,
-                ]),
-                BigInteger([
-                    16167998370005394173u64,
-                    16368247876256031138u64,
-                    7684402863186713702u64,
-                    15973935174702169712u64,
-                    2059475902705574472u64,
-                    22478546590488281u64,
-                ]),
-                BigInteger([
-                    11830566035098208669u64,
-                    14044622053560304457u64,
-                    18328645366552765319u64,
-                    5188116078118736324u64,
-                    12485308054829883772u64,
-                    69498674982608367u64,
-                ]),
-                BigInteger([
-                    4607052356837570608u64,
-                    1838845771944160431u64,
-                    10135592737743174125u64,
-                    11240192591526141330u64,
-                    11891778448983302331u64,
-                    45297577862430605u64,
-                ]),
-                BigInteger([
-                    15343304054708729360u64,
-                    14156559513181195658u64,
-                    14622678052687709527u64,
-                    12643229995029116110u64,
-                    17065253857945664558u64,
-                    74934851187670180u64,
-                ]),
-                BigInteger([
-                    3707355707731235211u64,
-                    2791737538150277149u64,
-                    17362937022487269512u64,
-                    12787119427474938414u64,
-                    5233367938578846307u64,
-                    73110376800754714u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2603085378258759142u64,
-                    7167328147333109165u64,
-                    16085860002118762727u64,
-                    1209112570058146098u64,
-                    1875887239138620228u64,
-                    67488058978580451u64,
-                ]),
-                BigInteger([
-                    15701828006441712948u64,
-                    12159179291052872995u64,
-                    3973200714512895938u64,
-                    9338572478335202064u64,
-                    9738673186593975640u64,
-                    111066927062795644u64,
-                ]),
-                BigInteger([
-                    13557320884171789635u64,
-                    3799932597073074888u64,
-                    6670897396968743239u64,
-                    12824933999073888363u64,
-                    5582243007793363963u64,
-                    44031998486344707u64,
-                ]),
-                BigInteger([
-                    13405021229076644703u64,
-                    16486299564878830773u64,
-                    8940127544685202479u64,
-                    17324184407787739574u64,
-                    498128113085555193u64,
-                    63050443569149599u64,
-                ]),
-                BigInteger([
-                    1882831424616103387u64,
-                    1843208333147755478u64,
-                    15728304613934923330u64,
-                    5162949480886865091u64,
-                    5637181114143158869u64,
-                    70535031246438028u64,
-                ]),
-                BigInteger([
-                    6416666781220487372u64,
-                    5899625549606632893u64,
-                    1937862860281641984u64,
-                    15149221385445272257u64,
-                    16293029171655412313u64,
-                    47048736890257859u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10368188507204013418u64,
-                    17510806858108376247u64,
-                    5295523941356846956u64,
-                    1536271725359997279u64,
-                    2304254069140225039u64,
-                    36258955857759670u64,
-                ]),
-                BigInteger([
-                    14711543721197717636u64,
-                    1779981187378773273u64,
-                    5760479073281739133u64,
-                    15852563484554818970u64,
-                    4094675982255333726u64,
-                    42918740974691155u64,
-                ]),
-                BigInteger([
-                    5701880913742441477u64,
-                    16931571641806917834u64,
-                    14633778290799820111u64,
-                    3274212918950418865u64,
-                    16397311339923626127u64,
-                    57139239371693972u64,
-                ]),
-                BigInteger([
-                    1467125693116668422u64,
-                    4232898296418704243u64,
-                    402026457027466035u64,
-                    6731930830168329211u64,
-                    11362615025995224616u64,
-                    18313597719870499u64,
-                ]),
-                BigInteger([
-                    4780918052602737098u64,
-                    5680053266611814467u64,
-                    6876149753046050752u64,
-                    7530272969025396573u64,
-                    11795883562534779971u64,
-                    101376702545494072u64,
-                ]),
-                BigInteger([
-                    15872593191930155943u64,
-                    2645165601024217911u64,
-                    9985895084074311009u64,
-                    15802086897522253415u64,
-                    2603978665667702273u64,
-                    86281402840774083u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11501734717346115375u64,
-                    11889847493863413503u64,
-                    9342349800476435427u64,
-                    9710692708677526644u64,
-                    15141619069234988160u64,
-                    112767117928183468u64,
-                ]),
-                BigInteger([
-                    5994288274864442832u64,
-                    3530303158090491634u64,
-                    12734605719591612854u64,
-                    3740396731556984452u64,
-                    856593294048003495u64,
-                    83607486470476552u64,
-                ]),
-                BigInteger([
-                    14821964444795146672u64,
-                    11422988866156590413u64,
-                    14929308594460292738u64,
-                    11498020245993850748u64,
-                    18344600601806678006u64,
-                    117411285430106103u64,
-                ]),
-                BigInteger([
-                    12924809401701071583u64,
-                    505942411698025227u64,
-                    14242062202927447268u64,
-                    11102367423902546861u64,
-                    14193511298404768103u64,
-                    53364552625157793u64,
-                ]),
-                BigInteger([
-                    6378593957743894450u64,
-                    11023211135941406362u64,
-                    5654626486082322055u64,
-                    4932136548651515857u64,
-                    15261430635650538939u64,
-                    91015244768461960u64,
-                ]),
-                BigInteger([
-                    10670451350671165943u64,
-                    7700513567357211756u64,
-                    12211623968660579598u64,
-                    3033211589056694845u64,
-                    12315117913257548426u64,
-                    36109841723749620u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    68092890173564279u64,
-                    16026923207449745928u64,
-                    14395686762627605744u64,
-                    678663231137368495u64,
-                    10487837556811730292u64,
-                    92958700116144812u64,
-                ]),
-                BigInteger([
-                    5571141266020151410u64,
-                    5411965893794995586u64,
-                    2938190449489208070u64,
-                    8553506546612193530u64,
-                    3195276056128025499u64,
-                    69538952532828879u64,
-                ]),
-                BigInteger([
-                    8529064608773541300u64,
-                    13040210218327989867u64,
-                    7343698898024089890u64,
-                    2761730494215993446u64,
-                    14820738564497641857u64,
-                    14783629712720029u64,
-                ]),
-                BigInteger([
-                    8927454140228460646u64,
-                    18301634072226050657u64,
-                    9861035396195500434u64,
-                    6039679155003626425u64,
-                    3472135508974930095u64,
-                    39316883392959850u64,
-                ]),
-                BigInteger([
-                    13512629097267249824u64,
-                    16279951634214659861u64,
-                    9286731795461279083u64,
-                    12340421382021695361u64,
-                    6653833710830010471u64,
-                    86664634983024943u64,
-                ]),
-                BigInteger([
-                    3217575982216240252u64,
-                    3369060700433618828u64,
-                    2142861647277055623u64,
-                    14490676880153270079u64,
-                    11519479342913876589u64,
-                    55276112182670359u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4546822974117107740u64,
-                    13513400211538647648u64,
-                    8941804149482646367u64,
-                    10976707170503647736u64,
-                    14367360843491345356u64,
-                    101542030826084457u64,
-                ]),
-                BigInteger([
-                    14850225270695070240u64,
-                    14579890193542282468u64,
-                    2506336044722183392u64,
-                    6968615815389676983u64,
-                    10403346346546579967u64,
-                    68509484004746600u64,
-                ]),
-                BigInteger([
-                    39871213025886086u64,
-                    1652886059990149084u64,
-                    9034974333629136131u64,
-                    6256858286294533016u64,
-                    12457973779870194837u64,
-                    21502258571334972u64,
-                ]),
-                BigInteger([
-                    8039485892713048014u64,
-                    16258731818599299314u64,
-                    13556562261080986601u64,
-                    5586693344652887704u64,
-                    2193945710455808242u64,
-                    25040048056699714u64,
-                ]),
-                BigInteger([
-                    11827606568156294598u64,
-                    18339263924192817973u64,
-                    16746871993843140344u64,
-                    1001490411064403578u64,
-                    11296760446225372082u64,
-                    102353154165004085u64,
-                ]),
-                BigInteger([
-                    12823703034776590230u64,
-                    2995489793061214019u64,
-                    3802340300710066522u64,
-                    4539618394833221834u64,
-                    10098220919907855484u64,
-                    82529617172807618u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    583253018568278630u64,
-                    4737923706655022481u64,
-                    2002098998049522521u64,
-                    4393857861430329547u64,
-                    12389897136010556402u64,
-                    2964340309680331u64,
-                ]),
-                BigInteger([
-                    13350917155522371022u64,
-                    14566976570296007871u64,
-                    2424327711266989275u64,
-                    17201857946560260858u64,
-                    17709625293443614817u64,
-                    27006879470699199u64,
-                ]),
-                BigInteger([
-                    11734896896645933179u64,
-                    14569031411526899048u64,
-                    17768679275669192322u64,
-                    12556827331056598740u64,
-                    13368107754729863567u64,
-                    93803435867362755u64,
-                ]),
-                BigInteger([
-                    16189128954889588816u64,
-                    1047461549775882044u64,
-                    4116447351567679160u64,
-                    6041836763114550674u64,
-                    6126656195481887211u64,
-                    90943644910393110u64,
-                ]),
-                BigInteger([
-                    16765175964548321534u64,
-                    307391114437544675u64,
-                    1577046242609306794u64,
-                    3784560466413684625u64,
-                    6135974936568540947u64,
-                    45292345594742332u64,
-                ]),
-                BigInteger([
-                    15033870756414872517u64,
-                    8348186138252513959u64,
-                    13449873160193198478u64,
-                    13557512683545999073u64,
-                    4870549841193401083u64,
-                    33142724426144381u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15624368562570252197u64,
-                    621044723362162616u64,
-                    14620078070812973202u64,
-                    11523815980130626038u64,
-                    3051481647972035658u64,
-                    49499016576859242u64,
-                ]),
-                BigInteger([
-                    15628815827961994720u64,
-                    7384699289250441123u64,
-                    10892798246454533479u64,
-                    1946547285425594385u64,
-                    15765100244994350908u64,
-                    118609449905102478u64,
-                ]),
-                BigInteger([
-                    1429062306414262989u64,
-                    17228361715769153576u64,
-                    2471241897208353512u64,
-                    10953259883138297953u64,
-                    15463442528649158152u64,
-                    32789694131874537u64,
-                ]),
-                BigInteger([
-                    10904401468452890491u64,
-                    10959419553967977345u64,
-                    5130453566767610554u64,
-                    9001382536879559745u64,
-                    3641573792887387838u64,
-                    65102453204845533u64,
-                ]),
-                BigInteger([
-                    18414004382557830983u64,
-                    17295787499036620554u64,
-                    13831189149054628968u64,
-                    8347268717700084736u64,
-                    5504286358949470797u64,
-                    14880837341820194u64,
-                ]),
-                BigInteger([
-                    5159460926982713649u64,
-                    9929970137160331776u64,
-                    10372883466997031407u64,
-                    5109446624330655053u64,
-                    1392841829983143537u64,
-                    63716158474621660u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17146981176416043553u64,
-                    9070478094621269017u64,
-                    351094499544435582u64,
-                    15580690337739957221u64,
-                    17450667150910351649u64,
-                    88644292900555601u64,
-                ]),
-                BigInteger([
-                    6541366093192291170u64,
-                    10223081203844940871u64,
-                    15087945495668478436u64,
-                    17485544531987576256u64,
-                    13147967386007929135u64,
-                    7143571901392053u64,
-                ]),
-                BigInteger([
-                    4607589323351050225u64,
-                    2941033994466909752u64,
-                    13365920302220738589u64,
-                    16126817518864817175u64,
-                    4029432947007797621u64,
-                    69652366486140983u64,
-                ]),
-                BigInteger([
-                    17617558264446536473u64,
-                    14067237206122882648u64,
-                    10675455571759013009u64,
-                    2529692953852756117u64,
-                    6724106158379039278u64,
-                    114310288362880270u64,
-                ]),
-                BigInteger([
-                    3884269389813965025u64,
-                    14636284630210859242u64,
-                    14476322860470282495u64,
-                    9948356086107842528u64,
-                    1742773453156099247u64,
-                    26904774447815450u64,
-                ]),
-                BigInteger([
-                    5524208782297564696u64,
-                    12346862596084628061u64,
-                    9101935451507464833u64,
-                    14994541823871959419u64,
-                    4966220984456224426u64,
-                    110629361732776772u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10874869836144237940u64,
-                    2515678285959734166u64,
-                    11863623950448179553u64,
-                    2710584014466129302u64,
-                    9847889280650070576u64,
-                    104775943495605751u64,
-                ]),
-                BigInteger([
-                    3168552340480158660u64,
-                    6420921115857883506u64,
-                    5074646169068442719u64,
-                    9469409844229009906u64,
-                    14493525036094980265u64,
-                    90780576566479611u64,
-                ]),
-                BigInteger([
-                    3828895641520684757u64,
-                    17148806676949393984u64,
-                    17635242970098160996u64,
-                    17031568935446017569u64,
-                    7482253575484139448u64,
-                    110743117190946769u64,
-                ]),
-                BigInteger([
-                    2497368779019392532u64,
-                    12971770424755241532u64,
-                    10813630361291566766u64,
-                    506589421626737813u64,
-                    5654613690791058272u64,
-                    89386590610681338u64,
-                ]),
-                BigInteger([
-                    6980528385401024474u64,
-                    17119811620099776224u64,
-                    13130134743628043773u64,
-                    18304201704576825537u64,
-                    5635366937587757536u64,
-                    31940037179684838u64,
-                ]),
-                BigInteger([
-                    6541324380748687833u64,
-                    167565550693117569u64,
-                    16535442095566026348u64,
-                    17217590434600888538u64,
-                    13131864859819116840u64,
-                    7931059934325992u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7781449934490538171u64,
-                    342104690404402460u64,
-                    16403315736794330891u64,
-                    8212243891999899751u64,
-                    2065459935335273405u64,
-                    82769415854564683u64,
-                ]),
-                BigInteger([
-                    14529333529133789070u64,
-                    7511038486495936144u64,
-                    12308328077844324378u64,
-                    17131331965070319017u64,
-                    3113159843389518322u64,
-                    65527301709016638u64,
-                ]),
-                BigInteger([
-                    3380020946976175824u64,
-                    17033652501461612708u64,
-                    10031992351841964152u64,
-                    9307774213576752042u64,
-                    13345260110196768905u64,
-                    36278540965935711u64,
-                ]),
-                BigInteger([
-                    15063892357735191742u64,
-                    8785743884473948300u64,
-                    6604570235958471105u64,
-                    9971745391140413876u64,
-                    17516904189024927310u64,
-                    85146819512829334u64,
-                ]),
-                BigInteger([
-                    3445543898231515317u64,
-                    14992672266532232639u64,
-                    1324532343485004186u64,
-                    17632858305294742594u64,
-                    11960927232592207495u64,
-                    4994693292453011u64,
-                ]),
-                BigInteger([
-                    1661744796748605638u64,
-                    13271489418846547575u64,
-                    6847451709500640227u64,
-                    5344898529391316024u64,
-                    3966422157980598331u64,
-                    91613304507520235u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    272329965145577888u64,
-                    5730166904670053149u64,
-                    3610458561563218538u64,
-                    3716129716085413227u64,
-                    4747613610965376876u64,
-                    62373792903098655u64,
-                ]),
-                BigInteger([
-                    7443505215973705979u64,
-                    7010162690429635471u64,
-                    11764223373844625891u64,
-                    2031135285792909934u64,
-                    11061793776740698380u64,
-                    20644001696838933u64,
-                ]),
-                BigInteger([
-                    8247279992765376183u64,
-                    13718200451162994610u64,
-                    8972750889368362609u64,
-                    10356482502901356650u64,
-                    17222823815781872240u64,
-                    15156187318554701u64,
-                ]),
-                BigInteger([
-                    16127324093810217794u64,
-                    6048245722493541741u64,
-                    17460008486660079627u64,
-                    12784889917339509329u64,
-                    16146613065554397394u64,
-                    63304294101765121u64,
-                ]),
-                BigInteger([
-                    1110366181557468483u64,
-                    992888800452406606u64,
-                    7505389038111195534u64,
-                    18234149718949225088u64,
-                    2694267610088134382u64,
-                    26686584732728331u64,
-                ]),
-                BigInteger([
-                    6647773559227503328u64,
-                    10850880562253281276u64,
-                    10509904930334144781u64,
-                    5067764246493642829u64,
-                    12774714275585612674u64,
-                    53950623285072501u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13856535501681790921u64,
-                    13241875676315554020u64,
-                    15344046569689630777u64,
-                    11936871052982456584u64,
-                    6652156494242610625u64,
-                    76657100785952849u64,
-                ]),
-                BigInteger([
-                    4613814491761595862u64,
-                    6064805441304464494u64,
-                    4533591122084044356u64,
-                    4926561987199165526u64,
-                    3275199081759855439u64,
-                    56728141377905359u64,
-                ]),
-                BigInteger([
-                    16065489980194133868u64,
-                    2871803311658744637u64,
-                    389164347239568384u64,
-                    9814419294927231488u64,
-                    14043886918418287799u64,
-                    80823261220641499u64,
-                ]),
-                BigInteger([
-                    7914575584875052834u64,
-                    11467558986092738798u64,
-                    6397834855067439045u64,
-                    16712604776173522077u64,
-                    11369319014548563153u64,
-                    73279814789121328u64,
-                ]),
-                BigInteger([
-                    12695393623862235705u64,
-                    15970647944194613007u64,
-                    13099890665923846007u64,
-                    9374236706247429332u64,
-                    4858828123721442895u64,
-                    92978284442891229u64,
-                ]),
-                BigInteger([
-                    3599208562214141378u64,
-                    11526281601619347129u64,
-                    15632125649533047921u64,
-                    8215988356818264104u64,
-                    7123173920786840043u64,
-                    4870687120434256u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1549306708413846642u64,
-                    18115227948773642191u64,
-                    766072222829659829u64,
-                    4892823629873765181u64,
-                    13668228234735019194u64,
-                    13273565862007470u64,
-                ]),
-                BigInteger([
-                    11426567069748357438u64,
-                    17448540271725603810u64,
-                    2023220076468307082u64,
-                    4115847478533424828u64,
-                    7214618568656874792u64,
-                    80822564605908180u64,
-                ]),
-                BigInteger([
-                    6422774108546444801u64,
-                    12023531640420310061u64,
-                    5639754257889705630u64,
-                    5322515858422247098u64,
-                    18102177329700267506u64,
-                    29061980830982006u64,
-                ]),
-                BigInteger([
-                    17801755699785726806u64,
-                    6957147372414083392u64,
-                    10096322235949868766u64,
-                    18086470402040011565u64,
-                    17498337780603789740u64,
-                    67896701957646723u64,
-                ]),
-                BigInteger([
-                    2716388941372211824u64,
-                    16954918753888329919u64,
-                    14180285137403813171u64,
-                    2167073684539714374u64,
-                    15507967267942029346u64,
-                    65918407713986976u64,
-                ]),
-                BigInteger([
-                    11234807685236649233u64,
-                    1386690230529838576u64,
-                    3584485233878033519u64,
-                    13043130267293018221u64,
-                    484906852910583346u64,
-                    30940401272139617u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5263196893194608055u64,
-                    1908037253818300914u64,
-                    9815068217976175390u64,
-                    15822381039541185002u64,
-                    18444802613244299028u64,
-                    89378973793681860u64,
-                ]),
-                BigInteger([
-                    8883572201492763763u64,
-                    11136163275721199266u64,
-                    8905421656372836445u64,
-                    17607721055510499950u64,
-                    4141395199956513157u64,
-                    74644967238839303u64,
-                ]),
-                BigInteger([
-                    10782773696476339943u64,
-                    3483691854875258084u64,
-                    16061859295665966709u64,
-                    1126158704589084832u64,
-                    16277798236231966065u64,
-                    58011623505198391u64,
-                ]),
-                BigInteger([
-                    15501074649453827694u64,
-                    10960692440317809563u64,
-                    5432998116490224101u64,
-                    18087630004405313980u64,
-                    8957107635195470266u64,
-                    64012100327045222u64,
-                ]),
-                BigInteger([
-                    10869766085187696922u64,
-                    13874245041208001971u64,
-                    13438516289236347674u64,
-                    1001573103782722881u64,
-                    6807890656947782010u64,
-                    117951620051436588u64,
-                ]),
-                BigInteger([
-                    816062381607240770u64,
-                    3704798491584739232u64,
-                    4483032886431664258u64,
-                    9007915794622605664u64,
-                    10922140379749022662u64,
-                    116001487893605454u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8239892234006745527u64,
-                    657089939085656683u64,
-                    11894323740366996657u64,
-                    5062829531949463458u64,
-                    11990913637478980271u64,
-                    119799775027480249u64,
-                ]),
-                BigInteger([
-                    1926717786078204454u64,
-                    3822874927370363737u64,
-                    2287292331831892871u64,
-                    16571543828496843752u64,
-                    7255757019115067996u64,
-                    108173375063303390u64,
-                ]),
-                BigInteger([
-                    16849091394923236694u64,
-                    15669861106612299739u64,
-                    4078193394421007971u64,
-                    2889544501392015378u64,
-                    15252994214555582984u64,
-                    90111782112223668u64,
-                ]),
-                BigInteger([
-                    8368563842261611008u64,
-                    4506985953256800647u64,
-                    13357617746384001500u64,
-                    7193200349481060301u64,
-                    11359543786951880314u64,
-                    63581324310330316u64,
-                ]),
-                BigInteger([
-                    9883765966548256695u64,
-                    17256326799088444097u64,
-                    12596895544749513044u64,
-                    15505680540288399389u64,
-                    5625269110024705645u64,
-                    98704410891203513u64,
-                ]),
-                BigInteger([
-                    2923302641086318570u64,
-                    18171076313814746844u64,
-                    16397832999834858640u64,
-                    9261235748995599580u64,
-                    4939605148420451234u64,
-                    84368578311947265u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1381546723071368065u64,
-                    18166371469927420872u64,
-                    15220721498658649680u64,
-                    11137173774606499397u64,
-                    13963258948257720510u64,
-                    70262472226180358u64,
-                ]),
-                BigInteger([
-                    962542550891798103u64,
-                    2578203401625584273u64,
-                    17435235678095612273u64,
-                    13056546907662761040u64,
-                    15545096784296530785u64,
-                    59807802890659647u64,
-                ]),
-                BigInteger([
-                    16609262269739848689u64,
-                    17741936753937357146u64,
-                    15264615906499214908u64,
-                    11176735033682651871u64,
-                    4982922591119933080u64,
-                    110900502361911797u64,
-                ]),
-                BigInteger([
-                    2879119644370603584u64,
-                    6981907987779928218u64,
-                    2077370506529369295u64,
-                    9674334079451179667u64,
-                    2695877494219426006u64,
-                    80670584974243945u64,
-                ]),
-                BigInteger([
-                    12933125863064087029u64,
-                    1384383381916536455u64,
-                    4855104661731188614u64,
-                    4086312491381983835u64,
-                    12901482305859192418u64,
-                    5035997124662705u64,
-                ]),
-                BigInteger([
-                    18093033135420437357u64,
-                    6369869982440254438u64,
-                    9022783388619283309u64,
-                    151381660188103199u64,
-                    16030685701868471962u64,
-                    38672428107288994u64,
-                ]),
-            ],
-        ];
-        let mut mds = Vec::<Vec<Self>>::new();
-        for row in MDS_V_MONT.iter() {
-            mds.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        mds
-    }
-
-    fn get_mds_w_hat_consts() -> Vec<Vec<Self>> {
-        const MDS_W_HAT_MONT: [[BigInteger; 6]; 57] = [
-            [
-                BigInteger([
-                    11796052698997416424u64,
-                    9860946373685828009u64,
-                    16286653163646986895u64,
-                    12529029254046593330u64,
-                    4567477776424009356u64,
-                    74844862150353617u64,
-                ]),
-                BigInteger([
-                    10824979246295732685u64,
-                    13234219962527202404u64,
-                    17536372915825262885u64,
-                    10381414004728605437u64,
-                    2792829455933398920u64,
-                    111297885698890442u64,
-                ]),
-                BigInteger([
-                    1588240623852931863u64,
-                    14823299345965587875u64,
-                    1759841480355684830u64,
-                    14791248698981413862u64,
-                    11421226800467824252u64,
-                    57210302862390860u64,
-                ]),
-                BigInteger([
-                    13101072067092388017u64,
-                    4152265509426526266u64,
-                    2318816063140599582u64,
-                    349623474459707753u64,
-                    5988552465355842830u64,
-                    36115753211884322u64,
-                ]),
-                BigInteger([
-                    7206498033390334164u64,
-                    11942534173731858954u64,
-                    4954194047966541687u64,
-                    6019101813915356509u64,
-                    9443251572554449698u64,
-                    70500239204480061u64,
-                ]),
-                BigInteger([
-                    6932495263771665820u64,
-                    3071801995419186952u64,
-                    9464767864548240544u64,
-                    6659571555985023135u64,
-                    17331029891790272766u64,
-                    88761547258892497u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17451469143233308786u64,
-                    14546886687047093606u64,
-                    17671362695713995997u64,
-                    15064326209420364286u64,
-                    10818314438877500671u64,
-                    69197708408221562u64,
-                ]),
-                BigInteger([
-                    10614331000682032193u64,
-                    6626796267517519478u64,
-                    16656601505366934425u64,
-                    9569267596995650900u64,
-                    15461775585590839599u64,
-                    8659608506143374u64,
-                ]),
-                BigInteger([
-                    9272932549259308288u64,
-                    13105160139426691000u64,
-                    7017740921053623282u64,
-                    17867449396931918060u64,
-                    11847810288303191191u64,
-                    86426941557994207u64,
-                ]),
-                BigInteger([
-                    2022254667505910743u64,
-                    14883557238942846654u64,
-                    1917330176378168020u64,
-                    3720261366224575433u64,
-                    13051735810706420046u64,
-                    115392561259727788u64,
-                ]),
-                BigInteger([
-                    9809299320623521280u64,
-                    14467721221001818548u64,
-                    165627729359325903u64,
-                    4156285692828289903u64,
-                    4112012047667259445u64,
-                    95303601854583923u64,
-                ]),
-                BigInteger([
-                    17003290799729469326u64,
-                    11209795229910252029u64,
-                    6817900251798830013u64,
-                    16261817778311844850u64,
-                    16618857836242348638u64,
-                    13127195029151292u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12759104539300829213u64,
-                    7844151832305942440u64,
-                    12227887198990393103u64,
-                    3944626970144337764u64,
-                    11376515961900758150u64,
-                    6915720598631678u64,
-                ]),
-                BigInteger([
-                    14025074152927024468u64,
-                    3220762290093429699u64,
-                    9523795855616197855u64,
-                    8346461363785658241u64,
-                    13865125928421769440u64,
-                    97633647387642826u64,
-                ]),
-                BigInteger([
-                    12448658790280057101u64,
-                    10131173386363741094u64,
-                    1799953574870153300u64,
-                    2315606826988936840u64,
-                    7578805507165870017u64,
-                    98289823945994675u64,
-                ]),
-                BigInteger([
-                    8118645781932243884u64,
-                    17083022042233483138u64,
-                    11258572914854246590u64,
-                    7047588648137527871u64,
-                    16786349560540534986u64,
-                    73710094930243220u64,
-                ]),
-                BigInteger([
-                    14394463533283573050u64,
-                    2257758237405684267u64,
-                    651054130954815154u64,
-                    3549432044560243359u64,
-                    10096180562961168240u64,
-                    30277099030125806u64,
-                ]),
-                BigInteger([
-                    17836070221889115450u64,
-                    7351784533098928822u64,
-                    4160793206678967067u64,
-                    16623626989332786948u64,
-                    9487365916076237404u64,
-                    9350661934144847u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10167652337691599057u64,
-                    10426035814470452440u64,
-                    11603739029504808860u64,
-                    8288862750934285394u64,
-                    11543420997210846129u64,
-                    38865040284762000u64,
-                ]),
-                BigInteger([
-                    9040786374829592147u64,
-                    1169131278803110741u64,
-                    122145110682612122u64,
-                    5028665512037325398u64,
-                    6428620460000866067u64,
-                    3609604482166422u64,
-                ]),
-                BigInteger([
-                    16537019781708678599u64,
-                    9936495634172911544u64,
-                    173893282530097437u64,
-                    14513199835057060182u64,
-                    13024805778777263197u64,
-                    44120161300578117u64,
-                ]),
-                BigInteger([
-                    6101129050047118974u64,
-                    11730143217601820792u64,
-                    15471623232133803758u64,
-                    12520498896955656328u64,
-                    6698601531860680014u64,
-                    83026585408336155u64,
-                ]),
-                BigInteger([
-                    17212283910460848186u64,
-                    11892097147436159559u64,
-                    4549185589991114256u64,
-                    11541288670676164700u64,
-                    16191308957834960613u64,
-                    5639506227509583u64,
-                ]),
-                BigInteger([
-                    13452258215609502779u64,
-                    8207231795468641537u64,
-                    630211082165845920u64,
-                    11340393630588388791u64,
-                    12140429176561885059u64,
-                    60315183789275583u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    89730147959370788u64,
-                    9709989265450818729u64,
-                    18403897728224647527u64,
-                    6967923453154922473u64,
-                    1860792112677556779u64,
-                    98023438280161375u64,
-                ]),
-                BigInteger([
-                    8389417369280644778u64,
-                    3676760569117480250u64,
-                    7692443317871068016u64,
-                    4757246867057427218u64,
-                    10005939500802729092u64,
-                    88792826714619801u64,
-                ]),
-                BigInteger([
-                    5214308752654679886u64,
-                    12096193510990521788u64,
-                    1952410080142134265u64,
-                    10055814037016692966u64,
-                    17533833764642775195u64,
-                    65491333893107233u64,
-                ]),
-                BigInteger([
-                    7664675920585110702u64,
-                    10100766838550617240u64,
-                    5825830340160551202u64,
-                    18191783392565076436u64,
-                    14554761694169662501u64,
-                    42682031786632752u64,
-                ]),
-                BigInteger([
-                    7035311013534513912u64,
-                    7851945063287830179u64,
-                    672524601142351298u64,
-                    16371328837242595379u64,
-                    14927983153398832500u64,
-                    11951213379892102u64,
-                ]),
-                BigInteger([
-                    7263548423362982675u64,
-                    14101942315026521696u64,
-                    12989714800129040033u64,
-                    13412122098090490817u64,
-                    14373651090297893998u64,
-                    83699507059741146u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16271115679995373972u64,
-                    2809003464076438682u64,
-                    18114214354646564304u64,
-                    7847915568898894127u64,
-                    7711181538153347648u64,
-                    3677121554899261u64,
-                ]),
-                BigInteger([
-                    2651503545925882921u64,
-                    3096537987142961023u64,
-                    13381884863622034919u64,
-                    9845877633325723171u64,
-                    2791856504911801145u64,
-                    31418723059534714u64,
-                ]),
-                BigInteger([
-                    2526255353642975977u64,
-                    10767120411533172335u64,
-                    6469606836280345259u64,
-                    15280010854884816901u64,
-                    8500894006598296536u64,
-                    106200828939186107u64,
-                ]),
-                BigInteger([
-                    7606134910757477003u64,
-                    2246859324521124858u64,
-                    14810383596728802428u64,
-                    11708912886638022064u64,
-                    2690029968431785046u64,
-                    47121234330841556u64,
-                ]),
-                BigInteger([
-                    107382095947040319u64,
-                    6683990602324606140u64,
-                    2129383018767271507u64,
-                    16711651381245290565u64,
-                    10049885542704477415u64,
-                    95911971746955510u64,
-                ]),
-                BigInteger([
-                    14211764466874833733u64,
-                    11789445620940014909u64,
-                    16393996602499253832u64,
-                    8874509026596604819u64,
-                    334439612790634959u64,
-                    98498638148705530u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15097282558628667548u64,
-                    2585479876563429188u64,
-                    2370336764035979605u64,
-                    13208250086835114571u64,
-                    10132497016052772179u64,
-                    15946684320724031u64,
-                ]),
-                BigInteger([
-                    4330812378826143200u64,
-                    3189716899482606817u64,
-                    17630742816456611283u64,
-                    14845830228458635352u64,
-                    303561114584880627u64,
-                    82109405832126525u64,
-                ]),
-                BigInteger([
-                    15285874186615862250u64,
-                    18353106801090821032u64,
-                    628235799802074994u64,
-                    10635837800869580999u64,
-                    466341258339199945u64,
-                    81167844830644360u64,
-                ]),
-                BigInteger([
-                    9085922189366379274u64,
-                    6507499709783170518u64,
-                    7150673592928319367u64,
-                    2709551221398950424u64,
-                    15820383369701238165u64,
-                    83499840583880011u64,
-                ]),
-                BigInteger([
-                    2230895547079162039u64,
-                    15216037169609652773u64,
-                    3028343212600793446u64,
-                    9726003852646507352u64,
-                    15006366695033842760u64,
-                    58253464126888397u64,
-                ]),
-                BigInteger([
-                    11460493430009673382u64,
-                    11041631946131769843u64,
-                    6734151772152001659u64,
-                    7007853236184177246u64,
-                    4829270905582933276u64,
-                    59251001229040858u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9499449505761123132u64,
-                    8633172690257471241u64,
-                    15669397108921633758u64,
-                    15034529507181901265u64,
-                    17079575083597421937u64,
-                    1422783306733378u64,
-                ]),
-                BigInteger([
-                    6401676888845506637u64,
-                    18173731577047344142u64,
-                    1301874813151282518u64,
-                    15681566027532764289u64,
-                    6352523235003414006u64,
-                    84244647440389758u64,
-                ]),
-                BigInteger([
-                    16458613928752313053u64,
-                    9816572204030087780u64,
-                    7420044624184490950u64,
-                    12417679351632852055u64,
-                    16382730567801520632u64,
-                    13210937137912369u64,
-                ]),
-                BigInteger([
-                    17714420517419809322u64,
-                    5777267278056050055u64,
-                    1921235328926308136u64,
-                    4192862287474644657u64,
-                    8025342061666653359u64,
-                    29797836628716943u64,
-                ]),
-                BigInteger([
-                    13933867239059189866u64,
-                    17757924634748580910u64,
-                    1207983972217774842u64,
-                    1512951748346279189u64,
-                    12726273483971917245u64,
-                    38213768900720984u64,
-                ]),
-                BigInteger([
-                    17865725949926382853u64,
-                    3913159117833184633u64,
-                    11393753843944593215u64,
-                    4007481222079983303u64,
-                    3772380775851366004u64,
-                    13398891831681550u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13670243229132412195u64,
-                    1250164077979802926u64,
-                    7583208151003436492u64,
-                    7854018462682031526u64,
-                    11440015701811789915u64,
-                    104309357797358520u64,
-                ]),
-                BigInteger([
-                    13183385041408608416u64,
-                    18292207370599755810u64,
-                    7587971287199726335u64,
-                    12183563421230502331u64,
-                    10151884752962586399u64,
-                    3559649734032497u64,
-                ]),
-                BigInteger([
-                    16783494855483244144u64,
-                    9743207919713639319u64,
-                    8837242747242352832u64,
-                    4373887755522002345u64,
-                    17206524620824734782u64,
-                    31422584629854962u64,
-                ]),
-                BigInteger([
-                    7013314718392117749u64,
-                    15689001258688573042u64,
-                    15371890028342988479u64,
-                    7218861394963045159u64,
-                    1654595083917893310u64,
-                    93605078205460868u64,
-                ]),
-                BigInteger([
-                    9734725724057641605u64,
-                    5486754819314345965u64,
-                    1219470019584846842u64,
-                    3615539042361773165u64,
-                    3239020872535287048u64,
-                    103449645392915157u64,
-                ]),
-                BigInteger([
-                    10996304082175893796u64,
-                    304890553222758712u64,
-                    12816563931450398029u64,
-                    16180690281661682195u64,
-                    10980546397182828965u64,
-                    16148765465809178u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16790969323114129316u64,
-                    3102009714499764585u64,
-                    9136838861568409965u64,
-                    5099892426314859342u64,
-                    10917565619358616472u64,
-                    120944196476600999u64,
-                ]),
-                BigInteger([
-                    8579282667468701256u64,
-                    214021911934501807u64,
-                    3199937980283211210u64,
-                    16943286487983185601u64,
-                    9553785497337388393u64,
-                    103151721954203758u64,
-                ]),
-                BigInteger([
-                    529445803448726987u64,
-                    2325497255130271873u64,
-                    10654336534871581526u64,
-                    10155171107819392309u64,
-                    15432996529020474742u64,
-                    109510173604130320u64,
-                ]),
-                BigInteger([
-                    14809482648331504482u64,
-                    2661952693587625466u64,
-                    17922081784755956695u64,
-                    6865141736032569158u64,
-                    1769861765756839953u64,
-                    88145516377902180u64,
-                ]),
-                BigInteger([
-                    16606860532288371591u64,
-                    82232026035103179u64,
-                    1458789760432264338u64,
-                    8865235452769570374u64,
-                    2170878250883863467u64,
-                    106467684223707394u64,
-                ]),
-                BigInteger([
-                    14728324022001561588u64,
-                    8729069250385713617u64,
-                    1419952989572321428u64,
-                    6131112906129507303u64,
-                    7434519593011406356u64,
-                    6557349243090185u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2639773046200964037u64,
-                    12387988614314209017u64,
-                    14864429924582247760u64,
-                    14989482240006788364u64,
-                    10632306843335455270u64,
-                    77186785477310388u64,
-                ]),
-                BigInteger([
-                    1891849598406231931u64,
-                    13813867275788294376u64,
-                    7653036802366565258u64,
-                    1042586350476091169u64,
-                    17107020400020033748u64,
-                    54531009395803901u64,
-                ]),
-                BigInteger([
-                    8276078048042038434u64,
-                    15542354350696077076u64,
-                    18007765246308602013u64,
-                    13056606178925506217u64,
-                    3861214120647009252u64,
-                    14457096729188599u64,
-                ]),
-                BigInteger([
-                    11467875660747896362u64,
-                    14375025768386870342u64,
-                    1527051873781423332u64,
-                    17661529197571475253u64,
-                    14785385778409260702u64,
-                    53982709792867802u64,
-                ]),
-                BigInteger([
-                    6883933763682532152u64,
-                    7577164314234875595u64,
-                    7600448938841253862u64,
-                    201589456866657456u64,
-                    12662778720898207360u64,
-                    43686775098200208u64,
-                ]),
-                BigInteger([
-                    14266319344139438555u64,
-                    13172834745615931813u64,
-                    1222566900579730734u64,
-                    17707067764597624423u64,
-                    11099142763273098506u64,
-                    73168403494774832u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    18263126464186417001u64,
-                    3417966533482547307u64,
-                    8171236908227883460u64,
-                    8474711638381628674u64,
-                    14609147850735074277u64,
-                    111327494961983168u64,
-                ]),
-                BigInteger([
-                    2270176163836521824u64,
-                    10100020023730704636u64,
-                    4210722786249310303u64,
-                    5960423436725039370u64,
-                    260800471834962498u64,
-                    7532412266969796u64,
-                ]),
-                BigInteger([
-                    17558516343775247650u64,
-                    9632181004257884829u64,
-                    11328660285262227128u64,
-                    6055377114755381325u64,
-                    1123663157827638377u64,
-                    44134944189911608u64,
-                ]),
-                BigInteger([
-                    2161064775156104976u64,
-                    4309467942645208905u64,
-                    16569653988851844425u64,
-                    7069189631609793207u64,
-                    9559827503291788962u64,
-                    19225200319408355u64,
-                ]),
-                BigInteger([
-                    13040861787293248060u64,
-                    15921301931483496843u64,
-                    6630547038517133459u64,
-                    4482240836083292749u64,
-                    17207607788043108764u64,
-                    113346901935373107u64,
-                ]),
-                BigInteger([
-                    1355825190555713007u64,
-                    15377132107424244310u64,
-                    1352498713001049123u64,
-                    18271098319109985636u64,
-                    1484456943117898953u64,
-                    3633491869395349u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5305117367759564659u64,
-                    4099893502551183462u64,
-                    14012773950336034470u64,
-                    10519417075234904362u64,
-                    10509523272565201720u64,
-                    62587614727711109u64,
-                ]),
-                BigInteger([
-                    14015414281638502005u64,
-                    9469590394449590502u64,
-                    7636062587147887489u64,
-                    1665908932995440468u64,
-                    12747864705036121846u64,
-                    107688875729848028u64,
-                ]),
-                BigInteger([
-                    14460868277008783654u64,
-                    861997083961636632u64,
-                    15105021777642857752u64,
-                    6890216395191031023u64,
-                    2877594455880158370u64,
-                    28188432989580395u64,
-                ]),
-                BigInteger([
-                    9254189811454312418u64,
-                    10926109917056218314u64,
-                    7894648773157497511u64,
-                    468842557734006983u64,
-                    360732530145129578u64,
-                    83814868041481720u64,
-                ]),
-                BigInteger([
-                    2048141700814802833u64,
-                    10806300727917465285u64,
-                    13031699637761962713u64,
-                    3408841980069682242u64,
-                    8004956130326550805u64,
-                    103184719680958016u64,
-                ]),
-                BigInteger([
-                    11256624422122159724u64,
-                    16214270959154925539u64,
-                    9582920460728370840u64,
-                    8024199528831702064u64,
-                    18109777834069616844u64,
-                    68105472587268486u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11205381864589042800u64,
-                    17919301003466675621u64,
-                    16561861196524212435u64,
-                    2991802403754950258u64,
-                    13732066498782990996u64,
-                    110951041461043101u64,
-                ]),
-                BigInteger([
-                    3641059934903010973u64,
-                    3783499767015434540u64,
-                    15586571842795140633u64,
-                    2131911162450028286u64,
-                    15900701107309263066u64,
-                    54896190631449348u64,
-                ]),
-                BigInteger([
-                    18359807183048909401u64,
-                    12596809206966985098u64,
-                    16492401472060694595u64,
-                    16558035200183096227u64,
-                    9284149988380066333u64,
-                    69426730232057387u64,
-                ]),
-                BigInteger([
-                    15270153473883729320u64,
-                    794824125576394438u64,
-                    17886243131625159079u64,
-                    8341278288717446112u64,
-                    461488301100483192u64,
-                    55504142700291688u64,
-                ]),
-                BigInteger([
-                    14006236991444008870u64,
-                    11321832203111326681u64,
-                    1698674517593232182u64,
-                    16819330981570927040u64,
-                    12621546914968752899u64,
-                    79933683254333621u64,
-                ]),
-                BigInteger([
-                    131497953566443724u64,
-                    18022419257403727747u64,
-                    168139977273617409u64,
-                    14849829964126969029u64,
-                    11770578689279712750u64,
-                    48692462850731359u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12937815561632760137u64,
-                    12126853496677688698u64,
-                    15414862040631323175u64,
-                    13530091360628098751u64,
-                    13436984465112652704u64,
-                    3837553352971506u64,
-                ]),
-                BigInteger([
-                    15232216631744495183u64,
-                    2291605256399256846u64,
-                    16342649063321489841u64,
-                    2128995739723028758u64,
-                    116236114476001916u64,
-                    115486817414747460u64,
-                ]),
-                BigInteger([
-                    8666320228658807064u64,
-                    8726958806647853757u64,
-                    16453964914588267024u64,
-                    6769568690077277891u64,
-                    13263190239472920520u64,
-                    41984712167323046u64,
-                ]),
-                BigInteger([
-                    16164351895690142846u64,
-                    2946930530706694553u64,
-                    11223937998427169295u64,
-                    11810617172914990069u64,
-                    7026574963786811897u64,
-                    94911976848962734u64,
-                ]),
-                BigInteger([
-                    7637307986493040824u64,
-                    11261392843834450041u64,
-                    9535890748840772392u64,
-                    17389612400478477005u64,
-                    13148133641032015029u64,
-                    39744838555505746u64,
-                ]),
-                BigInteger([
-                    806055919317736503u64,
-                    11541564199837016945u64,
-                    2069903821376788477u64,
-                    4730417565309485750u64,
-                    1374293845461813714u64,
-                    35615757497295894u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8609716581223084791u64,
-                    740111162656159187u64,
-                    456633327466099824u64,
-                    6699745405604536214u64,
-                    8526000620516060504u64,
-                    75898788759702140u64,
-                ]),
-                BigInteger([
-                    3822996190919926801u64,
-                    3945972269569504398u64,
-                    2893678342522496341u64,
-                    7705311081624940931u64,
-                    12304776635377729388u64,
-                    75800774867397655u64,
-                ]),
-                BigInteger([
-                    15157177205868790487u64,
-                    5238772150129837572u64,
-                    14288622765610650797u64,
-                    14443981721147853897u64,
-                    11397857739417172205u64,
-                    119888015717866917u64,
-                ]),
-                BigInteger([
-                    13342827387143482557u64,
-                    2627345563239191218u64,
-                    1196244544286042208u64,
-                    3612497301603655363u64,
-                    6241762442332792299u64,
-                    105120997656760929u64,
-                ]),
-                BigInteger([
-                    14308474490163082128u64,
-                    17896031304529479648u64,
-                    669754904043273245u64,
-                    14123771659978660516u64,
-                    14780021822605557550u64,
-                    82039042722959635u64,
-                ]),
-                BigInteger([
-                    14739081069946690064u64,
-                    15293512991557710633u64,
-                    17437698273194332676u64,
-                    6849259427926052961u64,
-                    7731642055958929299u64,
-                    3180267322478477u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10735707980114505714u64,
-                    2390590917530077110u64,
-                    7469135396921556434u64,
-                    7123548323842715712u64,
-                    4473765193121251015u64,
-                    117123345922736852u64,
-                ]),
-                BigInteger([
-                    219323777575928215u64,
-                    429316011059160774u64,
-                    17754954369980463420u64,
-                    2203322333269670183u64,
-                    9596717240958597084u64,
-                    119180395880772319u64,
-                ]),
-                BigInteger([
-                    6277087297753472942u64,
-                    9997458419258685904u64,
-                    17494497329649509851u64,
-                    4829411393060284008u64,
-                    11484587240573142225u64,
-                    62622580237693519u64,
-                ]),
-                BigInteger([
-                    16096816107677922223u64,
-                    6000495254009103163u64,
-                    13437121478350137844u64,
-                    8743513088700967436u64,
-                    8115109713301544952u64,
-                    30539519925938351u64,
-                ]),
-                BigInteger([
-                    12915807750175063835u64,
-                    11386938912429315215u64,
-                    1465095077818453367u64,
-                    10161515882393917802u64,
-                    15122854938566236378u64,
-                    56343811081103987u64,
-                ]),
-                BigInteger([
-                    15504710074649021063u64,
-                    355373775575437819u64,
-                    12347721569795017392u64,
-                    1542579907567085515u64,
-                    15712252101971151245u64,
-                    46236724220790956u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4908058315075933369u64,
-                    631290407738373870u64,
-                    1282471039570122770u64,
-                    10003820603573580645u64,
-                    3765277614593895254u64,
-                    45241282413312739u64,
-                ]),
-                BigInteger([
-                    9511020159325104535u64,
-                    10827983143653577474u64,
-                    17475271783543608074u64,
-                    17948637757138414316u64,
-                    13497562313138787568u64,
-                    78279394730168099u64,
-                ]),
-                BigInteger([
-                    12119240092866182005u64,
-                    5049139848747236370u64,
-                    8704918380922961940u64,
-                    17770097251231659291u64,
-                    17366640009213587256u64,
-                    93825397754036182u64,
-                ]),
-                BigInteger([
-                    15061378237436788343u64,
-                    9800438608204273671u64,
-                    5046276260278528059u64,
-                    4767861502430563063u64,
-                    7237705579157362232u64,
-                    13837652167738848u64,
-                ]),
-                BigInteger([
-                    5410874967504425160u64,
-                    15385472680484080421u64,
-                    12942265166155782600u64,
-                    11074843345902784070u64,
-                    3116375933121093972u64,
-                    64019771937169527u64,
-                ]),
-                BigInteger([
-                    14523448791198449712u64,
-                    11187725713577180130u64,
-                    4275907482022238624u64,
-                    8424090577027138957u64,
-                    11002959090424723877u64,
-                    66403757966411027u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13980778103395469656u64,
-                    2864050934160284125u64,
-                    17399213846284255451u64,
-                    10564639073269883516u64,
-                    15198844777096211194u64,
-                    69174204974611534u64,
-                ]),
-                BigInteger([
-                    5329247263712376681u64,
-                    10185764077459917273u64,
-                    9574048168215427212u64,
-                    11463517511114364388u64,
-                    9942940442051648764u64,
-                    26011941316099779u64,
-                ]),
-                BigInteger([
-                    12399080123493559125u64,
-                    12035291785178956741u64,
-                    16732793496490754304u64,
-                    2190531034629194123u64,
-                    5164492644153292306u64,
-                    67948825877586624u64,
-                ]),
-                BigInteger([
-                    9866742923835285653u64,
-                    17814915671848010503u64,
-                    11637828921908096628u64,
-                    9404949307455242023u64,
-                    13670751605589826979u64,
-                    46237960709067600u64,
-                ]),
-                BigInteger([
-                    2226422572621583188u64,
-                    1584863771581179754u64,
-                    4356066534447418928u64,
-                    6637871436944152855u64,
-                    16162397635756594521u64,
-                    34751526522963565u64,
-                ]),
-                BigInteger([
-                    14216393442420222465u64,
-                    15750751015708302769u64,
-                    599676102862465254u64,
-                    1702103131538744592u64,
-                    15107072000982622620u64,
-                    5151412132452356u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5560546710462261987u64,
-                    6963180249114131350u64,
-                    9432034800131489563u64,
-                    16443637279887047191u64,
-                    8101067776626978578u64,
-                    77797017063676226u64,
-                ]),
-                BigInteger([
-                    10175711392818572921u64,
-                    11141400279828459886u64,
-                    14858341384138929665u64,
-                    9904507289180002998u64,
-                    2934154115846349037u64,
-                    53260602667539980u64,
-                ]),
-                BigInteger([
-                    4981671693534186473u64,
-                    16640755013763316693u64,
-                    15329401657125366675u64,
-                    16304322610489321593u64,
-                    5280106486886424519u64,
-                    16668117719371517u64,
-                ]),
-                BigInteger([
-                    17168742945234337990u64,
-                    17722630842360912891u64,
-                    6752939758572667505u64,
-                    18239177543493224101u64,
-                    12711699339360014214u64,
-                    74702686281053940u64,
-                ]),
-                BigInteger([
-                    9115818593805189802u64,
-                    10849374401203923855u64,
-                    11400400114845446726u64,
-                    12313514124431612500u64,
-                    2827213213640149311u64,
-                    51234836844860137u64,
-                ]),
-                BigInteger([
-                    3348000548906242566u64,
-                    12451979245508082435u64,
-                    7832562423094252044u64,
-                    9470695065675328923u64,
-                    12978739282906279500u64,
-                    35486347227316462u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3593866350899680565u64,
-                    1109652713007409866u64,
-                    5031759813219078336u64,
-                    15219695915771989417u64,
-                    8390281474025992249u64,
-                    15822804013548286u64,
-                ]),
-                BigInteger([
-                    5743394830400088303u64,
-                    9022863001947940766u64,
-                    16291039505356906994u64,
-                    10661701922638996365u64,
-                    6575123908571920594u64,
-                    41577583412874912u64,
-                ]),
-                BigInteger([
-                    13334376545255420068u64,
-                    8451609625622958976u64,
-                    2309416229846315369u64,
-                    3693205825767430268u64,
-                    5883804577542404811u64,
-                    35058249575519875u64,
-                ]),
-                BigInteger([
-                    7406203278456896271u64,
-                    8837438499514231374u64,
-                    895382730486991278u64,
-                    16240574136751907430u64,
-                    1049727947491474229u64,
-                    66363237928401777u64,
-                ]),
-                BigInteger([
-                    2504464719905241661u64,
-                    16157531005413175598u64,
-                    13267622584564802135u64,
-                    8157918563000965483u64,
-                    5726845544255335504u64,
-                    103751736806959295u64,
-                ]),
-                BigInteger([
-                    13836755665904440336u64,
-                    3451145160864330665u64,
-                    8113444048880048388u64,
-                    5284403636652948198u64,
-                    10601659396179038165u64,
-                    50248885903370537u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2315786809708152943u64,
-                    13417739313697453540u64,
-                    2278155495648075544u64,
-                    12418312532292745398u64,
-                    3145922563472004661u64,
-                    15571044412235353u64,
-                ]),
-                BigInteger([
-                    10903158098980149173u64,
-                    831633412984071746u64,
-                    7058586277092142808u64,
-                    7412074568538985403u64,
-                    16416731694137401808u64,
-                    63136660353511067u64,
-                ]),
-                BigInteger([
-                    10114079422009467208u64,
-                    16423041756790544107u64,
-                    17738493638397154464u64,
-                    10894300559372321812u64,
-                    4334620312068466056u64,
-                    2140359787142906u64,
-                ]),
-                BigInteger([
-                    11176388849920612758u64,
-                    16895469260361728102u64,
-                    8341782991121443398u64,
-                    9707415364788151177u64,
-                    6771598760043060552u64,
-                    85365195838042323u64,
-                ]),
-                BigInteger([
-                    13938457560659210850u64,
-                    16220976573565791923u64,
-                    4589712891100931428u64,
-                    6208277106093788426u64,
-                    590808039306552145u64,
-                    33271808552610620u64,
-                ]),
-                BigInteger([
-                    374511639662403603u64,
-                    13585591967938141538u64,
-                    6782556413946766815u64,
-                    721497319571447314u64,
-                    2359376230186048753u64,
-                    86822158668118883u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14170772676466149538u64,
-                    13591326179499564799u64,
-                    5029717255173350103u64,
-                    6688688937267695095u64,
-                    3099881544451531328u64,
-                    67569127853345851u64,
-                ]),
-                BigInteger([
-                    14398379195206380156u64,
-                    12641655476755546129u64,
-                    7699191624362246601u64,
-                    11414383936907979524u64,
-                    9059640539391898496u64,
-                    81859532775812241u64,
-                ]),
-                BigInteger([
-                    988874881485302661u64,
-                    11343458734568145590u64,
-                    9840730105987139228u64,
-                    10806055385022407493u64,
-                    17913909932298901635u64,
-                    68519890717287743u64,
-                ]),
-                BigInteger([
-                    9180067989747582695u64,
-                    13030631094369188637u64,
-                    4451330955855938801u64,
-                    8294622392352538672u64,
-                    11954352161692008858u64,
-                    89495838433133141u64,
-                ]),
-                BigInteger([
-                    567063915729071108u64,
-                    7834133725176193204u64,
-                    8041436718031441521u64,
-                    12873058516851981319u64,
-                    16151351158972915081u64,
-                    39814469081014067u64,
-                ]),
-                BigInteger([
-                    18343941063997950102u64,
-                    11134390786540955528u64,
-                    989379012406557607u64,
-                    10249399613594458978u64,
-                    13433139920946204205u64,
-                    41358547628117646u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13521110359449770259u64,
-                    9758083275543316330u64,
-                    7412665050950427418u64,
-                    7956863341205871392u64,
-                    11334271443026715287u64,
-                    111126273833327485u64,
-                ]),
-                BigInteger([
-                    11236876936973249911u64,
-                    13573002062698095899u64,
-                    10894973404777804183u64,
-                    7550984273593565293u64,
-                    13415291847055404877u64,
-                    74274095877512939u64,
-                ]),
-                BigInteger([
-                    10684907497715418556u64,
-                    406266609854418966u64,
-                    1489227262286346721u64,
-                    14352727412731680947u64,
-                    4276291784294125532u64,
-                    28924192987905682u64,
-                ]),
-                BigInteger([
-                    1826999355602363942u64,
-                    2997689208840584617u64,
-                    17125115704038559240u64,
-                    13109052981345726993u64,
-                    13833308968249927712u64,
-                    83370184118905684u64,
-                ]),
-                BigInteger([
-                    8182731744875537457u64,
-                    8168420077294435886u64,
-                    13644992626489372962u64,
-                    3727781767338373878u64,
-                    1824027228226462088u64,
-                    94733272347120104u64,
-                ]),
-                BigInteger([
-                    3223364074149599033u64,
-                    342207716095046976u64,
-                    4946129408872765350u64,
-                    4564930807191782547u64,
-                    12461260220138907488u64,
-                    92578136812214809u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3161665108627583995u64,
-                    12275523117230383226u64,
-                    15523657278506430559u64,
-                    10596023499707736566u64,
-                    3983588179470729597u64,
-                    8844036598713626u64,
-                ]),
-                BigInteger([
-                    1842718080980276657u64,
-                    12740497666188550397u64,
-                    10304842592066480717u64,
-                    17415258486047844543u64,
-                    1552769666172285872u64,
-                    90138218755126212u64,
-                ]),
-                BigInteger([
-                    11788891704383274157u64,
-                    9035815344617858526u64,
-                    2548643599604057115u64,
-                    3077207523571965693u64,
-                    2972069173012954269u64,
-                    29699513463992640u64,
-                ]),
-                BigInteger([
-                    15024079061966752766u64,
-                    10066066919470668582u64,
-                    13418541646491282411u64,
-                    8888471422767367877u64,
-                    6943583167570128497u64,
-                    81008072565749298u64,
-                ]),
-                BigInteger([
-                    8338865101895568061u64,
-                    16025162664464109391u64,
-                    13545664580162242441u64,
-                    7135387280272529394u64,
-                    17875074234571282150u64,
-                    56153579509659949u64,
-                ]),
-                BigInteger([
-                    8237531091014379683u64,
-                    18080687732266195301u64,
-                    17431473939209979010u64,
-                    6628562523616588062u64,
-                    6637456902626759451u64,
-                    82988736022138081u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8076947933750247306u64,
-                    15120480338395384782u64,
-                    7758711474071234233u64,
-                    5118964969936471451u64,
-                    535138597038831191u64,
-                    46243072329285587u64,
-                ]),
-                BigInteger([
-                    11159227345165044957u64,
-                    14880351548798083557u64,
-                    10859253831677573977u64,
-                    3755733083453763144u64,
-                    12502423416114331983u64,
-                    47237691764721158u64,
-                ]),
-                BigInteger([
-                    6230061626622149381u64,
-                    13422071804959377045u64,
-                    14729497096682829390u64,
-                    7022398615962279673u64,
-                    4184039424214561844u64,
-                    42531711012271181u64,
-                ]),
-                BigInteger([
-                    12721696683843077032u64,
-                    12095390304947425348u64,
-                    15595551011288329353u64,
-                    17009788295663829154u64,
-                    14470905429634771405u64,
-                    26021846419889134u64,
-                ]),
-                BigInteger([
-                    676135909010438843u64,
-                    18382630202821804815u64,
-                    13793606097945165410u64,
-                    2468600445545649985u64,
-                    6430060350811066136u64,
-                    64508619155795573u64,
-                ]),
-                BigInteger([
-                    1243082653485514185u64,
-                    14476594017257228257u64,
-                    7432654967584846223u64,
-                    1074173948116652470u64,
-                    805650766913443280u64,
-                    100640517944932376u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10141823915232307405u64,
-                    3590464357325484416u64,
-                    12237306114463995764u64,
-                    15169725376614494468u64,
-                    10389545363083097545u64,
-                    54941629074370012u64,
-                ]),
-                BigInteger([
-                    9513459434848285474u64,
-                    10275743920168067787u64,
-                    9392500967150562971u64,
-                    10883555894410526893u64,
-                    12306891462406028177u64,
-                    97215491640493821u64,
-                ]),
-                BigInteger([
-                    10755671558813885991u64,
-                    10211634977101369450u64,
-                    3903654112855704839u64,
-                    18140323635310079094u64,
-                    16033524357478674636u64,
-                    93909156614282149u64,
-                ]),
-                BigInteger([
-                    8914983134060235950u64,
-                    10698428127110594227u64,
-                    13509792161120551372u64,
-                    9431618553819990365u64,
-                    2383616964966529514u64,
-                    2602025580296580u64,
-                ]),
-                BigInteger([
-                    8455604914016852204u64,
-                    3587058217757781441u64,
-                    11302927235956720264u64,
-                    5680519744391911696u64,
-                    18348687831077336603u64,
-                    28558489259028191u64,
-                ]),
-                BigInteger([
-                    18392597427962986119u64,
-                    11515119265711281305u64,
-                    2292255454336847775u64,
-                    6879287205653252812u64,
-                    4556824815224171406u64,
-                    116284187802444880u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6321609065694906824u64,
-                    2964474849846997793u64,
-                    483490601405266003u64,
-                    15556225950102133547u64,
-                    10422819873079217366u64,
-                    78213430502801500u64,
-                ]),
-                BigInteger([
-                    15289258080716509368u64,
-                    2547589252810009467u64,
-                    9367934913383880061u64,
-                    1660185728942688037u64,
-                    4310204304010726231u64,
-                    68955571349945083u64,
-                ]),
-                BigInteger([
-                    12728684131554756130u64,
-                    2852342300513801343u64,
-                    9620651425777400081u64,
-                    11313689040802944446u64,
-                    4811085709990851415u64,
-                    114764934729022460u64,
-                ]),
-                BigInteger([
-                    17899797816417578818u64,
-                    10015105436454573532u64,
-                    17466788666017284720u64,
-                    9860920644790536199u64,
-                    9400945372481644064u64,
-                    11299201233712086u64,
-                ]),
-                BigInteger([
-                    2448379484974277555u64,
-                    12221011629765215747u64,
-                    6917276081190695170u64,
-                    7764023514661968222u64,
-                    7660174095835226081u64,
-                    49731941907306384u64,
-                ]),
-                BigInteger([
-                    320936142456037332u64,
-                    10512640240695963273u64,
-                    2325922774126025361u64,
-                    2604247713200235852u64,
-                    2771197896188620465u64,
-                    43308944366860877u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6264799439346007118u64,
-                    14479713228916889843u64,
-                    18193876550205908709u64,
-                    9489439760360847155u64,
-                    2517614570502822354u64,
-                    38927180946675016u64,
-                ]),
-                BigInteger([
-                    5978541240311914396u64,
-                    12743976925106496033u64,
-                    5270434815224659475u64,
-                    9720833867444987495u64,
-                    7289793245202096409u64,
-                    113740238299221853u64,
-                ]),
-                BigInteger([
-                    11598189360415538293u64,
-                    18066324738819411667u64,
-                    5165566245220971017u64,
-                    13179500528757389361u64,
-                    6097685291502346949u64,
-                    36908473277348352u64,
-                ]),
-                BigInteger([
-                    1062795213859131621u64,
-                    14907083868970814887u64,
-                    7256257519218295700u64,
-                    17117514834846314225u64,
-                    92738152565789895u64,
-                    112964218839199891u64,
-                ]),
-                BigInteger([
-                    4357420476800383481u64,
-                    13860803488573088723u64,
-                    2611417052568908376u64,
-                    12039118254651905761u64,
-                    2182595296176150727u64,
-                    86325611471149246u64,
-                ]),
-                BigInteger([
-                    2877148288218814070u64,
-                    1207368229343404189u64,
-                    17745086007351609708u64,
-                    656630839357945949u64,
-                    14447171520828660355u64,
-                    61066892139376961u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3276313736719238185u64,
-                    4499763731133076892u64,
-                    3647413883434105436u64,
-                    8563063050303161141u64,
-                    3832040429418806313u64,
-                    85048517968461438u64,
-                ]),
-                BigInteger([
-                    7727549440471679544u64,
-                    15844362571037458876u64,
-                    9360634195232840137u64,
-                    4944123607002477012u64,
-                    9857572603206497351u64,
-                    20222843394201428u64,
-                ]),
-                BigInteger([
-                    16928268345172649547u64,
-                    6519586798739503848u64,
-                    3816070557005241864u64,
-                    5165845050081774390u64,
-                    2615791816210201246u64,
-                    7223555845360284u64,
-                ]),
-                BigInteger([
-                    11953711835561600675u64,
-                    8854709077734980105u64,
-                    9352408736056950583u64,
-                    5738404663997997209u64,
-                    18151511379424588809u64,
-                    100539295929512416u64,
-                ]),
-                BigInteger([
-                    2973368401279059955u64,
-                    16138785972027569546u64,
-                    11487119719581071306u64,
-                    2234639790117600341u64,
-                    16071177156935239314u64,
-                    70857839787887668u64,
-                ]),
-                BigInteger([
-                    15687779092707054454u64,
-                    13345183432938337387u64,
-                    16887474997115229201u64,
-                    4875482288843614851u64,
-                    7223443854113374834u64,
-                    15206633413738216u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7735854320643579634u64,
-                    7990131727623415438u64,
-                    4152729225997008241u64,
-                    799287501191246556u64,
-                    17241969294949141928u64,
-                    113862569123891205u64,
-                ]),
-                BigInteger([
-                    15054513649504011763u64,
-                    13293409755648377916u64,
-                    31838367189292122u64,
-                    14348979873550832382u64,
-                    12910400073636732111u64,
-                    96548636342646365u64,
-                ]),
-                BigInteger([
-                    3420204668769935181u64,
-                    3650139671910847850u64,
-                    4561198236269780209u64,
-                    13081978852545815825u64,
-                    13566913310824472080u64,
-                    6659516912241853u64,
-                ]),
-                BigInteger([
-                    16834024970903552474u64,
-                    10819398063038601371u64,
-                    5543261900878435381u64,
-                    12203716590286265984u64,
-                    5600119853142134798u64,
-                    118692150626131170u64,
-                ]),
-                BigInteger([
-                    13194427178402830437u64,
-                    991760745910818947u64,
-                    4619710713353856232u64,
-                    17734571063168470354u64,
-                    6569857485451006031u64,
-                    74657157018109534u64,
-                ]),
-                BigInteger([
-                    14918025928675477861u64,
-                    7535073610365898484u64,
-                    13239877991884373863u64,
-                    9094855836701051463u64,
-                    17580267488867154011u64,
-                    45994466589507612u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17798057607738319845u64,
-                    1121400403892335073u64,
-                    7205344643971223721u64,
-                    13236557219851431012u64,
-                    9550408928065857631u64,
-                    92324268918245872u64,
-                ]),
-                BigInteger([
-                    4828415340971060689u64,
-                    1879309584698505131u64,
-                    4433921501416816683u64,
-                    13958186530702033711u64,
-                    17266923714469180533u64,
-                    87683433200537474u64,
-                ]),
-                BigInteger([
-                    12763744746013898566u64,
-                    14955963102519455317u64,
-                    8829233915385304481u64,
-                    6858739597762889292u64,
-                    13299904204436332280u64,
-                    55636472193142759u64,
-                ]),
-                BigInteger([
-                    994277437524981909u64,
-                    9328233745493328229u64,
-                    9213669913961583966u64,
-                    2845451282758915569u64,
-                    1649929014848196874u64,
-                    47326644977165928u64,
-                ]),
-                BigInteger([
-                    8468714963254867837u64,
-                    10033687643110077899u64,
-                    15818260700113290835u64,
-                    7590328842615406290u64,
-                    15432735075246505718u64,
-                    103734298999001637u64,
-                ]),
-                BigInteger([
-                    11706364104211604525u64,
-                    17972065921984014748u64,
-                    17828328826337193980u64,
-                    17045021147680978642u64,
-                    5316313996957302634u64,
-                    108287175793673317u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17386056103842166991u64,
-                    9218825827552628532u64,
-                    7975087646975006224u64,
-                    17147425034956246179u64,
-                    16862079072327760501u64,
-                    5868679930533322u64,
-                ]),
-                BigInteger([
-                    15570901675796821378u64,
-                    4514641194548498754u64,
-                    7833450323487923437u64,
-                    17813326930966466146u64,
-                    16198414021290931029u64,
-                    49648256753511593u64,
-                ]),
-                BigInteger([
-                    12467478901050305031u64,
-                    17687025702276206857u64,
-                    8486751531767642497u64,
-                    11839462015310560102u64,
-                    5931058989453389273u64,
-                    28494397784343809u64,
-                ]),
-                BigInteger([
-                    9773368513816598123u64,
-                    8265832423292760163u64,
-                    311183789770031102u64,
-                    9963902497695472203u64,
-                    10950563539793631407u64,
-                    89167655563986533u64,
-                ]),
-                BigInteger([
-                    14390309252760319563u64,
-                    12782632973710647880u64,
-                    15006501669659655670u64,
-                    2349828168625012804u64,
-                    9207409834712452458u64,
-                    93135888717211610u64,
-                ]),
-                BigInteger([
-                    9010289255460297667u64,
-                    11560630451039079527u64,
-                    8313970073073744721u64,
-                    11904557092817578873u64,
-                    15768769715271164092u64,
-                    71782501355523266u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14492511159574572715u64,
-                    5488422553193603959u64,
-                    8273014039574452069u64,
-                    15307356567556587278u64,
-                    13827464139789957092u64,
-                    14972336821629344u64,
-                ]),
-                BigInteger([
-                    5556822212996963675u64,
-                    18327254913389033636u64,
-                    13171609411585302822u64,
-                    9793818688669588512u64,
-                    7062451672386959012u64,
-                    114906589382460517u64,
-                ]),
-                BigInteger([
-                    6839862445369083881u64,
-                    6280170763080202421u64,
-                    12853071859684676132u64,
-                    4509954551624687334u64,
-                    6155068289902536315u64,
-                    63733788382024980u64,
-                ]),
-                BigInteger([
-                    16949888314207612171u64,
-                    1499105586082814903u64,
-                    12945674386715638761u64,
-                    14768189529430802397u64,
-                    2577830145462928611u64,
-                    69059520701073355u64,
-                ]),
-                BigInteger([
-                    15809439991256659310u64,
-                    13924611261935691698u64,
-                    14438752042239595719u64,
-                    11720723933550848251u64,
-                    15062368303290486467u64,
-                    23832306158439865u64,
-                ]),
-                BigInteger([
-                    17511101988374768690u64,
-                    3054402344739285082u64,
-                    14880793171041573542u64,
-                    609295720016929771u64,
-                    8474375371578019160u64,
-                    58121935137136344u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2520201143662881125u64,
-                    2844369886569745861u64,
-                    14935922319251263746u64,
-                    15421873805035660432u64,
-                    5877693782729998959u64,
-                    6569104929193216u64,
-                ]),
-                BigInteger([
-                    11402857436990435630u64,
-                    15481112064526401807u64,
-                    9094292946113833697u64,
-                    2292056812002131187u64,
-                    3971927964439922250u64,
-                    6914131357154201u64,
-                ]),
-                BigInteger([
-                    9749719981352091894u64,
-                    1823847254170033444u64,
-                    9127443467038798441u64,
-                    17399537376185310098u64,
-                    4596997280507690820u64,
-                    120109755451142821u64,
-                ]),
-                BigInteger([
-                    5116077877619079063u64,
-                    1024711840249365087u64,
-                    6731845558039524713u64,
-                    3021661404061102763u64,
-                    7989109671135324234u64,
-                    117802498488145515u64,
-                ]),
-                BigInteger([
-                    2065224286835641521u64,
-                    3035593488225254036u64,
-                    3396735652036008616u64,
-                    3355858901262053837u64,
-                    15093129331259494550u64,
-                    72368364908797291u64,
-                ]),
-                BigInteger([
-                    15100337966679962359u64,
-                    152607102298128841u64,
-                    3976040022760402755u64,
-                    7957816303982612048u64,
-                    7084275877561437306u64,
-                    110618038649388362u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    987490678070203659u64,
-                    1161686336278676801u64,
-                    2778999513755602332u64,
-                    5719891063847391123u64,
-                    1475821350056800203u64,
-                    119901776552255454u64,
-                ]),
-                BigInteger([
-                    10599255346332857737u64,
-                    7882236060809563383u64,
-                    11658936476342381916u64,
-                    2889572133141655380u64,
-                    14550932147247361217u64,
-                    63863188691963580u64,
-                ]),
-                BigInteger([
-                    2249268850626545574u64,
-                    6453937611894602868u64,
-                    4956132426838134052u64,
-                    8188627725449576711u64,
-                    111434625996633748u64,
-                    92743760606374169u64,
-                ]),
-                BigInteger([
-                    12527506484025999126u64,
-                    10335946977318184516u64,
-                    2129561497461931184u64,
-                    16973163578746008776u64,
-                    9696436559038610011u64,
-                    68898666075534064u64,
-                ]),
-                BigInteger([
-                    13340876515267947984u64,
-                    626462308385301454u64,
-                    16889222159758644687u64,
-                    6843052603788784522u64,
-                    17858055153571341156u64,
-                    113071386312868884u64,
-                ]),
-                BigInteger([
-                    4126442150433135969u64,
-                    10027658815750201241u64,
-                    2112211796175552799u64,
-                    9578026251923212624u64,
-                    8998130891297558506u64,
-                    112864184346092179u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17910317099190651523u64,
-                    3459058924411306119u64,
-                    3166561537337993846u64,
-                    9757754740214570428u64,
-                    13339349341608396407u64,
-                    58595849939739439u64,
-                ]),
-                BigInteger([
-                    9455889780065438572u64,
-                    10909726515522195768u64,
-                    1454385802513998306u64,
-                    9976139675269259791u64,
-                    1676882698184319112u64,
-                    88291332664119354u64,
-                ]),
-                BigInteger([
-                    17544599737878804899u64,
-                    4226889480344641057u64,
-                    17972538685584582316u64,
-                    1510472549502237030u64,
-                    7531034467930005760u64,
-                    86290288122716947u64,
-                ]),
-                BigInteger([
-                    9685796407976165788u64,
-                    1653830066221221951u64,
-                    748048632211526327u64,
-                    16436602669904094830u64,
-                    7593615974807829158u64,
-                    84355352968670494u64,
-                ]),
-                BigInteger([
-                    8654385910160933570u64,
-                    5750602190681973240u64,
-                    15378470927074683565u64,
-                    11115988550869755179u64,
-                    16709630483241013949u64,
-                    9847857366835820u64,
-                ]),
-                BigInteger([
-                    5716300507974429615u64,
-                    4595348446573936429u64,
-                    8510646668794873910u64,
-                    14613552550645925019u64,
-                    565919891523953354u64,
-                    52720034518659260u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5118061032198788518u64,
-                    13844345538757341218u64,
-                    5270209999422336313u64,
-                    16839808620079742530u64,
-                    4836287230111636505u64,
-                    15640952290568192u64,
-                ]),
-                BigInteger([
-                    6941735147650997780u64,
-                    6054486295343514990u64,
-                    14175645307758371516u64,
-                    3455888435673046036u64,
-                    10232180694449436236u64,
-                    50950345684916359u64,
-                ]),
-                BigInteger([
-                    3320235538029264702u64,
-                    12722024397727821250u64,
-                    10363886864075949604u64,
-                    3814313956439561994u64,
-                    10550973054754279633u64,
-                    74769595441835261u64,
-                ]),
-                BigInteger([
-                    11497677380308152988u64,
-                    12135208722521296478u64,
-                    6103372394999526598u64,
-                    2260769144946801819u64,
-                    17558555712096240714u64,
-                    86238009252260225u64,
-                ]),
-                BigInteger([
-                    7654346556195562408u64,
-                    12423619438014571610u64,
-                    1559167690612774004u64,
-                    6961876612858383543u64,
-                    78649475758060894u64,
-                    91575927075502152u64,
-                ]),
-                BigInteger([
-                    7913678050256125523u64,
-                    1892574523304798831u64,
-                    1530139276215679359u64,
-                    6779813773250650404u64,
-                    11755326097150966043u64,
-                    106937609669488881u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13115091288675141142u64,
-                    18257366027164423944u64,
-                    17875836176155504072u64,
-                    11491808306867519409u64,
-                    5831276806383672397u64,
-                    34269216693391914u64,
-                ]),
-                BigInteger([
-                    16432515152238295177u64,
-                    13292275444687371272u64,
-                    13907682894217443091u64,
-                    1198034790685968379u64,
-                    16848392011165317375u64,
-                    67785448535579882u64,
-                ]),
-                BigInteger([
-                    1346011648288410635u64,
-                    877797523396073735u64,
-                    18233008031427045130u64,
-                    13367032159718341211u64,
-                    2602401377649734317u64,
-                    114996646833539594u64,
-                ]),
-                BigInteger([
-                    4769624612079841798u64,
-                    10976525291571824332u64,
-                    11596044509159197u64,
-                    11949635076447886655u64,
-                    14937144899785248624u64,
-                    75287735446393722u64,
-                ]),
-                BigInteger([
-                    6740864387435705913u64,
-                    5934228049133751015u64,
-                    684216907646302128u64,
-                    8877226278368641608u64,
-                    17085456430270675485u64,
-                    114823291025094199u64,
-                ]),
-                BigInteger([
-                    10369178277406343527u64,
-                    9936449709259696701u64,
-                    15542413180188294351u64,
-                    1442194788541271265u64,
-                    7775535758402084332u64,
-                    83066515946337907u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15227694951756890012u64,
-                    8481105859103733692u64,
-                    6510926667914553882u64,
-                    18224501113870205632u64,
-                    7926341926003929385u64,
-                    42688980757215019u64,
-                ]),
-                BigInteger([
-                    13590840533215858795u64,
-                    4648467439614147890u64,
-                    16328057817751540747u64,
-                    4490891353352409265u64,
-                    8896407671498613133u64,
-                    71513269326880208u64,
-                ]),
-                BigInteger([
-                    828908778348114456u64,
-                    17165946795499883789u64,
-                    11913624597112416149u64,
-                    15807521301077649127u64,
-                    5255169734740056111u64,
-                    96320754630192202u64,
-                ]),
-                BigInteger([
-                    7218579317009756168u64,
-                    6650244283180069329u64,
-                    2113389324337778511u64,
-                    2683089752337391439u64,
-                    9205272720414514235u64,
-                    68843574951860085u64,
-                ]),
-                BigInteger([
-                    8148666532742076259u64,
-                    16021484132134520774u64,
-                    5793787636024635452u64,
-                    13468833014152788961u64,
-                    1857183407490961410u64,
-                    76558493035802073u64,
-                ]),
-                BigInteger([
-                    10142177110148348323u64,
-                    16586919164228992524u64,
-                    17322887827154778383u64,
-                    15410633314203439778u64,
-                    3003490971554702442u64,
-                    65034737895493986u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    560165746138899517u64,
-                    12905424935765364740u64,
-                    14046127609688109034u64,
-                    17435444188056461756u64,
-                    10464158142442019072u64,
-                    88683906330394224u64,
-                ]),
-                BigInteger([
-                    6846786480453736789u64,
-                    16202040516675364526u64,
-                    1855012993947007602u64,
-                    7700725692693237841u64,
-                    5169089414707151790u64,
-                    80728713710186551u64,
-                ]),
-                BigInteger([
-                    15778728407522712879u64,
-                    18077583048194577003u64,
-                    13590611612010230745u64,
-                    17673875178472807422u64,
-                    17550705505804489008u64,
-                    87659421734931687u64,
-                ]),
-                BigInteger([
-                    9124687118940858968u64,
-                    7349692434540223145u64,
-                    15536863667207224288u64,
-                    2854139980754555101u64,
-                    4922227713190219660u64,
-                    109304384977277339u64,
-                ]),
-                BigInteger([
-                    350876153939275678u64,
-                    1097801962402953076u64,
-                    12588025562595841489u64,
-                    16267917969378415535u64,
-                    223631094170006760u64,
-                    46242136759483279u64,
-                ]),
-                BigInteger([
-                    48303243313305078u64,
-                    951509244651065975u64,
-                    3208567703213448020u64,
-                    4855783322952881102u64,
-                    66539442671036006u64,
-                    112146924605519504u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    2024974088330169639u64,
-                    6960588190778747033u64,
-                    10296435947692986558u64,
-                    2513194546564761202u64,
-                    8921999940252473991u64,
-                    119112552061346743u64,
-                ]),
-                BigInteger([
-                    10668989006806792758u64,
-                    8494484803731518395u64,
-                    4825610467820802536u64,
-                    15935780045255564027u64,
-                    2547799808604474481u64,
-                    106171086400643701u64,
-                ]),
-                BigInteger([
-                    1131973652580734769u64,
-                    298713251806232244u64,
-                    13885067284284606061u64,
-                    9984167337755913598u64,
-                    4049384804105003309u64,
-                    51425826642076000u64,
-                ]),
-                BigInteger([
-                    5634471720490219270u64,
-                    265235231775514822u64,
-                    10557870288890820406u64,
-                    7106126588131712652u64,
-                    10522923564716433970u64,
-                    25700565054441257u64,
-                ]),
-                BigInteger([
-                    5226945117744557351u64,
-                    11775617294801798991u64,
-                    10054888334704637286u64,
-                    17344081541612858846u64,
-                    5850216765258033241u64,
-                    69790136537824753u64,
-                ]),
-                BigInteger([
-                    9443214507470739886u64,
-                    17906184033784087386u64,
-                    2150250544153289300u64,
-                    8322011381253160824u64,
-                    17719735585260109574u64,
-                    38156915098825258u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11318514374944487271u64,
-                    14215517055453346436u64,
-                    1915708458857775783u64,
-                    17246721568102550768u64,
-                    13149918260953049217u64,
-                    12476142209547943u64,
-                ]),
-                BigInteger([
-                    5526978034963216992u64,
-                    5155338577734565139u64,
-                    17984296465060679752u64,
-                    8710596136313347867u64,
-                    4659110112114483860u64,
-                    110810518242191510u64,
-                ]),
-                BigInteger([
-                    10820039803166155610u64,
-                    10352505285525469422u64,
-                    12733728763709483901u64,
-                    16503630281064632704u64,
-                    2830599147731409094u64,
-                    48028745019170368u64,
-                ]),
-                BigInteger([
-                    3795397360438391718u64,
-                    18343803595229595200u64,
-                    14392769966142880177u64,
-                    2771670296146098303u64,
-                    2710818959768802172u64,
-                    68060986362911995u64,
-                ]),
-                BigInteger([
-                    9473630995962668096u64,
-                    13456386418648274548u64,
-                    12787939010039666162u64,
-                    8941314873544361053u64,
-                    15549540154058514864u64,
-                    58516321487308231u64,
-                ]),
-                BigInteger([
-                    7129474628897774876u64,
-                    1113780349535287126u64,
-                    14191718886817613550u64,
-                    7192333107122600138u64,
-                    747010168489256008u64,
-                    18773875552590175u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10673808186073002646u64,
-                    4669370073341929089u64,
-                    2840046538332721714u64,
-                    13394282731336692014u64,
-                    6469685377202392381u64,
-                    71521911511855607u64,
-                ]),
-                BigInteger([
-                    8746547748994563525u64,
-                    12929409770569323224u64,
-                    12723280687242086387u64,
-                    4158324959527576559u64,
-                    17390683173883530972u64,
-                    22850502598119810u64,
-                ]),
-                BigInteger([
-                    10967425122276540787u64,
-                    8680080643954035446u64,
-                    12126024778410309344u64,
-                    4885686480808016312u64,
-                    11205747765254651695u64,
-                    90386509288176853u64,
-                ]),
-                BigInteger([
-                    11701565355999475820u64,
-                    4489174975491759166u64,
-                    351799061124237564u64,
-                    7505281211117338116u64,
-                    17455502374113944837u64,
-                    41003791459877268u64,
-                ]),
-                BigInteger([
-                    139860748051094420u64,
-                    16540040875989139825u64,
-                    11475591725491764816u64,
-                    16518571584987842362u64,
-                    704198503080686987u64,
-                    85893592037540829u64,
-                ]),
-                BigInteger([
-                    11866122694653508223u64,
-                    1389433325377028891u64,
-                    17562024336549929388u64,
-                    4077429589096498768u64,
-                    17640921970048429502u64,
-                    87568756637794795u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    3830656114944657726u64,
-                    3550496901929850991u64,
-                    8306808664198618519u64,
-                    2632186815484209338u64,
-                    15069393489126647013u64,
-                    74845142733732636u64,
-                ]),
-                BigInteger([
-                    16991534930243945132u64,
-                    10329056292127504632u64,
-                    7787025410518434147u64,
-                    13826280704921903462u64,
-                    12959309518967314554u64,
-                    66583971478398679u64,
-                ]),
-                BigInteger([
-                    13507237219229172629u64,
-                    14238882306582386582u64,
-                    16219396223285091015u64,
-                    15579514462082063870u64,
-                    5359632577856221432u64,
-                    104777172998614716u64,
-                ]),
-                BigInteger([
-                    13142961032792504944u64,
-                    6929754882852739884u64,
-                    5320722906295524955u64,
-                    15491493238646123690u64,
-                    7803417881976425466u64,
-                    46477902446654776u64,
-                ]),
-                BigInteger([
-                    9141567014724830695u64,
-                    12879855287759287446u64,
-                    16242921095931402104u64,
-                    2042843054217380909u64,
-                    2203035336894903664u64,
-                    40868264666075320u64,
-                ]),
-                BigInteger([
-                    1480745500128429634u64,
-                    13961744863516044129u64,
-                    12118447168906308510u64,
-                    3699723962291161648u64,
-                    9412357936289560979u64,
-                    54310484225543827u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9699505629509957272u64,
-                    2537384555802144915u64,
-                    9883961664815025863u64,
-                    6101306859095661538u64,
-                    5684676947279603187u64,
-                    44932666458475833u64,
-                ]),
-                BigInteger([
-                    11327079529104624622u64,
-                    9219328618171787906u64,
-                    12854112432033453868u64,
-                    9135739727151032118u64,
-                    16524220506095392546u64,
-                    116961851159540880u64,
-                ]),
-                BigInteger([
-                    6769315362057754714u64,
-                    4002222069724560014u64,
-                    3377775323453789436u64,
-                    1773356870919163516u64,
-                    4307330398589787371u64,
-                    97644042082168493u64,
-                ]),
-                BigInteger([
-                    514512359026720181u64,
-                    9340114930333091595u64,
-                    736527694911279112u64,
-                    11776769556800575060u64,
-                    3377268470772633115u64,
-                    38831088972889185u64,
-                ]),
-                BigInteger([
-                    17852168299203832971u64,
-                    17131458292572070814u64,
-                    18296764856576861280u64,
-                    13572087593406829375u64,
-                    4720259036750721233u64,
-                    100640355954970218u64,
-                ]),
-                BigInteger([
-                    17262451497294484783u64,
-                    1953211280703180165u64,
-                    14950429834782065902u64,
-                    4723221369452155574u64,
-                    17911156631116284244u64,
-                    40191023286553644u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7509748186817794153u64,
-                    2463859025712605953u64,
-                    8328010083615423326u64,
-                    11526975324346066846u64,
-                    16211452910287162103u64,
-                    78421559201684670u64,
-                ]),
-                BigInteger([
-                    17671325146401114684u64,
-                    14573039895519665843u64,
-                    3941055178348226539u64,
-                    8861537744137153867u64,
-                    5223637730408876268u64,
-                    4566034405500483u64,
-                ]),
-                BigInteger([
-                    4629225472937332188u64,
-                    3578896378834254489u64,
-                    12553325361552626126u64,
-                    3920328325899792877u64,
-                    8104359949108300027u64,
-                    58745317213015513u64,
-                ]),
-                BigInteger([
-                    7618251301349173811u64,
-                    12878061268957347480u64,
-                    2886586474199443090u64,
-                    9256071358621622136u64,
-                    9657098683199503678u64,
-                    42890073952829710u64,
-                ]),
-                BigInteger([
-                    75091711569533711u64,
-                    14022766248417425702u64,
-                    16038567256418206185u64,
-                    3925127086354360159u64,
-                    10650279157505822083u64,
-                    3909478804093072u64,
-                ]),
-                BigInteger([
-                    9349347284903087367u64,
-                    15371320509069772737u64,
-                    6174316099014534960u64,
-                    16451859628712945964u64,
-                    2084920061539730402u64,
-                    18494034015061394u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17929835321909667626u64,
-                    12473258772213679161u64,
-                    8492623266791737048u64,
-                    13029694330995511163u64,
-                    13545216823942559209u64,
-                    37915033906152571u64,
-                ]),
-                BigInteger([
-                    15649601087365516835u64,
-                    13641054843733620481u64,
-                    8410734565586002506u64,
-                    10313591569352247686u64,
-                    12945875080766577352u64,
-                    19714129388035582u64,
-                ]),
-                BigInteger([
-                    8805022286560276942u64,
-                    3769754444713851625u64,
-                    15057629703966069465u64,
-                    4758269809693422697u64,
-                    11587568129007366633u64,
-                    99640312561249534u64,
-                ]),
-                BigInteger([
-                    7757249323314651842u64,
-                    15739212500594207354u64,
-                    10662333632119093418u64,
-                    5160142214297524703u64,
-                    3787710559174301610u64,
-                    27831962748563643u64,
-                ]),
-                BigInteger([
-                    10854780788662527720u64,
-                    5851726559351514731u64,
-                    10479346479357751360u64,
-                    10401381262432634888u64,
-                    6576227874606614366u64,
-                    106778334838586027u64,
-                ]),
-                BigInteger([
-                    17862303817665452392u64,
-                    7461172608774624044u64,
-                    8881670400393079151u64,
-                    233327429971783414u64,
-                    17840028833731520731u64,
-                    114257465119618731u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17645675201335257450u64,
-                    5546390268809914693u64,
-                    15290991315624200616u64,
-                    4468803641386612483u64,
-                    13806632622419712000u64,
-                    70300547361224398u64,
-                ]),
-                BigInteger([
-                    17249422196467644090u64,
-                    1569113221578927196u64,
-                    2369916527301188728u64,
-                    18579085558429769u64,
-                    6906917772258592435u64,
-                    30176004374178728u64,
-                ]),
-                BigInteger([
-                    6794763502282839923u64,
-                    2590102661258384851u64,
-                    655067592025028495u64,
-                    17666661460401151825u64,
-                    17662660999390665417u64,
-                    80577518236058507u64,
-                ]),
-                BigInteger([
-                    7781063121255185285u64,
-                    6601988728454345087u64,
-                    13605485219669432607u64,
-                    10407853606484130852u64,
-                    11904893628538361017u64,
-                    4247864408667418u64,
-                ]),
-                BigInteger([
-                    17406317326404442159u64,
-                    16734188871608819203u64,
-                    6599830006566923046u64,
-                    13869422353238453113u64,
-                    9064682211386126442u64,
-                    102758178697512995u64,
-                ]),
-                BigInteger([
-                    702321241205277976u64,
-                    174677596734409192u64,
-                    8236266498454040742u64,
-                    15909456701653822775u64,
-                    10104779570771437393u64,
-                    61459299617400496u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11091325444926469341u64,
-                    1954330617996697762u64,
-                    17586541101812743620u64,
-                    6599373100807999282u64,
-                    3161498560193893168u64,
-                    75724219285420255u64,
-                ]),
-                BigInteger([
-                    3851721093469404304u64,
-                    4827477376038347933u64,
-                    15767297313034668993u64,
-                    15631295851742314519u64,
-                    205593358198554979u64,
-                    39515877119689295u64,
-                ]),
-                BigInteger([
-                    17036239007245149143u64,
-                    2637320901255580617u64,
-                    16518116959051156648u64,
-                    11206289807235764722u64,
-                    15152228587460503742u64,
-                    41153883920995958u64,
-                ]),
-                BigInteger([
-                    7266558567388608868u64,
-                    18057383811711051115u64,
-                    4363701656896503119u64,
-                    18374326966393663093u64,
-                    5181804501236785123u64,
-                    58494924776699549u64,
-                ]),
-                BigInteger([
-                    6753585019125995622u64,
-                    11548106437742134482u64,
-                    15027905422118230566u64,
-                    9455526202551679564u64,
-                    10912432944218045573u64,
-                    73016737855528830u64,
-                ]),
-                BigInteger([
-                    17742543712834188319u64,
-                    17424047377242363463u64,
-                    13680262370924430861u64,
-                    11160312001904155567u64,
-                    12438397437339112175u64,
-                    31591807388262862u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5457936910175807110u64,
-                    12497486681643392761u64,
-                    7865634628154480293u64,
-                    17326101995485555456u64,
-                    16648737455278112469u64,
-                    79500332713695146u64,
-                ]),
-                BigInteger([
-                    15314587806930641279u64,
-                    17558764871760989840u64,
-                    7880218940988402328u64,
-                    1262271688694514164u64,
-                    6038818652394123567u64,
-                    46466314403068891u64,
-                ]),
-                BigInteger([
-                    5921784663001782438u64,
-                    9369945334605687345u64,
-                    11056446367446224146u64,
-                    5814255048151867103u64,
-                    12879820064891158953u64,
-                    114138340185606268u64,
-                ]),
-                BigInteger([
-                    13799635377287381207u64,
-                    575707533697950485u64,
-                    17316012649447321232u64,
-                    4894668006492970158u64,
-                    17370575716391666904u64,
-                    18694744304444297u64,
-                ]),
-                BigInteger([
-                    1814015847930046531u64,
-                    2905155855231923046u64,
-                    14451637258223430079u64,
-                    14035834869836687817u64,
-                    6746949040847249198u64,
-                    50929067402065128u64,
-                ]),
-                BigInteger([
-                    2872592740234494923u64,
-                    419693497019970637u64,
-                    14826427487951429775u64,
-                    16287268254277884250u64,
-                    4782397724035030671u64,
-                    34899701795145297u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13282761906397681536u64,
-                    16633138445477843250u64,
-                    13480242868358597229u64,
-                    17883472374911060452u64,
-                    4794189659072535604u64,
-                    57986407518513751u64,
-                ]),
-                BigInteger([
-                    4214770370965948339u64,
-                    5349590620929499856u64,
-                    12974043888727644576u64,
-                    12666196619487838730u64,
-                    3195364699253271360u64,
-                    35188400662866922u64,
-                ]),
-                BigInteger([
-                    15447426686182975568u64,
-                    8887988313335601218u64,
-                    7237451880817648721u64,
-                    3078642798045757371u64,
-                    16387839278214796295u64,
-                    79088261372891542u64,
-                ]),
-                BigInteger([
-                    15540408119294881652u64,
-                    609901532216424972u64,
-                    12828204955120716725u64,
-                    11591367251523089761u64,
-                    14082362155892848113u64,
-                    61183335227259619u64,
-                ]),
-                BigInteger([
-                    5594132336848414038u64,
-                    4882079523812120396u64,
-                    1020287236103600328u64,
-                    17040828648449263869u64,
-                    3638958779584930184u64,
-                    40008170600003568u64,
-                ]),
-                BigInteger([
-                    4384603387712835823u64,
-                    1078378685818099197u64,
-                    5425524369624158179u64,
-                    1154426044814790252u64,
-                    10775209337406293686u64,
-                    36399250904864319u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12748774456133493814u64,
-                    13181071917414971786u64,
-                    831078993834333150u64,
-                    4263955783804921726u64,
-                    8790656482824756669u64,
-                    120366690133289725u64,
-                ]),
-                BigInteger([
-                    18219239282432589494u64,
-                    16335942865061252205u64,
-                    12055259617882689460u64,
-                    14227765140390854101u64,
-                    9661937215856288590u64,
-                    5460202732288092u64,
-                ]),
-                BigInteger([
-                    12511606184996645452u64,
-                    6920710698484122369u64,
-                    3729274195778083677u64,
-                    5539743322465627224u64,
-                    7514726774444896675u64,
-                    69523763750383505u64,
-                ]),
-                BigInteger([
-                    17853166922375347182u64,
-                    5268815692650732193u64,
-                    11723284270503624891u64,
-                    14654047292971547688u64,
-                    2655403803082996503u64,
-                    27744209596026639u64,
-                ]),
-                BigInteger([
-                    9622471561754178595u64,
-                    15680866139836157605u64,
-                    6552653415366829289u64,
-                    15103284089728269792u64,
-                    3853605166789112868u64,
-                    115759741585188525u64,
-                ]),
-                BigInteger([
-                    5450208768230030146u64,
-                    2218575170134689184u64,
-                    2101498276693221542u64,
-                    4305776179279155896u64,
-                    4995434645872460870u64,
-                    112599206739671610u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    970328345319316410u64,
-                    12272079400885639735u64,
-                    17928951178698977705u64,
-                    4228433360639648766u64,
-                    393311164376071764u64,
-                    118681217653064341u64,
-                ]),
-                BigInteger([
-                    14652233547170373105u64,
-                    58412988517237326u64,
-                    7541258268681105562u64,
-                    4844898967326518651u64,
-                    10083178295368602637u64,
-                    56193683845719107u64,
-                ]),
-                BigInteger([
-                    8783408790081654906u64,
-                    14643452028067236987u64,
-                    14619348173574615376u64,
-                    8731912244950609793u64,
-                    5062834136999547074u64,
-                    44222411021090164u64,
-                ]),
-                BigInteger([
-                    18169199469879341275u64,
-                    3033149246889360388u64,
-                    5454496401691606264u64,
-                    6963310664564637185u64,
-                    11919164228565335513u64,
-                    65395696333157796u64,
-                ]),
-                BigInteger([
-                    4029291800299371u64,
-                    4278215751989589970u64,
-                    14822321722445115710u64,
-                    2621008317861993819u64,
-                    14668717525124019726u64,
-                    57516935423791933u64,
-                ]),
-                BigInteger([
-                    197768578715337180u64,
-                    5052068055808772752u64,
-                    2965299499062423051u64,
-                    12635355758315671855u64,
-                    6947865191702489382u64,
-                    76998371543311962u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5849960355471163690u64,
-                    16182757997221167683u64,
-                    7327347183751292166u64,
-                    12770772464071330649u64,
-                    3745670479577066837u64,
-                    11133262063266993u64,
-                ]),
-                BigInteger([
-                    1289617846932682653u64,
-                    1346277838968589659u64,
-                    9228524475026360736u64,
-                    6916940794617097239u64,
-                    9497566510621041407u64,
-                    87210182468886959u64,
-                ]),
-                BigInteger([
-                    8884656283776186005u64,
-                    12218487646480112591u64,
-                    7004935934495679117u64,
-                    10583935671063093969u64,
-                    7371482733718592289u64,
-                    29157003631452032u64,
-                ]),
-                BigInteger([
-                    762512419084011484u64,
-                    16265852903709597806u64,
-                    9974200262702740977u64,
-                    2892141962400465748u64,
-                    17248416821001253024u64,
-                    28349191709717555u64,
-                ]),
-                BigInteger([
-                    17097287496361872305u64,
-                    1086923044721199905u64,
-                    4593752174972570884u64,
-                    11975514846961049213u64,
-                    7164034990262059207u64,
-                    32354398187463632u64,
-                ]),
-                BigInteger([
-                    186100553123421447u64,
-                    3094250628005602064u64,
-                    2077687414504243919u64,
-                    6316078107287679661u64,
-                    15743877400261880445u64,
-                    46815462324938430u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15292841544991990042u64,
-                    4443767235495310796u64,
-                    17259312898332165841u64,
-                    17038221786315716149u64,
-                    12057874238596238537u64,
-                    56559967817568294u64,
-                ]),
-                BigInteger([
-                    17283723388500428272u64,
-                    16050490143942057022u64,
-                    6408625137866756989u64,
-                    17032389995646222354u64,
-                    3498709794843555858u64,
-                    92377961203065602u64,
-                ]),
-                BigInteger([
-                    13165651965080447015u64,
-                    17059172002731124155u64,
-                    10264299365965919634u64,
-                    14716887218657368287u64,
-                    11606172742968275958u64,
-                    113391206340071666u64,
-                ]),
-                BigInteger([
-                    9550858920261167601u64,
-                    16355830605505070118u64,
-                    6083912077118452968u64,
-                    6843790107170826059u64,
-                    10265506287183310024u64,
-                    62895253896173754u64,
-                ]),
-                BigInteger([
-                    6826578574102884421u64,
-                    6336806088967577891u64,
-                    15986875935733107286u64,
-                    15836008671251503428u64,
-                    6220616173848699112u64,
-                    94674339238938328u64,
-                ]),
-                BigInteger([
-                    15023265876074359786u64,
-                    11621245906705162752u64,
-                    17902290741157664746u64,
-                    9372712200167183281u64,
-                    7569957659979869246u64,
-                    20284968773228683u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15270116171747673210u64,
-                    4808259978468336395u64,
-                    4865044306156691029u64,
-                    9321722132082922745u64,
-                    10389874287931070256u64,
-                    51268656321543247u64,
-                ]),
-                BigInteger([
-                    355103009818523859u64,
-                    4594922118806871547u64,
-                    12466643597417193129u64,
-                    12914511709679064302u64,
-                    10268170072449990816u64,
-                    78578632638859191u64,
-                ]),
-                BigInteger([
-                    15644059463428340926u64,
-                    9497674653805703209u64,
-                    17792911274427547716u64,
-                    7919530927631576464u64,
-                    10537623285520395064u64,
-                    63522113613093702u64,
-                ]),
-                BigInteger([
-                    587291500266548453u64,
-                    5274526854260947890u64,
-                    16451783495537740735u64,
-                    17473914173154289364u64,
-                    12330513938772822275u64,
-                    27970913768137416u64,
-                ]),
-                BigInteger([
-                    4747995156899288168u64,
-                    5548638608523511904u64,
-                    16589441574720950303u64,
-                    982658095169505822u64,
-                    18433015952303741901u64,
-                    30529892543857344u64,
-                ]),
-                BigInteger([
-                    6295227439127113601u64,
-                    9664497781554724382u64,
-                    10854711904748821967u64,
-                    13647854276002576026u64,
-                    5559503584373560794u64,
-                    95030925631420333u64,
-                ]),
-            ],
-        ];
-        let mut mds = Vec::<Vec<Self>>::new();
-        for row in MDS_W_HAT_MONT.iter() {
-            mds.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        mds
-    }
-
-    fn get_mds_0_0_const() -> Self {
-      const M_0_0_MONT: BigInteger = BigInteger([
-          18176648193947600990u64,
-          1011703635580988350u64,
-          11606455264118807696u64,
-          7348521216039876994u64,
-          4402097446447690439u64,
-          30768671281273630u64,
-      ]);
-      let mds = Self::from_repr_raw(M_0_0_MONT);
-      mds
-    }
-
-    fn get_ark_opt_consts() -> Vec<Vec<Self>> {
-        const ARK_OPT_MONT: [[BigInteger; 7]; 65] = [
-            [
-                BigInteger([
-                    1504146516004890299u64,
-                    5191845523978117041u64,
-                    2697835982163585511u64,
-                    8173143837212697743u64,
-                    2798146286342024329u64,
-                    76443817350796328u64,
-                ]),
-                BigInteger([
-                    5389994602608877828u64,
-                    9786295965899834471u64,
-                    14957792512719787728u64,
-                    16442825313190552395u64,
-                    15941852526598907249u64,
-                    78329317172234101u64,
-                ]),
-                BigInteger([
-                    6204055390205055859u64,
-                    15055613746620039876u64,
-                    17369260415440106951u64,
-                    7601135635779353882u64,
-                    4557033530050655267u64,
-                    117925862831899491u64,
-                ]),
-                BigInteger([
-                    11960673606777769562u64,
-                    11989955156868188018u64,
-                    998228376274877163u64,
-                    10589489859353568014u64,
-                    6646726193831319281u64,
-                    34426068033518842u64,
-                ]),
-                BigInteger([
-                    7880502611372126655u64,
-                    5636840868346524232u64,
-                    15496967069559444662u64,
-                    12535640858582664989u64,
-                    13484168960063542742u64,
-                    106476553184112598u64,
-                ]),
-                BigInteger([
-                    15790554118372822977u64,
-                    1801520977369158637u64,
-                    8445788818144994194u64,
-                    11850592177218532653u64,
-                    9934317174198538860u64,
-                    115891036379791490u64,
-                ]),
-                BigInteger([
-                    14964375046498872717u64,
-                    15080611439012712337u64,
-                    16268406582264335352u64,
-                    4761710220731009267u64,
-                    3804541201341275552u64,
-                    72092258836262735u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7700301302382995101u64,
-                    15126980304804008921u64,
-                    14360672491001394288u64,
-                    5105329412407803168u64,
-                    15792704004987533577u64,
-                    85989405804326520u64,
-                ]),
-                BigInteger([
-                    972703151959427025u64,
-                    10768378208460351946u64,
-                    3951240123539262067u64,
-                    5848639655946443911u64,
-                    17458605183770260574u64,
-                    2539123042456015u64,
-                ]),
-                BigInteger([
-                    3237584163711619651u64,
-                    6892495680471421254u64,
-                    17790494676459930000u64,
-                    7188732890889265959u64,
-                    11943721334025845286u64,
-                    6259053514385785u64,
-                ]),
-                BigInteger([
-                    11445062139170095723u64,
-                    14770514971862235188u64,
-                    12070499158436078399u64,
-                    13065314973508018206u64,
-                    950577118507124527u64,
-                    64768442763030083u64,
-                ]),
-                BigInteger([
-                    2110338812687686495u64,
-                    3345174185075402195u64,
-                    5996808469636743401u64,
-                    9513502111197295967u64,
-                    12919850211076713012u64,
-                    77912006441795704u64,
-                ]),
-                BigInteger([
-                    14694324360987437841u64,
-                    12620412878306767361u64,
-                    762463711377916866u64,
-                    7500493642736892438u64,
-                    5507778487057498949u64,
-                    54504069351057351u64,
-                ]),
-                BigInteger([
-                    6468263337333553411u64,
-                    2511225626106093114u64,
-                    2722930689520222186u64,
-                    9005757845385948714u64,
-                    2133876554448820761u64,
-                    57905823377493050u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1638022881081861464u64,
-                    17347628339588340053u64,
-                    2877129804039829458u64,
-                    11136934192903453179u64,
-                    5243353842829040136u64,
-                    4147317391199996u64,
-                ]),
-                BigInteger([
-                    4132395126339299202u64,
-                    16336005127466142033u64,
-                    10586728948984679074u64,
-                    757625854250483881u64,
-                    13606448047433387004u64,
-                    36102016139837512u64,
-                ]),
-                BigInteger([
-                    13499941607855748403u64,
-                    13843465104755221755u64,
-                    1011167409411883667u64,
-                    8407973351399316249u64,
-                    689069071606772946u64,
-                    61675625376267808u64,
-                ]),
-                BigInteger([
-                    3322566255628090715u64,
-                    11431775112935668238u64,
-                    4990876137355815178u64,
-                    13548742375506061146u64,
-                    12192507874822243150u64,
-                    38426529269124577u64,
-                ]),
-                BigInteger([
-                    17519516578862378157u64,
-                    3496012659602892981u64,
-                    14924232906393981u64,
-                    6605731533832306392u64,
-                    13268447572107787334u64,
-                    18063194999411246u64,
-                ]),
-                BigInteger([
-                    8226889812996148980u64,
-                    270023081147009326u64,
-                    3826842023129680968u64,
-                    15366143308419252048u64,
-                    5510960207954878343u64,
-                    104381407059064295u64,
-                ]),
-                BigInteger([
-                    15380462820515678025u64,
-                    8239176710724119024u64,
-                    11852875838523362747u64,
-                    13150936481503395781u64,
-                    6884117919269476861u64,
-                    28641595223038750u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10386704902707958136u64,
-                    2708654219289837508u64,
-                    7399526516761831514u64,
-                    13700990428262287161u64,
-                    12962145523599496767u64,
-                    20474056460069716u64,
-                ]),
-                BigInteger([
-                    59985485941703479u64,
-                    17754006240266545295u64,
-                    11700801270633295858u64,
-                    4835722503637445646u64,
-                    14231200945523592160u64,
-                    83037278624848656u64,
-                ]),
-                BigInteger([
-                    4907707277946862151u64,
-                    11596959790358891684u64,
-                    8623820269642361603u64,
-                    1096654450014182670u64,
-                    4779660082823367318u64,
-                    43028617899893314u64,
-                ]),
-                BigInteger([
-                    12578669374779617092u64,
-                    16915692927781744988u64,
-                    4133593824670948945u64,
-                    1163883362174304015u64,
-                    3344928139234752404u64,
-                    95543052666035001u64,
-                ]),
-                BigInteger([
-                    1421028894434782273u64,
-                    18193063919519497419u64,
-                    8345125786328622331u64,
-                    14120841744427168241u64,
-                    1679610735817950829u64,
-                    69715860550769522u64,
-                ]),
-                BigInteger([
-                    2883319663066708270u64,
-                    14103971759157160725u64,
-                    13106035655786933233u64,
-                    12613561050078946903u64,
-                    3312263043591349136u64,
-                    115764107813575407u64,
-                ]),
-                BigInteger([
-                    5024449258477057361u64,
-                    3290561270809477657u64,
-                    16040956910956811590u64,
-                    13910431715309071554u64,
-                    13669422425632746417u64,
-                    88958130386103986u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    887394296622674955u64,
-                    14714726303286049367u64,
-                    6219013988541403275u64,
-                    15282623554174970743u64,
-                    16228231447938349656u64,
-                    7528095540163340u64,
-                ]),
-                BigInteger([
-                    2160083754533552619u64,
-                    16741329364901127122u64,
-                    9867633380597496350u64,
-                    6058292013853856354u64,
-                    4613333158920658071u64,
-                    24735824825929602u64,
-                ]),
-                BigInteger([
-                    8094415230791985193u64,
-                    1990618080675472004u64,
-                    4529549159960833346u64,
-                    1425044921044166726u64,
-                    16656000630612688093u64,
-                    53537039747051722u64,
-                ]),
-                BigInteger([
-                    1475199573251723396u64,
-                    2380864102126997838u64,
-                    10579371169460441396u64,
-                    2661337347246953946u64,
-                    8530068860750112755u64,
-                    104204151270398995u64,
-                ]),
-                BigInteger([
-                    2965076428377993184u64,
-                    3400735738485849489u64,
-                    16162762485142629134u64,
-                    11324320208355449434u64,
-                    4600059987442324206u64,
-                    116773618555590115u64,
-                ]),
-                BigInteger([
-                    1597364273040893192u64,
-                    15436611530337715747u64,
-                    2693549524109638062u64,
-                    3131953474115581314u64,
-                    12095082892875872860u64,
-                    94097491341182863u64,
-                ]),
-                BigInteger([
-                    7628464529150203043u64,
-                    14004366579298467728u64,
-                    16447116235062784506u64,
-                    17158178561767926127u64,
-                    5342138507837430154u64,
-                    63764578910941015u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17007856699403975296u64,
-                    10704178941963120757u64,
-                    15576372621451752063u64,
-                    17256433298782223487u64,
-                    8699727277571349838u64,
-                    39053869237189203u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1051200883729462830u64,
-                    1555586704098763461u64,
-                    4276127031863067006u64,
-                    18411705940355996543u64,
-                    11715803300420523864u64,
-                    77802282453507579u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    4703832231007120546u64,
-                    6645161809085883957u64,
-                    2110935388977467337u64,
-                    15990825804612318114u64,
-                    5585451467304387036u64,
-                    30944903819353863u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7199305121264731380u64,
-                    1761601530398193342u64,
-                    8214259719195074036u64,
-                    3615241641769524352u64,
-                    12378745090541986031u64,
-                    81334855020882791u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1109854047496946997u64,
-                    1765516523766329844u64,
-                    8911407383489628603u64,
-                    698368743201002728u64,
-                    13488543564009766246u64,
-                    30873228384699026u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1575792395695291061u64,
-                    7246065455654545816u64,
-                    10905970937849936724u64,
-                    16033140584045326842u64,
-                    9721433457721188722u64,
-                    86649518706185739u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7525470572563940787u64,
-                    15221860250386366376u64,
-                    5499068572472015789u64,
-                    554434517941478879u64,
-                    14384402844596697167u64,
-                    9575954441949425u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9085639492976964631u64,
-                    5607334784353781166u64,
-                    16518538834524918624u64,
-                    18225370799892506510u64,
-                    7778442163511264777u64,
-                    82180369425208138u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13455572021531065574u64,
-                    8253845899582537955u64,
-                    4339439275677385696u64,
-                    13194786794520669717u64,
-                    6450550412705695119u64,
-                    17560070436700186u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5024203223584886468u64,
-                    11399355114737545898u64,
-                    3450585875318165729u64,
-                    17597654550420358816u64,
-                    2778743316554308576u64,
-                    69602725056554772u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13313185716935682728u64,
-                    11891133000778450927u64,
-                    3378186293427128429u64,
-                    2823119787972789402u64,
-                    5071668586817228884u64,
-                    67517080386806571u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10819879294018527454u64,
-                    17374841449828356173u64,
-                    3064412218451267296u64,
-                    5047424053528240408u64,
-                    12616844521434586016u64,
-                    56395768740045078u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6327825243206870435u64,
-                    4756991085034212322u64,
-                    18119666400991491287u64,
-                    15699500533253875033u64,
-                    12608658474402495912u64,
-                    71553557883544907u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14255287695126767922u64,
-                    16140576701810541015u64,
-                    4933439871648095427u64,
-                    14556891054242548990u64,
-                    11365611020298493977u64,
-                    12746495110427891u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1234462065377874579u64,
-                    4483140035086852789u64,
-                    6484090898516270498u64,
-                    16143728563622203969u64,
-                    13755999984721397988u64,
-                    73118665525525278u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1345368446053560694u64,
-                    5853927162055679511u64,
-                    5699171474661549863u64,
-                    17081149421207438796u64,
-                    10826788098617571792u64,
-                    35741099401035337u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14197840425649813578u64,
-                    11578706710446417283u64,
-                    1701838491692508603u64,
-                    843382193568105324u64,
-                    8875504330236427229u64,
-                    91884132793215390u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16005323818061897213u64,
-                    6970576274439945730u64,
-                    18378820507336873626u64,
-                    14181195114242178272u64,
-                    4425925240295402223u64,
-                    96891063436338512u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    8090240669693559529u64,
-                    8611585379249288436u64,
-                    18213317468795982258u64,
-                    13208457517754307021u64,
-                    13635595810273789951u64,
-                    11543733712283682u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15644906205656136148u64,
-                    12953077638335956528u64,
-                    11470059465409650073u64,
-                    18365291942686679759u64,
-                    16241842400961871422u64,
-                    45132748450815988u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14486055720445538706u64,
-                    5282217057926530095u64,
-                    5692662334435342203u64,
-                    1918843634821578460u64,
-                    14711305303478492186u64,
-                    85566895445898173u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9033659847012683769u64,
-                    3410708797178117859u64,
-                    10128150312076438065u64,
-                    18231132357190273049u64,
-                    3253827985362393711u64,
-                    24258941689715518u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12640196910957596329u64,
-                    5363083672088254160u64,
-                    16277747229868995012u64,
-                    17822801779820628033u64,
-                    6416163178158352852u64,
-                    55898720136228138u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17429325791683436504u64,
-                    16258397818546780676u64,
-                    2898526543334892300u64,
-                    12200301524674065115u64,
-                    1899849002399324913u64,
-                    48852909262770804u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10660613736356027464u64,
-                    13838831997492965958u64,
-                    8691558280130471897u64,
-                    1520048961238514515u64,
-                    2568175771350719285u64,
-                    21092817565136577u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9784438113895079421u64,
-                    10167209982286820343u64,
-                    12197991953157705405u64,
-                    8101602912908096047u64,
-                    15189130987179224221u64,
-                    51172807052171258u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7244324815381217626u64,
-                    15180123998802387821u64,
-                    9294852100208445685u64,
-                    17521713269363085276u64,
-                    14104495300620925818u64,
-                    3669875433812425u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6322537478356267449u64,
-                    18188573323003731246u64,
-                    10367762352418190353u64,
-                    13379387928492369003u64,
-                    486670361995958233u64,
-                    72874002295029858u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15782352923694911503u64,
-                    7553602732601260685u64,
-                    6986486845388076597u64,
-                    4430343721440150717u64,
-                    6433904020860447962u64,
-                    13758397759392601u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1768498870888945213u64,
-                    1996072089060568514u64,
-                    18127188459994061158u64,
-                    7668184094823954725u64,
-                    5578942479950921328u64,
-                    102216532652358629u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9241923938771003956u64,
-                    182638214019833726u64,
-                    16352235977705296221u64,
-                    17169958181541599594u64,
-                    18332114252099425095u64,
-                    26208143522042298u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    18223641410686350611u64,
-                    3095853261263946956u64,
-                    12449407940223819351u64,
-                    17168829586104038224u64,
-                    139760823157289126u64,
-                    4173511999643880u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14190815742162896225u64,
-                    11032827490815658972u64,
-                    14642516363197580363u64,
-                    3565324014906857545u64,
-                    7018955970366679769u64,
-                    50807739324462237u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    5633905381845315141u64,
-                    11582643787630491851u64,
-                    11432218693594577585u64,
-                    16339832649855350258u64,
-                    16211722451541716591u64,
-                    90400849607278311u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1544384547093223397u64,
-                    12645815649796495992u64,
-                    15433819127706433694u64,
-                    17298904983857449990u64,
-                    5164921564114755641u64,
-                    55184198308942u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    17477833782268448262u64,
-                    16300182580105890052u64,
-                    8312155651133332666u64,
-                    11009821938852673089u64,
-                    14826810670897438890u64,
-                    36671498160264425u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    7150916642449722787u64,
-                    8876144612566167400u64,
-                    17265948481082032891u64,
-                    7566930790769743777u64,
-                    8081491680758305446u64,
-                    112831392347904069u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13190867159949008181u64,
-                    1936322209871106725u64,
-                    10703720168974783286u64,
-                    9931924262164550634u64,
-                    1827563850133826808u64,
-                    26811920201903833u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    15219440204999936367u64,
-                    125834323615473129u64,
-                    8154864233398402406u64,
-                    3763824161880490532u64,
-                    6261785308630209335u64,
-                    40932220481038532u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    299146665080940509u64,
-                    8060980503823695400u64,
-                    18328277830291518993u64,
-                    192539797127355039u64,
-                    16663719589703584968u64,
-                    45166366315524938u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    1671679902771113036u64,
-                    14286781066433662237u64,
-                    1957390908575053764u64,
-                    14941903461419865413u64,
-                    9341844813693682075u64,
-                    12299498226531820u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11878199697792597326u64,
-                    5291308865912917426u64,
-                    13046046291157566124u64,
-                    18298134206162036353u64,
-                    16716430938177879613u64,
-                    35779323684578111u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    13525315657311633854u64,
-                    3197538401269538165u64,
-                    3657976337581145523u64,
-                    6714129151317998095u64,
-                    18330377619035042421u64,
-                    32441473834915121u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16820540098253661004u64,
-                    5711970323985014512u64,
-                    7283148429837711517u64,
-                    11739445095070633593u64,
-                    16229885262363647453u64,
-                    110465410668416289u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14508127103777445539u64,
-                    14936840525815267838u64,
-                    2686041741135143946u64,
-                    9136983464300433616u64,
-                    9392871539434217836u64,
-                    23084132244079899u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11019355479387426749u64,
-                    7258888346270981367u64,
-                    4802584406632667027u64,
-                    4904967185930308304u64,
-                    17258644877414033689u64,
-                    9845018051420034u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9379829726459466911u64,
-                    6226358813187689147u64,
-                    5505112173415205610u64,
-                    8484170929909377439u64,
-                    5844547128625841548u64,
-                    32258984663371078u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9039213791669300705u64,
-                    5025936406242993508u64,
-                    5330990007747401512u64,
-                    4809918306998314774u64,
-                    6321191580904087972u64,
-                    34305124397094354u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    6020709077010024319u64,
-                    17428303063415149908u64,
-                    17647825622564911937u64,
-                    7154813539305162128u64,
-                    16248700816030500212u64,
-                    36336734292453235u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    11884129881522661355u64,
-                    2074447804097745656u64,
-                    13707547874429167607u64,
-                    7866030626063144167u64,
-                    978132312081786807u64,
-                    46543455660089290u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14600373112593299280u64,
-                    9483962949529685220u64,
-                    2135896587827563627u64,
-                    8971095607927073302u64,
-                    6741041394390877578u64,
-                    50825059765323672u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    603321354674418615u64,
-                    10744103471706143926u64,
-                    8016129327340381091u64,
-                    6929766277933044949u64,
-                    12928439277877308810u64,
-                    104861861113800822u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    12718207953686605709u64,
-                    12609632079311854708u64,
-                    15644230904107621355u64,
-                    8050345466764692286u64,
-                    5238884917690062126u64,
-                    49701598265077153u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10614513020485060977u64,
-                    7635948793041545134u64,
-                    10560328438219413061u64,
-                    16870344630315537440u64,
-                    730981644128325217u64,
-                    35997015478967545u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    607767669826446530u64,
-                    10842589837084223880u64,
-                    6203686682461195032u64,
-                    12861844554620561288u64,
-                    8555006614660741638u64,
-                    1367137918428084u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    10744249112661037454u64,
-                    15285892869809554708u64,
-                    68352141479601345u64,
-                    1266037710476531788u64,
-                    17833236068062312611u64,
-                    105206559102727025u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-                BigInteger([
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                    0u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    9629624410147464445u64,
-                    5375751808908842382u64,
-                    10766944429171285756u64,
-                    16924766044221334062u64,
-                    17930231856038839603u64,
-                    7399558664301194u64,
-                ]),
-                BigInteger([
-                    15976453535216284143u64,
-                    12104680141586616492u64,
-                    5506836328202543807u64,
-                    12614113875140169674u64,
-                    11993535173273120001u64,
-                    102388634419228646u64,
-                ]),
-                BigInteger([
-                    17336760898023953964u64,
-                    10794679403484672854u64,
-                    7126702110379145912u64,
-                    14980196838395100752u64,
-                    8603395872459561280u64,
-                    89352928607311741u64,
-                ]),
-                BigInteger([
-                    8172674102970413069u64,
-                    14285677965126947155u64,
-                    10634017298480619506u64,
-                    5619901030475938429u64,
-                    7343521958162265672u64,
-                    76688603173333153u64,
-                ]),
-                BigInteger([
-                    7128711173157411091u64,
-                    7095791948825986198u64,
-                    14948077404608430854u64,
-                    13639211779059794675u64,
-                    15213793678976725142u64,
-                    22194613544470408u64,
-                ]),
-                BigInteger([
-                    10048509786593036476u64,
-                    14810622426540609950u64,
-                    17851216145433368853u64,
-                    15899373311478494698u64,
-                    10658741917454649632u64,
-                    116289338892654372u64,
-                ]),
-                BigInteger([
-                    13153315262663195485u64,
-                    13331167978525689261u64,
-                    17354123031605594465u64,
-                    11998173807683346993u64,
-                    17237847269570776197u64,
-                    67593957550590707u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    16335183828315804946u64,
-                    15841779921026534358u64,
-                    3423408387059336295u64,
-                    4245762953662105499u64,
-                    742390488080658034u64,
-                    1227074472987953u64,
-                ]),
-                BigInteger([
-                    4921103127895474240u64,
-                    10124643882828773967u64,
-                    5132959708188850812u64,
-                    15306571106660832540u64,
-                    12235402498374291069u64,
-                    38762081562517282u64,
-                ]),
-                BigInteger([
-                    10929478945449841785u64,
-                    1605689253707320320u64,
-                    3407123937692930394u64,
-                    9480667846696798417u64,
-                    6157686005962423313u64,
-                    50323895904637977u64,
-                ]),
-                BigInteger([
-                    9623034554481263322u64,
-                    1102546741864826323u64,
-                    7346546707242776579u64,
-                    15394106080041862574u64,
-                    16908576454861581289u64,
-                    27219533154890964u64,
-                ]),
-                BigInteger([
-                    4412589134135808401u64,
-                    17874921843293230044u64,
-                    1947728802686576567u64,
-                    12834682297200342889u64,
-                    10607305598736726750u64,
-                    20431175958334920u64,
-                ]),
-                BigInteger([
-                    945320920214508917u64,
-                    15367779605215370787u64,
-                    8976245667267570313u64,
-                    6127115984038969250u64,
-                    9065215817540841972u64,
-                    66244310988556561u64,
-                ]),
-                BigInteger([
-                    9098198007425634209u64,
-                    18149797193699629057u64,
-                    15945452781425757478u64,
-                    10159332239838465307u64,
-                    8366449037861738114u64,
-                    42396643992023261u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    237503213390186892u64,
-                    17637555558680675663u64,
-                    12821357746078606476u64,
-                    8317613088941066321u64,
-                    1242739732735957756u64,
-                    52383963048884146u64,
-                ]),
-                BigInteger([
-                    210323306148131243u64,
-                    10657212579791263196u64,
-                    3233730566199720152u64,
-                    608805217214113763u64,
-                    5046725873431495572u64,
-                    9929836019172775u64,
-                ]),
-                BigInteger([
-                    1753931101453161290u64,
-                    3453234781589233999u64,
-                    3060749495390633883u64,
-                    16378110900914173189u64,
-                    10091226318988332042u64,
-                    24414926135593405u64,
-                ]),
-                BigInteger([
-                    6030668957188050364u64,
-                    6924414960604047616u64,
-                    8350220393755413661u64,
-                    14416070097663659017u64,
-                    18254480847350273614u64,
-                    59442499235686654u64,
-                ]),
-                BigInteger([
-                    16391166656428868152u64,
-                    7933951912476542274u64,
-                    17241131211642620354u64,
-                    10616281951813051456u64,
-                    15270866962496671907u64,
-                    42693135276942131u64,
-                ]),
-                BigInteger([
-                    15908647422108589002u64,
-                    7871581937849160205u64,
-                    11485391166707568454u64,
-                    8967890674443187949u64,
-                    2953704419511027860u64,
-                    83708094429939551u64,
-                ]),
-                BigInteger([
-                    957752937437919067u64,
-                    3691868197671849193u64,
-                    7516536878432022244u64,
-                    17395842416999095332u64,
-                    17493251045788477486u64,
-                    90832467393483731u64,
-                ]),
-            ],
-            [
-                BigInteger([
-                    14817460926672108382u64,
-                    15161533026653973158u64,
-                    5664147848673190108u64,
-                    9370956485840954820u64,
-                    7764123665377263195u64,
-                    108796662266842505u64,
-                ]),
-                BigInteger([
-                    10730352730723513847u64,
-                    970193137864205454u64,
-                    15736210237559180410u64,
-                    2497225475880021619u64,
-                    5327366169697658891u64,
-                    102377637754239488u64,
-                ]),
-                BigInteger([
-                    18336643944562640289u64,
-                    2821060908165335341u64,
-                    5961317955036205193u64,
-                    10450831854122836558u64,
-                    10545846292554080433u64,
-                    116566660199970119u64,
-                ]),
-                BigInteger([
-                    13093440903263085605u64,
-                    11527161211963754812u64,
-                    6494425250285444918u64,
-                    7366742095057089445u64,
-                    9451203523132946797u64,
-                    97038893027697752u64,
-                ]),
-                BigInteger([
-                    9731367256186495359u64,
-                    18307826426968714182u64,
-                    15355095671789482859u64,
-                    10716019696054083024u64,
-                    10403644834948680559u64,
-                    82588883607518769u64,
-                ]),
-                BigInteger([
-                    11349353324154694851u64,
-                    4124826686205133342u64,
-                    4223780857499140461u64,
-                    3036895890549457161u64,
-                    17834183844358627834u64,
-                    58501984063342835u64,
-                ]),
-                BigInteger([
-                    8480089438397568966u64,
-                    9640092278567783455u64,
-                    2520402773442502694u64,
-                    6026360287268518924u64,
-                    3975118429483272380u64,
-                    113906773438490951u64,
-                ]),
-            ],
-        ];
-        let mut ark = Vec::<Vec<Self>>::new();
-        for row in ARK_OPT_MONT.iter() {
-            ark.push(
-                row.iter()
-                    .map(|b| Self::from_repr_raw(*b))
-                    .collect::<Vec<Self>>()
-                    .to_vec(),
-            );
-        }
-        ark
-    }
 }
 
 impl<P: Fp384Parameters> FftField for Fp384<P> {
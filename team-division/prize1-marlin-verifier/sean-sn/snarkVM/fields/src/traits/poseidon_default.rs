@@ -16,7 +16,7 @@
 
 use crate::{PoseidonGrainLFSR, PrimeField};
 
-use anyhow::{bail, Result};
+use anyhow::{bail, ensure, Result};
 
 /// Parameters and RNG used
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -33,13 +33,137 @@ pub struct PoseidonParameters<F: PrimeField, const RATE: usize, const CAPACITY:
     /// Maximally Distance Separating Matrix.
     pub mds: Vec<Vec<F>>,
 
+    /// The round keys of the optimized permutation. The round keys of each partial round, except for
+    /// their first element, are carried back through the inverse MDS matrix into the previous round.
     pub ark_opt: Vec<Vec<F>>,
+    /// The dense matrix `M'` applied once, before the partial rounds, in place of the MDS matrix.
     pub mds_m_i: Vec<Vec<F>>,
+    /// The first column of each sparse matrix `M''`, without its first entry, from the last partial round to the first.
     pub mds_v: Vec<Vec<F>>,
+    /// The first row of each sparse matrix `M''`, without its first entry, from the last partial round to the first.
     pub mds_w_hat: Vec<Vec<F>>,
+    /// The first entry of every sparse matrix `M''`.
     pub mds_m_0_0: F,
 }
 
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonParameters<F, RATE, CAPACITY> {
+    /// Initializes the Poseidon parameters from the given round keys and MDS matrix,
+    /// and derives the sparse-MDS form of the permutation from them.
+    ///
+    /// The MDS matrix of each partial round is factored, from the last partial round to the first,
+    /// as `M = M' * M''`, where `M''` is sparse, and `M'` is merged into the matrix of the previous round.
+    pub fn new(
+        full_rounds: usize,
+        partial_rounds: usize,
+        alpha: u64,
+        ark: Vec<Vec<F>>,
+        mds: Vec<Vec<F>>,
+    ) -> Result<Self> {
+        let width = RATE + CAPACITY;
+        ensure!(width >= 2, "Expected a Poseidon state of at least 2 elements");
+        ensure!(full_rounds >= 2 && full_rounds % 2 == 0, "Expected a positive even number of full rounds");
+        ensure!(partial_rounds >= 1, "Expected at least one partial round");
+        ensure!(ark.len() == full_rounds + partial_rounds, "Expected one round key vector per round");
+        ensure!(ark.iter().all(|round| round.len() == width), "Expected {} round keys per round", width);
+        ensure!(mds.len() == width && mds.iter().all(|row| row.len() == width), "Expected a {0}x{0} MDS matrix", width);
+
+        let mds_transpose = transpose(&mds);
+
+        // Factor the MDS matrix of each partial round, from the last partial round to the first.
+        let mut mds_v = Vec::with_capacity(partial_rounds);
+        let mut mds_w_hat = Vec::with_capacity(partial_rounds);
+        let mut mds_m_i = identity(width);
+        let mut m_mul = mds_transpose.clone();
+        for _ in 0..partial_rounds {
+            let m_hat: Vec<Vec<F>> = m_mul[1..].iter().map(|row| row[1..].to_vec()).collect();
+            let w: Vec<F> = m_mul[1..].iter().map(|row| row[0]).collect();
+            let m_hat_inverse = match invert(&m_hat) {
+                Some(inverse) => inverse,
+                None => bail!("The MDS matrix has a singular submatrix"),
+            };
+            mds_v.push(m_mul[0][1..].to_vec());
+            mds_w_hat.push(mat_vec_mul(&m_hat_inverse, &w));
+
+            mds_m_i = identity(width);
+            for (row, m_hat_row) in mds_m_i[1..].iter_mut().zip(m_hat) {
+                row[1..].copy_from_slice(&m_hat_row);
+            }
+            m_mul = mat_mul(&mds_transpose, &mds_m_i);
+        }
+
+        // Carry the round keys of each partial round, except for their first element, into the previous round.
+        let mds_inverse = match invert(&mds) {
+            Some(inverse) => inverse,
+            None => bail!("The MDS matrix is singular"),
+        };
+        let half_full_rounds = full_rounds / 2;
+        let mut ark_opt = ark.clone();
+        for i in (half_full_rounds..(full_rounds + partial_rounds - half_full_rounds - 1)).rev() {
+            let carried = mat_vec_mul(&mds_inverse, &ark_opt[i + 1]);
+            ark_opt[i].iter_mut().zip(&carried).skip(1).for_each(|(key, carried)| *key += carried);
+            ark_opt[i + 1] = vec![F::zero(); width];
+            ark_opt[i + 1][0] = carried[0];
+        }
+
+        Ok(Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds_m_0_0: mds[0][0],
+            mds,
+            ark_opt,
+            mds_m_i,
+            mds_v,
+            mds_w_hat,
+        })
+    }
+
+    /// Samples the round keys and the MDS matrix from the Poseidon Grain LFSR,
+    /// skipping the first `skip_matrices` candidate MDS matrices.
+    pub fn from_grain_lfsr(
+        alpha: u64,
+        full_rounds: usize,
+        partial_rounds: usize,
+        skip_matrices: usize,
+    ) -> Result<Self> {
+        let width = RATE + CAPACITY;
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            width as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+
+        let mut ark = Vec::<Vec<F>>::new();
+        for _ in 0..(full_rounds + partial_rounds) {
+            ark.push(lfsr.get_field_elements_rejection_sampling(width)?);
+        }
+
+        let mut mds = vec![vec![F::zero(); width]; width];
+        for _ in 0..skip_matrices {
+            let _ = lfsr.get_field_elements_mod_p::<F>(2 * width)?;
+        }
+
+        // A qualifying matrix must satisfy the following requirements:
+        // - There is no duplication among the elements in x or y.
+        // - There is no i and j such that x[i] + y[j] = p.
+        // - There resultant MDS passes all three tests.
+
+        let xs = lfsr.get_field_elements_mod_p::<F>(width)?;
+        let ys = lfsr.get_field_elements_mod_p::<F>(width)?;
+
+        for (i, x) in xs.iter().enumerate().take(width) {
+            for (j, y) in ys.iter().enumerate().take(width) {
+                mds[i][j] = (*x + y).inverse().unwrap();
+            }
+        }
+
+        Self::new(full_rounds, partial_rounds, alpha, ark, mds)
+    }
+}
+
 /// A field with Poseidon parameters associated
 pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
@@ -48,74 +172,13 @@ pub trait PoseidonDefaultField {
     where
         Self: PrimeField,
     {
-        /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
-        #[allow(clippy::type_complexity)]
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
-            full_rounds: u64,
-            partial_rounds: u64,
-            skip_matrices: u64,
-        ) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
-
-            let mut ark = Vec::<Vec<F>>::new();
-            let mut mds = vec![vec![F::zero(); RATE + 1]; RATE + 1];
-            if RATE == 6 {
-                ark = F::get_ark_consts();
-                mds = F::get_mds_consts();
-            } else {
-                for _ in 0..(full_rounds + partial_rounds) {
-                    ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
-                }
-
-                for _ in 0..skip_matrices {
-                    let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
-                }
-
-                // A qualifying matrix must satisfy the following requirements:
-                // - There is no duplication among the elements in x or y.
-                // - There is no i and j such that x[i] + y[j] = p.
-                // - There resultant MDS passes all three tests.
-
-                let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-                let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-
-                for (i, x) in xs.iter().enumerate().take(RATE + 1) {
-                    for (j, y) in ys.iter().enumerate().take(RATE + 1) {
-                        mds[i][j] = (*x + y).inverse().unwrap();
-                    }
-                }
-            }
-
-            Ok((ark, mds))
-        }
-
         match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
-            Some(entry) => {
-                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
-                    entry.full_rounds as u64,
-                    entry.partial_rounds as u64,
-                    entry.skip_matrices as u64,
-                )?;
-                let ark_opt = Self::get_ark_opt_consts();
-                let mds_m_i = Self::get_mds_m_i_consts();
-                let mds_v = Self::get_mds_v_consts();
-                let mds_w_hat = Self::get_mds_w_hat_consts();
-                let mds_m_0_0 = Self::get_mds_0_0_const();
-
-                Ok(PoseidonParameters {
-                    full_rounds: entry.full_rounds,
-                    partial_rounds: entry.partial_rounds,
-                    alpha: entry.alpha as u64,
-                    ark,
-                    mds,
-                    ark_opt,
-                    mds_m_i,
-                    mds_v,
-                    mds_w_hat,
-                    mds_m_0_0,
-                })
-            }
+            Some(entry) => PoseidonParameters::from_grain_lfsr(
+                entry.alpha as u64,
+                entry.full_rounds,
+                entry.partial_rounds,
+                entry.skip_matrices,
+            ),
             None => bail!("No Poseidon parameters were found for this rate"),
         }
     }
@@ -161,3 +224,51 @@ impl PoseidonDefaultParametersEntry {
         Self { rate, alpha, full_rounds, partial_rounds, skip_matrices }
     }
 }
+
+/// Returns the `n x n` identity matrix.
+fn identity<F: PrimeField>(n: usize) -> Vec<Vec<F>> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { F::one() } else { F::zero() }).collect()).collect()
+}
+
+/// Returns the transpose of the square matrix `matrix`.
+fn transpose<F: PrimeField>(matrix: &[Vec<F>]) -> Vec<Vec<F>> {
+    (0..matrix.len()).map(|j| matrix.iter().map(|row| row[j]).collect()).collect()
+}
+
+/// Returns the product of the square matrix `matrix` with the vector `vector`.
+fn mat_vec_mul<F: PrimeField>(matrix: &[Vec<F>], vector: &[F]) -> Vec<F> {
+    matrix.iter().map(|row| F::sum_of_products(row.iter(), vector.iter())).collect()
+}
+
+/// Returns the product of the square matrices `lhs` and `rhs`.
+fn mat_mul<F: PrimeField>(lhs: &[Vec<F>], rhs: &[Vec<F>]) -> Vec<Vec<F>> {
+    let rhs_transpose = transpose(rhs);
+    lhs.iter().map(|row| mat_vec_mul(&rhs_transpose, row)).collect()
+}
+
+/// Returns the inverse of the square matrix `matrix`, using Gauss-Jordan elimination.
+fn invert<F: PrimeField>(matrix: &[Vec<F>]) -> Option<Vec<Vec<F>>> {
+    let n = matrix.len();
+    let mut left = matrix.to_vec();
+    let mut right = identity::<F>(n);
+
+    for column in 0..n {
+        let pivot_row = (column..n).find(|&row| !left[row][column].is_zero())?;
+        left.swap(column, pivot_row);
+        right.swap(column, pivot_row);
+
+        let inverse = left[column][column].inverse()?;
+        left[column].iter_mut().for_each(|elem| *elem *= inverse);
+        right[column].iter_mut().for_each(|elem| *elem *= inverse);
+
+        for row in 0..n {
+            let factor = left[row][column];
+            if row != column && !factor.is_zero() {
+                let (pivot_left, pivot_right) = (left[column].clone(), right[column].clone());
+                left[row].iter_mut().zip(&pivot_left).for_each(|(elem, pivot)| *elem -= factor * pivot);
+                right[row].iter_mut().zip(&pivot_right).for_each(|(elem, pivot)| *elem -= factor * pivot);
+            }
+        }
+    }
+    Some(right)
+}
@@ -44,40 +44,6 @@ pub trait PrimeField:
         Self::from_bytes_be_mod_order(&[0])
     }
 
-    fn get_ark_consts() -> Vec<Vec<Self>> {
-        let ark = Vec::<Vec<Self>>::new();
-        ark
-    }
-
-    fn get_mds_consts() -> Vec<Vec<Self>> {
-        let mds = Vec::<Vec<Self>>::new();
-        mds
-    }
-
-    fn get_mds_m_i_consts() -> Vec<Vec<Self>> {
-        let mds = Vec::<Vec<Self>>::new();
-        mds
-    }
-
-    fn get_mds_v_consts() -> Vec<Vec<Self>> {
-        let mds = Vec::<Vec<Self>>::new();
-        mds
-    }
-
-    fn get_mds_w_hat_consts() -> Vec<Vec<Self>> {
-        let mds = Vec::<Vec<Self>>::new();
-        mds
-    }
-
-    fn get_mds_0_0_const() -> Self {
-        Self::from_bytes_be_mod_order(&[0])
-    }
-
-    fn get_ark_opt_consts() -> Vec<Vec<Self>> {
-        let ark = Vec::<Vec<Self>>::new();
-        ark
-    }
-
     /// Returns the field size in bits.
     fn size_in_bits() -> usize {
         Self::Parameters::MODULUS_BITS as usize
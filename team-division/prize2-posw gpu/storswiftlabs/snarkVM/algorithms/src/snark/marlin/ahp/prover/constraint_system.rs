@@ -37,6 +37,19 @@ impl<F: Field> ConstraintSystem<F> {
         }
     }
 
+    /// Initializes a constraint system which holds the given variables and number of constraints
+    /// of a fixed prefix of the synthesis, as if it had synthesized them.
+    pub(crate) fn from_prefix(public_variables: &[F], private_variables: &[F], num_constraints: usize) -> Self {
+        assert!(public_variables[0].is_one());
+        Self {
+            public_variables: public_variables.to_vec(),
+            private_variables: private_variables.to_vec(),
+            num_public_variables: public_variables.len(),
+            num_private_variables: private_variables.len(),
+            num_constraints,
+        }
+    }
+
     /// Formats the public input according to the requirements of the constraint
     /// system
     pub(crate) fn format_public_input(public_input: &[F]) -> Vec<F> {
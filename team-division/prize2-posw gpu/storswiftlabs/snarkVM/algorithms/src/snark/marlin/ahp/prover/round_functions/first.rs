@@ -74,21 +74,32 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let z_a = state.z_a.take().unwrap();
         let z_b = state.z_b.take().unwrap();
+        let z_differences = state.z_differences.take().unwrap_or_else(|| vec![None; batch_size]);
         let private_variables = core::mem::take(&mut state.private_variables);
         assert_eq!(z_a.len(), batch_size);
         assert_eq!(z_b.len(), batch_size);
+        assert_eq!(z_differences.len(), batch_size);
         assert_eq!(private_variables.len(), batch_size);
+        state.committed_differences = z_differences.iter().map(Option::is_some).collect();
         let mut r_b_s = Vec::with_capacity(batch_size);
 
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(3 * batch_size);
         let state_ref = &state;
-        for (i, (z_a, z_b, private_variables, x_poly)) in
-            itertools::izip!(z_a, z_b, private_variables, &state.x_poly).enumerate()
+        for (i, (z_a, z_b, z_difference, private_variables, x_poly)) in
+            itertools::izip!(z_a, z_b, z_differences, private_variables, &state.x_poly).enumerate()
         {
+            let (z_a_difference, z_b_difference) = match z_difference {
+                Some([z_a_difference, z_b_difference]) => (Some(z_a_difference), Some(z_b_difference)),
+                None => (None, None),
+            };
             job_pool.add_job(move || Self::calculate_w(witness_label("w", i), private_variables, x_poly, state_ref));
-            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_a", i), z_a, false, state_ref, None));
+            job_pool.add_job(move || {
+                Self::calculate_z_m(witness_label("z_a", i), z_a, z_a_difference, false, state_ref, None)
+            });
             let r_b = F::rand(rng);
-            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_b", i), z_b, true, state_ref, Some(r_b)));
+            job_pool.add_job(move || {
+                Self::calculate_z_m(witness_label("z_b", i), z_b, z_b_difference, true, state_ref, Some(r_b))
+            });
             if MM::ZK {
                 r_b_s.push(r_b);
             }
@@ -195,9 +206,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         PoolResult::Witness(LabeledPolynomial::new(label, w_poly, None, Self::zk_bound()))
     }
 
+    /// Returns the polynomial interpolating the given evaluations, for opening, and the polynomial to commit to.
+    /// If evaluations to commit to are given, such as the difference of the evaluations with those of a
+    /// reference assignment, the polynomial to commit to interpolates them instead.
     fn calculate_z_m<'a>(
         label: impl ToString,
         evaluations: Vec<F>,
+        evaluations_for_committing: Option<Vec<F>>,
         will_be_evaluated: bool,
         state: &prover::State<'a, F, MM>,
         r: Option<F>,
@@ -238,6 +253,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         }
         end_timer!(poly_time_4);
         let poly_time_5 = start_timer!(|| format!("Computing {label} 5"));
+        let evals = match evaluations_for_committing {
+            Some(evaluations) => EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain),
+            None => evals,
+        };
         let poly_for_committing = if should_randomize {
             let poly_terms = vec![
                 (F::one(), PolynomialWithBasis::new_lagrange_basis(evals)),
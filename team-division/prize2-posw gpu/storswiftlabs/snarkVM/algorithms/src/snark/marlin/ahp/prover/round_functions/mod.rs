@@ -21,7 +21,7 @@ use crate::snark::marlin::{
 };
 use itertools::Itertools;
 use snarkvm_fields::PrimeField;
-use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_r1cs::{ConstraintSynthesizer, PartialAssignment, PrefixSize, SynthesisPart};
 
 use snarkvm_utilities::cfg_iter;
 #[cfg(not(feature = "std"))]
@@ -35,61 +35,68 @@ mod fourth;
 mod second;
 mod third;
 
+/// The maximum percentage of the variables of an instance which may differ from the reference assignment
+/// of its partial assignment for `z_A` and `z_B` to be updated from it. Beyond it, most rows are updated,
+/// so the update costs as much as the full products, and `z_A` and `z_B` are recomputed in full instead.
+const MAXIMUM_CHANGED_VARIABLES_PERCENT: usize = 50;
+
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Initialize the AHP prover.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>>(
         index: &'a Circuit<F, MM>,
         circuits: &[C],
+    ) -> Result<prover::State<'a, F, MM>, AHPError> {
+        Self::init_prover_with_partial_assignments(index, circuits, &vec![None; circuits.len()])
+    }
+
+    /// Initialize the AHP prover, reusing the given partial assignment of each circuit, if any.
+    /// The caller must ensure each partial assignment was computed for this index.
+    ///
+    /// Only the remainder of the synthesis of a circuit is performed when its partial assignment records
+    /// a fixed prefix, and only the contributions of the variables that differ from its reference assignment
+    /// are computed in `z_A` and `z_B`, which the first round then commits to in place of `z_A` and `z_B`.
+    /// If too many variables differ, `z_A` and `z_B` are computed in full, as without a partial assignment.
+    pub fn init_prover_with_partial_assignments<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F, MM>,
+        circuits: &[C],
+        partial_assignments: &[Option<&PartialAssignment<F>>],
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
+        assert_eq!(circuits.len(), partial_assignments.len());
 
         // Perform matrix multiplications.
-        let (padded_public_variables, private_variables, z_a, z_b) = cfg_iter!(circuits)
-            .map(|circuit| {
-                let constraint_time = start_timer!(|| "Generating constraints and witnesses");
-                let mut pcs = prover::ConstraintSystem::new();
-                circuit.generate_constraints(&mut pcs)?;
-                end_timer!(constraint_time);
-
-                let padding_time = start_timer!(|| "Padding matrices to make them square");
-                crate::snark::marlin::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs);
-                pcs.make_matrices_square();
-                end_timer!(padding_time);
-
-                let num_non_zero_a = index.index_info.num_non_zero_a;
-                let num_non_zero_b = index.index_info.num_non_zero_b;
-                let num_non_zero_c = index.index_info.num_non_zero_c;
-
-                let prover::ConstraintSystem {
-                    public_variables: padded_public_variables,
-                    private_variables,
-                    num_constraints,
-                    num_public_variables,
-                    num_private_variables,
-                    ..
-                } = pcs;
-
-                assert_eq!(padded_public_variables.len(), num_public_variables);
-                assert!(padded_public_variables[0].is_one());
-                assert_eq!(private_variables.len(), num_private_variables);
-
-                if cfg!(debug_assertions) {
-                    println!("Number of padded public variables in Prover::Init: {}", num_public_variables);
-                    println!("Number of private variables: {}", num_private_variables);
-                    println!("Number of constraints: {}", num_constraints);
-                    println!("Number of non-zero entries in A: {}", num_non_zero_a);
-                    println!("Number of non-zero entries in B: {}", num_non_zero_b);
-                    println!("Number of non-zero entries in C: {}", num_non_zero_c);
-                }
+        let (padded_public_variables, private_variables, z_a, z_b, z_differences) = cfg_iter!(circuits)
+            .zip(partial_assignments)
+            .map(|(circuit, &partial_assignment)| {
+                let (padded_public_variables, private_variables) =
+                    Self::synthesize_assignment(index, circuit, partial_assignment)?;
+                let num_public_variables = padded_public_variables.len();
+                let num_variables = num_public_variables + private_variables.len();
 
-                if index.index_info.num_constraints != num_constraints
-                    || index.index_info.num_variables != (num_public_variables + num_private_variables)
-                {
-                    return Err(AHPError::InstanceDoesNotMatchIndex);
+                let differences = partial_assignment
+                    .and_then(|partial| {
+                        let changed_variables =
+                            partial.changed_variables(&padded_public_variables, &private_variables)?;
+                        match changed_variables.len() * 100 <= num_variables * MAXIMUM_CHANGED_VARIABLES_PERCENT {
+                            true => Some((partial, partial.product_differences(&changed_variables))),
+                            false => None,
+                        }
+                    })
+                    .filter(|(_, differences)| {
+                        differences.len() == 2
+                            && differences[0].len() == index.a.len()
+                            && differences[1].len() == index.b.len()
+                    });
+                if let Some((partial, mut differences)) = differences {
+                    let update_time = start_timer!(|| "Updating z_A and z_B from the partial assignment");
+                    let (z_b_difference, z_a_difference) = (differences.pop().unwrap(), differences.pop().unwrap());
+                    let z_a = add_to_product(&partial.products()[0], &z_a_difference);
+                    let z_b = add_to_product(&partial.products()[1], &z_b_difference);
+                    end_timer!(update_time);
+                    let z_differences = Some([z_a_difference, z_b_difference]);
+                    return Ok((padded_public_variables, private_variables, z_a, z_b, z_differences));
                 }
 
-                Self::formatted_public_input_is_admissible(&padded_public_variables)?;
-
                 let eval_z_a_time = start_timer!(|| "Evaluating z_A");
                 let z_a = cfg_iter!(index.a)
                     .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
@@ -101,19 +108,149 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                     .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
                     .collect();
                 end_timer!(eval_z_b_time);
-                end_timer!(init_time);
-                Ok((padded_public_variables, private_variables, z_a, z_b))
+                Ok((padded_public_variables, private_variables, z_a, z_b, None))
             })
-            .collect::<Result<Vec<_>, _>>()?
+            .collect::<Result<Vec<_>, AHPError>>()?
             .into_iter()
             .multiunzip();
 
         let mut state = prover::State::initialize(padded_public_variables, private_variables, index)?;
         state.z_a = Some(z_a);
         state.z_b = Some(z_b);
+        state.z_differences = Some(z_differences);
+        end_timer!(init_time);
 
         Ok(state)
     }
+
+    /// Returns the partial assignment of the given circuit, which caches its products with the
+    /// `A` and `B` matrices of the index, for other instances of the circuit to reuse.
+    /// If the circuit splits its synthesis, the partial assignment also records its fixed prefix.
+    pub fn partial_assignment<C: ConstraintSynthesizer<F>>(
+        index: &Circuit<F, MM>,
+        circuit: &C,
+    ) -> Result<PartialAssignment<F>, AHPError> {
+        let partial_assignment_time = start_timer!(|| "AHP::Prover::PartialAssignment");
+
+        let constraint_time = start_timer!(|| "Generating constraints and witnesses");
+        let mut pcs = prover::ConstraintSystem::new();
+        let fixed_prefix = match circuit.generate_constraints_part(&mut pcs, SynthesisPart::FixedPrefix)? {
+            true => {
+                let fixed_prefix = PrefixSize {
+                    num_public_variables: pcs.num_public_variables,
+                    num_private_variables: pcs.num_private_variables,
+                    num_constraints: pcs.num_constraints,
+                };
+                if !circuit.generate_constraints_part(&mut pcs, SynthesisPart::Remainder)? {
+                    return Err(AHPError::InstanceDoesNotMatchIndex);
+                }
+                Some(fixed_prefix)
+            }
+            false => {
+                circuit.generate_constraints(&mut pcs)?;
+                None
+            }
+        };
+        end_timer!(constraint_time);
+
+        let (padded_public_variables, private_variables) = Self::finalize_assignment(index, pcs)?;
+        let mut partial_assignment =
+            PartialAssignment::new(padded_public_variables, private_variables, &[&index.a[..], &index.b[..]]);
+        if let Some(fixed_prefix) = fixed_prefix {
+            partial_assignment = partial_assignment.with_fixed_prefix(fixed_prefix);
+        }
+        end_timer!(partial_assignment_time);
+
+        Ok(partial_assignment)
+    }
+
+    /// Synthesizes the given circuit, and returns its padded public variables and its private variables.
+    /// If the given partial assignment records a fixed prefix, the variables of the prefix are copied
+    /// from its reference assignment, and only the remainder of the synthesis is performed.
+    fn synthesize_assignment<C: ConstraintSynthesizer<F>>(
+        index: &Circuit<F, MM>,
+        circuit: &C,
+        partial_assignment: Option<&PartialAssignment<F>>,
+    ) -> Result<(Vec<F>, Vec<F>), AHPError> {
+        let constraint_time = start_timer!(|| "Generating constraints and witnesses");
+        let mut synthesized = None;
+        if let Some((partial, fixed_prefix)) =
+            partial_assignment.and_then(|partial| Some((partial, partial.fixed_prefix()?)))
+        {
+            let mut pcs = prover::ConstraintSystem::from_prefix(
+                &partial.public_variables()[..fixed_prefix.num_public_variables],
+                &partial.private_variables()[..fixed_prefix.num_private_variables],
+                fixed_prefix.num_constraints,
+            );
+            if circuit.generate_constraints_part(&mut pcs, SynthesisPart::Remainder)? {
+                synthesized = Some(pcs);
+            }
+        }
+        let pcs = match synthesized {
+            Some(pcs) => pcs,
+            None => {
+                let mut pcs = prover::ConstraintSystem::new();
+                circuit.generate_constraints(&mut pcs)?;
+                pcs
+            }
+        };
+        end_timer!(constraint_time);
+
+        Self::finalize_assignment(index, pcs)
+    }
+
+    /// Pads the given synthesized constraint system, checks it against the index, and returns its
+    /// padded public variables and its private variables.
+    fn finalize_assignment(
+        index: &Circuit<F, MM>,
+        mut pcs: prover::ConstraintSystem<F>,
+    ) -> Result<(Vec<F>, Vec<F>), AHPError> {
+        let padding_time = start_timer!(|| "Padding matrices to make them square");
+        crate::snark::marlin::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs);
+        pcs.make_matrices_square();
+        end_timer!(padding_time);
+
+        let num_non_zero_a = index.index_info.num_non_zero_a;
+        let num_non_zero_b = index.index_info.num_non_zero_b;
+        let num_non_zero_c = index.index_info.num_non_zero_c;
+
+        let prover::ConstraintSystem {
+            public_variables: padded_public_variables,
+            private_variables,
+            num_constraints,
+            num_public_variables,
+            num_private_variables,
+            ..
+        } = pcs;
+
+        assert_eq!(padded_public_variables.len(), num_public_variables);
+        assert!(padded_public_variables[0].is_one());
+        assert_eq!(private_variables.len(), num_private_variables);
+
+        if cfg!(debug_assertions) {
+            println!("Number of padded public variables in Prover::Init: {}", num_public_variables);
+            println!("Number of private variables: {}", num_private_variables);
+            println!("Number of constraints: {}", num_constraints);
+            println!("Number of non-zero entries in A: {}", num_non_zero_a);
+            println!("Number of non-zero entries in B: {}", num_non_zero_b);
+            println!("Number of non-zero entries in C: {}", num_non_zero_c);
+        }
+
+        if index.index_info.num_constraints != num_constraints
+            || index.index_info.num_variables != (num_public_variables + num_private_variables)
+        {
+            return Err(AHPError::InstanceDoesNotMatchIndex);
+        }
+
+        Self::formatted_public_input_is_admissible(&padded_public_variables)?;
+
+        Ok((padded_public_variables, private_variables))
+    }
+}
+
+/// Returns the sum of the given product with a reference assignment and its difference with another assignment.
+fn add_to_product<F: PrimeField>(product: &[F], difference: &[F]) -> Vec<F> {
+    cfg_iter!(product).zip(difference).map(|(product, difference)| *product + difference).collect()
 }
 
 fn inner_product<F: PrimeField>(
@@ -68,6 +68,16 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     /// The length of this list must be equal to the batch size.
     pub(super) z_b: Option<Vec<Vec<F>>>,
 
+    /// The list of differences between the Az and Bz vectors of each instance in the batch and
+    /// those of the reference assignment of its partial assignment, if the instance has one.
+    /// The length of this list must be equal to the batch size.
+    pub(super) z_differences: Option<Vec<Option<[Vec<F>; 2]>>>,
+
+    /// Whether the first round commits to the differences of the Az and Bz vectors of each instance
+    /// in the batch instead of to the Az and Bz vectors.
+    /// The length of this list must be equal to the batch size.
+    pub(in crate::snark) committed_differences: Vec<bool>,
+
     /// A list of polynomials corresponding to the interpolation of the public input.
     /// The length of this list must be equal to the batch size.
     pub(super) x_poly: Vec<DensePolynomial<F>>,
@@ -131,6 +141,8 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
             private_variables,
             z_a: None,
             z_b: None,
+            z_differences: None,
+            committed_differences: vec![false; batch_size],
             first_round_oracles: None,
             mz_poly_randomizer: None,
             verifier_first_message: None,
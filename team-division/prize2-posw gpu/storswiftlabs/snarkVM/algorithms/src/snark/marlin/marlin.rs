@@ -15,8 +15,19 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    polycommit::{
+        kzg10,
+        sonic_pc::{
+            Commitment,
+            Evaluations,
+            LabeledCommitment,
+            LabeledPolynomialWithBasis,
+            QuerySet,
+            Randomness,
+            SonicKZG10,
+        },
+    },
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS},
         fiat_shamir::traits::FiatShamirRng,
@@ -24,6 +35,7 @@ use crate::{
         proof,
         prover,
         witness_label,
+        CircuitInfo,
         CircuitProvingKey,
         CircuitVerifyingKey,
         MarlinError,
@@ -44,9 +56,9 @@ use rayon::prelude::*;
 
 use rand::{CryptoRng, Rng};
 use rand_core::RngCore;
-use snarkvm_curves::PairingEngine;
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
-use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_r1cs::{ConstraintSynthesizer, PartialAssignment};
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
 use std::{borrow::Borrow, sync::Arc, thread, time::Duration};
@@ -75,6 +87,27 @@ static P9: AtomicU32 = AtomicU32::new(0);
 static P10: AtomicU32 = AtomicU32::new(0);
 // static p_array:[u32;10] = [5,12,4,5,12,6,12,12,16,10];
 
+/// The commitments to the products of the `A` and `B` matrices with the reference assignment of a
+/// partial assignment, which the prover attaches to the partial assignment, together with the index
+/// they were computed for.
+struct ReferenceCommitments<E: PairingEngine> {
+    z_a: E::G1Projective,
+    z_b: E::G1Projective,
+    /// The information of the index of the partial assignment.
+    circuit_info: CircuitInfo<E::Fr>,
+    /// The commitments to the index polynomials, which bind the matrices of the index.
+    circuit_commitments: Vec<Commitment<E>>,
+}
+
+impl<E: PairingEngine> ReferenceCommitments<E> {
+    /// Returns `true` if the reference commitments were computed for the index of the given proving key.
+    fn is_for<MM: MarlinMode>(&self, circuit_proving_key: &CircuitProvingKey<E, MM>) -> bool {
+        let circuit_verifying_key = &circuit_proving_key.circuit_verifying_key;
+        self.circuit_info == circuit_verifying_key.circuit_info
+            && self.circuit_commitments == circuit_verifying_key.circuit_commitments
+    }
+}
+
 /// The Marlin proof system.
 #[derive(Clone, Debug)]
 pub struct MarlinSNARK<
@@ -272,6 +305,75 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         Self::absorb(commitments, sponge);
         sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c], OptimizationType::Weight);
     }
+
+    /// Commits to the products of the `A` and `B` matrices with the reference assignment of the given
+    /// partial assignment, without hiding, in the Lagrange basis of the constraint domain.
+    fn commit_to_reference_products(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        partial_assignment: &PartialAssignment<E::Fr>,
+    ) -> Result<ReferenceCommitments<E>, SNARKError> {
+        let constraint_domain = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
+            .ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let products = partial_assignment.products().iter().zip(["z_a", "z_b"]).map(|(product, label)| {
+            let evaluations = EvaluationsOnDomain::from_vec_and_domain(product.clone(), constraint_domain);
+            LabeledPolynomialWithBasis::new_lagrange_basis(label.to_string(), evaluations, None)
+        });
+        let (commitments, _) = SonicKZG10::<E, FS>::commit(&circuit_proving_key.committer_key, products, None)?;
+        assert_eq!(commitments.len(), 2);
+
+        Ok(ReferenceCommitments {
+            z_a: commitments[0].commitment().0.to_projective(),
+            z_b: commitments[1].commitment().0.to_projective(),
+            circuit_info: circuit_proving_key.circuit_verifying_key.circuit_info,
+            circuit_commitments: circuit_proving_key.circuit_verifying_key.circuit_commitments.clone(),
+        })
+    }
+
+    /// Returns the partial assignment of each of the given circuits which the prover may reuse, namely
+    /// those with reference commitments computed for the index of the given proving key.
+    fn reusable_partial_assignments<'a, C: ConstraintSynthesizer<E::Fr>>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &'a [C],
+    ) -> Vec<Option<&'a PartialAssignment<E::Fr>>> {
+        circuits
+            .iter()
+            .map(|circuit| {
+                circuit.partial_assignment().filter(|partial_assignment| {
+                    partial_assignment
+                        .prover_data::<ReferenceCommitments<E>>()
+                        .map_or(false, |reference| reference.is_for(circuit_proving_key))
+                })
+            })
+            .collect()
+    }
+
+    /// Adds the commitments to the reference products to the commitments to `z_A` and `z_B` of the
+    /// instances for which the first round committed to their differences with the reference products.
+    fn add_reference_commitments(
+        partial_assignments: &[Option<&PartialAssignment<E::Fr>>],
+        committed_differences: &[bool],
+        first_commitments: &mut [LabeledCommitment<Commitment<E>>],
+    ) -> Result<(), SNARKError> {
+        for (i, partial) in partial_assignments.iter().enumerate().filter(|(i, _)| committed_differences[*i]) {
+            let reference = partial
+                .and_then(|partial| partial.prover_data::<ReferenceCommitments<E>>())
+                .expect("The instance has reference commitments");
+
+            // The first round commits to `w`, `z_a` and `z_b` for each instance.
+            for (commitment, reference) in
+                first_commitments[3 * i + 1..3 * i + 3].iter_mut().zip([reference.z_a, reference.z_b])
+            {
+                let mut sum = reference;
+                sum.add_assign_mixed(&commitment.commitment().0);
+                *commitment = LabeledCommitment::new(
+                    commitment.label().to_string(),
+                    kzg10::Commitment(sum.to_affine()),
+                    commitment.degree_bound(),
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
@@ -446,7 +548,12 @@ where
 
         Self::terminate(terminator)?;
 
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+        let partial_assignments = Self::reusable_partial_assignments(circuit_proving_key, circuits);
+        let prover_state = AHPForR1CS::<_, MM>::init_prover_with_partial_assignments(
+            &circuit_proving_key.circuit,
+            circuits,
+            &partial_assignments,
+        )?;
         let public_input = prover_state.public_inputs();
         let padded_public_input = prover_state.padded_public_inputs();
         assert_eq!(prover_state.batch_size, batch_size);
@@ -477,7 +584,7 @@ where
         // eprintln!("{:?} P3 count:{}",thread::current().id(),P3.load(Ordering::SeqCst));
         let a3 = Local::now().timestamp_millis();
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
+        let (mut first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
             SonicKZG10::<E, FS>::commit(
                 &circuit_proving_key.committer_key,
@@ -485,6 +592,11 @@ where
                 Some(zk_rng),
             )?
         };
+        Self::add_reference_commitments(
+            &partial_assignments,
+            &prover_state.committed_differences,
+            &mut first_commitments,
+        )?;
         end_timer!(first_round_comm_time);
         P3.fetch_sub(1, Ordering::SeqCst);
         while P4.load(Ordering::SeqCst) > p_array[3] {
@@ -967,6 +1079,16 @@ where
         Self::prove_batch_with_terminator(proving_key, std::slice::from_ref(input_and_witness), terminator, rng)
     }
 
+    fn partial_assignment<C: ConstraintSynthesizer<Self::ScalarField>>(
+        proving_key: &Self::ProvingKey,
+        input_and_witness: &C,
+    ) -> Result<Option<PartialAssignment<Self::ScalarField>>, SNARKError> {
+        let mut partial_assignment = AHPForR1CS::<_, MM>::partial_assignment(&proving_key.circuit, input_and_witness)?;
+        let reference_commitments = Self::commit_to_reference_products(proving_key, &partial_assignment)?;
+        partial_assignment.set_prover_data(reference_commitments);
+        Ok(Some(partial_assignment))
+    }
+
     fn verify_batch<B: Borrow<Self::VerifierInput>>(
         verifying_key: &Self::VerifyingKey,
         input: &[B],
@@ -995,10 +1117,11 @@ pub mod test {
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
-    use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+    use snarkvm_r1cs::{ConstraintSystem, Index, PrefixSize, SynthesisError, SynthesisPart, Variable};
     use snarkvm_utilities::{test_crypto_rng, Uniform};
 
     use core::ops::MulAssign;
+    use rand::{rngs::StdRng, SeedableRng};
 
     const ITERATIONS: usize = 10;
 
@@ -1065,4 +1188,191 @@ pub mod test {
             assert!(TestSNARK::verify(&vk.clone(), &vec![c], &proof).unwrap(), "The native verification check fails.");
         }
     }
+
+    /// A circuit which provides the partial assignment of a reference instance.
+    struct PartiallyAssignedCircuit<'a, F: Field> {
+        circuit: Circuit<F>,
+        partial_assignment: &'a PartialAssignment<F>,
+    }
+
+    impl<'a, ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for PartiallyAssignedCircuit<'a, ConstraintF> {
+        fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            self.circuit.generate_constraints(cs)
+        }
+
+        fn partial_assignment(&self) -> Option<&PartialAssignment<ConstraintF>> {
+            Some(self.partial_assignment)
+        }
+    }
+
+    /// Returns `true` if the prover updates `z_A` and `z_B` of the given circuit from its partial assignment,
+    /// instead of computing them in full.
+    fn updates_from_partial_assignment<C: ConstraintSynthesizer<Fr>>(
+        pk: &CircuitProvingKey<Bls12_377, MarlinHidingMode>,
+        circuit: &C,
+    ) -> bool {
+        let circuits = std::slice::from_ref(circuit);
+        let partial_assignments = TestSNARK::reusable_partial_assignments(pk, circuits);
+        let state =
+            AHPForR1CS::init_prover_with_partial_assignments(&pk.circuit, circuits, &partial_assignments).unwrap();
+        let state = AHPForR1CS::prover_first_round(state, &mut test_crypto_rng()).unwrap();
+        state.committed_differences[0]
+    }
+
+    #[test]
+    fn marlin_partial_assignment_test() {
+        let mut rng = test_crypto_rng();
+
+        let reference = Circuit {
+            a: Some(Fr::rand(&mut rng)),
+            b: Some(Fr::rand(&mut rng)),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+        let (pk, vk) = TestSNARK::setup(&reference, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let partial_assignment = TestSNARK::partial_assignment(&pk, &reference).unwrap().unwrap();
+
+        for _ in 0..ITERATIONS {
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            let mut c = a;
+            c.mul_assign(&b);
+
+            let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+            let partially_assigned_circuit =
+                PartiallyAssignedCircuit { circuit, partial_assignment: &partial_assignment };
+
+            // Every variable differs from the reference assignment, so `z_A` and `z_B` are computed in full.
+            assert!(!updates_from_partial_assignment(&pk, &partially_assigned_circuit));
+
+            // The proof from the partial assignment is the proof of the naive prover, given the same randomness.
+            let seed: u64 = rng.gen();
+            let expected = TestSNARK::prove(&pk, &circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+            let candidate =
+                TestSNARK::prove(&pk, &partially_assigned_circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+            assert_eq!(expected, candidate);
+
+            assert!(TestSNARK::verify(&vk, &vec![c], &candidate).unwrap());
+        }
+    }
+
+    /// A circuit which squares a seed repeatedly, and then multiplies the last square with an input.
+    /// It splits its synthesis after the squarings, which are the same for all instances with the same seed.
+    #[derive(Copy, Clone)]
+    struct SplitCircuit<'a, F: Field> {
+        seed: F,
+        input: F,
+        num_squarings: usize,
+        partial_assignment: Option<&'a PartialAssignment<F>>,
+    }
+
+    impl<'a, F: Field> SplitCircuit<'a, F> {
+        fn product(&self) -> F {
+            (0..self.num_squarings).fold(self.seed, |square, _| square.square()) * self.input
+        }
+    }
+
+    impl<'a, ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for SplitCircuit<'a, ConstraintF> {
+        fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            self.generate_constraints_part(cs, SynthesisPart::FixedPrefix)?;
+            self.generate_constraints_part(cs, SynthesisPart::Remainder)?;
+            Ok(())
+        }
+
+        fn generate_constraints_part<CS: ConstraintSystem<ConstraintF>>(
+            &self,
+            cs: &mut CS,
+            part: SynthesisPart,
+        ) -> Result<bool, SynthesisError> {
+            match part {
+                SynthesisPart::FixedPrefix => {
+                    let mut square = self.seed;
+                    let mut variable = cs.alloc(|| "seed", || Ok(square))?;
+                    for i in 0..self.num_squarings {
+                        let next_square = square.square();
+                        let next_variable = cs.alloc(|| format!("square {}", i), || Ok(next_square))?;
+                        cs.enforce(
+                            || format!("squaring {}", i),
+                            |lc| lc + variable,
+                            |lc| lc + variable,
+                            |lc| lc + next_variable,
+                        );
+                        square = next_square;
+                        variable = next_variable;
+                    }
+                }
+                SynthesisPart::Remainder => {
+                    // The last square is the last private variable of the fixed prefix.
+                    let last_square = Variable::new_unchecked(Index::Private(self.num_squarings));
+                    let input = cs.alloc(|| "input", || Ok(self.input))?;
+                    let product = cs.alloc_input(|| "product", || Ok(self.product()))?;
+                    cs.enforce(|| "product", |lc| lc + last_square, |lc| lc + input, |lc| lc + product);
+                }
+            }
+            Ok(true)
+        }
+
+        fn partial_assignment(&self) -> Option<&PartialAssignment<ConstraintF>> {
+            self.partial_assignment
+        }
+    }
+
+    #[test]
+    fn marlin_split_synthesis_test() {
+        let mut rng = test_crypto_rng();
+
+        let reference = SplitCircuit {
+            seed: Fr::rand(&mut rng),
+            input: Fr::rand(&mut rng),
+            num_squarings: 50,
+            partial_assignment: None,
+        };
+        let (pk, vk) = TestSNARK::setup(&reference, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let partial_assignment = TestSNARK::partial_assignment(&pk, &reference).unwrap().unwrap();
+        let expected_prefix = PrefixSize { num_public_variables: 1, num_private_variables: 51, num_constraints: 50 };
+        assert_eq!(partial_assignment.fixed_prefix(), Some(expected_prefix));
+
+        for _ in 0..ITERATIONS {
+            // Only the input and the product differ from the reference assignment.
+            let circuit = SplitCircuit { input: Fr::rand(&mut rng), ..reference };
+            let partially_assigned_circuit = SplitCircuit { partial_assignment: Some(&partial_assignment), ..circuit };
+            assert!(updates_from_partial_assignment(&pk, &partially_assigned_circuit));
+
+            // The proof from the fixed prefix and the sparse update is the proof of the naive prover,
+            // given the same randomness.
+            let seed: u64 = rng.gen();
+            let expected = TestSNARK::prove(&pk, &circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+            let candidate =
+                TestSNARK::prove(&pk, &partially_assigned_circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+            assert_eq!(expected, candidate);
+
+            assert!(TestSNARK::verify(&vk, &vec![circuit.product()], &candidate).unwrap());
+        }
+    }
+
+    #[test]
+    fn marlin_partial_assignment_of_another_index_test() {
+        let mut rng = test_crypto_rng();
+
+        let reference = SplitCircuit {
+            seed: Fr::rand(&mut rng),
+            input: Fr::rand(&mut rng),
+            num_squarings: 50,
+            partial_assignment: None,
+        };
+        let (other_pk, _) = TestSNARK::setup(&reference, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let partial_assignment = TestSNARK::partial_assignment(&other_pk, &reference).unwrap().unwrap();
+
+        // The partial assignment was computed for the index of another proving key, so it is not reused.
+        let (pk, vk) = TestSNARK::setup(&reference, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+        let circuit = SplitCircuit { input: Fr::rand(&mut rng), ..reference };
+        let partially_assigned_circuit = SplitCircuit { partial_assignment: Some(&partial_assignment), ..circuit };
+        assert!(!updates_from_partial_assignment(&pk, &partially_assigned_circuit));
+
+        let seed: u64 = rng.gen();
+        let expected = TestSNARK::prove(&pk, &circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+        let candidate = TestSNARK::prove(&pk, &partially_assigned_circuit, &mut StdRng::seed_from_u64(seed)).unwrap();
+        assert_eq!(expected, candidate);
+        assert!(TestSNARK::verify(&vk, &vec![circuit.product()], &candidate).unwrap());
+    }
 }
@@ -19,7 +19,7 @@ use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToB
 
 use rand::{CryptoRng, Rng};
 use snarkvm_fields::{PrimeField, ToConstraintField};
-use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_r1cs::{ConstraintSynthesizer, PartialAssignment};
 use std::{borrow::Borrow, fmt::Debug, sync::atomic::AtomicBool};
 
 /// Defines a trait that describes preparing from an unprepared version to a prepare version.
@@ -115,6 +115,17 @@ pub trait SNARK {
     ) -> Result<Self::Proof, SNARKError> {
         Self::prove_batch_with_terminator(proving_key, std::slice::from_ref(input_and_witness), terminator, rng)
     }
+
+    /// Returns the partial assignment of the given circuit, which other instances of the circuit
+    /// may provide to skip recomputing the work that depends only on unchanged variables.
+    /// Provers only reuse it with the proving key it was computed with.
+    /// Returns `None` if the proof system does not support partial assignments.
+    fn partial_assignment<C: ConstraintSynthesizer<Self::ScalarField>>(
+        _proving_key: &Self::ProvingKey,
+        _input_and_witness: &C,
+    ) -> Result<Option<PartialAssignment<Self::ScalarField>>, SNARKError> {
+        Ok(None)
+    }
 /*
     fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
         circuit: &C,
@@ -26,7 +26,7 @@ use snarkvm_gadgets::{
     EqGadget,
     ToBytesGadget,
 };
-use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, PartialAssignment, SynthesisError};
 
 use anyhow::Result;
 use snarkvm_algorithms::merkle_tree::MerkleTree;
@@ -37,6 +37,8 @@ pub struct PoSWCircuit<N: Network> {
     block_header_root: N::BlockHeaderRoot,
     nonce: N::PoSWNonce,
     hashed_leaves: Vec<<<N::BlockHeaderRootParameters as MerkleParameters>::LeafCRH as CRH>::Output>,
+    /// The partial assignment of an instance of this circuit for the same block template.
+    partial_assignment: Option<Arc<PartialAssignment<N::InnerScalarField>>>,
 }
 
 impl<N: Network> PoSWCircuit<N> {
@@ -44,7 +46,12 @@ impl<N: Network> PoSWCircuit<N> {
     pub fn new(block_template: &BlockTemplate<N>, nonce: N::PoSWNonce) -> Result<Self> {
         let tree = block_template.to_header_tree()?;
 
-        Ok(Self { block_header_root: (*tree.root()).into(), nonce, hashed_leaves: tree.hashed_leaves().to_vec() })
+        Ok(Self {
+            block_header_root: (*tree.root()).into(),
+            nonce,
+            hashed_leaves: tree.hashed_leaves().to_vec(),
+            partial_assignment: None,
+        })
     }
 
     pub fn new_abm(
@@ -56,6 +63,7 @@ impl<N: Network> PoSWCircuit<N> {
             block_header_root,
             nonce,
             hashed_leaves,
+            partial_assignment: None,
         })
     }
 
@@ -67,6 +75,7 @@ impl<N: Network> PoSWCircuit<N> {
             block_header_root: Default::default(),
             nonce: Default::default(),
             hashed_leaves: vec![empty_hash; usize::pow(2, N::HEADER_TREE_DEPTH as u32)],
+            partial_assignment: None,
         })
     }
 
@@ -75,6 +84,11 @@ impl<N: Network> PoSWCircuit<N> {
         vec![*self.block_header_root, *self.nonce]
     }
 
+    /// Returns the block header root.
+    pub fn block_header_root(&self) -> N::BlockHeaderRoot {
+        self.block_header_root
+    }
+
     /// Returns the block nonce.
     pub fn nonce(&self) -> N::PoSWNonce {
         self.nonce
@@ -85,6 +99,13 @@ impl<N: Network> PoSWCircuit<N> {
     pub(crate) fn set_nonce(&mut self, nonce: N::PoSWNonce) {
         self.nonce = nonce;
     }
+
+    /// Sets the partial assignment of an instance of this circuit for the same block template.
+    /// As only the nonce-dependent variables change between such instances, the prover uses it
+    /// to skip recomputing the contributions of the block header root and the hashed leaves.
+    pub(crate) fn set_partial_assignment(&mut self, partial_assignment: Arc<PartialAssignment<N::InnerScalarField>>) {
+        self.partial_assignment = Some(partial_assignment);
+    }
 }
 
 impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for PoSWCircuit<N> {
@@ -161,6 +182,10 @@ impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for PoSWCircuit<N> {
 
         Ok(())
     }
+
+    fn partial_assignment(&self) -> Option<&PartialAssignment<N::InnerScalarField>> {
+        self.partial_assignment.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -171,7 +196,13 @@ mod test {
     use snarkvm_r1cs::TestConstraintSystem;
     use snarkvm_utilities::{FromBytes, ToBytes, Uniform};
 
-    use rand::{rngs::ThreadRng, thread_rng, CryptoRng, Rng};
+    use rand::{
+        rngs::{StdRng, ThreadRng},
+        thread_rng,
+        CryptoRng,
+        Rng,
+        SeedableRng,
+    };
     use std::time::Instant;
 
     fn posw_constraints_test<N: Network>() {
@@ -191,26 +222,24 @@ mod test {
         assert_eq!(26909, num_constraints);
     }
 
-    fn posw_proof_test<N: Network, R: Rng + CryptoRng>(rng: &mut R) {
-        // Generate the proving and verifying key.
-        let (proving_key, verifying_key) = {
-            let max_degree =
-                AHPForR1CS::<N::InnerScalarField, MarlinHidingMode>::max_degree(20000, 20000, 200000).unwrap();
-            let universal_srs = <<N as Network>::PoSWSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
-
-            <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(
-                &PoSWCircuit::<N>::blank().unwrap(),
-                &mut SRS::<R, _>::Universal(&universal_srs),
-            )
-            .unwrap()
-        };
-
-        // Sample a random nonce.
-        let nonce = Uniform::rand(rng);
+    /// Generates the proving and verifying key of the PoSW circuit.
+    fn posw_keys<N: Network, R: Rng + CryptoRng>(
+        rng: &mut R,
+    ) -> (<N::PoSWSNARK as SNARK>::ProvingKey, <N::PoSWSNARK as SNARK>::VerifyingKey) {
+        let max_degree = AHPForR1CS::<N::InnerScalarField, MarlinHidingMode>::max_degree(20000, 20000, 200000).unwrap();
+        let universal_srs = <<N as Network>::PoSWSNARK as SNARK>::universal_setup(&max_degree, rng).unwrap();
+
+        <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(
+            &PoSWCircuit::<N>::blank().unwrap(),
+            &mut SRS::<R, _>::Universal(&universal_srs),
+        )
+        .unwrap()
+    }
 
-        // Construct the block template.
+    /// Constructs the block template of the genesis block.
+    fn genesis_template<N: Network>() -> BlockTemplate<N> {
         let block = N::genesis_block();
-        let block_template = BlockTemplate::new(
+        BlockTemplate::new(
             block.previous_block_hash(),
             block.height(),
             block.timestamp(),
@@ -219,7 +248,18 @@ mod test {
             block.previous_ledger_root(),
             block.transactions().clone(),
             block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
-        );
+        )
+    }
+
+    fn posw_proof_test<N: Network, R: Rng + CryptoRng>(rng: &mut R) {
+        // Generate the proving and verifying key.
+        let (proving_key, verifying_key) = posw_keys::<N, R>(rng);
+
+        // Sample a random nonce.
+        let nonce = Uniform::rand(rng);
+
+        // Construct the block template.
+        let block_template = genesis_template::<N>();
 
         // Construct an assigned circuit.
         let assigned_circuit = PoSWCircuit::<N>::new(&block_template, nonce).unwrap();
@@ -245,6 +285,43 @@ mod test {
         assert!(<<N as Network>::PoSWSNARK as SNARK>::verify(&verifying_key, &inputs, &proof).unwrap());
     }
 
+    fn posw_partial_assignment_test<N: Network, R: Rng + CryptoRng>(rng: &mut R) {
+        // Generate the proving and verifying key.
+        let (proving_key, verifying_key) = posw_keys::<N, R>(rng);
+
+        // Compute the partial assignment of a reference nonce, which the proofs of all other nonces share.
+        let block_template = genesis_template::<N>();
+        let reference_circuit = PoSWCircuit::<N>::new(&block_template, Uniform::rand(rng)).unwrap();
+        let partial_assignment = Arc::new(
+            <<N as Network>::PoSWSNARK as SNARK>::partial_assignment(&proving_key, &reference_circuit)
+                .unwrap()
+                .unwrap(),
+        );
+
+        for _ in 0..3 {
+            let circuit = PoSWCircuit::<N>::new(&block_template, Uniform::rand(rng)).unwrap();
+            let mut partially_assigned_circuit = circuit.clone();
+            partially_assigned_circuit.set_partial_assignment(partial_assignment.clone());
+
+            // The proof from the shared partial assignment is the proof of the naive prover, given the same randomness.
+            let seed: u64 = rng.gen();
+            let expected =
+                <<N as Network>::PoSWSNARK as SNARK>::prove(&proving_key, &circuit, &mut StdRng::seed_from_u64(seed))
+                    .unwrap();
+            let candidate = <<N as Network>::PoSWSNARK as SNARK>::prove(
+                &proving_key,
+                &partially_assigned_circuit,
+                &mut StdRng::seed_from_u64(seed),
+            )
+            .unwrap();
+            assert_eq!(expected, candidate);
+
+            // Verify the proof is valid on the public inputs.
+            let inputs = circuit.to_public_inputs();
+            assert!(<<N as Network>::PoSWSNARK as SNARK>::verify(&verifying_key, &inputs, &candidate).unwrap());
+        }
+    }
+
     #[test]
     fn test_posw_constraints() {
         posw_constraints_test::<Testnet1>();
@@ -256,4 +333,9 @@ mod test {
         posw_proof_test::<Testnet1, ThreadRng>(&mut thread_rng());
         posw_proof_test::<Testnet2, ThreadRng>(&mut thread_rng());
     }
+
+    #[test]
+    fn test_posw_partial_assignment() {
+        posw_partial_assignment_test::<Testnet2, ThreadRng>(&mut thread_rng());
+    }
 }
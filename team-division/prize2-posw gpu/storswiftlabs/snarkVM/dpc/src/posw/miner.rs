@@ -0,0 +1,572 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-worker PoSW miner, which partitions the nonce space across its workers
+//! and reports its progress as a stream of mining statistics.
+//!
+//! The `hashrate` binary of `msm` mines through this miner. The Trapdoor `posw-gpu` main is not ported,
+//! as it runs on its own snarkVM fork, whose GPU prover setup (`posw::reload_all_bases`) is not part of this tree.
+
+use crate::{
+    posw::PoSWCircuit,
+    BlockHeader,
+    BlockHeaderMetadata,
+    BlockTemplate,
+    Network,
+    PoSWError,
+    PoSWProof,
+    PoSWScheme,
+};
+use snarkvm_algorithms::{errors::SNARKError, traits::SNARK};
+use snarkvm_utilities::Uniform;
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use rand::{rngs::StdRng, CryptoRng, Rng, SeedableRng};
+use rayon::ThreadPoolBuilder;
+use std::{
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+use time::OffsetDateTime;
+
+/// The number of nonces in the range assigned to each worker.
+/// Worker `i` searches the nonces `base_nonce + i * 2^64 + j`, for `j` in `[0, 2^64)`.
+pub const NONCES_PER_WORKER: u128 = 1 << 64;
+
+/// The maximum amount of time the miner waits before checking the terminator and deadline.
+const POLLING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The stack size of the threads in the thread pool of a worker.
+const WORKER_STACK_SIZE: usize = 8 << 20;
+
+/// The check applied by the workers to each proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProofCheck {
+    /// Verify each proof against the difficulty target, and stop at the first valid proof.
+    Difficulty,
+    /// Verify each proof against the difficulty target, count the invalid proofs, and keep proving.
+    Validity,
+    /// Count each proof without verifying it, and keep proving.
+    Unchecked,
+}
+
+/// The configuration of a PoSW miner.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MinerConfig {
+    /// The number of workers proving in parallel.
+    pub number_of_workers: usize,
+    /// The number of threads in the thread pool of each worker, or `None` to use the global thread pool.
+    pub threads_per_worker: Option<usize>,
+    /// The number of workers started together. Each group starts `worker_group_delay` after the previous one.
+    pub worker_group_size: usize,
+    /// The amount of time between the start of two consecutive groups of workers.
+    pub worker_group_delay: Duration,
+    /// The amount of time after the block timestamp the miner searches for a valid proof before giving up.
+    pub maximum_mining_duration: Duration,
+    /// The amount of time before the deadline from which the workers stop starting new proofs.
+    pub proof_cutoff: Duration,
+    /// The maximum number of proofs computed by each worker, if any.
+    pub maximum_proofs_per_worker: Option<u64>,
+    /// The check applied by the workers to each proof.
+    pub proof_check: ProofCheck,
+    /// The amount of time between two consecutive mining statistics.
+    pub stats_interval: Duration,
+}
+
+impl Default for MinerConfig {
+    fn default() -> Self {
+        Self {
+            number_of_workers: 1,
+            threads_per_worker: None,
+            worker_group_size: 1,
+            worker_group_delay: Duration::ZERO,
+            maximum_mining_duration: Duration::from_secs(600), // 600 seconds = 10 minutes.
+            proof_cutoff: Duration::ZERO,
+            maximum_proofs_per_worker: None,
+            proof_check: ProofCheck::Difficulty,
+            stats_interval: Duration::from_secs(120),
+        }
+    }
+}
+
+/// A snapshot of the progress of a PoSW miner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MiningStats {
+    /// The amount of time since the miner started.
+    pub elapsed: Duration,
+    /// The number of proofs computed by all workers.
+    pub total_proofs: u64,
+    /// The number of proofs computed by each worker.
+    pub proofs_per_worker: Vec<u64>,
+    /// The number of proofs which failed verification, which are not counted in `total_proofs`.
+    /// This is only tracked with `ProofCheck::Validity`.
+    pub invalid_proofs: u64,
+    /// The amount of time between the start of the miner and the last computed proof, if any.
+    pub last_proof: Option<Duration>,
+    /// The number of proofs computed per second since the previous statistics.
+    pub proofs_per_second: f64,
+    /// The lowest (best) proof difficulty seen so far, if any proof was computed.
+    pub best_difficulty: Option<u64>,
+}
+
+/// A PoSW miner, which proves over disjoint nonce ranges from multiple workers.
+pub struct Miner<'a, N: Network, P: PoSWScheme<N>> {
+    /// The PoSW instance, which must be set up with a proving key.
+    posw: &'a P,
+    /// The configuration of the miner.
+    config: MinerConfig,
+    /// The subscribers to the mining statistics.
+    subscribers: Vec<Sender<MiningStats>>,
+    _network: PhantomData<N>,
+}
+
+impl<'a, N: Network, P: PoSWScheme<N>> Miner<'a, N, P> {
+    /// Initializes a new instance of a miner.
+    pub fn new(posw: &'a P, config: MinerConfig) -> Self {
+        Self { posw, config, subscribers: Vec::new(), _network: PhantomData }
+    }
+
+    /// Returns the configuration of the miner.
+    pub fn config(&self) -> &MinerConfig {
+        &self.config
+    }
+
+    /// Returns a receiver of the mining statistics, which are sent every stats interval,
+    /// and once more when mining ends.
+    pub fn subscribe(&mut self) -> Receiver<MiningStats> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Returns the nonce at the given index of the range assigned to the given worker.
+    pub fn worker_nonce(base_nonce: N::PoSWNonce, worker: usize, index: u64) -> N::PoSWNonce {
+        let offset = (worker as u128) * NONCES_PER_WORKER + index as u128;
+        N::PoSWNonce::from(*base_nonce + N::InnerScalarField::from(offset))
+    }
+
+    ///
+    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target,
+    /// starting from a random base nonce.
+    ///
+    pub fn mine<R: Rng + CryptoRng>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        self.mine_from_nonce(block_template, Uniform::rand(rng), terminator, rng)
+    }
+
+    ///
+    /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target,
+    /// searching the nonce ranges of each worker from the given base nonce.
+    ///
+    pub fn mine_from_nonce<R: Rng + CryptoRng>(
+        &self,
+        block_template: &BlockTemplate<N>,
+        base_nonce: N::PoSWNonce,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<BlockHeader<N>, PoSWError> {
+        if self.config.number_of_workers == 0 {
+            return Err(PoSWError::Message("The miner requires at least one worker".to_string()));
+        }
+        let proving_key = match self.posw.proving_key() {
+            Some(proving_key) => proving_key,
+            None => return Err(PoSWError::Message("Tried to mine without a PoSW proving key".to_string())),
+        };
+
+        // Reuse the work of the prover that does not depend on the nonce, which all workers share.
+        let mut reference_circuit = PoSWCircuit::<N>::new(block_template, base_nonce)?;
+        self.posw.set_partial_assignment(&mut reference_circuit)?;
+
+        let start = Instant::now();
+        let deadline = self.deadline(block_template, start);
+        // The workers do not start new proofs after the cutoff, as they would not complete before the deadline.
+        let cutoff = deadline.map(|deadline| deadline.checked_sub(self.config.proof_cutoff).unwrap_or(start));
+
+        // The workers stop once a valid proof is found, or the miner is terminated or out of time.
+        let config = self.config;
+        let stop = AtomicBool::new(false);
+        let counters = MiningCounters::new(config.number_of_workers);
+
+        let result = thread::scope(|scope| {
+            let (result_sender, result_receiver) = mpsc::channel();
+
+            for worker in 0..config.number_of_workers {
+                let result_sender = result_sender.clone();
+                let (posw, stop, counters) = (self.posw, &stop, &counters);
+                let mut circuit = reference_circuit.clone();
+                let mut worker_rng = StdRng::from_seed(rng.gen());
+
+                scope.spawn(move || {
+                    // Start the workers group by group, to stagger their use of the provers.
+                    let group = (worker / config.worker_group_size.max(1)) as u32;
+                    let worker_start = start + config.worker_group_delay * group;
+                    while !stop.load(Ordering::SeqCst) && Instant::now() < worker_start {
+                        let remaining = worker_start.saturating_duration_since(Instant::now());
+                        thread::sleep(std::cmp::min(POLLING_INTERVAL, remaining));
+                    }
+
+                    let mut prove = || -> Result<Option<(N::PoSWNonce, PoSWProof<N>)>, PoSWError> {
+                        for index in 0..config.maximum_proofs_per_worker.unwrap_or(u64::MAX) {
+                            if stop.load(Ordering::SeqCst) || cutoff.map_or(false, |cutoff| Instant::now() >= cutoff) {
+                                break;
+                            }
+
+                            // Run one iteration of PoSW on the next nonce in this worker's range.
+                            circuit.set_nonce(Self::worker_nonce(base_nonce, worker, index));
+                            let proof = PoSWProof::<N>::new(
+                                <<N as Network>::PoSWSNARK as SNARK>::prove_with_terminator(
+                                    proving_key,
+                                    &circuit,
+                                    stop,
+                                    &mut worker_rng,
+                                )?
+                                .into(),
+                            );
+
+                            // Check if the proof satisfies the difficulty target.
+                            let is_valid = match config.proof_check {
+                                ProofCheck::Difficulty | ProofCheck::Validity => {
+                                    posw.verify(block_template.difficulty_target(), &circuit.to_public_inputs(), &proof)
+                                }
+                                ProofCheck::Unchecked => true,
+                            };
+                            match config.proof_check {
+                                ProofCheck::Validity if !is_valid => {
+                                    counters.invalid_proofs.fetch_add(1, Ordering::SeqCst);
+                                }
+                                _ => counters.record_proof(worker, start.elapsed(), &proof),
+                            }
+                            if config.proof_check == ProofCheck::Difficulty && is_valid {
+                                return Ok(Some((circuit.nonce(), proof)));
+                            }
+                        }
+                        Ok(None)
+                    };
+
+                    let result = match config.threads_per_worker {
+                        Some(threads) => ThreadPoolBuilder::new()
+                            .stack_size(WORKER_STACK_SIZE)
+                            .num_threads(threads)
+                            .build()
+                            .map_err(|error| PoSWError::Message(format!("Failed to build a worker pool: {}", error)))
+                            .and_then(|pool| pool.install(prove)),
+                        None => prove(),
+                    };
+
+                    match result {
+                        Ok(Some(solution)) => {
+                            let _ = result_sender.send(Ok(solution));
+                        }
+                        Ok(None) => {}
+                        // A worker interrupted by the miner has nothing to report.
+                        Err(_) if stop.load(Ordering::SeqCst) => {}
+                        Err(error) => {
+                            let _ = result_sender.send(Err(error));
+                        }
+                    }
+                });
+            }
+            drop(result_sender);
+
+            let mut previous_stats = (start, 0);
+            let mut next_stats = start + config.stats_interval;
+            let result = loop {
+                // Forward the terminator to the workers, as they are only interrupted by the miner.
+                if terminator.load(Ordering::SeqCst) {
+                    break Err(SNARKError::Terminated.into());
+                }
+
+                let now = Instant::now();
+                if deadline.map_or(false, |deadline| now >= deadline) {
+                    break Err(PoSWError::Message("Failed mine block in the allowed mining duration".to_string()));
+                }
+                if now >= next_stats {
+                    previous_stats = self.send_stats(start, previous_stats, &counters);
+                    next_stats += config.stats_interval;
+                }
+
+                let mut wake_up = std::cmp::min(next_stats, now + POLLING_INTERVAL);
+                if let Some(deadline) = deadline {
+                    wake_up = std::cmp::min(wake_up, deadline);
+                }
+                match result_receiver.recv_timeout(wake_up.saturating_duration_since(now)) {
+                    Ok(result) => break result,
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        break Err(PoSWError::Message("All PoSW workers exited without a proof".to_string()));
+                    }
+                }
+            };
+
+            // Interrupt the remaining workers, which are joined at the end of the scope.
+            stop.store(true, Ordering::SeqCst);
+            result
+        });
+
+        // Report the final statistics, including the proofs of the interrupted workers.
+        self.send_stats(start, (start, 0), &counters);
+        let (nonce, proof) = result?;
+
+        // Construct a block header.
+        Ok(BlockHeader::from(
+            block_template.previous_ledger_root(),
+            block_template.transactions().transactions_root(),
+            BlockHeaderMetadata::new(block_template),
+            nonce,
+            proof,
+        )?)
+    }
+
+    /// Returns the instant at which the miner runs out of time, which is the maximum mining duration
+    /// after the timestamp of the block template, or `None` if it is too far in the future.
+    fn deadline(&self, block_template: &BlockTemplate<N>, now: Instant) -> Option<Instant> {
+        let deadline = block_template.block_timestamp() as i128 * 1_000_000_000
+            + self.config.maximum_mining_duration.as_nanos() as i128;
+        let remaining = deadline - OffsetDateTime::now_utc().unix_timestamp_nanos();
+        now.checked_add(Duration::from_nanos(u64::try_from(remaining.max(0)).unwrap_or(u64::MAX)))
+    }
+
+    /// Sends the current mining statistics to all subscribers, and returns the time and
+    /// the total number of proofs of this snapshot.
+    fn send_stats(
+        &self,
+        start: Instant,
+        (previous_time, previous_total): (Instant, u64),
+        counters: &MiningCounters,
+    ) -> (Instant, u64) {
+        let now = Instant::now();
+        let proofs_per_worker: Vec<u64> =
+            counters.proofs_per_worker.iter().map(|proofs| proofs.load(Ordering::SeqCst)).collect();
+        let total_proofs = proofs_per_worker.iter().sum();
+
+        let interval = now.duration_since(previous_time).as_secs_f64();
+        let proofs_per_second = match interval > 0.0 {
+            true => total_proofs.saturating_sub(previous_total) as f64 / interval,
+            false => 0.0,
+        };
+
+        let stats = MiningStats {
+            elapsed: now.duration_since(start),
+            total_proofs,
+            proofs_per_worker,
+            invalid_proofs: counters.invalid_proofs.load(Ordering::SeqCst),
+            last_proof: match total_proofs {
+                0 => None,
+                _ => Some(Duration::from_nanos(counters.last_proof.load(Ordering::SeqCst))),
+            },
+            proofs_per_second,
+            best_difficulty: match counters.best_difficulty.load(Ordering::SeqCst) {
+                u64::MAX => None,
+                difficulty => Some(difficulty),
+            },
+        };
+        for subscriber in &self.subscribers {
+            // A disconnected subscriber is not an error for the miner.
+            let _ = subscriber.send(stats.clone());
+        }
+
+        (now, total_proofs)
+    }
+}
+
+/// The progress of the workers of a miner, which is shared across the workers.
+struct MiningCounters {
+    /// The number of proofs computed by each worker.
+    proofs_per_worker: Vec<AtomicU64>,
+    /// The number of proofs which failed verification.
+    invalid_proofs: AtomicU64,
+    /// The number of nanoseconds between the start of the miner and the last computed proof.
+    last_proof: AtomicU64,
+    /// The lowest proof difficulty seen so far, or `u64::MAX` if no proof was computed.
+    best_difficulty: AtomicU64,
+}
+
+impl MiningCounters {
+    fn new(number_of_workers: usize) -> Self {
+        Self {
+            proofs_per_worker: (0..number_of_workers).map(|_| AtomicU64::new(0)).collect(),
+            invalid_proofs: AtomicU64::new(0),
+            last_proof: AtomicU64::new(0),
+            best_difficulty: AtomicU64::new(u64::MAX),
+        }
+    }
+
+    /// Records a proof computed by the given worker, the given amount of time after the start of the miner.
+    fn record_proof<N: Network>(&self, worker: usize, elapsed: Duration, proof: &PoSWProof<N>) {
+        self.proofs_per_worker[worker].fetch_add(1, Ordering::SeqCst);
+        self.last_proof.fetch_max(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX), Ordering::SeqCst);
+        if let Ok(difficulty) = proof.to_proof_difficulty() {
+            self.best_difficulty.fetch_min(difficulty, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+
+    type TestMiner<'a> = Miner<'a, Testnet2, <Testnet2 as Network>::PoSW>;
+
+    /// Returns a template of the genesis block, timestamped now so that the mining deadline is ahead.
+    fn genesis_template(difficulty_target: u64) -> BlockTemplate<Testnet2> {
+        let block = Testnet2::genesis_block();
+        BlockTemplate::new(
+            block.previous_block_hash(),
+            block.height(),
+            OffsetDateTime::now_utc().unix_timestamp(),
+            difficulty_target,
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_worker_nonce_ranges() {
+        let base_nonce = <Testnet2 as Network>::PoSWNonce::rand(&mut thread_rng());
+
+        // The nonces are deterministic and start at the base nonce.
+        assert_eq!(base_nonce, TestMiner::worker_nonce(base_nonce, 0, 0));
+        assert_eq!(TestMiner::worker_nonce(base_nonce, 3, 7), TestMiner::worker_nonce(base_nonce, 3, 7));
+
+        // The last nonce of a worker is followed by the first nonce of the next worker.
+        let last = TestMiner::worker_nonce(base_nonce, 1, u64::MAX);
+        let next = TestMiner::worker_nonce(base_nonce, 2, 0);
+        assert_eq!(*next, *last + <Testnet2 as Network>::InnerScalarField::from(1u64));
+    }
+
+    #[test]
+    fn test_mine_with_workers() {
+        let posw = Testnet2::posw();
+        let mut miner = TestMiner::new(posw, MinerConfig { number_of_workers: 2, ..Default::default() });
+        let stats = miner.subscribe();
+
+        let block_template = genesis_template(Testnet2::genesis_block().difficulty_target());
+        let block_header = miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng()).unwrap();
+        assert!(posw.verify_from_block_header(&block_header));
+
+        // The final statistics account for the winning proof.
+        let stats = stats.try_iter().last().unwrap();
+        assert_eq!(stats.proofs_per_worker.len(), 2);
+        assert_eq!(stats.total_proofs, stats.proofs_per_worker.iter().sum::<u64>());
+        assert!(stats.total_proofs >= 1);
+        assert!(stats.best_difficulty.unwrap() <= block_template.difficulty_target());
+    }
+
+    #[test]
+    fn test_mine_timeout() {
+        let config = MinerConfig {
+            number_of_workers: 2,
+            maximum_mining_duration: Duration::from_secs(2),
+            stats_interval: Duration::from_secs(1),
+            ..Default::default()
+        };
+        let mut miner = TestMiner::new(Testnet2::posw(), config);
+        let stats = miner.subscribe();
+
+        // No proof satisfies a difficulty target of zero.
+        let block_template = genesis_template(0);
+        let result = miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng());
+        assert!(matches!(result, Err(PoSWError::Message(_))));
+        assert!(stats.try_iter().count() >= 2);
+    }
+
+    #[test]
+    fn test_mine_expired_template() {
+        let miner = TestMiner::new(Testnet2::posw(), MinerConfig::default());
+
+        // The deadline is relative to the block timestamp, which is long past for the genesis block.
+        let block = Testnet2::genesis_block();
+        let block_template = BlockTemplate::new(
+            block.previous_block_hash(),
+            block.height(),
+            block.timestamp(),
+            block.difficulty_target(),
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+        );
+        let result = miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng());
+        assert!(matches!(result, Err(PoSWError::Message(_))));
+    }
+
+    #[test]
+    fn test_mine_without_proving_key() {
+        let posw = <Testnet2 as Network>::PoSW::load(false).unwrap();
+        let miner = TestMiner::new(&posw, MinerConfig::default());
+
+        // A verify-only PoSW cannot mine.
+        let block_template = genesis_template(Testnet2::genesis_block().difficulty_target());
+        let result = miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng());
+        assert!(matches!(result, Err(PoSWError::Message(_))));
+    }
+
+    #[test]
+    fn test_mine_unchecked_proofs() {
+        let config = MinerConfig {
+            number_of_workers: 2,
+            maximum_proofs_per_worker: Some(2),
+            proof_check: ProofCheck::Unchecked,
+            ..Default::default()
+        };
+        let mut miner = TestMiner::new(Testnet2::posw(), config);
+        let stats = miner.subscribe();
+
+        // Unchecked proofs are counted even if they do not satisfy the difficulty target.
+        let block_template = genesis_template(0);
+        assert!(miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng()).is_err());
+
+        let stats = stats.try_iter().last().unwrap();
+        assert_eq!(stats.proofs_per_worker, vec![2, 2]);
+        assert_eq!(stats.invalid_proofs, 0);
+        assert!(stats.last_proof.unwrap() <= stats.elapsed);
+    }
+
+    #[test]
+    fn test_mine_validity_check() {
+        let config = MinerConfig {
+            number_of_workers: 2,
+            threads_per_worker: Some(1),
+            maximum_proofs_per_worker: Some(1),
+            proof_check: ProofCheck::Validity,
+            ..Default::default()
+        };
+        let mut miner = TestMiner::new(Testnet2::posw(), config);
+        let stats = miner.subscribe();
+
+        // No proof satisfies a difficulty target of zero, so every proof is invalid.
+        let block_template = genesis_template(0);
+        assert!(miner.mine(&block_template, &AtomicBool::new(false), &mut thread_rng()).is_err());
+
+        let stats = stats.try_iter().last().unwrap();
+        assert_eq!(stats.total_proofs, 0);
+        assert_eq!(stats.invalid_proofs, 2);
+        assert_eq!(stats.last_proof, None);
+    }
+}
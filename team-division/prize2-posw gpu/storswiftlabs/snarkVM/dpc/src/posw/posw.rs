@@ -17,9 +17,8 @@
 //! Generic PoSW Miner and Verifier, compatible with any implementer of the SNARK trait.
 
 use crate::{
-    posw::PoSWCircuit,
+    posw::{Miner, MinerConfig, PoSWCircuit},
     BlockHeader,
-    BlockHeaderMetadata,
     BlockTemplate,
     Network,
     PoSWError,
@@ -27,13 +26,16 @@ use crate::{
     PoSWScheme,
 };
 use snarkvm_algorithms::{traits::SNARK, SRS};
+use snarkvm_r1cs::{ConstraintSynthesizer, PartialAssignment};
 use snarkvm_utilities::Uniform;
 
 use core::sync::atomic::AtomicBool;
 use rand::{CryptoRng, Rng};
-use time::OffsetDateTime;
-use std::sync::atomic::{AtomicI64, Ordering};
-use chrono::Local;
+use std::sync::{Arc, RwLock};
+
+/// The partial assignment of a PoSW circuit, and the block header root it was computed for.
+type CachedPartialAssignment<N> =
+    Option<(<N as Network>::BlockHeaderRoot, Arc<PartialAssignment<<N as Network>::InnerScalarField>>)>;
 
 /// A Proof of Succinct Work miner and verifier.
 #[derive(Clone)]
@@ -43,6 +45,8 @@ pub struct PoSW<N: Network> {
     proving_key: Option<<<N as Network>::PoSWSNARK as SNARK>::ProvingKey>,
     /// The verifying key.
     verifying_key: <<N as Network>::PoSWSNARK as SNARK>::VerifyingKey,
+    /// The partial assignment of the last block header root proven with this instance, which the miner shares.
+    partial_assignment: Arc<RwLock<CachedPartialAssignment<N>>>,
 }
 
 impl<N: Network> PoSWScheme<N> for PoSW<N> {
@@ -55,7 +59,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         let (proving_key, verifying_key) =
             <<N as Network>::PoSWSNARK as SNARK>::setup::<_, R>(&PoSWCircuit::<N>::blank()?, srs)?;
 
-        Ok(Self { proving_key: Some(proving_key), verifying_key })
+        Ok(Self { proving_key: Some(proving_key), verifying_key, partial_assignment: Default::default() })
     }
 
     ///
@@ -68,6 +72,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
                 false => None,
             },
             verifying_key: N::posw_verifying_key().clone(),
+            partial_assignment: Default::default(),
         })
     }
 
@@ -85,6 +90,39 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         &self.verifying_key
     }
 
+    ///
+    /// Sets the partial assignment of the block header root of the given circuit on the circuit,
+    /// if it has none, computing it from the circuit if it is not cached.
+    ///
+    fn set_partial_assignment(&self, circuit: &mut PoSWCircuit<N>) -> Result<(), PoSWError> {
+        if circuit.partial_assignment().is_some() {
+            return Ok(());
+        }
+        let proving_key = match &self.proving_key {
+            Some(proving_key) => proving_key,
+            None => return Err(PoSWError::Message("Tried to prove without a PoSW proving key".to_string())),
+        };
+
+        let block_header_root = circuit.block_header_root();
+        let cached = match &*self.partial_assignment.read().unwrap() {
+            Some((root, partial_assignment)) if *root == block_header_root => Some(partial_assignment.clone()),
+            _ => None,
+        };
+        let partial_assignment = match cached {
+            Some(partial_assignment) => partial_assignment,
+            None => match <<N as Network>::PoSWSNARK as SNARK>::partial_assignment(proving_key, circuit)? {
+                Some(partial_assignment) => {
+                    let partial_assignment = Arc::new(partial_assignment);
+                    *self.partial_assignment.write().unwrap() = Some((block_header_root, partial_assignment.clone()));
+                    partial_assignment
+                }
+                None => return Ok(()),
+            },
+        };
+        circuit.set_partial_assignment(partial_assignment);
+        Ok(())
+    }
+
     ///
     /// Given the block template, compute a PoSW and nonce that satisfies the difficulty target.
     ///
@@ -94,64 +132,7 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<BlockHeader<N>, PoSWError> {
-        const MAXIMUM_MINING_DURATION: i64 = 600; // 600 seconds = 10 minutes.
-
-        // Instantiate the circuit.
-        let mut circuit = PoSWCircuit::<N>::new(block_template, Uniform::rand(rng))?;
-
-        let mut iteration = 1;
-        static mut BASE_TIME: AtomicI64 = AtomicI64::new(0);
-        static mut TOTAL_PROOFS: AtomicI64 = AtomicI64::new(0);
-        static mut TEMP_PROOFS: AtomicI64 = AtomicI64::new(0);
-
-        unsafe {
-            if BASE_TIME.load(Ordering::SeqCst) == 0 {
-                BASE_TIME.store(Local::now().timestamp(), Ordering::SeqCst);
-            }
-        }
-
-        loop {
-            // Every 100 iterations, check that the miner is still within the allowed mining duration.
-            if iteration % 100 == 0
-                && OffsetDateTime::now_utc().unix_timestamp()
-                    >= block_template.block_timestamp() + MAXIMUM_MINING_DURATION
-            {
-                return Err(PoSWError::Message("Failed mine block in the allowed mining duration".to_string()));
-            }
-
-            // Run one iteration of PoSW.
-            let proof = self.prove_once_unchecked(&mut circuit, terminator, rng)?;
-            unsafe {
-                TOTAL_PROOFS.fetch_add(1, Ordering::SeqCst);
-                TEMP_PROOFS.fetch_add(1, Ordering::SeqCst);
-                let diff = Local::now().timestamp() - BASE_TIME.load(Ordering::SeqCst);
-                if diff >= 60*2 {
-                    eprintln!(
-                        "{}  mine perf: {:.2} p/s Total: {}",
-                        Local::now().to_rfc3339(),
-                        (TEMP_PROOFS.load(Ordering::SeqCst) as f64) /(diff as f64),
-                        TOTAL_PROOFS.load(Ordering::SeqCst),);
-
-                    BASE_TIME.store(Local::now().timestamp(), Ordering::SeqCst);
-                    TEMP_PROOFS.store(0, Ordering::SeqCst);
-                }
-            }
-
-            // Check if the updated block header is valid.
-            if self.verify(block_template.difficulty_target(), &circuit.to_public_inputs(), &proof) {
-                // Construct a block header.
-                return Ok(BlockHeader::from(
-                    block_template.previous_ledger_root(),
-                    block_template.transactions().transactions_root(),
-                    BlockHeaderMetadata::new(block_template),
-                    circuit.nonce(),
-                    proof,
-                )?);
-            }
-
-            // Increment the iteration by one.
-            iteration += 1;
-        }
+        Miner::new(self, MinerConfig::default()).mine(block_template, terminator, rng)
     }
 
     ///
@@ -169,6 +150,9 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         // Sample a random nonce.
         circuit.set_nonce(Uniform::rand(rng));
 
+        // Reuse the work of the prover that does not depend on the nonce.
+        self.set_partial_assignment(circuit)?;
+
         // Construct a PoSW proof.
         Ok(PoSWProof::<N>::new(
             <<N as Network>::PoSWSNARK as SNARK>::prove_with_terminator(pk, circuit, terminator, rng)?.into(),
@@ -188,6 +172,9 @@ impl<N: Network> PoSWScheme<N> for PoSW<N> {
         // Sample a random nonce.
         circuit.set_nonce(Uniform::rand(rng));
 
+        // Reuse the work of the prover that does not depend on the nonce.
+        self.set_partial_assignment(circuit)?;
+
         // Construct a PoSW proof.
         Ok(PoSWProof::<N>::new(
             <<N as Network>::PoSWSNARK as SNARK>::prove_with_terminator(pk, circuit, terminator, rng)?
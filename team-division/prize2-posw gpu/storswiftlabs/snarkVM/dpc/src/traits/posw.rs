@@ -46,6 +46,10 @@ pub trait PoSWScheme<N: Network>: Clone + Send + Sync {
         rng: &mut R,
     ) -> Result<BlockHeader<N>, PoSWError>;
 
+    /// Sets the partial assignment of the block header root of the given circuit on the circuit, if it has none,
+    /// so that proofs for its nonces reuse the work that does not depend on the nonce.
+    fn set_partial_assignment(&self, circuit: &mut PoSWCircuit<N>) -> Result<(), PoSWError>;
+
     ///
     /// Given the block template, compute a PoSW proof.
     /// WARNING - This method does *not* ensure the resulting proof satisfies the difficulty target.
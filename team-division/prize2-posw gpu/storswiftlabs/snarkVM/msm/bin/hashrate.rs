@@ -14,31 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_dpc::{testnet2::Testnet2, BlockHeader, BlockTemplate, Network, PoSWScheme};
-use std::{
-    collections::VecDeque,
-    env,
-    process,
-    sync::{
-        atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering},
-        Arc,
-    },
-    time::Duration,
-};
-
-use chrono::Local;
+use snarkvm_dpc::{testnet2::Testnet2, BlockHeader, BlockTemplate, Miner, MinerConfig, Network, ProofCheck};
+use std::{collections::VecDeque, env, process, sync::atomic::AtomicBool, thread, time::Duration};
+
+use chrono::Utc;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
 use structopt::StructOpt;
 
-use rayon::{ThreadPool, ThreadPoolBuilder};
-use tokio::task;
-
 use tracing::*;
 use tracing_subscriber::{fmt, EnvFilter};
 
 pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
-const MAXIMUM_MINING_DURATION: i64 = 20000;
+const MAXIMUM_MINING_DURATION: Duration = Duration::from_secs(20);
+const STATS_INTERVAL: Duration = Duration::from_secs(20);
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "prover", about = "Standalone prover.", setting = structopt::clap::AppSettings::ColoredHelp)]
@@ -89,11 +78,10 @@ struct Opt {
     /// 在-s设置后生效，表示时间周期末端不起的新任务
     /// Example: -l 500
     #[structopt(short = "l", long = "loss-time")]
-    loss_time: Option<i64>,
+    loss_time: Option<u64>,
 }
 
-#[tokio::main]
-async fn main() {
+fn main() {
     let opt = Opt::from_args();
 
     let debug = opt.debug;
@@ -104,41 +92,23 @@ async fn main() {
     }
 
     let second = opt.second;
-    let verify = opt.verify;
     let once = opt.once;
 
-    let loss_time = opt.loss_time.unwrap_or(0);
     // 单任务线程数
     let mut threads = opt.threads.unwrap_or(7);
-    // 并发任务数
-    let jobs: Option<u8>;
     // GPU编号
-    let cuda: Option<String>;
-
-    if cfg!(feature = "cuda") || cfg!(feature = "opencl") {
-        cuda = opt.cuda;
-        if cuda.is_none() {
-            if opt.jobs.is_none() {
-                jobs = Some(2)
-            } else {
-                jobs = opt.jobs;
-            }
-        } else {
+    let cuda: Option<String> = match cfg!(feature = "cuda") || cfg!(feature = "opencl") {
+        true => opt.cuda,
+        false => None,
+    };
+    // 并发任务数
+    let jobs = match cuda {
+        Some(_) => {
             threads = 2;
-            if opt.jobs.is_none() {
-                jobs = Some(26)
-            } else {
-                jobs = opt.jobs;
-            }
+            opt.jobs.unwrap_or(26)
         }
-    } else {
-        cuda = None;
-        if opt.jobs.is_none() {
-            jobs = Some(2)
-        } else {
-            jobs = opt.jobs;
-        }
-    }
+        None => opt.jobs.unwrap_or(2),
+    };
 
     if let Some(cuda) = cuda.clone() {
         env::set_var("BELLMAN_WORKING_GPUS", cuda.to_string());
@@ -147,162 +117,75 @@ async fn main() {
         info!("{} Starting prover with CPU", VERSION);
     }
 
-    let mut threadpools: Vec<Arc<ThreadPool>> = Vec::new();
-
-    for _ in 0..jobs.unwrap() {
-        let p = ThreadPoolBuilder::new().stack_size(8 << 20).num_threads(threads).build().unwrap();
-        threadpools.push(Arc::new(p));
+    // Every fifth job starts INIT_WAIT_TIME milliseconds (at most 800) after the previous ones.
+    let mut init_wait_time = 0;
+    if let Ok(t) = env::var("INIT_WAIT_TIME") {
+        init_wait_time = std::cmp::min(t.parse::<u64>().unwrap(), 800);
     }
 
-    let block = Testnet2::genesis_block();
-    let block_template = BlockTemplate::new(
-        block.previous_block_hash(),
-        block.height(),
-        block.timestamp(),
-        block.difficulty_target(),
-        block.cumulative_weight(),
-        block.previous_ledger_root(),
-        block.transactions().clone(),
-        block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
-    );
-
-    let threadpools = Arc::new(threadpools);
-    let terminator = Arc::new(AtomicBool::new(false));
-    let total_proofs: Arc<AtomicU32> = Arc::new(AtomicU32::new(0));
-    let invalid_proofs: Arc<AtomicU32> = Arc::new(AtomicU32::new(0));
-    let usetime: Arc<AtomicI64> = Arc::new(AtomicI64::new(0));
-    let last_thread_time: Arc<AtomicI64> = Arc::new(AtomicI64::new(0));
-
-    let threadpools = threadpools.clone();
-    let totals = total_proofs.clone();
-
+    // Warm up the prover before the test starts.
+    let terminator = AtomicBool::new(false);
     let mut rng = ChaChaRng::seed_from_u64(1234567);
-    BlockHeader::mine_once_unchecked(&block_template, &terminator, &mut rng).unwrap();
+    let warm_up_template = genesis_template(Testnet2::genesis_block().timestamp());
+    BlockHeader::mine_once_unchecked(&warm_up_template, &terminator, &mut rng).unwrap();
+
+    // The miner runs until the block timestamp plus the mining duration, so the block is timestamped now.
+    let now = Utc::now();
+    let block_template = genesis_template(now.timestamp());
+
+    let config = MinerConfig {
+        number_of_workers: if once { 1 } else { jobs as usize },
+        threads_per_worker: Some(threads),
+        worker_group_size: 5,
+        worker_group_delay: Duration::from_millis(init_wait_time),
+        // The block timestamp is truncated to the second, which the mining duration makes up for.
+        maximum_mining_duration: match second {
+            true => MAXIMUM_MINING_DURATION + Duration::from_nanos(now.timestamp_subsec_nanos() as u64),
+            false => Duration::MAX,
+        },
+        // Jobs do not start new proofs in the last milliseconds of the test, as they would not complete in time.
+        proof_cutoff: match second {
+            true => Duration::from_millis(opt.loss_time.unwrap_or(0)),
+            false => Duration::ZERO,
+        },
+        maximum_proofs_per_worker: if once { Some(1) } else { None },
+        // Proofs are only verified on request, so that the proof rates are comparable across runs.
+        proof_check: match opt.verify {
+            true => ProofCheck::Validity,
+            false => ProofCheck::Unchecked,
+        },
+        stats_interval: STATS_INTERVAL,
+    };
+    let mut miner = Miner::<Testnet2, _>::new(Testnet2::posw(), config);
+    let stats = miner.subscribe();
 
-    task::spawn(async move {
-        log_rate(totals).await;
-    });
-    let start_time = Local::now();
-    let mut handlers = Vec::new();
-    let mut pool_i: i64 = 0;
-    for pool in threadpools.iter() {
-        let terminator = terminator.clone();
-        let block_template = block_template.clone();
-        let pool = pool.clone();
-        let total_proofs = total_proofs.clone();
-        let invalid_proofs = invalid_proofs.clone();
-        let usetime = usetime.clone();
-        let last_thread_time = last_thread_time.clone();
-        pool_i = pool_i + 1;
-        if pool_i % 5 == 0{
-            let mut min_time: i64 = 0;
-            if let Ok(t) = std::env::var("INIT_WAIT_TIME") {
-                let m = t.parse::<u32>().unwrap();
-                min_time = if m <= 800 { m as i64 } else { 800 };
+    let logger = thread::spawn(move || {
+        fn calculate_proof_rate(now: u64, past: u64, interval: u64) -> Box<str> {
+            if interval < 1 {
+                return Box::from("---");
             }
-
-            let thread_last_time = last_thread_time.load(Ordering::SeqCst);
-            let thread_start_time = Local::now().timestamp_millis();
-            let diff_time = thread_start_time - thread_last_time;
-            if min_time - diff_time > 0 {
-                last_thread_time.store(thread_last_time + min_time, Ordering::SeqCst);
-                tokio::time::sleep(Duration::from_millis((min_time - diff_time).try_into().unwrap())).await;
-            } else {
-                last_thread_time.store(thread_start_time, Ordering::SeqCst);
+            if now <= past || past == 0 {
+                return Box::from("---");
             }
+            let rate = (now - past) as f64 / (interval * 60) as f64;
+            Box::from(format!("{:.2}", rate))
         }
-        handlers.push(task::spawn(async move {
-            loop {
-                let terminator = terminator.clone();
-                let block_template = block_template.clone();
-                // let block_height = block_template.block_height();
-                let difficulty_target = block_template.difficulty_target();
-                let header_root = block_template.to_header_root().unwrap();
-                let pool = pool.clone();
-                let mut rng = ChaChaRng::seed_from_u64(1234567);
-                if let Ok(Ok(block_header)) = task::spawn_blocking(move || {
-                    pool.install(|| BlockHeader::mine_once_unchecked(&block_template, &terminator, &mut rng))
-                })
-                .await
-                {
-                    let proof = block_header.proof().clone();
-                    if verify {
-                        let difficulty = proof.to_proof_difficulty().unwrap_or(u64::MAX);
-                        debug!("cacl proof difficulty {}, block template target {}", difficulty, difficulty_target);
-                        if !Testnet2::posw().verify(difficulty_target, &[*header_root, *block_header.nonce()], &proof) {
-                            invalid_proofs.fetch_add(1, Ordering::SeqCst);
-                            let invalid = invalid_proofs.load(Ordering::SeqCst);
-                            error!("proof invalid {}", invalid);
-                            continue;
-                        }
-                    }
-
-                    if second {
-                        let start_t = start_time.timestamp_millis();
-                        let cur_t = Local::now().timestamp_millis();
-                        let diff_time = cur_t - start_t;
-                        if diff_time < MAXIMUM_MINING_DURATION {
-                            usetime.store(diff_time, Ordering::SeqCst);
-                            total_proofs.fetch_add(1, Ordering::SeqCst);
-                        }
-                    } else {
-                        total_proofs.fetch_add(1, Ordering::SeqCst);
-                    }
-                }
-
-                if second {
-                    let start_t = start_time.timestamp_millis();
-                    let cur_t = Local::now().timestamp_millis();
-                    let diff_time = cur_t - start_t;
-                    if diff_time >= (MAXIMUM_MINING_DURATION - loss_time) {
-                        break;
-                    }
-                }
-
-                if once {
-                    break;
-                }
-            }
-        }));
 
-        if once {
-            break;
-        }
-    }
-    futures::future::join_all(handlers).await;
-    let proofs = total_proofs.load(Ordering::SeqCst);
-    let use_t = usetime.load(Ordering::SeqCst);
-    info!(
-        "{}",
-        format!("Test end ! 20 second create proofs {}, lost time {})", proofs, (MAXIMUM_MINING_DURATION - use_t))
-    );
-}
-
-async fn log_rate(total_proofs: Arc<AtomicU32>) {
-    fn calculate_proof_rate(now: u32, past: u32, interval: u32) -> Box<str> {
-        if interval < 1 {
-            return Box::from("---");
-        }
-        if now <= past || past == 0 {
-            return Box::from("---");
-        }
-        let rate = (now - past) as f64 / (interval * 60) as f64;
-        Box::from(format!("{:.2}", rate))
-    }
-    let mut log = VecDeque::<u32>::from(vec![0; 60]);
-    loop {
-        tokio::time::sleep(Duration::from_secs(20)).await;
-        let proofs = total_proofs.load(Ordering::SeqCst);
-        log.push_back(proofs);
-        let s20 = *log.get(59).unwrap_or(&0);
-        let m1 = *log.get(57).unwrap_or(&0);
-        let m5 = *log.get(45).unwrap_or(&0);
-        let m10 = *log.get(30).unwrap_or(&0);
-        let m20 = log.pop_front().unwrap_or_default();
-
-        info!(
-            "{}",
-            (format!(
+        let mut log = VecDeque::<u64>::from(vec![0; 60]);
+        let mut last_stats = None;
+        for stats in stats {
+            let proofs = stats.total_proofs;
+            log.push_back(proofs);
+            let s20 = *log.get(59).unwrap_or(&0);
+            let m1 = *log.get(57).unwrap_or(&0);
+            let m5 = *log.get(45).unwrap_or(&0);
+            let m10 = *log.get(30).unwrap_or(&0);
+            let m20 = log.pop_front().unwrap_or_default();
+
+            if stats.invalid_proofs > 0 {
+                error!("proof invalid {}", stats.invalid_proofs);
+            }
+            info!(
                 "{} {} perf: {} (last 20s:{})(1m: {} P/s, 5m: {} P/s, 10m: {} P/s, 20m: {} P/s)",
                 VERSION,
                 process::id(),
@@ -312,11 +195,42 @@ async fn log_rate(total_proofs: Arc<AtomicU32>) {
                 calculate_proof_rate(proofs, m5, 5),
                 calculate_proof_rate(proofs, m10, 10),
                 calculate_proof_rate(proofs, m20, 20),
-            ))
+            );
+            last_stats = Some(stats);
+        }
+        last_stats
+    });
+
+    if let Err(error) = miner.mine(&block_template, &terminator, &mut rng) {
+        debug!("{}", error);
+    }
+    drop(miner);
+
+    if let Some(stats) = logger.join().unwrap() {
+        let use_time = stats.last_proof.unwrap_or_default();
+        info!(
+            "Test end ! 20 second create proofs {}, lost time {}",
+            stats.total_proofs,
+            MAXIMUM_MINING_DURATION.saturating_sub(use_time).as_millis()
         );
     }
 }
 
+/// Returns a template of the genesis block with the given timestamp.
+fn genesis_template(timestamp: i64) -> BlockTemplate<Testnet2> {
+    let block = Testnet2::genesis_block();
+    BlockTemplate::new(
+        block.previous_block_hash(),
+        block.height(),
+        timestamp,
+        block.difficulty_target(),
+        block.cumulative_weight(),
+        block.previous_ledger_root(),
+        block.transactions().clone(),
+        block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+    )
+}
+
 fn init_logger(v: u8) {
     match v {
         0 => std::env::set_var("RUST_LOG", "info"),
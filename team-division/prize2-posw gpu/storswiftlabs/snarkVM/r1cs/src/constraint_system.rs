@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::{errors::SynthesisError, Index, LinearCombination, Namespace, PartialAssignment, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -25,6 +25,36 @@ use std::marker::PhantomData;
 pub trait ConstraintSynthesizer<F: Field>: Sync {
     /// Drives generation of new constraints inside `CS`.
     fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError>;
+
+    /// Drives generation of the given part of the constraints inside `CS`, and returns `true`.
+    /// Synthesizing the fixed prefix and then the remainder must be equivalent to `generate_constraints`.
+    /// When synthesizing the remainder, `CS` holds the variables of the fixed prefix, which may have
+    /// been copied from another instance of this circuit instead of being synthesized.
+    ///
+    /// Returns `false`, without generating any constraint, if this circuit does not split its synthesis.
+    fn generate_constraints_part<CS: ConstraintSystem<F>>(
+        &self,
+        _cs: &mut CS,
+        _part: SynthesisPart,
+    ) -> Result<bool, SynthesisError> {
+        Ok(false)
+    }
+
+    /// Returns the partial assignment of a reference instance of this circuit, if any.
+    /// Provers use it to only recompute the contributions of the variables that differ
+    /// from the reference instance.
+    fn partial_assignment(&self) -> Option<&PartialAssignment<F>> {
+        None
+    }
+}
+
+/// A part of the synthesis of a circuit, for circuits which split their synthesis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SynthesisPart {
+    /// The constraints and variables which are the same for all instances sharing a partial assignment.
+    FixedPrefix,
+    /// The constraints and variables which follow the fixed prefix.
+    Remainder,
 }
 
 /// Represents a constraint system which can have new variables
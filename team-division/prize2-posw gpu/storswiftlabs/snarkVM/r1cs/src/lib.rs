@@ -24,7 +24,7 @@ mod constraint_counter;
 pub use constraint_counter::*;
 
 mod constraint_system;
-pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem};
+pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem, SynthesisPart};
 
 mod constraint_variable;
 pub use constraint_variable::*;
@@ -41,6 +41,9 @@ pub use namespace::*;
 mod optional_vec;
 pub use optional_vec::*;
 
+mod partial_assignment;
+pub use partial_assignment::*;
+
 mod test_constraint_system;
 pub use test_constraint_system::{Fr, TestConstraintSystem};
 
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+
+use std::{
+    any::Any,
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+/// The assignment of a reference instance of a circuit, together with the products of some
+/// constraint matrices with it. The products for another instance of the same circuit are
+/// obtained by only updating the rows of the variables that differ from the reference.
+///
+/// A matrix is given in row-major order, where each entry is a coefficient and the index of
+/// its variable in the full assignment, i.e. the public variables followed by the private ones.
+#[derive(Clone)]
+pub struct PartialAssignment<F: Field> {
+    /// The public variables of the reference instance.
+    public_variables: Vec<F>,
+    /// The private variables of the reference instance.
+    private_variables: Vec<F>,
+    /// For each matrix, the coefficients and rows of the entries of each variable.
+    columns: Vec<Vec<Vec<(F, usize)>>>,
+    /// For each matrix, its product with the reference assignment.
+    products: Vec<Vec<F>>,
+    /// The size of the fixed prefix of the synthesis, if the circuit splits its synthesis.
+    fixed_prefix: Option<PrefixSize>,
+    /// The data a prover derives from the reference assignment, such as commitments to its products.
+    prover_data: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+/// The number of variables and constraints of the fixed prefix of the synthesis of a circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PrefixSize {
+    /// The number of public variables, including the constant `one`.
+    pub num_public_variables: usize,
+    /// The number of private variables.
+    pub num_private_variables: usize,
+    /// The number of constraints.
+    pub num_constraints: usize,
+}
+
+impl<F: Field> PartialAssignment<F> {
+    /// Initializes a new partial assignment from the given reference assignment and matrices.
+    pub fn new(public_variables: Vec<F>, private_variables: Vec<F>, matrices: &[&[Vec<(F, usize)>]]) -> Self {
+        let num_variables = public_variables.len() + private_variables.len();
+
+        let mut columns = Vec::with_capacity(matrices.len());
+        let mut products = Vec::with_capacity(matrices.len());
+        for matrix in matrices {
+            let mut matrix_columns = vec![Vec::new(); num_variables];
+            let mut product = Vec::with_capacity(matrix.len());
+            for (row, entries) in matrix.iter().enumerate() {
+                let mut result = F::zero();
+                for &(coefficient, index) in entries {
+                    matrix_columns[index].push((coefficient, row));
+                    result += match index < public_variables.len() {
+                        true => public_variables[index],
+                        false => private_variables[index - public_variables.len()],
+                    } * coefficient;
+                }
+                product.push(result);
+            }
+            columns.push(matrix_columns);
+            products.push(product);
+        }
+
+        Self { public_variables, private_variables, columns, products, fixed_prefix: None, prover_data: None }
+    }
+
+    /// Records the size of the fixed prefix of the synthesis of the reference instance,
+    /// whose variables are the leading variables of the reference assignment.
+    pub fn with_fixed_prefix(mut self, fixed_prefix: PrefixSize) -> Self {
+        assert!(fixed_prefix.num_public_variables <= self.public_variables.len());
+        assert!(fixed_prefix.num_private_variables <= self.private_variables.len());
+        self.fixed_prefix = Some(fixed_prefix);
+        self
+    }
+
+    /// Returns the size of the fixed prefix of the synthesis, if the circuit splits its synthesis.
+    pub fn fixed_prefix(&self) -> Option<PrefixSize> {
+        self.fixed_prefix
+    }
+
+    /// Attaches the data a prover derives from the reference assignment, replacing any previous data.
+    pub fn set_prover_data<T: Any + Send + Sync>(&mut self, prover_data: T) {
+        self.prover_data = Some(Arc::new(prover_data));
+    }
+
+    /// Returns the data attached by a prover, if it has the given type.
+    pub fn prover_data<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.prover_data.as_ref().and_then(|prover_data| prover_data.downcast_ref())
+    }
+
+    /// Returns the public variables of the reference instance.
+    pub fn public_variables(&self) -> &[F] {
+        &self.public_variables
+    }
+
+    /// Returns the private variables of the reference instance.
+    pub fn private_variables(&self) -> &[F] {
+        &self.private_variables
+    }
+
+    /// Returns the products of the matrices with the reference assignment.
+    pub fn products(&self) -> &[Vec<F>] {
+        &self.products
+    }
+
+    /// Returns the products of the matrices with the given assignment, recomputing only the
+    /// contributions of the variables that differ from the reference assignment.
+    /// Returns `None` if the assignment does not have the shape of the reference assignment.
+    pub fn products_for(&self, public_variables: &[F], private_variables: &[F]) -> Option<Vec<Vec<F>>> {
+        let differences = self.product_differences_for(public_variables, private_variables)?;
+
+        Some(
+            differences
+                .into_iter()
+                .zip(&self.products)
+                .map(|(mut difference, product)| {
+                    difference.iter_mut().zip(product).for_each(|(difference, product)| *difference += product);
+                    difference
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the differences between the products of the matrices with the given assignment
+    /// and with the reference assignment, which are zero in the rows of unchanged variables.
+    /// Returns `None` if the assignment does not have the shape of the reference assignment.
+    pub fn product_differences_for(&self, public_variables: &[F], private_variables: &[F]) -> Option<Vec<Vec<F>>> {
+        let changed_variables = self.changed_variables(public_variables, private_variables)?;
+        Some(self.product_differences(&changed_variables))
+    }
+
+    /// Returns the index of each variable of the given assignment which differs from the reference
+    /// assignment, with its difference from the reference assignment.
+    /// Returns `None` if the assignment does not have the shape of the reference assignment.
+    pub fn changed_variables(&self, public_variables: &[F], private_variables: &[F]) -> Option<Vec<(usize, F)>> {
+        if public_variables.len() != self.public_variables.len()
+            || private_variables.len() != self.private_variables.len()
+        {
+            return None;
+        }
+
+        Some(
+            public_variables
+                .iter()
+                .chain(private_variables)
+                .zip(self.public_variables.iter().chain(&self.private_variables))
+                .enumerate()
+                .filter(|(_, (variable, reference))| variable != reference)
+                .map(|(index, (variable, reference))| (index, *variable - *reference))
+                .collect(),
+        )
+    }
+
+    /// Returns the differences between the products of the matrices with the assignment given by
+    /// the changed variables and with the reference assignment.
+    pub fn product_differences(&self, changed_variables: &[(usize, F)]) -> Vec<Vec<F>> {
+        self.columns
+            .iter()
+            .zip(&self.products)
+            .map(|(columns, product)| {
+                let mut product_difference = vec![F::zero(); product.len()];
+                for (index, difference) in changed_variables {
+                    for &(coefficient, row) in &columns[*index] {
+                        product_difference[row] += *difference * coefficient;
+                    }
+                }
+                product_difference
+            })
+            .collect()
+    }
+}
+
+impl<F: Field> PartialEq for PartialAssignment<F> {
+    /// Compares the reference assignments and matrices, as the prover data is derived from them.
+    fn eq(&self, other: &Self) -> bool {
+        self.public_variables == other.public_variables
+            && self.private_variables == other.private_variables
+            && self.columns == other.columns
+            && self.products == other.products
+            && self.fixed_prefix == other.fixed_prefix
+    }
+}
+
+impl<F: Field> Eq for PartialAssignment<F> {}
+
+impl<F: Field> Debug for PartialAssignment<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartialAssignment")
+            .field("public_variables", &self.public_variables)
+            .field("private_variables", &self.private_variables)
+            .field("products", &self.products)
+            .field("fixed_prefix", &self.fixed_prefix)
+            .field("has_prover_data", &self.prover_data.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{rand::test_rng, Uniform};
+
+    #[test]
+    fn test_products_for() {
+        let rng = &mut test_rng();
+
+        let (num_public, num_private, num_rows) = (4, 60, 50);
+        let mut matrix = vec![Vec::new(); num_rows];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for j in 0..i % 8 {
+                row.push((Fr::rand(rng), (i * 7 + j * 13) % (num_public + num_private)));
+            }
+        }
+
+        let public_variables: Vec<Fr> = (0..num_public).map(|_| Fr::rand(rng)).collect();
+        let private_variables: Vec<Fr> = (0..num_private).map(|_| Fr::rand(rng)).collect();
+        let partial = PartialAssignment::new(public_variables.clone(), private_variables.clone(), &[&matrix[..]]);
+
+        // Change one public variable and a few private ones.
+        let mut new_public_variables = public_variables;
+        new_public_variables[1] = Fr::rand(rng);
+        let mut new_private_variables = private_variables;
+        for index in [0, 7, 42] {
+            new_private_variables[index] = Fr::rand(rng);
+        }
+
+        let expected =
+            PartialAssignment::new(new_public_variables.clone(), new_private_variables.clone(), &[&matrix[..]]);
+        let candidate = partial.products_for(&new_public_variables, &new_private_variables).unwrap();
+        assert_eq!(expected.products(), candidate);
+
+        // The differences are zero in the rows which only involve unchanged variables.
+        let differences = partial.product_differences_for(&new_public_variables, &new_private_variables).unwrap();
+        for (row, entries) in matrix.iter().enumerate() {
+            let is_changed = entries.iter().any(|&(_, index)| [1, 4, 11, 46].contains(&index));
+            assert_eq!(differences[0][row] + partial.products()[0][row], candidate[0][row]);
+            if !is_changed {
+                assert!(differences[0][row].is_zero());
+            }
+        }
+
+        // Only the changed variables are recorded, in order of their index in the full assignment.
+        let changed_variables = partial.changed_variables(&new_public_variables, &new_private_variables).unwrap();
+        assert_eq!(changed_variables.iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![1, 4, 11, 46]);
+        assert_eq!(partial.product_differences(&changed_variables), differences);
+
+        // An assignment of a different shape is rejected.
+        assert!(partial.products_for(&new_public_variables, &new_private_variables[1..]).is_none());
+    }
+
+    #[test]
+    fn test_prover_data() {
+        let mut partial = PartialAssignment::<Fr>::new(vec![Fr::one()], vec![], &[]);
+        assert!(partial.prover_data::<u64>().is_none());
+
+        // The prover data is only returned with its own type, and does not affect equality.
+        partial.set_prover_data(7u64);
+        assert_eq!(partial.prover_data::<u64>(), Some(&7));
+        assert!(partial.prover_data::<u32>().is_none());
+        assert_eq!(partial, PartialAssignment::new(vec![Fr::one()], vec![], &[]));
+    }
+}